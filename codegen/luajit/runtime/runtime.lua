@@ -145,6 +145,11 @@ do
 			end
 		end
 
+		-- Rounding to zero keeps the sign of the input
+		if result == 0 then
+			result = num * 0
+		end
+
 		return result
 	end
 
@@ -642,8 +647,11 @@ do
 
 	local alias_t = ffi.typeof("uint8_t *")
 	local any_t = ffi.typeof("union Any *")
+	local string_t = ffi.typeof("const uint8_t *")
 	local cast = ffi.cast
 
+	local WASM_PAGE_SIZE = 65536
+
 	local function by_offset(pointer, offset)
 		local aliased = cast(alias_t, pointer)
 
@@ -767,7 +775,21 @@ do
 		ffi.fill(start, len, value)
 	end
 
-	local WASM_PAGE_SIZE = 65536
+	function store.init(memory, addr, data, offset, len)
+		-- Dropped segments behave as if they were empty
+		data = data or ""
+		addr = to_number(u32(addr))
+		offset = to_number(u32(offset))
+		len = to_number(u32(len))
+
+		if offset + len > #data or addr + len > memory.min * WASM_PAGE_SIZE then
+			error("out of bounds memory access")
+		end
+
+		local start = by_offset(memory.data, addr)
+
+		ffi.copy(start, cast(string_t, data) + offset, len)
+	end
 
 	local function finalizer(memory)
 		ffi.C.free(memory.data)
@@ -814,4 +836,1191 @@ do
 	module.allocator = allocator
 end
 
+do
+	local atomic = {}
+
+	-- Nothing else can change memory while we wait, so a matching value
+	-- would block forever or until it times out. Hosts can set the
+	-- `wait_hook` to trap or yield instead, returning the wait result
+	local function wait_for(timeout)
+		local hook = atomic.wait_hook
+
+		if hook then
+			return hook(timeout)
+		elseif timeout < 0 then
+			error("wait would block forever")
+		else
+			return 2
+		end
+	end
+
+	function atomic.wait_i32(value, expected, timeout)
+		if value ~= expected then
+			return 1
+		end
+
+		return wait_for(timeout)
+	end
+
+	function atomic.wait_i64(value, expected, timeout)
+		if value ~= expected then
+			return 1
+		end
+
+		return wait_for(timeout)
+	end
+
+	local atomic_load = {}
+	local atomic_store = {}
+
+	local load = module.load
+	local store = module.store
+
+	local WASM_PAGE_SIZE = 65536
+
+	-- Atomic accesses trap when the address is not a multiple of their
+	-- size, and are bounds checked here as plain accesses are not
+	local function new_checked(func, size)
+		return function(memory, addr, value)
+			if addr % size ~= 0 then
+				error("unaligned atomic")
+			elseif addr < 0 or addr + size > memory.min * WASM_PAGE_SIZE then
+				error("out of bounds memory access")
+			end
+
+			return func(memory, addr, value)
+		end
+	end
+
+	atomic_load.i32 = new_checked(load.i32, 4)
+	atomic_load.i64 = new_checked(load.i64, 8)
+	atomic_load.i32_u8 = new_checked(load.i32_u8, 1)
+	atomic_load.i32_u16 = new_checked(load.i32_u16, 2)
+	atomic_load.i64_u8 = new_checked(load.i64_u8, 1)
+	atomic_load.i64_u16 = new_checked(load.i64_u16, 2)
+	atomic_load.i64_u32 = new_checked(load.i64_u32, 4)
+
+	atomic_store.i32 = new_checked(store.i32, 4)
+	atomic_store.i64 = new_checked(store.i64, 8)
+	atomic_store.i32_n8 = new_checked(store.i32_n8, 1)
+	atomic_store.i32_n16 = new_checked(store.i32_n16, 2)
+	atomic_store.i64_n8 = new_checked(store.i64_n8, 1)
+	atomic_store.i64_n16 = new_checked(store.i64_n16, 2)
+	atomic_store.i64_n32 = new_checked(store.i64_n32, 4)
+
+	module.atomic = atomic
+	module.atomic_load = atomic_load
+	module.atomic_store = atomic_store
+end
+
+do
+	local tbl = {}
+
+	-- Dropped segments behave as if they were empty
+	local EMPTY_ELEMENT = { n = 0 }
+
+	local function assert_in_bounds(target, index, len)
+		if index + len > target.min then
+			error("out of bounds table access")
+		end
+	end
+
+	function tbl.get(target, index)
+		index = to_number(u32(index))
+
+		assert_in_bounds(target, index, 1)
+
+		return target.data[index]
+	end
+
+	function tbl.set(target, index, value)
+		index = to_number(u32(index))
+
+		assert_in_bounds(target, index, 1)
+
+		target.data[index] = value
+	end
+
+	function tbl.size(target)
+		return target.min
+	end
+
+	local function fill_unchecked(data, index, value, len)
+		for i = index, index + len - 1 do
+			data[i] = value
+		end
+	end
+
+	function tbl.fill(target, index, value, len)
+		index = to_number(u32(index))
+		len = to_number(u32(len))
+
+		assert_in_bounds(target, index, len)
+		fill_unchecked(target.data, index, value, len)
+	end
+
+	function tbl.grow(target, value, num)
+		num = to_number(u32(num))
+
+		local old = target.min
+		local new = old + num
+
+		if new > target.max then
+			return -1
+		else
+			-- Empty slots are already `nil` so they only need filling with real values
+			if value ~= nil then
+				fill_unchecked(target.data, old, value, num)
+			end
+
+			target.min = new
+
+			return to_signed(old)
+		end
+	end
+
+	-- Copies backwards when the ranges overlap so that no entry is overwritten before it is read
+	local function move_unchecked(source, first, last, index, destination)
+		if source == destination and index > first then
+			for i = last - first, 0, -1 do
+				destination[index + i] = source[first + i]
+			end
+		else
+			for i = 0, last - first do
+				destination[index + i] = source[first + i]
+			end
+		end
+	end
+
+	function tbl.copy(target_1, index_1, target_2, index_2, len)
+		index_1 = to_number(u32(index_1))
+		index_2 = to_number(u32(index_2))
+		len = to_number(u32(len))
+
+		assert_in_bounds(target_1, index_1, len)
+		assert_in_bounds(target_2, index_2, len)
+
+		move_unchecked(target_2.data, index_2, index_2 + len - 1, index_1, target_1.data)
+	end
+
+	function tbl.init(target, index, element, offset, len)
+		element = element or EMPTY_ELEMENT
+		index = to_number(u32(index))
+		offset = to_number(u32(offset))
+		len = to_number(u32(len))
+
+		if offset + len > element.n then
+			error("out of bounds table access")
+		end
+
+		assert_in_bounds(target, index, len)
+		move_unchecked(element, offset + 1, offset + len, index, target.data)
+	end
+
+	module.table = tbl
+end
+
+do
+	local select = {}
+
+	-- `condition and on_true or on_false` picks `on_false` whenever
+	-- `on_true` is a null reference, since those are `nil`
+	function select.any(condition, on_true, on_false)
+		if condition then
+			return on_true
+		else
+			return on_false
+		end
+	end
+
+	module.select = select
+end
+
+do
+	local exception = {}
+
+	local table_unpack = unpack
+
+	-- Traps are raised as strings, so a dedicated metatable keeps
+	-- exceptions from ever being mistaken for one
+	local EXCEPTION_META = {
+		__tostring = function()
+			return "uncaught exception"
+		end,
+	}
+
+	function exception.new(tag, ...)
+		return setmetatable({ tag = tag, n = select("#", ...), ... }, EXCEPTION_META)
+	end
+
+	function exception.throw(tag, ...)
+		error(exception.new(tag, ...), 0)
+	end
+
+	function exception.is(data)
+		return getmetatable(data) == EXCEPTION_META
+	end
+
+	function exception.matches(data, tag)
+		return exception.is(data) and data.tag == tag
+	end
+
+	function exception.unpack(data)
+		return table_unpack(data, 1, data.n)
+	end
+
+	-- `delegate` wraps exceptions with the level of the `try` that should
+	-- handle them, so the handlers nested inside of it pass them on
+	local DELEGATE_META = {}
+
+	function exception.delegate(data, level)
+		if not exception.is(data) then
+			return data
+		end
+
+		return setmetatable({ data = data, level = level }, DELEGATE_META)
+	end
+
+	function exception.resolve(data, level)
+		if getmetatable(data) == DELEGATE_META and data.level >= level then
+			return data.data
+		end
+
+		return data
+	end
+
+	module.exception = exception
+end
+
+do
+	local v128 = {}
+	local splat = {}
+	local extract_lane = {}
+	local replace_lane = {}
+	local shuffle = {}
+	local swizzle = {}
+	local bitselect = {}
+	local band = {}
+	local bor = {}
+	local bxor = {}
+	local bnot = {}
+	local bandnot = {}
+	local any_true = {}
+	local all_true = {}
+	local bitmask = {}
+	local eq = {}
+	local ne = {}
+	local shl = {}
+	local shr = {}
+	local abs = {}
+	local ceil = {}
+	local floor = {}
+	local sqrt = {}
+	local pmin = {}
+	local pmax = {}
+	local add_sat = {}
+	local sub_sat = {}
+	local avgr = {}
+	local q15mulr_sat = {}
+	local dot = {}
+	local narrow = {}
+	local extend_low = {}
+	local extend_high = {}
+	local extadd_pairwise = {}
+	local extmul_low = {}
+	local extmul_high = {}
+	local saturate_zero = {}
+	local convert_low = {}
+	local demote_zero = {}
+	local promote_low = {}
+
+	local add = module.add
+	local sub = module.sub
+	local mul = module.mul
+	local div = module.div
+	local neg = module.neg
+	local min = module.min
+	local max = module.max
+	local nearest = module.nearest
+	local popcnt = module.popcnt
+	local le = module.le
+	local lt = module.lt
+	local ge = module.ge
+	local gt = module.gt
+	local truncate = module.truncate
+	local saturate = module.saturate
+	local convert = module.convert
+	local load = module.load
+	local store = module.store
+
+	local bit_and = bit.band
+	local bit_or = bit.bor
+	local bit_xor = bit.bxor
+	local bit_not = bit.bnot
+	local bit_lshift = bit.lshift
+	local bit_rshift = bit.rshift
+	local bit_arshift = bit.arshift
+
+	local math_abs = math.abs
+	local math_sqrt = math.sqrt
+	local math_min = math.min
+	local math_max = math.max
+
+	ffi.cdef([[
+	union V128 {
+		int8_t i8[16];
+		int16_t i16[8];
+		int32_t i32[4];
+		int64_t i64[2];
+
+		uint8_t u8[16];
+		uint16_t u16[8];
+		uint32_t u32[4];
+		uint64_t u64[2];
+
+		float f32[4];
+		double f64[2];
+	};
+	]])
+
+	local v128_t = ffi.typeof("union V128")
+	local alias_t = ffi.typeof("uint8_t *")
+	local cast = ffi.cast
+
+	local function clamp(num, lower, upper)
+		return math_min(math_max(num, lower), upper)
+	end
+
+	-- Vectors are treated as immutable, so every operation creates a new one.
+	-- Integer lanes are written with wrapping so signed and unsigned readers
+	-- can share the same writer.
+	local LANE_I8 = {
+		count = 16,
+		read = function(vector, index)
+			return vector.i8[index]
+		end,
+		write = function(vector, index, value)
+			vector.u8[index] = bit_and(value, 0xFF)
+		end,
+	}
+
+	local LANE_U8 = {
+		count = 16,
+		read = function(vector, index)
+			return vector.u8[index]
+		end,
+		write = LANE_I8.write,
+	}
+
+	local LANE_I16 = {
+		count = 8,
+		read = function(vector, index)
+			return vector.i16[index]
+		end,
+		write = function(vector, index, value)
+			vector.u16[index] = bit_and(value, 0xFFFF)
+		end,
+	}
+
+	local LANE_U16 = {
+		count = 8,
+		read = function(vector, index)
+			return vector.u16[index]
+		end,
+		write = LANE_I16.write,
+	}
+
+	local LANE_I32 = {
+		count = 4,
+		read = function(vector, index)
+			return vector.i32[index]
+		end,
+		write = function(vector, index, value)
+			vector.i32[index] = to_signed(value)
+		end,
+	}
+
+	local LANE_U32 = {
+		count = 4,
+		read = function(vector, index)
+			return vector.u32[index]
+		end,
+		write = LANE_I32.write,
+	}
+
+	local LANE_I64 = {
+		count = 2,
+		read = function(vector, index)
+			return vector.i64[index]
+		end,
+		write = function(vector, index, value)
+			vector.i64[index] = value
+		end,
+	}
+
+	-- LuaJIT mistakes negative NaNs with a payload for other values, so
+	-- those lanes are read as the default negative NaN instead
+	local NAN_NEGATIVE = -math_abs(0 / 0)
+
+	local LANE_F32 = {
+		count = 4,
+		read = function(vector, index)
+			local bits = vector.i32[index]
+
+			if bits < 0 and bits > -0x800000 then
+				return NAN_NEGATIVE
+			end
+
+			return vector.f32[index]
+		end,
+		write = function(vector, index, value)
+			vector.f32[index] = value
+		end,
+	}
+
+	local LANE_F64 = {
+		count = 2,
+		read = function(vector, index)
+			local bits = vector.i64[index]
+
+			if bits < 0 and bits > -0x10000000000000LL then
+				return NAN_NEGATIVE
+			end
+
+			return vector.f64[index]
+		end,
+		write = function(vector, index, value)
+			vector.f64[index] = value
+		end,
+	}
+
+	local function map_unary(lane, func)
+		local count, read, write = lane.count, lane.read, lane.write
+
+		return function(rhs)
+			local result = v128_t()
+
+			for i = 0, count - 1 do
+				write(result, i, func(read(rhs, i)))
+			end
+
+			return result
+		end
+	end
+
+	local function map_binary(lane, func)
+		local count, read, write = lane.count, lane.read, lane.write
+
+		return function(lhs, rhs)
+			local result = v128_t()
+
+			for i = 0, count - 1 do
+				write(result, i, func(read(lhs, i), read(rhs, i)))
+			end
+
+			return result
+		end
+	end
+
+	local function map_compare(lane, func)
+		local count, read = lane.count, lane.read
+		local size = 16 / count
+
+		return function(lhs, rhs)
+			local result = v128_t()
+
+			for i = 0, count - 1 do
+				if func(read(lhs, i), read(rhs, i)) then
+					ffi.fill(cast(alias_t, result) + i * size, size, 0xFF)
+				end
+			end
+
+			return result
+		end
+	end
+
+	local function map_shift(lane, bits, func)
+		local count, read, write = lane.count, lane.read, lane.write
+
+		return function(lhs, rhs)
+			local result = v128_t()
+
+			rhs = rhs % bits
+
+			for i = 0, count - 1 do
+				write(result, i, func(read(lhs, i), rhs))
+			end
+
+			return result
+		end
+	end
+
+	-- Converts `count` lanes starting at lane `start`, leaving the rest zeroed
+	local function map_convert(lane_in, lane_out, start, count, func)
+		local read, write = lane_in.read, lane_out.write
+
+		return function(rhs)
+			local result = v128_t()
+
+			for i = 0, count - 1 do
+				write(result, i, func(read(rhs, start + i)))
+			end
+
+			return result
+		end
+	end
+
+	local function map_narrow(lane_in, lane_out, lower, upper)
+		local read, write = lane_in.read, lane_out.write
+		local count = lane_in.count
+
+		return function(lhs, rhs)
+			local result = v128_t()
+
+			for i = 0, count - 1 do
+				write(result, i, clamp(read(lhs, i), lower, upper))
+				write(result, i + count, clamp(read(rhs, i), lower, upper))
+			end
+
+			return result
+		end
+	end
+
+	local function map_pairwise(lane_in, lane_out)
+		local read, write = lane_in.read, lane_out.write
+
+		return function(rhs)
+			local result = v128_t()
+
+			for i = 0, lane_out.count - 1 do
+				write(result, i, read(rhs, i * 2) + read(rhs, i * 2 + 1))
+			end
+
+			return result
+		end
+	end
+
+	local function map_float_sign(size, func)
+		return function(rhs)
+			local result = v128_t(rhs)
+
+			for i = size - 1, 15, size do
+				result.u8[i] = func(rhs.u8[i])
+			end
+
+			return result
+		end
+	end
+
+	local function new_splat(lane)
+		local count, write = lane.count, lane.write
+
+		return function(value)
+			local result = v128_t()
+
+			for i = 0, count - 1 do
+				write(result, i, value)
+			end
+
+			return result
+		end
+	end
+
+	local function new_replace_lane(lane)
+		local write = lane.write
+
+		return function(vector, index, value)
+			local result = v128_t(vector)
+
+			write(result, index, value)
+
+			return result
+		end
+	end
+
+	local function new_all_true(lane)
+		local count, read = lane.count, lane.read
+
+		return function(rhs)
+			for i = 0, count - 1 do
+				if read(rhs, i) == 0 then
+					return 0
+				end
+			end
+
+			return 1
+		end
+	end
+
+	local function new_bitmask(lane)
+		local count, read = lane.count, lane.read
+
+		return function(rhs)
+			local result = 0
+
+			for i = 0, count - 1 do
+				if read(rhs, i) < 0 then
+					result = bit_or(result, bit_lshift(1, i))
+				end
+			end
+
+			return result
+		end
+	end
+
+	local function add_number(lhs, rhs)
+		return lhs + rhs
+	end
+
+	local function sub_number(lhs, rhs)
+		return lhs - rhs
+	end
+
+	local function mul_number(lhs, rhs)
+		return lhs * rhs
+	end
+
+	local function div_number(lhs, rhs)
+		return lhs / rhs
+	end
+
+	local function neg_number(rhs)
+		return -rhs
+	end
+
+	local function abs_number(rhs)
+		if rhs < 0 then
+			return -rhs
+		else
+			return rhs
+		end
+	end
+
+	local function is_equal(lhs, rhs)
+		return lhs == rhs
+	end
+
+	local function is_not_equal(lhs, rhs)
+		return lhs ~= rhs
+	end
+
+	local function is_less(lhs, rhs)
+		return lhs < rhs
+	end
+
+	local function is_less_equal(lhs, rhs)
+		return lhs <= rhs
+	end
+
+	local function is_greater(lhs, rhs)
+		return lhs > rhs
+	end
+
+	local function is_greater_equal(lhs, rhs)
+		return lhs >= rhs
+	end
+
+	local function no_op(num)
+		return num
+	end
+
+	-- Follows the WebAssembly rules for NaN propagation and signed zeroes
+	local function min_float(lhs, rhs)
+		if lhs ~= lhs or rhs ~= rhs then
+			return lhs + rhs
+		elseif lhs == rhs then
+			if 1 / lhs < 0 then
+				return lhs
+			else
+				return rhs
+			end
+		else
+			return (math_min(lhs, rhs))
+		end
+	end
+
+	local function max_float(lhs, rhs)
+		if lhs ~= lhs or rhs ~= rhs then
+			return lhs + rhs
+		elseif lhs == rhs then
+			if 1 / lhs > 0 then
+				return lhs
+			else
+				return rhs
+			end
+		else
+			return (math_max(lhs, rhs))
+		end
+	end
+
+	local function saturate_i32(num)
+		if num ~= num then
+			return 0
+		end
+
+		return saturate.i32_f64(num)
+	end
+
+	local function saturate_u32(num)
+		if num ~= num then
+			return 0
+		end
+
+		return saturate.u32_f64(num)
+	end
+
+	function v128.from_u32(data_1, data_2, data_3, data_4)
+		local result = v128_t()
+
+		result.i32[0] = to_signed(data_1)
+		result.i32[1] = to_signed(data_2)
+		result.i32[2] = to_signed(data_3)
+		result.i32[3] = to_signed(data_4)
+
+		return result
+	end
+
+	v128.ZERO = v128_t()
+
+	splat.i8x16 = new_splat(LANE_I8)
+	splat.i16x8 = new_splat(LANE_I16)
+	splat.i32x4 = new_splat(LANE_I32)
+	splat.i64x2 = new_splat(LANE_I64)
+	splat.f32x4 = new_splat(LANE_F32)
+	splat.f64x2 = new_splat(LANE_F64)
+
+	extract_lane.i8x16 = LANE_I8.read
+	extract_lane.u8x16 = LANE_U8.read
+	extract_lane.i16x8 = LANE_I16.read
+	extract_lane.u16x8 = LANE_U16.read
+	extract_lane.i32x4 = LANE_I32.read
+	extract_lane.i64x2 = LANE_I64.read
+	extract_lane.f32x4 = LANE_F32.read
+	extract_lane.f64x2 = LANE_F64.read
+
+	replace_lane.i8x16 = new_replace_lane(LANE_I8)
+	replace_lane.i16x8 = new_replace_lane(LANE_I16)
+	replace_lane.i32x4 = new_replace_lane(LANE_I32)
+	replace_lane.i64x2 = new_replace_lane(LANE_I64)
+	replace_lane.f32x4 = new_replace_lane(LANE_F32)
+	replace_lane.f64x2 = new_replace_lane(LANE_F64)
+
+	function shuffle.i8x16(lhs, rhs, ...)
+		local result = v128_t()
+
+		for i = 0, 15 do
+			local index = select(i + 1, ...)
+
+			if index < 16 then
+				result.u8[i] = lhs.u8[index]
+			else
+				result.u8[i] = rhs.u8[index - 16]
+			end
+		end
+
+		return result
+	end
+
+	function swizzle.i8x16(lhs, rhs)
+		local result = v128_t()
+
+		for i = 0, 15 do
+			local index = rhs.u8[i]
+
+			if index < 16 then
+				result.u8[i] = lhs.u8[index]
+			end
+		end
+
+		return result
+	end
+
+	function bitselect.v128(on_true, on_false, condition)
+		local result = v128_t()
+
+		for i = 0, 3 do
+			local mask = condition.i32[i]
+			local value_1 = bit_and(on_true.i32[i], mask)
+			local value_2 = bit_and(on_false.i32[i], bit_not(mask))
+
+			result.i32[i] = bit_or(value_1, value_2)
+		end
+
+		return result
+	end
+
+	function any_true.v128(rhs)
+		for i = 0, 3 do
+			if rhs.i32[i] ~= 0 then
+				return 1
+			end
+		end
+
+		return 0
+	end
+
+	band.v128 = map_binary(LANE_I32, bit_and)
+	bor.v128 = map_binary(LANE_I32, bit_or)
+	bxor.v128 = map_binary(LANE_I32, bit_xor)
+	bnot.v128 = map_unary(LANE_I32, bit_not)
+
+	bandnot.v128 = map_binary(LANE_I32, function(lhs, rhs)
+		return bit_and(lhs, bit_not(rhs))
+	end)
+
+	all_true.i8x16 = new_all_true(LANE_I8)
+	all_true.i16x8 = new_all_true(LANE_I16)
+	all_true.i32x4 = new_all_true(LANE_I32)
+	all_true.i64x2 = new_all_true(LANE_I64)
+
+	bitmask.i8x16 = new_bitmask(LANE_I8)
+	bitmask.i16x8 = new_bitmask(LANE_I16)
+	bitmask.i32x4 = new_bitmask(LANE_I32)
+	bitmask.i64x2 = new_bitmask(LANE_I64)
+
+	popcnt.i8x16 = map_unary(LANE_U8, popcnt.i32)
+
+	abs.i8x16 = map_unary(LANE_I8, abs_number)
+	abs.i16x8 = map_unary(LANE_I16, abs_number)
+	abs.i32x4 = map_unary(LANE_I32, abs_number)
+	abs.i64x2 = map_unary(LANE_I64, abs_number)
+
+	neg.i8x16 = map_unary(LANE_I8, neg_number)
+	neg.i16x8 = map_unary(LANE_I16, neg_number)
+	neg.i32x4 = map_unary(LANE_I32, neg_number)
+	neg.i64x2 = map_unary(LANE_I64, neg_number)
+
+	-- Float signs are flipped directly so NaN payloads are kept intact
+	abs.f32x4 = map_float_sign(4, function(num)
+		return bit_and(num, 0x7F)
+	end)
+
+	neg.f32x4 = map_float_sign(4, function(num)
+		return bit_xor(num, 0x80)
+	end)
+
+	abs.f64x2 = map_float_sign(8, function(num)
+		return bit_and(num, 0x7F)
+	end)
+
+	neg.f64x2 = map_float_sign(8, function(num)
+		return bit_xor(num, 0x80)
+	end)
+
+	add.i8x16 = map_binary(LANE_I8, add_number)
+	add.i16x8 = map_binary(LANE_I16, add_number)
+	add.i32x4 = map_binary(LANE_I32, add_number)
+	add.i64x2 = map_binary(LANE_I64, add_number)
+
+	sub.i8x16 = map_binary(LANE_I8, sub_number)
+	sub.i16x8 = map_binary(LANE_I16, sub_number)
+	sub.i32x4 = map_binary(LANE_I32, sub_number)
+	sub.i64x2 = map_binary(LANE_I64, sub_number)
+
+	mul.i16x8 = map_binary(LANE_I16, mul_number)
+	mul.i32x4 = map_binary(LANE_I32, mul.i32)
+	mul.i64x2 = map_binary(LANE_I64, mul_number)
+
+	add_sat.i8x16 = map_binary(LANE_I8, function(lhs, rhs)
+		return clamp(lhs + rhs, -0x80, 0x7F)
+	end)
+
+	add_sat.u8x16 = map_binary(LANE_U8, function(lhs, rhs)
+		return math_min(lhs + rhs, 0xFF)
+	end)
+
+	add_sat.i16x8 = map_binary(LANE_I16, function(lhs, rhs)
+		return clamp(lhs + rhs, -0x8000, 0x7FFF)
+	end)
+
+	add_sat.u16x8 = map_binary(LANE_U16, function(lhs, rhs)
+		return math_min(lhs + rhs, 0xFFFF)
+	end)
+
+	sub_sat.i8x16 = map_binary(LANE_I8, function(lhs, rhs)
+		return clamp(lhs - rhs, -0x80, 0x7F)
+	end)
+
+	sub_sat.u8x16 = map_binary(LANE_U8, function(lhs, rhs)
+		return math_max(lhs - rhs, 0)
+	end)
+
+	sub_sat.i16x8 = map_binary(LANE_I16, function(lhs, rhs)
+		return clamp(lhs - rhs, -0x8000, 0x7FFF)
+	end)
+
+	sub_sat.u16x8 = map_binary(LANE_U16, function(lhs, rhs)
+		return math_max(lhs - rhs, 0)
+	end)
+
+	min.i8x16 = map_binary(LANE_I8, math_min)
+	min.u8x16 = map_binary(LANE_U8, math_min)
+	min.i16x8 = map_binary(LANE_I16, math_min)
+	min.u16x8 = map_binary(LANE_U16, math_min)
+	min.i32x4 = map_binary(LANE_I32, math_min)
+	min.u32x4 = map_binary(LANE_U32, math_min)
+
+	max.i8x16 = map_binary(LANE_I8, math_max)
+	max.u8x16 = map_binary(LANE_U8, math_max)
+	max.i16x8 = map_binary(LANE_I16, math_max)
+	max.u16x8 = map_binary(LANE_U16, math_max)
+	max.i32x4 = map_binary(LANE_I32, math_max)
+	max.u32x4 = map_binary(LANE_U32, math_max)
+
+	local function rounding_average(lhs, rhs)
+		return bit_rshift(lhs + rhs + 1, 1)
+	end
+
+	avgr.u8x16 = map_binary(LANE_U8, rounding_average)
+	avgr.u16x8 = map_binary(LANE_U16, rounding_average)
+
+	q15mulr_sat.i16x8 = map_binary(LANE_I16, function(lhs, rhs)
+		return clamp(bit_arshift(lhs * rhs + 0x4000, 15), -0x8000, 0x7FFF)
+	end)
+
+	function dot.i32x4_i16x8(lhs, rhs)
+		local result = v128_t()
+
+		for i = 0, 3 do
+			local value_1 = lhs.i16[i * 2] * rhs.i16[i * 2]
+			local value_2 = lhs.i16[i * 2 + 1] * rhs.i16[i * 2 + 1]
+
+			result.i32[i] = to_signed(value_1 + value_2)
+		end
+
+		return result
+	end
+
+	shl.i8x16 = map_shift(LANE_I8, 8, bit_lshift)
+	shl.i16x8 = map_shift(LANE_I16, 16, bit_lshift)
+	shl.i32x4 = map_shift(LANE_I32, 32, bit_lshift)
+	shl.i64x2 = map_shift(LANE_I64, 64, bit_lshift)
+
+	shr.i8x16 = map_shift(LANE_I8, 8, bit_arshift)
+	shr.u8x16 = map_shift(LANE_U8, 8, bit_rshift)
+	shr.i16x8 = map_shift(LANE_I16, 16, bit_arshift)
+	shr.u16x8 = map_shift(LANE_U16, 16, bit_rshift)
+	shr.i32x4 = map_shift(LANE_I32, 32, bit_arshift)
+	shr.u32x4 = map_shift(LANE_I32, 32, bit_rshift)
+	shr.i64x2 = map_shift(LANE_I64, 64, bit_arshift)
+	shr.u64x2 = map_shift(LANE_I64, 64, bit_rshift)
+
+	eq.i8x16 = map_compare(LANE_I8, is_equal)
+	eq.i16x8 = map_compare(LANE_I16, is_equal)
+	eq.i32x4 = map_compare(LANE_I32, is_equal)
+	eq.i64x2 = map_compare(LANE_I64, is_equal)
+	eq.f32x4 = map_compare(LANE_F32, is_equal)
+	eq.f64x2 = map_compare(LANE_F64, is_equal)
+
+	ne.i8x16 = map_compare(LANE_I8, is_not_equal)
+	ne.i16x8 = map_compare(LANE_I16, is_not_equal)
+	ne.i32x4 = map_compare(LANE_I32, is_not_equal)
+	ne.i64x2 = map_compare(LANE_I64, is_not_equal)
+	ne.f32x4 = map_compare(LANE_F32, is_not_equal)
+	ne.f64x2 = map_compare(LANE_F64, is_not_equal)
+
+	lt.i8x16 = map_compare(LANE_I8, is_less)
+	lt.u8x16 = map_compare(LANE_U8, is_less)
+	lt.i16x8 = map_compare(LANE_I16, is_less)
+	lt.u16x8 = map_compare(LANE_U16, is_less)
+	lt.i32x4 = map_compare(LANE_I32, is_less)
+	lt.u32x4 = map_compare(LANE_U32, is_less)
+	lt.i64x2 = map_compare(LANE_I64, is_less)
+	lt.f32x4 = map_compare(LANE_F32, is_less)
+	lt.f64x2 = map_compare(LANE_F64, is_less)
+
+	le.i8x16 = map_compare(LANE_I8, is_less_equal)
+	le.u8x16 = map_compare(LANE_U8, is_less_equal)
+	le.i16x8 = map_compare(LANE_I16, is_less_equal)
+	le.u16x8 = map_compare(LANE_U16, is_less_equal)
+	le.i32x4 = map_compare(LANE_I32, is_less_equal)
+	le.u32x4 = map_compare(LANE_U32, is_less_equal)
+	le.i64x2 = map_compare(LANE_I64, is_less_equal)
+	le.f32x4 = map_compare(LANE_F32, is_less_equal)
+	le.f64x2 = map_compare(LANE_F64, is_less_equal)
+
+	gt.i8x16 = map_compare(LANE_I8, is_greater)
+	gt.u8x16 = map_compare(LANE_U8, is_greater)
+	gt.i16x8 = map_compare(LANE_I16, is_greater)
+	gt.u16x8 = map_compare(LANE_U16, is_greater)
+	gt.i32x4 = map_compare(LANE_I32, is_greater)
+	gt.u32x4 = map_compare(LANE_U32, is_greater)
+	gt.i64x2 = map_compare(LANE_I64, is_greater)
+	gt.f32x4 = map_compare(LANE_F32, is_greater)
+	gt.f64x2 = map_compare(LANE_F64, is_greater)
+
+	ge.i8x16 = map_compare(LANE_I8, is_greater_equal)
+	ge.u8x16 = map_compare(LANE_U8, is_greater_equal)
+	ge.i16x8 = map_compare(LANE_I16, is_greater_equal)
+	ge.u16x8 = map_compare(LANE_U16, is_greater_equal)
+	ge.i32x4 = map_compare(LANE_I32, is_greater_equal)
+	ge.u32x4 = map_compare(LANE_U32, is_greater_equal)
+	ge.i64x2 = map_compare(LANE_I64, is_greater_equal)
+	ge.f32x4 = map_compare(LANE_F32, is_greater_equal)
+	ge.f64x2 = map_compare(LANE_F64, is_greater_equal)
+
+	narrow.i8x16_i16x8 = map_narrow(LANE_I16, LANE_I8, -0x80, 0x7F)
+	narrow.u8x16_i16x8 = map_narrow(LANE_I16, LANE_U8, 0, 0xFF)
+	narrow.i16x8_i32x4 = map_narrow(LANE_I32, LANE_I16, -0x8000, 0x7FFF)
+	narrow.u16x8_i32x4 = map_narrow(LANE_I32, LANE_U16, 0, 0xFFFF)
+
+	extend_low.i16x8_i8x16 = map_convert(LANE_I8, LANE_I16, 0, 8, no_op)
+	extend_low.i16x8_u8x16 = map_convert(LANE_U8, LANE_U16, 0, 8, no_op)
+	extend_low.i32x4_i16x8 = map_convert(LANE_I16, LANE_I32, 0, 4, no_op)
+	extend_low.i32x4_u16x8 = map_convert(LANE_U16, LANE_U32, 0, 4, no_op)
+	extend_low.i64x2_i32x4 = map_convert(LANE_I32, LANE_I64, 0, 2, no_op)
+	extend_low.i64x2_u32x4 = map_convert(LANE_U32, LANE_I64, 0, 2, no_op)
+
+	extend_high.i16x8_i8x16 = map_convert(LANE_I8, LANE_I16, 8, 8, no_op)
+	extend_high.i16x8_u8x16 = map_convert(LANE_U8, LANE_U16, 8, 8, no_op)
+	extend_high.i32x4_i16x8 = map_convert(LANE_I16, LANE_I32, 4, 4, no_op)
+	extend_high.i32x4_u16x8 = map_convert(LANE_U16, LANE_U32, 4, 4, no_op)
+	extend_high.i64x2_i32x4 = map_convert(LANE_I32, LANE_I64, 2, 2, no_op)
+	extend_high.i64x2_u32x4 = map_convert(LANE_U32, LANE_I64, 2, 2, no_op)
+
+	extadd_pairwise.i16x8_i8x16 = map_pairwise(LANE_I8, LANE_I16)
+	extadd_pairwise.i16x8_u8x16 = map_pairwise(LANE_U8, LANE_U16)
+	extadd_pairwise.i32x4_i16x8 = map_pairwise(LANE_I16, LANE_I32)
+	extadd_pairwise.i32x4_u16x8 = map_pairwise(LANE_U16, LANE_U32)
+
+	local function new_extend_multiply(extend, multiply)
+		return function(lhs, rhs)
+			return multiply(extend(lhs), extend(rhs))
+		end
+	end
+
+	for name, extend in pairs(extend_low) do
+		local multiply = mul[string.sub(name, 1, 5)]
+
+		extmul_low[name] = new_extend_multiply(extend, multiply)
+		extmul_high[name] = new_extend_multiply(extend_high[name], multiply)
+	end
+
+	ceil.f32x4 = map_unary(LANE_F32, math_ceil)
+	ceil.f64x2 = map_unary(LANE_F64, math_ceil)
+	floor.f32x4 = map_unary(LANE_F32, math_floor)
+	floor.f64x2 = map_unary(LANE_F64, math_floor)
+	truncate.f32x4 = map_unary(LANE_F32, truncate_f64)
+	truncate.f64x2 = map_unary(LANE_F64, truncate_f64)
+	nearest.f32x4 = map_unary(LANE_F32, nearest.f32)
+	nearest.f64x2 = map_unary(LANE_F64, nearest.f64)
+	sqrt.f32x4 = map_unary(LANE_F32, math_sqrt)
+	sqrt.f64x2 = map_unary(LANE_F64, math_sqrt)
+
+	add.f32x4 = map_binary(LANE_F32, add_number)
+	add.f64x2 = map_binary(LANE_F64, add_number)
+	sub.f32x4 = map_binary(LANE_F32, sub_number)
+	sub.f64x2 = map_binary(LANE_F64, sub_number)
+	mul.f32x4 = map_binary(LANE_F32, mul_number)
+	mul.f64x2 = map_binary(LANE_F64, mul_number)
+	div.f32x4 = map_binary(LANE_F32, div_number)
+	div.f64x2 = map_binary(LANE_F64, div_number)
+	min.f32x4 = map_binary(LANE_F32, min_float)
+	min.f64x2 = map_binary(LANE_F64, min_float)
+	max.f32x4 = map_binary(LANE_F32, max_float)
+	max.f64x2 = map_binary(LANE_F64, max_float)
+
+	local function pseudo_min(lhs, rhs)
+		if rhs < lhs then
+			return rhs
+		else
+			return lhs
+		end
+	end
+
+	local function pseudo_max(lhs, rhs)
+		if lhs < rhs then
+			return rhs
+		else
+			return lhs
+		end
+	end
+
+	pmin.f32x4 = map_binary(LANE_F32, pseudo_min)
+	pmin.f64x2 = map_binary(LANE_F64, pseudo_min)
+	pmax.f32x4 = map_binary(LANE_F32, pseudo_max)
+	pmax.f64x2 = map_binary(LANE_F64, pseudo_max)
+
+	saturate.i32x4_f32x4 = map_convert(LANE_F32, LANE_I32, 0, 4, saturate_i32)
+	saturate.u32x4_f32x4 = map_convert(LANE_F32, LANE_U32, 0, 4, saturate_u32)
+	saturate_zero.i32x4_f64x2 = map_convert(LANE_F64, LANE_I32, 0, 2, saturate_i32)
+	saturate_zero.u32x4_f64x2 = map_convert(LANE_F64, LANE_U32, 0, 2, saturate_u32)
+	convert.f32x4_i32x4 = map_convert(LANE_I32, LANE_F32, 0, 4, no_op)
+	convert.f32x4_u32x4 = map_convert(LANE_U32, LANE_F32, 0, 4, no_op)
+	convert_low.f64x2_i32x4 = map_convert(LANE_I32, LANE_F64, 0, 2, no_op)
+	convert_low.f64x2_u32x4 = map_convert(LANE_U32, LANE_F64, 0, 2, no_op)
+	demote_zero.f32x4_f64x2 = map_convert(LANE_F64, LANE_F32, 0, 2, no_op)
+	promote_low.f64x2_f32x4 = map_convert(LANE_F32, LANE_F64, 0, 2, no_op)
+
+	function load.v128(memory, addr)
+		local result = v128_t()
+
+		ffi.copy(result, cast(alias_t, memory.data) + addr, 16)
+
+		return result
+	end
+
+	local function new_load_extend(extend)
+		return function(memory, addr)
+			local temp = v128_t()
+
+			ffi.copy(temp, cast(alias_t, memory.data) + addr, 8)
+
+			return extend(temp)
+		end
+	end
+
+	load.v128_i8x8 = new_load_extend(extend_low.i16x8_i8x16)
+	load.v128_u8x8 = new_load_extend(extend_low.i16x8_u8x16)
+	load.v128_i16x4 = new_load_extend(extend_low.i32x4_i16x8)
+	load.v128_u16x4 = new_load_extend(extend_low.i32x4_u16x8)
+	load.v128_i32x2 = new_load_extend(extend_low.i64x2_i32x4)
+	load.v128_u32x2 = new_load_extend(extend_low.i64x2_u32x4)
+
+	function store.v128(memory, addr, value)
+		ffi.copy(cast(alias_t, memory.data) + addr, value, 16)
+	end
+
+	module.v128 = v128
+	module.splat = splat
+	module.extract_lane = extract_lane
+	module.replace_lane = replace_lane
+	module.shuffle = shuffle
+	module.swizzle = swizzle
+	module.bitselect = bitselect
+	module.band = band
+	module.bor = bor
+	module.bxor = bxor
+	module.bnot = bnot
+	module.bandnot = bandnot
+	module.any_true = any_true
+	module.all_true = all_true
+	module.bitmask = bitmask
+	module.eq = eq
+	module.ne = ne
+	module.shl = shl
+	module.shr = shr
+	module.abs = abs
+	module.ceil = ceil
+	module.floor = floor
+	module.sqrt = sqrt
+	module.pmin = pmin
+	module.pmax = pmax
+	module.add_sat = add_sat
+	module.sub_sat = sub_sat
+	module.avgr = avgr
+	module.q15mulr_sat = q15mulr_sat
+	module.dot = dot
+	module.narrow = narrow
+	module.extend_low = extend_low
+	module.extend_high = extend_high
+	module.extadd_pairwise = extadd_pairwise
+	module.extmul_low = extmul_low
+	module.extmul_high = extmul_high
+	module.saturate_zero = saturate_zero
+	module.convert_low = convert_low
+	module.demote_zero = demote_zero
+	module.promote_low = promote_low
+end
+
 return module
@@ -1,3 +1,4 @@
 pub mod br_table;
 pub mod into_string;
 pub mod localize;
+pub mod spill;
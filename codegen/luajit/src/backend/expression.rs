@@ -4,10 +4,14 @@ use std::{
 };
 
 use wasm_ast::node::{
-	BinOp, CmpOp, Expression, GetGlobal, LoadAt, Local, MemorySize, Select, Temporary, UnOp, Value,
+	BinOp, BitSelect, CmpOp, Expression, ExtractLane, GetGlobal, IndexType, LoadAt, Local,
+	MemorySize, RefIsNull, ReplaceLane, Select, Shuffle, Temporary, UnOp, Value,
 };
 
-use crate::analyzer::into_string::{IntoName, IntoNameTuple, TryIntoSymbol};
+use crate::analyzer::{
+	into_string::{IntoName, IntoNameTuple, TryIntoSymbol},
+	localize::is_never_nil,
+};
 
 use super::manager::{write_separated, Driver, Manager};
 
@@ -27,6 +31,16 @@ macro_rules! impl_write_number {
 
 impl Driver for Select {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		if !is_never_nil(self.on_true()) {
+			write!(w, "select_any(")?;
+			Condition(self.condition()).write(mng, w)?;
+			write!(w, ", ")?;
+			self.on_true().write(mng, w)?;
+			write!(w, ", ")?;
+			self.on_false().write(mng, w)?;
+			return write!(w, ")");
+		}
+
 		write!(w, "(")?;
 		Condition(self.condition()).write(mng, w)?;
 		write!(w, " and ")?;
@@ -41,8 +55,8 @@ impl Driver for Temporary {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		let var = self.var();
 
-		if let Some(var) = var.checked_sub(mng.num_temp()) {
-			write!(w, "reg_spill[{}]", var + 1)
+		if let Some(index) = mng.spill().temporary(var) {
+			write!(w, "reg_spill[{index}]")
 		} else {
 			write!(w, "reg_{var}")
 		}
@@ -53,17 +67,18 @@ impl Driver for Local {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		let var = self.var();
 
-		if let Some(var) = var.checked_sub(mng.num_local()) {
-			write!(w, "loc_spill[{}]", var + 1)
+		if let Some(index) = mng.spill().local(var) {
+			write!(w, "loc_spill[{index}]")
 		} else {
-			write!(w, "loc_{var}")
+			mng.write_local(var, w)
 		}
 	}
 }
 
 impl Driver for GetGlobal {
-	fn write(&self, _mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
-		write!(w, "GLOBAL_LIST[{}].value", self.var())
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		mng.name_list().write_global(self.var(), w)?;
+		write!(w, ".value")
 	}
 }
 
@@ -72,8 +87,12 @@ impl Driver for LoadAt {
 		let name = self.load_type().into_name();
 		let memory = self.memory();
 
+		if self.is_atomic() {
+			write!(w, "atomic_")?;
+		}
+
 		write!(w, "load_{name}(memory_at_{memory}, ")?;
-		self.pointer().write(mng, w)?;
+		Address(self.index_type(), self.pointer()).write(mng, w)?;
 
 		if self.offset() != 0 {
 			write!(w, " + {}", self.offset())?;
@@ -85,20 +104,49 @@ impl Driver for LoadAt {
 
 impl Driver for MemorySize {
 	fn write(&self, _mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
-		write!(w, "memory_at_{}.min", self.memory())
+		let memory = self.memory();
+
+		match self.index_type() {
+			IndexType::I32 => write!(w, "memory_at_{memory}.min"),
+			IndexType::I64 => {
+				write!(w, "extend_i64_u32(")?;
+				write!(w, "memory_at_{memory}.min")?;
+				write!(w, ")")
+			}
+		}
 	}
 }
 
 impl_write_number!(write_f32, f32);
 impl_write_number!(write_f64, f64);
 
+fn write_v128(number: u128, w: &mut dyn Write) -> Result<()> {
+	if number == 0 {
+		return write!(w, "v128_ZERO");
+	}
+
+	let list = number.to_le_bytes();
+	let data = list
+		.chunks_exact(4)
+		.map(|v| u32::from_le_bytes(v.try_into().unwrap()));
+
+	write!(w, "v128_from_u32(")?;
+	write_separated(data, |v, w| write!(w, "{v}"), w)?;
+	write!(w, ")")
+}
+
 impl Driver for Value {
-	fn write(&self, _mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		match self {
 			Self::I32(i) => write!(w, "{i}"),
 			Self::I64(i) => write!(w, "{i}LL"),
 			Self::F32(f) => write_f32(*f, w),
 			Self::F64(f) => write_f64(*f, w),
+			// Null references of every type are `nil` so that host values
+			// can be passed through as references without being wrapped
+			Self::RefNull(_) => write!(w, "nil"),
+			Self::RefFunc(i) => mng.name_list().write_function(*i, w),
+			Self::V128(v) => write_v128(*v, w),
 		}
 	}
 }
@@ -162,15 +210,94 @@ impl Driver for CmpOp {
 	}
 }
 
+impl Driver for RefIsNull {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		write!(w, "(")?;
+		self.value().write(mng, w)?;
+		write!(w, " == nil and 1 or 0)")
+	}
+}
+
+impl Driver for ExtractLane {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let name = self.lane_type().into_name();
+
+		write!(w, "extract_lane_{name}(")?;
+		self.vector().write(mng, w)?;
+		write!(w, ", {})", self.lane())
+	}
+}
+
+impl Driver for ReplaceLane {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let name = self.lane_type().into_name();
+
+		write!(w, "replace_lane_{name}(")?;
+		self.vector().write(mng, w)?;
+		write!(w, ", {}, ", self.lane())?;
+		self.value().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for Shuffle {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		write!(w, "shuffle_i8x16(")?;
+		self.lhs().write(mng, w)?;
+		write!(w, ", ")?;
+		self.rhs().write(mng, w)?;
+
+		for lane in self.lane_list() {
+			write!(w, ", {lane}")?;
+		}
+
+		write!(w, ")")
+	}
+}
+
+impl Driver for BitSelect {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		write!(w, "bitselect_v128(")?;
+		self.on_true().write(mng, w)?;
+		write!(w, ", ")?;
+		self.on_false().write(mng, w)?;
+		write!(w, ", ")?;
+		self.condition().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+// Addresses and sizes of 64-bit memories are `i64` values,
+// so they are converted to plain numbers for the runtime
+pub struct Address<'a>(pub IndexType, pub &'a Expression);
+
+impl Driver for Address<'_> {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		match self.0 {
+			IndexType::I32 => self.1.write(mng, w),
+			IndexType::I64 => {
+				write!(w, "convert_f64_u64(")?;
+				self.1.write(mng, w)?;
+				write!(w, ")")
+			}
+		}
+	}
+}
+
 pub struct Condition<'a>(pub &'a Expression);
 
 impl Driver for Condition<'_> {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
-		if let Expression::CmpOp(node) = self.0 {
-			CmpOpBoolean(node).write(mng, w)
-		} else {
-			self.0.write(mng, w)?;
-			write!(w, " ~= 0")
+		match self.0 {
+			Expression::CmpOp(node) => CmpOpBoolean(node).write(mng, w),
+			Expression::RefIsNull(node) => {
+				node.value().write(mng, w)?;
+				write!(w, " == nil")
+			}
+			_ => {
+				self.0.write(mng, w)?;
+				write!(w, " ~= 0")
+			}
 		}
 	}
 }
@@ -188,6 +315,11 @@ impl Driver for Expression {
 			Self::UnOp(e) => e.write(mng, w),
 			Self::BinOp(e) => e.write(mng, w),
 			Self::CmpOp(e) => e.write(mng, w),
+			Self::RefIsNull(e) => e.write(mng, w),
+			Self::ExtractLane(e) => e.write(mng, w),
+			Self::ReplaceLane(e) => e.write(mng, w),
+			Self::Shuffle(e) => e.write(mng, w),
+			Self::BitSelect(e) => e.write(mng, w),
 		}
 	}
 }
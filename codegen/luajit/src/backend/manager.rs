@@ -1,11 +1,21 @@
 use std::{
-	collections::HashMap,
+	cell::RefCell,
+	collections::{BTreeSet, HashMap},
 	io::{Result, Write},
+	rc::Rc,
 };
 
-use wasm_ast::node::{BrTable, FuncData};
+use wasm_ast::{
+	node::{BrTable, FuncData},
+	source_map::SourceMap,
+};
+
+use crate::analyzer::{
+	br_table, localize,
+	spill::{self, Spill},
+};
 
-use crate::analyzer::{br_table, localize};
+use super::name_list::NameList;
 
 #[macro_export]
 macro_rules! indentation {
@@ -32,62 +42,71 @@ macro_rules! line {
 	}};
 }
 
-fn get_pinned_registers(
-	upvalues: usize,
-	params: usize,
-	locals: usize,
-	temporaries: usize,
-) -> (usize, usize) {
+fn get_available_registers(upvalues: usize, params: usize) -> usize {
 	const MAX_LOCAL_COUNT: usize = 180;
 
-	let available = MAX_LOCAL_COUNT
+	MAX_LOCAL_COUNT
 		.saturating_sub(upvalues)
-		.saturating_sub(params);
-
-	let temporaries = available.min(temporaries);
-	let locals = available.saturating_sub(temporaries).min(locals);
-
-	(params + locals, temporaries)
+		.saturating_sub(params)
 }
 
 pub struct Manager {
 	table_map: HashMap<usize, usize>,
-	num_local: usize,
-	num_temp: usize,
+	spill: Spill,
 	num_label: usize,
 	label_list: Vec<usize>,
+	try_list: Vec<(usize, BTreeSet<usize>)>,
+	delegate_list: Vec<bool>,
 	indentation: usize,
+	name_list: Rc<NameList>,
+	function: usize,
+	source_map: Option<Rc<RefCell<SourceMap>>>,
 }
 
 impl Manager {
-	pub fn empty() -> Self {
+	pub fn empty(name_list: Rc<NameList>) -> Self {
 		Self {
 			table_map: HashMap::new(),
-			num_local: 0,
-			num_temp: usize::MAX,
+			spill: Spill::default(),
 			num_label: 0,
 			label_list: Vec::new(),
+			try_list: Vec::new(),
+			delegate_list: Vec::new(),
 			indentation: 0,
+			name_list,
+			function: 0,
+			source_map: None,
 		}
 	}
 
-	pub fn function(ast: &FuncData) -> Self {
+	pub fn function(
+		ast: &FuncData,
+		name_list: Rc<NameList>,
+		function: usize,
+		source_map: Option<Rc<RefCell<SourceMap>>>,
+	) -> Self {
 		let (upvalues, memories) = localize::visit(ast);
 		let table_map = br_table::visit(ast);
-		let (num_local, num_temp) = get_pinned_registers(
-			upvalues.len() + memories.len(),
-			ast.num_param(),
-			ast.local_data().len(),
-			ast.num_stack(),
-		);
+		let available = get_available_registers(upvalues.len() + memories.len(), ast.num_param());
+		let spill = spill::visit(ast, available);
 
 		Self {
 			table_map,
-			num_local,
-			num_temp,
+			spill,
 			num_label: 0,
 			label_list: Vec::new(),
+			try_list: Vec::new(),
+			delegate_list: Vec::new(),
 			indentation: 0,
+			name_list,
+			function,
+			source_map,
+		}
+	}
+
+	pub fn add_code_offset(&self, offset: usize) {
+		if let Some(source_map) = &self.source_map {
+			source_map.borrow_mut().add_offset(offset);
 		}
 	}
 
@@ -101,12 +120,8 @@ impl Manager {
 		!self.table_map.is_empty()
 	}
 
-	pub const fn num_local(&self) -> usize {
-		self.num_local
-	}
-
-	pub const fn num_temp(&self) -> usize {
-		self.num_temp
+	pub const fn spill(&self) -> &Spill {
+		&self.spill
 	}
 
 	pub fn label_list(&self) -> &[usize] {
@@ -124,6 +139,51 @@ impl Manager {
 		self.label_list.pop().unwrap();
 	}
 
+	// `try` bodies run in a protected closure, so labels below
+	// the start of the innermost one can only be reached by returning
+	pub fn try_start(&self) -> usize {
+		self.try_list.last().map_or(0, |v| v.0)
+	}
+
+	pub fn push_try(&mut self) {
+		self.try_list.push((self.label_list.len(), BTreeSet::new()));
+		self.delegate_list.push(false);
+	}
+
+	// Also returns whether the handlers may receive an exception wrapped by `delegate`
+	pub fn pop_try(&mut self) -> (BTreeSet<usize>, bool) {
+		let is_delegated = self.delegate_list.pop().unwrap();
+
+		(self.try_list.pop().unwrap().1, is_delegated)
+	}
+
+	// Exceptions delegated past an open `try` body are wrapped with their
+	// target level, so every handler on the way has to unwrap or pass them
+	pub fn add_delegate(&mut self, level: usize) -> bool {
+		if self.try_start() <= level {
+			return false;
+		}
+
+		self.delegate_list.fill(true);
+
+		true
+	}
+
+	pub fn add_try_exit(&mut self, position: usize) {
+		self.try_list.last_mut().unwrap().1.insert(position);
+	}
+
+	pub fn name_list(&self) -> &NameList {
+		&self.name_list
+	}
+
+	pub fn write_local(&self, var: usize, w: &mut dyn Write) -> Result<()> {
+		match self.name_list.local_name(self.function, var) {
+			Some(name) => write!(w, "loc_{name}"),
+			None => write!(w, "loc_{var}"),
+		}
+	}
+
 	pub const fn indentation(&self) -> usize {
 		self.indentation
 	}
@@ -0,0 +1,105 @@
+use std::{
+	collections::HashMap,
+	io::{Result, Write},
+};
+
+use wasm_ast::module::Module;
+
+// Mangled names can get very long, so only a prefix is kept
+const MAX_NAME_LEN: usize = 48;
+
+// Names keep their index as a suffix, so two that sanitize to the same
+// text stay unique and can never clash with Lua keywords
+fn sanitize(name: &str, index: usize) -> Option<String> {
+	let mut result = String::new();
+
+	for c in name.chars() {
+		if result.len() >= MAX_NAME_LEN {
+			break;
+		} else if c.is_ascii_alphanumeric() {
+			result.push(c);
+		} else if !result.is_empty() && !result.ends_with('_') {
+			result.push('_');
+		}
+	}
+
+	let result = result.trim_end_matches('_');
+
+	if result.is_empty() {
+		None
+	} else if result.starts_with(|c: char| c.is_ascii_digit()) {
+		Some(format!("_{result}_{index}"))
+	} else {
+		Some(format!("{result}_{index}"))
+	}
+}
+
+fn sanitize_map(map: &HashMap<u32, &str>) -> HashMap<usize, String> {
+	map.iter()
+		.filter_map(|(&index, name)| {
+			let index = usize::try_from(index).unwrap();
+
+			sanitize(name, index).map(|name| (index, name))
+		})
+		.collect()
+}
+
+fn write_name(name: Option<&String>, list: &str, index: usize, w: &mut dyn Write) -> Result<()> {
+	match name {
+		Some(name) => write!(w, "{list}.{name}"),
+		None => write!(w, "{list}[{index}]"),
+	}
+}
+
+// Identifiers written in place of the numeric defaults, which
+// are used as-is for anything without a name. Only functions, tables,
+// memories, globals and locals are renamed; data and element segments,
+// tags and labels keep their numbered slots even when the name section
+// has entries for them.
+#[derive(Default)]
+pub struct NameList {
+	function_map: HashMap<usize, String>,
+	table_map: HashMap<usize, String>,
+	memory_map: HashMap<usize, String>,
+	global_map: HashMap<usize, String>,
+	local_map: HashMap<usize, HashMap<usize, String>>,
+}
+
+impl NameList {
+	pub fn from_module(wasm: &Module) -> Self {
+		let name_section = wasm.name_section();
+		let local_map = name_section
+			.local_map()
+			.iter()
+			.map(|(&index, map)| (usize::try_from(index).unwrap(), sanitize_map(map)))
+			.collect();
+
+		Self {
+			function_map: sanitize_map(name_section.function_map()),
+			table_map: sanitize_map(name_section.table_map()),
+			memory_map: sanitize_map(name_section.memory_map()),
+			global_map: sanitize_map(name_section.global_map()),
+			local_map,
+		}
+	}
+
+	pub fn local_name(&self, function: usize, var: usize) -> Option<&str> {
+		self.local_map.get(&function)?.get(&var).map(String::as_str)
+	}
+
+	pub fn write_function(&self, index: usize, w: &mut dyn Write) -> Result<()> {
+		write_name(self.function_map.get(&index), "FUNC_LIST", index, w)
+	}
+
+	pub fn write_table(&self, index: usize, w: &mut dyn Write) -> Result<()> {
+		write_name(self.table_map.get(&index), "TABLE_LIST", index, w)
+	}
+
+	pub fn write_memory(&self, index: usize, w: &mut dyn Write) -> Result<()> {
+		write_name(self.memory_map.get(&index), "MEMORY_LIST", index, w)
+	}
+
+	pub fn write_global(&self, index: usize, w: &mut dyn Write) -> Result<()> {
+		write_name(self.global_map.get(&index), "GLOBAL_LIST", index, w)
+	}
+}
@@ -4,8 +4,11 @@ use std::{
 };
 
 use wasm_ast::node::{
-	Block, Br, BrIf, BrTable, Call, CallIndirect, FuncData, If, LabelType, MemoryCopy, MemoryFill,
-	MemoryGrow, ResultList, SetGlobal, SetLocal, SetTemporary, Statement, StoreAt, Terminator,
+	AtomicWait, Block, Br, BrIf, BrTable, Call, CallIndirect, DataDrop, ElemDrop, FuncData, If,
+	IndexType, LabelType, MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, ResultList, Rethrow,
+	ReturnCall, ReturnCallIndirect, SetGlobal, SetLocal, SetTemporary, Statement, StoreAt,
+	TableCopy, TableFill, TableGet, TableGrow, TableInit, TableSet, TableSize, Terminator, Throw,
+	Try,
 };
 use wasmparser::ValType;
 
@@ -14,7 +17,7 @@ use crate::{
 };
 
 use super::{
-	expression::Condition,
+	expression::{Address, Condition},
 	manager::{Driver, Manager},
 };
 
@@ -24,9 +27,21 @@ impl Driver for ResultList {
 	}
 }
 
+fn write_jump(position: usize, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+	let level = mng.label_list()[position];
+
+	if position < mng.try_start() {
+		mng.add_try_exit(position);
+
+		line!(mng, w, "do return {level} end")
+	} else {
+		line!(mng, w, "goto continue_at_{level}")
+	}
+}
+
 impl Driver for Br {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
-		let level = *mng.label_list().iter().nth_back(self.target()).unwrap();
+		let position = mng.label_list().len() - 1 - self.target();
 
 		if !self.align().is_aligned() {
 			indentation!(mng, w)?;
@@ -36,7 +51,7 @@ impl Driver for Br {
 			writeln!(w)?;
 		}
 
-		line!(mng, w, "goto continue_at_{level}")
+		write_jump(position, mng, w)
 	}
 }
 
@@ -132,12 +147,63 @@ impl Driver for BrTable {
 	}
 }
 
+// Lua only allows `return` as the last statement of a block, so tail calls
+// are wrapped to stay valid wherever the terminator is placed.
+impl Driver for ReturnCall {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		indented!(mng, w, "do return ")?;
+		mng.name_list().write_function(self.function(), w)?;
+		write!(w, "(")?;
+		self.param_list().write(mng, w)?;
+		writeln!(w, ") end")
+	}
+}
+
+impl Driver for ReturnCallIndirect {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		indented!(mng, w, "do return ")?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ".data[")?;
+		self.index().write(mng, w)?;
+		write!(w, "](")?;
+		self.param_list().write(mng, w)?;
+		writeln!(w, ") end")
+	}
+}
+
+impl Driver for Throw {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		indented!(mng, w, "rt.exception.throw(TAG_LIST[{}]", self.tag())?;
+
+		self.param_list().iter().try_for_each(|v| {
+			write!(w, ", ")?;
+			v.write(mng, w)
+		})?;
+
+		writeln!(w, ")")
+	}
+}
+
+impl Driver for Rethrow {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let level = mng.label_list().len() - 1 - self.target();
+
+		line!(mng, w, "error(exception_{level}, 0)")
+	}
+}
+
 impl Driver for Terminator {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		mng.add_code_offset(self.code_offset());
+
 		match self {
-			Self::Unreachable => line!(mng, w, r#"error("out of code bounds")"#),
+			Self::Unreachable(_) => line!(mng, w, r#"error("out of code bounds")"#),
 			Self::Br(s) => s.write(mng, w),
 			Self::BrTable(s) => s.write(mng, w),
+			Self::ReturnCall(s) => s.write(mng, w),
+			Self::ReturnCallIndirect(s) => s.write(mng, w),
+			Self::Throw(s) => s.write(mng, w),
+			Self::Rethrow(s) => s.write(mng, w),
 		}
 	}
 }
@@ -216,6 +282,140 @@ impl Driver for If {
 	}
 }
 
+// Exceptions leaving the body unhandled are passed on to the `try` targeted
+// by `delegate`, wrapped whenever they have to skip the handlers on the way.
+fn write_rethrow(
+	data: &Try,
+	level: usize,
+	is_delegated: bool,
+	mng: &mut Manager,
+	w: &mut dyn Write,
+) -> Result<()> {
+	if let Some(target) = data.delegate() {
+		let target = level - 1 - target;
+
+		if mng.add_delegate(target) {
+			return line!(
+				mng,
+				w,
+				"error(rt.exception.delegate(exception_{level}, {target}), 0)"
+			);
+		}
+	}
+
+	// Wrapped exceptions are never let out of the function
+	if is_delegated && mng.try_start() == 0 {
+		line!(
+			mng,
+			w,
+			"error(rt.exception.resolve(exception_{level}, 0), 0)"
+		)
+	} else {
+		line!(mng, w, "error(exception_{level}, 0)")
+	}
+}
+
+fn write_catch_list(
+	data: &Try,
+	level: usize,
+	is_delegated: bool,
+	mng: &mut Manager,
+	w: &mut dyn Write,
+) -> Result<()> {
+	let mut head = "if";
+
+	if is_delegated {
+		line!(
+			mng,
+			w,
+			"local exception_{level} = rt.exception.resolve(result, {level})"
+		)?;
+	} else {
+		line!(mng, w, "local exception_{level} = result")?;
+	}
+
+	for catch in data.catch_list() {
+		let tag = catch.tag();
+
+		line!(
+			mng,
+			w,
+			"{head} rt.exception.matches(exception_{level}, TAG_LIST[{tag}]) then"
+		)?;
+		mng.indent();
+
+		if !catch.payload().is_empty() {
+			indentation!(mng, w)?;
+			catch.payload().write(mng, w)?;
+			writeln!(w, " = rt.exception.unpack(exception_{level})")?;
+		}
+
+		catch.block().write(mng, w)?;
+		mng.dedent();
+
+		head = "elseif";
+	}
+
+	if let Some(block) = data.catch_all() {
+		line!(mng, w, "{head} rt.exception.is(exception_{level}) then")?;
+		mng.indent();
+		block.write(mng, w)?;
+		mng.dedent();
+
+		head = "elseif";
+	}
+
+	// Traps and other Lua errors are never caught, not even by `catch_all`,
+	// so they continue unwinding past every handler
+	if head == "if" {
+		return write_rethrow(data, level, is_delegated, mng, w);
+	}
+
+	line!(mng, w, "else")?;
+	mng.indent();
+	write_rethrow(data, level, is_delegated, mng, w)?;
+	mng.dedent();
+	line!(mng, w, "end")
+}
+
+// Branches out of the body are returned as the label they target,
+// which is then jumped to from outside of it.
+impl Driver for Try {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let level = mng.label_list().len();
+
+		line!(mng, w, "do")?;
+		mng.indent();
+		line!(mng, w, "local success, result = pcall(function()")?;
+		mng.indent();
+		mng.push_try();
+		self.body().write(mng, w)?;
+
+		let (exit_list, is_delegated) = mng.pop_try();
+
+		mng.dedent();
+		line!(mng, w, "end)")?;
+
+		line!(mng, w, "if not success then")?;
+		mng.indent();
+		write_catch_list(self, level, is_delegated, mng, w)?;
+		mng.dedent();
+
+		for position in exit_list {
+			let label = mng.label_list()[position];
+
+			line!(mng, w, "elseif result == {label} then")?;
+			mng.indent();
+			write_jump(position, mng, w)?;
+			mng.dedent();
+		}
+
+		line!(mng, w, "end")?;
+		mng.dedent();
+		line!(mng, w, "end")
+	}
+}
+
 impl Driver for Call {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		if !self.result_list().is_empty() {
@@ -223,7 +423,8 @@ impl Driver for Call {
 			write!(w, " = ")?;
 		}
 
-		write!(w, "FUNC_LIST[{}](", self.function())?;
+		mng.name_list().write_function(self.function(), w)?;
+		write!(w, "(")?;
 		self.param_list().write(mng, w)?;
 		write!(w, ")")
 	}
@@ -236,7 +437,8 @@ impl Driver for CallIndirect {
 			write!(w, " = ")?;
 		}
 
-		write!(w, "TABLE_LIST[{}].data[", self.table())?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ".data[")?;
 		self.index().write(mng, w)?;
 		write!(w, "](")?;
 		self.param_list().write(mng, w)?;
@@ -262,7 +464,8 @@ impl Driver for SetLocal {
 
 impl Driver for SetGlobal {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
-		write!(w, "GLOBAL_LIST[{}].value = ", self.var())?;
+		mng.name_list().write_global(self.var(), w)?;
+		write!(w, ".value = ")?;
 		self.value().write(mng, w)
 	}
 }
@@ -272,9 +475,13 @@ impl Driver for StoreAt {
 		let name = self.store_type().into_name();
 		let memory = self.memory();
 
+		if self.is_atomic() {
+			write!(w, "atomic_")?;
+		}
+
 		write!(w, "store_{name}(memory_at_{memory}, ")?;
 
-		self.pointer().write(mng, w)?;
+		Address(self.index_type(), self.pointer()).write(mng, w)?;
 
 		if self.offset() != 0 {
 			write!(w, " + {}", self.offset())?;
@@ -290,9 +497,37 @@ impl Driver for MemoryGrow {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		let memory = self.memory();
 
+		let index_type = self.index_type();
+
 		self.result().write(mng, w)?;
-		write!(w, " = rt.allocator.grow(memory_at_{memory}, ")?;
-		self.size().write(mng, w)?;
+
+		// Failure is signalled with `-1`, so the result is sign extended
+		if index_type == IndexType::I64 {
+			write!(
+				w,
+				" = extend_i64_i32(rt.allocator.grow(memory_at_{memory}, "
+			)?;
+			Address(index_type, self.size()).write(mng, w)?;
+			write!(w, "))")
+		} else {
+			write!(w, " = rt.allocator.grow(memory_at_{memory}, ")?;
+			self.size().write(mng, w)?;
+			write!(w, ")")
+		}
+	}
+}
+
+impl Driver for AtomicWait {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let name = self.value().load_type().into_name();
+
+		self.result().write(mng, w)?;
+		write!(w, " = rt.atomic.wait_{name}(")?;
+		self.value().write(mng, w)?;
+		write!(w, ", ")?;
+		self.expected().write(mng, w)?;
+		write!(w, ", ")?;
+		self.timeout().write(mng, w)?;
 		write!(w, ")")
 	}
 }
@@ -302,12 +537,22 @@ impl Driver for MemoryCopy {
 		let memory_1 = self.destination().memory();
 		let memory_2 = self.source().memory();
 
+		let destination = self.destination();
+		let source = self.source();
+
+		// The size is only 64-bit when both memories are
+		let size_type = if destination.index_type() == IndexType::I64 {
+			source.index_type()
+		} else {
+			IndexType::I32
+		};
+
 		write!(w, "rt.store.copy(memory_at_{memory_1}, ")?;
-		self.destination().pointer().write(mng, w)?;
+		Address(destination.index_type(), destination.pointer()).write(mng, w)?;
 		write!(w, ", memory_at_{memory_2}, ")?;
-		self.source().pointer().write(mng, w)?;
+		Address(source.index_type(), source.pointer()).write(mng, w)?;
 		write!(w, ", ")?;
-		self.size().write(mng, w)?;
+		Address(size_type, self.size()).write(mng, w)?;
 		write!(w, ")")
 	}
 }
@@ -316,16 +561,142 @@ impl Driver for MemoryFill {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		let memory = self.destination().memory();
 
+		let index_type = self.destination().index_type();
+
 		write!(w, "rt.store.fill(memory_at_{memory}, ")?;
-		self.destination().pointer().write(mng, w)?;
+		Address(index_type, self.destination().pointer()).write(mng, w)?;
+		write!(w, ", ")?;
+		Address(index_type, self.size()).write(mng, w)?;
+		write!(w, ", ")?;
+		self.value().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for MemoryInit {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let memory = self.destination().memory();
+
+		let index_type = self.destination().index_type();
+
+		write!(w, "rt.store.init(memory_at_{memory}, ")?;
+		Address(index_type, self.destination().pointer()).write(mng, w)?;
+		write!(w, ", DATA_LIST[{}], ", self.data())?;
+		self.offset().write(mng, w)?;
+		write!(w, ", ")?;
+		self.size().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for DataDrop {
+	fn write(&self, _mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		write!(w, "DATA_LIST[{}] = nil", self.data())
+	}
+}
+
+impl Driver for TableGet {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		self.result().write(mng, w)?;
+		write!(w, " = rt.table.get(")?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ", ")?;
+		self.index().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableSet {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		write!(w, "rt.table.set(")?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ", ")?;
+		self.index().write(mng, w)?;
+		write!(w, ", ")?;
+		self.value().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableSize {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		self.result().write(mng, w)?;
+		write!(w, " = rt.table.size(")?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableGrow {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		self.result().write(mng, w)?;
+		write!(w, " = rt.table.grow(")?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ", ")?;
+		self.value().write(mng, w)?;
 		write!(w, ", ")?;
 		self.size().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableFill {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let table = self.destination().table();
+
+		write!(w, "rt.table.fill(")?;
+		mng.name_list().write_table(table, w)?;
+		write!(w, ", ")?;
+		self.destination().index().write(mng, w)?;
 		write!(w, ", ")?;
 		self.value().write(mng, w)?;
+		write!(w, ", ")?;
+		self.size().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableCopy {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let table_1 = self.destination().table();
+		let table_2 = self.source().table();
+
+		write!(w, "rt.table.copy(")?;
+		mng.name_list().write_table(table_1, w)?;
+		write!(w, ", ")?;
+		self.destination().index().write(mng, w)?;
+		write!(w, ", ")?;
+		mng.name_list().write_table(table_2, w)?;
+		write!(w, ", ")?;
+		self.source().index().write(mng, w)?;
+		write!(w, ", ")?;
+		self.size().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableInit {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let table = self.destination().table();
+
+		write!(w, "rt.table.init(")?;
+		mng.name_list().write_table(table, w)?;
+		write!(w, ", ")?;
+		self.destination().index().write(mng, w)?;
+		write!(w, ", ELEMENT_LIST[{}], ", self.element())?;
+		self.offset().write(mng, w)?;
+		write!(w, ", ")?;
+		self.size().write(mng, w)?;
 		write!(w, ")")
 	}
 }
 
+impl Driver for ElemDrop {
+	fn write(&self, _mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		write!(w, "ELEMENT_LIST[{}] = nil", self.element())
+	}
+}
+
 fn write_stat(stat: &dyn Driver, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 	indentation!(mng, w)?;
 	stat.write(mng, w)?;
@@ -334,6 +705,8 @@ fn write_stat(stat: &dyn Driver, mng: &mut Manager, w: &mut dyn Write) -> Result
 
 impl Driver for Statement {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		mng.add_code_offset(self.code_offset());
+
 		match self {
 			Self::Block(s) => s.write(mng, w),
 			Self::BrIf(s) => s.write(mng, w),
@@ -345,58 +718,76 @@ impl Driver for Statement {
 			Self::SetGlobal(s) => write_stat(s, mng, w),
 			Self::StoreAt(s) => write_stat(s, mng, w),
 			Self::MemoryGrow(s) => write_stat(s, mng, w),
+			Self::AtomicWait(s) => write_stat(s, mng, w),
 			Self::MemoryCopy(s) => write_stat(s, mng, w),
 			Self::MemoryFill(s) => write_stat(s, mng, w),
+			Self::MemoryInit(s) => write_stat(s, mng, w),
+			Self::DataDrop(s) => write_stat(s, mng, w),
+			Self::TableGet(s) => write_stat(s, mng, w),
+			Self::TableSet(s) => write_stat(s, mng, w),
+			Self::TableSize(s) => write_stat(s, mng, w),
+			Self::TableGrow(s) => write_stat(s, mng, w),
+			Self::TableFill(s) => write_stat(s, mng, w),
+			Self::TableCopy(s) => write_stat(s, mng, w),
+			Self::TableInit(s) => write_stat(s, mng, w),
+			Self::ElemDrop(s) => write_stat(s, mng, w),
+			Self::Try(s) => s.write(mng, w),
 		}
 	}
 }
 
-fn write_parameter_list(ast: &FuncData, w: &mut dyn Write) -> Result<()> {
+fn write_parameter_list(ast: &FuncData, mng: &Manager, w: &mut dyn Write) -> Result<()> {
 	write!(w, "function(")?;
-	write_separated(0..ast.num_param(), |i, w| write!(w, "loc_{i}"), w)?;
+	write_separated(0..ast.num_param(), |i, w| mng.write_local(i, w), w)?;
 	writeln!(w, ")")
 }
 
 const fn type_to_zero(typ: ValType) -> &'static str {
 	match typ {
 		ValType::F32 | ValType::F64 => "0.0",
+		ValType::Ref(_) => "nil",
+		ValType::V128 => "v128_ZERO",
 		ValType::I64 => "0LL",
-		_ => "0",
+		ValType::I32 => "0",
 	}
 }
 
 fn write_variable_list(ast: &FuncData, mng: &Manager, w: &mut dyn Write) -> Result<()> {
-	let mut locals = ast.local_data().iter().copied();
-	let num_local = mng.num_local() - ast.num_param();
+	let mut spilled = Vec::new();
 
-	for (i, typ) in locals.by_ref().enumerate().take(num_local) {
+	for (i, &typ) in ast.local_data().iter().enumerate() {
 		let index = ast.num_param() + i;
 		let zero = type_to_zero(typ);
 
-		line!(mng, w, "local loc_{index} = {zero}")?;
+		if mng.spill().local(index).is_some() {
+			spilled.push(zero);
+			continue;
+		}
+
+		indented!(mng, w, "local ")?;
+		mng.write_local(index, w)?;
+		writeln!(w, " = {zero}")?;
 	}
 
-	if locals.len() != 0 {
+	if !spilled.is_empty() {
 		indented!(mng, w, "local loc_spill = {{ ")?;
 
-		for typ in locals {
-			let zero = type_to_zero(typ);
-
+		for zero in spilled {
 			write!(w, "{zero}, ")?;
 		}
 
 		writeln!(w, "}}")?;
 	}
 
-	let mut temporaries = 0..ast.num_stack();
-
-	for i in temporaries.by_ref().take(mng.num_temp()) {
-		line!(mng, w, "local reg_{i}")?;
+	for i in 0..ast.num_stack() {
+		if mng.spill().temporary(i).is_none() {
+			line!(mng, w, "local reg_{i}")?;
+		}
 	}
 
-	if !temporaries.is_empty() {
-		let len = temporaries.len();
+	let len = mng.spill().num_temporary();
 
+	if len != 0 {
 		line!(mng, w, "local reg_spill = table.create({len})")?;
 	}
 
@@ -407,7 +798,7 @@ impl Driver for FuncData {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		mng.indent();
 
-		write_parameter_list(self, w)?;
+		write_parameter_list(self, mng, w)?;
 		write_variable_list(self, mng, w)?;
 
 		if mng.has_table() {
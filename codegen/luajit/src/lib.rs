@@ -1,6 +1,10 @@
 pub static RUNTIME: &str = include_str!("../runtime/runtime.lua");
 
-pub use translator::{from_inst_list, from_module_typed, from_module_untyped};
+pub use translator::{
+	default_settings, from_inst_list, from_module_named, from_module_named_mapped,
+	from_module_typed, from_module_typed_mapped, from_module_untyped, write_ast, write_cfg,
+	write_wasm,
+};
 
 mod analyzer;
 mod backend;
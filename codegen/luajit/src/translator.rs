@@ -1,21 +1,32 @@
 use std::{
+	cell::RefCell,
 	collections::BTreeSet,
-	io::{Result, Write},
+	io::{self, Write},
+	rc::Rc,
 };
 
 use wasm_ast::{
+	cfg::Cfg,
+	encode::encode_module,
+	error::Result,
 	factory::Factory,
 	module::{External, Module, TypeInfo},
-	node::{FuncData, Statement},
+	node::{Expression, FuncData, IndexType},
+	optimize::Settings,
+	print::{Print, Printer},
+	source_map::{LineCounter, SourceMap},
 };
 use wasmparser::{
 	ConstExpr, Data, DataKind, Element, ElementItems, ElementKind, Export, Import, Operator,
-	OperatorsReader,
+	ValType,
 };
 
 use crate::{
 	analyzer::localize,
-	backend::manager::{Driver, Manager},
+	backend::{
+		manager::{Driver, Manager},
+		name_list::NameList,
+	},
 };
 
 trait AsIEName {
@@ -29,18 +40,12 @@ impl AsIEName for External {
 			Self::Table => "table_list",
 			Self::Memory => "memory_list",
 			Self::Global => "global_list",
-			Self::Tag => unimplemented!(),
+			Self::Tag => "tag_list",
 		}
 	}
 }
 
-fn reader_to_code(reader: OperatorsReader) -> Vec<Operator> {
-	let parsed: std::result::Result<_, _> = reader.into_iter().collect();
-
-	parsed.unwrap()
-}
-
-fn write_named_array(name: &str, len: usize, w: &mut dyn Write) -> Result<()> {
+fn write_named_array(name: &str, len: usize, w: &mut dyn Write) -> io::Result<()> {
 	let Some(len) = len.checked_sub(1) else {
 		return Ok(());
 	};
@@ -48,20 +53,41 @@ fn write_named_array(name: &str, len: usize, w: &mut dyn Write) -> Result<()> {
 	writeln!(w, "local {name} = table_new({len}, 1)")
 }
 
-fn write_constant(init: &ConstExpr, type_info: &TypeInfo, w: &mut dyn Write) -> Result<()> {
-	let code = reader_to_code(init.get_operators_reader());
-	let func = Factory::from_type_info(type_info).create_anonymous(code.as_slice());
+fn write_constant(
+	init: &ConstExpr,
+	type_info: &TypeInfo,
+	name_list: &Rc<NameList>,
+	w: &mut dyn Write,
+) -> Result<()> {
+	let value = Factory::from_type_info(type_info).create_constant(init)?;
+
+	value.write(&mut Manager::empty(name_list.clone()), w)?;
 
-	if let Some(Statement::SetTemporary(stat)) = func.code().code().last() {
-		stat.value().write(&mut Manager::empty(), w)
-	} else {
-		writeln!(w, r#"error("Valueless constant")"#)
+	Ok(())
+}
+
+fn write_external_at(
+	external: External,
+	index: usize,
+	name_list: &NameList,
+	w: &mut dyn Write,
+) -> io::Result<()> {
+	match external {
+		External::Func => name_list.write_function(index, w),
+		External::Table => name_list.write_table(index, w),
+		External::Memory => name_list.write_memory(index, w),
+		External::Global => name_list.write_global(index, w),
+		_ => write!(w, "{}[{index}]", external.as_ie_name().to_uppercase()),
 	}
 }
 
-fn write_import_of(list: &[Import], wanted: External, w: &mut dyn Write) -> Result<()> {
+fn write_import_of(
+	list: &[Import],
+	wanted: External,
+	name_list: &NameList,
+	w: &mut dyn Write,
+) -> io::Result<()> {
 	let lower = wanted.as_ie_name();
-	let upper = lower.to_uppercase();
 
 	for (i, Import { name, module, .. }) in list
 		.iter()
@@ -69,173 +95,305 @@ fn write_import_of(list: &[Import], wanted: External, w: &mut dyn Write) -> Resu
 		.enumerate()
 	{
 		write!(w, "\t")?;
-		writeln!(w, r#"{upper}[{i}] = wasm["{module}"].{lower}["{name}"]"#)?;
+		write_external_at(wanted, i, name_list, w)?;
+		writeln!(w, r#" = wasm["{module}"].{lower}["{name}"]"#)?;
 	}
 
 	Ok(())
 }
 
-fn write_export_of(list: &[Export], wanted: External, w: &mut dyn Write) -> Result<()> {
+fn write_export_of(
+	list: &[Export],
+	wanted: External,
+	name_list: &NameList,
+	w: &mut dyn Write,
+) -> io::Result<()> {
 	let lower = wanted.as_ie_name();
-	let upper = lower.to_uppercase();
 
 	writeln!(w, "\t\t{lower} = {{")?;
 
 	for Export { name, index, .. } in list.iter().filter(|v| External::from(v.kind) == wanted) {
 		write!(w, "\t\t\t")?;
-		writeln!(w, r#"["{name}"] = {upper}[{index}],"#)?;
+		write!(w, r#"["{name}"] = "#)?;
+		write_external_at(wanted, usize::try_from(*index).unwrap(), name_list, w)?;
+		writeln!(w, ",")?;
 	}
 
 	writeln!(w, "\t\t}},")
 }
 
-fn write_import_list(list: &[Import], w: &mut dyn Write) -> Result<()> {
-	write_import_of(list, External::Func, w)?;
-	write_import_of(list, External::Table, w)?;
-	write_import_of(list, External::Memory, w)?;
-	write_import_of(list, External::Global, w)
+fn write_import_list(list: &[Import], name_list: &NameList, w: &mut dyn Write) -> io::Result<()> {
+	write_import_of(list, External::Func, name_list, w)?;
+	write_import_of(list, External::Table, name_list, w)?;
+	write_import_of(list, External::Memory, name_list, w)?;
+	write_import_of(list, External::Global, name_list, w)?;
+	write_import_of(list, External::Tag, name_list, w)
 }
 
-fn write_export_list(list: &[Export], w: &mut dyn Write) -> Result<()> {
-	write_export_of(list, External::Func, w)?;
-	write_export_of(list, External::Table, w)?;
-	write_export_of(list, External::Memory, w)?;
-	write_export_of(list, External::Global, w)
+fn write_export_list(list: &[Export], name_list: &NameList, w: &mut dyn Write) -> io::Result<()> {
+	write_export_of(list, External::Func, name_list, w)?;
+	write_export_of(list, External::Table, name_list, w)?;
+	write_export_of(list, External::Memory, name_list, w)?;
+	write_export_of(list, External::Global, name_list, w)?;
+	write_export_of(list, External::Tag, name_list, w)
 }
 
-fn write_table_list(wasm: &Module, w: &mut dyn Write) -> Result<()> {
+fn write_table_list(wasm: &Module, name_list: &NameList, w: &mut dyn Write) -> io::Result<()> {
 	let offset = wasm.import_count(External::Table);
 	let table = wasm.table_section();
 
 	for (i, table) in table.iter().enumerate() {
 		let index = offset + i;
 		let min = table.ty.initial;
-		let max = table.ty.maximum.unwrap_or(0xFFFF);
+		let max = table.ty.maximum.unwrap_or(0xFFFF_FFFF);
+
+		write!(w, "\t")?;
+		name_list.write_table(index, w)?;
+		writeln!(w, " = {{ min = {min}, max = {max}, data = {{}} }}")?;
+	}
+
+	Ok(())
+}
+
+// Tags only need an identity that exceptions can be matched against
+fn write_tag_list(wasm: &Module, w: &mut dyn Write) -> io::Result<()> {
+	let offset = wasm.import_count(External::Tag);
 
-		writeln!(
-			w,
-			"\tTABLE_LIST[{index}] = {{ min = {min}, max = {max}, data = {{}} }}"
-		)?;
+	for i in 0..wasm.tag_section().len() {
+		let index = offset + i;
+
+		writeln!(w, "\tTAG_LIST[{index}] = {{}}")?;
 	}
 
 	Ok(())
 }
 
-fn write_memory_list(wasm: &Module, w: &mut dyn Write) -> Result<()> {
+fn write_memory_list(wasm: &Module, name_list: &NameList, w: &mut dyn Write) -> io::Result<()> {
 	let offset = wasm.import_count(External::Memory);
 	let memory = wasm.memory_section();
 
 	for (i, ty) in memory.iter().enumerate() {
 		let index = offset + i;
 		let min = ty.initial;
-		let max = ty.maximum.unwrap_or(0xFFFF);
 
-		writeln!(w, "\tMEMORY_LIST[{index}] = rt.allocator.new({min}, {max})")?;
+		// Neither runtime can address more than 4 GiB, so 64-bit
+		// memories share the page limit of 32-bit ones
+		let max = match ty.maximum {
+			Some(max) if ty.memory64 => max.min(0x10000),
+			Some(max) => max,
+			None => 0xFFFF,
+		};
+
+		write!(w, "\t")?;
+		name_list.write_memory(index, w)?;
+		writeln!(w, " = rt.allocator.new({min}, {max})")?;
 	}
 
 	Ok(())
 }
 
-fn write_global_list(wasm: &Module, type_info: &TypeInfo, w: &mut dyn Write) -> Result<()> {
+fn write_global_list(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	name_list: &Rc<NameList>,
+	w: &mut dyn Write,
+) -> Result<()> {
 	let offset = wasm.import_count(External::Global);
 	let global = wasm.global_section();
 
 	for (i, global) in global.iter().enumerate() {
 		let index = offset + i;
 
-		write!(w, "\tGLOBAL_LIST[{index}] = {{ value = ")?;
-		write_constant(&global.init_expr, type_info, w)?;
+		write!(w, "\t")?;
+		name_list.write_global(index, w)?;
+		write!(w, " = {{ value = ")?;
+		write_constant(&global.init_expr, type_info, name_list, w)?;
 		writeln!(w, " }}")?;
 	}
 
 	Ok(())
 }
 
-fn write_element_list(list: &[Element], type_info: &TypeInfo, w: &mut dyn Write) -> Result<()> {
-	for element in list {
-		let ElementKind::Active {
-			table_index: index,
-			offset_expr: init,
-		} = element.kind
-		else {
-			unimplemented!("passive elements not supported")
-		};
+fn write_element_items(
+	element: &Element,
+	type_info: &TypeInfo,
+	name_list: &Rc<NameList>,
+	w: &mut dyn Write,
+) -> Result<u32> {
+	let len = match element.items.clone() {
+		ElementItems::Functions(functions) => {
+			let len = functions.count();
+
+			write!(w, "{{ n = {len}, ")?;
 
-		let index = index.unwrap_or(0);
+			for index in functions {
+				let index = index?;
+				name_list.write_function(index.try_into().unwrap(), w)?;
+				write!(w, ", ")?;
+			}
 
-		writeln!(w, "\tdo")?;
-		writeln!(w, "\t\tlocal target = TABLE_LIST[{index}].data")?;
-		write!(w, "\t\tlocal offset = ")?;
+			len
+		}
+		ElementItems::Expressions(_, expressions) => {
+			let len = expressions.count();
 
-		write_constant(&init, type_info, w)?;
+			write!(w, "{{ n = {len}, ")?;
 
-		writeln!(w)?;
-		write!(w, "\t\tlocal data = {{ ")?;
-
-		match element.items.clone() {
-			ElementItems::Functions(functions) => {
-				for index in functions {
-					let index = index.unwrap();
-					write!(w, "FUNC_LIST[{index}],")?;
-				}
-			}
-			ElementItems::Expressions(_, expressions) => {
-				for init in expressions {
-					let init = init.unwrap();
-					write_constant(&init, type_info, w)?;
-				}
+			for init in expressions {
+				let init = init?;
+				write_constant(&init, type_info, name_list, w)?;
+				write!(w, ", ")?;
 			}
+
+			len
 		}
+	};
 
-		writeln!(w, " }}")?;
-		writeln!(w, "\t\ttable.move(data, 1, #data, offset, target)")?;
-		writeln!(w, "\tend")?;
+	write!(w, "}}")?;
+
+	Ok(len)
+}
+
+fn write_element_list(
+	list: &[Element],
+	type_info: &TypeInfo,
+	name_list: &Rc<NameList>,
+	w: &mut dyn Write,
+) -> Result<()> {
+	for (i, element) in list.iter().enumerate() {
+		// Active segments are dropped once they have been written and declared
+		// segments are never used so neither are added to the element list
+		match element.kind.clone() {
+			ElementKind::Active {
+				table_index,
+				offset_expr,
+			} => {
+				let index = table_index.unwrap_or(0);
+
+				write!(w, "\trt.table.init(")?;
+				name_list.write_table(index.try_into().unwrap(), w)?;
+				write!(w, ", ")?;
+				write_constant(&offset_expr, type_info, name_list, w)?;
+				write!(w, ", ")?;
+				let len = write_element_items(element, type_info, name_list, w)?;
+
+				writeln!(w, ", 0, {len})")?;
+			}
+			ElementKind::Passive => {
+				write!(w, "\tELEMENT_LIST[{i}] = ")?;
+				write_element_items(element, type_info, name_list, w)?;
+				writeln!(w)?;
+			}
+			ElementKind::Declared => {}
+		}
 	}
 
 	Ok(())
 }
 
-fn write_data_list(list: &[Data], type_info: &TypeInfo, w: &mut dyn Write) -> Result<()> {
-	for data in list {
+fn write_data_list(
+	list: &[Data],
+	type_info: &TypeInfo,
+	name_list: &Rc<NameList>,
+	w: &mut dyn Write,
+) -> Result<()> {
+	for (i, data) in list.iter().enumerate() {
+		// Active segments are dropped once they have been written so
+		// they are never added to the data list
 		let (index, init) = match data.kind {
-			DataKind::Passive => unimplemented!("passive data not supported"),
+			DataKind::Passive => {
+				write!(w, "\tDATA_LIST[{i}] = ")?;
+				writeln!(w, r#""{}""#, data.data.escape_ascii())?;
+
+				continue;
+			}
 			DataKind::Active {
 				memory_index,
 				offset_expr,
 			} => (memory_index, offset_expr),
 		};
 
-		write!(w, "\trt.store.string(MEMORY_LIST[{index}], ")?;
-		write_constant(&init, type_info, w)?;
+		write!(w, "\trt.store.string(")?;
+		name_list.write_memory(index.try_into().unwrap(), w)?;
+		write!(w, ", ")?;
+
+		if type_info.by_memory_index(index.try_into().unwrap()) == IndexType::I64 {
+			write!(w, "rt.convert.f64_u64(")?;
+			write_constant(&init, type_info, name_list, w)?;
+			write!(w, ")")?;
+		} else {
+			write_constant(&init, type_info, name_list, w)?;
+		}
+
 		writeln!(w, r#","{}")"#, data.data.escape_ascii())?;
 	}
 
 	Ok(())
 }
 
-fn build_func_list(wasm: &Module, type_info: &TypeInfo) -> Vec<FuncData> {
+// Initializers are built ahead of time so their operations can be
+// localized and any non-constant expressions are reported early
+fn build_constant_list(wasm: &Module, type_info: &TypeInfo) -> Result<Vec<Expression>> {
+	let mut builder = Factory::from_type_info(type_info);
+	let mut list = Vec::new();
+
+	for global in wasm.global_section() {
+		list.push(builder.create_constant(&global.init_expr)?);
+	}
+
+	for element in wasm.element_section() {
+		if let ElementKind::Active { offset_expr, .. } = &element.kind {
+			list.push(builder.create_constant(offset_expr)?);
+		}
+
+		if let ElementItems::Expressions(_, expressions) = element.items.clone() {
+			for init in expressions {
+				list.push(builder.create_constant(&init?)?);
+			}
+		}
+	}
+
+	for data in wasm.data_section() {
+		if let DataKind::Active { offset_expr, .. } = &data.kind {
+			list.push(builder.create_constant(offset_expr)?);
+		}
+	}
+
+	Ok(list)
+}
+
+fn build_func_list(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	settings: &Settings,
+) -> Result<Vec<FuncData>> {
 	let offset = wasm.import_count(External::Func);
 	let mut builder = Factory::from_type_info(type_info);
 
-	wasm.code_section()
+	let mut func_list = wasm
+		.code_section()
 		.iter()
 		.enumerate()
-		.map(|f| builder.create_indexed(f.0 + offset, f.1).unwrap())
-		.collect()
+		.map(|f| builder.create_indexed(f.0 + offset, f.1))
+		.collect::<Result<Vec<_>>>()?;
+
+	settings.run(type_info, offset, &mut func_list);
+
+	Ok(func_list)
 }
 
-fn write_local_operation(head: &str, tail: &str, w: &mut dyn Write) -> Result<()> {
+fn write_local_operation(head: &str, tail: &str, w: &mut dyn Write) -> io::Result<()> {
 	write!(w, "local {head}_{tail} = ")?;
 
 	match (head, tail) {
-		("abs" | "ceil" | "floor" | "sqrt", _) => write!(w, "math.{head}"),
+		("abs" | "ceil" | "floor" | "sqrt", "f32" | "f64") => write!(w, "math.{head}"),
 		("rem", "i32") => write!(w, "math.fmod"),
-		("band" | "bor" | "bxor" | "bnot", _) => write!(w, "bit.{head}"),
-		("shl", _) => write!(w, "bit.lshift"),
+		("band" | "bor" | "bxor" | "bnot", "i32" | "i64") => write!(w, "bit.{head}"),
+		("shl", "i32" | "i64") => write!(w, "bit.lshift"),
 		("shr", "i32" | "i64") => write!(w, "bit.arshift"),
 		("shr", "u32" | "u64") => write!(w, "bit.rshift"),
-		("rotl", _) => write!(w, "bit.rol"),
-		("rotr", _) => write!(w, "bit.ror"),
+		("rotl", "i32" | "i64") => write!(w, "bit.rol"),
+		("rotr", "i32" | "i64") => write!(w, "bit.ror"),
 		("convert", "f32_i64" | "f64_i64") => write!(w, "tonumber"),
 		_ => write!(w, "rt.{head}.{tail}"),
 	}?;
@@ -243,15 +401,34 @@ fn write_local_operation(head: &str, tail: &str, w: &mut dyn Write) -> Result<()
 	writeln!(w)
 }
 
-fn write_localize_used(func_list: &[FuncData], w: &mut dyn Write) -> Result<BTreeSet<usize>> {
+fn write_localize_used(
+	wasm: &Module,
+	func_list: &[FuncData],
+	const_list: &[Expression],
+	w: &mut dyn Write,
+) -> io::Result<BTreeSet<usize>> {
 	let mut loc_set = BTreeSet::new();
 	let mut mem_set = BTreeSet::new();
 
+	let has_global_v128 = wasm
+		.global_section()
+		.iter()
+		.any(|g| g.ty.content_type == ValType::V128);
+
+	if has_global_v128 {
+		loc_set.insert(("v128", "ZERO"));
+		loc_set.insert(("v128", "from_u32"));
+	}
+
 	for (loc, mem) in func_list.iter().map(localize::visit) {
 		loc_set.extend(loc);
 		mem_set.extend(mem);
 	}
 
+	for init in const_list {
+		loc_set.extend(localize::visit_constant(init));
+	}
+
 	for loc in loc_set {
 		write_local_operation(loc.0, loc.1, w)?;
 	}
@@ -263,23 +440,38 @@ fn write_localize_used(func_list: &[FuncData], w: &mut dyn Write) -> Result<BTre
 	Ok(mem_set)
 }
 
-fn write_func_start(wasm: &Module, index: u32, w: &mut dyn Write) -> Result<()> {
-	write!(w, "FUNC_LIST[{index}] = ")?;
+fn write_func_start(
+	wasm: &Module,
+	index: u32,
+	name_list: &NameList,
+	w: &mut dyn Write,
+) -> io::Result<()> {
+	name_list.write_function(index.try_into().unwrap(), w)?;
+	write!(w, " = ")?;
 
 	wasm.name_section()
+		.function_map()
 		.get(&index)
 		.map_or_else(|| Ok(()), |name| write!(w, "--[[ {name} ]] "))
 }
 
-fn write_func_list(wasm: &Module, func_list: &[FuncData], w: &mut dyn Write) -> Result<()> {
+fn write_func_list(
+	wasm: &Module,
+	func_list: &[FuncData],
+	name_list: &Rc<NameList>,
+	source_map: Option<&Rc<RefCell<SourceMap>>>,
+	w: &mut dyn Write,
+) -> io::Result<()> {
 	let offset = wasm.import_count(External::Func);
 
 	func_list.iter().enumerate().try_for_each(|(i, v)| {
-		let index = (offset + i).try_into().unwrap();
+		let index = offset + i;
+
+		write_func_start(wasm, index.try_into().unwrap(), name_list, w)?;
 
-		write_func_start(wasm, index, w)?;
+		let mut mng = Manager::function(v, name_list.clone(), index, source_map.cloned());
 
-		v.write(&mut Manager::function(v), w)
+		v.write(&mut mng, w)
 	})
 }
 
@@ -287,62 +479,237 @@ fn write_module_start(
 	wasm: &Module,
 	type_info: &TypeInfo,
 	mem_set: &BTreeSet<usize>,
+	name_list: &Rc<NameList>,
 	w: &mut dyn Write,
 ) -> Result<()> {
 	writeln!(w, "local function run_init_code()")?;
-	write_table_list(wasm, w)?;
-	write_memory_list(wasm, w)?;
-	write_global_list(wasm, type_info, w)?;
-	write_element_list(wasm.element_section(), type_info, w)?;
-	write_data_list(wasm.data_section(), type_info, w)?;
+	write_table_list(wasm, name_list, w)?;
+	write_memory_list(wasm, name_list, w)?;
+	write_global_list(wasm, type_info, name_list, w)?;
+	write_tag_list(wasm, w)?;
+	write_element_list(wasm.element_section(), type_info, name_list, w)?;
+	write_data_list(wasm.data_section(), type_info, name_list, w)?;
 	writeln!(w, "end")?;
 
 	writeln!(w, "return function(wasm)")?;
-	write_import_list(wasm.import_section(), w)?;
+	write_import_list(wasm.import_section(), name_list, w)?;
 	writeln!(w, "\trun_init_code()")?;
 
 	for mem in mem_set {
-		writeln!(w, "\tmemory_at_{mem} = MEMORY_LIST[{mem}]")?;
+		write!(w, "\tmemory_at_{mem} = ")?;
+		name_list.write_memory(*mem, w)?;
+		writeln!(w)?;
 	}
 
 	if let Some(start) = wasm.start_section() {
-		writeln!(w, "\tFUNC_LIST[{start}]()")?;
+		write!(w, "\t")?;
+		name_list.write_function(start.try_into().unwrap(), w)?;
+		writeln!(w, "()")?;
 	}
 
 	writeln!(w, "\treturn {{")?;
-	write_export_list(wasm.export_section(), w)?;
+	write_export_list(wasm.export_section(), name_list, w)?;
 	writeln!(w, "\t}}")?;
-	writeln!(w, "end")
+	writeln!(w, "end")?;
+
+	Ok(())
+}
+
+/// The settings used by `from_module_untyped`, which inline and run the
+/// standard passes when built with the `optimize` feature and do nothing otherwise.
+#[must_use]
+pub fn default_settings() -> Settings {
+	if cfg!(feature = "optimize") {
+		Settings::standard()
+	} else {
+		Settings::default()
+	}
 }
 
 /// # Errors
-/// Returns `Err` if writing to `Write` failed.
+/// Returns `Err` if the code could not be translated or writing to `Write` failed.
 pub fn from_inst_list(code: &[Operator], type_info: &TypeInfo, w: &mut dyn Write) -> Result<()> {
-	let ast = Factory::from_type_info(type_info).create_anonymous(code);
+	let ast = Factory::from_type_info(type_info).create_anonymous(code)?;
 
-	ast.write(&mut Manager::function(&ast), w)
+	ast.write(&mut Manager::function(&ast, Rc::default(), 0, None), w)?;
+
+	Ok(())
 }
 
-/// # Errors
-/// Returns `Err` if writing to `Write` failed.
-pub fn from_module_typed(wasm: &Module, type_info: &TypeInfo, w: &mut dyn Write) -> Result<()> {
-	let func_list = build_func_list(wasm, type_info);
-	let mem_set = write_localize_used(&func_list, w)?;
+fn write_module(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	settings: &Settings,
+	name_list: &Rc<NameList>,
+	source_map: Option<&Rc<RefCell<SourceMap>>>,
+	w: &mut dyn Write,
+) -> Result<()> {
+	let func_list = build_func_list(wasm, type_info, settings)?;
+	let const_list = build_constant_list(wasm, type_info)?;
+	let mem_set = write_localize_used(wasm, &func_list, &const_list, w)?;
 
 	writeln!(w, "local table_new = require(\"table.new\")")?;
 	write_named_array("FUNC_LIST", wasm.function_space(), w)?;
 	write_named_array("TABLE_LIST", wasm.table_space(), w)?;
 	write_named_array("MEMORY_LIST", wasm.memory_space(), w)?;
 	write_named_array("GLOBAL_LIST", wasm.global_space(), w)?;
+	write_named_array("TAG_LIST", wasm.tag_space(), w)?;
+	write_named_array("ELEMENT_LIST", wasm.element_section().len(), w)?;
+	write_named_array("DATA_LIST", wasm.data_section().len(), w)?;
+
+	write_func_list(wasm, &func_list, name_list, source_map, w)?;
+	write_module_start(wasm, type_info, &mem_set, name_list, w)
+}
+
+/// # Errors
+/// Returns `Err` if the module could not be translated or writing to `Write` failed.
+pub fn from_module_typed(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	settings: &Settings,
+	w: &mut dyn Write,
+) -> Result<()> {
+	write_module(wasm, type_info, settings, &Rc::default(), None, w)
+}
+
+/// Like `from_module_typed`, but names from the name section are
+/// written as identifiers in place of numbered functions, tables, memories,
+/// globals and locals.
+/// Names of any other kind are ignored.
+///
+/// # Errors
+/// Returns `Err` if the module could not be translated or writing to `Write` failed.
+pub fn from_module_named(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	settings: &Settings,
+	w: &mut dyn Write,
+) -> Result<()> {
+	let name_list = Rc::new(NameList::from_module(wasm));
+
+	write_module(wasm, type_info, settings, &name_list, None, w)
+}
+
+fn write_module_mapped(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	settings: &Settings,
+	name_list: &Rc<NameList>,
+	source_map: &mut SourceMap,
+	w: &mut dyn Write,
+) -> Result<()> {
+	let shared = Rc::new(RefCell::new(std::mem::take(source_map)));
+	let counter = &mut LineCounter::new(w, shared.clone());
+	let result = write_module(wasm, type_info, settings, name_list, Some(&shared), counter);
+
+	*source_map = shared.take();
+
+	result
+}
+
+/// Like `from_module_typed`, but every statement also maps the line it is
+/// written on to its code offset. Lines are counted on from where
+/// `source_map` left off, so text written before the module can be included.
+///
+/// # Errors
+/// Returns `Err` if the module could not be translated or writing to `Write` failed.
+pub fn from_module_typed_mapped(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	settings: &Settings,
+	source_map: &mut SourceMap,
+	w: &mut dyn Write,
+) -> Result<()> {
+	write_module_mapped(wasm, type_info, settings, &Rc::default(), source_map, w)
+}
 
-	write_func_list(wasm, &func_list, w)?;
-	write_module_start(wasm, type_info, &mem_set, w)
+/// Like `from_module_named`, but also fills in `source_map` in the same way
+/// as `from_module_typed_mapped`.
+///
+/// # Errors
+/// Returns `Err` if the module could not be translated or writing to `Write` failed.
+pub fn from_module_named_mapped(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	settings: &Settings,
+	source_map: &mut SourceMap,
+	w: &mut dyn Write,
+) -> Result<()> {
+	let name_list = Rc::new(NameList::from_module(wasm));
+
+	write_module_mapped(wasm, type_info, settings, &name_list, source_map, w)
+}
+
+/// Writes the AST of every function in the module as text, exactly as it
+/// would be handed to the backend.
+///
+/// # Errors
+/// Returns `Err` if the module could not be translated or writing to `Write` failed.
+pub fn write_ast(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	settings: &Settings,
+	w: &mut dyn Write,
+) -> Result<()> {
+	let offset = wasm.import_count(External::Func);
+
+	for (i, v) in build_func_list(wasm, type_info, settings)?
+		.iter()
+		.enumerate()
+	{
+		writeln!(w, "; function {}", i + offset)?;
+		v.print(&mut Printer::default(), w)?;
+		writeln!(w)?;
+	}
+
+	Ok(())
+}
+
+/// Writes the control flow graph of every function in the module in the
+/// DOT format, with one `digraph` per function.
+///
+/// # Errors
+/// Returns `Err` if the module could not be translated or writing to `Write` failed.
+pub fn write_cfg(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	settings: &Settings,
+	w: &mut dyn Write,
+) -> Result<()> {
+	let offset = wasm.import_count(External::Func);
+
+	for (i, v) in build_func_list(wasm, type_info, settings)?
+		.iter()
+		.enumerate()
+	{
+		Cfg::from_func(v).write_dot(&format!("function {}", i + offset), w)?;
+	}
+
+	Ok(())
+}
+
+/// Writes the module back out as a wasm binary, with every function body
+/// rebuilt from the same AST that would be handed to the backend.
+///
+/// # Errors
+/// Returns `Err` if the module could not be translated or writing to `Write` failed.
+pub fn write_wasm(
+	wasm: &Module,
+	type_info: &TypeInfo,
+	settings: &Settings,
+	w: &mut dyn Write,
+) -> Result<()> {
+	let func_list = build_func_list(wasm, type_info, settings)?;
+
+	w.write_all(&encode_module(wasm, &func_list))?;
+
+	Ok(())
 }
 
 /// # Errors
-/// Returns `Err` if writing to `Write` failed.
+/// Returns `Err` if the module could not be translated or writing to `Write` failed.
 pub fn from_module_untyped(wasm: &Module, w: &mut dyn Write) -> Result<()> {
 	let type_info = TypeInfo::from_module(wasm);
 
-	from_module_typed(wasm, &type_info, w)
+	from_module_typed(wasm, &type_info, &default_settings(), w)
 }
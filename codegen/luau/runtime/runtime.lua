@@ -150,10 +150,15 @@ do
 		local result = math_round(num)
 
 		if (math_abs(num) + 0.5) % 2 == 1 then
-			return result - math_sign(result)
-		else
-			return result
+			result = result - math_sign(result)
+		end
+
+		-- Rounding to zero keeps the sign of the input
+		if result == 0 then
+			result = num * 0
 		end
+
+		return result
 	end
 
 	neg.f64 = neg.f32
@@ -795,6 +800,15 @@ do
 		buffer_fill(memory.data, addr, value, len)
 	end
 
+	function store.init(memory, addr, data, offset, len)
+		-- Dropped segments behave as if they were empty
+		data = data or ""
+
+		assert(offset + len <= #data, "out of bounds memory access")
+
+		buffer_copy(memory.data, addr, buffer_from_string(data), offset, len)
+	end
+
 	local WASM_PAGE_SIZE = 65536
 
 	function allocator.new(min, max)
@@ -827,4 +841,1165 @@ do
 	module.allocator = allocator
 end
 
+do
+	local atomic = {}
+
+	local num_is_equal = Integer.is_equal
+	local num_is_negative = Integer.is_negative
+
+	-- Nothing else can change memory while we wait, so a matching value
+	-- would block forever or until it times out. Hosts can set the
+	-- `wait_hook` to trap or yield instead, returning the wait result
+	local function wait_for(timeout)
+		local hook = atomic.wait_hook
+
+		if hook then
+			return hook(timeout)
+		elseif num_is_negative(timeout) then
+			error("wait would block forever")
+		else
+			return 2
+		end
+	end
+
+	function atomic.wait_i32(value, expected, timeout)
+		if value ~= expected then
+			return 1
+		end
+
+		return wait_for(timeout)
+	end
+
+	function atomic.wait_i64(value, expected, timeout)
+		if not num_is_equal(value, expected) then
+			return 1
+		end
+
+		return wait_for(timeout)
+	end
+
+	local atomic_load = {}
+	local atomic_store = {}
+
+	local load = module.load
+	local store = module.store
+
+	-- Atomic accesses trap when the address is not a multiple of their
+	-- size, while single bytes are always aligned
+	local function new_aligned(func, size)
+		return function(memory, addr, value)
+			if addr % size ~= 0 then
+				error("unaligned atomic")
+			end
+
+			return func(memory, addr, value)
+		end
+	end
+
+	atomic_load.i32 = new_aligned(load.i32, 4)
+	atomic_load.i64 = new_aligned(load.i64, 8)
+	atomic_load.i32_u8 = load.i32_u8
+	atomic_load.i32_u16 = new_aligned(load.i32_u16, 2)
+	atomic_load.i64_u8 = load.i64_u8
+	atomic_load.i64_u16 = new_aligned(load.i64_u16, 2)
+	atomic_load.i64_u32 = new_aligned(load.i64_u32, 4)
+
+	atomic_store.i32 = new_aligned(store.i32, 4)
+	atomic_store.i64 = new_aligned(store.i64, 8)
+	atomic_store.i32_n8 = store.i32_n8
+	atomic_store.i32_n16 = new_aligned(store.i32_n16, 2)
+	atomic_store.i64_n8 = store.i64_n8
+	atomic_store.i64_n16 = new_aligned(store.i64_n16, 2)
+	atomic_store.i64_n32 = new_aligned(store.i64_n32, 4)
+
+	module.atomic = atomic
+	module.atomic_load = atomic_load
+	module.atomic_store = atomic_store
+end
+
+do
+	local tbl = {}
+
+	local table_move = table.move
+
+	-- Dropped segments behave as if they were empty
+	local EMPTY_ELEMENT = { n = 0 }
+
+	local function assert_in_bounds(target, index, len)
+		assert(index + len <= target.min, "out of bounds table access")
+	end
+
+	function tbl.get(target, index)
+		assert_in_bounds(target, index, 1)
+
+		return target.data[index]
+	end
+
+	function tbl.set(target, index, value)
+		assert_in_bounds(target, index, 1)
+
+		target.data[index] = value
+	end
+
+	function tbl.size(target)
+		return target.min
+	end
+
+	function tbl.fill(target, index, value, len)
+		assert_in_bounds(target, index, len)
+
+		local data = target.data
+
+		for i = index, index + len - 1 do
+			data[i] = value
+		end
+	end
+
+	function tbl.grow(target, value, num)
+		local old = target.min
+		local new = old + num
+
+		if new <= target.max then
+			-- Empty slots are already `nil` so they only need filling with real values
+			if value ~= nil then
+				tbl.fill(target, old, value, num)
+			end
+
+			target.min = new
+
+			return old
+		else
+			return 0xFFFFFFFF
+		end
+	end
+
+	function tbl.copy(target_1, index_1, target_2, index_2, len)
+		assert_in_bounds(target_1, index_1, len)
+		assert_in_bounds(target_2, index_2, len)
+
+		table_move(target_2.data, index_2, index_2 + len - 1, index_1, target_1.data)
+	end
+
+	function tbl.init(target, index, element, offset, len)
+		element = element or EMPTY_ELEMENT
+
+		assert(offset + len <= element.n, "out of bounds table access")
+		assert_in_bounds(target, index, len)
+
+		table_move(element, offset + 1, offset + len, index, target.data)
+	end
+
+	module.table = tbl
+end
+
+do
+	local exception = {}
+
+	local table_unpack = table.unpack
+
+	-- Traps are raised as strings, so a dedicated metatable keeps
+	-- exceptions from ever being mistaken for one
+	local EXCEPTION_META = {
+		__tostring = function()
+			return "uncaught exception"
+		end,
+	}
+
+	function exception.new(tag, ...)
+		return setmetatable({ tag = tag, n = select("#", ...), ... }, EXCEPTION_META)
+	end
+
+	function exception.throw(tag, ...)
+		error(exception.new(tag, ...), 0)
+	end
+
+	function exception.is(data)
+		return getmetatable(data) == EXCEPTION_META
+	end
+
+	function exception.matches(data, tag)
+		return exception.is(data) and data.tag == tag
+	end
+
+	function exception.unpack(data)
+		return table_unpack(data, 1, data.n)
+	end
+
+	-- `delegate` wraps exceptions with the level of the `try` that should
+	-- handle them, so the handlers nested inside of it pass them on
+	local DELEGATE_META = {}
+
+	function exception.delegate(data, level)
+		if not exception.is(data) then
+			return data
+		end
+
+		return setmetatable({ data = data, level = level }, DELEGATE_META)
+	end
+
+	function exception.resolve(data, level)
+		if getmetatable(data) == DELEGATE_META and data.level >= level then
+			return data.data
+		end
+
+		return data
+	end
+
+	module.exception = exception
+end
+
+do
+	local tail = {}
+
+	local table_pack = table.pack
+	local table_unpack = table.unpack
+
+	-- Luau does not reuse stack frames for `return f()`, so functions with
+	-- tail calls return `CALL` followed by the callee and its arguments
+	-- instead, which `run` keeps calling in a loop until a call returns
+	local CALL = newproxy()
+	local BODY_LIST = setmetatable({}, { __mode = "k" })
+
+	local function run(body, ...)
+		local result = table_pack(body(...))
+
+		while result[1] == CALL do
+			local func = result[2]
+			local next = BODY_LIST[func]
+
+			if next == nil then
+				return func(table_unpack(result, 3, result.n))
+			end
+
+			result = table_pack(next(table_unpack(result, 3, result.n)))
+		end
+
+		return table_unpack(result, 1, result.n)
+	end
+
+	function tail.new(body)
+		local function func(...)
+			return run(body, ...)
+		end
+
+		BODY_LIST[func] = body
+
+		return func
+	end
+
+	tail.CALL = CALL
+
+	module.tail = tail
+end
+
+do
+	local v128 = {}
+	local splat = {}
+	local extract_lane = {}
+	local replace_lane = {}
+	local shuffle = {}
+	local swizzle = {}
+	local bitselect = {}
+	local bandnot = {}
+	local any_true = {}
+	local all_true = {}
+	local bitmask = {}
+	local abs = {}
+	local ceil = {}
+	local floor = {}
+	local sqrt = {}
+	local pmin = {}
+	local pmax = {}
+	local add_sat = {}
+	local sub_sat = {}
+	local avgr = {}
+	local q15mulr_sat = {}
+	local dot = {}
+	local narrow = {}
+	local extend_low = {}
+	local extend_high = {}
+	local extadd_pairwise = {}
+	local extmul_low = {}
+	local extmul_high = {}
+	local saturate_zero = {}
+	local convert_low = {}
+	local demote_zero = {}
+	local promote_low = {}
+
+	local add = module.add
+	local sub = module.sub
+	local mul = module.mul
+	local div = module.div
+	local neg = module.neg
+	local min = module.min
+	local max = module.max
+	local nearest = module.nearest
+	local popcnt = module.popcnt
+	local eq = module.eq
+	local ne = module.ne
+	local le = module.le
+	local lt = module.lt
+	local ge = module.ge
+	local gt = module.gt
+	local band = module.band
+	local bor = module.bor
+	local bxor = module.bxor
+	local bnot = module.bnot
+	local shl = module.shl
+	local shr = module.shr
+	local truncate = module.truncate
+	local saturate = module.saturate
+	local convert = module.convert
+	local load = module.load
+	local store = module.store
+
+	local bit_not = bit32.bnot
+	local bit_arshift = bit32.arshift
+
+	local math_abs = math.abs
+	local math_ceil = math.ceil
+	local math_floor = math.floor
+	local math_sqrt = math.sqrt
+	local math_min = math.min
+	local math_max = math.max
+	local math_clamp = math.clamp
+
+	local buffer_create = buffer.create
+	local buffer_copy = buffer.copy
+	local buffer_fill = buffer.fill
+
+	local buffer_read_i8 = buffer.readi8
+	local buffer_read_u8 = buffer.readu8
+	local buffer_read_i16 = buffer.readi16
+	local buffer_read_u16 = buffer.readu16
+	local buffer_read_i32 = buffer.readi32
+	local buffer_read_u32 = buffer.readu32
+	local buffer_read_f32 = buffer.readf32
+	local buffer_read_f64 = buffer.readf64
+
+	local buffer_write_u8 = buffer.writeu8
+	local buffer_write_u16 = buffer.writeu16
+	local buffer_write_u32 = buffer.writeu32
+	local buffer_write_f32 = buffer.writef32
+	local buffer_write_f64 = buffer.writef64
+
+	local num_negate = Integer.negate
+	local num_is_negative = Integer.is_negative
+	local num_is_equal = Integer.is_equal
+
+	local function read_i64(data, offset)
+		return num_from_u32(buffer_read_u32(data, offset), buffer_read_u32(data, offset + 4))
+	end
+
+	local function write_i64(data, offset, value)
+		local data_1, data_2 = num_into_u32(value)
+
+		buffer_write_u32(data, offset, data_1)
+		buffer_write_u32(data, offset + 4, data_2)
+	end
+
+	local function read_i64_high(data, offset)
+		return buffer_read_i32(data, offset + 4)
+	end
+
+	-- Vectors are immutable 16 byte buffers, so every operation creates a new one.
+	-- Integer lanes are written with wrapping so signed and unsigned readers
+	-- can share the same writer.
+	local LANE_I8 = { count = 16, size = 1, read = buffer_read_i8, write = buffer_write_u8 }
+	local LANE_U8 = { count = 16, size = 1, read = buffer_read_u8, write = buffer_write_u8 }
+	local LANE_I16 = { count = 8, size = 2, read = buffer_read_i16, write = buffer_write_u16 }
+	local LANE_U16 = { count = 8, size = 2, read = buffer_read_u16, write = buffer_write_u16 }
+	local LANE_I32 = { count = 4, size = 4, read = buffer_read_i32, write = buffer_write_u32 }
+	local LANE_U32 = { count = 4, size = 4, read = buffer_read_u32, write = buffer_write_u32 }
+	local LANE_I64 = { count = 2, size = 8, read = read_i64, write = write_i64 }
+	local LANE_F32 = { count = 4, size = 4, read = buffer_read_f32, write = buffer_write_f32 }
+	local LANE_F64 = { count = 2, size = 8, read = buffer_read_f64, write = buffer_write_f64 }
+
+	local function map_unary(lane, func)
+		local size, read, write = lane.size, lane.read, lane.write
+		local last = 16 - size
+
+		return function(rhs)
+			local result = buffer_create(16)
+
+			for i = 0, last, size do
+				write(result, i, func(read(rhs, i)))
+			end
+
+			return result
+		end
+	end
+
+	local function map_binary(lane, func)
+		local size, read, write = lane.size, lane.read, lane.write
+		local last = 16 - size
+
+		return function(lhs, rhs)
+			local result = buffer_create(16)
+
+			for i = 0, last, size do
+				write(result, i, func(read(lhs, i), read(rhs, i)))
+			end
+
+			return result
+		end
+	end
+
+	local function map_compare(lane, func)
+		local size, read = lane.size, lane.read
+		local last = 16 - size
+
+		return function(lhs, rhs)
+			local result = buffer_create(16)
+
+			for i = 0, last, size do
+				if func(read(lhs, i), read(rhs, i)) then
+					buffer_fill(result, i, 0xFF, size)
+				end
+			end
+
+			return result
+		end
+	end
+
+	local function map_shift(lane, bits, func)
+		local size, read, write = lane.size, lane.read, lane.write
+		local last = 16 - size
+
+		return function(lhs, rhs)
+			local result = buffer_create(16)
+			local count = rhs % bits
+
+			for i = 0, last, size do
+				write(result, i, func(read(lhs, i), count))
+			end
+
+			return result
+		end
+	end
+
+	-- Converts `count` lanes starting at lane `start`, leaving the rest zeroed
+	local function map_convert(lane_in, lane_out, start, count, func)
+		local size_in, read = lane_in.size, lane_in.read
+		local size_out, write = lane_out.size, lane_out.write
+
+		return function(rhs)
+			local result = buffer_create(16)
+
+			for i = 0, count - 1 do
+				write(result, i * size_out, func(read(rhs, (start + i) * size_in)))
+			end
+
+			return result
+		end
+	end
+
+	local function map_narrow(lane_in, lane_out, lower, upper)
+		local size_in, read = lane_in.size, lane_in.read
+		local size_out, write = lane_out.size, lane_out.write
+		local count = lane_in.count
+
+		return function(lhs, rhs)
+			local result = buffer_create(16)
+
+			for i = 0, count - 1 do
+				local value_1 = math_clamp(read(lhs, i * size_in), lower, upper)
+				local value_2 = math_clamp(read(rhs, i * size_in), lower, upper)
+
+				write(result, i * size_out, value_1)
+				write(result, (i + count) * size_out, value_2)
+			end
+
+			return result
+		end
+	end
+
+	local function map_pairwise(lane_in, lane_out)
+		local size_in, read = lane_in.size, lane_in.read
+		local size_out, write = lane_out.size, lane_out.write
+
+		return function(rhs)
+			local result = buffer_create(16)
+
+			for i = 0, lane_out.count - 1 do
+				local offset = i * 2 * size_in
+
+				write(result, i * size_out, read(rhs, offset) + read(rhs, offset + size_in))
+			end
+
+			return result
+		end
+	end
+
+	local function map_float_sign(size, func)
+		return function(rhs)
+			local result = buffer_create(16)
+
+			buffer_copy(result, 0, rhs)
+
+			for i = size - 1, 15, size do
+				buffer_write_u8(result, i, func(buffer_read_u8(rhs, i)))
+			end
+
+			return result
+		end
+	end
+
+	local function new_splat(lane)
+		local size, write = lane.size, lane.write
+		local last = 16 - size
+
+		return function(value)
+			local result = buffer_create(16)
+
+			for i = 0, last, size do
+				write(result, i, value)
+			end
+
+			return result
+		end
+	end
+
+	local function new_extract_lane(lane)
+		local size, read = lane.size, lane.read
+
+		return function(vector, index)
+			return read(vector, index * size)
+		end
+	end
+
+	local function new_replace_lane(lane)
+		local size, write = lane.size, lane.write
+
+		return function(vector, index, value)
+			local result = buffer_create(16)
+
+			buffer_copy(result, 0, vector)
+			write(result, index * size, value)
+
+			return result
+		end
+	end
+
+	local function new_all_true(lane)
+		local size, read = lane.size, lane.read
+		local last = 16 - size
+
+		return function(rhs)
+			for i = 0, last, size do
+				if read(rhs, i) == 0 then
+					return 0
+				end
+			end
+
+			return 1
+		end
+	end
+
+	local function new_bitmask(lane)
+		local size, read = lane.size, lane.read
+
+		return function(rhs)
+			local result = 0
+
+			for i = 0, lane.count - 1 do
+				if read(rhs, i * size) < 0 then
+					result = result + 2 ^ i
+				end
+			end
+
+			return result
+		end
+	end
+
+	local function num_from_i32(value)
+		if value < 0 then
+			return num_from_u32(value + 0x100000000, 0xFFFFFFFF)
+		else
+			return num_from_u32(value, 0)
+		end
+	end
+
+	local function num_from_u32_lane(value)
+		return num_from_u32(value, 0)
+	end
+
+	local function add_number(lhs, rhs)
+		return lhs + rhs
+	end
+
+	local function sub_number(lhs, rhs)
+		return lhs - rhs
+	end
+
+	local function mul_number(lhs, rhs)
+		return lhs * rhs
+	end
+
+	local function neg_number(rhs)
+		return -rhs
+	end
+
+	local function is_equal(lhs, rhs)
+		return lhs == rhs
+	end
+
+	local function is_not_equal(lhs, rhs)
+		return lhs ~= rhs
+	end
+
+	local function is_less(lhs, rhs)
+		return lhs < rhs
+	end
+
+	local function is_less_equal(lhs, rhs)
+		return lhs <= rhs
+	end
+
+	local function is_greater(lhs, rhs)
+		return lhs > rhs
+	end
+
+	local function is_greater_equal(lhs, rhs)
+		return lhs >= rhs
+	end
+
+	local function shift_left(lhs, rhs)
+		return lhs * 2 ^ rhs
+	end
+
+	local function shift_right(lhs, rhs)
+		return math_floor(lhs / 2 ^ rhs)
+	end
+
+	local function truncate_float(num)
+		if num >= 0 then
+			return math_floor(num)
+		else
+			return math_ceil(num)
+		end
+	end
+
+	-- Follows the WebAssembly rules for NaN propagation and signed zeroes
+	local function min_float(lhs, rhs)
+		if lhs ~= lhs or rhs ~= rhs then
+			return lhs + rhs
+		elseif lhs == rhs then
+			return if 1 / lhs < 0 then lhs else rhs
+		else
+			return math_min(lhs, rhs)
+		end
+	end
+
+	local function max_float(lhs, rhs)
+		if lhs ~= lhs or rhs ~= rhs then
+			return lhs + rhs
+		elseif lhs == rhs then
+			return if 1 / lhs > 0 then lhs else rhs
+		else
+			return math_max(lhs, rhs)
+		end
+	end
+
+	local function saturate_i32(num)
+		if num ~= num then
+			return 0
+		end
+
+		return math_clamp(truncate_float(num), -0x80000000, 0x7FFFFFFF)
+	end
+
+	local function saturate_u32(num)
+		if num ~= num then
+			return 0
+		end
+
+		return math_clamp(truncate_float(num), 0, 0xFFFFFFFF)
+	end
+
+	local function popcnt_u8(num)
+		local result = 0
+
+		while num ~= 0 do
+			result = result + bit_and(num, 1)
+			num = bit_rshift(num, 1)
+		end
+
+		return result
+	end
+
+	function v128.from_u32(data_1, data_2, data_3, data_4)
+		local result = buffer_create(16)
+
+		buffer_write_u32(result, 0, data_1)
+		buffer_write_u32(result, 4, data_2)
+		buffer_write_u32(result, 8, data_3)
+		buffer_write_u32(result, 12, data_4)
+
+		return result
+	end
+
+	v128.ZERO = buffer_create(16)
+
+	splat.i8x16 = new_splat(LANE_U8)
+	splat.i16x8 = new_splat(LANE_U16)
+	splat.i32x4 = new_splat(LANE_U32)
+	splat.i64x2 = new_splat(LANE_I64)
+	splat.f32x4 = new_splat(LANE_F32)
+	splat.f64x2 = new_splat(LANE_F64)
+
+	function extract_lane.i8x16(vector, index)
+		return bit_or(buffer_read_i8(vector, index), 0)
+	end
+
+	function extract_lane.i16x8(vector, index)
+		return bit_or(buffer_read_i16(vector, index * 2), 0)
+	end
+
+	extract_lane.u8x16 = new_extract_lane(LANE_U8)
+	extract_lane.u16x8 = new_extract_lane(LANE_U16)
+	extract_lane.i32x4 = new_extract_lane(LANE_U32)
+	extract_lane.i64x2 = new_extract_lane(LANE_I64)
+	extract_lane.f32x4 = new_extract_lane(LANE_F32)
+	extract_lane.f64x2 = new_extract_lane(LANE_F64)
+
+	replace_lane.i8x16 = new_replace_lane(LANE_U8)
+	replace_lane.i16x8 = new_replace_lane(LANE_U16)
+	replace_lane.i32x4 = new_replace_lane(LANE_U32)
+	replace_lane.i64x2 = new_replace_lane(LANE_I64)
+	replace_lane.f32x4 = new_replace_lane(LANE_F32)
+	replace_lane.f64x2 = new_replace_lane(LANE_F64)
+
+	function shuffle.i8x16(lhs, rhs, ...)
+		local result = buffer_create(16)
+
+		for i = 0, 15 do
+			local index = select(i + 1, ...)
+
+			if index < 16 then
+				buffer_write_u8(result, i, buffer_read_u8(lhs, index))
+			else
+				buffer_write_u8(result, i, buffer_read_u8(rhs, index - 16))
+			end
+		end
+
+		return result
+	end
+
+	function swizzle.i8x16(lhs, rhs)
+		local result = buffer_create(16)
+
+		for i = 0, 15 do
+			local index = buffer_read_u8(rhs, i)
+
+			if index < 16 then
+				buffer_write_u8(result, i, buffer_read_u8(lhs, index))
+			end
+		end
+
+		return result
+	end
+
+	function bitselect.v128(on_true, on_false, condition)
+		local result = buffer_create(16)
+
+		for i = 0, 12, 4 do
+			local mask = buffer_read_u32(condition, i)
+			local value_1 = bit_and(buffer_read_u32(on_true, i), mask)
+			local value_2 = bit_and(buffer_read_u32(on_false, i), bit_not(mask))
+
+			buffer_write_u32(result, i, bit_or(value_1, value_2))
+		end
+
+		return result
+	end
+
+	function any_true.v128(rhs)
+		for i = 0, 12, 4 do
+			if buffer_read_u32(rhs, i) ~= 0 then
+				return 1
+			end
+		end
+
+		return 0
+	end
+
+	band.v128 = map_binary(LANE_U32, bit_and)
+	bor.v128 = map_binary(LANE_U32, bit_or)
+	bxor.v128 = map_binary(LANE_U32, bit_xor)
+	bnot.v128 = map_unary(LANE_U32, bit_not)
+
+	bandnot.v128 = map_binary(LANE_U32, function(lhs, rhs)
+		return bit_and(lhs, bit_not(rhs))
+	end)
+
+	all_true.i8x16 = new_all_true(LANE_U8)
+	all_true.i16x8 = new_all_true(LANE_U16)
+	all_true.i32x4 = new_all_true(LANE_U32)
+
+	function all_true.i64x2(rhs)
+		for i = 0, 8, 8 do
+			if buffer_read_u32(rhs, i) == 0 and buffer_read_u32(rhs, i + 4) == 0 then
+				return 0
+			end
+		end
+
+		return 1
+	end
+
+	bitmask.i8x16 = new_bitmask(LANE_I8)
+	bitmask.i16x8 = new_bitmask(LANE_I16)
+	bitmask.i32x4 = new_bitmask(LANE_I32)
+	bitmask.i64x2 = new_bitmask({ count = 2, size = 8, read = read_i64_high })
+
+	popcnt.i8x16 = map_unary(LANE_U8, popcnt_u8)
+
+	abs.i8x16 = map_unary(LANE_I8, math_abs)
+	abs.i16x8 = map_unary(LANE_I16, math_abs)
+	abs.i32x4 = map_unary(LANE_I32, math_abs)
+
+	abs.i64x2 = map_unary(LANE_I64, function(num)
+		if num_is_negative(num) then
+			return num_negate(num)
+		else
+			return num
+		end
+	end)
+
+	neg.i8x16 = map_unary(LANE_I8, neg_number)
+	neg.i16x8 = map_unary(LANE_I16, neg_number)
+	neg.i32x4 = map_unary(LANE_I32, neg_number)
+	neg.i64x2 = map_unary(LANE_I64, num_negate)
+
+	-- Float signs are flipped directly so NaN payloads are kept intact
+	abs.f32x4 = map_float_sign(4, function(num)
+		return bit_and(num, 0x7F)
+	end)
+
+	neg.f32x4 = map_float_sign(4, function(num)
+		return bit_xor(num, 0x80)
+	end)
+
+	abs.f64x2 = map_float_sign(8, function(num)
+		return bit_and(num, 0x7F)
+	end)
+
+	neg.f64x2 = map_float_sign(8, function(num)
+		return bit_xor(num, 0x80)
+	end)
+
+	add.i8x16 = map_binary(LANE_I8, add_number)
+	add.i16x8 = map_binary(LANE_I16, add_number)
+	add.i32x4 = map_binary(LANE_I32, add_number)
+	add.i64x2 = map_binary(LANE_I64, add.i64)
+
+	sub.i8x16 = map_binary(LANE_I8, sub_number)
+	sub.i16x8 = map_binary(LANE_I16, sub_number)
+	sub.i32x4 = map_binary(LANE_I32, sub_number)
+	sub.i64x2 = map_binary(LANE_I64, sub.i64)
+
+	mul.i16x8 = map_binary(LANE_I16, mul_number)
+	mul.i32x4 = map_binary(LANE_U32, mul.i32)
+	mul.i64x2 = map_binary(LANE_I64, mul.i64)
+
+	add_sat.i8x16 = map_binary(LANE_I8, function(lhs, rhs)
+		return math_clamp(lhs + rhs, -0x80, 0x7F)
+	end)
+
+	add_sat.u8x16 = map_binary(LANE_U8, function(lhs, rhs)
+		return math_min(lhs + rhs, 0xFF)
+	end)
+
+	add_sat.i16x8 = map_binary(LANE_I16, function(lhs, rhs)
+		return math_clamp(lhs + rhs, -0x8000, 0x7FFF)
+	end)
+
+	add_sat.u16x8 = map_binary(LANE_U16, function(lhs, rhs)
+		return math_min(lhs + rhs, 0xFFFF)
+	end)
+
+	sub_sat.i8x16 = map_binary(LANE_I8, function(lhs, rhs)
+		return math_clamp(lhs - rhs, -0x80, 0x7F)
+	end)
+
+	sub_sat.u8x16 = map_binary(LANE_U8, function(lhs, rhs)
+		return math_max(lhs - rhs, 0)
+	end)
+
+	sub_sat.i16x8 = map_binary(LANE_I16, function(lhs, rhs)
+		return math_clamp(lhs - rhs, -0x8000, 0x7FFF)
+	end)
+
+	sub_sat.u16x8 = map_binary(LANE_U16, function(lhs, rhs)
+		return math_max(lhs - rhs, 0)
+	end)
+
+	min.i8x16 = map_binary(LANE_I8, math_min)
+	min.u8x16 = map_binary(LANE_U8, math_min)
+	min.i16x8 = map_binary(LANE_I16, math_min)
+	min.u16x8 = map_binary(LANE_U16, math_min)
+	min.i32x4 = map_binary(LANE_I32, math_min)
+	min.u32x4 = map_binary(LANE_U32, math_min)
+
+	max.i8x16 = map_binary(LANE_I8, math_max)
+	max.u8x16 = map_binary(LANE_U8, math_max)
+	max.i16x8 = map_binary(LANE_I16, math_max)
+	max.u16x8 = map_binary(LANE_U16, math_max)
+	max.i32x4 = map_binary(LANE_I32, math_max)
+	max.u32x4 = map_binary(LANE_U32, math_max)
+
+	local function rounding_average(lhs, rhs)
+		return math_floor((lhs + rhs + 1) / 2)
+	end
+
+	avgr.u8x16 = map_binary(LANE_U8, rounding_average)
+	avgr.u16x8 = map_binary(LANE_U16, rounding_average)
+
+	q15mulr_sat.i16x8 = map_binary(LANE_I16, function(lhs, rhs)
+		return math_clamp(math_floor((lhs * rhs + 0x4000) / 0x8000), -0x8000, 0x7FFF)
+	end)
+
+	function dot.i32x4_i16x8(lhs, rhs)
+		local result = buffer_create(16)
+
+		for i = 0, 12, 4 do
+			local value_1 = buffer_read_i16(lhs, i) * buffer_read_i16(rhs, i)
+			local value_2 = buffer_read_i16(lhs, i + 2) * buffer_read_i16(rhs, i + 2)
+
+			buffer_write_u32(result, i, value_1 + value_2)
+		end
+
+		return result
+	end
+
+	shl.i8x16 = map_shift(LANE_U8, 8, shift_left)
+	shl.i16x8 = map_shift(LANE_U16, 16, shift_left)
+	shl.i32x4 = map_shift(LANE_U32, 32, bit_lshift)
+
+	shr.i8x16 = map_shift(LANE_I8, 8, shift_right)
+	shr.u8x16 = map_shift(LANE_U8, 8, shift_right)
+	shr.i16x8 = map_shift(LANE_I16, 16, shift_right)
+	shr.u16x8 = map_shift(LANE_U16, 16, shift_right)
+	shr.i32x4 = map_shift(LANE_U32, 32, bit_arshift)
+	shr.u32x4 = map_shift(LANE_U32, 32, bit_rshift)
+
+	shl.i64x2 = map_shift(LANE_I64, 64, function(lhs, rhs)
+		return shl.i64(lhs, num_from_u32(rhs, 0))
+	end)
+
+	shr.i64x2 = map_shift(LANE_I64, 64, function(lhs, rhs)
+		return shr.i64(lhs, num_from_u32(rhs, 0))
+	end)
+
+	shr.u64x2 = map_shift(LANE_I64, 64, function(lhs, rhs)
+		return shr.u64(lhs, num_from_u32(rhs, 0))
+	end)
+
+	eq.i8x16 = map_compare(LANE_U8, is_equal)
+	eq.i16x8 = map_compare(LANE_U16, is_equal)
+	eq.i32x4 = map_compare(LANE_U32, is_equal)
+	eq.i64x2 = map_compare(LANE_I64, num_is_equal)
+	eq.f32x4 = map_compare(LANE_F32, is_equal)
+	eq.f64x2 = map_compare(LANE_F64, is_equal)
+
+	ne.i8x16 = map_compare(LANE_U8, is_not_equal)
+	ne.i16x8 = map_compare(LANE_U16, is_not_equal)
+	ne.i32x4 = map_compare(LANE_U32, is_not_equal)
+	ne.i64x2 = map_compare(LANE_I64, ne.i64)
+	ne.f32x4 = map_compare(LANE_F32, is_not_equal)
+	ne.f64x2 = map_compare(LANE_F64, is_not_equal)
+
+	lt.i8x16 = map_compare(LANE_I8, is_less)
+	lt.u8x16 = map_compare(LANE_U8, is_less)
+	lt.i16x8 = map_compare(LANE_I16, is_less)
+	lt.u16x8 = map_compare(LANE_U16, is_less)
+	lt.i32x4 = map_compare(LANE_I32, is_less)
+	lt.u32x4 = map_compare(LANE_U32, is_less)
+	lt.i64x2 = map_compare(LANE_I64, lt.i64)
+	lt.f32x4 = map_compare(LANE_F32, is_less)
+	lt.f64x2 = map_compare(LANE_F64, is_less)
+
+	le.i8x16 = map_compare(LANE_I8, is_less_equal)
+	le.u8x16 = map_compare(LANE_U8, is_less_equal)
+	le.i16x8 = map_compare(LANE_I16, is_less_equal)
+	le.u16x8 = map_compare(LANE_U16, is_less_equal)
+	le.i32x4 = map_compare(LANE_I32, is_less_equal)
+	le.u32x4 = map_compare(LANE_U32, is_less_equal)
+	le.i64x2 = map_compare(LANE_I64, le.i64)
+	le.f32x4 = map_compare(LANE_F32, is_less_equal)
+	le.f64x2 = map_compare(LANE_F64, is_less_equal)
+
+	gt.i8x16 = map_compare(LANE_I8, is_greater)
+	gt.u8x16 = map_compare(LANE_U8, is_greater)
+	gt.i16x8 = map_compare(LANE_I16, is_greater)
+	gt.u16x8 = map_compare(LANE_U16, is_greater)
+	gt.i32x4 = map_compare(LANE_I32, is_greater)
+	gt.u32x4 = map_compare(LANE_U32, is_greater)
+	gt.i64x2 = map_compare(LANE_I64, gt.i64)
+	gt.f32x4 = map_compare(LANE_F32, is_greater)
+	gt.f64x2 = map_compare(LANE_F64, is_greater)
+
+	ge.i8x16 = map_compare(LANE_I8, is_greater_equal)
+	ge.u8x16 = map_compare(LANE_U8, is_greater_equal)
+	ge.i16x8 = map_compare(LANE_I16, is_greater_equal)
+	ge.u16x8 = map_compare(LANE_U16, is_greater_equal)
+	ge.i32x4 = map_compare(LANE_I32, is_greater_equal)
+	ge.u32x4 = map_compare(LANE_U32, is_greater_equal)
+	ge.i64x2 = map_compare(LANE_I64, ge.i64)
+	ge.f32x4 = map_compare(LANE_F32, is_greater_equal)
+	ge.f64x2 = map_compare(LANE_F64, is_greater_equal)
+
+	narrow.i8x16_i16x8 = map_narrow(LANE_I16, LANE_I8, -0x80, 0x7F)
+	narrow.u8x16_i16x8 = map_narrow(LANE_I16, LANE_U8, 0, 0xFF)
+	narrow.i16x8_i32x4 = map_narrow(LANE_I32, LANE_I16, -0x8000, 0x7FFF)
+	narrow.u16x8_i32x4 = map_narrow(LANE_I32, LANE_U16, 0, 0xFFFF)
+
+	extend_low.i16x8_i8x16 = map_convert(LANE_I8, LANE_I16, 0, 8, no_op)
+	extend_low.i16x8_u8x16 = map_convert(LANE_U8, LANE_U16, 0, 8, no_op)
+	extend_low.i32x4_i16x8 = map_convert(LANE_I16, LANE_I32, 0, 4, no_op)
+	extend_low.i32x4_u16x8 = map_convert(LANE_U16, LANE_U32, 0, 4, no_op)
+	extend_low.i64x2_i32x4 = map_convert(LANE_I32, LANE_I64, 0, 2, num_from_i32)
+	extend_low.i64x2_u32x4 = map_convert(LANE_U32, LANE_I64, 0, 2, num_from_u32_lane)
+
+	extend_high.i16x8_i8x16 = map_convert(LANE_I8, LANE_I16, 8, 8, no_op)
+	extend_high.i16x8_u8x16 = map_convert(LANE_U8, LANE_U16, 8, 8, no_op)
+	extend_high.i32x4_i16x8 = map_convert(LANE_I16, LANE_I32, 4, 4, no_op)
+	extend_high.i32x4_u16x8 = map_convert(LANE_U16, LANE_U32, 4, 4, no_op)
+	extend_high.i64x2_i32x4 = map_convert(LANE_I32, LANE_I64, 2, 2, num_from_i32)
+	extend_high.i64x2_u32x4 = map_convert(LANE_U32, LANE_I64, 2, 2, num_from_u32_lane)
+
+	extadd_pairwise.i16x8_i8x16 = map_pairwise(LANE_I8, LANE_I16)
+	extadd_pairwise.i16x8_u8x16 = map_pairwise(LANE_U8, LANE_U16)
+	extadd_pairwise.i32x4_i16x8 = map_pairwise(LANE_I16, LANE_I32)
+	extadd_pairwise.i32x4_u16x8 = map_pairwise(LANE_U16, LANE_U32)
+
+	local function new_extend_multiply(extend, multiply)
+		return function(lhs, rhs)
+			return multiply(extend(lhs), extend(rhs))
+		end
+	end
+
+	for name, extend in extend_low do
+		local multiply = mul[string.sub(name, 1, 5)]
+
+		extmul_low[name] = new_extend_multiply(extend, multiply)
+		extmul_high[name] = new_extend_multiply(extend_high[name], multiply)
+	end
+
+	ceil.f32x4 = map_unary(LANE_F32, math_ceil)
+	ceil.f64x2 = map_unary(LANE_F64, math_ceil)
+	floor.f32x4 = map_unary(LANE_F32, math_floor)
+	floor.f64x2 = map_unary(LANE_F64, math_floor)
+	truncate.f32x4 = map_unary(LANE_F32, truncate_float)
+	truncate.f64x2 = map_unary(LANE_F64, truncate_float)
+	nearest.f32x4 = map_unary(LANE_F32, nearest.f32)
+	nearest.f64x2 = map_unary(LANE_F64, nearest.f64)
+	sqrt.f32x4 = map_unary(LANE_F32, math_sqrt)
+	sqrt.f64x2 = map_unary(LANE_F64, math_sqrt)
+
+	add.f32x4 = map_binary(LANE_F32, add_number)
+	add.f64x2 = map_binary(LANE_F64, add_number)
+	sub.f32x4 = map_binary(LANE_F32, sub_number)
+	sub.f64x2 = map_binary(LANE_F64, sub_number)
+	mul.f32x4 = map_binary(LANE_F32, mul_number)
+	mul.f64x2 = map_binary(LANE_F64, mul_number)
+
+	local function div_number(lhs, rhs)
+		return lhs / rhs
+	end
+
+	div.f32x4 = map_binary(LANE_F32, div_number)
+	div.f64x2 = map_binary(LANE_F64, div_number)
+	min.f32x4 = map_binary(LANE_F32, min_float)
+	min.f64x2 = map_binary(LANE_F64, min_float)
+	max.f32x4 = map_binary(LANE_F32, max_float)
+	max.f64x2 = map_binary(LANE_F64, max_float)
+
+	local function pseudo_min(lhs, rhs)
+		return if rhs < lhs then rhs else lhs
+	end
+
+	local function pseudo_max(lhs, rhs)
+		return if lhs < rhs then rhs else lhs
+	end
+
+	pmin.f32x4 = map_binary(LANE_F32, pseudo_min)
+	pmin.f64x2 = map_binary(LANE_F64, pseudo_min)
+	pmax.f32x4 = map_binary(LANE_F32, pseudo_max)
+	pmax.f64x2 = map_binary(LANE_F64, pseudo_max)
+
+	saturate.i32x4_f32x4 = map_convert(LANE_F32, LANE_I32, 0, 4, saturate_i32)
+	saturate.u32x4_f32x4 = map_convert(LANE_F32, LANE_U32, 0, 4, saturate_u32)
+	saturate_zero.i32x4_f64x2 = map_convert(LANE_F64, LANE_I32, 0, 2, saturate_i32)
+	saturate_zero.u32x4_f64x2 = map_convert(LANE_F64, LANE_U32, 0, 2, saturate_u32)
+	convert.f32x4_i32x4 = map_convert(LANE_I32, LANE_F32, 0, 4, no_op)
+	convert.f32x4_u32x4 = map_convert(LANE_U32, LANE_F32, 0, 4, no_op)
+	convert_low.f64x2_i32x4 = map_convert(LANE_I32, LANE_F64, 0, 2, no_op)
+	convert_low.f64x2_u32x4 = map_convert(LANE_U32, LANE_F64, 0, 2, no_op)
+	demote_zero.f32x4_f64x2 = map_convert(LANE_F64, LANE_F32, 0, 2, no_op)
+	promote_low.f64x2_f32x4 = map_convert(LANE_F32, LANE_F64, 0, 2, no_op)
+
+	function load.v128(memory, addr)
+		local result = buffer_create(16)
+
+		buffer_copy(result, 0, memory.data, addr, 16)
+
+		return result
+	end
+
+	local function new_load_extend(extend)
+		return function(memory, addr)
+			local temp = buffer_create(16)
+
+			buffer_copy(temp, 0, memory.data, addr, 8)
+
+			return extend(temp)
+		end
+	end
+
+	load.v128_i8x8 = new_load_extend(extend_low.i16x8_i8x16)
+	load.v128_u8x8 = new_load_extend(extend_low.i16x8_u8x16)
+	load.v128_i16x4 = new_load_extend(extend_low.i32x4_i16x8)
+	load.v128_u16x4 = new_load_extend(extend_low.i32x4_u16x8)
+	load.v128_i32x2 = new_load_extend(extend_low.i64x2_i32x4)
+	load.v128_u32x2 = new_load_extend(extend_low.i64x2_u32x4)
+
+	function store.v128(memory, addr, value)
+		buffer_copy(memory.data, addr, value, 0, 16)
+	end
+
+	module.v128 = v128
+	module.splat = splat
+	module.extract_lane = extract_lane
+	module.replace_lane = replace_lane
+	module.shuffle = shuffle
+	module.swizzle = swizzle
+	module.bitselect = bitselect
+	module.bandnot = bandnot
+	module.any_true = any_true
+	module.all_true = all_true
+	module.bitmask = bitmask
+	module.abs = abs
+	module.ceil = ceil
+	module.floor = floor
+	module.sqrt = sqrt
+	module.pmin = pmin
+	module.pmax = pmax
+	module.add_sat = add_sat
+	module.sub_sat = sub_sat
+	module.avgr = avgr
+	module.q15mulr_sat = q15mulr_sat
+	module.dot = dot
+	module.narrow = narrow
+	module.extend_low = extend_low
+	module.extend_high = extend_high
+	module.extadd_pairwise = extadd_pairwise
+	module.extmul_low = extmul_low
+	module.extmul_high = extmul_high
+	module.saturate_zero = saturate_zero
+	module.convert_low = convert_low
+	module.demote_zero = demote_zero
+	module.promote_low = promote_low
+end
+
 return module
@@ -2,8 +2,9 @@ use std::collections::BTreeSet;
 
 use wasm_ast::{
 	node::{
-		BinOp, CmpOp, FuncData, LoadAt, MemoryCopy, MemoryFill, MemoryGrow, MemorySize, StoreAt,
-		UnOp, Value,
+		BinOp, BinOpType, BitSelect, CmpOp, Expression, ExtractLane, FuncData, IndexType, LoadAt,
+		MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemorySize, ReplaceLane, ReturnCall,
+		ReturnCallIndirect, Shuffle, StoreAt, UnOp, Value,
 	},
 	visit::{Driver, Visitor},
 };
@@ -16,30 +17,48 @@ struct Visit {
 	memory_set: BTreeSet<usize>,
 }
 
+impl Visit {
+	fn insert_address(&mut self, index_type: IndexType) {
+		if index_type == IndexType::I64 {
+			self.local_set.insert(("convert", "f64_u64"));
+		}
+	}
+}
+
 impl Visitor for Visit {
 	fn visit_load_at(&mut self, v: &LoadAt) {
 		let name = v.load_type().into_name();
+		let kind = if v.is_atomic() { "atomic_load" } else { "load" };
 
 		self.memory_set.insert(v.memory());
-		self.local_set.insert(("load", name));
+		self.local_set.insert((kind, name));
+		self.insert_address(v.index_type());
 	}
 
 	fn visit_store_at(&mut self, v: &StoreAt) {
 		let name = v.store_type().into_name();
+		let kind = if v.is_atomic() {
+			"atomic_store"
+		} else {
+			"store"
+		};
 
 		self.memory_set.insert(v.memory());
-		self.local_set.insert(("store", name));
+		self.local_set.insert((kind, name));
+		self.insert_address(v.index_type());
 	}
 
 	fn visit_value(&mut self, v: Value) {
 		let name = match v {
-			Value::I64(0) => "ZERO",
-			Value::I64(1) => "ONE",
-			Value::I64(_) => "from_u32",
+			Value::I64(0) => ("i64", "ZERO"),
+			Value::I64(1) => ("i64", "ONE"),
+			Value::I64(_) => ("i64", "from_u32"),
+			Value::V128(0) => ("v128", "ZERO"),
+			Value::V128(_) => ("v128", "from_u32"),
 			_ => return,
 		};
 
-		self.local_set.insert(("i64", name));
+		self.local_set.insert(name);
 	}
 
 	fn visit_un_op(&mut self, v: &UnOp) {
@@ -49,6 +68,14 @@ impl Visitor for Visit {
 	}
 
 	fn visit_bin_op(&mut self, v: &BinOp) {
+		// Any of these may be done on numbers when they provably fit
+		if matches!(
+			v.op_type(),
+			BinOpType::Add_I64 | BinOpType::Sub_I64 | BinOpType::Mul_I64
+		) {
+			self.local_set.insert(("i64", "from_u64"));
+		}
+
 		if v.op_type().try_into_symbol().is_some() {
 			return;
 		}
@@ -68,21 +95,66 @@ impl Visitor for Visit {
 		self.local_set.insert(name);
 	}
 
+	fn visit_extract_lane(&mut self, v: &ExtractLane) {
+		let name = v.lane_type().into_name();
+
+		self.local_set.insert(("extract_lane", name));
+	}
+
+	fn visit_replace_lane(&mut self, v: &ReplaceLane) {
+		let name = v.lane_type().into_name();
+
+		self.local_set.insert(("replace_lane", name));
+	}
+
+	fn visit_return_call(&mut self, _: &ReturnCall) {
+		self.local_set.insert(("tail", "CALL"));
+	}
+
+	fn visit_return_call_indirect(&mut self, _: &ReturnCallIndirect) {
+		self.local_set.insert(("tail", "CALL"));
+	}
+
+	fn visit_shuffle(&mut self, _: &Shuffle) {
+		self.local_set.insert(("shuffle", "i8x16"));
+	}
+
+	fn visit_bit_select(&mut self, _: &BitSelect) {
+		self.local_set.insert(("bitselect", "v128"));
+	}
+
 	fn visit_memory_size(&mut self, m: &MemorySize) {
 		self.memory_set.insert(m.memory());
+
+		if m.index_type() == IndexType::I64 {
+			self.local_set.insert(("extend", "i64_u32"));
+		}
 	}
 
 	fn visit_memory_grow(&mut self, m: &MemoryGrow) {
 		self.memory_set.insert(m.memory());
+
+		if m.index_type() == IndexType::I64 {
+			self.local_set.insert(("extend", "i64_i32"));
+			self.insert_address(m.index_type());
+		}
 	}
 
 	fn visit_memory_copy(&mut self, m: &MemoryCopy) {
 		self.memory_set.insert(m.destination().memory());
 		self.memory_set.insert(m.source().memory());
+		self.insert_address(m.destination().index_type());
+		self.insert_address(m.source().index_type());
 	}
 
 	fn visit_memory_fill(&mut self, m: &MemoryFill) {
 		self.memory_set.insert(m.destination().memory());
+		self.insert_address(m.destination().index_type());
+	}
+
+	fn visit_memory_init(&mut self, m: &MemoryInit) {
+		self.memory_set.insert(m.destination().memory());
+		self.insert_address(m.destination().index_type());
 	}
 }
 
@@ -96,7 +168,22 @@ pub fn visit(ast: &FuncData) -> (BTreeSet<(&'static str, &'static str)>, BTreeSe
 		visit.local_set.insert(("i64", "ZERO"));
 	}
 
+	if ast.local_data().contains(&ValType::V128) {
+		visit.local_set.insert(("v128", "ZERO"));
+	}
+
 	ast.accept(&mut visit);
 
 	(visit.local_set, visit.memory_set)
 }
+
+pub fn visit_constant(init: &Expression) -> BTreeSet<(&'static str, &'static str)> {
+	let mut visit = Visit {
+		local_set: BTreeSet::new(),
+		memory_set: BTreeSet::new(),
+	};
+
+	init.accept(&mut visit);
+
+	visit.local_set
+}
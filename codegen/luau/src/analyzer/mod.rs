@@ -1,3 +1,6 @@
 pub mod br_target;
 pub mod into_string;
 pub mod localize;
+pub mod range;
+pub mod spill;
+pub mod structure;
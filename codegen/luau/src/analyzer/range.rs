@@ -0,0 +1,1025 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use wasm_ast::{
+	cfg::{Branch, Cfg, ENTRY},
+	node::{
+		Align, BinOp, BinOpType, CmpOp, CmpOpType, Expression, FuncData, LoadAt, LoadType, Select,
+		Statement, Terminator, UnOp, UnOpType, Value,
+	},
+	visit::{Driver, Visitor},
+};
+use wasmparser::ValType;
+
+const U32_MAX: u64 = u32::MAX as u64;
+
+// Integers up to this are held exactly by a double
+const NUMBER_MAX: u64 = 1 << 53;
+
+// Bounds that keep growing at a node jump to the next threshold
+// once they have changed this many times, so loops settle quickly
+const WIDEN_AFTER: usize = 2;
+
+// Passes that tighten bounds again after widening overshot them
+const NARROW_PASSES: usize = 3;
+
+// Integers are held as unsigned numbers, so bounds are unsigned too
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Bound {
+	min: u64,
+	max: u64,
+}
+
+impl Bound {
+	const fn exact(value: u64) -> Self {
+		Self {
+			min: value,
+			max: value,
+		}
+	}
+
+	const fn full(is_64: bool) -> Self {
+		let max = if is_64 { u64::MAX } else { U32_MAX };
+
+		Self { min: 0, max }
+	}
+
+	fn join(self, other: Self) -> Self {
+		Self {
+			min: self.min.min(other.min),
+			max: self.max.max(other.max),
+		}
+	}
+
+	// `None` if no value is in both
+	fn meet(self, other: Self) -> Option<Self> {
+		let min = self.min.max(other.min);
+		let max = self.max.min(other.max);
+
+		(min <= max).then_some(Self { min, max })
+	}
+
+	const fn is_signed_safe(self, is_64: bool) -> bool {
+		let max = if is_64 {
+			i64::MAX as u64
+		} else {
+			i32::MAX as u64
+		};
+
+		self.max <= max
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Variable {
+	Temporary(usize),
+	Local(usize),
+}
+
+impl Variable {
+	fn of(expr: &Expression) -> Option<Self> {
+		match expr {
+			Expression::GetTemporary(v) => Some(Self::Temporary(v.var())),
+			Expression::GetLocal(v) => Some(Self::Local(v.var())),
+			_ => None,
+		}
+	}
+}
+
+// Variables missing from a state may hold any value
+type State = HashMap<Variable, Bound>;
+
+fn join_state(lhs: &State, rhs: &State) -> State {
+	lhs.iter()
+		.filter_map(|(k, a)| rhs.get(k).map(|b| (*k, a.join(*b))))
+		.collect()
+}
+
+fn set_variable(state: &mut State, var: Variable, bound: Option<Bound>) {
+	match bound {
+		Some(bound) => state.insert(var, bound),
+		None => state.remove(&var),
+	};
+}
+
+const fn get_bin_op_width(op_type: BinOpType) -> Option<bool> {
+	match op_type {
+		BinOpType::Add_I32
+		| BinOpType::Sub_I32
+		| BinOpType::Mul_I32
+		| BinOpType::DivS_I32
+		| BinOpType::DivU_I32
+		| BinOpType::RemS_I32
+		| BinOpType::RemU_I32
+		| BinOpType::And_I32
+		| BinOpType::Or_I32
+		| BinOpType::Xor_I32
+		| BinOpType::Shl_I32
+		| BinOpType::ShrS_I32
+		| BinOpType::ShrU_I32
+		| BinOpType::Rotl_I32
+		| BinOpType::Rotr_I32 => Some(false),
+		BinOpType::Add_I64
+		| BinOpType::Sub_I64
+		| BinOpType::Mul_I64
+		| BinOpType::DivS_I64
+		| BinOpType::DivU_I64
+		| BinOpType::RemS_I64
+		| BinOpType::RemU_I64
+		| BinOpType::And_I64
+		| BinOpType::Or_I64
+		| BinOpType::Xor_I64
+		| BinOpType::Shl_I64
+		| BinOpType::ShrS_I64
+		| BinOpType::ShrU_I64
+		| BinOpType::Rotl_I64
+		| BinOpType::Rotr_I64 => Some(true),
+		_ => None,
+	}
+}
+
+// Only `add`, `sub` and `mul` are ever written without wrapping
+fn find_exact(op_type: BinOpType, lhs: Bound, rhs: Bound) -> Option<Bound> {
+	let (min, max) = match op_type {
+		BinOpType::Add_I32 | BinOpType::Add_I64 => {
+			(lhs.min.checked_add(rhs.min)?, lhs.max.checked_add(rhs.max)?)
+		}
+		BinOpType::Sub_I32 | BinOpType::Sub_I64 => {
+			(lhs.min.checked_sub(rhs.max)?, lhs.max - rhs.min)
+		}
+		BinOpType::Mul_I32 | BinOpType::Mul_I64 => {
+			(lhs.min.checked_mul(rhs.min)?, lhs.max.checked_mul(rhs.max)?)
+		}
+		_ => return None,
+	};
+
+	let full = Bound::full(get_bin_op_width(op_type)?);
+
+	(max <= full.max).then_some(Bound { min, max })
+}
+
+const fn get_mask_of(value: u64) -> u64 {
+	match value.checked_ilog2() {
+		Some(log) => u64::MAX >> (63 - log),
+		None => 0,
+	}
+}
+
+// Comparison of `lhs` against `rhs` as unsigned numbers
+#[derive(Clone, Copy)]
+enum Relation {
+	Eq,
+	Ne,
+	Lt,
+	Le,
+	Gt,
+	Ge,
+}
+
+impl Relation {
+	// Signed comparisons only agree when neither side can be negative
+	const fn of(op_type: CmpOpType) -> Option<(Self, bool, bool)> {
+		let result = match op_type {
+			CmpOpType::Eq_I32 => (Self::Eq, false, false),
+			CmpOpType::Ne_I32 => (Self::Ne, false, false),
+			CmpOpType::LtU_I32 => (Self::Lt, false, false),
+			CmpOpType::LtS_I32 => (Self::Lt, false, true),
+			CmpOpType::LeU_I32 => (Self::Le, false, false),
+			CmpOpType::LeS_I32 => (Self::Le, false, true),
+			CmpOpType::GtU_I32 => (Self::Gt, false, false),
+			CmpOpType::GtS_I32 => (Self::Gt, false, true),
+			CmpOpType::GeU_I32 => (Self::Ge, false, false),
+			CmpOpType::GeS_I32 => (Self::Ge, false, true),
+			CmpOpType::Eq_I64 => (Self::Eq, true, false),
+			CmpOpType::Ne_I64 => (Self::Ne, true, false),
+			CmpOpType::LtU_I64 => (Self::Lt, true, false),
+			CmpOpType::LtS_I64 => (Self::Lt, true, true),
+			CmpOpType::LeU_I64 => (Self::Le, true, false),
+			CmpOpType::LeS_I64 => (Self::Le, true, true),
+			CmpOpType::GtU_I64 => (Self::Gt, true, false),
+			CmpOpType::GtS_I64 => (Self::Gt, true, true),
+			CmpOpType::GeU_I64 => (Self::Ge, true, false),
+			CmpOpType::GeS_I64 => (Self::Ge, true, true),
+			_ => return None,
+		};
+
+		Some(result)
+	}
+
+	const fn negate(self) -> Self {
+		match self {
+			Self::Eq => Self::Ne,
+			Self::Ne => Self::Eq,
+			Self::Lt => Self::Ge,
+			Self::Le => Self::Gt,
+			Self::Gt => Self::Le,
+			Self::Ge => Self::Lt,
+		}
+	}
+
+	const fn swap(self) -> Self {
+		match self {
+			Self::Eq | Self::Ne => self,
+			Self::Lt => Self::Gt,
+			Self::Le => Self::Ge,
+			Self::Gt => Self::Lt,
+			Self::Ge => Self::Le,
+		}
+	}
+
+	// Values of `lhs` left once the relation to `rhs` is known to hold
+	fn refine(self, lhs: Bound, rhs: Bound) -> Option<Bound> {
+		let limit = match self {
+			Self::Eq => rhs,
+			Self::Ne if rhs.min != rhs.max => lhs,
+			Self::Ne if lhs.min == rhs.min => Bound {
+				min: lhs.min.checked_add(1)?,
+				max: lhs.max,
+			},
+			Self::Ne if lhs.max == rhs.max => Bound {
+				min: lhs.min,
+				max: lhs.max.checked_sub(1)?,
+			},
+			Self::Ne => lhs,
+			Self::Lt => Bound {
+				min: 0,
+				max: rhs.max.checked_sub(1)?,
+			},
+			Self::Le => Bound {
+				min: 0,
+				max: rhs.max,
+			},
+			Self::Gt => Bound {
+				min: rhs.min.checked_add(1)?,
+				max: u64::MAX,
+			},
+			Self::Ge => Bound {
+				min: rhs.min,
+				max: u64::MAX,
+			},
+		};
+
+		lhs.meet(limit)
+	}
+}
+
+// Bounds of expressions as they are evaluated with a given state
+struct Eval<'a> {
+	state: &'a State,
+	memo_map: HashMap<usize, Option<Bound>>,
+}
+
+impl<'a> Eval<'a> {
+	fn new(state: &'a State) -> Self {
+		Self {
+			state,
+			memo_map: HashMap::new(),
+		}
+	}
+
+	fn find(&mut self, expr: &Expression) -> Option<Bound> {
+		match expr {
+			Expression::Select(v) => self.find_select(v),
+			Expression::GetTemporary(_) | Expression::GetLocal(_) => {
+				self.state.get(&Variable::of(expr)?).copied()
+			}
+			Expression::LoadAt(v) => Self::find_load_at(v),
+			Expression::MemorySize(_) => Some(Bound {
+				min: 0,
+				max: 0x10000,
+			}),
+			Expression::Value(v) => Self::find_value(*v),
+			Expression::UnOp(v) => self.find_un_op(v),
+			Expression::BinOp(v) => self.find_bin_op(v),
+			Expression::CmpOp(_) | Expression::RefIsNull(_) => Some(Bound { min: 0, max: 1 }),
+			_ => None,
+		}
+	}
+
+	fn find_select(&mut self, select: &Select) -> Option<Bound> {
+		let on_true = self.find(select.on_true())?;
+		let on_false = self.find(select.on_false())?;
+
+		Some(on_true.join(on_false))
+	}
+
+	fn find_load_at(load_at: &LoadAt) -> Option<Bound> {
+		let max = match load_at.load_type() {
+			LoadType::I32_U8 | LoadType::I64_U8 => 0xFF,
+			LoadType::I32_U16 | LoadType::I64_U16 => 0xFFFF,
+			LoadType::I64_U32 => U32_MAX,
+			_ => return None,
+		};
+
+		Some(Bound { min: 0, max })
+	}
+
+	fn find_value(value: Value) -> Option<Bound> {
+		let value = match value {
+			Value::I32(v) => u64::from(v as u32),
+			Value::I64(v) => v as u64,
+			_ => return None,
+		};
+
+		Some(Bound::exact(value))
+	}
+
+	fn find_un_op(&mut self, un_op: &UnOp) -> Option<Bound> {
+		let bound = match un_op.op_type() {
+			UnOpType::Clz_I32 | UnOpType::Ctz_I32 | UnOpType::Popcnt_I32 => {
+				Bound { min: 0, max: 32 }
+			}
+			UnOpType::Clz_I64 | UnOpType::Ctz_I64 | UnOpType::Popcnt_I64 => {
+				Bound { min: 0, max: 64 }
+			}
+			UnOpType::Wrap_I32_I64 => self
+				.find(un_op.rhs())
+				.filter(|v| v.max <= U32_MAX)
+				.unwrap_or(Bound::full(false)),
+			UnOpType::Extend_I64_U32 => self.find(un_op.rhs()).unwrap_or(Bound::full(false)),
+			UnOpType::Extend_I64_I32 => self
+				.find(un_op.rhs())
+				.filter(|v| v.is_signed_safe(false))
+				.unwrap_or(Bound::full(true)),
+			_ => return None,
+		};
+
+		Some(bound)
+	}
+
+	fn find_bin_op(&mut self, bin_op: &BinOp) -> Option<Bound> {
+		let id = bin_op as *const _ as usize;
+
+		if let Some(&bound) = self.memo_map.get(&id) {
+			return bound;
+		}
+
+		let bound = self.find_bin_op_uncached(bin_op);
+
+		self.memo_map.insert(id, bound);
+
+		bound
+	}
+
+	fn find_operands(&mut self, bin_op: &BinOp) -> Option<(Bound, Bound)> {
+		let full = Bound::full(get_bin_op_width(bin_op.op_type())?);
+		let lhs = self.find(bin_op.lhs()).unwrap_or(full);
+		let rhs = self.find(bin_op.rhs()).unwrap_or(full);
+
+		Some((lhs, rhs))
+	}
+
+	fn find_bin_op_uncached(&mut self, bin_op: &BinOp) -> Option<Bound> {
+		let op_type = bin_op.op_type();
+		let is_64 = get_bin_op_width(op_type)?;
+		let (lhs, rhs) = self.find_operands(bin_op)?;
+		let bound = match op_type {
+			BinOpType::And_I32 | BinOpType::And_I64 => Bound {
+				min: 0,
+				max: lhs.max.min(rhs.max),
+			},
+			BinOpType::Or_I32 | BinOpType::Or_I64 => Bound {
+				min: lhs.min.max(rhs.min),
+				max: get_mask_of(lhs.max.max(rhs.max)),
+			},
+			BinOpType::Xor_I32 | BinOpType::Xor_I64 => Bound {
+				min: 0,
+				max: get_mask_of(lhs.max.max(rhs.max)),
+			},
+			BinOpType::ShrU_I32 | BinOpType::ShrU_I64 if rhs.min == rhs.max => {
+				let shift = rhs.min % if is_64 { 64 } else { 32 };
+
+				Bound {
+					min: lhs.min >> shift,
+					max: lhs.max >> shift,
+				}
+			}
+			BinOpType::ShrU_I32 | BinOpType::ShrU_I64 => Bound {
+				min: 0,
+				max: lhs.max,
+			},
+			// Division by zero traps, so the divisor is at least 1
+			BinOpType::DivU_I32 | BinOpType::DivU_I64 => Bound {
+				min: lhs.min / rhs.max.max(1),
+				max: lhs.max / rhs.min.max(1),
+			},
+			BinOpType::RemU_I32 | BinOpType::RemU_I64 => Bound {
+				min: 0,
+				max: lhs.max.min(rhs.max.saturating_sub(1)),
+			},
+			_ => find_exact(op_type, lhs, rhs).unwrap_or(Bound::full(is_64)),
+		};
+
+		Some(bound)
+	}
+
+	// Narrows the variables compared by `condition` to the values that
+	// take the branch, or returns `None` if none can
+	fn refine(&mut self, condition: &Expression, is_true: bool) -> Option<State> {
+		let mut state = self.state.clone();
+
+		match condition {
+			Expression::CmpOp(v) => self.refine_cmp_op(v, is_true, &mut state)?,
+			_ => {
+				if let Some(var) = Variable::of(condition) {
+					let lhs = self.find(condition).unwrap_or(Bound::full(false));
+					let relation = if is_true { Relation::Ne } else { Relation::Eq };
+					let bound = relation.refine(lhs, Bound::exact(0))?;
+
+					state.insert(var, bound);
+				}
+			}
+		}
+
+		Some(state)
+	}
+
+	fn refine_cmp_op(&mut self, cmp_op: &CmpOp, is_true: bool, state: &mut State) -> Option<()> {
+		let Some((relation, is_64, is_signed)) = Relation::of(cmp_op.op_type()) else {
+			return Some(());
+		};
+
+		let relation = if is_true { relation } else { relation.negate() };
+		let full = Bound::full(is_64);
+		let lhs = self.find(cmp_op.lhs()).unwrap_or(full);
+		let rhs = self.find(cmp_op.rhs()).unwrap_or(full);
+
+		if is_signed && !(lhs.is_signed_safe(is_64) && rhs.is_signed_safe(is_64)) {
+			return Some(());
+		}
+
+		if let Some(var) = Variable::of(cmp_op.lhs()) {
+			state.insert(var, relation.refine(lhs, rhs)?);
+		}
+
+		if let Some(var) = Variable::of(cmp_op.rhs()) {
+			state.insert(var, relation.swap().refine(rhs, lhs)?);
+		}
+
+		Some(())
+	}
+}
+
+fn apply_statement(state: &mut State, stat: &Statement) {
+	match stat {
+		Statement::SetTemporary(v) => {
+			let bound = Eval::new(state).find(v.value());
+
+			set_variable(state, Variable::Temporary(v.var().var()), bound);
+		}
+		Statement::SetLocal(v) => {
+			let bound = Eval::new(state).find(v.value());
+
+			set_variable(state, Variable::Local(v.var().var()), bound);
+		}
+		Statement::Call(v) => {
+			for var in v.result_list().iter() {
+				state.remove(&Variable::Temporary(var.var()));
+			}
+		}
+		Statement::CallIndirect(v) => {
+			for var in v.result_list().iter() {
+				state.remove(&Variable::Temporary(var.var()));
+			}
+		}
+		Statement::MemoryGrow(v) => {
+			state.remove(&Variable::Temporary(v.result().var()));
+		}
+		Statement::AtomicWait(v) => {
+			state.remove(&Variable::Temporary(v.result().var()));
+		}
+		Statement::TableGet(v) => {
+			state.remove(&Variable::Temporary(v.result().var()));
+		}
+		Statement::TableSize(v) => {
+			state.remove(&Variable::Temporary(v.result().var()));
+		}
+		Statement::TableGrow(v) => {
+			state.remove(&Variable::Temporary(v.result().var()));
+		}
+		_ => {}
+	}
+}
+
+fn apply_align(state: &State, align: Align) -> State {
+	let list: Vec<_> = align
+		.old_range()
+		.iter()
+		.map(|v| state.get(&Variable::Temporary(v.var())).copied())
+		.collect();
+
+	let mut state = state.clone();
+
+	for (var, bound) in align.new_range().iter().zip(list) {
+		set_variable(&mut state, Variable::Temporary(var.var()), bound);
+	}
+
+	state
+}
+
+#[derive(Default)]
+struct ThresholdList {
+	threshold_set: BTreeSet<u64>,
+}
+
+impl Visitor for ThresholdList {
+	fn visit_cmp_op(&mut self, cmp_op: &CmpOp) {
+		for value in [cmp_op.lhs(), cmp_op.rhs()] {
+			let Expression::Value(value) = value else {
+				continue;
+			};
+
+			if let Some(bound) = Eval::find_value(*value) {
+				self.threshold_set.insert(bound.max.saturating_sub(1));
+				self.threshold_set.insert(bound.max);
+				self.threshold_set.insert(bound.max.saturating_add(1));
+			}
+		}
+	}
+}
+
+// Operators for the integer operations that the range analysis
+// has shown to never wrap
+pub const fn get_exact_symbol(op_type: BinOpType) -> Option<&'static str> {
+	match op_type {
+		BinOpType::Add_I32 | BinOpType::Add_I64 => Some("+"),
+		BinOpType::Sub_I32 | BinOpType::Sub_I64 => Some("-"),
+		BinOpType::Mul_I32 | BinOpType::Mul_I64 => Some("*"),
+		_ => None,
+	}
+}
+
+// An operand of an `i64` operation done on plain numbers
+pub enum NumberOperand<'a> {
+	Value(i64),
+	// An `i32` zero extended, which is already a number
+	Extend(&'a Expression),
+	Number(&'static str, Box<NumberOperand<'a>>, Box<NumberOperand<'a>>),
+}
+
+// Operations that are known to never wrap. Those on `i64` are only kept
+// if they can be done entirely with numbers, which means that both
+// operands are numbers themselves and nothing exceeds 53 bits.
+#[derive(Default)]
+pub struct Range {
+	exact_set: HashSet<usize>,
+	number_set: HashSet<usize>,
+}
+
+impl Range {
+	pub fn is_exact(&self, bin_op: &BinOp) -> bool {
+		let id = bin_op as *const _ as usize;
+
+		self.exact_set.contains(&id)
+	}
+
+	pub fn is_number(&self, bin_op: &BinOp) -> bool {
+		let id = bin_op as *const _ as usize;
+
+		self.number_set.contains(&id)
+	}
+
+	/// The operation as it is written on numbers, if it can be.
+	pub fn as_number<'a>(&self, bin_op: &'a BinOp) -> Option<NumberOperand<'a>> {
+		if !self.is_number(bin_op) {
+			return None;
+		}
+
+		let symbol = get_exact_symbol(bin_op.op_type())?;
+		let lhs = self.as_number_operand(bin_op.lhs())?;
+		let rhs = self.as_number_operand(bin_op.rhs())?;
+
+		Some(NumberOperand::Number(symbol, lhs.into(), rhs.into()))
+	}
+
+	// Operands that are already numbers, being `i32` values or small constants
+	fn as_number_operand<'a>(&self, expr: &'a Expression) -> Option<NumberOperand<'a>> {
+		match expr {
+			Expression::Value(Value::I64(v))
+				if u64::try_from(*v).is_ok_and(|v| v <= NUMBER_MAX) =>
+			{
+				Some(NumberOperand::Value(*v))
+			}
+			Expression::UnOp(v) if matches!(v.op_type(), UnOpType::Extend_I64_U32) => {
+				Some(NumberOperand::Extend(v.rhs()))
+			}
+			Expression::BinOp(v) => self.as_number(v),
+			_ => None,
+		}
+	}
+}
+
+struct Record<'a, 'b> {
+	eval: Eval<'a>,
+	range: &'b mut Range,
+}
+
+impl Visitor for Record<'_, '_> {
+	fn visit_bin_op(&mut self, bin_op: &BinOp) {
+		let id = bin_op as *const _ as usize;
+		let op_type = bin_op.op_type();
+		let Some((lhs, rhs)) = self.eval.find_operands(bin_op) else {
+			return;
+		};
+
+		let Some(bound) = find_exact(op_type, lhs, rhs) else {
+			return;
+		};
+
+		if get_bin_op_width(op_type) == Some(false) {
+			self.range.exact_set.insert(id);
+		} else if bound.max <= NUMBER_MAX
+			&& self.range.as_number_operand(bin_op.lhs()).is_some()
+			&& self.range.as_number_operand(bin_op.rhs()).is_some()
+		{
+			self.range.number_set.insert(id);
+		}
+	}
+}
+
+struct Analysis<'a> {
+	cfg: Cfg<'a>,
+	threshold_set: BTreeSet<u64>,
+	in_list: Vec<Option<State>>,
+	edge_list: Vec<Vec<(usize, State)>>,
+}
+
+impl Analysis<'_> {
+	// States along every edge leaving the node. Handlers may be entered
+	// from anywhere in it, and nothing is known of their temporaries.
+	fn find_edge_list(&self, id: usize) -> Vec<(usize, State)> {
+		let Some(mut state) = self.in_list[id].clone() else {
+			return Vec::new();
+		};
+
+		let node = &self.cfg.node_list()[id];
+		let mut handler = state.clone();
+
+		for stat in node.code() {
+			apply_statement(&mut state, stat);
+			handler = join_state(&handler, &state);
+		}
+
+		handler.retain(|k, _| matches!(k, Variable::Local(_)));
+
+		let successor_list = node.successor_list();
+		let mut edge_list: Vec<_> = match node.last() {
+			Some(Branch::BrIf(v)) => {
+				let mut eval = Eval::new(&state);
+				let on_true = eval
+					.refine(v.condition(), true)
+					.map(|s| apply_align(&s, v.target().align()));
+				let on_false = eval.refine(v.condition(), false);
+
+				if let [target, next] = *successor_list {
+					[(target, on_true), (next, on_false)]
+						.into_iter()
+						.filter_map(|(id, s)| Some((id, s?)))
+						.collect()
+				} else {
+					let joined = match (on_true, on_false) {
+						(Some(a), Some(b)) => Some(join_state(&a, &b)),
+						(a, b) => a.or(b),
+					};
+
+					successor_list
+						.iter()
+						.zip(joined)
+						.map(|(&id, s)| (id, s))
+						.collect()
+				}
+			}
+			Some(Branch::If(v)) => {
+				let mut eval = Eval::new(&state);
+				let on_true = eval.refine(v.condition(), true);
+				let on_false = eval.refine(v.condition(), false);
+
+				successor_list
+					.iter()
+					.zip([on_true, on_false])
+					.filter_map(|(&id, s)| Some((id, s?)))
+					.collect()
+			}
+			Some(Branch::Terminator(v)) => {
+				let state = match v {
+					Terminator::Br(v) => apply_align(&state, v.align()),
+					Terminator::BrTable(v) => v
+						.data()
+						.iter()
+						.chain(std::iter::once(&v.default()))
+						.map(|v| apply_align(&state, v.align()))
+						.reduce(|a, b| join_state(&a, &b))
+						.unwrap(),
+					_ => state,
+				};
+
+				successor_list
+					.iter()
+					.map(|&id| (id, state.clone()))
+					.collect()
+			}
+			None => successor_list
+				.iter()
+				.map(|&id| (id, state.clone()))
+				.collect(),
+		};
+
+		for &id in node.handler_list() {
+			edge_list.push((id, handler.clone()));
+		}
+
+		edge_list
+	}
+
+	fn find_in(&self, id: usize) -> Option<State> {
+		self.cfg.node_list()[id]
+			.predecessor_list()
+			.iter()
+			.flat_map(|&v| &self.edge_list[v])
+			.filter(|v| v.0 == id)
+			.map(|v| v.1.clone())
+			.reduce(|a, b| join_state(&a, &b))
+	}
+
+	fn widen(&self, old: &State, new: State) -> State {
+		new.into_iter()
+			.map(|(k, mut v)| {
+				let last = old[&k];
+
+				if v.max > last.max {
+					v.max = self.threshold_set.range(v.max..).next().copied().unwrap();
+				}
+
+				if v.min < last.min {
+					v.min = 0;
+				}
+
+				(k, v)
+			})
+			.collect()
+	}
+
+	fn run(&mut self, order: &[usize]) {
+		let order: Vec<_> = order.iter().copied().filter(|&v| v != ENTRY).collect();
+		let mut change_list = vec![0; self.cfg.len()];
+		let mut has_changed = true;
+
+		self.edge_list[ENTRY] = self.find_edge_list(ENTRY);
+
+		while has_changed {
+			has_changed = false;
+
+			for &id in &order {
+				let Some(new) = self.find_in(id) else {
+					continue;
+				};
+
+				let new = match &self.in_list[id] {
+					Some(old) => {
+						let joined = join_state(old, &new);
+
+						if joined == *old {
+							continue;
+						}
+
+						change_list[id] += 1;
+
+						if change_list[id] > WIDEN_AFTER {
+							self.widen(old, joined)
+						} else {
+							joined
+						}
+					}
+					None => new,
+				};
+
+				self.in_list[id] = Some(new);
+				self.edge_list[id] = self.find_edge_list(id);
+				has_changed = true;
+			}
+		}
+
+		for _ in 0..NARROW_PASSES {
+			for &id in &order {
+				self.in_list[id] = self.find_in(id);
+				self.edge_list[id] = self.find_edge_list(id);
+			}
+		}
+	}
+
+	fn record(&self, range: &mut Range) {
+		for (id, state) in self.in_list.iter().enumerate() {
+			let Some(mut state) = state.clone() else {
+				continue;
+			};
+
+			let node = &self.cfg.node_list()[id];
+
+			for stat in node.code() {
+				stat.accept(&mut Record {
+					eval: Eval::new(&state),
+					range,
+				});
+
+				apply_statement(&mut state, stat);
+			}
+
+			let mut record = Record {
+				eval: Eval::new(&state),
+				range,
+			};
+
+			match node.last() {
+				Some(Branch::BrIf(v)) => v.accept(&mut record),
+				Some(Branch::If(v)) => v.condition().accept(&mut record),
+				Some(Branch::Terminator(v)) => v.accept(&mut record),
+				None => {}
+			}
+		}
+	}
+}
+
+// Locals start out as zero, while parameters may hold anything
+fn find_entry_state(ast: &FuncData) -> State {
+	ast.local_data()
+		.iter()
+		.enumerate()
+		.filter(|v| matches!(v.1, ValType::I32 | ValType::I64))
+		.map(|v| (Variable::Local(ast.num_param() + v.0), Bound::exact(0)))
+		.collect()
+}
+
+pub fn visit(ast: &FuncData) -> Range {
+	let cfg = Cfg::from_func(ast);
+	let order = cfg.reverse_post_order();
+	let mut threshold_list = ThresholdList::default();
+
+	ast.accept(&mut threshold_list);
+	threshold_list
+		.threshold_set
+		.extend([i32::MAX as u64, U32_MAX, i64::MAX as u64, u64::MAX]);
+
+	let len = cfg.len();
+	let mut analysis = Analysis {
+		cfg,
+		threshold_set: threshold_list.threshold_set,
+		in_list: vec![None; len],
+		edge_list: vec![Vec::new(); len],
+	};
+
+	analysis.in_list[ENTRY] = Some(find_entry_state(ast));
+	analysis.run(&order);
+
+	let mut range = Range::default();
+
+	analysis.record(&mut range);
+
+	range
+}
+
+#[cfg(test)]
+mod tests {
+	use wasm_ast::node::{
+		Align, BinOp, BinOpType, Block, Br, BrIf, CmpOp, CmpOpType, Expression, FuncData,
+		GetGlobal, LabelType, Local, SetLocal, Statement, UnOp, UnOpType, Value,
+	};
+	use wasmparser::ValType;
+
+	use super::{visit, NumberOperand};
+
+	fn get_local(var: usize) -> Box<Expression> {
+		Expression::GetLocal(Local::new(var)).into()
+	}
+
+	fn value(value: Value) -> Box<Expression> {
+		Expression::Value(value).into()
+	}
+
+	fn bin_op(op_type: BinOpType, lhs: Box<Expression>, rhs: Box<Expression>) -> Box<Expression> {
+		Expression::BinOp(BinOp::new(op_type, lhs, rhs)).into()
+	}
+
+	fn set_local(var: usize, value: Box<Expression>) -> Statement {
+		Statement::SetLocal(SetLocal::new(Local::new(var), value))
+	}
+
+	fn bin_op_of(statement: &Statement) -> &BinOp {
+		let Statement::SetLocal(set) = statement else {
+			unreachable!("statement should set a local");
+		};
+
+		let Expression::BinOp(bin_op) = set.value() else {
+			unreachable!("value should be a binary operation");
+		};
+
+		bin_op
+	}
+
+	// Counts `l0` up by one for as long as `condition` holds
+	fn counted_loop(condition: Expression) -> FuncData {
+		let target = Br::new(0, Align::new(0, 0, 0));
+		let body = Block::new(
+			Some(LabelType::Backward),
+			vec![
+				set_local(
+					0,
+					bin_op(BinOpType::Add_I32, get_local(0), value(Value::I32(1))),
+				),
+				Statement::BrIf(BrIf::new(condition.into(), target)),
+			],
+			None,
+		);
+		let code = Block::new(None, vec![Statement::Block(body)], None);
+
+		FuncData::new(vec![ValType::I32], 0, 0, 0, code)
+	}
+
+	fn loop_increment(func: &FuncData) -> &BinOp {
+		let Statement::Block(body) = &func.code().code()[0] else {
+			unreachable!("loop should be kept");
+		};
+
+		bin_op_of(&body.code()[0])
+	}
+
+	#[test]
+	fn widens_counted_loop_to_limit() {
+		let limit = value(Value::I32(1_000_000));
+		let condition = CmpOp::new(CmpOpType::LtU_I32, get_local(0), limit);
+		let func = counted_loop(Expression::CmpOp(condition));
+		let range = visit(&func);
+
+		assert!(range.is_exact(loop_increment(&func)));
+	}
+
+	#[test]
+	fn widens_unbounded_loop_to_full() {
+		let func = counted_loop(Expression::GetGlobal(GetGlobal::new(0)));
+		let range = visit(&func);
+
+		assert!(!range.is_exact(loop_increment(&func)));
+	}
+
+	#[test]
+	fn refuses_sub_that_may_wrap() {
+		let masked = bin_op(BinOpType::And_I32, get_local(0), value(Value::I32(0xFF)));
+		let code = Block::new(
+			None,
+			vec![
+				set_local(1, masked),
+				set_local(
+					2,
+					bin_op(BinOpType::Sub_I32, value(Value::I32(0xFE)), get_local(1)),
+				),
+				set_local(
+					2,
+					bin_op(BinOpType::Sub_I32, value(Value::I32(0xFF)), get_local(1)),
+				),
+			],
+			None,
+		);
+		let func = FuncData::new(vec![ValType::I32; 2], 0, 1, 0, code);
+		let range = visit(&func);
+
+		assert!(!range.is_exact(bin_op_of(&func.code().code()[1])));
+		assert!(range.is_exact(bin_op_of(&func.code().code()[2])));
+	}
+
+	#[test]
+	fn writes_small_i64_as_number() {
+		let extend = || {
+			let un_op = UnOp::new(UnOpType::Extend_I64_U32, get_local(0));
+
+			Box::new(Expression::UnOp(un_op))
+		};
+		let code = Block::new(
+			None,
+			vec![
+				set_local(
+					1,
+					bin_op(BinOpType::Add_I64, extend(), value(Value::I64(1))),
+				),
+				set_local(
+					1,
+					bin_op(BinOpType::Add_I64, extend(), value(Value::I64(1 << 60))),
+				),
+				set_local(
+					1,
+					bin_op(BinOpType::Add_I64, get_local(1), value(Value::I64(1))),
+				),
+			],
+			None,
+		);
+		let func = FuncData::new(vec![ValType::I64], 0, 1, 0, code);
+		let range = visit(&func);
+		let number = range.as_number(bin_op_of(&func.code().code()[0]));
+
+		assert!(matches!(
+			number,
+			Some(NumberOperand::Number("+", lhs, rhs))
+				if matches!(*lhs, NumberOperand::Extend(_))
+					&& matches!(*rhs, NumberOperand::Value(1))
+		));
+		assert!(range.as_number(bin_op_of(&func.code().code()[1])).is_none());
+		assert!(range.as_number(bin_op_of(&func.code().code()[2])).is_none());
+	}
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use wasm_ast::{
+	node::FuncData,
+	optimize::use_count::{self, Variable},
+};
+
+// Variables that do not fit in registers, each with its 1-based
+// index into the spill table of its kind
+#[derive(Default)]
+pub struct Spill {
+	local_map: HashMap<usize, usize>,
+	temporary_map: HashMap<usize, usize>,
+}
+
+impl Spill {
+	pub fn local(&self, var: usize) -> Option<usize> {
+		self.local_map.get(&var).copied()
+	}
+
+	pub fn temporary(&self, var: usize) -> Option<usize> {
+		self.temporary_map.get(&var).copied()
+	}
+
+	pub fn num_temporary(&self) -> usize {
+		self.temporary_map.len()
+	}
+}
+
+// The `available` most used variables stay in registers, and the rest
+// are spilled in order of kind and index
+pub fn visit(ast: &FuncData, available: usize) -> Spill {
+	let mut spilled: Vec<_> = use_count::by_use_count(ast)
+		.into_iter()
+		.skip(available)
+		.collect();
+	let mut spill = Spill::default();
+
+	spilled.sort_unstable();
+
+	for var in spilled {
+		match var {
+			Variable::Temporary(var) => {
+				let index = spill.temporary_map.len() + 1;
+
+				spill.temporary_map.insert(var, index);
+			}
+			Variable::Local(var) => {
+				let index = spill.local_map.len() + 1;
+
+				spill.local_map.insert(var, index);
+			}
+		}
+	}
+
+	spill
+}
@@ -0,0 +1,295 @@
+use std::collections::HashSet;
+
+use wasm_ast::node::{Block, Br, FuncData, LabelType, Statement, Terminator, Try};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Jump {
+	Break,
+	Continue,
+	Return,
+	// Leaves the protected closure of a `try` body with the target level
+	Unwind(usize),
+	// Breaks out one loop at a time until the loop at the level is reached
+	Desired(usize),
+}
+
+#[derive(Clone, Copy)]
+struct Label {
+	label_type: Option<LabelType>,
+	exit: usize,
+}
+
+// Only labels that are branched to are written as loops. Any label whose
+// block ends right where its parent does shares the exit of that parent,
+// so a branch to either can leave through whichever loop is innermost.
+#[derive(Default)]
+pub struct LabelList {
+	label_list: Vec<Label>,
+	try_list: Vec<usize>,
+}
+
+impl LabelList {
+	pub fn len(&self) -> usize {
+		self.label_list.len()
+	}
+
+	pub fn push(&mut self, label_type: Option<LabelType>, is_tail: bool) {
+		let level = self.label_list.len();
+		let exit = match self.label_list.last() {
+			Some(parent) if is_tail => parent.exit,
+			_ => level,
+		};
+
+		self.label_list.push(Label { label_type, exit });
+	}
+
+	pub fn pop(&mut self) {
+		self.label_list.pop().unwrap();
+	}
+
+	// `try` bodies run in a protected closure, so labels below
+	// the start of the innermost one can only be reached by returning
+	pub fn try_start(&self) -> usize {
+		self.try_list.last().copied().unwrap_or_default()
+	}
+
+	pub fn push_try(&mut self) {
+		self.try_list.push(self.label_list.len());
+	}
+
+	pub fn pop_try(&mut self) {
+		self.try_list.pop().unwrap();
+	}
+
+	/// Level and type of the loop a `break` or `continue` would apply to.
+	pub fn innermost_loop(&self) -> Option<(usize, LabelType)> {
+		let level = self
+			.label_list
+			.iter()
+			.rposition(|v| v.label_type.is_some())
+			.filter(|&v| v >= self.try_start())?;
+
+		self.label_list[level].label_type.map(|v| (level, v))
+	}
+
+	pub fn resolve(&self, target: usize) -> Jump {
+		let level = self.label_list.len() - 1 - target;
+		let label = self.label_list[level];
+
+		if level < self.try_start() {
+			return Jump::Unwind(level);
+		}
+
+		match (label.label_type, self.innermost_loop()) {
+			(Some(LabelType::Backward), Some((last, _))) if last == level => Jump::Continue,
+			(Some(LabelType::Forward), Some((last, _)))
+				if self.label_list[last].exit == label.exit =>
+			{
+				Jump::Break
+			}
+			_ if level == 0 && self.try_list.is_empty() => Jump::Return,
+			_ => Jump::Desired(level),
+		}
+	}
+}
+
+// The blocks are written in the same nesting as in the AST, this only
+// picks how each branch leaves them. Branches become `break`, `continue`
+// or `return` where possible, and the rest set `desired`, which is then
+// checked only by the loops and `try` bodies they actually pass through.
+// Control flow is never reordered, so irreducible or deeply nested
+// branches still fall back to the `desired` chain
+pub struct Structure {
+	tail_set: HashSet<usize>,
+	check_set: HashSet<usize>,
+}
+
+impl Structure {
+	pub fn is_tail(&self, block: &Block) -> bool {
+		self.tail_set.contains(&(block as *const _ as usize))
+	}
+
+	/// Whether `desired` may still be set once the loop or `try` is left.
+	pub fn has_check<T>(&self, node: &T) -> bool {
+		self.check_set.contains(&(node as *const _ as usize))
+	}
+
+	pub fn has_desired(&self) -> bool {
+		!self.check_set.is_empty()
+	}
+}
+
+#[derive(Default)]
+struct Visit {
+	label_list: LabelList,
+	node_list: Vec<usize>,
+	tail_set: HashSet<usize>,
+	check_set: HashSet<usize>,
+}
+
+impl Visit {
+	fn add_br(&mut self, br: Br) {
+		let level = match self.label_list.resolve(br.target()) {
+			Jump::Break | Jump::Continue | Jump::Return => return,
+			Jump::Unwind(level) | Jump::Desired(level) => level,
+		};
+
+		// Every loop and `try` left on the way has to pass `desired` on
+		self.check_set.extend(&self.node_list[level + 1..]);
+	}
+
+	fn add_block(&mut self, block: &Block, is_tail: bool) {
+		let id = block as *const _ as usize;
+
+		if is_tail {
+			self.tail_set.insert(id);
+		}
+
+		self.label_list.push(block.label_type(), is_tail);
+		self.node_list.push(id);
+
+		let len = block.code().len();
+
+		for (i, stat) in block.code().iter().enumerate() {
+			self.add_statement(stat, i + 1 == len && block.last().is_none());
+		}
+
+		match block.last() {
+			Some(Terminator::Br(br)) => self.add_br(*br),
+			Some(Terminator::BrTable(table)) => {
+				table.data().iter().for_each(|&v| self.add_br(v));
+				self.add_br(table.default());
+			}
+			_ => {}
+		}
+
+		self.node_list.pop();
+		self.label_list.pop();
+	}
+
+	fn add_try(&mut self, try_: &Try) {
+		let id = try_ as *const _ as usize;
+
+		// The `try` stands in for its body once the closure returns
+		self.label_list.push_try();
+		self.add_block(try_.body(), false);
+		self.label_list.pop_try();
+
+		if self.check_set.contains(&(try_.body() as *const _ as usize)) {
+			self.check_set.insert(id);
+		}
+
+		for catch in try_.catch_list() {
+			self.add_block(catch.block(), false);
+		}
+
+		if let Some(block) = try_.catch_all() {
+			self.add_block(block, false);
+		}
+	}
+
+	fn add_statement(&mut self, stat: &Statement, is_tail: bool) {
+		match stat {
+			Statement::Block(v) => self.add_block(v, is_tail),
+			Statement::BrIf(v) => self.add_br(v.target()),
+			Statement::If(v) => {
+				self.add_block(v.on_true(), is_tail);
+
+				if let Some(v) = v.on_false() {
+					self.add_block(v, is_tail);
+				}
+			}
+			Statement::Try(v) => self.add_try(v),
+			_ => {}
+		}
+	}
+}
+
+pub fn visit(ast: &FuncData) -> Structure {
+	let mut visit = Visit::default();
+
+	visit.add_block(ast.code(), false);
+
+	Structure {
+		tail_set: visit.tail_set,
+		check_set: visit.check_set,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use wasm_ast::node::LabelType;
+
+	use super::{Jump, LabelList};
+
+	fn label_list(list: &[(Option<LabelType>, bool)]) -> LabelList {
+		let mut label_list = LabelList::default();
+
+		for &(label_type, is_tail) in list {
+			label_list.push(label_type, is_tail);
+		}
+
+		label_list
+	}
+
+	#[test]
+	fn resolves_function_end_to_return() {
+		let label_list = label_list(&[(None, false), (Some(LabelType::Backward), false)]);
+
+		assert_eq!(label_list.resolve(1), Jump::Return);
+	}
+
+	#[test]
+	fn resolves_innermost_loop() {
+		let forward = label_list(&[(None, false), (Some(LabelType::Forward), false)]);
+		let backward = label_list(&[(None, false), (Some(LabelType::Backward), false)]);
+
+		assert_eq!(forward.resolve(0), Jump::Break);
+		assert_eq!(backward.resolve(0), Jump::Continue);
+	}
+
+	#[test]
+	fn resolves_shared_exit_to_break() {
+		let label_list = label_list(&[
+			(None, false),
+			(Some(LabelType::Forward), false),
+			(Some(LabelType::Forward), true),
+		]);
+
+		assert_eq!(label_list.resolve(1), Jump::Break);
+	}
+
+	#[test]
+	fn resolves_outer_label_to_desired() {
+		let forward = label_list(&[
+			(None, false),
+			(Some(LabelType::Forward), false),
+			(Some(LabelType::Forward), false),
+		]);
+		let backward = label_list(&[
+			(None, false),
+			(Some(LabelType::Backward), false),
+			(Some(LabelType::Forward), true),
+		]);
+
+		assert_eq!(forward.resolve(1), Jump::Desired(1));
+		assert_eq!(backward.resolve(1), Jump::Desired(1));
+	}
+
+	#[test]
+	fn resolves_label_outside_try_to_unwind() {
+		let mut label_list = label_list(&[(None, false), (Some(LabelType::Forward), false)]);
+
+		label_list.push_try();
+		label_list.push(None, false);
+		label_list.push(Some(LabelType::Backward), false);
+
+		assert_eq!(label_list.resolve(0), Jump::Continue);
+		assert_eq!(label_list.resolve(2), Jump::Unwind(1));
+		assert_eq!(label_list.resolve(3), Jump::Unwind(0));
+
+		label_list.pop();
+
+		assert_eq!(label_list.innermost_loop(), None);
+	}
+}
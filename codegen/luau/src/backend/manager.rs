@@ -1,11 +1,23 @@
 use std::{
+	cell::RefCell,
 	collections::HashMap,
 	io::{Result, Write},
+	rc::Rc,
 };
 
-use wasm_ast::node::{BrTable, FuncData, LabelType};
+use wasm_ast::{
+	node::{Block, BrTable, FuncData},
+	source_map::SourceMap,
+};
+
+use crate::analyzer::{
+	br_target, localize,
+	range::{self, Range},
+	spill::{self, Spill},
+	structure::{self, LabelList, Structure},
+};
 
-use crate::analyzer::{br_target, localize};
+use super::name_list::NameList;
 
 #[macro_export]
 macro_rules! indentation {
@@ -32,62 +44,80 @@ macro_rules! line {
 	}};
 }
 
-fn get_pinned_registers(
-	upvalues: usize,
-	params: usize,
-	locals: usize,
-	temporaries: usize,
-) -> (usize, usize) {
+fn get_available_registers(upvalues: usize, params: usize) -> usize {
 	const MAX_LOCAL_COUNT: usize = 180;
 
-	let available = MAX_LOCAL_COUNT
+	MAX_LOCAL_COUNT
 		.saturating_sub(upvalues)
-		.saturating_sub(params);
-
-	let temporaries = available.min(temporaries);
-	let locals = available.saturating_sub(temporaries).min(locals);
-
-	(params + locals, temporaries)
+		.saturating_sub(params)
 }
 
 pub struct Manager {
 	table_map: HashMap<usize, usize>,
-	has_branch: bool,
-	num_local: usize,
-	num_temp: usize,
-	label_list: Vec<Option<LabelType>>,
+	structure: Option<Structure>,
+	num_result: usize,
+	range: Range,
+	spill: Spill,
+	label_list: LabelList,
+	delegate_list: Vec<bool>,
 	indentation: usize,
+	name_list: Rc<NameList>,
+	function: usize,
+	source_map: Option<Rc<RefCell<SourceMap>>>,
+	has_tail_call: bool,
 }
 
 impl Manager {
-	pub fn empty() -> Self {
+	pub fn empty(name_list: Rc<NameList>) -> Self {
 		Self {
 			table_map: HashMap::new(),
-			has_branch: false,
-			num_local: 0,
-			num_temp: usize::MAX,
-			label_list: Vec::new(),
+			structure: None,
+			num_result: 0,
+			range: Range::default(),
+			spill: Spill::default(),
+			label_list: LabelList::default(),
+			delegate_list: Vec::new(),
 			indentation: 0,
+			name_list,
+			function: 0,
+			source_map: None,
+			has_tail_call: false,
 		}
 	}
 
-	pub fn function(ast: &FuncData) -> Self {
+	pub fn function(
+		ast: &FuncData,
+		name_list: Rc<NameList>,
+		function: usize,
+		source_map: Option<Rc<RefCell<SourceMap>>>,
+	) -> Self {
 		let (upvalues, memories) = localize::visit(ast);
-		let (table_map, has_branch) = br_target::visit(ast);
-		let (num_local, num_temp) = get_pinned_registers(
-			upvalues.len() + memories.len(),
-			ast.num_param(),
-			ast.local_data().len(),
-			ast.num_stack(),
-		);
+		let has_tail_call = upvalues.contains(&("tail", "CALL"));
+		let table_map = br_target::visit(ast);
+		let structure = structure::visit(ast);
+		let range = range::visit(ast);
+		let available = get_available_registers(upvalues.len() + memories.len(), ast.num_param());
+		let spill = spill::visit(ast, available);
 
 		Self {
 			table_map,
-			has_branch,
-			num_local,
-			num_temp,
-			label_list: Vec::new(),
+			structure: Some(structure),
+			num_result: ast.num_result(),
+			range,
+			spill,
+			label_list: LabelList::default(),
+			delegate_list: Vec::new(),
 			indentation: 0,
+			name_list,
+			function,
+			source_map,
+			has_tail_call,
+		}
+	}
+
+	pub fn add_code_offset(&self, offset: usize) {
+		if let Some(source_map) = &self.source_map {
+			source_map.borrow_mut().add_offset(offset);
 		}
 	}
 
@@ -97,32 +127,81 @@ impl Manager {
 		self.table_map[&id]
 	}
 
+	// Functions with tail calls are wrapped in a trampoline that runs them
+	pub const fn has_tail_call(&self) -> bool {
+		self.has_tail_call
+	}
+
 	pub fn has_table(&self) -> bool {
 		!self.table_map.is_empty()
 	}
 
-	pub const fn has_branch(&self) -> bool {
-		self.has_branch
+	pub fn has_desired(&self) -> bool {
+		self.structure.as_ref().is_some_and(Structure::has_desired)
+	}
+
+	pub fn has_check<T>(&self, node: &T) -> bool {
+		self.structure.as_ref().is_some_and(|v| v.has_check(node))
 	}
 
-	pub const fn num_local(&self) -> usize {
-		self.num_local
+	pub const fn num_result(&self) -> usize {
+		self.num_result
 	}
 
-	pub const fn num_temp(&self) -> usize {
-		self.num_temp
+	pub const fn range(&self) -> &Range {
+		&self.range
 	}
 
-	pub fn label_list(&self) -> &[Option<LabelType>] {
+	pub const fn spill(&self) -> &Spill {
+		&self.spill
+	}
+
+	pub const fn label_list(&self) -> &LabelList {
 		&self.label_list
 	}
 
-	pub fn push_label(&mut self, label: Option<LabelType>) {
-		self.label_list.push(label);
+	pub fn push_label(&mut self, block: &Block) {
+		let is_tail = self.structure.as_ref().is_some_and(|v| v.is_tail(block));
+
+		self.label_list.push(block.label_type(), is_tail);
 	}
 
 	pub fn pop_label(&mut self) {
-		self.label_list.pop().unwrap();
+		self.label_list.pop();
+	}
+
+	pub fn push_try(&mut self) {
+		self.label_list.push_try();
+		self.delegate_list.push(false);
+	}
+
+	// Also returns whether the handlers may receive an exception wrapped by `delegate`
+	pub fn pop_try(&mut self) -> bool {
+		self.label_list.pop_try();
+		self.delegate_list.pop().unwrap()
+	}
+
+	// Exceptions delegated past an open `try` body are wrapped with their
+	// target level, so every handler on the way has to unwrap or pass them
+	pub fn add_delegate(&mut self, level: usize) -> bool {
+		if self.label_list.try_start() <= level {
+			return false;
+		}
+
+		self.delegate_list.fill(true);
+
+		true
+	}
+
+	pub fn name_list(&self) -> &NameList {
+		&self.name_list
+	}
+
+	pub fn write_local(&self, var: usize, w: &mut dyn Write) -> Result<()> {
+		match self.name_list.local_name(self.function, var) {
+			Some(name) => write!(w, "loc_{name}"),
+			None => write!(w, "loc_{var}"),
+		}
 	}
 
 	pub const fn indentation(&self) -> usize {
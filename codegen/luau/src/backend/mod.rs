@@ -1,4 +1,5 @@
 pub mod manager;
+pub mod name_list;
 
 mod expression;
 mod statement;
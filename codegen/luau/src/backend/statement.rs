@@ -4,17 +4,22 @@ use std::{
 };
 
 use wasm_ast::node::{
-	Block, Br, BrIf, BrTable, Call, CallIndirect, FuncData, If, LabelType, MemoryCopy, MemoryFill,
-	MemoryGrow, ResultList, SetGlobal, SetLocal, SetTemporary, Statement, StoreAt, Terminator,
+	AtomicWait, Block, Br, BrIf, BrTable, Call, CallIndirect, DataDrop, ElemDrop, Expression,
+	FuncData, If, IndexType, LabelType, MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, ResultList,
+	Rethrow, ReturnCall, ReturnCallIndirect, SetGlobal, SetLocal, SetTemporary, Statement, StoreAt,
+	TableCopy, TableFill, TableGet, TableGrow, TableInit, TableSet, TableSize, Terminator, Throw,
+	Try,
 };
 use wasmparser::ValType;
 
 use crate::{
-	analyzer::into_string::IntoName, backend::manager::write_separated, indentation, indented, line,
+	analyzer::{into_string::IntoName, structure::Jump},
+	backend::manager::write_separated,
+	indentation, indented, line,
 };
 
 use super::{
-	expression::Condition,
+	expression::{Address, Condition},
 	manager::{Driver, Manager},
 };
 
@@ -34,21 +39,31 @@ impl Driver for Br {
 			writeln!(w)?;
 		}
 
-		if self.target() == 0 {
-			if mng.label_list().last() == Some(&Some(LabelType::Backward)) {
-				line!(mng, w, "continue")
-			} else {
+		match mng.label_list().resolve(self.target()) {
+			Jump::Break => line!(mng, w, "break"),
+			Jump::Continue => line!(mng, w, "continue"),
+			Jump::Return => write_return(mng, w),
+			Jump::Unwind(level) => line!(mng, w, "do return {level} end"),
+			Jump::Desired(level) => {
+				line!(mng, w, "desired = {level}")?;
 				line!(mng, w, "break")
 			}
-		} else {
-			let level = mng.label_list().len() - 1 - self.target();
-
-			line!(mng, w, "desired = {level}")?;
-			line!(mng, w, "break")
 		}
 	}
 }
 
+// Branches to the function body return the values they carry
+// directly instead of breaking out of every loop on the way
+fn write_return(mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+	if mng.num_result() == 0 {
+		return line!(mng, w, "do return end");
+	}
+
+	indented!(mng, w, "do return ")?;
+	ResultList::new(0, mng.num_result()).write(mng, w)?;
+	writeln!(w, " end")
+}
+
 fn to_ordered_table(list: &[Br], default: Br) -> Vec<Br> {
 	let mut data: Vec<_> = list
 		.iter()
@@ -141,64 +156,142 @@ impl Driver for BrTable {
 	}
 }
 
+fn write_tail_parameter_list(
+	list: &[Expression],
+	mng: &mut Manager,
+	w: &mut dyn Write,
+) -> Result<()> {
+	list.iter().try_for_each(|v| {
+		write!(w, ", ")?;
+		v.write(mng, w)
+	})?;
+
+	writeln!(w, " end")
+}
+
+// Lua only allows `return` as the last statement of a block, so tail calls
+// are wrapped to stay valid wherever the terminator is placed. Luau does not
+// eliminate tail calls, so the callee is returned to the trampoline of the
+// function to be run there instead, keeping deep recursion off the stack.
+impl Driver for ReturnCall {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		indented!(mng, w, "do return tail_CALL, ")?;
+		mng.name_list().write_function(self.function(), w)?;
+		write_tail_parameter_list(self.param_list(), mng, w)
+	}
+}
+
+impl Driver for ReturnCallIndirect {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		indented!(mng, w, "do return tail_CALL, ")?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ".data[")?;
+		self.index().write(mng, w)?;
+		write!(w, "]")?;
+		write_tail_parameter_list(self.param_list(), mng, w)
+	}
+}
+
+impl Driver for Throw {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		indented!(mng, w, "rt.exception.throw(TAG_LIST[{}]", self.tag())?;
+
+		self.param_list().iter().try_for_each(|v| {
+			write!(w, ", ")?;
+			v.write(mng, w)
+		})?;
+
+		writeln!(w, ")")
+	}
+}
+
+impl Driver for Rethrow {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let level = mng.label_list().len() - 1 - self.target();
+
+		line!(mng, w, "error(exception_{level}, 0)")
+	}
+}
+
 impl Driver for Terminator {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		mng.add_code_offset(self.code_offset());
+
 		match self {
-			Self::Unreachable => line!(mng, w, r#"error("out of code bounds")"#),
+			Self::Unreachable(_) => line!(mng, w, r#"error("out of code bounds")"#),
 			Self::Br(s) => s.write(mng, w),
 			Self::BrTable(s) => s.write(mng, w),
+			Self::ReturnCall(s) => s.write(mng, w),
+			Self::ReturnCallIndirect(s) => s.write(mng, w),
+			Self::Throw(s) => s.write(mng, w),
+			Self::Rethrow(s) => s.write(mng, w),
 		}
 	}
 }
 
 fn write_br_parent(mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
-	if !mng.has_branch() || mng.label_list().iter().all(Option::is_none) {
+	// The top of a `try` body has nothing to break out of
+	let Some((level, label_type)) = mng.label_list().innermost_loop() else {
 		return Ok(());
-	}
+	};
 
 	line!(mng, w, "if desired then")?;
 	mng.indent();
+	line!(mng, w, "if desired == {level} then")?;
+	mng.indent();
+	line!(mng, w, "desired = nil")?;
 
-	if let Some(last) = *mng.label_list().last().unwrap() {
-		let level = mng.label_list().len() - 1;
-
-		line!(mng, w, "if desired == {level} then")?;
-		mng.indent();
-		line!(mng, w, "desired = nil")?;
-
-		if last == LabelType::Backward {
-			line!(mng, w, "continue")?;
-		}
-
-		mng.dedent();
-		line!(mng, w, "end")?;
+	if label_type == LabelType::Backward {
+		line!(mng, w, "continue")?;
 	}
 
+	mng.dedent();
+	line!(mng, w, "end")?;
 	line!(mng, w, "break")?;
-
 	mng.dedent();
 	line!(mng, w, "end")
 }
 
-impl Driver for Block {
-	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
-		mng.push_label(self.label_type());
+// Only blocks that are branched to need a loop to break out of. The others
+// are written as they are, in a `do` block unless they already fill one.
+fn write_block(block: &Block, is_scoped: bool, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+	let is_loop = block.label_type().is_some();
+
+	mng.push_label(block);
 
+	if is_loop {
 		line!(mng, w, "while true do")?;
 		mng.indent();
+	} else if !is_scoped {
+		line!(mng, w, "do")?;
+		mng.indent();
+	}
 
-		self.code().iter().try_for_each(|s| s.write(mng, w))?;
+	block.code().iter().try_for_each(|s| s.write(mng, w))?;
 
-		match self.last() {
-			Some(v) => v.write(mng, w)?,
-			None => line!(mng, w, "break")?,
-		}
+	match block.last() {
+		Some(v) => v.write(mng, w)?,
+		None if is_loop => line!(mng, w, "break")?,
+		None => {}
+	}
 
+	if is_loop || !is_scoped {
 		mng.dedent();
 		line!(mng, w, "end")?;
+	}
 
-		mng.pop_label();
-		write_br_parent(mng, w)
+	mng.pop_label();
+
+	if is_loop && mng.has_check(block) {
+		write_br_parent(mng, w)?;
+	}
+
+	Ok(())
+}
+
+impl Driver for Block {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		write_block(self, false, mng, w)
 	}
 }
 
@@ -221,13 +314,13 @@ impl Driver for If {
 		writeln!(w, " then")?;
 
 		mng.indent();
-		self.on_true().write(mng, w)?;
+		write_block(self.on_true(), true, mng, w)?;
 		mng.dedent();
 
 		if let Some(v) = self.on_false() {
 			line!(mng, w, "else")?;
 			mng.indent();
-			v.write(mng, w)?;
+			write_block(v, true, mng, w)?;
 			mng.dedent();
 		}
 
@@ -235,6 +328,151 @@ impl Driver for If {
 	}
 }
 
+// Exceptions leaving the body unhandled are passed on to the `try` targeted
+// by `delegate`, wrapped whenever they have to skip the handlers on the way.
+fn write_rethrow(
+	data: &Try,
+	level: usize,
+	is_delegated: bool,
+	mng: &mut Manager,
+	w: &mut dyn Write,
+) -> Result<()> {
+	if let Some(target) = data.delegate() {
+		let target = level - 1 - target;
+
+		if mng.add_delegate(target) {
+			return line!(
+				mng,
+				w,
+				"error(rt.exception.delegate(exception_{level}, {target}), 0)"
+			);
+		}
+	}
+
+	// Wrapped exceptions are never let out of the function
+	if is_delegated && mng.label_list().try_start() == 0 {
+		line!(
+			mng,
+			w,
+			"error(rt.exception.resolve(exception_{level}, 0), 0)"
+		)
+	} else {
+		line!(mng, w, "error(exception_{level}, 0)")
+	}
+}
+
+fn write_catch_list(
+	data: &Try,
+	level: usize,
+	is_delegated: bool,
+	mng: &mut Manager,
+	w: &mut dyn Write,
+) -> Result<()> {
+	let mut head = "if";
+
+	if is_delegated {
+		line!(
+			mng,
+			w,
+			"local exception_{level} = rt.exception.resolve(result, {level})"
+		)?;
+	} else {
+		line!(mng, w, "local exception_{level} = result")?;
+	}
+
+	for catch in data.catch_list() {
+		let tag = catch.tag();
+
+		line!(
+			mng,
+			w,
+			"{head} rt.exception.matches(exception_{level}, TAG_LIST[{tag}]) then"
+		)?;
+		mng.indent();
+
+		if !catch.payload().is_empty() {
+			indentation!(mng, w)?;
+			catch.payload().write(mng, w)?;
+			writeln!(w, " = rt.exception.unpack(exception_{level})")?;
+		}
+
+		write_block(catch.block(), true, mng, w)?;
+		mng.dedent();
+
+		head = "elseif";
+	}
+
+	if let Some(block) = data.catch_all() {
+		line!(mng, w, "{head} rt.exception.is(exception_{level}) then")?;
+		mng.indent();
+		write_block(block, true, mng, w)?;
+		mng.dedent();
+
+		head = "elseif";
+	}
+
+	// Traps and other Lua errors are never caught, not even by `catch_all`,
+	// so they continue unwinding past every handler
+	if head == "if" {
+		return write_rethrow(data, level, is_delegated, mng, w);
+	}
+
+	line!(mng, w, "else")?;
+	mng.indent();
+	write_rethrow(data, level, is_delegated, mng, w)?;
+	mng.dedent();
+	line!(mng, w, "end")
+}
+
+// Branches out of the body are returned as the level they target,
+// which is then used to continue the break chain outside of it.
+impl Driver for Try {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let level = mng.label_list().len();
+
+		line!(mng, w, "do")?;
+		mng.indent();
+		line!(mng, w, "local success, result = pcall(function()")?;
+		mng.indent();
+		mng.push_try();
+		write_block(self.body(), true, mng, w)?;
+		let is_delegated = mng.pop_try();
+		mng.dedent();
+		line!(mng, w, "end)")?;
+
+		line!(mng, w, "if not success then")?;
+		mng.indent();
+		write_catch_list(self, level, is_delegated, mng, w)?;
+		mng.dedent();
+
+		if mng.has_check(self) {
+			line!(mng, w, "elseif result then")?;
+			mng.indent();
+
+			if mng.label_list().try_start() != 0 {
+				line!(mng, w, "if result < {} then", mng.label_list().try_start())?;
+				mng.indent();
+				line!(mng, w, "return result")?;
+				mng.dedent();
+				line!(mng, w, "end")?;
+			}
+
+			line!(mng, w, "desired = result")?;
+			mng.dedent();
+		}
+
+		line!(mng, w, "end")?;
+		mng.dedent();
+		line!(mng, w, "end")?;
+
+		if mng.has_check(self) {
+			write_br_parent(mng, w)?;
+		}
+
+		Ok(())
+	}
+}
+
 impl Driver for Call {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		if !self.result_list().is_empty() {
@@ -242,7 +480,8 @@ impl Driver for Call {
 			write!(w, " = ")?;
 		}
 
-		write!(w, "FUNC_LIST[{}](", self.function())?;
+		mng.name_list().write_function(self.function(), w)?;
+		write!(w, "(")?;
 		self.param_list().write(mng, w)?;
 		write!(w, ")")
 	}
@@ -255,7 +494,8 @@ impl Driver for CallIndirect {
 			write!(w, " = ")?;
 		}
 
-		write!(w, "TABLE_LIST[{}].data[", self.table())?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ".data[")?;
 		self.index().write(mng, w)?;
 		write!(w, "](")?;
 		self.param_list().write(mng, w)?;
@@ -281,7 +521,8 @@ impl Driver for SetLocal {
 
 impl Driver for SetGlobal {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
-		write!(w, "GLOBAL_LIST[{}].value = ", self.var())?;
+		mng.name_list().write_global(self.var(), w)?;
+		write!(w, ".value = ")?;
 		self.value().write(mng, w)
 	}
 }
@@ -291,9 +532,13 @@ impl Driver for StoreAt {
 		let name = self.store_type().into_name();
 		let memory = self.memory();
 
+		if self.is_atomic() {
+			write!(w, "atomic_")?;
+		}
+
 		write!(w, "store_{name}(memory_at_{memory}, ")?;
 
-		self.pointer().write(mng, w)?;
+		Address(self.index_type(), self.pointer()).write(mng, w)?;
 
 		if self.offset() != 0 {
 			write!(w, " + {}", self.offset())?;
@@ -309,9 +554,37 @@ impl Driver for MemoryGrow {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		let memory = self.memory();
 
+		let index_type = self.index_type();
+
 		self.result().write(mng, w)?;
-		write!(w, " = rt.allocator.grow(memory_at_{memory}, ")?;
-		self.size().write(mng, w)?;
+
+		// Failure is signalled with `-1`, so the result is sign extended
+		if index_type == IndexType::I64 {
+			write!(
+				w,
+				" = extend_i64_i32(rt.allocator.grow(memory_at_{memory}, "
+			)?;
+			Address(index_type, self.size()).write(mng, w)?;
+			write!(w, "))")
+		} else {
+			write!(w, " = rt.allocator.grow(memory_at_{memory}, ")?;
+			self.size().write(mng, w)?;
+			write!(w, ")")
+		}
+	}
+}
+
+impl Driver for AtomicWait {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let name = self.value().load_type().into_name();
+
+		self.result().write(mng, w)?;
+		write!(w, " = rt.atomic.wait_{name}(")?;
+		self.value().write(mng, w)?;
+		write!(w, ", ")?;
+		self.expected().write(mng, w)?;
+		write!(w, ", ")?;
+		self.timeout().write(mng, w)?;
 		write!(w, ")")
 	}
 }
@@ -321,12 +594,22 @@ impl Driver for MemoryCopy {
 		let memory_1 = self.destination().memory();
 		let memory_2 = self.source().memory();
 
+		let destination = self.destination();
+		let source = self.source();
+
+		// The size is only 64-bit when both memories are
+		let size_type = if destination.index_type() == IndexType::I64 {
+			source.index_type()
+		} else {
+			IndexType::I32
+		};
+
 		write!(w, "rt.store.copy(memory_at_{memory_1}, ")?;
-		self.destination().pointer().write(mng, w)?;
+		Address(destination.index_type(), destination.pointer()).write(mng, w)?;
 		write!(w, ", memory_at_{memory_2}, ")?;
-		self.source().pointer().write(mng, w)?;
+		Address(source.index_type(), source.pointer()).write(mng, w)?;
 		write!(w, ", ")?;
-		self.size().write(mng, w)?;
+		Address(size_type, self.size()).write(mng, w)?;
 		write!(w, ")")
 	}
 }
@@ -335,16 +618,142 @@ impl Driver for MemoryFill {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		let memory = self.destination().memory();
 
+		let index_type = self.destination().index_type();
+
 		write!(w, "rt.store.fill(memory_at_{memory}, ")?;
-		self.destination().pointer().write(mng, w)?;
+		Address(index_type, self.destination().pointer()).write(mng, w)?;
+		write!(w, ", ")?;
+		Address(index_type, self.size()).write(mng, w)?;
+		write!(w, ", ")?;
+		self.value().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for MemoryInit {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let memory = self.destination().memory();
+
+		let index_type = self.destination().index_type();
+
+		write!(w, "rt.store.init(memory_at_{memory}, ")?;
+		Address(index_type, self.destination().pointer()).write(mng, w)?;
+		write!(w, ", DATA_LIST[{}], ", self.data())?;
+		self.offset().write(mng, w)?;
 		write!(w, ", ")?;
 		self.size().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for DataDrop {
+	fn write(&self, _mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		write!(w, "DATA_LIST[{}] = nil", self.data())
+	}
+}
+
+impl Driver for TableGet {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		self.result().write(mng, w)?;
+		write!(w, " = rt.table.get(")?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ", ")?;
+		self.index().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableSet {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		write!(w, "rt.table.set(")?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ", ")?;
+		self.index().write(mng, w)?;
 		write!(w, ", ")?;
 		self.value().write(mng, w)?;
 		write!(w, ")")
 	}
 }
 
+impl Driver for TableSize {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		self.result().write(mng, w)?;
+		write!(w, " = rt.table.size(")?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableGrow {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		self.result().write(mng, w)?;
+		write!(w, " = rt.table.grow(")?;
+		mng.name_list().write_table(self.table(), w)?;
+		write!(w, ", ")?;
+		self.value().write(mng, w)?;
+		write!(w, ", ")?;
+		self.size().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableFill {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let table = self.destination().table();
+
+		write!(w, "rt.table.fill(")?;
+		mng.name_list().write_table(table, w)?;
+		write!(w, ", ")?;
+		self.destination().index().write(mng, w)?;
+		write!(w, ", ")?;
+		self.value().write(mng, w)?;
+		write!(w, ", ")?;
+		self.size().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableCopy {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let table_1 = self.destination().table();
+		let table_2 = self.source().table();
+
+		write!(w, "rt.table.copy(")?;
+		mng.name_list().write_table(table_1, w)?;
+		write!(w, ", ")?;
+		self.destination().index().write(mng, w)?;
+		write!(w, ", ")?;
+		mng.name_list().write_table(table_2, w)?;
+		write!(w, ", ")?;
+		self.source().index().write(mng, w)?;
+		write!(w, ", ")?;
+		self.size().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for TableInit {
+	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		let table = self.destination().table();
+
+		write!(w, "rt.table.init(")?;
+		mng.name_list().write_table(table, w)?;
+		write!(w, ", ")?;
+		self.destination().index().write(mng, w)?;
+		write!(w, ", ELEMENT_LIST[{}], ", self.element())?;
+		self.offset().write(mng, w)?;
+		write!(w, ", ")?;
+		self.size().write(mng, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Driver for ElemDrop {
+	fn write(&self, _mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		write!(w, "ELEMENT_LIST[{}] = nil", self.element())
+	}
+}
+
 fn write_stat(stat: &dyn Driver, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 	indentation!(mng, w)?;
 	stat.write(mng, w)?;
@@ -353,6 +762,8 @@ fn write_stat(stat: &dyn Driver, mng: &mut Manager, w: &mut dyn Write) -> Result
 
 impl Driver for Statement {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
+		mng.add_code_offset(self.code_offset());
+
 		match self {
 			Self::Block(s) => s.write(mng, w),
 			Self::BrIf(s) => s.write(mng, w),
@@ -364,58 +775,76 @@ impl Driver for Statement {
 			Self::SetGlobal(s) => write_stat(s, mng, w),
 			Self::StoreAt(s) => write_stat(s, mng, w),
 			Self::MemoryGrow(s) => write_stat(s, mng, w),
+			Self::AtomicWait(s) => write_stat(s, mng, w),
 			Self::MemoryCopy(s) => write_stat(s, mng, w),
 			Self::MemoryFill(s) => write_stat(s, mng, w),
+			Self::MemoryInit(s) => write_stat(s, mng, w),
+			Self::DataDrop(s) => write_stat(s, mng, w),
+			Self::TableGet(s) => write_stat(s, mng, w),
+			Self::TableSet(s) => write_stat(s, mng, w),
+			Self::TableSize(s) => write_stat(s, mng, w),
+			Self::TableGrow(s) => write_stat(s, mng, w),
+			Self::TableFill(s) => write_stat(s, mng, w),
+			Self::TableCopy(s) => write_stat(s, mng, w),
+			Self::TableInit(s) => write_stat(s, mng, w),
+			Self::ElemDrop(s) => write_stat(s, mng, w),
+			Self::Try(s) => s.write(mng, w),
 		}
 	}
 }
 
-fn write_parameter_list(ast: &FuncData, w: &mut dyn Write) -> Result<()> {
+fn write_parameter_list(ast: &FuncData, mng: &Manager, w: &mut dyn Write) -> Result<()> {
 	write!(w, "function(")?;
-	write_separated(0..ast.num_param(), |i, w| write!(w, "loc_{i}"), w)?;
+	write_separated(0..ast.num_param(), |i, w| mng.write_local(i, w), w)?;
 	writeln!(w, ")")
 }
 
 const fn type_to_zero(typ: ValType) -> &'static str {
 	match typ {
 		ValType::F32 | ValType::F64 => "0.0",
+		ValType::Ref(_) => "nil",
+		ValType::V128 => "v128_ZERO",
 		ValType::I64 => "i64_ZERO",
-		_ => "0",
+		ValType::I32 => "0",
 	}
 }
 
 fn write_variable_list(ast: &FuncData, mng: &Manager, w: &mut dyn Write) -> Result<()> {
-	let mut locals = ast.local_data().iter().copied();
-	let num_local = mng.num_local() - ast.num_param();
+	let mut spilled = Vec::new();
 
-	for (i, typ) in locals.by_ref().enumerate().take(num_local) {
+	for (i, &typ) in ast.local_data().iter().enumerate() {
 		let index = ast.num_param() + i;
 		let zero = type_to_zero(typ);
 
-		line!(mng, w, "local loc_{index} = {zero}")?;
+		if mng.spill().local(index).is_some() {
+			spilled.push(zero);
+			continue;
+		}
+
+		indented!(mng, w, "local ")?;
+		mng.write_local(index, w)?;
+		writeln!(w, " = {zero}")?;
 	}
 
-	if locals.len() != 0 {
+	if !spilled.is_empty() {
 		indented!(mng, w, "local loc_spill = {{ ")?;
 
-		for typ in locals {
-			let zero = type_to_zero(typ);
-
+		for zero in spilled {
 			write!(w, "{zero}, ")?;
 		}
 
 		writeln!(w, "}}")?;
 	}
 
-	let mut temporaries = 0..ast.num_stack();
-
-	for i in temporaries.by_ref().take(mng.num_temp()) {
-		line!(mng, w, "local reg_{i}")?;
+	for i in 0..ast.num_stack() {
+		if mng.spill().temporary(i).is_none() {
+			line!(mng, w, "local reg_{i}")?;
+		}
 	}
 
-	if !temporaries.is_empty() {
-		let len = temporaries.len();
+	let len = mng.spill().num_temporary();
 
+	if len != 0 {
 		line!(mng, w, "local reg_spill = table.create({len})")?;
 	}
 
@@ -426,10 +855,14 @@ impl Driver for FuncData {
 	fn write(&self, mng: &mut Manager, w: &mut dyn Write) -> Result<()> {
 		mng.indent();
 
-		write_parameter_list(self, w)?;
+		if mng.has_tail_call() {
+			write!(w, "rt.tail.new(")?;
+		}
+
+		write_parameter_list(self, mng, w)?;
 		write_variable_list(self, mng, w)?;
 
-		if mng.has_branch() {
+		if mng.has_desired() {
 			line!(mng, w, "local desired")?;
 		}
 
@@ -437,7 +870,7 @@ impl Driver for FuncData {
 			line!(mng, w, "local br_map = {{}}")?;
 		}
 
-		self.code().write(mng, w)?;
+		write_block(self.code(), true, mng, w)?;
 
 		if self.num_result() != 0 {
 			indented!(mng, w, "return ")?;
@@ -449,6 +882,10 @@ impl Driver for FuncData {
 
 		mng.dedent();
 
-		line!(mng, w, "end")
+		if mng.has_tail_call() {
+			line!(mng, w, "end)")
+		} else {
+			line!(mng, w, "end")
+		}
 	}
 }
@@ -1,18 +1,83 @@
 use std::io::{ErrorKind, Result, Write};
 
-use wasm_ast::module::Module;
+use wasm_ast::{
+	dwarf::LineTable,
+	module::{Module, TypeInfo},
+	optimize::Settings,
+	source_map::SourceMap,
+	Error,
+};
 
-fn load_arg_source() -> Result<Vec<u8>> {
+#[derive(PartialEq, Eq)]
+enum Emit {
+	Lua,
+	Ast,
+	Cfg,
+	Wasm,
+}
+
+struct Arguments {
+	data: Vec<u8>,
+	file: String,
+	has_names: bool,
+	has_dwarf: bool,
+	emit: Emit,
+	source_map: Option<String>,
+	settings: Settings,
+}
+
+fn load_arguments() -> Result<Arguments> {
 	let mut arguments = std::env::args();
 	let path = arguments.next().unwrap_or_else(|| "wasm2luau".to_string());
 
-	arguments.next().map_or_else(
+	let mut has_names = false;
+	let mut has_dwarf = false;
+	let mut emit = Emit::Lua;
+	let mut source_map = None;
+	let mut settings = codegen_luau::default_settings();
+	let mut file = None;
+
+	for argument in arguments {
+		match argument.as_str() {
+			"--names" => has_names = true,
+			"--dwarf" => has_dwarf = true,
+			"--emit=lua" => emit = Emit::Lua,
+			"--emit=ast" => emit = Emit::Ast,
+			"--emit=cfg" => emit = Emit::Cfg,
+			"--emit=wasm" => emit = Emit::Wasm,
+			_ => {
+				if let Some(path) = argument.strip_prefix("--source-map=") {
+					source_map = Some(path.to_string());
+				} else if let Some(size) = argument.strip_prefix("--inline-size=") {
+					let size = size.parse().map_err(|_| ErrorKind::InvalidInput)?;
+
+					settings.inline_max_size = Some(size);
+				} else {
+					file = Some(argument);
+				}
+			}
+		}
+	}
+
+	file.map_or_else(
 		|| {
-			eprintln!("usage: {path} <file>\n");
+			eprintln!(
+				"usage: {path} [--names] [--inline-size=<n>] [--emit=lua|ast|cfg|wasm] [--source-map=<path> [--dwarf]] <file>\n"
+			);
 
 			Err(ErrorKind::NotFound.into())
 		},
-		std::fs::read,
+		|file| {
+			std::fs::read(&file).map(|data| Arguments {
+				data,
+				file,
+				has_names,
+				has_dwarf,
+				emit,
+				source_map,
+				settings,
+			})
+		},
 	)
 }
 
@@ -30,11 +95,81 @@ fn do_runtime(lock: &mut dyn Write) -> Result<()> {
 }
 
 fn main() -> Result<()> {
-	let data = load_arg_source()?;
-	let wasm = Module::try_from_data(&data).unwrap();
+	let arguments = load_arguments()?;
+	let wasm = Module::try_from_data(&arguments.data).map_err(Error::from)?;
 
 	let lock = &mut std::io::stdout().lock();
 
-	do_runtime(lock)?;
-	codegen_luau::from_module_untyped(&wasm, lock)
+	if arguments.emit == Emit::Ast {
+		let type_info = TypeInfo::from_module(&wasm);
+
+		codegen_luau::write_ast(&wasm, &type_info, &arguments.settings, lock)?;
+
+		return Ok(());
+	}
+
+	if arguments.emit == Emit::Cfg {
+		let type_info = TypeInfo::from_module(&wasm);
+
+		codegen_luau::write_cfg(&wasm, &type_info, &arguments.settings, lock)?;
+
+		return Ok(());
+	}
+
+	if arguments.emit == Emit::Wasm {
+		let type_info = TypeInfo::from_module(&wasm);
+
+		codegen_luau::write_wasm(&wasm, &type_info, &arguments.settings, lock)?;
+
+		return Ok(());
+	}
+
+	let mut runtime = Vec::new();
+
+	do_runtime(&mut runtime)?;
+	lock.write_all(&runtime)?;
+
+	let type_info = TypeInfo::from_module(&wasm);
+
+	// Lines are mapped for the whole output, runtime included
+	if let Some(path) = &arguments.source_map {
+		let mut source_map = SourceMap::default();
+
+		source_map.add_text(&runtime);
+
+		if arguments.has_names {
+			codegen_luau::from_module_named_mapped(
+				&wasm,
+				&type_info,
+				&arguments.settings,
+				&mut source_map,
+				lock,
+			)?;
+		} else {
+			codegen_luau::from_module_typed_mapped(
+				&wasm,
+				&type_info,
+				&arguments.settings,
+				&mut source_map,
+				lock,
+			)?;
+		}
+
+		let file = &mut std::fs::File::create(path)?;
+
+		// With `--dwarf` lines map to the files the module was compiled from
+		if arguments.has_dwarf {
+			let line_table = LineTable::from_module(&wasm)?;
+
+			source_map.write_json_dwarf(&line_table, file)?;
+		} else {
+			source_map.write_json(&arguments.file, file)?;
+		}
+	} else if arguments.has_names {
+		codegen_luau::from_module_named(&wasm, &type_info, &arguments.settings, lock)?;
+	} else {
+		codegen_luau::from_module_typed(&wasm, &type_info, &arguments.settings, lock)?;
+	}
+
+	Ok(())
 }
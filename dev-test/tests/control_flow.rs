@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+
+use wasm_ast::{
+	cfg::{
+		dominator::{Dominators, LoopNest},
+		Cfg, ENTRY,
+	},
+	factory::Factory,
+	module::{External, Module, TypeInfo},
+	node::FuncData,
+};
+use wast::{parser::ParseBuffer, QuoteWat, Wast, WastDirective, Wat};
+
+static DO_NOT_RUN: [&str; 2] = ["names.wast", "skip-stack-guard-page.wast"];
+
+fn test_func(name: &str, func: &FuncData) {
+	let cfg = Cfg::from_func(func);
+	let dominators = Dominators::from_cfg(&cfg);
+	let loop_nest = LoopNest::from_cfg(&cfg, &dominators);
+
+	for (id, node) in cfg.node_list().iter().enumerate() {
+		for successor in node.successors() {
+			let list = cfg.node_list()[successor].predecessor_list();
+
+			assert!(
+				list.contains(&id),
+				"{name}: edge {id} -> {successor} is one-sided"
+			);
+		}
+
+		if !dominators.is_reachable(id) {
+			continue;
+		}
+
+		assert!(
+			dominators.dominates(ENTRY, id),
+			"{name}: entry must dominate {id}"
+		);
+
+		if let Some(idom) = dominators.immediate_dominator(id) {
+			for &predecessor in node.predecessor_list() {
+				if dominators.is_reachable(predecessor) {
+					assert!(
+						dominators.dominates(idom, predecessor),
+						"{name}: bad idom of {id}"
+					);
+				}
+			}
+		}
+	}
+
+	assert!(
+		loop_nest.is_reducible(),
+		"{name}: structured code must be reducible"
+	);
+
+	for data in loop_nest.loop_list() {
+		for &id in data.body() {
+			assert!(
+				dominators.dominates(data.header(), id),
+				"{name}: loop escapes header"
+			);
+		}
+	}
+
+	cfg.write_dot(name, &mut std::io::sink()).unwrap();
+}
+
+fn test_module(name: &str, bytes: &[u8]) {
+	let wasm = Module::try_from_data(bytes).unwrap();
+	let type_info = TypeInfo::from_module(&wasm);
+	let offset = wasm.import_count(External::Func);
+	let mut builder = Factory::from_type_info(&type_info);
+
+	for (i, v) in wasm.code_section().iter().enumerate() {
+		let func = builder.create_indexed(i + offset, v).unwrap();
+
+		test_func(name, &func);
+	}
+}
+
+#[test_generator::test_resources("dev-test/spec/*.wast")]
+fn control_flow_file(path: PathBuf) {
+	let path = path.strip_prefix("dev-test/").unwrap();
+	let name = path.file_name().unwrap().to_str().unwrap();
+
+	if DO_NOT_RUN.contains(&name) {
+		return;
+	}
+
+	let source = std::fs::read_to_string(path).unwrap();
+	let lexed = ParseBuffer::new(&source).expect("Failed to tokenize");
+	let parsed: Wast = wast::parser::parse(&lexed).unwrap();
+
+	for variant in parsed.directives {
+		if let WastDirective::Wat(QuoteWat::Wat(Wat::Module(mut module))) = variant {
+			let bytes = module.encode().unwrap();
+
+			test_module(name, &bytes);
+		}
+	}
+}
@@ -49,6 +49,13 @@ do
 	load_checked("i64", 8)
 	load_checked("f32", 4)
 	load_checked("f64", 8)
+	load_checked("v128", 16)
+	load_checked("v128_i8x8", 8)
+	load_checked("v128_u8x8", 8)
+	load_checked("v128_i16x4", 8)
+	load_checked("v128_u16x4", 8)
+	load_checked("v128_i32x2", 8)
+	load_checked("v128_u32x2", 8)
 
 	store_checked("i32_n8", 1)
 	store_checked("i32_n16", 2)
@@ -59,6 +66,7 @@ do
 	store_checked("i64", 8)
 	store_checked("f32", 4)
 	store_checked("f64", 8)
+	store_checked("v128", 16)
 end
 
 local loaded = {}
@@ -77,8 +85,70 @@ local function is_number_equal(lhs, rhs)
 	return math.abs(lhs - rhs) < 0.00001 or string.format("%.3g", lhs) == string.format("%.3g", rhs)
 end
 
+-- Expected references that only constrain whether the result is null
+local REF_NULL = newproxy()
+local REF_NON_NULL = newproxy()
+
+local extern_ref_list = {}
+
+local function extern_ref(index)
+	local data = extern_ref_list[index]
+
+	if not data then
+		data = newproxy()
+		extern_ref_list[index] = data
+	end
+
+	return data
+end
+
+local function is_ref_equal(lhs, rhs)
+	if rhs == REF_NULL then
+		return lhs == nil
+	elseif rhs == REF_NON_NULL then
+		return lhs ~= nil
+	end
+
+	return false
+end
+
+-- Expected vectors are compared lane by lane in the shape they were written
+local V128_META = {
+	__tostring = function(data)
+		local list = {}
+
+		for i = 1, data.n do
+			list[i] = tostring(data[i])
+		end
+
+		return data.shape .. "(" .. table.concat(list, ", ") .. ")"
+	end,
+}
+
+local function v128_pattern(shape, ...)
+	return setmetatable({ shape = shape, n = select("#", ...), ... }, V128_META)
+end
+
+local function is_v128_equal(lhs, rhs)
+	if getmetatable(rhs) ~= V128_META or lhs == nil then
+		return false
+	end
+
+	local extract = rt.extract_lane[rhs.shape]
+
+	for i = 1, rhs.n do
+		local data = extract(lhs, i - 1)
+
+		if data ~= rhs[i] and not is_number_equal(data, rhs[i]) then
+			return false
+		end
+	end
+
+	return true
+end
+
 local function assert_eq(lhs, rhs, level)
-	if lhs == rhs or is_number_equal(lhs, rhs) then
+	if lhs == rhs or is_number_equal(lhs, rhs) or is_ref_equal(lhs, rhs) or is_v128_equal(lhs, rhs) then
 		return
 	end
 
@@ -113,6 +183,12 @@ local function assert_trap(func, ...)
 	end
 end
 
+local function assert_trap_strict(func, ...)
+	if pcall(func, ...) then
+		error("Failed to trap", 2)
+	end
+end
+
 local function assert_return(data, wanted)
 	for i, v in ipairs(wanted) do
 		assert_eq(data[i], v, 2)
@@ -140,6 +216,6 @@ linked.spectest = {
 		global_i32 = { value = 666 },
 		global_i64 = { value = 666LL },
 	},
-	table_list = { table = { data = {} } },
+	table_list = { table = { min = 10, max = 20, data = {} } },
 	memory_list = { memory = rt.allocator.new(1, 2) },
 }
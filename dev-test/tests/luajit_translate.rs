@@ -24,6 +24,9 @@ impl LuaJIT {
 			WastArg::Core(WastArgCore::I64(v)) => write!(w, "{v}LL"),
 			WastArg::Core(WastArgCore::F32(v)) => target::write_f32(f32::from_bits(v.bits), w),
 			WastArg::Core(WastArgCore::F64(v)) => target::write_f64(f64::from_bits(v.bits), w),
+			WastArg::Core(WastArgCore::RefNull(_)) => write!(w, "nil"),
+			WastArg::Core(WastArgCore::RefExtern(v)) => write!(w, "extern_ref({v})"),
+			WastArg::Core(WastArgCore::V128(v)) => target::write_v128(v, w),
 			_ => panic!("Unsupported expression"),
 		}
 	}
@@ -34,6 +37,14 @@ impl LuaJIT {
 			WastRet::Core(WastRetCore::I64(v)) => write!(w, "{v}LL"),
 			WastRet::Core(WastRetCore::F32(v)) => target::write_f32_nan(v, w),
 			WastRet::Core(WastRetCore::F64(v)) => target::write_f64_nan(v, w),
+			WastRet::Core(WastRetCore::RefNull(_)) => write!(w, "REF_NULL"),
+			WastRet::Core(WastRetCore::RefExtern(Some(v))) => write!(w, "extern_ref({v})"),
+			WastRet::Core(WastRetCore::RefExtern(None) | WastRetCore::RefFunc(None)) => {
+				write!(w, "REF_NON_NULL")
+			}
+			WastRet::Core(WastRetCore::V128(v)) => {
+				target::write_v128_nan(v, |v, w| write!(w, "{v}"), |v, w| write!(w, "{v}LL"), w)
+			}
 			_ => panic!("Unsupported expression"),
 		}
 	}
@@ -145,8 +156,14 @@ impl Target for LuaJIT {
 	fn write_module(data: &Module, name: Option<&str>, w: &mut dyn Write) -> Result<()> {
 		let type_info = TypeInfo::from_module(data);
 
+		// Anything the text format gave an `$id` is named, the rest stays numbered
 		writeln!(w, r#"loaded["temp"] = (function()"#)?;
-		codegen_luajit::from_module_typed(data, &type_info, w)?;
+		codegen_luajit::from_module_named(
+			data,
+			&type_info,
+			&codegen_luajit::default_settings(),
+			w,
+		)?;
 		writeln!(w, "end)()(linked)")?;
 
 		if let Some(name) = name {
@@ -229,5 +246,34 @@ fn translate_file(path: PathBuf) {
 
 	let source = std::fs::read_to_string(path).unwrap();
 
-	LuaJIT::test(name, &source).unwrap();
+	LuaJIT::test(name, &source, false).unwrap();
+}
+
+// Memory accesses are not bounds checked
+static DO_NOT_RUN_EXTRA: [&str; 1] = ["simd_memory_trap.wast"];
+
+// Proposals and corner cases the standard test suite does not cover
+#[test_generator::test_resources("dev-test/wast/*.wast")]
+fn translate_extra_file(path: PathBuf) {
+	let path = path.strip_prefix("dev-test/").unwrap();
+	let name = path.file_name().unwrap().to_str().unwrap();
+
+	if DO_NOT_RUN_EXTRA.contains(&name) {
+		return;
+	}
+
+	let source = std::fs::read_to_string(path).unwrap();
+
+	LuaJIT::test(name, &source, true).unwrap();
+}
+
+// Threads are only lowered when built with the `atomics` feature
+#[cfg(feature = "atomics")]
+#[test_generator::test_resources("dev-test/wast/atomics/*.wast")]
+fn translate_atomics_file(path: PathBuf) {
+	let path = path.strip_prefix("dev-test/").unwrap();
+	let name = path.file_name().unwrap().to_str().unwrap();
+	let source = std::fs::read_to_string(path).unwrap();
+
+	LuaJIT::test(name, &source, true).unwrap();
 }
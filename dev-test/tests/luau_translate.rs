@@ -38,6 +38,9 @@ impl Luau {
 			WastArg::Core(WastArgCore::I64(v)) => Self::write_i64(*v, w),
 			WastArg::Core(WastArgCore::F32(v)) => target::write_f32(f32::from_bits(v.bits), w),
 			WastArg::Core(WastArgCore::F64(v)) => target::write_f64(f64::from_bits(v.bits), w),
+			WastArg::Core(WastArgCore::RefNull(_)) => write!(w, "nil"),
+			WastArg::Core(WastArgCore::RefExtern(v)) => write!(w, "extern_ref({v})"),
+			WastArg::Core(WastArgCore::V128(v)) => target::write_v128(v, w),
 			_ => panic!("Unsupported expression"),
 		}
 	}
@@ -48,6 +51,14 @@ impl Luau {
 			WastRet::Core(WastRetCore::I64(v)) => Self::write_i64(*v, w),
 			WastRet::Core(WastRetCore::F32(v)) => target::write_f32_nan(v, w),
 			WastRet::Core(WastRetCore::F64(v)) => target::write_f64_nan(v, w),
+			WastRet::Core(WastRetCore::RefNull(_)) => write!(w, "REF_NULL"),
+			WastRet::Core(WastRetCore::RefExtern(Some(v))) => write!(w, "extern_ref({v})"),
+			WastRet::Core(WastRetCore::RefExtern(None) | WastRetCore::RefFunc(None)) => {
+				write!(w, "REF_NON_NULL")
+			}
+			WastRet::Core(WastRetCore::V128(v)) => {
+				target::write_v128_nan(v, Self::write_i32, Self::write_i64, w)
+			}
 			_ => panic!("Unsupported expression"),
 		}
 	}
@@ -163,8 +174,9 @@ impl Target for Luau {
 	fn write_module(data: &Module, name: Option<&str>, w: &mut dyn Write) -> Result<()> {
 		let type_info = TypeInfo::from_module(data);
 
+		// Anything the text format gave an `$id` is named, the rest stays numbered
 		writeln!(w, r#"loaded["temp"] = (function()"#)?;
-		codegen_luau::from_module_typed(data, &type_info, w)?;
+		codegen_luau::from_module_named(data, &type_info, &codegen_luau::default_settings(), w)?;
 		writeln!(w, "end)()(linked)")?;
 
 		if let Some(name) = name {
@@ -247,5 +259,26 @@ fn translate_file(path: PathBuf) {
 
 	let source = std::fs::read_to_string(path).unwrap();
 
-	Luau::test(name, &source).unwrap();
+	Luau::test(name, &source, false).unwrap();
+}
+
+// Proposals and corner cases the standard test suite does not cover
+#[test_generator::test_resources("dev-test/wast/*.wast")]
+fn translate_extra_file(path: PathBuf) {
+	let path = path.strip_prefix("dev-test/").unwrap();
+	let name = path.file_name().unwrap().to_str().unwrap();
+	let source = std::fs::read_to_string(path).unwrap();
+
+	Luau::test(name, &source, true).unwrap();
+}
+
+// Threads are only lowered when built with the `atomics` feature
+#[cfg(feature = "atomics")]
+#[test_generator::test_resources("dev-test/wast/atomics/*.wast")]
+fn translate_atomics_file(path: PathBuf) {
+	let path = path.strip_prefix("dev-test/").unwrap();
+	let name = path.file_name().unwrap().to_str().unwrap();
+	let source = std::fs::read_to_string(path).unwrap();
+
+	Luau::test(name, &source, true).unwrap();
 }
@@ -44,6 +44,74 @@ macro_rules! impl_write_number_nan {
 impl_write_number_nan!(write_f32, write_f32_nan, f32, wast::token::F32);
 impl_write_number_nan!(write_f64, write_f64_nan, f64, wast::token::F64);
 
+#[allow(clippy::missing_errors_doc)]
+pub fn write_v128(data: &wast::core::V128Const, w: &mut dyn Write) -> Result<()> {
+	let data = data.to_le_bytes();
+	let mut iter = data
+		.chunks_exact(4)
+		.map(|v| u32::from_le_bytes(v.try_into().unwrap()));
+
+	write!(w, "rt.v128.from_u32({}", iter.next().unwrap())?;
+	iter.try_for_each(|v| write!(w, ", {v}"))?;
+	write!(w, ")")
+}
+
+type WriteLane<T> = fn(T, &mut dyn Write) -> Result<()>;
+
+// Integer lanes narrower than 32 bits are always compared unsigned
+#[allow(clippy::missing_errors_doc)]
+pub fn write_v128_nan(
+	data: &wast::core::V128Pattern,
+	write_i32: WriteLane<i32>,
+	write_i64: WriteLane<i64>,
+	w: &mut dyn Write,
+) -> Result<()> {
+	use wast::core::V128Pattern;
+
+	match data {
+		V128Pattern::I8x16(list) => {
+			write!(w, r#"v128_pattern("u8x16""#)?;
+			list.iter()
+				.try_for_each(|v| write!(w, ", {}", v.to_ne_bytes()[0]))?;
+		}
+		V128Pattern::I16x8(list) => {
+			write!(w, r#"v128_pattern("u16x8""#)?;
+			list.iter()
+				.try_for_each(|v| write!(w, ", {}", u16::from_ne_bytes(v.to_ne_bytes())))?;
+		}
+		V128Pattern::I32x4(list) => {
+			write!(w, r#"v128_pattern("i32x4""#)?;
+			list.iter().try_for_each(|v| {
+				write!(w, ", ")?;
+				write_i32(*v, w)
+			})?;
+		}
+		V128Pattern::I64x2(list) => {
+			write!(w, r#"v128_pattern("i64x2""#)?;
+			list.iter().try_for_each(|v| {
+				write!(w, ", ")?;
+				write_i64(*v, w)
+			})?;
+		}
+		V128Pattern::F32x4(list) => {
+			write!(w, r#"v128_pattern("f32x4""#)?;
+			list.iter().try_for_each(|v| {
+				write!(w, ", ")?;
+				write_f32_nan(v, w)
+			})?;
+		}
+		V128Pattern::F64x2(list) => {
+			write!(w, r#"v128_pattern("f64x2""#)?;
+			list.iter().try_for_each(|v| {
+				write!(w, ", ")?;
+				write_f64_nan(v, w)
+			})?;
+		}
+	}
+
+	write!(w, ")")
+}
+
 #[allow(clippy::missing_const_for_fn)]
 fn try_into_ast_module(data: QuoteWat) -> Option<WaModule> {
 	if let QuoteWat::Wat(Wat::Module(data)) = data {
@@ -53,6 +121,17 @@ fn try_into_ast_module(data: QuoteWat) -> Option<WaModule> {
 	}
 }
 
+// Text that fails to parse never reaches us, but everything that does
+// encode must be refused while loading the module.
+fn assert_rejected(mut module: QuoteWat, message: &str) {
+	let Ok(bytes) = module.encode() else { return };
+
+	assert!(
+		AstModule::try_from_data(&bytes).is_err(),
+		"module should be rejected: {message}"
+	);
+}
+
 pub fn get_name_from_id(id: Option<Id>) -> &str {
 	id.as_ref().map_or("temp", Id::name)
 }
@@ -109,6 +188,14 @@ pub trait Target: Sized {
 			WastDirective::AssertExhaustion { call, .. } => {
 				Self::write_assert_exhaustion(&call, w)?;
 			}
+			WastDirective::AssertInvalid {
+				module, message, ..
+			}
+			| WastDirective::AssertMalformed {
+				module, message, ..
+			} => {
+				assert_rejected(module, message);
+			}
 			_ => {}
 		}
 
@@ -127,7 +214,7 @@ pub trait Target: Sized {
 		}
 	}
 
-	fn run_generation(source: &str) -> Result<Vec<u8>> {
+	fn run_generation(source: &str, is_strict: bool) -> Result<Vec<u8>> {
 		let lexed = ParseBuffer::new(source).expect("Failed to tokenize");
 		let parsed: Wast = wast::parser::parse(&lexed).unwrap();
 
@@ -135,6 +222,11 @@ pub trait Target: Sized {
 
 		Self::write_runtime(&mut data)?;
 
+		// Only the spec tests are allowed to miss the traps they expect
+		if is_strict {
+			writeln!(data, "assert_trap = assert_trap_strict")?;
+		}
+
 		for variant in parsed.directives {
 			Self::write_variant(variant, &mut data)?;
 		}
@@ -142,8 +234,8 @@ pub trait Target: Sized {
 		Ok(data)
 	}
 
-	fn test(name: &str, source: &str) -> Result<()> {
-		let data = Self::run_generation(source)?;
+	fn test(name: &str, source: &str, is_strict: bool) -> Result<()> {
+		let data = Self::run_generation(source, is_strict)?;
 		let temp = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
 			.join(name)
 			.with_extension("wast.lua");
@@ -0,0 +1,328 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use wasm_ast::{
+	encode::encode_module,
+	factory::Factory,
+	module::{External, Module, TypeInfo},
+	node::FuncData,
+	optimize::Settings,
+};
+use wasmi::{Config, Engine, ExternRef, FuncRef, Instance, Linker, Store, Val};
+use wast::{
+	core::{HeapType, NanPattern, WastArgCore, WastRetCore},
+	parser::ParseBuffer,
+	token::Id,
+	QuoteWat, Wast, WastArg, WastDirective, WastExecute, WastInvoke, WastRet, Wat,
+};
+
+static DO_NOT_RUN: [&str; 2] = ["names.wast", "skip-stack-guard-page.wast"];
+
+fn build_func_list(wasm: &Module, settings: &Settings) -> Vec<FuncData> {
+	let type_info = TypeInfo::from_module(wasm);
+	let offset = wasm.import_count(External::Func);
+	let mut builder = Factory::from_type_info(&type_info);
+
+	let mut func_list: Vec<_> = wasm
+		.code_section()
+		.iter()
+		.enumerate()
+		.map(|f| builder.create_indexed(f.0 + offset, f.1).unwrap())
+		.collect();
+
+	settings.run(&type_info, offset, &mut func_list);
+
+	func_list
+}
+
+// The rebuilt binary goes through the same validating loader as the
+// original, so any stack or label mismatch in the encoder surfaces here.
+fn re_encode(name: &str, bytes: &[u8], settings: &Settings) -> Vec<u8> {
+	let wasm = Module::try_from_data(bytes).unwrap();
+	let func_list = build_func_list(&wasm, settings);
+	let data = encode_module(&wasm, &func_list);
+
+	if let Err(error) = Module::try_from_data(&data) {
+		let optimize = settings.has_passes;
+
+		panic!("{name}: re-encoded module is invalid (optimize: {optimize}): {error}");
+	}
+
+	data
+}
+
+fn get_name_from_id(id: Option<Id>) -> &str {
+	id.as_ref().map_or("temp", Id::name)
+}
+
+// Directives that are replayed once for every set of settings
+enum Directive<'a> {
+	Module(Option<&'a str>, Vec<u8>),
+	Register(&'a str, &'a str),
+	Invoke(WastInvoke<'a>),
+	AssertReturn(WastInvoke<'a>, Vec<WastRet<'a>>),
+	AssertTrap(WastInvoke<'a>),
+}
+
+impl<'a> Directive<'a> {
+	fn from_variant(variant: WastDirective<'a>) -> Option<Self> {
+		let result = match variant {
+			WastDirective::Wat(QuoteWat::Wat(Wat::Module(mut module))) => {
+				let name = module.id.map(|v| v.name());
+
+				Self::Module(name, module.encode().unwrap())
+			}
+			WastDirective::Register { name, module, .. } => {
+				Self::Register(name, get_name_from_id(module))
+			}
+			WastDirective::Invoke(invoke) => Self::Invoke(invoke),
+			WastDirective::AssertReturn {
+				exec: WastExecute::Invoke(invoke),
+				results,
+				..
+			} => Self::AssertReturn(invoke, results),
+			WastDirective::AssertTrap {
+				exec: WastExecute::Invoke(invoke),
+				..
+			} => Self::AssertTrap(invoke),
+			_ => return None,
+		};
+
+		Some(result)
+	}
+}
+
+fn into_val(arg: &WastArg) -> Option<Val> {
+	let result = match arg {
+		WastArg::Core(WastArgCore::I32(v)) => Val::I32(*v),
+		WastArg::Core(WastArgCore::I64(v)) => Val::I64(*v),
+		WastArg::Core(WastArgCore::F32(v)) => Val::F32(v.bits.into()),
+		WastArg::Core(WastArgCore::F64(v)) => Val::F64(v.bits.into()),
+		WastArg::Core(WastArgCore::RefNull(HeapType::Func)) => Val::FuncRef(FuncRef::null()),
+		WastArg::Core(WastArgCore::RefNull(HeapType::Extern)) => Val::ExternRef(ExternRef::null()),
+		_ => return None,
+	};
+
+	Some(result)
+}
+
+const fn is_f32_match(pattern: &NanPattern<wast::token::F32>, bits: u32) -> bool {
+	match pattern {
+		NanPattern::CanonicalNan => bits & 0x7FFF_FFFF == 0x7FC0_0000,
+		NanPattern::ArithmeticNan => bits & 0x7FC0_0000 == 0x7FC0_0000,
+		NanPattern::Value(v) => v.bits == bits,
+	}
+}
+
+const fn is_f64_match(pattern: &NanPattern<wast::token::F64>, bits: u64) -> bool {
+	match pattern {
+		NanPattern::CanonicalNan => bits & 0x7FFF_FFFF_FFFF_FFFF == 0x7FF8_0000_0000_0000,
+		NanPattern::ArithmeticNan => bits & 0x7FF8_0000_0000_0000 == 0x7FF8_0000_0000_0000,
+		NanPattern::Value(v) => v.bits == bits,
+	}
+}
+
+// Results that can not be compared here, such as vectors, always match
+fn is_match(expected: &WastRetCore, value: &Val) -> bool {
+	match (expected, value) {
+		(WastRetCore::I32(v), Val::I32(value)) => v == value,
+		(WastRetCore::I64(v), Val::I64(value)) => v == value,
+		(WastRetCore::F32(v), Val::F32(value)) => is_f32_match(v, value.to_bits()),
+		(WastRetCore::F64(v), Val::F64(value)) => is_f64_match(v, value.to_bits()),
+		(WastRetCore::RefNull(_), Val::FuncRef(value)) => value.is_null(),
+		(WastRetCore::RefNull(_), Val::ExternRef(value)) => value.is_null(),
+		(WastRetCore::RefFunc(None), Val::FuncRef(value)) => !value.is_null(),
+		(WastRetCore::RefExtern(None), Val::ExternRef(value)) => !value.is_null(),
+		(WastRetCore::Either(list), value) => list.iter().any(|v| is_match(v, value)),
+		(
+			WastRetCore::I32(_)
+			| WastRetCore::I64(_)
+			| WastRetCore::F32(_)
+			| WastRetCore::F64(_)
+			| WastRetCore::RefNull(_)
+			| WastRetCore::RefFunc(None)
+			| WastRetCore::RefExtern(None),
+			_,
+		) => false,
+		_ => true,
+	}
+}
+
+// Runs the directives against the re-encoded modules. Modules the
+// interpreter cannot load, or whose imports it cannot provide, are
+// skipped along with every call into them.
+struct Runner<'a> {
+	name: &'a str,
+	settings: Settings,
+	engine: Engine,
+	store: Store<()>,
+	linker: Linker<()>,
+	instance_map: HashMap<&'a str, Option<Instance>>,
+}
+
+impl<'a> Runner<'a> {
+	fn new(name: &'a str, settings: Settings) -> Self {
+		let mut config = Config::default();
+
+		config.wasm_tail_call(true).wasm_extended_const(true);
+
+		let engine = Engine::new(&config);
+		let store = Store::new(&engine, ());
+		let linker = Linker::new(&engine);
+
+		Self {
+			name,
+			settings,
+			engine,
+			store,
+			linker,
+			instance_map: HashMap::new(),
+		}
+	}
+
+	fn instantiate(&mut self, bytes: &[u8]) -> Option<Instance> {
+		// Features the interpreter lacks are not checked at all
+		wasmi::Module::new(&self.engine, bytes).ok()?;
+
+		let data = re_encode(self.name, bytes, &self.settings);
+		let module = wasmi::Module::new(&self.engine, &data[..]).unwrap_or_else(|error| {
+			panic!("{}: re-encoded module is rejected: {error}", self.name);
+		});
+
+		let result = self
+			.linker
+			.instantiate(&mut self.store, &module)
+			.and_then(|v| v.start(&mut self.store));
+
+		match result {
+			Ok(instance) => Some(instance),
+			Err(_) if module.imports().len() != 0 => None,
+			Err(error) => panic!("{}: re-encoded module failed to start: {error}", self.name),
+		}
+	}
+
+	fn add_module(&mut self, name: Option<&'a str>, bytes: &[u8]) {
+		let instance = self.instantiate(bytes);
+
+		if let Some(name) = name {
+			self.instance_map.insert(name, instance);
+		}
+
+		self.instance_map.insert("temp", instance);
+	}
+
+	fn add_register(&mut self, post: &str, pre: &str) {
+		let Some(Some(instance)) = self.instance_map.get(pre) else {
+			return;
+		};
+
+		let export_list: Vec<_> = instance
+			.exports(&self.store)
+			.map(|v| (v.name().to_string(), v.into_extern()))
+			.collect();
+
+		for (name, item) in export_list {
+			self.linker.define(post, &name, item).unwrap();
+		}
+	}
+
+	// Returns `None` when the call could not be made here at all
+	fn invoke(&mut self, invoke: &WastInvoke) -> Option<Result<Vec<Val>, wasmi::Error>> {
+		let instance = (*self.instance_map.get(get_name_from_id(invoke.module))?)?;
+		let func = instance.get_func(&self.store, invoke.name)?;
+		let param_list = invoke
+			.args
+			.iter()
+			.map(into_val)
+			.collect::<Option<Vec<_>>>()?;
+		let mut result_list: Vec<_> = func
+			.ty(&self.store)
+			.results()
+			.iter()
+			.map(|&v| Val::default(v))
+			.collect();
+
+		let result = func
+			.call(&mut self.store, &param_list, &mut result_list)
+			.map(|()| result_list);
+
+		Some(result)
+	}
+
+	fn add_assert_return(&mut self, invoke: &WastInvoke, expected: &[WastRet]) {
+		let Some(result) = self.invoke(invoke) else {
+			return;
+		};
+
+		let optimize = self.settings.has_passes;
+		let name = self.name;
+		let func = invoke.name;
+		let value_list = result.unwrap_or_else(|error| {
+			panic!("{name}: `{func}` trapped (optimize: {optimize}): {error}");
+		});
+
+		for (expected, value) in expected.iter().zip(&value_list) {
+			let WastRet::Core(expected) = expected else {
+				continue;
+			};
+
+			assert!(
+				is_match(expected, value),
+				"{name}: `{func}` returned {value:?}, expected {expected:?} (optimize: {optimize})"
+			);
+		}
+	}
+
+	fn add_assert_trap(&mut self, invoke: &WastInvoke) {
+		let Some(result) = self.invoke(invoke) else {
+			return;
+		};
+
+		let optimize = self.settings.has_passes;
+
+		assert!(
+			result.is_err(),
+			"{}: `{}` did not trap (optimize: {optimize})",
+			self.name,
+			invoke.name
+		);
+	}
+
+	fn run(&mut self, directive: &Directive<'a>) {
+		match directive {
+			Directive::Module(name, bytes) => self.add_module(*name, bytes),
+			Directive::Register(post, pre) => self.add_register(post, pre),
+			Directive::Invoke(invoke) => {
+				let _ = self.invoke(invoke);
+			}
+			Directive::AssertReturn(invoke, expected) => self.add_assert_return(invoke, expected),
+			Directive::AssertTrap(invoke) => self.add_assert_trap(invoke),
+		}
+	}
+}
+
+#[test_generator::test_resources("dev-test/spec/*.wast")]
+fn round_trip_file(path: PathBuf) {
+	let path = path.strip_prefix("dev-test/").unwrap();
+	let name = path.file_name().unwrap().to_str().unwrap();
+
+	if DO_NOT_RUN.contains(&name) {
+		return;
+	}
+
+	let source = std::fs::read_to_string(path).unwrap();
+	let lexed = ParseBuffer::new(&source).expect("Failed to tokenize");
+	let parsed: Wast = wast::parser::parse(&lexed).unwrap();
+	let directive_list: Vec<_> = parsed
+		.directives
+		.into_iter()
+		.filter_map(Directive::from_variant)
+		.collect();
+
+	for settings in [Settings::default(), Settings::standard()] {
+		let mut runner = Runner::new(name, settings);
+
+		for directive in &directive_list {
+			runner.run(directive);
+		}
+	}
+}
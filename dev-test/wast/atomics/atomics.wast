@@ -0,0 +1,110 @@
+;; Atomic accesses of a single threaded host, which must still trap on
+;; misaligned and out of bounds addresses
+
+(module
+  (memory 1 1 shared)
+  (data (i32.const 0) "\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10")
+
+  (func (export "i32.atomic.load") (param i32) (result i32)
+    (i32.atomic.load (local.get 0)))
+  (func (export "i64.atomic.load") (param i32) (result i64)
+    (i64.atomic.load (local.get 0)))
+  (func (export "i32.atomic.load8_u") (param i32) (result i32)
+    (i32.atomic.load8_u (local.get 0)))
+  (func (export "i32.atomic.load16_u") (param i32) (result i32)
+    (i32.atomic.load16_u (local.get 0)))
+  (func (export "i64.atomic.load32_u") (param i32) (result i64)
+    (i64.atomic.load32_u (local.get 0)))
+  (func (export "i32.atomic.load offset=4") (param i32) (result i32)
+    (i32.atomic.load offset=4 (local.get 0)))
+
+  (func (export "i32.atomic.store") (param i32 i32) (result i32)
+    (i32.atomic.store (local.get 0) (local.get 1))
+    (i32.load (local.get 0)))
+  (func (export "i64.atomic.store") (param i32 i64) (result i64)
+    (i64.atomic.store (local.get 0) (local.get 1))
+    (i64.load (local.get 0)))
+  (func (export "i32.atomic.store16") (param i32 i32) (result i32)
+    (i32.atomic.store16 (local.get 0) (local.get 1))
+    (i32.load (i32.const 0)))
+
+  (func (export "i32.atomic.rmw.add") (param i32 i32) (result i32 i32)
+    (i32.atomic.rmw.add (local.get 0) (local.get 1))
+    (i32.load (local.get 0)))
+  (func (export "i64.atomic.rmw.sub") (param i32 i64) (result i64 i64)
+    (i64.atomic.rmw.sub (local.get 0) (local.get 1))
+    (i64.load (local.get 0)))
+  (func (export "i32.atomic.rmw8.xchg_u") (param i32 i32) (result i32 i32)
+    (i32.atomic.rmw8.xchg_u (local.get 0) (local.get 1))
+    (i32.load (i32.const 0)))
+  (func (export "i32.atomic.rmw.cmpxchg") (param i32 i32 i32) (result i32 i32)
+    (i32.atomic.rmw.cmpxchg (local.get 0) (local.get 1) (local.get 2))
+    (i32.load (local.get 0)))
+  (func (export "i32.atomic.rmw16.cmpxchg_u") (param i32 i32 i32) (result i32 i32)
+    (i32.atomic.rmw16.cmpxchg_u (local.get 0) (local.get 1) (local.get 2))
+    (i32.load (i32.const 0)))
+
+  (func (export "memory.atomic.notify") (param i32 i32) (result i32)
+    (memory.atomic.notify (local.get 0) (local.get 1)))
+  (func (export "memory.atomic.wait32") (param i32 i32 i64) (result i32)
+    (memory.atomic.wait32 (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "memory.atomic.wait64") (param i32 i64 i64) (result i32)
+    (memory.atomic.wait64 (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "atomic.fence") (result i32)
+    (atomic.fence)
+    (i32.const 1))
+)
+
+(assert_return (invoke "i32.atomic.load" (i32.const 0)) (i32.const 0x04030201))
+(assert_return (invoke "i32.atomic.load" (i32.const 12)) (i32.const 0x100f0e0d))
+(assert_return (invoke "i64.atomic.load" (i32.const 8)) (i64.const 0x100f0e0d0c0b0a09))
+(assert_return (invoke "i32.atomic.load8_u" (i32.const 3)) (i32.const 4))
+(assert_return (invoke "i32.atomic.load16_u" (i32.const 2)) (i32.const 0x0403))
+(assert_return (invoke "i64.atomic.load32_u" (i32.const 4)) (i64.const 0x08070605))
+(assert_return (invoke "i32.atomic.load offset=4" (i32.const 4)) (i32.const 0x0c0b0a09))
+(assert_return (invoke "i32.atomic.load" (i32.const 65532)) (i32.const 0))
+(assert_return (invoke "i32.atomic.load8_u" (i32.const 65535)) (i32.const 0))
+
+(assert_trap (invoke "i32.atomic.load" (i32.const 1)) "unaligned atomic")
+(assert_trap (invoke "i32.atomic.load" (i32.const 2)) "unaligned atomic")
+(assert_trap (invoke "i64.atomic.load" (i32.const 4)) "unaligned atomic")
+(assert_trap (invoke "i32.atomic.load16_u" (i32.const 1)) "unaligned atomic")
+(assert_trap (invoke "i64.atomic.load32_u" (i32.const 2)) "unaligned atomic")
+(assert_trap (invoke "i32.atomic.load offset=4" (i32.const 2)) "unaligned atomic")
+(assert_trap (invoke "i32.atomic.load" (i32.const 65536)) "out of bounds memory access")
+(assert_trap (invoke "i32.atomic.load8_u" (i32.const 65536)) "out of bounds memory access")
+(assert_trap (invoke "i32.atomic.load offset=4" (i32.const 65532)) "out of bounds memory access")
+
+(assert_return (invoke "i32.atomic.store" (i32.const 32) (i32.const 0x12345678)) (i32.const 0x12345678))
+(assert_return (invoke "i64.atomic.store" (i32.const 40) (i64.const -2)) (i64.const -2))
+(assert_return (invoke "i32.atomic.store16" (i32.const 2) (i32.const 0xabcdef)) (i32.const 0xcdef0201))
+(assert_trap (invoke "i32.atomic.store" (i32.const 33) (i32.const 0)) "unaligned atomic")
+(assert_trap (invoke "i64.atomic.store" (i32.const 36) (i64.const 0)) "unaligned atomic")
+(assert_trap (invoke "i32.atomic.store16" (i32.const 3) (i32.const 0)) "unaligned atomic")
+(assert_trap (invoke "i32.atomic.store" (i32.const 65536) (i32.const 0)) "out of bounds memory access")
+
+(assert_return (invoke "i32.atomic.rmw.add" (i32.const 48) (i32.const 5)) (i32.const 0) (i32.const 5))
+(assert_return (invoke "i32.atomic.rmw.add" (i32.const 48) (i32.const -1)) (i32.const 5) (i32.const 4))
+(assert_return (invoke "i64.atomic.rmw.sub" (i32.const 56) (i64.const 1)) (i64.const 0) (i64.const -1))
+(assert_return (invoke "i32.atomic.rmw8.xchg_u" (i32.const 0) (i32.const 0x1ff)) (i32.const 1) (i32.const 0xcdef02ff))
+(assert_return (invoke "i32.atomic.rmw.cmpxchg" (i32.const 48) (i32.const 3) (i32.const 9)) (i32.const 4) (i32.const 4))
+(assert_return (invoke "i32.atomic.rmw.cmpxchg" (i32.const 48) (i32.const 4) (i32.const 9)) (i32.const 4) (i32.const 9))
+(assert_return (invoke "i32.atomic.rmw16.cmpxchg_u" (i32.const 0) (i32.const 0x102ff) (i32.const 7)) (i32.const 0x02ff) (i32.const 0xcdef0007))
+(assert_trap (invoke "i32.atomic.rmw.add" (i32.const 50) (i32.const 1)) "unaligned atomic")
+(assert_trap (invoke "i64.atomic.rmw.sub" (i32.const 60) (i64.const 1)) "unaligned atomic")
+(assert_trap (invoke "i32.atomic.rmw.cmpxchg" (i32.const 65536) (i32.const 0) (i32.const 0)) "out of bounds memory access")
+
+(assert_return (invoke "memory.atomic.notify" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "memory.atomic.notify" (i32.const 65532) (i32.const 1)) (i32.const 0))
+(assert_trap (invoke "memory.atomic.notify" (i32.const 2) (i32.const 1)) "unaligned atomic")
+(assert_trap (invoke "memory.atomic.notify" (i32.const 65536) (i32.const 1)) "out of bounds memory access")
+
+(assert_return (invoke "memory.atomic.wait32" (i32.const 48) (i32.const 0) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "memory.atomic.wait32" (i32.const 48) (i32.const 9) (i64.const 0)) (i32.const 2))
+(assert_return (invoke "memory.atomic.wait64" (i32.const 56) (i64.const -1) (i64.const 10)) (i32.const 2))
+(assert_trap (invoke "memory.atomic.wait32" (i32.const 48) (i32.const 9) (i64.const -1)) "wait would block forever")
+(assert_trap (invoke "memory.atomic.wait32" (i32.const 49) (i32.const 0) (i64.const 0)) "unaligned atomic")
+(assert_trap (invoke "memory.atomic.wait64" (i32.const 52) (i64.const 0) (i64.const 0)) "unaligned atomic")
+(assert_trap (invoke "memory.atomic.wait32" (i32.const 65536) (i32.const 0) (i64.const 0)) "out of bounds memory access")
+
+(assert_return (invoke "atomic.fence") (i32.const 1))
@@ -0,0 +1,278 @@
+;; Legacy exception handling (`try`, `catch`, `catch_all`, `rethrow`, `delegate`)
+
+(module $M
+  (tag $e (export "e") (param i32))
+  (func (export "throw") (param i32) (throw $e (local.get 0)))
+)
+(register "m" $M)
+
+(module
+  (tag $imported (import "m" "e") (param i32))
+  (func $throw_imported (import "m" "throw") (param i32))
+
+  (tag $e0)
+  (tag $e1 (param i32))
+  (tag $e2 (param i32 i64))
+
+  (memory 1)
+  (table 1 funcref)
+  (type $t (func))
+
+  (func $throw_e0 (throw $e0))
+  (func $throw_e1 (param i32) (throw $e1 (local.get 0)))
+
+  (func (export "catch-payload") (param i32) (result i32)
+    try (result i32)
+      (call $throw_e1 (local.get 0))
+      (i32.const 0)
+    catch $e1
+      (i32.add (i32.const 1))
+    end
+  )
+
+  (func (export "catch-multi") (result i64) (local i64)
+    try (result i64)
+      (throw $e2 (i32.const 3) (i64.const 4))
+    catch $e2
+      (local.set 0)
+      (i64.extend_i32_u)
+      (local.get 0)
+      (i64.add)
+    end
+  )
+
+  (func (export "catch-order") (param i32) (result i32)
+    try (result i32)
+      (if (local.get 0) (then (call $throw_e0)))
+      (call $throw_e1 (i32.const 7))
+      (i32.const 0)
+    catch $e0
+      (i32.const 1)
+    catch $e1
+      (drop)
+      (i32.const 2)
+    catch_all
+      (i32.const 3)
+    end
+  )
+
+  (func (export "catch-imported") (result i32)
+    try (result i32)
+      (call $throw_imported (i32.const 5))
+      (i32.const 0)
+    catch $imported
+    end
+  )
+
+  (func (export "catch-all") (result i32)
+    try (result i32)
+      (throw $e1 (i32.const 9))
+    catch $e0
+      (i32.const 1)
+    catch_all
+      (i32.const 2)
+    end
+  )
+
+  (func (export "uncaught-tag") (result i32)
+    try (result i32)
+      try (result i32)
+        (call $throw_e1 (i32.const 4))
+        (i32.const 0)
+      catch $e0
+        (i32.const 1)
+      end
+    catch $e1
+    end
+  )
+
+  (func (export "no-throw") (result i32)
+    try (result i32)
+      (i32.const 11)
+    catch_all
+      (i32.const 0)
+    end
+  )
+
+  (func (export "rethrow") (result i32)
+    try (result i32)
+      try
+        (throw $e1 (i32.const 21))
+      catch_all
+        (rethrow 0)
+      end
+      (i32.const 0)
+    catch $e1
+    end
+  )
+
+  (func (export "rethrow-outer") (result i32)
+    try (result i32)
+      try
+        (throw $e1 (i32.const 22))
+      catch $e1
+        (drop)
+        try
+          (throw $e0)
+        catch_all
+          (rethrow 1)
+        end
+      end
+      (i32.const 0)
+    catch $e0
+      (i32.const 1)
+    catch $e1
+    end
+  )
+
+  (func (export "delegate-outer") (result i32)
+    try (result i32)
+      try
+        try
+          (throw $e1 (i32.const 31))
+        delegate 1
+      catch $e1
+        (drop)
+      end
+      (i32.const 0)
+    catch $e1
+    end
+  )
+
+  (func (export "delegate-next") (result i32)
+    try (result i32)
+      try
+        (throw $e1 (i32.const 32))
+      delegate 0
+      (i32.const 0)
+    catch $e1
+    end
+  )
+
+  (func (export "delegate-block") (result i32)
+    try (result i32)
+      block
+        try
+          try
+            (throw $e1 (i32.const 33))
+          delegate 2
+        catch_all
+        end
+      end
+      (i32.const 0)
+    catch $e1
+    end
+  )
+
+  (func $delegate_caller
+    try
+      try
+        (throw $e1 (i32.const 34))
+      delegate 1
+    catch_all
+    end
+  )
+
+  (func (export "delegate-caller") (result i32)
+    try (result i32)
+      (call $delegate_caller)
+      (i32.const 0)
+    catch $e1
+    end
+  )
+
+  (func (export "delegate-nested") (result i32)
+    try (result i32)
+      try
+        try
+          try
+            (throw $e1 (i32.const 35))
+          delegate 0
+        delegate 1
+      catch_all
+      end
+      (i32.const 0)
+    catch $e1
+    end
+  )
+
+  (func (export "delegate-trap") (result i32)
+    try (result i32)
+      try
+        (drop (i32.div_s (i32.const 1) (i32.const 0)))
+      delegate 0
+      (i32.const 0)
+    catch_all
+      (i32.const 1)
+    end
+  )
+
+  ;; Traps and any other Lua error are never caught, even by `catch_all`
+  (func (export "catch-all-divide") (result i32)
+    try (result i32)
+      (i32.div_u (i32.const 1) (i32.const 0))
+    catch_all
+      (i32.const 1)
+    end
+  )
+
+  (func (export "catch-all-memory") (result i32)
+    try (result i32)
+      (i32.load (i32.const 65536))
+    catch_all
+      (i32.const 1)
+    end
+  )
+
+  (func (export "catch-all-table") (result i32)
+    try (result i32)
+      (call_indirect (type $t) (i32.const 0))
+      (i32.const 0)
+    catch_all
+      (i32.const 1)
+    end
+  )
+
+  (func (export "catch-all-unreachable") (result i32)
+    try (result i32)
+      (unreachable)
+    catch_all
+      (i32.const 1)
+    end
+  )
+
+  (func (export "branch-out") (param i32) (result i32)
+    (block $outer (result i32)
+      try (result i32)
+        (br_if $outer (i32.const 41) (local.get 0))
+        (drop)
+        (throw $e0)
+      catch $e0
+        (i32.const 42)
+      end
+    )
+  )
+)
+
+(assert_return (invoke "catch-payload" (i32.const 5)) (i32.const 6))
+(assert_return (invoke "catch-multi") (i64.const 7))
+(assert_return (invoke "catch-order" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "catch-order" (i32.const 0)) (i32.const 2))
+(assert_return (invoke "catch-imported") (i32.const 5))
+(assert_return (invoke "catch-all") (i32.const 2))
+(assert_return (invoke "uncaught-tag") (i32.const 4))
+(assert_return (invoke "no-throw") (i32.const 11))
+(assert_return (invoke "rethrow") (i32.const 21))
+(assert_return (invoke "rethrow-outer") (i32.const 22))
+(assert_return (invoke "delegate-outer") (i32.const 31))
+(assert_return (invoke "delegate-next") (i32.const 32))
+(assert_return (invoke "delegate-block") (i32.const 33))
+(assert_return (invoke "delegate-caller") (i32.const 34))
+(assert_return (invoke "delegate-nested") (i32.const 35))
+(assert_trap (invoke "delegate-trap") "integer divide by zero")
+(assert_trap (invoke "catch-all-divide") "integer divide by zero")
+(assert_trap (invoke "catch-all-memory") "out of bounds memory access")
+(assert_trap (invoke "catch-all-table") "uninitialized element")
+(assert_trap (invoke "catch-all-unreachable") "unreachable")
+(assert_return (invoke "branch-out" (i32.const 1)) (i32.const 41))
+(assert_return (invoke "branch-out" (i32.const 0)) (i32.const 42))
@@ -0,0 +1,126 @@
+;; Names that sanitize to the same text, clash with Lua keywords or start
+;; with a digit must still become distinct identifiers
+
+(module $exporter
+  (table $shared (export "shared-table") 2 funcref)
+  (memory $shared (export "shared-memory") 1)
+
+  (func $seven (result i32) (i32.const 7))
+  (elem (table $shared) (i32.const 1) func $seven)
+  (data (memory $shared) (i32.const 8) "\2a")
+)
+
+(register "exporter" $exporter)
+
+(module
+  (import "exporter" "shared-table" (table $imported 2 funcref))
+  (import "exporter" "shared-memory" (memory $imported 1))
+
+  (table $a-b 4 funcref)
+  (table $a.b 4 funcref)
+  (table $end 1 externref)
+  (table $1st 2 funcref)
+
+  (memory $local 1)
+  (memory $a-b 1)
+  (memory $a.b 1)
+
+  (type $get (func (result i32)))
+
+  (func $one (result i32) (i32.const 1))
+  (func $two (result i32) (i32.const 2))
+
+  (elem (table $a-b) (i32.const 0) func $one $two)
+  (elem (table $a.b) (i32.const 0) func $two $one)
+  (elem $passive func $two)
+
+  (data (memory $local) (i32.const 0) "\01\02")
+  (data (memory $a-b) (i32.const 0) "\03\04")
+  (data (memory $a.b) (i32.const 0) "\05\06")
+
+  (func (export "call-a-b") (param i32) (result i32)
+    (call_indirect $a-b (type $get) (local.get 0))
+  )
+
+  (func (export "call-a.b") (param i32) (result i32)
+    (call_indirect $a.b (type $get) (local.get 0))
+  )
+
+  (func (export "call-imported") (param i32) (result i32)
+    (call_indirect $imported (type $get) (local.get 0))
+  )
+
+  (func (export "copy-tables") (param i32 i32 i32)
+    (table.copy $a-b $a.b (local.get 0) (local.get 1) (local.get 2))
+  )
+
+  (func (export "init-1st") (result i32)
+    (table.init $1st $passive (i32.const 1) (i32.const 0) (i32.const 1))
+    (call_indirect $1st (type $get) (i32.const 1))
+  )
+
+  (func (export "sizes") (result i32 i32 i32 i32)
+    (table.size $a-b)
+    (table.size $end)
+    (table.grow $1st (ref.null func) (i32.const 3))
+    (table.size $1st)
+  )
+
+  (func (export "set-end") (param externref) (result externref)
+    (table.set $end (i32.const 0) (local.get 0))
+    (table.get $end (i32.const 0))
+  )
+
+  (func (export "fill-a.b") (param i32 i32)
+    (table.fill $a.b (local.get 0) (ref.func $one) (local.get 1))
+  )
+
+  (func (export "load-all") (result i32 i32 i32 i32)
+    (i32.load8_u $local (i32.const 1))
+    (i32.load8_u $a-b (i32.const 1))
+    (i32.load8_u $a.b (i32.const 1))
+    (i32.load8_u $imported (i32.const 8))
+  )
+
+  (func (export "store-a.b") (param i32)
+    (i32.store8 $a.b (i32.const 1) (local.get 0))
+  )
+
+  (func (export "load-a-b") (result i32)
+    (i32.load8_u $a-b (i32.const 1))
+  )
+
+  (func (export "load-a.b") (result i32)
+    (i32.load8_u $a.b (i32.const 1))
+  )
+
+  (func (export "grow-local") (result i32 i32)
+    (memory.grow $local (i32.const 1))
+    (memory.size $local)
+  )
+)
+
+(assert_return (invoke "call-a-b" (i32.const 0)) (i32.const 1))
+(assert_return (invoke "call-a-b" (i32.const 1)) (i32.const 2))
+(assert_return (invoke "call-a.b" (i32.const 0)) (i32.const 2))
+(assert_return (invoke "call-a.b" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "call-imported" (i32.const 1)) (i32.const 7))
+(assert_trap (invoke "call-imported" (i32.const 0)) "uninitialized element")
+
+(invoke "copy-tables" (i32.const 2) (i32.const 0) (i32.const 2))
+(assert_return (invoke "call-a-b" (i32.const 2)) (i32.const 2))
+(assert_return (invoke "call-a-b" (i32.const 3)) (i32.const 1))
+
+(assert_return (invoke "init-1st") (i32.const 2))
+(assert_return (invoke "sizes") (i32.const 4) (i32.const 1) (i32.const 2) (i32.const 5))
+(assert_return (invoke "set-end" (ref.extern 1)) (ref.extern 1))
+
+(invoke "fill-a.b" (i32.const 0) (i32.const 2))
+(assert_return (invoke "call-a.b" (i32.const 0)) (i32.const 1))
+(assert_return (invoke "call-a.b" (i32.const 1)) (i32.const 1))
+
+(assert_return (invoke "load-all") (i32.const 2) (i32.const 4) (i32.const 6) (i32.const 42))
+(invoke "store-a.b" (i32.const 9))
+(assert_return (invoke "load-a-b") (i32.const 4))
+(assert_return (invoke "load-a.b") (i32.const 9))
+(assert_return (invoke "grow-local") (i32.const 1) (i32.const 2))
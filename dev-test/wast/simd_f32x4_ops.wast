@@ -0,0 +1,1221 @@
+;; Generated lane by lane against V8, covering f32x4 operations
+
+(module
+  (func (export "f32x4.abs") (param v128) (result v128)
+    (f32x4.abs (local.get 0))
+  )
+  (func (export "f32x4.neg") (param v128) (result v128)
+    (f32x4.neg (local.get 0))
+  )
+  (func (export "f32x4.sqrt") (param v128) (result v128)
+    (f32x4.sqrt (local.get 0))
+  )
+  (func (export "f32x4.ceil") (param v128) (result v128)
+    (f32x4.ceil (local.get 0))
+  )
+  (func (export "f32x4.floor") (param v128) (result v128)
+    (f32x4.floor (local.get 0))
+  )
+  (func (export "f32x4.trunc") (param v128) (result v128)
+    (f32x4.trunc (local.get 0))
+  )
+  (func (export "f32x4.nearest") (param v128) (result v128)
+    (f32x4.nearest (local.get 0))
+  )
+  (func (export "f32x4.convert_i32x4_s") (param v128) (result v128)
+    (f32x4.convert_i32x4_s (local.get 0))
+  )
+  (func (export "f32x4.convert_i32x4_u") (param v128) (result v128)
+    (f32x4.convert_i32x4_u (local.get 0))
+  )
+  (func (export "f32x4.demote_f64x2_zero") (param v128) (result v128)
+    (f32x4.demote_f64x2_zero (local.get 0))
+  )
+  (func (export "f32x4.add") (param v128 v128) (result v128)
+    (f32x4.add (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.sub") (param v128 v128) (result v128)
+    (f32x4.sub (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.mul") (param v128 v128) (result v128)
+    (f32x4.mul (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.div") (param v128 v128) (result v128)
+    (f32x4.div (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.min") (param v128 v128) (result v128)
+    (f32x4.min (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.max") (param v128 v128) (result v128)
+    (f32x4.max (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.pmin") (param v128 v128) (result v128)
+    (f32x4.pmin (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.pmax") (param v128 v128) (result v128)
+    (f32x4.pmax (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.eq") (param v128 v128) (result v128)
+    (f32x4.eq (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.ne") (param v128 v128) (result v128)
+    (f32x4.ne (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.lt") (param v128 v128) (result v128)
+    (f32x4.lt (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.gt") (param v128 v128) (result v128)
+    (f32x4.gt (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.le") (param v128 v128) (result v128)
+    (f32x4.le (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.ge") (param v128 v128) (result v128)
+    (f32x4.ge (local.get 0) (local.get 1))
+  )
+)
+
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f017f80 0x4081fe00 0x70103fc0 0x027eaa55))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic 0x1.6900000000000p-91 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x4521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 0x1.79bde00000000p-74 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x3fc00000 0x40200000))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0x3f000000 0x40200000))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x501502f9 0x4f000000 0x4f000001))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x3f800000 0x3dcccccd))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x40040000))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0800759c 0x7e37e43c 0x0b70e62b 0x00001268))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 0x1.0000000000000p-129 0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x3fe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x80000000))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x83020100 0x87060504 0x8b0a0908 0x8f0e0d0c))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f017f80 0xc081fe00 0x70103fc0 0x027eaa55))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 -0x1.0000000000000p-133 nan:arithmetic -0x1.6900000000000p-91 -0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 -0x1.01fffc0000000p+1 nan:arithmetic -0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 -0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x875bcd15 0x4521974f 0x8000ffff 0x80010000))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic -0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 0x1.79bde00000000p-74 -0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x80000000 0x00000000 0xbfc00000 0x40200000))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 -inf inf nan:arithmetic -0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x80000001 0xbf000000 0x3f000000 0xc0200000))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xd01502f9 0x501502f9 0xcf000000 0x4f000001))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xcf800000 0xc7800040 0x3f800000 0xbdcccccd))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x00000000))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x80000000 0xbff80000 0x80000000 0x40040000))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0800759c 0xfe37e43c 0x0b70e62b 0x00001268))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic -0x1.bffffe0000000p+4 -0x1.0000000000000p-129 0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic -0x1.dffffe0000000p+4 -0x1.227a000000000p+66 -0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x80000000 0x3fe00000 0x80000000 0xc00c0000))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x21366e49 0x23393a18 0x253bfb41 0x273eb23b))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const f32x4 nan:arithmetic 0x1.01fc080000000p+1 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x1.6a09e60000000p-67 nan:arithmetic 0x1.adebc20000000p-46 0x1.6a09120000000p-63))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.6b733a0000000p+0 nan:arithmetic 0x1.007fe20000000p-63 nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.6a09e60000000p-75 nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f32x4 0x1.da6c0e0000000p-57 nan:arithmetic 0x1.6a09320000000p-67 0x1.6a09e60000000p-67))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 nan:arithmetic 0x1.adba480000000p-46 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f32x4 0x0.0p+0 -0x0.0p+0 0x1.3988e20000000p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf nan:arithmetic nan:arithmetic 0x1.ffc99c0000000p+63))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 0x1.6a09e60000000p-75 0x1.6a09e60000000p-1 nan:arithmetic 0x1.94c5840000000p+0))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f32x4 0x1.86a0000000000p+16 nan:arithmetic 0x1.6a09e60000000p+15 nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const f32x4 0x1.0000000000000p+16 0x1.0000400000000p+8 nan:arithmetic 0x1.43d1360000000p-2))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 0x0.0p+0 0x1.6456400000000p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const f32x4 nan:arithmetic 0x1.b1f0f00000000p+62 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.52a7fa0000000p+2 0x1.6a09e60000000p-65 nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.5e8adc0000000p+2 0x1.10b1a80000000p+33 0x1.53ac4e0000000p+4))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 0x1.7aa10e0000000p+0))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x3f800000 0x3f800000 0x3f800000 0x3f800000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x40a00000 0xf0103fc0 0x80000000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x1.0000000000000p+0 nan:arithmetic 0x1.0000000000000p+0 0x1.0000000000000p+0))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.8000000000000p+1 nan:arithmetic 0x1.0000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.0000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x3f800000 0xc5219000 0x3f800000 0x3f800000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x0.0p+0 0x1.0000000000000p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x40000000 0xc0000000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x3f800000 0x3f800000 0x80000000 0x40400000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800080 0xbf800000 0x3f800000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40000000 0x00000000 0xc0000000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80000000 0x7e37e43c 0x80000000 0x80000000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.c000000000000p+4 0x1.0000000000000p+0 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.e000000000000p+4 0x1.227a000000000p+66 0x1.c300000000000p+8))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbf800000 0x00000000 0x40400000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x40800000 0xf0103fc0 0xbf800000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.0000000000000p+1 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xc521a000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x1.0000000000000p+0 0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3f800000 0xc0400000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xbf800000 0x40000000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800000 0xbf800000 0x00000000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3f800000 0x00000000 0xc0400000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xbf800000 0x7e37e43c 0xbf800000 0xbf800000))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.b000000000000p+4 0x0.0p+0 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.d000000000000p+4 0x1.227a000000000p+66 0x1.c200000000000p+8))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xc0000000 0x00000000 0x40000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x40800000 0xf0103fc0 0x80000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.0000000000000p+1 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xc5219000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x0.0p+0 0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3f800000 0xc0000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x40000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800000 0xbf800000 0x00000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3f800000 0x00000000 0xc0000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80000000 0x7e37e43c 0x80000000 0x80000000))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.b000000000000p+4 0x0.0p+0 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.d000000000000p+4 0x1.227a000000000p+66 0x1.c200000000000p+8))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbf800000 0x00000000 0x40000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x40800000 0xf0103fc0 0x80000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.0000000000000p+1 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xc5219000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x0.0p+0 0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x40000000 0xc0000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x40000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800000 0xbf800000 0x00000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40000000 0x00000000 0xc0000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80000000 0x7e37e43c 0x80000000 0x80000000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.c000000000000p+4 0x0.0p+0 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.e000000000000p+4 0x1.227a000000000p+66 0x1.c300000000000p+8))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xc0000000 0x00000000 0x40000000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xbf800000 0xbf800000 0xbf800000 0xbf800000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x4c408040 0x4ce0c0a0 0x4d30a090 0x4d70e0d1))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xcb7e8080 0x4e8103fc 0xcd7efc04 0xcefb02ab))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x47800000 0x4f000000 0x4d91a400 0x4b7ffed4))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4e800200 0x4efffd80 0x4b008001 0x4effffff))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x4f000000 0xcf000000 0x3f800000 0xbf800000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x4ceb79a3 0xce6b79a3 0x477fff00 0x47800000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xbf800000 0x4f000000 0x00000000 0xcf000000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xceca8642 0x4d91a2b4 0xc0a00000 0xbf800000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xcf000000 0x4e7f0000 0xce7f8000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x4eff0000 0xcb000000 0x4eff8000 0x4efeff93))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x3f800000 0x4e7c0000 0xce820000 0x4e804000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x4ea02a06 0xce3fabf4 0x4e9e0000 0xce440000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4e9f0000 0x4e8f0000 0xce810000 0x4e773333))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xcf000000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x4effe000 0x00000000 0x4efff000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x4e7fe000 0x00000000 0xce7ff000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xceefff15 0x4efc6fc8 0xcee91e34 0xceffffdb))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xca000000 0x4e83c000 0x49800000 0xce788000))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xca000000 0x4e83e000 0x4ec1227a 0x4e87c2b2))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xce804000 0x00000000 0x4e801800))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x4f800000 0x4f800000 0x4f800000 0x4f800000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x4c408040 0x4ce0c0a0 0x4d30a090 0x4d70e0d1))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x4f7f0180 0x4e8103fc 0x4f701040 0x4f027eaa))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x47800000 0x4f000000 0x4d91a400 0x4b7ffed4))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4e800200 0x4efffd80 0x4b008001 0x4effffff))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x4f000000 0x4f000000 0x3f800000 0x4f800000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x4ceb79a3 0x4f452197 0x477fff00 0x47800000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x4f000000 0x00000000 0x4f000000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x4f1abcdf 0x4d91a2b4 0x4f800000 0x4f800000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x4f000000 0x4e7f0000 0x4f402000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x4eff0000 0x4f7f8000 0x4eff8000 0x4efeff93))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x3f800000 0x4e7c0000 0x4f3f0000 0x4e804000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x4ea02a06 0x4f501503 0x4e9e0000 0x4f4f0000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4e9f0000 0x4e8f0000 0x4f3f8000 0x4e773333))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x4f000000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x4effe000 0x00000000 0x4efff000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x4e7fe000 0x00000000 0x4f400400))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x4f080076 0x4efc6fc8 0x4f0b70e6 0x4f000012))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x4f7fe000 0x4e83c000 0x49800000 0x4f41e000))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x4f7fe000 0x4e83e000 0x4ec1227a 0x4e87c2b2))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x4f3fe000 0x00000000 0x4e801800))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x440ff008 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xe90cba78 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x80000000 0xc1000002 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xff800000 0x7f800000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x38000000 0x41000006 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xff800000 0xff800000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x7c000202 0x2e66666e 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 inf nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x3fc00000 0xc0200000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f800000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x4f000000 0xcf000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x4f800000 0x5f0ac723 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xbf000000 0x40600000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03820100 0x07860504 0x0b8a0908 0x0f8e0d0c))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x0f0e0d0c))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x1.79bde00000000p-74 0x1.68acf40000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff800000 0x4101fe00 0xf0903fc0 0x82feaa55))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 -0x1.02ff000000000p+127 nan:arithmetic -0x1.207f800000000p+97 -0x1.bd54f40000000p-123))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0xc0200000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x1.0000000000000p-132 nan:arithmetic 0x1.6900000000000p-90 0x1.fffda80000000p-125))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.6900000000000p-91 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf nan:arithmetic nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.01fffc0000000p+2 nan:arithmetic 0x1.0100020000000p-125 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.0100040000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic -0x1.0000000000000p-1 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.0000000000000p-148 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f32x4 nan:arithmetic -0x1.432e9e0000000p+11 0x1.0000000000000p-133 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f32x4 nan:arithmetic -0x1.2a05f20000000p+33 0x1.0000000000000p+31 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x07dbcd15 0xc5a1974f 0x0001fffe 0x00020000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.fffe000000000p-134 0x1.0000000000000p-133))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x4775e70b 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x1.79bde00000000p-73 0x1.68acf00000000p-90 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f32x4 -0x1.79bde00000000p-74 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 -0x1.79bde00000000p-74 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x40400000 0xc0a00000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x3fc00000 0xc0920000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -inf nan:arithmetic inf))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000002 0x3f800000 0xbf800000 0x40a00000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.c7fffe0000000p+4 -0x1.0000000000000p-1 -0x1.9800000000000p+4))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x509502f9 0xd09502f9 0x4f800000 0xcf800001))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x505502f9 0xd01502b9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic -0x1.2a05f20000000p+33 0x1.227a000000000p+66 -0x1.fffffc0000000p+30))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x50000000 0x48000040 0xc0000000 0x3e4ccccd))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x477ffec0 0xbf800000 0x40126666))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40780000 0x00000000 0xc0840000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0xc0040000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x3ff80000 0x0b0a0908 0xc0040000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8880759c 0x7eb7e43c 0x8bf0e62b 0x800024d0))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.6fc8780000000p+125 -0x1.e1cc540000000p-105 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x7e37e43c 0xf0103fc0 0x827eaca2))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+5 0x1.0000000000000p-128 -0x1.c000000000000p+5))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.cffffe0000000p+5 0x1.227a000000000p+66 0x1.a6b1c80000000p+8))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.6900000000000p-91 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+5 0x1.227a000000000p+67 0x1.c2b1c80000000p+9))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f32x4 nan:arithmetic 0x1.c3fffe0000000p+4 0x1.227a000000000p+66 0x1.c4e1c80000000p+8))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.227a000000000p+66 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xc0600000 0x00000000 0x408c0000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x1.c000000000000p+0 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x875bcd15 0x4521974f 0x8000ffff 0x80010000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f017f80 0xc081fe00 0x70103fc0 0x0f0e0d0c))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 0x1.79bde00000000p-74 -0x1.68acec0000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 -0x1.02ff000000000p+127 nan:arithmetic -0x1.207f800000000p+97 -0x1.1eaa300000000p-122))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x40200000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 -0x1.01fffc0000000p+1 nan:arithmetic 0x1.6900000000000p-91 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 -inf nan:arithmetic nan:arithmetic -0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.0100000000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0000000000000p-1 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f32x4 nan:arithmetic 0x1.432e9e0000000p+11 -0x1.fffc000000000p-134 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f32x4 nan:arithmetic 0x1.2a05f20000000p+33 -0x1.0000000000000p+31 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.fffe000000000p-134 0x1.0000000000000p-133))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xcf800000 0xc7850cfa 0x3f800000 0xbdcccccd))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 0x0.0p+0 0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f32x4 -0x1.79bde00000000p-74 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 -0x1.79bde00000000p-74 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 -inf inf nan:arithmetic -0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x3fc00000 0xbee00000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xd01502f9 0x501502f9 0xcf000000 0x4f000001))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic -0x1.b7fffe0000000p+4 -0x1.0000000000000p-1 0x1.e800000000000p+4))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4faa05f2 0xd0150339 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic -0x1.2a05f20000000p+33 -0x1.227a000000000p+66 -0x1.0000060000000p+31))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x47800120 0xbf800000 0xc005999a))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0800759c 0xfe37e43c 0x0b70e62b 0xc0040000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x83020100 0x3ff80000 0x8b0a0908 0xc0040000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.6fc8780000000p+125 -0x1.e1cc580000000p-105 0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f017f80 0x7e37e43c 0x70103fc0 0x027ea808))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic -0x1.0000000000000p+1 -0x1.227a000000000p+66 -0x1.deb1c80000000p+8))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 nan:arithmetic nan:arithmetic -0x1.6900000000000p-91 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f32x4 nan:arithmetic 0x1.fbfffe0000000p+4 0x1.227a000000000p+66 0x1.c081c80000000p+8))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.227a000000000p+66 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x1.c000000000000p+0 -0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xc2838681 0x08081b00 0xbb9b8ee8 0x80000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x0.0p+0 0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f800000 0x418403f0 0x7f800000 0x00000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 -0x1.02ff000000000p-6 nan:arithmetic -0x1.96d3cc0000000p+6 -0x0.0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x80000000 0x80000000 0xf0585fa0 0x031f2a75))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.0200000000000p-132 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf nan:arithmetic nan:arithmetic 0x1.ff90e40000000p+2))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.0403f80000000p+2 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 0x1.0000000000000p-148 nan:arithmetic -0x1.0100000000000p-127 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x1.0000000000000p-118 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x4acbff56 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x175bcd15 0xcd2197a0 0x8000ffff 0x0000199a))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 0x1.0000000000000p-147 0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f32x4 -0x0.0p+0 -0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x40100000 0x40c80000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic -inf))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x40a50000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf inf nan:arithmetic inf))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 inf -inf nan:arithmetic inf))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const f32x4 -inf -inf nan:arithmetic -0x1.2641700000000p-9))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x3e800000 0x3e800000 0x40c80000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x059502f9 0xcf9502f9 0xce800000 0xcfa00001))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+3 -0x1.0000000000000p-130 -0x1.1800000000000p+6))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x60ad78ec 0x60ad78ec 0x5e800000 0x5e800002))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x601502f9 0xd8150344 0xcf000000 0xcd4ccccf))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic -0x1.1765920000000p+38 0x1.227a000000000p+97 -0x1.c2b1cc0000000p+39))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x5f800000 0x4f800080 0x3f800000 0x3c23d70b))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x80000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xc7e00070 0x80000000 0x3e600000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40704000 0x00000000 0x40882000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80000000 0x7eb2251a 0x80000000 0x000025f6))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x0781d4dc 0x00000000 0x8f927d74))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x7f800000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic inf -0x0.0p+0 0x1.01b0000000000p-132))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x4781f67c 0x7f3ac0ed 0x3c07bd76 0x00000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.87fffc0000000p+9 0x0.0p+0 0x1.8800000000000p+9))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.a3fffc0000000p+9 0x1.227a000000000p-63 -0x1.8a5b900000000p+13))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x1.bffdf40000000p-121))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.c1fffc0000000p+9 inf 0x1.8cbac00000000p+17))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f32x4 nan:arithmetic -0x1.a3fffe0000000p+5 0x0.0p+0 0x1.ecf2720000000p+9))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.239c7c0000000p-60 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x40440000 0x00000000 0x40992000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x3f800000 0x3f800000 0x3f800000 0x3f800000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x80000000 0x0603f737 0x80000000 0xcc0ecba1))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x1.606bb40000000p-48 0x1.7c7f4e0000000p-23 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3f800000 0x3f800000 0x3f800000 0x3f800000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 -inf nan:arithmetic -inf -0x1.fd56fe0000000p+2))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff800000 0xff800000 0xefc05500 0x01cbbb77))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x1.0000000000000p+0 nan:arithmetic 0x1.0000000000000p+0 0x1.0000000000000p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.fc08000000000p-135 nan:arithmetic 0x1.6798640000000p+35 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.0000000000000p+0 nan:arithmetic 0x1.0000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.0100020000000p+23 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 inf nan:arithmetic -0x1.0100020000000p-125 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.0000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x1.0001000000000p-16 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x3f800000 0x3f800000 0x3f800000 0x3f800000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic inf -inf))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000001b 0xbd2196fe 0x8000ffff 0x000a0000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 0x1.0000000000000p+0 0x1.0000000000000p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f32x4 -inf -inf nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 -inf nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.0000000000000p+0 0x1.0000000000000p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 0x0.0p+0 0x0.0p+0 nan:arithmetic -0x1.4044080000000p-127))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 inf 0x1.364d940000000p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic 0x1.0000000000000p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.9942960000000p+126))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const f32x4 -inf -inf nan:arithmetic -inf))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x3f800000 0x3f800000 0x3f800000 0x3f800000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xae5be6ff 0xaf800000 0xb09fffff))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.24924a0000000p-6 -inf -0x1.6db6dc0000000p-4))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x3f800000 0x3f800000 0x3f800000 0x3f800000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x401502f9 0xc81502ae 0xcf000000 0xd0a00001))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic -0x1.3de4360000000p+28 0x1.c33b1c0000000p-36 -0x1.22d2840000000p+22))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x3f800000 0x3f800000 0x3f800000 0x3f800000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x7f800000 0x7f800000 0xff800000 0xff800000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x7f800000 0xc712496e 0xff800000 0x3d3b3ee7))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 nan:arithmetic 0x1.0000000000000p+0 nan:arithmetic 0x1.0000000000000p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80000000 0x012c9f9d 0x80000000 0x7f800000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x786cdc63 0x00000000 0xf06de2ef))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x3f800000 0x3f800000 0x3f800000 0x3f800000))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.a452d40000000p+120 -0x1.e1cc560000000p+24 0x1.5000000000000p-142))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x7d3512c5 0x00000000 0x3814058e))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.0000000000000p+0 0x1.0000000000000p+0 0x1.0000000000000p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.ddddde0000000p-1 0x0.0p+0 -0x1.fcf0640000000p-5))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.6b14900000000p-39 -inf))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.0000000000000p+0 0x1.0000000000000p+0 0x1.0000000000000p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f32x4 nan:arithmetic -0x1.1249240000000p+4 inf 0x1.9c10420000000p+7))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 nan:arithmetic nan:arithmetic inf nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f32x4 nan:arithmetic 0x1.0000000000000p+0 nan:arithmetic 0x1.0000000000000p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const f32x4 nan:arithmetic -inf nan:arithmetic inf))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic inf 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xc521974f 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x07060504 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x1.79bde00000000p-74 0x1.0c0a080000000p-113 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 -0x1.02ff000000000p+127 nan:arithmetic -0x1.207f800000000p+97 -0x1.fd54aa0000000p-123))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x80000000 0xf0103fc0 0xc0200000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic 0x1.6900000000000p-91 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic nan:arithmetic 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 0x1.0000000000000p-149 nan:arithmetic -0x1.0000000000000p-1 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f32x4 nan:arithmetic -0x1.432e9e0000000p+11 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f32x4 nan:arithmetic -0x1.2a05f20000000p+33 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x075bcd15 0xc521974f 0xbf800000 0x00010000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x1.79bde00000000p-74 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f32x4 -0x1.79bde00000000p-74 -0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 -0x1.79bde00000000p-74 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 0x0.0p+0 -inf nan:arithmetic -0x1.4000000000000p+1))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xc0200000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 0x1.0000000000000p-149 -inf nan:arithmetic 0x1.4000000000000p+1))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const f32x4 -0x1.00eb380000000p-111 -inf nan:arithmetic -0x1.2680000000000p-137))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000001 0xd01502f9 0xbf000000 0xcf000001))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.0000000000000p-1 -0x1.0000000000000p-1 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0xd01502f9 0xbf800000 0xcf000001))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic -0x1.2a05f20000000p+33 0x1.0000000000000p+31 -0x1.0000020000000p+31))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xbf800000 0x80000000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x3ff80000 0x8b70e62b 0xc0040000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x07060504 0x00000000 0xc0040000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 -0x1.e1cc560000000p-105 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 0x1.0000000000000p-129 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 0x1.0000000000000p-129 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.0000000000000p-129 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f32x4 nan:arithmetic -0x1.c000000000000p+0 0x0.0p+0 0x1.1800000000000p+1))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x1.c000000000000p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x00000000 0x0000ffff 0x00010000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x03020100 0x4081fe00 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 0x1.0402000000000p-121 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic 0x1.6900000000000p-91 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x4081fe00 0x3fc00000 0x827eaa55))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic 0x1.6900000000000p-91 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.6900000000000p-91 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf nan:arithmetic nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.fffe000000000p-134 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.0000000000000p+31 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.fffe000000000p-134 0x1.0000000000000p-133))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x0000ffff 0x3dcccccd))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x1.79bde00000000p-74 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f32x4 0x0.0p+0 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -0x0.0p+0 nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x3fc00000 0xc0040000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 inf 0x1.0000000000000p-1 nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const f32x4 inf 0x1.6fc8780000000p+125 nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x3f000000 0x4f000000 0x40200000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 0x1.0000000000000p-129 0x1.4000000000000p+1))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x501502f9 0x47800040 0x4f000000 0x3dcccccd))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x3dcccccd))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x7e37e43c 0x00000000 0x80001268))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x3ff80000 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.6fc8780000000p+125 0x1.0000000000000p-129 -0x1.2680000000000p-137))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 0x1.0000000000000p-129 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.6900000000000p-91 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.227a000000000p+66 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xc521974f 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x07060504 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x80000000 0xf0103fc0 0xc0200000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic 0x1.6900000000000p-91 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic 0x1.0100020000000p-126 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic 0x1.6900000000000p-91 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 0x1.0000000000000p-149 nan:arithmetic -0x1.0000000000000p-1 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f32x4 nan:arithmetic -0x1.432e9e0000000p+11 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f32x4 nan:arithmetic -0x1.2a05f20000000p+33 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x075bcd15 0xc521974f 0xbf800000 0x00010000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x1.79bde00000000p-74 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f32x4 -0x1.79bde00000000p-74 -0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 -0x1.79bde00000000p-74 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0xff800000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xc0200000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 0x1.0000000000000p-149 -inf nan:arithmetic 0x1.4000000000000p+1))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const f32x4 -0x1.00eb380000000p-111 -inf nan:arithmetic -0x1.2680000000000p-137))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000001 0xd01502f9 0xbf000000 0xcf000001))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0xc1e00000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0xd01502f9 0xbf800000 0xcf000001))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xbf800000 0x80000000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x3ff80000 0x8b70e62b 0xc0040000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x07060504 0x00000000 0xc0040000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8800759c 0x41dfffff 0x8b70e62b 0xc1e00000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 0x1.0000000000000p-129 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 0x1.0000000000000p-129 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 0x1.0000000000000p-129 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f32x4 nan:arithmetic -0x1.c000000000000p+0 0x0.0p+0 0x1.1800000000000p+1))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.0100020000000p-126 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x00000000 0x0000ffff 0x00010000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x03020100 0x4081fe00 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x03020100 0x12345678 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x4081fe00 0x12348000 0x00fffed4))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x4081fe00 0x3fc00000 0x827eaa55))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic 0x1.6900000000000p-91 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.6900000000000p-91 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf nan:arithmetic 0x1.6900000000000p-91 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0100020000000p-126 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.fffe000000000p-134 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.0000000000000p+31 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x0000ffff 0x3dcccccd))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f32x4 -0x1.79bde00000000p-74 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f32x4 0x0.0p+0 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x80000000 0x3fc00000 0x7f7fc99e))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x3fc00000 0xc0040000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f32x4 inf -inf nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f32x4 inf 0x1.0000000000000p-1 nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const f32x4 inf 0x1.6fc8780000000p+125 nan:arithmetic 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x3f000000 0x4f000000 0x40200000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000001 0x41dfffff 0x00100000 0x40200000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x501502f9 0x47800040 0x4f000000 0x3dcccccd))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x501502f9 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x3dcccccd))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x7e37e43c 0x00000000 0x80001268))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x3ff80000 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x00100000 0x80001268))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 0x1.0000000000000p-129 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f32x4 nan:arithmetic 0x1.bffffe0000000p+4 0x1.6900000000000p-91 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 0x1.227a000000000p+66 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x80000000 0x00000001 0x400c0000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
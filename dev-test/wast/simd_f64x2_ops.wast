@@ -0,0 +1,1221 @@
+;; Generated lane by lane against V8, covering f64x2 operations
+
+(module
+  (func (export "f64x2.abs") (param v128) (result v128)
+    (f64x2.abs (local.get 0))
+  )
+  (func (export "f64x2.neg") (param v128) (result v128)
+    (f64x2.neg (local.get 0))
+  )
+  (func (export "f64x2.sqrt") (param v128) (result v128)
+    (f64x2.sqrt (local.get 0))
+  )
+  (func (export "f64x2.ceil") (param v128) (result v128)
+    (f64x2.ceil (local.get 0))
+  )
+  (func (export "f64x2.floor") (param v128) (result v128)
+    (f64x2.floor (local.get 0))
+  )
+  (func (export "f64x2.trunc") (param v128) (result v128)
+    (f64x2.trunc (local.get 0))
+  )
+  (func (export "f64x2.nearest") (param v128) (result v128)
+    (f64x2.nearest (local.get 0))
+  )
+  (func (export "f64x2.convert_low_i32x4_s") (param v128) (result v128)
+    (f64x2.convert_low_i32x4_s (local.get 0))
+  )
+  (func (export "f64x2.convert_low_i32x4_u") (param v128) (result v128)
+    (f64x2.convert_low_i32x4_u (local.get 0))
+  )
+  (func (export "f64x2.promote_low_f32x4") (param v128) (result v128)
+    (f64x2.promote_low_f32x4 (local.get 0))
+  )
+  (func (export "f64x2.add") (param v128 v128) (result v128)
+    (f64x2.add (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.sub") (param v128 v128) (result v128)
+    (f64x2.sub (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.mul") (param v128 v128) (result v128)
+    (f64x2.mul (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.div") (param v128 v128) (result v128)
+    (f64x2.div (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.min") (param v128 v128) (result v128)
+    (f64x2.min (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.max") (param v128 v128) (result v128)
+    (f64x2.max (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.pmin") (param v128 v128) (result v128)
+    (f64x2.pmin (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.pmax") (param v128 v128) (result v128)
+    (f64x2.pmax (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.eq") (param v128 v128) (result v128)
+    (f64x2.eq (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.ne") (param v128 v128) (result v128)
+    (f64x2.ne (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.lt") (param v128 v128) (result v128)
+    (f64x2.lt (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.gt") (param v128 v128) (result v128)
+    (f64x2.gt (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.le") (param v128 v128) (result v128)
+    (f64x2.le (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.ge") (param v128 v128) (result v128)
+    (f64x2.ge (local.get 0) (local.get 1))
+  )
+)
+
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x027eaa55))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x4521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x3fc00000 0x40200000))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x7f800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x501502f9 0x4f000000 0x4f000001))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x40040000))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x00001268))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0x41e00000))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x3fe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x87060504 0x0b0a0908 0x8f0e0d0c))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0xc081fe00 0xf0103fc0 0x027eaa55))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x4521974f 0x0000ffff 0x80010000))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 -0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x3fc00000 0x40200000))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x7f800000 0x7fc00000 0xff7fc99e))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0xbf000000 0xbf000000 0xc0200000))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x501502f9 0x4f000000 0x4f000001))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0xc7800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 -inf nan:arithmetic))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x00000000 0x40040000))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xfe37e43c 0x8b70e62b 0x00001268))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0xc1dfffff 0x00100000 0x41e00000))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0xc1efffff 0x60913d00 0xc3e158e4))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x3fe00000 0x00000000 0xc00c0000))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xb1a971a0 0x237a8b78 0xc065fde6 0x277f029a))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const f64x2 0x1.7feab4b395159p+4 nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.6a0345b0ff9a9p-504))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f64x2 nan:arithmetic 0x1.000000007fff8p-513))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.209f9f2cc09c1p-366 nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f64x2 -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f64x2 nan:arithmetic 0x1.68d5c35fc08dbp+508))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x667f3bcd 0x3f76a09e 0xed8debad 0x4006a09e))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xdfc560bd 0x43b6a0cb 0x9356710c 0x3ede5b9d))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f64x2 0x1.3988e1409212ep+0 nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const f64x2 0x1.38d352e5096afp+498 nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f64x2 0x1.6a09e6673eb7dp+15 nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xfff00000 0x40efffff 0xe385635d 0x41e78f90))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const f64x2 nan:arithmetic 0x1.deeea11683f49p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x40820000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.0000000000000p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.0000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xc0200000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x40220000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40000000 0x00000000 0xc0000000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x41e00000 0x00000000 0xc1e00000))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x40100000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x4081f800 0x00000000 0xbff00000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x1.0000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xc0220000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40200000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0xc0080000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x00000000 0xbff00000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffc00000 0x41dfffff 0x00200000 0xc1e00000))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbff00000 0x00000000 0x40080000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x4081f800 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xc0200000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40200000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0xc0000000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffc00000 0x41dfffff 0x00000000 0xc1e00000))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x40080000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x40820000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xc0200000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40200000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40000000 0x00000000 0xc0000000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x41e00000 0x00000000 0xc1e00000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x40100000))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0xbff00000 0x00000000 0xbff00000))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x41881008 0x10000000 0x419c1814))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xc16fd010 0x80000000 0x41d0207f))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x40f00000 0xffc00000 0x41dfffff))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xff800000 0x41d0003f 0x00000000 0x41dfffb0))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffc00000 0x41dfffff 0x00000000 0xc1e00000))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x54000000 0x419d6f34 0x58800000 0xc1cd6f34))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xbff00000 0xffc00000 0x41dfffff))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x44000000 0xc1d950c8 0x78000000 0x41b23456))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xc1e00000))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x41dfe000 0x00000000 0xc1600000))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x41cf8000))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xbe400000 0x41d40540 0x83800000 0xc1c7f57e))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x41d3e000 0x10000000 0x41d1e000))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x41dffc00))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x41cffc00))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x99000000 0xc1ddffe2 0x0f000000 0x41df8df9))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xc1400000 0xffc00000 0x41d077ff))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xc1400000 0xffc00000 0x41d07bff))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xc1d00800))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffe00000 0x41efffff 0xffe00000 0x41efffff))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x41881008 0x10000000 0x419c1814))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xf0000000 0x41efe02f 0x80000000 0x41d0207f))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x40f00000 0xffc00000 0x41dfffff))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xff800000 0x41d0003f 0x00000000 0x41dfffb0))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffc00000 0x41dfffff 0x00000000 0x41e00000))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x54000000 0x419d6f34 0xe9e00000 0x41e8a432))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffe00000 0x41efffff 0xffc00000 0x41dfffff))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xde000000 0x41e3579b 0x78000000 0x41b23456))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x41e00000))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x41dfe000 0x00000000 0x41eff000))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x41cf8000))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xbe400000 0x41d40540 0x5f200000 0x41ea02a0))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x41d3e000 0x10000000 0x41d1e000))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x41dffc00))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x41cffc00))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xb3800000 0x41e1000e 0x0f000000 0x41df8df9))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x41effc00 0xffc00000 0x41d077ff))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x41effc00 0xffc00000 0x41d07bff))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x41e7fc00))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x38604020 0x80000000 0x38e0c0a0))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xc7e02ff0 0x00000000 0x40103fc0))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 0x1.0000000000000p-133 nan:arithmetic))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 0x1.01fffc0000000p+1 nan:arithmetic))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xa0000000 0x38eb79a2 0xe0000000 0xc0a432e9))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0xbb579bde 0x00000000 0x3a468acf))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff00000))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x36a00000 0x00000000 0x3fe00000))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x20000000 0x4202a05f 0x20000000 0xc202a05f))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x41f00000 0x00000000 0x40f00008))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3fff0000))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80000000 0xb9000eb3 0x80000000 0x47c6fc87))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const f64x2 nan:arithmetic 0x1.bffffe0000000p+4))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const f64x2 nan:arithmetic 0x1.dffffe0000000p+4))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xbffc0000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07160504 0x0b0a0908 0x0f1e0d0c))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4091fe00 0xf0103fc0 0x828eaa55))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -0x1.eaa55d0116baep-984))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x4081fe00 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1007))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f64x2 nan:arithmetic 0x1.fc99e7fc00000p+1016))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x0.00000fffffffep-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f64x2 -0x1.1974f075bcd15p+83 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f64x2 -0x1.502f9501502f9p+258 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc531974f 0x0001fffe 0x00020000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic 0x0.100000000ffffp-1022))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f7fb9a3 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.456789abcdef0p-731 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0300000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x3fc00000 0xc0250000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff900000 0x7fc00000 0x7f8fc99e))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80bc3780 0xff7fffff 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f100000 0xbf000000 0x40300000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00080 0x41dfffff 0xfe1fffe8 0xc1dfffff))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd02502f9 0x4f000000 0xcf100001))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47900040 0xbf800000 0x3ddccccd))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x47800040 0x0001cccd 0x400c0000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40080000 0x00000000 0xc0140000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e47e43c 0x16e1cc56 0x800024d1))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8800759c 0x7e37e43c 0xf010400a 0x827eaa55))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41efffff 0x00100000 0xc1f00000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe80000 0x41f7ffff 0x60813d00 0x43e158e4))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -0x1.0000000100000p+31))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41ffffff 0x60913d00 0x43f158e4))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffd00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbff00000 0x00000000 0x401c0000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x1.0000000000000p-1 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x4521974f 0x0000ffff 0x80010000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0xc081fe00 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 -0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -0x1.eaa56100f13d2p-984))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x4081fe00 0x3fc00000 0x40200000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f64x2 nan:arithmetic -0x1.fc99e7fc00000p+1016))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f64x2 0x1.1974f075bcd15p+83 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f64x2 0x1.502f9501502f9p+258 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic 0x0.100000000ffffp-1022))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f80465d 0xc7800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 -inf nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x7f800000 0x7fc00000 0xff7fc99e))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x7f800000 0xc0160000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x3ea1e440 0xff800001 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x501502f9 0x4f000000 0x4f000001))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffdfff80 0xc1dfffff 0x0110000c 0x41e00000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x47800040 0xfffe3333 0xc00bffff))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 -inf nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xfe37e43c 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x00100000 0x41e00000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8800759c 0x7e37e43c 0xf0103f76 0x027eaa55))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0xc1dfffff 0x60a13d00 0xc3e158e4))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -0x1.0000000100000p+31))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xfff00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x1.0000000000000p-1 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x41d3e098 0x0798c2e5 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7d83ae60 0x41143b82 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x6a3eee29 0x02aeaa56))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f64x2 nan:arithmetic 0x1.fc8748fa7a248p+9))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f64x2 0x1.1974f07389e77p-960 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f64x2 0x1.502f94feafd06p-785 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xd4d45139 0x4a53571e 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xbbffbb31 0xccb19795 0x0000399a 0x00000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f64x2 -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x7f8000fe 0x40500000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0xfe675385 0xffafc99e))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x4fb00000 0x40340000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff00000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0xfe900000 0xfb36d415 0x7fafc99f))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xfff00000 0xeee9f983 0xbf224942))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000002 0x3e100000 0x7e0008e8 0x40500001))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502fa 0xcf2502f9 0x0e000f9f 0xcf300002))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00002 0x40efffff 0xbf100001 0xc2100000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffb7fb2d 0x603b97ce 0x9e001b66 0x5e100002))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xc4610a13 0xd7a5034d 0x1a8013da 0xccdcccd0))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x50000000 0xd21502f9 0xcbc66aa6 0xd2f158e5))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xa0027d8b 0x4f100080 0xd3948212 0x3ba9eb86))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0xc7700040 0x07900000 0x3de93334))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40020000 0x00000000 0x40190000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x66005835 0x7e41eb2d 0x5c9a3f6c 0x00002e05))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x024180c0 0x071083c3 0x86e645a5 0x8f22c827))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x7ff00000 0x70f8938b 0x0182688b))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0e37459f 0x7ecaddc9 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffc00000 0x43cfffff 0x00200000 0x43d00000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffc00000 0x43dfffff 0x60a295e4 0xc5d158e4))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -0x1.ffed412547ed4p-977))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffc00000 0x43efffff 0x2a16a1b1 0x47d2ced3))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0xc1dfffff 0xa8fe2ac0 0x43fe5b8f))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x3fd00000 0x00000000 0x40288000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 0x0.0000040000000p-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x21f1da22 0x067394da 0xa493472f 0xcc7f5bdd))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.152b7127a6a77p-179 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -0x1.eab75660a1909p+23))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xfff00000 0x75e1933e 0x024eaa55))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.0000000000000p+0))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 0x1.0000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -inf))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x53d40c64 0xbd919708 0xdc0850b6 0x01f1c71b))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.0000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f64x2 -inf nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f64x2 nan:arithmetic 0x1.0000000000000p+0))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x8476c6c8 0x80901b5f))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0xff99999a 0x40099999))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xfff00000 0x04493d9d 0x7f4fc99d))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x6dbe8072 0xc1356e20 0x00000000 0xfff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xb39265cd 0xaed85e12 0xe000178e 0xb10ffffe))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00100001 0x3d100000 0xbeefffff 0xbe300000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xdd1c6b5d 0xc88502a4 0x502f60fa 0xd121c71d))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x502a05f2 0xce1502f9 0xb9ae19a1 0xcb0d83cb))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0xc7900040 0xdb249249 0x3db07507))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xd23ab682 0x01b01297 0x00000000 0x7ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x46fb72db 0x78e17063 0x383d5326 0xf0e54c12))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x881859d9 0x7c47e43c 0x000024d1 0x00000000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xbdfaa71b 0x7da53f02 0x2fb95927 0x3d0335a5))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x3fe00000 0x4fd45676 0xbded83c9))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -inf))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0xc1ffffff 0x253845b7 0x43c3d34e))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x3ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xfff00000 0x00000000 0x7ff00000))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x03020100 0x07060504 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.6050403020100p-911 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -0x1.eaa55f0103fc0p-984))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f64x2 -0x1.1974f075bcd15p+83 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f64x2 -0x1.502f9501502f9p+258 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f64x2 -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0xff800000 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f800000 0xff800000 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000001 0x3f000000 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f64x2 0x1.8000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic -0x1.0000000100000p+31))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x1.0000000000000p-1 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f64x2 nan:arithmetic 0x1.fc99e7fc00000p+1016))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f64x2 -0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f64x2 -0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic 0x0.100000000ffffp-1022))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x80000000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x3ff80000 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x03020100 0x07060504 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f64x2 -0x1.1974f075bcd15p+83 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f64x2 -0x1.502f9501502f9p+258 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f64x2 -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0xff800000 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f800000 0xff800000 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000001 0x3f000000 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f64x2 0x1.8000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff017f80 0x4081fe00 0x12348000 0x00fffed4))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const f64x2 nan:arithmetic 0x1.fc99e7fc00000p+1016))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const f64x2 -0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const f64x2 -0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const f64x2 -0x0.000007fffffffp-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const f64x2 0x1.456789abcdef0p-732 nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x80000000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 inf nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x3ff80000 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x12348000 0x00fffed4))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
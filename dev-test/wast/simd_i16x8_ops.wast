@@ -0,0 +1,2749 @@
+;; Generated lane by lane against V8, covering i16x8 operations
+
+(module
+  (func (export "i16x8.abs") (param v128) (result v128)
+    (i16x8.abs (local.get 0))
+  )
+  (func (export "i16x8.neg") (param v128) (result v128)
+    (i16x8.neg (local.get 0))
+  )
+  (func (export "i16x8.extadd_pairwise_i8x16_s") (param v128) (result v128)
+    (i16x8.extadd_pairwise_i8x16_s (local.get 0))
+  )
+  (func (export "i16x8.extadd_pairwise_i8x16_u") (param v128) (result v128)
+    (i16x8.extadd_pairwise_i8x16_u (local.get 0))
+  )
+  (func (export "i16x8.extend_low_i8x16_s") (param v128) (result v128)
+    (i16x8.extend_low_i8x16_s (local.get 0))
+  )
+  (func (export "i16x8.extend_low_i8x16_u") (param v128) (result v128)
+    (i16x8.extend_low_i8x16_u (local.get 0))
+  )
+  (func (export "i16x8.extend_high_i8x16_s") (param v128) (result v128)
+    (i16x8.extend_high_i8x16_s (local.get 0))
+  )
+  (func (export "i16x8.extend_high_i8x16_u") (param v128) (result v128)
+    (i16x8.extend_high_i8x16_u (local.get 0))
+  )
+  (func (export "i16x8.add") (param v128 v128) (result v128)
+    (i16x8.add (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.add_sat_s") (param v128 v128) (result v128)
+    (i16x8.add_sat_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.add_sat_u") (param v128 v128) (result v128)
+    (i16x8.add_sat_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.sub") (param v128 v128) (result v128)
+    (i16x8.sub (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.sub_sat_s") (param v128 v128) (result v128)
+    (i16x8.sub_sat_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.sub_sat_u") (param v128 v128) (result v128)
+    (i16x8.sub_sat_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.mul") (param v128 v128) (result v128)
+    (i16x8.mul (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.min_s") (param v128 v128) (result v128)
+    (i16x8.min_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.min_u") (param v128 v128) (result v128)
+    (i16x8.min_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.max_s") (param v128 v128) (result v128)
+    (i16x8.max_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.max_u") (param v128 v128) (result v128)
+    (i16x8.max_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.avgr_u") (param v128 v128) (result v128)
+    (i16x8.avgr_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.q15mulr_sat_s") (param v128 v128) (result v128)
+    (i16x8.q15mulr_sat_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.eq") (param v128 v128) (result v128)
+    (i16x8.eq (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.ne") (param v128 v128) (result v128)
+    (i16x8.ne (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.lt_s") (param v128 v128) (result v128)
+    (i16x8.lt_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.lt_u") (param v128 v128) (result v128)
+    (i16x8.lt_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.gt_s") (param v128 v128) (result v128)
+    (i16x8.gt_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.gt_u") (param v128 v128) (result v128)
+    (i16x8.gt_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.le_s") (param v128 v128) (result v128)
+    (i16x8.le_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.le_u") (param v128 v128) (result v128)
+    (i16x8.le_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.ge_s") (param v128 v128) (result v128)
+    (i16x8.ge_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.ge_u") (param v128 v128) (result v128)
+    (i16x8.ge_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.narrow_i32x4_s") (param v128 v128) (result v128)
+    (i16x8.narrow_i32x4_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.narrow_i32x4_u") (param v128 v128) (result v128)
+    (i16x8.narrow_i32x4_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extmul_low_i8x16_s") (param v128 v128) (result v128)
+    (i16x8.extmul_low_i8x16_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extmul_low_i8x16_u") (param v128 v128) (result v128)
+    (i16x8.extmul_low_i8x16_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extmul_high_i8x16_s") (param v128 v128) (result v128)
+    (i16x8.extmul_high_i8x16_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extmul_high_i8x16_u") (param v128 v128) (result v128)
+    (i16x8.extmul_high_i8x16_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.shl") (param v128 i32) (result v128)
+    (i16x8.shl (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.shr_s") (param v128 i32) (result v128)
+    (i16x8.shr_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.shr_u") (param v128 i32) (result v128)
+    (i16x8.shr_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.all_true") (param v128) (result i32)
+    (i16x8.all_true (local.get 0))
+  )
+  (func (export "i16x8.bitmask") (param v128) (result i32)
+    (i16x8.bitmask (local.get 0))
+  )
+)
+
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00ff7f80 0x40810200 0x0ff03fc0 0x7d8255ab))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fff0001 0x12348000 0x00ff012c))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000002 0x7ffe4000 0x00807fff 0x7fff0081))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fff0001 0x80000000 0x00000001 0x00010001))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075b32eb 0x3adf68b1 0x00000001 0x00010000))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00010001 0x7fff0001 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x65442110 0x12345678 0x00010005 0x00010001))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0x3fe00000))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x00800000 0x7fc00000 0x7f7f3662))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0x41000000 0x40200000))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x2feb02f9 0x4f000000 0x31000001))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x40800000 0x3dcc3333))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7800759c 0x7e371bc4 0x749019d5 0x80001268))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00200000 0x41df0001 0x00100000 0x3e200000))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00200000 0x41ef0001 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x40200000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfeff00 0xf8fafafc 0xf4f6f6f8 0xf0f2f2f4))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00ff8080 0xbf7f0200 0x0ff0c040 0x7d8255ab))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0x80010001 0xedcc8000 0xff01012c))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xc0000002 0x80024000 0xff807fff 0x80010081))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80010001 0x80000000 0x0000ffff 0x00010001))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf8a532eb 0x3adf68b1 0x00000001 0xffff0000))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00010001 0x80010001 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x65442110 0xedcca988 0x00010005 0x00010001))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0xc0400000 0x3fe00000))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80800000 0x00800000 0x80400000 0x80813662))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ffff 0xc1000000 0x41000000 0xbfe00000))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xafebfd07 0x2febfd07 0xb1000000 0x3100ffff))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xb0800000 0xb880ffc0 0x40800000 0xc2343333))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x80100000 0x00000000 0x80080000))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xc0080000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x78008a64 0x81c91bc4 0x749019d5 0x8000ed98))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00200000 0xbe210001 0xfff00000 0x3e200000))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00200000 0xbe110001 0x9f6fc300 0xbc1fa71c))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x40200000 0x00000000 0xbff40000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfffefffe 0xfffefffe 0xfffefffe 0xfffefffe))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00050001 0x000d0009 0x00150011 0x001d0019))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0000ffff 0xffc1fffe 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x007efffe 0x0046ff80 0xffffffd2))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0040fffd 0x007dffc0 0xff80ff81 0x007e007e))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x007efffe 0xff800000 0x00000001 0xfffefffe))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0062ffe2 0xffe6ffe6 0x0000fffe 0x00010000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffefffe 0x007efffe 0x00000000 0xff800000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xff56ffce 0x004600ce 0xfffefffa 0xfffefffe))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xff800000 0xffff0000 0xffe00000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0xff7f0000 0x003f0000 0x00feff67))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x003f0000 0xffbf0000 0x00600000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0065fffb 0xffe5fffb 0x004f0000 0xffcf0001))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffcf0000 0xffc70040 0xff3f0000 0x0009ff99))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff800000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x006f0000 0x00000000 0x00770000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00370000 0x00000000 0xffc40000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff880011 0x00b50020 0xfffb0011 0xff80007a))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffdf0000 0x0020fffe 0x00100000 0xffa10000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffdf0000 0x0030fffe 0xfff1003d 0x0024003c))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xff9f0000 0x00000000 0x004c0000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x01fe01fe 0x01fe01fe 0x01fe01fe 0x01fe01fe))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00050001 0x000d0009 0x00150011 0x001d0019))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x010000ff 0x00c100fe 0x010000ff 0x010000ff))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x017e01fe 0x00460080 0x00ff01d2))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x004001fd 0x017d00c0 0x00800081 0x017e017e))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x017e01fe 0x00800000 0x00000001 0x01fe01fe))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x006200e2 0x00e600e6 0x000001fe 0x00010000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x01fe01fe 0x017e01fe 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x015601ce 0x004600ce 0x01fe01fa 0x01fe01fe))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00800000 0x00ff0000 0x00e00000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00ff0000 0x017f0000 0x013f0000 0x00fe0167))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x003f0000 0x00bf0000 0x00600000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x006500fb 0x00e500fb 0x004f0000 0x00cf0001))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00cf0000 0x00c70040 0x013f0000 0x01090199))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x016f0000 0x00000000 0x01770000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x01370000 0x00000000 0x00c40000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00880111 0x00b50120 0x00fb0111 0x0080007a))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x01df0000 0x012001fe 0x00100000 0x01a10000))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x01df0000 0x013001fe 0x00f1003d 0x0124013c))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x019f0000 0x00000000 0x004c0000))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00010000 0x00030002 0x00050004 0x00070006))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x007fff80 0xffff0001 0xfffe0000 0x0040ff81))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0xffffffff 0x007fffff))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffffffe 0x00400000 0xffc00000 0x007ffffe))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x007fffff 0x00000000 0xff800000))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffcd0015 0x0007005b 0xff97004f 0xffc50021))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x007fffff))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffdefff0 0xff9affbc 0x00560078 0x00120034))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff800000))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x007fff80 0x00000000 0xffffff80))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x003f0000))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0002fff9 0x00500015 0x0002fff9 0xffd00015))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x004fff80 0x00000040 0x0047ff80))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x007ffff0))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x003ffff8))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0075ff9c 0xff880000 0xffe4003c 0x007e0037))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffe0 0xffffffff 0x0041ffdf))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffe0 0xffffffff 0x0041ffef))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffbfffe0))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00ff00ff 0x00ff00ff 0x00ff00ff 0x00ff00ff))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00010000 0x00030002 0x00050004 0x00070006))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x007f0080 0x00ff0001 0x00fe0000 0x00400081))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0x00ff00ff 0x007f00ff))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00ff00fe 0x00400000 0x00c00000 0x007f00fe))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00ff00ff 0x007f00ff 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00cd0015 0x0007005b 0x0097004f 0x00c50021))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00ff00ff 0x00ff00ff 0x00ff00ff 0x007f00ff))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00de00f0 0x009a00bc 0x00560078 0x00120034))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x007f0080 0x00000000 0x00ff0080))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x003f0000))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000200f9 0x00500015 0x000200f9 0x00d00015))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x004f0080 0x00000040 0x00470080))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x007f00f0))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x003f00f8))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0075009c 0x00880000 0x00e4003c 0x007e0037))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00ff00e0 0x00ff00ff 0x004100df))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00ff00e0 0x00ff00ff 0x004100ef))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00bf00e0))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00090008 0x000b000a 0x000d000c 0x000f000e))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x003fffc0 0xfff00010 0xffaa0055 0xff82007e))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff800000 0x00120034 0xfffeffd4 0x0000ffff))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xff800001 0x0000ff80 0xffff007f 0x007fffff))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff800000))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xfffffffb 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x003fffc0 0x00000000 0xffc00020))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x007fffc0 0xffc9ff9e 0x007f007f))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xffbf0000 0x00000000 0x00400020))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x004f0000 0x00000001 0xffcf0000))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xffbfff80 0xffccffcd 0x003dffcc))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff800000))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x007ffff8))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffc00004))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffe6002b 0xff8b0070 0x00120068 0xff800000))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000010 0x00000000 0xffc1ffe0))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x003d0000 0x0060ff91 0x0058ffe4 0x0043ffe1))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0040000c))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00ff00ff 0x00ff00ff 0x00ff00ff 0x00ff00ff))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00090008 0x000b000a 0x000d000c 0x000f000e))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x003f00c0 0x00f00010 0x00aa0055 0x0082007e))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00800000 0x00120034 0x00fe00d4 0x000000ff))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00800001 0x00000080 0x00ff007f 0x007f00ff))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00ff00ff 0x00ff00ff))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00ff00ff 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00ff00fb 0x00ff00ff 0x00ff00ff 0x00ff00ff))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x003f00c0 0x00000000 0x00c00020))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x007f00c0 0x00c9009e 0x007f007f))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00bf0000 0x00000000 0x00400020))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x004f0000 0x00000001 0x00cf0000))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00bf0080 0x00cc00cd 0x003d00cc))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x007f00f8))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00c00004))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00e6002b 0x008b0070 0x00120068 0x00800000))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000010 0x00000000 0x00c100e0))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x003d0000 0x00600091 0x005800e4 0x004300e1))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0040000c))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfffefffe 0xfffefffe 0xfffefffe 0xfffefffe))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x030100ff 0x07050503 0x0b090907 0x0f0d0d0b))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffefffe 0x7ffefffe 0xffffffff 0x7fffffff))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x02038080 0x47870304 0xfb1a48c8 0x918cb761))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9dbedff0 0x193a5b7c 0x0b090903 0x0f0d0d0b))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xfe02ff00 0x8102fc00 0xe0207f80 0x04fc54aa))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff027f80 0xc080fdff 0x0244bfc0 0x837da929))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0x2fd03fc0 0x429eaa55))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00020000 0xfffefffe 0x24680000 0x01fefda8))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4001fffe 0xfffdbfff 0x12b40001 0x80fefe53))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f810000 0x7f7fffff 0x91f48000 0x807ec872))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x8000fffc 0xfffc8000 0x01000002 0xfffefefe))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xbffffffd 0xfffec000 0x00808002 0x7ffeff7e))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000ffff 0xbefec000 0xbf808001 0xc01fff7f))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xfffefffe 0x00000000 0x00000002 0xfffefffe))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x875acd14 0x4521974f 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xd01402f8 0x501502f9 0x4f000001 0xceff0000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0eb69a2a 0x8a422e9e 0x0000fffe 0x00020000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075acd14 0x4520974e 0x0000ffff 0x80010000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x56dbcd15 0x0ca1978f 0xbf80ffff 0x3dcdcccd))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffefffe 0xfffefffe 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abbdeef 0x92335677 0xfffffffb 0x7fffffff))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x3578bde0 0x2468acf0 0xfffefff6 0xfffefffe))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0x3fbffffb 0xc01fffff))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x92245678 0xfffffffb 0x7ff7ffff))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x7f800000 0x80400000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x7f800000 0xbf800000 0x3f9fc99e))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x3fc00000 0x80240000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xff000000 0xff000000 0xff800000 0xfefe933c))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0x3e800000 0x3ec00000 0xbf9fc99e))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0780759c 0x7db7e43c 0x0b30e62b 0xff7fdc06))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000002 0x7e000000 0x7e000000 0x80400000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502fa 0x0f1502f9 0x0e000000 0x0f200001))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00001 0x80dfffff 0xbf100000 0x02000000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xa02a05f2 0xa02a05f2 0x9e000000 0x9e000002))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9f9502f9 0x17950339 0x0e800000 0x0cccccce))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x4ff502f9 0x120402f8 0xaf913d00 0x12e158e5))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9f000000 0x8f000080 0x7f000000 0x7b98999a))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x07600040 0xbf800000 0x7dd8cccd))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff80000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffe00000 0x00000000 0xfff00000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbfe80000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0x7fefffff 0xffffffff 0x7ff7ffff))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x80080000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xbe2fe43c 0x8b70e62b 0x40041268))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x46fe0504 0x0b0a0908 0xcf120d0c))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x1000eb38 0xfc6ec878 0x16e0cc56 0x000024d0))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x87e0759c 0xc016e43b 0x8b80e62b 0x41e01268))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8701f51c 0xbeb8e23c 0x7b8025eb 0x027ebcbd))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffc00000 0x83befffe 0x00200000 0x83c00000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffc00000 0x83cefffe 0x60a13d00 0x05c158e4))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe10000 0xc1defffe 0x12448000 0xc2dffed4))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffc00000 0x83defffe 0xc1227a00 0x87c2b1c8))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x01cfffff 0x60913d00 0x83ed58e4))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x3fe0fffe 0xc1edbfff 0x6111bd01 0xc3e05863))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x7fc00000 0x00000000 0x80180000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x3fe00000 0x00000001 0x400bffff))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfffefffe 0xfffefffe 0xfffefffe 0xfffefffe))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x030100ff 0x07050503 0x0b090907 0x0f0d0d0b))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffefffe 0x7ffefffe 0xffffffff 0x8000ffff))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x02037fff 0x47870304 0xfb1a48c8 0x918cb761))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9dbedff0 0x193a5b7c 0x0b090903 0x0f0d0d0b))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xfe027fff 0x7ffffc00 0xe0207f80 0x80008000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff027f80 0x7ffffdff 0x0244bfc0 0x837da929))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0x2fd03fc0 0x8000aa55))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00020000 0x7ffffffe 0x24688000 0x01fefda8))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4001fffe 0x7fffbfff 0x12b48000 0x7ffffe53))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f810000 0x7f7fffff 0x7fff8000 0x7fffc872))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x7ffffffc 0x7fff8000 0x01008000 0x7ffffefe))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7ffffffd 0xfffec000 0x00808002 0x7ffeff7e))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000ffff 0x7fffc000 0xbf808001 0x7fffff7f))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7ffffffe 0x80000000 0x00000002 0xfffefffe))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x7fffcd14 0x8000974f 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7fff02f8 0x800002f9 0x4f000001 0xceff0000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0eb69a2a 0x8a428000 0x0000fffe 0x00020000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075acd14 0x4520974e 0x0000ffff 0x80010000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x56dbcd15 0x0ca1978f 0xbf80ffff 0x3dcdcccd))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffefffe 0x7ffffffe 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abbdeef 0x7fff5677 0xfffffffb 0x8000ffff))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x8000bde0 0x24687fff 0xfffefff6 0xfffefffe))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0x3fbffffb 0xc01fffff))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x7fff5678 0xfffffffb 0x7ff7ffff))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x7f800000 0x80400000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x80000000 0x7fff0000 0x3f9fc99e))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x3fc00000 0x80240000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7fff0000 0xff000000 0x7fff0000 0x7fff933c))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0x3e800000 0x3ec00000 0x7fffc99e))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0780759c 0x7db7e43c 0x0b30e62b 0xff7fdc06))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000002 0x7e000000 0x80000000 0x7fff0000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502fa 0x0f1502f9 0x0e000000 0x0f200001))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00001 0x7fffffff 0xbf100000 0x02000000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7fff05f2 0xa02a05f2 0x7fff0000 0x9e000002))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x7fff02f9 0x17950339 0x0e800000 0x0cccccce))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x4ff502f9 0x120402f8 0x7fff3d00 0x12e158e5))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x7fff0000 0x7fff0080 0x80000000 0x7b98999a))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x07600040 0xbf800000 0x7dd8cccd))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff80000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7fff0000 0x00000000 0x7fff0000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7fff0000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0x7fefffff 0xffffffff 0x7ff7ffff))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x80080000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7fffe43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x46fe0504 0x0b0a0908 0xcf120d0c))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80007fff 0x7fffc878 0x8000cc56 0x800024d0))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x87e0759c 0x7fffe43b 0x8b80e62b 0x80001268))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x87017fff 0x7fffe23c 0x800025eb 0x8000bcbd))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffc00000 0x7ffffffe 0x00200000 0x83c00000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffc00000 0x7ffffffe 0x60a13d00 0x05c158e4))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe10000 0x7ffffffe 0x12448000 0xc2dffed4))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffc00000 0x7ffffffe 0x7fff7a00 0x7fff7fff))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x01cfffff 0x60913d00 0x7fff58e4))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x3fe0fffe 0x7fffbfff 0x6111bd01 0x7fff5863))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x7fff0000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0x400bffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffff8080 0x4787ffff 0xfb1a48c8 0x918cb761))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9dbedff0 0x193a5b7c 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffff00 0x8102ffff 0xffff7f80 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff027f80 0xc080ffff 0xffffbfc0 0x837dffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0xffff3fc0 0xffffaa55))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00020000 0xfffeffff 0x2468ffff 0x01feffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4001fffe 0xfffdffff 0x12b4ffff 0x80feffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f810000 0xffffffff 0x91f48000 0x807effff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x8000ffff 0xfffcffff 0x0100ffff 0xfffeffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xbfffffff 0xfffec000 0x00808002 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000ffff 0xbefec000 0xbf808001 0xc01fff7f))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xfffeffff 0xffff0000 0x00000002 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x875affff 0xffff974f 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xd014ffff 0xffff02f9 0x4f000001 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0eb6ffff 0xffffffff 0x0000ffff 0x00020000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0x80010000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x56dbcd15 0xffff978f 0xbf80ffff 0x3dcdcccd))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xfffeffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x9233ffff 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x2468acf0 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x92245678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffff0000 0x7f800000 0xffff0000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xffff0000 0xbf800000 0xffffc99e))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x3fc00000 0xffff0000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xff000000 0xffff0000 0xff800000 0xfefeffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0xffff0000 0xffff0000 0xbf9fc99e))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffff759c 0xffffe43c 0xffffe62b 0xff7fdc06))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000002 0x7e000000 0xffff0000 0x80400000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502fa 0xffff02f9 0xffff0000 0xffff0001))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00001 0x80dfffff 0xbf100000 0xffff0000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xa02a05f2 0xffff05f2 0x9e000000 0xffff0002))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9f9502f9 0xffff0339 0xffff0000 0xffffccce))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff02f9 0xffffffff 0xaf913d00 0xffff58e5))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9f000000 0x8f000080 0xffff0000 0x7b98ffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0xffff0040 0xbf800000 0x7dd8cccd))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff80000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffe00000 0x00000000 0xfff00000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbfe80000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xbe2fe43c 0x8b70e62b 0xffff1268))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x46fe0504 0x0b0a0908 0xcf120d0c))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffeb38 0xfc6effff 0xffffffff 0xffff24d0))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff759c 0xc016ffff 0x8b80e62b 0xffff1268))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xfffff51c 0xbeb8ffff 0xffffffff 0xffffbcbd))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0x83beffff 0x00200000 0xffff0000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff0000 0x83ceffff 0x60a13d00 0xffff58e4))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe10000 0xc1deffff 0x12448000 0xc2dffed4))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff0000 0x83deffff 0xc1227a00 0x87c2b1c8))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0xffffffff 0x60913d00 0x83ed58e4))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffffffe 0xc1edffff 0x6111bd01 0xc3e0ffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x80180000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0xffff0000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf8a532eb 0x3adf68b1 0x00000001 0xffff0000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0xffffffff 0x7fffffff))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x04018180 0xc6850704 0x1afac948 0x8c9062b7))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x68462210 0xf4d2ae8c 0x0b0b090d 0x0f0f0d0d))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff007f80 0xc082fe01 0xdddcbfc0 0x817fab81))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0xb0503fc0 0xc25eaa55))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xc0010002 0x00013fff 0x11b4ffff 0x8100ff55))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80810000 0x807fffff 0x92748000 0x81803536))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xc001ffff 0xfffec000 0x00808000 0x8000ff80))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffd 0x40fec000 0x41808001 0x3fdfff7f))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432ea 0xbadf68b1 0x00000002 0xfffeffff))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd06 0xafebfd07 0xb1000001 0x30fffffe))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075ccd16 0x45229750 0x0000ffff 0x80010000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xb7dbcd15 0x7da1970f 0x4080ffff 0xc2353333))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x6543210f 0x6dcba987 0x00010005 0x80010001))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0xc03ffffb 0x3fdfffff))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x92445678 0xfffffffb 0x8007ffff))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80800000 0x80800000 0xc0000000 0x40a13662))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40080000 0x3fc00000 0x001c0000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f80ffff 0xc0800000 0xc0c00000 0x3f5fc99e))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xf7808a64 0x81491bc4 0xf45019d5 0xff7fb736))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xafebfd08 0x6eebfd07 0x70000000 0x7120ffff))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00200001 0xfd210001 0xbef00000 0x7e400000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x009502f9 0x889502b9 0x8f800000 0x91343334))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x503502f9 0x8e2602fa 0xee6fc300 0x8b1fa71d))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x87a00040 0xbf800000 0xfdc0cccd))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x80100000 0x00000000 0x00080000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xbff40000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00010001 0x7ff10001 0x00010001 0x7ff90001))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x78008a64 0xc1c11bc4 0x749019d5 0x4004ed98))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfeff00 0x38f2fafc 0xf4f6f6f8 0xb0f6f2f4))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8820759c 0x3c58e43d 0x8b60e62b 0xbe201268))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x88fff61c 0x3db6e63c 0x9b60a66b 0xfd826813))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xfff00000 0x9f7fc300 0x7dffa71c))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffdf0000 0xc1e00000 0xeddc8000 0xc0e1012c))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x820fffff 0x60913d00 0x03d558e4))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xbfe00002 0xc1f13fff 0x6011bcff 0xc3e25965))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80010001 0x3fe00000 0x0000ffff 0x400d0001))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf8a532eb 0x3adf68b1 0x00000001 0xffff0000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0xffffffff 0x7fffffff))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x04018180 0xc6850704 0x1afac948 0x7fff62b7))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x68462210 0xf4d2ae8c 0x0b0b090d 0x0f0f0d0d))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff007f80 0xc082fe01 0xdddc7fff 0x817fab81))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x7ffffe00 0xb0503fc0 0xc25eaa55))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xc0010002 0x00013fff 0x11b4ffff 0x8100ff55))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80810000 0x7fffffff 0x92748000 0x81803536))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xc001ffff 0x7fffc000 0x00808000 0x7fffff80))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffd 0x40fec000 0x41808001 0x3fdfff7f))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432ea 0xbadf68b1 0x00000002 0xfffeffff))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd06 0xafebfd07 0xb1000001 0x30fffffe))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075ccd16 0x80009750 0x0000ffff 0x7fff0000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xb7dbcd15 0x8000970f 0x4080ffff 0xc2353333))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x6543210f 0x6dcba987 0x00010005 0x80010001))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x7fff5678 0xc03ffffb 0x3fdfffff))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x92445678 0xfffffffb 0x8007ffff))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80800000 0x80800000 0xc0000000 0x80003662))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0x001c0000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f80ffff 0xc0800000 0x7fff0000 0x3f5fc99e))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7fff8a64 0x81491bc4 0x7fff19d5 0x7fffb736))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xafebfd08 0x6eebfd07 0x80000000 0x7120ffff))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00200001 0xfd210001 0xbef00000 0x7e400000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x009502f9 0x889502b9 0x7fff0000 0x91343334))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x503502f9 0x8e2602fa 0xee6fc300 0x8b1fa71d))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x7fffcccd))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x7fff0040 0xbf800000 0xfdc0cccd))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x80100000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x7fff0000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00010001 0x7ff10001 0x00010001 0x7ff90001))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x78008a64 0xc1c11bc4 0x749019d5 0x4004ed98))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfeff00 0x38f2fafc 0xf4f6f6f8 0xb0f6f2f4))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8820759c 0x3c58e43d 0x8b60e62b 0xbe201268))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x88fff61c 0x3db6e63c 0x9b60a66b 0xfd826813))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xfff00000 0x9f7fc300 0x8000a71c))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffdf0000 0xc1e00000 0xeddc7fff 0xc0e1012c))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x7fffffff 0x60913d00 0x03d558e4))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xbfe00002 0xc1f13fff 0x60117fff 0xc3e25965))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80010001 0x3fe00000 0x0000ffff 0x400d0001))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0xffffffff 0x7fffffff))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff007f80 0x00000000 0xdddc0000 0x817f0000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x0000fe00 0xb0503fc0 0x0000aa55))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00013fff 0x11b40000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x0000ffff 0x00008000 0x00003536))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x0000c000 0x00808000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffd 0x40fec000 0x00008001 0x3fdfff7f))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432ea 0x00000000 0x00000000 0xfffeffff))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd06 0x00000000 0x00000001 0x30fffffe))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x45220000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000cd15 0x7da1970f 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x6543210f 0x6dcba987 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x00005678 0xc03ffffb 0x3fdfffff))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x00005678 0xfffffffb 0x8007ffff))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40a10000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40080000 0x3fc00000 0x001c0000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0xc0800000 0x00000000 0x3f5fc99e))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x81490000 0x00000000 0x0000b736))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x70000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000001 0x00000000 0xbef00000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x009502f9 0x889502b9 0x00000000 0x91340000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x000002f9 0x8e260000 0x00000000 0x8b1f0000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x0000cccd))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x00000040 0xbf800000 0x0000cccd))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00080000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40040000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x38f20000 0x00000000 0xb0f60000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000759c 0x3c580000 0x8b60e62b 0x00001268))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x3db60000 0x0000a66b 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7dff0000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffdf0000 0x00000000 0x00000000 0xc0e10000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x0000ffff 0x60913d00 0x03d558e4))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xbfe00000 0x00003fff 0x60110000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x3fe00000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfeff00 0xf8fafafc 0xf4f6f6f8 0xf0f2f2f4))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00010001 0x80010001 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x0c040000 0x54242810 0xdc649040 0xa4c43890))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x01028000 0x0a06f800 0x10a0be00 0x84e44cfc))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x6978f000 0xd938b1e0 0xf4f6d2d8 0xf0f2f2f4))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xfe014000 0xc1010000 0x01001000 0x36040039))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff010000 0x3f7f0200 0xe3400000 0xfb826464))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0xfc000000 0xcfc00000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x00010001 0x5a900000 0xfe015f90))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000000 0x80024000 0x1a008000 0x7f01972c))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x00800000 0x73000000 0xff81bad8))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000004 0x00040000 0x40000001 0x00014101))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xc0000002 0x00000000 0x00008001 0x80010081))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000fffe 0x82000000 0x80000000 0xbfe00000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00010001 0x00000000 0x00000001 0x00010001))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a532eb 0x80000000 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2febfd07 0x80000000 0x00000000 0x3100ffff))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x1a59a3b9 0xce414a61 0x00000001 0x00010000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xf8a532eb 0xbadf68b1 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xc2800000 0xb780d3c0 0x00000000 0x3dcc0000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00010001 0x00010001 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x65442110 0xedcca988 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xba102100 0x5a90d840 0x00010019 0x00010001))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0xc0400000 0x3fe00000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xdcc00000 0x00000000 0x80080000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x10000000 0x04000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x10000000 0x2fe00000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x40000000 0x40000000 0x10000000 0x41017d84))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x80000000 0x40000000 0xafe00000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xe4800000 0x24000000 0x80000430))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x04000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000002f9 0x2b000000 0x00000000 0xe0000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xe1000000 0xf0000000 0x3c000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x21b9d631 0x21b9d631 0x00000000 0x00000001))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x85800000 0xdd80be40 0x80000000 0xf400cccd))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xfd600000 0x989bfd07 0xbf000000 0xef0058e4))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x40000000 0x40001000 0x40000000 0xda905c29))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x10000000 0x00000000 0xe5900000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x01000000 0x00000000 0x00400000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00800000 0x00000000 0xffe00000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x80100000 0x00000000 0x80080000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00400000 0x00000000 0x00100000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xce480000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x47d00000 0x00000000 0xbc380000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000f710 0x2fd1ee10 0xd1004b39 0x0000ca40))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xe8e91bc4 0xb7000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x88003200 0x59b78800 0xb7003540 0x00002c88))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x04000000 0x00410001 0x01000000 0x84000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x04000000 0x1e310001 0x09100000 0x05e00000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe00000 0x3e210001 0x23400000 0x1e200000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x04000000 0x3d210001 0x12210000 0x8bc18b10))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x02200000 0x00000000 0x6e8c0000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xfc224000 0x48803d00 0x3c1f351c))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x04000000 0x00000000 0x00900000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xbff40000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000cd15 0xc521974f 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x8000ffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff010100 0x0706fe00 0xf0100908 0x827eaa55))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x07060504 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff010000 0x4081fe00 0xf0108000 0x827eaa55))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff010000 0x8000fe00 0xf0100000 0x827eaa55))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0001fffe 0x7ffec000 0x00808000 0x00fffed4))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00010000 0xff80ffff 0x12348000 0x00ffc99e))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x4000fffe 0x8000c000 0x00008001 0xffffff7f))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000fffe 0x3f00c000 0xbf008001 0x4020ff7f))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x8000974f 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x5015ffff 0x80000000 0x00000000 0xcf00ffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffcd15 0xc521974f 0x0000ffff 0x80000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x075bcd15 0xc521974f 0xbf80ffff 0x0001cccd))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x1234ffff 0xfffffffb 0x8000ffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x80000000 0xfffffffb 0xc020ffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x12340000 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc020c99e))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xff800000 0xbf000000 0x4020c99e))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x88000000 0xff80e43c 0x8b70e62b 0x8000c99e))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000001 0xd0150000 0xbf000000 0xcf000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x3f00ffff 0xbf000000 0xc1e00000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0xd0150040 0xbf800000 0xcf00cccd))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0xd015ffff 0x4f000000 0xcf000001))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xbf800000 0x8000cccd))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x88000000 0x3ff8e43c 0x8b70e62b 0x80000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x07060000 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x88000000 0x41dfe43c 0x8b70e62b 0x80000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8800759c 0x4081e43c 0x8b70e62b 0x8000aa55))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00108000 0xc1e0fed4))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0xbfe0ffff 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffe0fffe 0x41efc000 0x00808001 0x43e1ff7f))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x0000ffff 0x80000000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x4081fe00 0x12343fc0 0x00ffaa55))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x40810000 0x3fc00000 0x827e0000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00010000 0x7ffec000 0x00808000 0x00fffed4))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00010000 0x7fff0000 0x12340000 0x00ffc99e))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x4000fffe 0x7ffe0000 0x00000001 0x7fffff7f))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0x00800000 0x40200000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x80000000 0x00000001 0x00010000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x80000000 0x00000000 0xcf000001))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd15 0x7fff974f 0x00000000 0x00010000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x075b0000 0x47800040 0x00000000 0x00010000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x12340000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x12340000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0x7f7f0000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x3f000000 0x7fc00000 0x40200000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f800000 0x7e370000 0x7fc00000 0x7f7f1268))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000001 0x3f000000 0x4f000000 0x40200000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x3f000000 0x00100000 0x40200000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x4f000000 0x3dcc0001))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x50150000 0x41ef02f9 0x4f000000 0x43e10001))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3dcc0000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x47800000 0x00000000 0x3dcc0000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x07060000 0x00000000 0x0f0e0000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x88000000 0x41dfe43c 0x00100000 0x80000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8800759c 0x4081e43c 0x8b703fc0 0x80001268))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0x43e10000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x41dfffff 0x00100000 0x00ff0000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x41ef0000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000000 0x41efc000 0x00803d00 0x43e158e4))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075b0000 0x00000000 0x00000000 0x00010000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x03027f80 0x40810504 0x0b0a3fc0 0x0f0e0d0c))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x03020100 0x12345678 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00017f80 0x7fffffff 0x12343fc0 0x00fffed4))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00007f80 0x40810000 0x3fc03fc0 0xc0200000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000000 0x7fffffff 0x12348001 0x7fffff7f))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x7fff0000 0x7fc00000 0x7f7ffed4))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x7ffe0000 0x00800001 0x7fffffff))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x40000001 0x7ffe0000 0x00800000 0x7fff0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x7fffffff 0xc5210000 0x00000001 0x00010000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7fff02f9 0xd01502f9 0x4f000001 0xffff0001))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bffff 0x7fffffff 0x00000000 0x00010000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x3dcc0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x7fff5678 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x7fff0000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x12345678 0x3fc00000 0xffff0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff05678 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7f0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0x3f000000 0x7fc00000 0x7f7f0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f80759c 0x7e370000 0x7fc00000 0x7f7f1268))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x3f0002f9 0x4f000000 0x40200001))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000001 0x41df0000 0x00100000 0x40200000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x501502f9 0x478002f9 0x4f000000 0x3dcc0001))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x501502f9 0x41ef02f9 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x3dcc0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000759c 0x7e370000 0x00000000 0xc0041268))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x3ff80504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe0759c 0x7e37ffff 0x00100000 0xc1e01268))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x7e37fe00 0xf0103fc0 0x827e1268))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12340000 0x00ff0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x41ef0000 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000000 0x7ffeffff 0x60913d00 0x7fff58e4))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fff0000 0xbfe00000 0x00000001 0x400c0000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff017f80 0x7fffffff 0xf0108000 0x827efed4))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x8000fe00 0xf0103fc0 0xc020aa55))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7fffffff 0x12348001 0x7fffff7f))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff80ffff 0x7fc08000 0x7f7ffed4))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x8000c000 0x00808001 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffe 0x7ffec000 0xbf008001 0x7fffff7f))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x7fffffff 0xc521974f 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7fffffff 0xd01502f9 0x4f000001 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xc521ffff 0x0000ffff 0x80000000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f80cd15 0xc521974f 0xbf80ffff 0x3dcccccd))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x7fffffff 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x80005678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x7ff05678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0xc020c99e))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0xff800000 0xbf000000 0x7f7fc99e))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xff80e43c 0x8b70e62b 0x8000c99e))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0xbf000000 0xcf000001))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00001 0x41dfffff 0xbf000000 0xc1e00000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x501502f9 0xd01502f9 0xbf800000 0xcf00cccd))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe002f9 0xd015ffff 0x60913d00 0xcf0058e4))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x8000cccd))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0xbfe00040 0xbf800000 0x400ccccd))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0xc0041268))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x3ff80504 0x0b0a0908 0xc0040d0c))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe0759c 0x7e37ffff 0x8b70e62b 0xc1e01268))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x7e37fe00 0xf010e62b 0x827eaa55))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0xc1e058e4))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe00000 0x7fffffff 0x12348000 0xc1e0fed4))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0xbfe0ffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffe0fffe 0x7ffeffff 0x60918001 0x7fffff7f))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0xbfe00000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x03ae668b 0x62914ba8 0x00008000 0x00010000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x81818080 0x83838282 0x85858484 0x87878686))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xbfffffff 0x80008000 0xc0008000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x81024040 0x23c48182 0x7d8d2464 0x48c65bb1))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x4edf6ff8 0x0c9d2dbe 0x85858482 0x87878686))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7f813fc0 0x6040ff00 0x81225fe0 0x41bfd495))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x7f813fc0 0x60417f00 0x97e81fe0 0xa14f552b))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x20017fff 0x7fffe000 0x095a8001 0x407fff2a))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x3fc10000 0xbfc08000 0x48fa4000 0x403fe439))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x6000ffff 0x7fff6000 0x00404001 0xbfffffbf))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x20008000 0x5f7f6000 0x5fc04001 0x60107fc0))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x43ade68a 0xa2914ba8 0x00008000 0x80008000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x680a817c 0xa80b017d 0x27800001 0xe7808000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x83ade68a 0xa290cba7 0x00008000 0x40010000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x2b6e668b 0x86514bc8 0x5fc08000 0x1ee76667))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xcd5eef78 0x491aab3c 0x80007ffe 0xc0008000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x80008000 0x40008000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x4d5e6f78 0x491a2b3c 0x9fe07ffe 0xe0108000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x4d5e6f78 0x49122b3c 0x80007ffe 0xbffc8000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x3fc00000 0xbfc00000 0x5fc00000 0x9fd064cf))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x5ffc0000 0x1fe00000 0xc0120000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x3fc00001 0x9f400000 0x9f600000 0x5fd064cf))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x83c03ace 0xbedc721e 0x85987316 0x7fc06e03))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x280b017d 0x878b017d 0x87000000 0x87900001))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x7ff00001 0x40708000 0x5f880000 0x81000000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4fcb017d 0x8bcb019d 0x87400000 0x86666667))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xa7fb017d 0x8902817c 0x57c91e80 0x89712c73))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x27c00000 0x23c00020 0x5fc00000 0x5ee66667))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x27c00000 0x83b00020 0x5fc00000 0x3eec6667))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x7ffc0000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x5ff40000 0x00000000 0x9ffe0000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x80008000 0xbff88000 0x80008000 0xbffc8000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x44003ace 0x5f18721e 0x45b87316 0xa0020934))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x01810080 0x237f0282 0x05850484 0x67890686))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xc3f03ace 0x600bf21e 0x45c07316 0xa0f00934))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xc3817a8e 0x5f5cf11e 0xbdc092f6 0x813f5e5f))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41e7ffff 0x30511e80 0x82e12c72))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7ff10000 0x60efffff 0x09224000 0x61707f6a))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x7ff00000 0x80e88000 0x30491e80 0x41f72c72))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x9ff07fff 0x60f7e000 0x30895e81 0x61f0ac32))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x5ff00000 0x00000000 0x20060000))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x40008000 0x9ff00000 0x00000001 0xa0068000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00010000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00120002 0x00630032 0x00f400a3 0x01c50154))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xfffa00ff 0x038affec 0xfea0047f 0xf13df745))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xfd9fffbe 0x01000363 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00027f01 0x20820008 0x01fc1fc0 0x7b103956))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x40800000 0xfdbcc040 0xff0600c9))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xbf7f0000 0xf8100000 0x3ea20000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x7ffe0000 0x02977fff 0x00020003))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00010000 0x7ffd0001 0x00127fff 0x00ff0001))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00010000 0xff800000 0x122b0000 0x00fe007f))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x20000000 0x7ffc2000 0x00017ffe 0x7ffe0001))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x40000000 0x80020000 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x3eff0000 0xffbf0000 0x401f0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7ffe0000 0x7fff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075b0000 0x3adf0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x50140000 0x2feb0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x006c1441 0x1b1455a1 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xc5210001 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x04920000 0xdf1dffcc 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x7ffe0000 0x00000000 0x7fff0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00010000 0x1234ffff 0x00000000 0x00010000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7fff0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x501d088a 0x02973a6a 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xedcc0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x12320000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x7fff0000 0x1fc00000 0x1fe00000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00800000 0x3fa00000 0xc0600000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xc0080000 0x00000000 0x1fee0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f010000 0x00010000 0x7f800000 0x7eff171b))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xffc10000 0xbf210000 0x3fdf0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x88780000 0xff820000 0x8baa0000 0x8081f82e))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x1f020000 0x21020000 0x20200000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xe86a0000 0xd7e20000 0xe7740000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x206c0000 0xfff80000 0xe0e00000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x321a0012 0x11f00012 0x30c20000 0x12c20000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x31bd0000 0xe53c0001 0xd8310000 0xe8580000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffec0000 0xe7510000 0x3b990000 0xe6040001))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x31610000 0x27f10000 0x20810000 0x1dd6147b))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xc2340000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xdc2e0000 0x00000000 0x1eec0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7fff0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80080000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7fe00000 0x00000000 0x7ff00000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0xc0080000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x1ff80000 0x00000000 0x1ffc0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x3f140000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x03830000 0x00000000 0xf8790000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x70806c10 0x7c740606 0x6a260537 0x7fff02a6))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x001e0000 0x40f40000 0xfff10000 0x3e200000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00ef7526 0x3f9b006f 0x0e83f322 0x7d82f3ae))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x21e60000 0x00000000 0x1e270000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x21ee0000 0x000c0000 0xdf0e0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x41de0000 0x00020000 0xff840000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x21f60000 0x48da1d12 0x23ff3dbb))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xdef80000 0x00000000 0x21f70000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfff00000 0x41ee0001 0x0061c300 0x43e0ffa6))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x20200000 0x00000000 0x200c0000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x40200000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x0000ffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x0000ffff 0x00000000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0x00000000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x0000ffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffff0000 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffff0000 0xffffffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0xffffffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffff0000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffff0000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0000ffff 0xffff0000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0xffffffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0x00000000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffff0000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffff0000 0x00000000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffff0000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ffff 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0xffffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000ffff 0xffff0000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x0000ffff 0x00000000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x0000ffff 0xffff0000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffff0000 0x00000000 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffff0000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffff0000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffff0000 0xffffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000ffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x0000ffff 0xffffffff 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffff0000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffff0000 0xffff0000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ffff 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffff0000 0xffffffff 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ffff 0xffff0000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffff0000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0000ffff 0xffff0000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0xffffffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0x00000000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffff0000 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xffff0000 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x0000ffff 0x00000000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0x00000000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffff0000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ffff 0x0000ffff 0xffffffff 0x00000000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0xffffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x0000ffff 0x00000000 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0xffff0000 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000ffff 0xffff0000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x0000ffff 0x00000000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x0000ffff 0xffff0000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x00000000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffff0000 0x00000000 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffff0000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffff0000 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0xffff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000ffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x0000ffff 0xffffffff 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffff0000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffff0000 0xffffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ffff 0x00000000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffff0000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ffff 0xffff0000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffff0000 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x80007fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x7fffffff 0x80000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x7fff8000 0x80008000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x7fff8000 0xfffffffb))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7fff8000 0x80008000 0x7fff8000 0x80008000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7fff8000 0x80008000 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x7fff8000 0x80008000 0x80000000 0x80007fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x80007fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x80007fff 0xffff0001))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x7fff0001 0x7fff8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80007fff 0xffff0001 0x80007fff 0xffff0001))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x80007fff 0xffff0001 0x80007fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x80007fff 0xffff0001 0x80007fff 0x80007fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x80007fff 0x7fff7fff 0x80007fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x80007fff 0x7fff7fff 0x7fffffff 0x80000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x80007fff 0x7fff7fff 0x7fff7fff 0x7fff8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x7fffffff 0x80000000 0x7fffffff 0x80000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x7fff8000 0xfffffffb))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x7fff8000 0xfffffffb 0x7fff8000 0xfffffffb))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x7fff8000 0xfffffffb 0x80000000 0x80007fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x7fff8000 0xfffffffb 0x7fff0000 0x7fff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x80000000 0x80007fff 0x80000000 0x80007fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80000000 0x80007fff 0x80007fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x80000000 0x80007fff 0x7fff0000 0x80000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80007fff 0x7fff7fff 0x80007fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x80007fff 0x7fff7fff 0x7fff0001 0x7fff8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80007fff 0x7fff7fff 0x7fff8000 0x80008000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7fff0001 0x7fff8000 0x7fff0001 0x7fff8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7fff0001 0x7fff8000 0x80007fff 0x80007fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x7fff0001 0x7fff8000 0x7fff8000 0x80007fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x80007fff 0x80007fff 0x80007fff 0x80007fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x80007fff 0x80007fff 0x7fff7fff 0x7fff8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x80007fff 0x80007fff 0x7fff8000 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x7fff7fff 0x7fff8000 0x7fff7fff 0x7fff8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x7fff7fff 0x7fff8000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x7fff7fff 0x7fff8000 0x80000000 0x7fff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x80000000 0x7fff0000 0x7fff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x7fff0000 0x7fff0000 0x7fff0000 0x7fff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x7fff0000 0x7fff0000 0x7fff0000 0x80000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x7fff0000 0x7fff0000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x7fff0000 0x80000000 0x7fff0000 0x80000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7fff0000 0x80000000 0x7fff8000 0x80008000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x7fff0000 0x80000000 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7fff8000 0x80008000 0x7fff8000 0x80008000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x7fff8000 0x80008000 0x7fff8000 0x80007fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7fff8000 0x80008000 0x7fff8000 0x80008000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x7fff8000 0x80007fff 0x7fff8000 0x80007fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x7fff8000 0x80007fff 0x7fff8000 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7fff8000 0x80007fff 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x7fff8000 0x7fff7fff 0x7fff8000 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x7fff8000 0x7fff7fff 0x80000000 0x7fff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x7fff8000 0x7fff7fff 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x80000000 0x7fff0000 0x80000000 0x7fff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x80000000 0x7fff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80000000 0x7fff0000 0x80007fff 0xffff0001))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffff0000 0x00000000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0x00000001))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff0001 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x0000ffff 0x00000001 0x0000ffff 0x00000001))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0x00000001 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0000ffff 0x00000001 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffff0000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffff0000 0x00000000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x0000ffff 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x0000ffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x0000ffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffff0001 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffff0001 0xffff0000 0xffff0001 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffff0001 0xffff0000 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0001 0xffff0000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000ffff 0x0000ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0000ffff 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffff0000 0xffffffff 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffff0000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffff0000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0x0000ffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff0000 0xffffffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0xffffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffff0000 0x0000ffff 0x00000001))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffff0000 0xfffdfffe 0xfffbfffc 0xfff9fffa))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0xff810001))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00010000 0x00090004 0x00190010 0x00310024))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x007f0000 0xfffd0002 0xfff60000 0x01c0fd06))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffde0000 0xfeceff78 0x01ae01e0 0x007e0138))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3f014000 0x00010001 0x00040000 0x10003f01))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0x00020000 0x1fc0007f))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xe0000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0x00010001 0x3f010001))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00400000 0x3f010002))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x0000ff80 0x00000000 0xff810080))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00010004 0x10000000 0x10000000 0x3f010004))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00010002 0x1fc00000 0x00000000 0xc0800000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000fffe 0x00000000 0x00000000 0x1f410000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00010001 0x3f010001 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0033ffeb 0x0379ffa5 0x00000000 0x1d800000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xfffe0007 0x27b0ffeb 0x00000000 0x18000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0a2901b9 0x00312059 0x2b111861 0x0d990441))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x0033ffeb 0xfff9ffa5 0x0069ffb1 0xe2bbffdf))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x0229d280 0x000013c0 0xefa3ef80))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x3f010001))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00220010 0x00660044 0xffaaff88 0x08eeffcc))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x04840100 0x28a41210 0x1ce43840 0x01440a90))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xf7000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x08eefcc0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xe0800000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3f014000 0x00000000 0x00014000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffc10000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xc4780000 0x00000000 0xff82e480))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x0f810000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0000fff9 0x00000000 0x00000000 0xf4300000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0fff0000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00040031 0x190001b9 0x00040031 0x090001b9))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x18b0f580 0x0000fe40 0xf2b0f580))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffb0fd60 0xfffe0007 0xf3d0fe9b))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x18614000 0x00001000 0x13b14000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xedf91000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3f010100))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1f410080))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff810010))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0f810040))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1f02fe48))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x01b9ffd0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x35792710 0x38400000 0x03100e10 0x3e040bd1))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00780000 0x001cffc4 0x1ffef8e9))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3a0b3200 0x00780000 0x00380000 0x1f80e4b7))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00010400 0x00010001 0x10810441))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00010400 0x00010001 0x10810231))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x0000ffe0 0x00010001 0x203f0021))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00010400 0x00010001 0x10810121))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xef7f0220))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xffc00000 0x00400000 0x203f0022))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10810400))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x20800000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfe01fe01 0xfe01fe01 0xfe01fe01 0xfe01fe01))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00ff0000 0x02fd01fe 0x04fb03fc 0x06f905fa))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfe01fe01 0xfe01fe01 0xfe01fe01 0x7e81fe01))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00010000 0x00090004 0x00190010 0x00310024))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x007f0000 0x02fd0002 0x04f60000 0x01c00306))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00de0000 0x01ce0178 0x01ae01e0 0x007e0138))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3f014000 0xfe010001 0xfc040000 0x10004101))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0xfd020000 0x1fc0807f))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x20000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0xfe01fe01 0x3f01fe01))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0xbf400000 0x3f01fd02))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000080 0x00000000 0x7e817f80))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfe01fc04 0x10000000 0x90000000 0x3f01fc04))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xfe01fd02 0x1fc00000 0x00000000 0x3f800000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x000000fe 0x00000000 0x00000000 0x1f410000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xfe01fe01 0x3f01fe01 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xcc3314eb 0x03795aa5 0x00000000 0x62800000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x01fef807 0x27b014eb 0x00000000 0x68000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xa42901b9 0x00312059 0x59111861 0x97990441))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xcc3314eb 0x06f95aa5 0x96694eb1 0x61bb20df))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x02292d80 0x000013c0 0x36a31080))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfe01fe01 0xfe01fe01 0xfe01fe01 0x3f01fe01))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xdd22ef10 0x9966bb44 0x55aa7788 0x08ee33cc))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xc084e100 0x5ca48a10 0x1ce43840 0x01440a90))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x09000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x08ee30c0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7f800000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1f800000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3f014000 0x00000000 0xfe014000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ec10000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x43780000 0x00000000 0x7d821b80))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x0f810000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000000f9 0x00000000 0x00000000 0x33300000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0fff0000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0004f231 0x190001b9 0x0004f231 0xa90001b9))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x18b00a80 0x00003e40 0x39b00a80))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x4fb01260 0x01fef807 0x34d0139b))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x18614000 0x00001000 0x13b14000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x34f97000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3f01e100))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1f41e880))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7e81ef10))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0f81f040))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1f023548))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x01b905d0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x35795f10 0x48400000 0xcb100e10 0x3e040bd1))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x87780000 0xe31c3bc4 0x1ffe2fe9))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3a0b4e00 0x87780000 0xe2380000 0x1f801bb7))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xfe01c400 0xfe01fe01 0x1081c241))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xfe01c400 0xfe01fe01 0x1081d031))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x000000e0 0xfe01fe01 0x203fde21))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xfe01c400 0xfe01fe01 0x1081df21))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x307fd120))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x3fc00000 0xbf400000 0x203fed22))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x8e81c400))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x5f800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfff7fff8 0xfff5fff6 0xfff3fff4 0xfff1fff2))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00510040 0x00790064 0x00a90090 0x00e100c4))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0237fe00 0xff5000a0 0xfba203fc 0xf89e06e4))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xfff7ffd8 0xfff5fff6 0xfff3fff4 0xfff1fff2))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0f811000 0x01000100 0x1ce41c39 0x3e043e04))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xe0800000 0xfee00340 0x00acf164 0x0000ff82))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xfc10fc00 0x00000000 0x1f800fc0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x40000000 0x01440a90 0x00040790 0x00000001))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000000 0x0000e600 0x0002ea2c 0x00000001))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x08eef300 0x006e10d8 0x0000ff81))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000001 0x00004000 0x00013f01 0x3f010001))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x0001ff81 0xff810001))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1fc0ffe0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0x00000000 0x00000000 0x0000ffff))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x0000ffff 0x00310000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00010001 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0000ffcc))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00010019 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffc10040 0x00000000 0x0040ffe0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff810008))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x0f811000 0x00000000 0x10000400))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x1f411000 0x00000000 0xe0400fe0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10000080))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3f011000 0x0bd12584 0x3f013f01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xdfc10000 0x00000000 0x1fc00fe0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xc5f5e400 0xfc22d830 0xc0800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x10810000 0x00000000 0x10000400))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xebf10000 0x00000000 0xf3c00000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xf040fc00))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x18610000 0x00000001 0x09610000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xebf10000 0x0000ffcd 0xf4530000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x1da00000 0x0000ffe4 0xf32d0000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x10814000 0x0a900a29 0x0e890a90))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xe1800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0f40fd90))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xc0800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3f010040))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xe040ffe0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff810008))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10000010))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x20000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xfc400038))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x02a40739 0x35793100 0x01442a40 0x40000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000700 0x00000000 0x1f800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xf99af540 0x07500700 0xf9f42288 0x3f000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000100 0x00000000 0x0f810400))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x0000f910 0x00000000 0xef8303e0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000340 0x00000000 0x00000020))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0e890000 0x24003021 0x1e400310 0x118903c1))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10c0fe8c))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xe1800000 0x00003780 0xffa8f21c 0x213d001f))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10000090))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffc0fff4))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfe01fe01 0xfe01fe01 0xfe01fe01 0xfe01fe01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x08f707f8 0x0af509f6 0x0cf30bf4 0x0ef10df2))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7f800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00510040 0x00790064 0x00a90090 0x00e100c4))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x02370600 0x0a5000a0 0x08a203fc 0x079e06e4))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x08f707d8 0x0af509f6 0x0cf30bf4 0x0ef10df2))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0f819000 0xe1000100 0x70e41c39 0x42043e04))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x1f800000 0x10e00340 0xa8ac4664 0x00007d82))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x3b100c00 0x00000000 0x61800fc0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x40000000 0x01440a90 0xfc04af90 0x0000fe01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000000 0x00001a00 0xfd02692c 0x0000fe01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x08ee2700 0xc76e82d8 0x00007e81))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000001 0x00004000 0xfe013f01 0x3f01fe01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0xfe017e81 0x7e81fe01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1fc01fe0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0xfe01fe01 0xfe01fe01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x000000ff 0x00000000 0x00000000 0x000000ff))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x000000ff 0xce310000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xfe01fe01 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x000000cc))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7f800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xfe01f619 0xfe01fe01 0xfe01fe01 0xfe01fe01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x3ec1bf40 0x00000000 0xbf401fe0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7e81f708))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x0f819000 0x00000000 0x90000400))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x1f419000 0x00000000 0x5f400fe0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x90000080))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3f019000 0x9dd16184 0x3f013f01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x5ec10000 0x00000000 0x1fc00fe0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x44f55400 0x0e224030 0x3f800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x8e810000 0x00000000 0x10000400))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x3af10000 0x00000000 0x33c00000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x30401c00))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x18610000 0x00000001 0xa7610000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x3af10000 0x000000cd 0x31530000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x1da00000 0x000000e4 0x362d0000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x8e814000 0xa290a429 0x0e89a290))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1e800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0f400990))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3f800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3f01f040))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x5f4003e0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7e81f708))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x90000010))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x60000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0b400038))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xcea40739 0x4b793100 0x01442a40 0x40000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000700 0x00000000 0x60800000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x389a2040 0x82500700 0x0bf42288 0x41000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000100 0x00000000 0x9181c400))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000910 0x00000000 0x3283c4e0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000340 0x00000000 0x0000df20))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0e890000 0x24005221 0x1e40cb10 0x1189c5c1))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10c00a8c))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x1e800000 0x00004880 0x57a8711c 0x213de01f))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10000090))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3fc00bf4))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0xfffefffe 0xfffefffe 0xfffefffe 0xfffefffe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0xff80ff80 0xff80ff80 0xff80ff80 0xff80ff80))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0xff00ff00 0xff00ff00 0xff00ff00 0xff00ff00))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0xfffefffe 0xfffefffe 0xfffefffe 0xfffefffe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0xfffefffe 0xfffefffe 0xfffefffe 0xfffefffe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0xfff0fff0 0xfff0fff0 0xfff0fff0 0xfff0fff0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x81008000 0x83008200 0x85008400 0x87008600))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x02000000 0x06000400 0x0a000800 0x0e000c00))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x30201000 0x70605040 0xb0a09080 0xf0e0d0c0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0xfe02ff00 0x8102fc00 0xe0207f80 0x04fc54aa))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0x8080c000 0x40800000 0x0800e000 0x3f002a80))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0x01008000 0x81000000 0x1000c000 0x7e005500))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0x80000000 0x80000000 0x00000000 0x00008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0x80000000 0x80000000 0x00000000 0x00008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0xfe02ff00 0x8102fc00 0xe0207f80 0x04fc54aa))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0x80000000 0x80000000 0x00000000 0x00008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0xfe02ff00 0x8102fc00 0xe0207f80 0x04fc54aa))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0x80000000 0x80000000 0x00000000 0x00008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0xf010f800 0x0810e000 0x0100fc00 0x27e0a550))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00020000 0xfffefffe 0x24680000 0x01fefda8))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0x00800000 0xff80ff80 0x1a000000 0x7f806a00))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0x01000000 0xff00ff00 0x34000000 0xff00d400))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0x80000000 0x80008000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0x80000000 0x80008000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x00020000 0xfffefffe 0x24680000 0x01fefda8))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x80000000 0x80008000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x00020000 0xfffefffe 0x24680000 0x01fefda8))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x80000000 0x80008000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x00100000 0xfff0fff0 0x23400000 0x0ff0ed40))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x8000fffc 0xfffc8000 0x01000002 0xfffefefe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x0000ff00 0xff000000 0x40000080 0xff80bf80))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x0000fe00 0xfe000000 0x80000100 0xff007f00))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x8000fffc 0xfffc8000 0x01000002 0xfffefefe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x8000fffc 0xfffc8000 0x01000002 0xfffefefe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x0000ffe0 0xffe00000 0x08000010 0xfff0f7f0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0xfffefffe 0x00000000 0x00000002 0xfffefffe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0xff80ff80 0x00000000 0x00000080 0xff80ff80))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0xff00ff00 0x00000000 0x00000100 0xff00ff00))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0x80008000 0x00000000 0x00008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x80008000 0x00000000 0x00008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0xfffefffe 0x00000000 0x00000002 0xfffefffe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x80008000 0x00000000 0x00008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0xfffefffe 0x00000000 0x00000002 0xfffefffe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x80008000 0x00000000 0x00008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0xfff0fff0 0x00000000 0x00000010 0xfff0fff0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x0eb69a2a 0x8a422e9e 0x0000fffe 0x00020000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0xad808a80 0x9080a780 0x0000ff80 0x00800000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x5b001500 0x21004f00 0x0000ff00 0x01000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0x80008000 0x80008000 0x00008000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x80008000 0x80008000 0x00008000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x0eb69a2a 0x8a422e9e 0x0000fffe 0x00020000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x80008000 0x80008000 0x00008000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x0eb69a2a 0x8a422e9e 0x0000fffe 0x00020000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x80008000 0x80008000 0x00008000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x75b0d150 0x521074f0 0x0000fff0 0x00100000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xfffefffe 0xfffefffe 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0xff80ff80 0xff80ff80 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0xff00ff00 0xff00ff00 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0x80008000 0x80008000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0x80008000 0x80008000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0xfffefffe 0xfffefffe 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0x80008000 0x80008000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0xfffefffe 0xfffefffe 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0x80008000 0x80008000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0xfff0fff0 0xfff0fff0 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3578bde0 0x2468acf0 0xfffefff6 0xfffefffe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0x5e007800 0x1a003c00 0xff80fd80 0xff80ff80))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0xbc00f000 0x34007800 0xff00fb00 0xff00ff00))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x80008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x80008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0x3578bde0 0x2468acf0 0xfffefff6 0xfffefffe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x80008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3578bde0 0x2468acf0 0xfffefff6 0xfffefffe))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x80008000 0x80008000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0xabc0ef00 0x23406780 0xfff0ffb0 0xfff0fff0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x00060002 0x000e000a 0x00160012 0x001e001a))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x00030001 0x00070005 0x000b0009 0x000f000d))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x00300010 0x00700050 0x00b00090 0x00f000d0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0xff803fc0 0x2040ff00 0xf8081fe0 0xc13fd52a))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0xfffe00ff 0x0081fffc 0xffe0007f 0xff04ff54))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0xffff007f 0x0040fffe 0xfff0003f 0xff82ffaa))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0xff803fc0 0x2040ff00 0xf8081fe0 0xc13fd52a))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0xff803fc0 0x2040ff00 0xf8081fe0 0xc13fd52a))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0xfff007f8 0x0408ffe0 0xff0103fc 0xf827faa5))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00000000 0x3fffffff 0x091ac000 0x007fff6a))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0x00000000 0x00ffffff 0x0024ff00 0x0001fffd))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0x00000000 0x007fffff 0x0012ff80 0x0000fffe))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0x00000000 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0x00000000 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x00000000 0x3fffffff 0x091ac000 0x007fff6a))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00000000 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x00000000 0x3fffffff 0x091ac000 0x007fff6a))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00000000 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x00000000 0x07ffffff 0x0123f800 0x000fffed))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x2000ffff 0x3fffe000 0x0040c000 0x3fffffbf))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x0080ffff 0x00ffff80 0x0001ff00 0x00fffffe))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x0040ffff 0x007fffc0 0x0000ff80 0x007fffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x2000ffff 0x3fffe000 0x0040c000 0x3fffffbf))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x2000ffff 0x3fffe000 0x0040c000 0x3fffffbf))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x0400ffff 0x07fffc00 0x0008f800 0x07fffff7))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3fffffff 0xc0000000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0x00ffffff 0xff000000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0x007fffff 0xff800000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0x3fffffff 0xc0000000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3fffffff 0xc0000000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0x07ffffff 0xf8000000 0x00000000 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x03ade68a 0xe290cba7 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0x000eff9a 0xff8aff2e 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x0007ffcd 0xffc5ff97 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x03ade68a 0xe290cba7 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x03ade68a 0xe290cba7 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x0000ffff 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x0075fcd1 0xfc52f974 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0xffffffff 0x007fffff 0x00000000 0xff800000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0xffffffff 0x07ffffff 0x00000000 0xf8000000))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0xcd5eef78 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0xff35ffbd 0x002400ac 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0xff9affde 0x00120056 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0xcd5eef78 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0xcd5eef78 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0xf9abfdef 0x01230567 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0x01ff01ff 0x01ff01ff 0x01ff01ff 0x01ff01ff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0x00ff00ff 0x00ff00ff 0x00ff00ff 0x00ff00ff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0x7fff7fff 0x7fff7fff 0x7fff7fff 0x7fff7fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0x0fff0fff 0x0fff0fff 0x0fff0fff 0x0fff0fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x00060002 0x000e000a 0x00160012 0x001e001a))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x00030001 0x00070005 0x000b0009 0x000f000d))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x00300010 0x00700050 0x00b00090 0x00f000d0))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0x7f803fc0 0x20407f00 0x78081fe0 0x413f552a))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0x01fe00ff 0x008101fc 0x01e0007f 0x01040154))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0x00ff007f 0x004000fe 0x00f0003f 0x008200aa))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0x00010000 0x00000001 0x00010000 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0x00010000 0x00000001 0x00010000 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0x7f803fc0 0x20407f00 0x78081fe0 0x413f552a))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0x00010000 0x00000001 0x00010000 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0x7f803fc0 0x20407f00 0x78081fe0 0x413f552a))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0x00010000 0x00000001 0x00010000 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0x0ff007f8 0x04080fe0 0x0f0103fc 0x08270aa5))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00000000 0x3fff7fff 0x091a4000 0x007f7f6a))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0x00000000 0x00ff01ff 0x00240100 0x000101fd))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0x00000000 0x007f00ff 0x00120080 0x000000fe))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x00000000 0x3fff7fff 0x091a4000 0x007f7f6a))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x00000000 0x3fff7fff 0x091a4000 0x007f7f6a))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x00000000 0x07ff0fff 0x01230800 0x000f0fed))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x20007fff 0x3fff6000 0x00404000 0x3fff7fbf))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x008001ff 0x00ff0180 0x00010100 0x00ff01fe))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x004000ff 0x007f00c0 0x00000080 0x007f00ff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x20007fff 0x3fff6000 0x00404000 0x3fff7fbf))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x20007fff 0x3fff6000 0x00404000 0x3fff7fbf))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x04000fff 0x07ff0c00 0x00080800 0x07ff0ff7))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3fff7fff 0x40000000 0x00000000 0x7fff7fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0x00ff01ff 0x01000000 0x00000000 0x01ff01ff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0x007f00ff 0x00800000 0x00000000 0x00ff00ff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0x00000001 0x00010000 0x00000000 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000001 0x00010000 0x00000000 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0x3fff7fff 0x40000000 0x00000000 0x7fff7fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000001 0x00010000 0x00000000 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3fff7fff 0x40000000 0x00000000 0x7fff7fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000001 0x00010000 0x00000000 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0x07ff0fff 0x08000000 0x00000000 0x0fff0fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x03ad668a 0x62904ba7 0x00007fff 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0x000e019a 0x018a012e 0x000001ff 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x000700cd 0x00c50097 0x000000ff 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0x00000001 0x00010001 0x00000001 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x00000001 0x00010001 0x00000001 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x03ad668a 0x62904ba7 0x00007fff 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x00000001 0x00010001 0x00000001 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x03ad668a 0x62904ba7 0x00007fff 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x00000001 0x00010001 0x00000001 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x00750cd1 0x0c520974 0x00000fff 0x00000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0x7fff7fff 0x3fff7fff 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0x01ff01ff 0x00ff01ff 0x00000000 0x01000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0x00ff00ff 0x007f00ff 0x00000000 0x00800000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0x00010001 0x00000001 0x00000000 0x00010000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0x00010001 0x00000001 0x00000000 0x00010000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0x7fff7fff 0x3fff7fff 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0x00010001 0x00000001 0x00000000 0x00010000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0x7fff7fff 0x3fff7fff 0x00000000 0x40000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0x00010001 0x00000001 0x00000000 0x00010000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0x0fff0fff 0x07ff0fff 0x00000000 0x08000000))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0x7fff7ffd 0x7fff7fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0x013501bd 0x002400ac 0x01ff01ff 0x01ff01ff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0x009a00de 0x00120056 0x00ff00ff 0x00ff00ff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0x00010001 0x00000000 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00010001 0x00000000 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0x7fff7ffd 0x7fff7fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00010001 0x00000000 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0x7fff7ffd 0x7fff7fff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00010001 0x00000000 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0x09ab0def 0x01230567 0x0fff0fff 0x0fff0fff))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffff00 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000080 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffff00ff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00008000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00800000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00ffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffff00 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000080 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffff00ff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00008000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00800000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffff00 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000080 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffff00ff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xff00ffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00800000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0x00ffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffff00)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000080)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffff00ff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xff00ffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 230))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 84))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 85))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 201))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 29))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 135))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 243))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 136))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 72))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 32))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 136))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 96))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 128))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 128))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 182))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 134))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 6))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 8))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffff00 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000080 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffff00ff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 254))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00008000 0x00000000 0x00000000 0x00000000)) (i32.const 1))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00800000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00ffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 253))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x00000000)) (i32.const 2))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffff00 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000080 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffff00ff 0xffffffff 0xffffffff)) (i32.const 251))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00008000 0x00000000 0x00000000)) (i32.const 4))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00800000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff)) (i32.const 247))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000)) (i32.const 8))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffff00 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000080 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffff00ff 0xffffffff)) (i32.const 239))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x00000000)) (i32.const 16))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xff00ffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00800000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0x00ffffff 0xffffffff)) (i32.const 223))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000)) (i32.const 32))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffff00)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000080)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffff00ff)) (i32.const 191))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000)) (i32.const 64))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xff00ffff)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff)) (i32.const 127))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 128))
@@ -0,0 +1,2366 @@
+;; Generated lane by lane against V8, covering i32x4 operations
+
+(module
+  (func (export "i32x4.abs") (param v128) (result v128)
+    (i32x4.abs (local.get 0))
+  )
+  (func (export "i32x4.neg") (param v128) (result v128)
+    (i32x4.neg (local.get 0))
+  )
+  (func (export "i32x4.extadd_pairwise_i16x8_s") (param v128) (result v128)
+    (i32x4.extadd_pairwise_i16x8_s (local.get 0))
+  )
+  (func (export "i32x4.extadd_pairwise_i16x8_u") (param v128) (result v128)
+    (i32x4.extadd_pairwise_i16x8_u (local.get 0))
+  )
+  (func (export "i32x4.extend_low_i16x8_s") (param v128) (result v128)
+    (i32x4.extend_low_i16x8_s (local.get 0))
+  )
+  (func (export "i32x4.extend_low_i16x8_u") (param v128) (result v128)
+    (i32x4.extend_low_i16x8_u (local.get 0))
+  )
+  (func (export "i32x4.extend_high_i16x8_s") (param v128) (result v128)
+    (i32x4.extend_high_i16x8_s (local.get 0))
+  )
+  (func (export "i32x4.extend_high_i16x8_u") (param v128) (result v128)
+    (i32x4.extend_high_i16x8_u (local.get 0))
+  )
+  (func (export "i32x4.trunc_sat_f32x4_s") (param v128) (result v128)
+    (i32x4.trunc_sat_f32x4_s (local.get 0))
+  )
+  (func (export "i32x4.trunc_sat_f32x4_u") (param v128) (result v128)
+    (i32x4.trunc_sat_f32x4_u (local.get 0))
+  )
+  (func (export "i32x4.trunc_sat_f64x2_s_zero") (param v128) (result v128)
+    (i32x4.trunc_sat_f64x2_s_zero (local.get 0))
+  )
+  (func (export "i32x4.trunc_sat_f64x2_u_zero") (param v128) (result v128)
+    (i32x4.trunc_sat_f64x2_u_zero (local.get 0))
+  )
+  (func (export "i32x4.add") (param v128 v128) (result v128)
+    (i32x4.add (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.sub") (param v128 v128) (result v128)
+    (i32x4.sub (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.mul") (param v128 v128) (result v128)
+    (i32x4.mul (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.min_s") (param v128 v128) (result v128)
+    (i32x4.min_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.min_u") (param v128 v128) (result v128)
+    (i32x4.min_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.max_s") (param v128 v128) (result v128)
+    (i32x4.max_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.max_u") (param v128 v128) (result v128)
+    (i32x4.max_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.dot_i16x8_s") (param v128 v128) (result v128)
+    (i32x4.dot_i16x8_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.eq") (param v128 v128) (result v128)
+    (i32x4.eq (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.ne") (param v128 v128) (result v128)
+    (i32x4.ne (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.lt_s") (param v128 v128) (result v128)
+    (i32x4.lt_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.lt_u") (param v128 v128) (result v128)
+    (i32x4.lt_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.gt_s") (param v128 v128) (result v128)
+    (i32x4.gt_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.gt_u") (param v128 v128) (result v128)
+    (i32x4.gt_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.le_s") (param v128 v128) (result v128)
+    (i32x4.le_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.le_u") (param v128 v128) (result v128)
+    (i32x4.le_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.ge_s") (param v128 v128) (result v128)
+    (i32x4.ge_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.ge_u") (param v128 v128) (result v128)
+    (i32x4.ge_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.extmul_low_i16x8_s") (param v128 v128) (result v128)
+    (i32x4.extmul_low_i16x8_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.extmul_low_i16x8_u") (param v128 v128) (result v128)
+    (i32x4.extmul_low_i16x8_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.extmul_high_i16x8_s") (param v128 v128) (result v128)
+    (i32x4.extmul_high_i16x8_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.extmul_high_i16x8_u") (param v128 v128) (result v128)
+    (i32x4.extmul_high_i16x8_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.shl") (param v128 i32) (result v128)
+    (i32x4.shl (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.shr_s") (param v128 i32) (result v128)
+    (i32x4.shr_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.shr_u") (param v128 i32) (result v128)
+    (i32x4.shr_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.all_true") (param v128) (result i32)
+    (i32x4.all_true (local.get 0))
+  )
+  (func (export "i32x4.bitmask") (param v128) (result i32)
+    (i32x4.bitmask (local.get 0))
+  )
+)
+
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00fe8080 0x4081fe00 0x0fefc040 0x7d8155ab))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x3ade68b1 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x65432110 0x12345678 0x00000005 0x00000001))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0x3fe00000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x00800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0x41000000 0x40200000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x2feafd07 0x4f000000 0x30ffffff))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x40800000 0x3dcccccd))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x77ff8a64 0x7e37e43c 0x748f19d5 0x7fffed98))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00200000 0x41dfffff 0x00100000 0x3e200000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00200000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x40200000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdff00 0xf8f9fafc 0xf4f5f6f8 0xf0f1f2f4))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00fe8080 0xbf7e0200 0x0fefc040 0x7d8155ab))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0x80000001 0xedcb8000 0xff00012c))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xbfff0002 0x80014000 0xff7f7fff 0x80000081))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80000001 0x80000000 0xffffffff 0x00000001))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf8a432eb 0x3ade68b1 0xffff0001 0xffff0000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x80000001 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x65432110 0xedcba988 0x00000005 0x00000001))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0xc0400000 0x3fe00000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80800000 0x00800000 0x80400000 0x80803662))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xc1000000 0x41000000 0xbfe00000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xafeafd07 0x2feafd07 0xb1000000 0x30ffffff))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xb0800000 0xb87fffc0 0x40800000 0xc2333333))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x80100000 0x00000000 0x80080000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xc0080000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x77ff8a64 0x81c81bc4 0x748f19d5 0x7fffed98))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00200000 0xbe200001 0xfff00000 0x3e200000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00200000 0xbe100001 0x9f6ec300 0xbc1ea71c))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x40200000 0x00000000 0xbff40000))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfffffffe 0xfffffffe 0xfffffffe 0xfffffffe))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000402 0x00000c0a 0x00001412 0x00001c1a))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00007e81 0x00003e81 0x00002fd0 0xffff2cd3))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000001 0x00007ffe 0xffff9234 0xffffffd3))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00003ffe 0x00003ffe 0xffff8081 0x00007f7e))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00007ffe 0xffff8000 0x00000001 0xfffffffe))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffd470 0xffff5c70 0xffffffff 0x00000001))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffffffe 0x00007ffe 0x00000000 0xffff8000))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffff79ac 0x000068ac 0xfffffffa 0xfffffffe))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffff8000 0x00003fc0 0xffffc020))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00007f80 0xffffff80 0x00007fc0 0x0000491d))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00003f00 0xffffbf00 0x00004020))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0000530e 0xffffd30e 0x00004f00 0xffffcf01))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00004f80 0x000047c0 0xffffbf80 0x00000a99))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff8000))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00007ff0 0x00000000 0x00007ff8))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00003ff8 0x00000000 0xffffc004))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xfffffd9c 0x00006273 0xffff719b 0xffff9268))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffe0 0x000041de 0x00000010 0xffffc1e0))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffe0 0x000041ee 0x00009d91 0x00009cc5))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffffbfe0 0x00000000 0x0000400c))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0001fffe 0x0001fffe 0x0001fffe 0x0001fffe))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000402 0x00000c0a 0x00001412 0x00001c1a))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00017e81 0x00013e81 0x00012fd0 0x00012cd3))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000001 0x00017ffe 0x00009234 0x0000ffd3))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00013ffe 0x00013ffe 0x00008081 0x00017f7e))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00017ffe 0x00008000 0x00000001 0x0001fffe))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000d470 0x00015c70 0x0000ffff 0x00000001))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x0001fffe 0x00017ffe 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x000179ac 0x000068ac 0x0001fffa 0x0001fffe))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00008000 0x00003fc0 0x0000c020))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00007f80 0x0000ff80 0x00007fc0 0x0001491d))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00003f00 0x0000bf00 0x00004020))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0000530e 0x0000d30e 0x00004f00 0x0000cf01))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00004f80 0x000047c0 0x0000bf80 0x00010a99))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00007ff0 0x00000000 0x00007ff8))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00003ff8 0x00000000 0x0000c004))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000fd9c 0x00016273 0x0001719b 0x00009268))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ffe0 0x000141de 0x00000010 0x0000c1e0))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0000ffe0 0x000141ee 0x00009d91 0x00009cc5))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x0000bfe0 0x00000000 0x0000400c))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000100 0x00000302 0x00000504 0x00000706))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00007f80 0xffffff01 0xfffffe00 0x00004081))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0xffffffff 0x00007fff))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffffffe 0x00004000 0xffffc000 0x00007ffe))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x00007fff 0x00000000 0xffff8000))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffcd15 0x0000075b 0xffff974f 0xffffc521))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00007fff))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffdef0 0xffff9abc 0x00005678 0x00001234))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff8000))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00007f80 0x00000000 0xffffff80))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00003f00))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000002f9 0x00005015 0x000002f9 0xffffd015))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00004f80 0x00000040 0x00004780))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00007ff0))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00003ff8))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000759c 0xffff8800 0xffffe43c 0x00007e37))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffe0 0xffffffff 0x000041df))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffe0 0xffffffff 0x000041ef))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffbfe0))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000100 0x00000302 0x00000504 0x00000706))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00007f80 0x0000ff01 0x0000fe00 0x00004081))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0x0000ffff 0x00007fff))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000fffe 0x00004000 0x0000c000 0x00007ffe))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x0000ffff 0x00007fff 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000cd15 0x0000075b 0x0000974f 0x0000c521))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x00007fff))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x0000def0 0x00009abc 0x00005678 0x00001234))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00007f80 0x00000000 0x0000ff80))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00003f00))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000002f9 0x00005015 0x000002f9 0x0000d015))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00004f80 0x00000040 0x00004780))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00007ff0))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00003ff8))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000759c 0x00008800 0x0000e43c 0x00007e37))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x0000ffe0 0x0000ffff 0x000041df))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x0000ffe0 0x0000ffff 0x000041ef))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0000bfe0))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000908 0x00000b0a 0x00000d0c 0x00000f0e))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00003fc0 0xfffff010 0xffffaa55 0xffff827e))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff8000 0x00001234 0xfffffed4 0x000000ff))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff8001 0x00000080 0xffffff7f 0x00007fff))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff8000))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xfffffffb 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00003fc0 0x00000000 0xffffc020))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00007fc0 0xffffc99e 0x00007f7f))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xffffbf00 0x00000000 0x00004020))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00004f00 0x00000001 0xffffcf00))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xffffbf80 0xffffcccd 0x00003dcc))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff8000))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00007ff8))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffc004))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffe62b 0xffff8b70 0x00001268 0xffff8000))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000010 0x00000000 0xffffc1e0))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00003d00 0x00006091 0x000058e4 0x000043e1))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0000400c))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000908 0x00000b0a 0x00000d0c 0x00000f0e))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00003fc0 0x0000f010 0x0000aa55 0x0000827e))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00008000 0x00001234 0x0000fed4 0x000000ff))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00008001 0x00000080 0x0000ff7f 0x00007fff))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x0000fffb 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00003fc0 0x00000000 0x0000c020))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00007fc0 0x0000c99e 0x00007f7f))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x0000bf00 0x00000000 0x00004020))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00004f00 0x00000001 0x0000cf00))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x0000bf80 0x0000cccd 0x00003dcc))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00007ff8))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0000c004))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000e62b 0x00008b70 0x00001268 0x00008000))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000010 0x00000000 0x0000c1e0))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00003d00 0x00006091 0x000058e4 0x000043e1))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0000400c))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x80000000 0x00000004 0x80000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000002 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xfffff5e7 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000001 0xfffffffe))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000000 0x7fffffff))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000002))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7fffffff 0x80000000 0x7fffffff 0x80000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x7fffffff 0x00010000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000001 0x00000000 0xfffffffe))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x0000001b 0x00000000 0xffffffe4))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x0000001d 0x7fffffff 0x000001c2))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000002))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000004 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000002 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000002))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0x80000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0x00010000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000001 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x0000001b 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x0000001d 0xffffffff 0x000001c2))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000002))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0000023f 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xfffffff8 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80000000 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000008 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x80000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x7fffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x7fffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000001 0xfffffffe 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7fffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x7fffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000003 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0000023f 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000008 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x7fffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000003 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfffffffe 0xfffffffe 0xfffffffe 0xfffffffe))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x030200ff 0x07060503 0x0b0a0907 0x0f0e0d0b))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffffffe 0x7ffffffe 0xffffffff 0x7fffffff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x02038080 0x47880304 0xfb1a48c8 0x918cb761))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9dbedff0 0x193a5b7c 0x0b0a0903 0x0f0e0d0b))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xfe02ff00 0x8103fc00 0xe0207f80 0x04fd54aa))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff027f80 0xc081fdff 0x0244bfc0 0x837ea929))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0x2fd03fc0 0x429eaa55))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00020000 0xfffffffe 0x24690000 0x01fffda8))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4001fffe 0xfffebfff 0x12b50001 0x80fffe53))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f810000 0x7f7fffff 0x91f48000 0x807fc872))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x8001fffc 0xfffd8000 0x01010002 0xfffffefe))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xc000fffd 0xfffec000 0x00808002 0x7fffff7e))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000ffff 0xbefec000 0xbf808001 0xc01fff7f))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xfffffffe 0x00000000 0x00000002 0xfffffffe))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x875bcd14 0x4521974f 0x00010000 0x0000ffff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xd01502f8 0x501502f9 0x4f000001 0xcf000000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0eb79a2a 0x8a432e9e 0x0001fffe 0x00020000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd14 0x4521974e 0x0000ffff 0x80010000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x56dbcd15 0x0ca1978f 0xbf80ffff 0x3dcdcccd))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffffffe 0xfffffffe 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdeef 0x92345677 0xfffffffb 0x7fffffff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x3579bde0 0x2468acf0 0xfffffff6 0xfffffffe))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0x3fbffffb 0xc01fffff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x92245678 0xfffffffb 0x7ff7ffff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x7f800000 0x80400000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x7f800000 0xbf800000 0x3f9fc99e))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x3fc00000 0x80240000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xff000000 0xff000000 0xff800000 0xfeff933c))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0x3e800000 0x3ec00000 0xbf9fc99e))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0780759c 0x7db7e43c 0x0b30e62b 0xff7fdc06))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000002 0x7e000000 0x7e000000 0x80400000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502fa 0x0f1502f9 0x0e000000 0x0f200001))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00001 0x80dfffff 0xbf100000 0x02000000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xa02a05f2 0xa02a05f2 0x9e000000 0x9e000002))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9f9502f9 0x17950339 0x0e800000 0x0cccccce))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x4ff502f9 0x120502f8 0xaf913d00 0x12e158e5))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9f000000 0x8f000080 0x7f000000 0x7b99999a))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x07600040 0xbf800000 0x7dd8cccd))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff80000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffe00000 0x00000000 0xfff00000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbfe80000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0x7fefffff 0xffffffff 0x7ff7ffff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x80080000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xbe2fe43c 0x8b70e62b 0x40041268))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x46fe0504 0x0b0a0908 0xcf120d0c))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x1000eb38 0xfc6fc878 0x16e1cc56 0x000024d0))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x87e0759c 0xc017e43b 0x8b80e62b 0x41e01268))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8701f51c 0xbeb9e23c 0x7b8125eb 0x027ebcbd))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffc00000 0x83bffffe 0x00200000 0x83c00000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffc00000 0x83cffffe 0x60a13d00 0x05c158e4))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe10000 0xc1dffffe 0x12448000 0xc2dffed4))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffc00000 0x83dffffe 0xc1227a00 0x87c2b1c8))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x01cfffff 0x60913d00 0x83ed58e4))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x3fe0fffe 0xc1eebfff 0x6111bd01 0xc3e15863))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x7fc00000 0x00000000 0x80180000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x3fe00000 0x00000001 0x400bffff))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf8a432eb 0x3ade68b1 0xffff0001 0xffff0000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0xffffffff 0x7fffffff))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x04008180 0xc6840704 0x1af9c948 0x8c8f62b7))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x68452210 0xf4d1ae8c 0x0b0a090d 0x0f0e0d0d))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff007f80 0xc081fe01 0xdddbbfc0 0x817eab81))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0xb0503fc0 0xc25eaa55))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xc0000002 0x00013fff 0x11b3ffff 0x80ffff55))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80810000 0x807fffff 0x92748000 0x81803536))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xc000ffff 0xfffec000 0x00808000 0x7fffff80))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffd 0x40fec000 0x41808001 0x3fdfff7f))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432ea 0xbade68b1 0xffff0002 0xfffeffff))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd06 0xafeafd07 0xb1000001 0x30fffffe))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd16 0x45219750 0x0000ffff 0x80010000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xb7dbcd15 0x7da1970f 0x4080ffff 0xc2343333))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x6543210f 0x6dcba987 0x00000005 0x80000001))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0xc03ffffb 0x3fdfffff))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x92445678 0xfffffffb 0x8007ffff))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80800000 0x80800000 0xc0000000 0x40a03662))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40080000 0x3fc00000 0x001c0000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f7fffff 0xc0800000 0xc0c00000 0x3f5fc99e))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xf77f8a64 0x81481bc4 0xf44f19d5 0xff7fb736))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xafeafd08 0x6eeafd07 0x70000000 0x711fffff))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00200001 0xfd200001 0xbef00000 0x7e400000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x009502f9 0x889502b9 0x8f800000 0x91333334))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x503502f9 0x8e2502fa 0xee6ec300 0x8b1ea71d))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x87a00040 0xbf800000 0xfdc0cccd))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x80100000 0x00000000 0x00080000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xbff40000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x7ff00001 0x00000001 0x7ff80001))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x77ff8a64 0xc1c01bc4 0x748f19d5 0x4003ed98))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdff00 0x38f1fafc 0xf4f5f6f8 0xb0f5f2f4))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8820759c 0x3c57e43d 0x8b60e62b 0xbe201268))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x88fef61c 0x3db5e63c 0x9b60a66b 0xfd816813))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xfff00000 0x9f7ec300 0x7dfea71c))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffdf0000 0xc1e00000 0xeddb8000 0xc0e0012c))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x820fffff 0x60913d00 0x03d558e4))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xbfdf0002 0xc1f13fff 0x6010bcff 0xc3e15965))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80000001 0x3fe00000 0xffffffff 0x400c0001))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdff00 0xf8f9fafc 0xf4f5f6f8 0xf0f1f2f4))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x80000001 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x04010000 0x74492810 0x64f19040 0xd5fa3890))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x807f8000 0x7ffdf800 0xd03fbe00 0xd83c4cfc))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x4abef000 0x9d51b1e0 0xc8cdd2d8 0xf0f1f2f4))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3e804000 0xf8040000 0x07e01000 0x71010039))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7f800000 0xbf7e0200 0x92e00000 0x68906464))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x10000000 0x0aa00000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0x40000000 0xa8015f90))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffe0000 0x80014000 0x52348000 0x7f00972c))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00800000 0x00000000 0x343fbad8))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffc0004 0x90000000 0x41010001 0x00004101))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xbfff0002 0x00000000 0x00808001 0x80000081))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffe 0x00000000 0xbf000000 0xafe00000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432eb 0x80000000 0x0000ffff 0xffff0000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd07 0x80000000 0x4f000000 0x30ffffff))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x9738a3b9 0xf1cc4a61 0xfffe0001 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xf8a432eb 0xbade68b1 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x05800000 0xd8e5d3c0 0x40800000 0xcccd0000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x00000001 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x65432110 0xedcba988 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xf2a52100 0x1df4d840 0x00000019 0x00000001))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0xc1400000 0x3fe00000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x98800000 0x00000000 0x80080000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xb3c00000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x6d8d7d84))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0x00000000 0x00000000 0xb3c00000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x32000000 0xe2000000 0xf5400000 0xc8170430))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x47000000 0x00000000 0x40200000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0xc1000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x1ce2d631 0x1ce2d631 0x00000000 0x9e000001))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x53800000 0x90c0be40 0x00000000 0x00cccccd))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xa0e00000 0x325afd07 0x00000000 0x9fe158e4))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xc0001000 0x00000000 0xbc8f5c29))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xf8000000 0x00000000 0xd99c0000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x80100000 0x00000000 0x80080000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xde200000 0x00000000 0x49a00000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xd7e00000 0x00000000 0x34300000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xf607f710 0xed42ee10 0xe6914b39 0x0152ca40))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x4c800000 0x72481bc4 0x62b00000 0x83000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x142f3200 0x76af8800 0x10013540 0x6b6f2c88))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x7c400001 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x7c300001 0xd0000000 0xab800000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x3e200001 0x00000000 0xcd800000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x7c200001 0x28890000 0xb7a58b10))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x40200000 0x00000000 0x2ab00000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00400000 0x80014000 0x7f113d00 0xcb72351c))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xbff40000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xc521974f 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x80000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x07060504 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x07060504 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x80000000 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00010000 0x7ffec000 0x00808001 0x00fffed4))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00010000 0xff800000 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x4000fffe 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x80000000 0x00000001 0xcf000001))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xc521974f 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x075bcd15 0xc521974f 0xbf800000 0x00010000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0x80000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x80000000 0xfffffffb 0xc0200000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0xff800000 0xbf000000 0x40200000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xff800000 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000001 0xd01502f9 0xbf000000 0xcf000001))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x3f000000 0xbf000000 0xc1e00000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0xd01502f9 0xbf800000 0xcf000001))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xbf800000 0x80000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x3ff80000 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x07060504 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8800759c 0x41dfffff 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8800759c 0x4081fe00 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffe00000 0x41efffff 0x00808001 0x43e158e4))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x4081fe00 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x4081fe00 0x3fc00000 0x827eaa55))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00010000 0x7ffec000 0x00808001 0x00fffed4))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00000001 0x7fffff7f))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0x00808001 0x40200000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x80000000 0x00000001 0x00010000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x80000000 0x00000001 0xcf000001))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd15 0x7fffffff 0x00000000 0x00010000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x075bcd15 0x47800040 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x12345678 0x3fc00000 0xc0200000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x12345678 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0x7fc00000 0x40200000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f800000 0x7e37e43c 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000001 0x3f000000 0x4f000000 0x40200000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000001 0x3f000000 0x00100000 0x40200000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x4f000000 0x3dcccccd))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x501502f9 0x41efffff 0x4f000000 0x43e158e4))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3dcccccd))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x47800040 0x00000000 0x3dcccccd))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x80001268))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x07060504 0x00000000 0x0f0e0d0c))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8800759c 0x41dfffff 0x00100000 0x80001268))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8800759c 0x4081fe00 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0x43e158e4))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x41dfffff 0x00100000 0x00fffed4))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x41efffff 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x41efffff 0x00808001 0x43e158e4))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x00000000 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x03020100 0x4081fe00 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x03020100 0x12345678 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x4081fe00 0x3fc00000 0xc0200000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7fffffff 0x12348000 0x7fffff7f))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x7fffffff 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x7fffffff 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7fffffff 0xd01502f9 0x4f000000 0xffffffff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd15 0x7fffffff 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x0000ffff 0x3dcccccd))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x12345678 0x3fc00000 0xffffffff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0x3f000000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f800000 0x7e37e43c 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x3f000000 0x4f000000 0x40200000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000001 0x41dfffff 0x00100000 0x40200000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x501502f9 0x47800040 0x4f000000 0x3dcccccd))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x501502f9 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x3dcccccd))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x47800040 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x7e37e43c 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x3ff80000 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x7e37e43c 0x00100000 0xc1e00000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x7e37e43c 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x60913d00 0x7fffff7f))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0xbfe00000 0x00000001 0x400c0000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff017f80 0x7fffffff 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x80000000 0xf0103fc0 0xc0200000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7fffffff 0x12348000 0x7fffff7f))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00808001 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffe 0x7ffec000 0xbf000000 0x7fffff7f))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x7fffffff 0xc521974f 0x0000ffff 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7fffffff 0xd01502f9 0x4f000000 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xc521974f 0x0000ffff 0x80000000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0xc521974f 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x7fffffff 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x80000000 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x7ff00000 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0xc0200000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0xff800000 0xbf000000 0x7f7fc99e))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xff800000 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0xbf000000 0xcf000001))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0xbf000000 0xc1e00000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x501502f9 0xd01502f9 0xbf800000 0xcf000001))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0xd01502f9 0x60913d00 0xcf000001))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x80000000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0xbfe00000 0xbf800000 0x400c0000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0xc0040000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x3ff80000 0x0b0a0908 0xc0040000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x7e37e43c 0x8b70e62b 0xc1e00000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x7e37e43c 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0xc1e00000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe00000 0x7fffffff 0x12348000 0xc1e00000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0xbfe00000 0x60913d00 0x43e158e4))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffe00000 0x7ffec000 0x60913d00 0x7fffff7f))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0xbfe00000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000002 0x00000002 0x00000002 0x00000002))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfffffbfe 0xfffff3f6 0xffffebee 0xffffe3e6))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000002 0xffff8002 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x000a0c04 0x004a7c34 0x00cb6ca4 0x018cdd54))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x007c8102 0x01bb0206 0x018fcea0 0xf440d1e0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xfeae5978 0x02318b18 0xffffc7ce 0xffffe3e6))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3f813e01 0x1044c101 0x10de1100 0x5a33363d))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffff01 0x2040417f 0xdefde340 0xffe75fe6))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xdfbf8000 0xfc07fc00 0x1f50cfc0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000001 0x3fff0002 0x414b5a90 0x00025d91))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00004000 0x3ffec002 0x40089a00 0x0080162d))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00007f80 0xffc00080 0x09157300 0x00beba59))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x10000004 0x4ffe0004 0x3fff4001 0x3fff4102))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x1fffc002 0xc0010000 0xffff8001 0xffff8082))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xfffffffe 0x1f7f8200 0xffdf8000 0x200fbfe0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x3fff0002 0x40000000 0x00000001 0x00000002))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x03adab90 0x1d6f8000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x280a2cf2 0x17f58000 0x00000000 0x000030ff))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0a56be12 0x385a18a2 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00002b90 0xe2912390 0x00000000 0xffff8000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0248c280 0xef748b40 0x00000000 0x00003dcc))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000002 0x3fff0002 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00008654 0x09199754 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x2c53db10 0x1e8032d0 0x0000001a 0x00000002))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xf6e60000 0xffffc040 0x00003fe0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x0918dcc0 0x00000000 0xffff8008))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x40000000 0x0fe01000 0x0ff00400))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00400000 0x1fd01000 0xe0302fe0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xe0040000 0x00000000 0x0ff70080))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x3f804000 0x00004000 0x3fc01000 0x4b0cbe85))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xffe08000 0xdf904000 0x1fefafe0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xc43c0000 0xffc0e480 0xc5d52400 0xbc578430))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x0f810000 0x10810000 0x10100400))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000002f9 0xf4352b00 0xebf10000 0xf3b9e000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x1035e100 0xfffbf000 0xf0703c00))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x1915f7ea 0x0900f7ea 0x18610000 0x09610001))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x18de8580 0xf29e9bc0 0xec188000 0xf42bc0cd))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xfff5fd60 0xf3a895a2 0x1dccbf00 0xf30247e4))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x18b04000 0x13f85000 0x10404000 0x192836b9))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xe11a0000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xee171000 0x00000000 0x0f75e590))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x3ff00100 0x00000000 0x3ff80040))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x1ff80080 0x00000000 0xe003ffe0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0xffff8010 0x00000000 0xffff8008))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x0ffc0040 0x00000000 0x0ffe0010))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x1f89ce48 0x00000000 0x1ffe0000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x01c147d0 0x00000000 0xfc3cbc38))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x6e47f710 0x413d1de1 0x37ae1c39 0x4152ca40))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x000f0000 0x207a04ad 0xfff8b700 0x1f100000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3b0aba00 0x2004e1b7 0x00d2ec40 0x38982c88))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000400 0x10f30042 0x00000100 0x0f138400))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000400 0x10f71e32 0x00060910 0xef8705e0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffe0 0x20ef3e22 0x00012340 0xffc21e20))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000400 0x10fb3d22 0x32f61221 0x30dd16d1))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xef7c0220 0x00000000 0x10fb6e8c))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfff80000 0x20f73c22 0xe1b08580 0x21c3713b))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x10100400 0x00000000 0x10060090))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x20100000 0x00000000 0xffffbff4))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffff00 0xfffffcfe 0xfffffafc 0xfffff8fa))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0xffff8001))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00010000 0x00090c04 0x00192810 0x00315424))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x007f8000 0xfffd0102 0xfff5f800 0x01c50a06))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffdef000 0xfecf6978 0x01b1b1e0 0x007fd938))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3f804000 0x0000fe01 0x00040000 0x1040c101))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffff01 0x00000200 0x20403f7f))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xdfbf8000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0x00000001 0x3fff0001))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00004000 0x00004000 0x3ffe8002))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00007f80 0x00000000 0xffc00080))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000004 0x10000000 0x10000000 0x3ffe0004))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000002 0x1fffc000 0x00000000 0xc0010000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xfffffffe 0x00000000 0x00000000 0x1f7f8200))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x3fff0001 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x000032eb 0x03ad78a5 0x00000000 0x1d6f8000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xfffffd07 0x280a2feb 0x00000000 0x17f58000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0a20a3b9 0x00361a59 0x2ad04a61 0x0d89ce41))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x000032eb 0xfffff8a5 0x000068b1 0xe290badf))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x0248c280 0xffe5d3c0 0xef8eb780))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x3fff0001))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00002110 0x00006544 0xffffa988 0x0919edcc))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x04452100 0x280eba10 0x1d34d840 0x014b5a90))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xf6e60000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0918dcc0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00400000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xe0040000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3f804000 0x00000000 0x00004000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffe08000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xc43c0000 0x00000000 0xffc0e480))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x0f810000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000002f9 0x00000000 0x00000000 0xf4352b00))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1035e100))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0008d631 0x190d21b9 0x0008d631 0x08f821b9))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x18de8580 0x0000be40 0xf29ddd80))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xfff5fd60 0xfffffd07 0xf3a8989b))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x18b04000 0x00001000 0x13f84000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xee171000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ff00100))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1ff80080))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff8010))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0ffc0040))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1f89ce48))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x01c147d0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x3607f710 0x38400000 0x0302ee10 0x3e3a2fd1))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x000f0000 0x00001bc4 0x2079e8e9))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3a933200 0x00778800 0x00378800 0x1fcd59b7))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000400 0x00000001 0x10f30041))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000400 0x00000001 0x10f71e31))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffffe0 0x00000001 0x20ef3e21))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000400 0x00000001 0x10fb3d21))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xef7c0220))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xfff80000 0x00004000 0x20f6fc22))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10100400))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x20100000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfffe0001 0xfffe0001 0xfffe0001 0xfffe0001))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00ffff00 0x0301fcfe 0x0503fafc 0x0705f8fa))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffe0001 0xfffe0001 0xfffe0001 0x7ffe8001))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00010000 0x00090c04 0x00192810 0x00315424))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x007f8000 0x02ff0102 0x04f9f800 0x01c50a06))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00def000 0x01d16978 0x01b1b1e0 0x007fd938))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3f804000 0xfe02fe01 0xfc040000 0x1040c101))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x0000ff01 0xfdff0200 0x20403f7f))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x20408000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0xfffe0001 0x3fff0001))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00004000 0xbfff4000 0x3ffe8002))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00007f80 0x00000000 0x7fbf0080))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffc0004 0x10000000 0x90000000 0x3ffe0004))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xfffd0002 0x1fffc000 0x00000000 0x3fff0000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000fffe 0x00000000 0x00000000 0x1f7f8200))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xfffe0001 0x3fff0001 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xcd1432eb 0x03ad78a5 0x00000000 0x62908000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x02f8fd07 0x280a2feb 0x00000000 0x680a8000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xa44aa3b9 0x00361a59 0x596e4a61 0x97cbce41))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xcd1432eb 0x075af8a5 0x974e68b1 0x628fbadf))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x0248c280 0x0025d3c0 0x370eb780))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffe0001 0xfffe0001 0xfffe0001 0x3fff0001))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xdeef2110 0x9abb6544 0x5677a988 0x0919edcc))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xc2252100 0x5d86ba10 0x1d34d840 0x014b5a90))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x091a0000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0918dcc0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7fc00000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1ffc0000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3f804000 0x00000000 0xff004000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ee08000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x43bc0000 0x00000000 0x7df7e480))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x0f810000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000002f9 0x00000000 0x00000000 0x33352b00))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1035e100))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0008d631 0x190d21b9 0x0008d631 0xa92221b9))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x18de8580 0x0000be40 0x3a1ddd80))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x500afd60 0x02f8fd07 0x3597989b))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x18b04000 0x00001000 0x13f84000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x35971000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ff00100))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1ff80080))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7fef8010))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0ffc0040))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1f89ce48))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x01c147d0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x3607f710 0x48400000 0xcb7aee10 0x3e3a2fd1))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x87ef0000 0xe43b1bc4 0x2079e8e9))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3a933200 0x87788800 0xe2738800 0x1fcd59b7))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffc00400 0xfffe0001 0x10f30041))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffc00400 0xfffe0001 0x10f71e31))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x0000ffe0 0xfffe0001 0x20ef3e21))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffc00400 0xfffe0001 0x10fb3d21))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x316b0220))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x3ff80000 0xbfff4000 0x20f6fc22))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x8fd00400))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x5ff00000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfffff6f8 0xfffff4f6 0xfffff2f4 0xfffff0f2))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00519040 0x0079dc64 0x00aa3890 0x00e2a4c4))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x023fbe00 0xff5010a0 0xfba24cfc 0xf89e84e4))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffd2d8 0xfffff4f6 0xfffff2f4 0xfffff0f2))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0fe01000 0x00fe0100 0x1cab0039 0x3d883604))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xe0200000 0xfedde340 0x00646464 0xff82fb82))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xfc07fc00 0x00000000 0x1f50cfc0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x40000000 0x014b5a90 0x00015f90 0x0000fe01))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x3fff8000 0x00091a00 0x0000972c 0x007f7f01))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x09157300 0x003fbad8 0x007eff81))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x3fff0001 0x00004000 0x00004101 0x3fff0001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffff8001 0x00000000 0x00000081 0xffff8001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xffdf8000 0x00000000 0x200fbfe0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00003100))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff8000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00003dcc))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000019 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffc040 0x00000000 0x00003fe0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff8008))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x0fe01000 0x00000000 0x0ff00400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x1fd01000 0x00000000 0xe0302fe0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0ff70080))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3fc01000 0x0b8d7d84 0x3f7f4101))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xdf904000 0x00000000 0x1fefafe0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0xc5d52400 0xfc170430 0xc0408000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x10810000 0x00000000 0x10100400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xebf10000 0x00000000 0xf3b9e000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xfffbf000 0x00000000 0xf0703c00))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x18610000 0x00000001 0x09610000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xec188000 0xffffcccd 0xf42bf400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x1dccbf00 0x000058e4 0xf301ef00))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x10404000 0x0a3d5c29 0x0eeada90))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xe11a0000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0f75e590))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xc0040000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ff80040))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xe003ffe0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff8008))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0ffe0010))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1ffe0000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xfc3cbc38))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x029b4b39 0x3512d100 0x0152ca40 0x40000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xfff8b700 0x00000000 0x1f100000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xf9913540 0x0741b700 0xf9d72c88 0x3ec10000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000100 0x00000000 0x0f138400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00060910 0x00000000 0xef8705e0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00012340 0x00000000 0xffc21e20))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0e890000 0x246d1221 0x1edd8b10 0x11ff8bc1))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10fb6e8c))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xe1803d00 0x00304880 0xffd3351c 0x21f03c1f))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10060090))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffffbff4))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfffe0001 0xfffe0001 0xfffe0001 0xfffe0001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x0907f6f8 0x0b09f4f6 0x0d0bf2f4 0x0f0df0f2))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7fff8000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00519040 0x0079dc64 0x00aa3890 0x00e2a4c4))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x023fbe00 0x0a5a10a0 0x08ae4cfc 0x07ac84e4))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x0907d2d8 0x0b09f4f6 0x0d0bf2f4 0x0f0df0f2))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0fe01000 0xe11e0100 0x71550039 0x42843604))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x1fe00000 0x1111e340 0xa98d6464 0x0081fb82))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x3bc7fc00 0x00000000 0x61eecfc0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x40000000 0x014b5a90 0xfda95f90 0x0000fe01))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40008000 0x00091a00 0xfe53972c 0x007f7f01))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x09157300 0xc8b1bad8 0x007eff81))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40010001 0x00004000 0xfefe4101 0x3fff0001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00008001 0x00000000 0xff7e0081 0x7ffe8001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x005f8000 0x00000000 0x200fbfe0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0xfffe0001 0xfffe0001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0x00000000 0x00000000 0x0000ffff))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x0000ffff 0xceff3100))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xfffe0001 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00003dcc))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7fff8000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xfff60019 0xfffe0001 0xfffe0001 0xfffe0001))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x3fbfc040 0x00000000 0xc01f3fe0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7ff78008))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x0fe01000 0x00000000 0x90300400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x1fd01000 0x00000000 0x5faf2fe0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x901b0080))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3fc01000 0x9ec97d84 0x3f7f4101))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x5f504000 0x00000000 0x1fefafe0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x45952400 0x0e7f0430 0x3fbf8000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x8e810000 0x00000000 0x10100400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x3af10000 0x00000000 0x33d9e000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x000bf000 0x00000000 0x30903c00))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x18610000 0x00000001 0xa7610000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x3b188000 0x0000cccd 0x31f7f400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x1dccbf00 0x000058e4 0x36e2ef00))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x8f404000 0xa3d75c29 0x0eeada90))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1ee60000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0f75e590))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ff80040))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x5ffbffe0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7ff78008))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x90060010))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x60020000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0b4abc38))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xcef14b39 0x4bf2d100 0x0152ca40 0x40000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x0008b700 0x00000000 0x60f00000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x39513540 0x82c1b700 0x0c3f2c88 0x413f0000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000100 0x00000000 0x92d38400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00060910 0x00000000 0x336805e0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00012340 0x00000000 0x00c11e20))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0e890000 0x246d1221 0x1edd8b10 0x11ff8bc1))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10fb6e8c))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x1e803d00 0x00304880 0x58b7351c 0x21f03c1f))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10060090))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x400bbff4))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0xfffffffe 0xfffffffe 0xfffffffe 0xfffffffe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0xffffff80 0xffffff80 0xffffff80 0xffffff80))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffff00 0xffffff00 0xffffff00 0xffffff00))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffff8000 0xffff8000 0xffff8000 0xffff8000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0xfffffffe 0xfffffffe 0xfffffffe 0xfffffffe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0xfffffffe 0xfffffffe 0xfffffffe 0xfffffffe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0xfffffff0 0xfffffff0 0xfffffff0 0xfffffff0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x81008000 0x83028200 0x85048400 0x87068600))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x02010000 0x06050400 0x0a090800 0x0e0d0c00))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x00800000 0x02820000 0x04840000 0x06860000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x01000000 0x05040000 0x09080000 0x0d0c0000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x30201000 0x70605040 0xb0a09080 0xf0e0d0c0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0xfe02ff00 0x8103fc00 0xe0207f80 0x04fd54aa))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0x80bfc000 0x40ff0000 0x081fe000 0x3f552a80))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0x017f8000 0x81fe0000 0x103fc000 0x7eaa5500))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0xbfc00000 0xff000000 0x1fe00000 0x552a8000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0x7f800000 0xfe000000 0x3fc00000 0xaa550000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0xfe02ff00 0x8103fc00 0xe0207f80 0x04fd54aa))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0xfe02ff00 0x8103fc00 0xe0207f80 0x04fd54aa))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0xf017f800 0x081fe000 0x0103fc00 0x27eaa550))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00020000 0xfffffffe 0x24690000 0x01fffda8))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0x00800000 0xffffff80 0x1a400000 0x7fff6a00))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0x01000000 0xffffff00 0x34800000 0xfffed400))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0x80000000 0xffff8000 0x40000000 0xff6a0000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0x00000000 0xffff0000 0x80000000 0xfed40000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x00020000 0xfffffffe 0x24690000 0x01fffda8))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x00020000 0xfffffffe 0x24690000 0x01fffda8))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x00100000 0xfffffff0 0x23480000 0x0fffed40))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x8001fffc 0xfffd8000 0x01010002 0xfffffefe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x007fff00 0xff600000 0x40400080 0xffffbf80))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x00fffe00 0xfec00000 0x80800100 0xffff7f00))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x7fff0000 0x60000000 0x40008000 0xffbf8000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0xfffe0000 0xc0000000 0x80010000 0xff7f0000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x8001fffc 0xfffd8000 0x01010002 0xfffffefe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x8001fffc 0xfffd8000 0x01010002 0xfffffefe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x000fffe0 0xffec0000 0x08080010 0xfffff7f0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0xfffffffe 0x00000000 0x00000002 0xfffffffe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0xffffff80 0x00000000 0x00000080 0xffffff80))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffff00 0x00000000 0x00000100 0xffffff00))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffff8000 0x00000000 0x00008000 0xffff8000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffff0000 0x00000000 0x00010000 0xffff0000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x80000000 0x00000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0xfffffffe 0x00000000 0x00000002 0xfffffffe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x80000000 0x00000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0xfffffffe 0x00000000 0x00000002 0xfffffffe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x80000000 0x00000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0xfffffff0 0x00000000 0x00000010 0xfffffff0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x0eb79a2a 0x8a432e9e 0x0001fffe 0x00020000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0xade68a80 0x90cba780 0x007fff80 0x00800000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x5bcd1500 0x21974f00 0x00ffff00 0x01000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0xe68a8000 0xcba78000 0x7fff8000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0xcd150000 0x974f0000 0xffff0000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x0eb79a2a 0x8a432e9e 0x0001fffe 0x00020000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x0eb79a2a 0x8a432e9e 0x0001fffe 0x00020000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x75bcd150 0x521974f0 0x000ffff0 0x00100000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xfffffffe 0xfffffffe 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0xffffff80 0xffffff80 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0xffffff00 0xffffff00 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0xffff8000 0xffff8000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffff0000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0x80000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0xfffffffe 0xfffffffe 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0x80000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0xfffffffe 0xfffffffe 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0x80000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0xfffffff0 0xfffffff0 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3579bde0 0x2468acf0 0xfffffff6 0xfffffffe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0x5e6f7800 0x1a2b3c00 0xfffffd80 0xffffff80))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0xbcdef000 0x34567800 0xfffffb00 0xffffff00))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0x6f780000 0x2b3c0000 0xfffd8000 0xffff8000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0xdef00000 0x56780000 0xfffb0000 0xffff0000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0x3579bde0 0x2468acf0 0xfffffff6 0xfffffffe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3579bde0 0x2468acf0 0xfffffff6 0xfffffffe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0xabcdef00 0x23456780 0xffffffb0 0xfffffff0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x00060402 0x000e0c0a 0x00161412 0x001e1c1a))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x00030201 0x00070605 0x000b0a09 0x000f0e0d))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x00000604 0x00000e0c 0x00001614 0x00001e1c))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x00000302 0x00000706 0x00000b0a 0x00000f0e))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x00302010 0x00706050 0x00b0a090 0x00f0e0d0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0xff80bfc0 0x2040ff00 0xf8081fe0 0xc13f552a))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0xfffe02ff 0x008103fc 0xffe0207f 0xff04fd54))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0xffff017f 0x004081fe 0xfff0103f 0xff827eaa))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0xfffffe02 0x00008103 0xffffe020 0xffff04fd))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0xffffff01 0x00004081 0xfffff010 0xffff827e))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0xff80bfc0 0x2040ff00 0xf8081fe0 0xc13f552a))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0xff80bfc0 0x2040ff00 0xf8081fe0 0xc13f552a))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0xfff017f8 0x04081fe0 0xff0103fc 0xf827eaa5))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00008000 0x3fffffff 0x091a4000 0x007fff6a))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0x00000200 0x00ffffff 0x00246900 0x0001fffd))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0x00000100 0x007fffff 0x00123480 0x0000fffe))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0x00000002 0x0000ffff 0x00002469 0x000001ff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0x00000001 0x00007fff 0x00001234 0x000000ff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x00008000 0x3fffffff 0x091a4000 0x007fff6a))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x00008000 0x3fffffff 0x091a4000 0x007fff6a))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x00001000 0x07ffffff 0x01234800 0x000fffed))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x20007fff 0x3fff6000 0x00404000 0x3fffffbf))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x008001ff 0x00fffd80 0x00010100 0x00fffffe))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x004000ff 0x007ffec0 0x00008080 0x007fffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x00008001 0x0000fffd 0x00000101 0x0000ffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0x00004000 0x00007ffe 0x00000080 0x00007fff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x20007fff 0x3fff6000 0x00404000 0x3fffffbf))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x20007fff 0x3fff6000 0x00404000 0x3fffffbf))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x04000fff 0x07ffec00 0x00080800 0x07fffff7))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3fffffff 0xc0000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0x00ffffff 0xff000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0x007fffff 0xff800000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0x0000ffff 0xffff0000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0x00007fff 0xffff8000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0x3fffffff 0xc0000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3fffffff 0xc0000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0x07ffffff 0xf8000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x03ade68a 0xe290cba7 0x00007fff 0x00008000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0x000eb79a 0xff8a432e 0x000001ff 0x00000200))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x00075bcd 0xffc52197 0x000000ff 0x00000100))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0x00000eb7 0xffff8a43 0x00000001 0x00000002))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0x0000075b 0xffffc521 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x03ade68a 0xe290cba7 0x00007fff 0x00008000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x03ade68a 0xe290cba7 0x00007fff 0x00008000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x0075bcd1 0xfc521974 0x00000fff 0x00001000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0xffffffff 0x007fffff 0x00000000 0xff800000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffffffff 0x00007fff 0x00000000 0xffff8000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0xffffffff 0x07ffffff 0x00000000 0xf8000000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0xcd5e6f78 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0xff3579bd 0x002468ac 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0xff9abcde 0x00123456 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffff3579 0x00002468 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffff9abc 0x00001234 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0xcd5e6f78 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0xcd5e6f78 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0xf9abcdef 0x01234567 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0x7fffffff 0x7fffffff 0x7fffffff 0x7fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0x01ffffff 0x01ffffff 0x01ffffff 0x01ffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0x00ffffff 0x00ffffff 0x00ffffff 0x00ffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0x0001ffff 0x0001ffff 0x0001ffff 0x0001ffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0x7fffffff 0x7fffffff 0x7fffffff 0x7fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0x7fffffff 0x7fffffff 0x7fffffff 0x7fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0x0fffffff 0x0fffffff 0x0fffffff 0x0fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x00060402 0x000e0c0a 0x00161412 0x001e1c1a))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x00030201 0x00070605 0x000b0a09 0x000f0e0d))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x00000604 0x00000e0c 0x00001614 0x00001e1c))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x00000302 0x00000706 0x00000b0a 0x00000f0e))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x00302010 0x00706050 0x00b0a090 0x00f0e0d0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0x7f80bfc0 0x2040ff00 0x78081fe0 0x413f552a))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0x01fe02ff 0x008103fc 0x01e0207f 0x0104fd54))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0x00ff017f 0x004081fe 0x00f0103f 0x00827eaa))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0x0001fe02 0x00008103 0x0001e020 0x000104fd))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0x0000ff01 0x00004081 0x0000f010 0x0000827e))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0x7f80bfc0 0x2040ff00 0x78081fe0 0x413f552a))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0x7f80bfc0 0x2040ff00 0x78081fe0 0x413f552a))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0x0ff017f8 0x04081fe0 0x0f0103fc 0x0827eaa5))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00008000 0x3fffffff 0x091a4000 0x007fff6a))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0x00000200 0x00ffffff 0x00246900 0x0001fffd))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0x00000100 0x007fffff 0x00123480 0x0000fffe))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0x00000002 0x0000ffff 0x00002469 0x000001ff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0x00000001 0x00007fff 0x00001234 0x000000ff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x00008000 0x3fffffff 0x091a4000 0x007fff6a))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x00008000 0x3fffffff 0x091a4000 0x007fff6a))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x00001000 0x07ffffff 0x01234800 0x000fffed))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x20007fff 0x3fff6000 0x00404000 0x3fffffbf))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x008001ff 0x00fffd80 0x00010100 0x00fffffe))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x004000ff 0x007ffec0 0x00008080 0x007fffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x00008001 0x0000fffd 0x00000101 0x0000ffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0x00004000 0x00007ffe 0x00000080 0x00007fff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x20007fff 0x3fff6000 0x00404000 0x3fffffbf))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x20007fff 0x3fff6000 0x00404000 0x3fffffbf))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x04000fff 0x07ffec00 0x00080800 0x07fffff7))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3fffffff 0x40000000 0x00000000 0x7fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0x00ffffff 0x01000000 0x00000000 0x01ffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0x007fffff 0x00800000 0x00000000 0x00ffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0x0000ffff 0x00010000 0x00000000 0x0001ffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0x00007fff 0x00008000 0x00000000 0x0000ffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000001 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0x3fffffff 0x40000000 0x00000000 0x7fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000001 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3fffffff 0x40000000 0x00000000 0x7fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000001 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0x07ffffff 0x08000000 0x00000000 0x0fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x03ade68a 0x6290cba7 0x00007fff 0x00008000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0x000eb79a 0x018a432e 0x000001ff 0x00000200))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x00075bcd 0x00c52197 0x000000ff 0x00000100))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0x00000eb7 0x00018a43 0x00000001 0x00000002))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0x0000075b 0x0000c521 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000001 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x03ade68a 0x6290cba7 0x00007fff 0x00008000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000001 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x03ade68a 0x6290cba7 0x00007fff 0x00008000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000001 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x0075bcd1 0x0c521974 0x00000fff 0x00001000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0x7fffffff 0x3fffffff 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0x01ffffff 0x00ffffff 0x00000000 0x01000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0x00ffffff 0x007fffff 0x00000000 0x00800000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0x0001ffff 0x0000ffff 0x00000000 0x00010000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0x0000ffff 0x00007fff 0x00000000 0x00008000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0x7fffffff 0x3fffffff 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0x7fffffff 0x3fffffff 0x00000000 0x40000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0x0fffffff 0x07ffffff 0x00000000 0x08000000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0x7ffffffd 0x7fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0x013579bd 0x002468ac 0x01ffffff 0x01ffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0x009abcde 0x00123456 0x00ffffff 0x00ffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0x00013579 0x00002468 0x0001ffff 0x0001ffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0x00009abc 0x00001234 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0x7ffffffd 0x7fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0x7ffffffd 0x7fffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0x09abcdef 0x01234567 0x0fffffff 0x0fffffff))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffff00 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000080 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffff00ff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00008000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00800000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00ffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffff00 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000080 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffff00ff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00008000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00800000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffff00 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000080 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffff00ff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xff00ffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00800000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0x00ffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffff00)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000080)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffff00ff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xff00ffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 13))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 10))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 2))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 9))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 13))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 10))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 2))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 4))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 10))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 4))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 8))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 8))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 13))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 9))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 1))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 2))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffff00 0xffffffff 0xffffffff 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000080 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffff00ff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00008000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00800000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00ffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 14))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x00000000)) (i32.const 1))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffff00 0xffffffff 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000080 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffff00ff 0xffffffff 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00008000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00800000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff)) (i32.const 13))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000)) (i32.const 2))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffff00 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000080 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffff00ff 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xff00ffff 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00800000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0x00ffffff 0xffffffff)) (i32.const 11))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000)) (i32.const 4))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffff00)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000080)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffff00ff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xff00ffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff)) (i32.const 7))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 8))
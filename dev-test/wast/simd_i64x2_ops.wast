@@ -0,0 +1,1595 @@
+;; Generated lane by lane against V8, covering i64x2 operations
+
+(module
+  (func (export "i64x2.abs") (param v128) (result v128)
+    (i64x2.abs (local.get 0))
+  )
+  (func (export "i64x2.neg") (param v128) (result v128)
+    (i64x2.neg (local.get 0))
+  )
+  (func (export "i64x2.extend_low_i32x4_s") (param v128) (result v128)
+    (i64x2.extend_low_i32x4_s (local.get 0))
+  )
+  (func (export "i64x2.extend_low_i32x4_u") (param v128) (result v128)
+    (i64x2.extend_low_i32x4_u (local.get 0))
+  )
+  (func (export "i64x2.extend_high_i32x4_s") (param v128) (result v128)
+    (i64x2.extend_high_i32x4_s (local.get 0))
+  )
+  (func (export "i64x2.extend_high_i32x4_u") (param v128) (result v128)
+    (i64x2.extend_high_i32x4_u (local.get 0))
+  )
+  (func (export "i64x2.add") (param v128 v128) (result v128)
+    (i64x2.add (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.sub") (param v128 v128) (result v128)
+    (i64x2.sub (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.mul") (param v128 v128) (result v128)
+    (i64x2.mul (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.eq") (param v128 v128) (result v128)
+    (i64x2.eq (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.ne") (param v128 v128) (result v128)
+    (i64x2.ne (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.lt_s") (param v128 v128) (result v128)
+    (i64x2.lt_s (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.gt_s") (param v128 v128) (result v128)
+    (i64x2.gt_s (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.le_s") (param v128 v128) (result v128)
+    (i64x2.le_s (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.ge_s") (param v128 v128) (result v128)
+    (i64x2.ge_s (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.extmul_low_i32x4_s") (param v128 v128) (result v128)
+    (i64x2.extmul_low_i32x4_s (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.extmul_low_i32x4_u") (param v128 v128) (result v128)
+    (i64x2.extmul_low_i32x4_u (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.extmul_high_i32x4_s") (param v128 v128) (result v128)
+    (i64x2.extmul_high_i32x4_s (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.extmul_high_i32x4_u") (param v128 v128) (result v128)
+    (i64x2.extmul_high_i32x4_u (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.shl") (param v128 i32) (result v128)
+    (i64x2.shl (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.shr_s") (param v128 i32) (result v128)
+    (i64x2.shr_s (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.shr_u") (param v128 i32) (result v128)
+    (i64x2.shr_u (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.all_true") (param v128) (result i32)
+    (i64x2.all_true (local.get 0))
+  )
+  (func (export "i64x2.bitmask") (param v128) (result i32)
+    (i64x2.bitmask (local.get 0))
+  )
+)
+
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0x0fefc040 0x7d8155aa))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80000001 0x7fffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf8a432eb 0x3ade68b0 0x0000ffff 0x00010000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0x00000005 0x00000000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0xc0400000 0x3fdfffff))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80800000 0x007fffff 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xafeafd07 0x2feafd06 0xb1000000 0x30fffffe))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x748f19d5 0x7fffed97))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0xfff00000 0x3e1fffff))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x40200000 0x00000000 0x400c0000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdff00 0xf8f9fafb 0xf4f5f6f8 0xf0f1f2f3))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00fe8080 0xbf7e01ff 0x0fefc040 0x7d8155aa))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0x80000000 0xedcb8000 0xff00012b))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xbfff0002 0x80013fff 0xff7f7fff 0x80000080))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80000001 0x7fffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf8a432eb 0x3ade68b0 0xffff0001 0xfffeffff))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x65432110 0xedcba987 0x00000005 0x00000000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0xc0400000 0x3fdfffff))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80800000 0x007fffff 0x80400000 0x80803661))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xc0ffffff 0x41000000 0xbfdfffff))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xafeafd07 0x2feafd06 0xb1000000 0x30fffffe))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xb0800000 0xb87fffbf 0x40800000 0xc2333332))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x80100000 0x00000000 0x80080000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xc0080000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x77ff8a64 0x81c81bc3 0x748f19d5 0x7fffed97))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00200000 0xbe200000 0xfff00000 0x3e1fffff))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00200000 0xbe100000 0x9f6ec300 0xbc1ea71b))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x40200000 0x00000000 0xbff40000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x00000000 0x07060504 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0xffffffff 0x4081fe00 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x00000000 0x7fffffff 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x00000000 0x7ffec000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x00000000 0x80000000 0xffffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x00000000 0xc521974f 0xffffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x7fffffff 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0xffffffff 0x12345678 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0xffffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x00000000 0xff800000 0xffffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x3f000000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x00000000 0xd01502f9 0xffffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x00000000 0x47800040 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x7ff00000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x3ff80000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xffffffff 0x7e37e43c 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0xffffffff 0x41dfffff 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0xffffffff 0x41efffff 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xbfe00000 0xffffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x00000000 0x07060504 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x00000000 0x4081fe00 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x00000000 0x7fffffff 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x00000000 0x7ffec000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x00000000 0x80000000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x00000000 0xc521974f 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00000000 0x7fffffff 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x00000000 0x12345678 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x00000000 0xff800000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x3f000000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x00000000 0xd01502f9 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x00000000 0x47800040 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x7ff00000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x3ff80000 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x00000000 0x7e37e43c 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x00000000 0x41dfffff 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x00000000 0x41efffff 0x00000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xbfe00000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x0b0a0908 0x00000000 0x0f0e0d0c 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xf0103fc0 0xffffffff 0x827eaa55 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x12348000 0x00000000 0x00fffed4 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00808001 0x00000000 0x7fffff7f 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0x00000000 0x00010000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xfffffffb 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x3fc00000 0x00000000 0xc0200000 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7fc00000 0x00000000 0x7f7fc99e 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xbf000000 0xffffffff 0x40200000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x4f000000 0x00000000 0xcf000001 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xbf800000 0xffffffff 0x3dcccccd 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x7ff80000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0xc0040000 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8b70e62b 0xffffffff 0x80001268 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00100000 0x00000000 0xc1e00000 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x60913d00 0x00000000 0x43e158e4 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x400c0000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x0b0a0908 0x00000000 0x0f0e0d0c 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xf0103fc0 0x00000000 0x827eaa55 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x12348000 0x00000000 0x00fffed4 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00808001 0x00000000 0x7fffff7f 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0x00000000 0x00010000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xfffffffb 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x3fc00000 0x00000000 0xc0200000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7fc00000 0x00000000 0x7f7fc99e 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xbf000000 0x00000000 0x40200000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x4f000000 0x00000000 0xcf000001 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xbf800000 0x00000000 0x3dcccccd 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x7ff80000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0xc0040000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8b70e62b 0x00000000 0x80001268 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00100000 0x00000000 0xc1e00000 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x60913d00 0x00000000 0x43e158e4 0x00000000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x400c0000 0x00000000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfffffffe 0xffffffff 0xfffffffe 0xffffffff))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x030200ff 0x07060504 0x0b0a0907 0x0f0e0d0c))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffffffe 0x7fffffff 0xffffffff 0x7fffffff))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x02038080 0x47880305 0xfb1a48c8 0x918cb761))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9dbedff0 0x193a5b7c 0x0b0a0903 0x0f0e0d0c))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xfe02ff00 0x8103fc01 0xe0207f80 0x04fd54ab))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff027f80 0xc081fdff 0x0244bfc0 0x837ea92a))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0x2fd03fc0 0x429eaa56))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00020000 0xfffffffe 0x24690000 0x01fffda8))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4001fffe 0xfffebfff 0x12b50001 0x80fffe53))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f810000 0x7f7fffff 0x91f48000 0x807fc872))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x8001fffc 0xfffd8000 0x01010002 0xfffffefe))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xc000fffd 0xfffec000 0x00808002 0x7fffff7e))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000ffff 0xbefec000 0xbf808001 0xc01fff7f))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xfffffffe 0x00000000 0x00000002 0xfffffffe))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x875bcd14 0x4521974f 0x00010000 0x0000ffff))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xd01502f8 0x501502f9 0x4f000001 0xcf000000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0eb79a2a 0x8a432e9e 0x0001fffe 0x00020000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd14 0x4521974f 0x0000ffff 0x80010000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x56dbcd15 0x0ca1978f 0xbf80ffff 0x3dcdcccd))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfffffffe 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdeef 0x92345678 0xfffffffb 0x7fffffff))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x3579bde0 0x2468acf1 0xfffffff6 0xffffffff))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0x3fbffffb 0xc0200000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x92245678 0xfffffffb 0x7ff7ffff))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x7f800000 0x80400000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x7f800000 0xbf800000 0x3f9fc99e))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x3fc00000 0x80240000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xff000000 0xff000000 0xff800000 0xfeff933c))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0x3e800000 0x3ec00000 0xbf9fc99f))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0780759c 0x7db7e43d 0x0b30e62b 0xff7fdc07))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000002 0x7e000000 0x7e000000 0x80400001))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502fa 0x0f1502f9 0x0e000000 0x0f200002))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00001 0x80dfffff 0xbf100000 0x02000000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xa02a05f2 0xa02a05f2 0x9e000000 0x9e000002))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9f9502f9 0x17950339 0x0e800000 0x0ccccccf))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x4ff502f9 0x120502f9 0xaf913d00 0x12e158e5))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9f000000 0x8f000080 0x7f000000 0x7b99999b))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x07600040 0xbf800000 0x7dd8cccd))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff80000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffe00000 0x00000000 0xfff00000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbfe80000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0x7fefffff 0xffffffff 0x7ff7ffff))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x80080000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xbe2fe43c 0x8b70e62b 0x40041268))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x46fe0504 0x0b0a0908 0xcf120d0c))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x1000eb38 0xfc6fc879 0x16e1cc56 0x000024d1))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x87e0759c 0xc017e43c 0x8b80e62b 0x41e01268))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8701f51c 0xbeb9e23d 0x7b8125eb 0x027ebcbe))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffc00000 0x83bfffff 0x00200000 0x83c00000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffc00000 0x83cfffff 0x60a13d00 0x05c158e4))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe10000 0xc1dffffe 0x12448000 0xc2dffed4))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffc00000 0x83dfffff 0xc1227a00 0x87c2b1c8))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x01cfffff 0x60913d00 0x83ed58e4))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x3fe0fffe 0xc1eec000 0x6111bd01 0xc3e15863))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x7fc00000 0x00000000 0x80180000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x3fe00000 0x00000001 0x400bffff))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf8a432eb 0x3ade68b0 0xffff0001 0xfffeffff))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0xffffffff 0x7fffffff))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x04008180 0xc6840703 0x1af9c948 0x8c8f62b6))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x68452210 0xf4d1ae8b 0x0b0a090d 0x0f0e0d0c))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff007f80 0xc081fe01 0xdddbbfc0 0x817eab81))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0xb0503fc0 0xc25eaa55))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xc0000002 0x00013ffe 0x11b3ffff 0x80ffff55))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80810000 0x807ffffe 0x92748000 0x81803535))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xc000ffff 0xfffebfff 0x00808000 0x7fffff80))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffd 0x40fec000 0x41808001 0x3fdfff7e))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432ea 0xbade68b1 0xffff0002 0xfffefffe))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd06 0xafeafd07 0xb1000001 0x30fffffd))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd16 0x4521974f 0x0000ffff 0x80010000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xb7dbcd15 0x7da1970e 0x4080ffff 0xc2343332))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x6543210f 0x6dcba987 0x00000005 0x80000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0xc03ffffb 0x3fdfffff))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x92445678 0xfffffffb 0x8007ffff))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80800000 0x807fffff 0xc0000000 0x40a03661))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40080000 0x3fc00000 0x001c0000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f7fffff 0xc0800000 0xc0c00000 0x3f5fc99d))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xf77f8a64 0x81481bc3 0xf44f19d5 0xff7fb735))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xafeafd08 0x6eeafd06 0x70000000 0x711fffff))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00200001 0xfd200000 0xbef00000 0x7e400000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x009502f9 0x889502b9 0x8f800000 0x91333333))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x503502f9 0x8e2502f9 0xee6ec300 0x8b1ea71c))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x87a00040 0xbf800000 0xfdc0cccd))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x80100000 0x00000000 0x00080000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xbff40000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x7ff00000 0x00000001 0x7ff80000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x77ff8a64 0xc1c01bc3 0x748f19d5 0x4003ed97))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdff00 0x38f1fafb 0xf4f5f6f8 0xb0f5f2f3))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8820759c 0x3c57e43c 0x8b60e62b 0xbe201268))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x88fef61c 0x3db5e63b 0x9b60a66b 0xfd816812))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xfff00000 0x9f7ec300 0x7dfea71b))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffdf0000 0xc1e00000 0xeddb8000 0xc0e0012b))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x820fffff 0x60913d00 0x03d558e4))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xbfdf0002 0xc1f13fff 0x6010bcff 0xc3e15965))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80000001 0x3fdfffff 0xffffffff 0x400c0000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdff00 0xf8f9fafb 0xf4f5f6f8 0xf0f1f2f3))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x04010000 0x3823140a 0x64f19040 0xfb3585eb))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x807f8000 0x01808180 0xd03fbe00 0xb11e287f))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x4abef000 0x30061071 0xc8cdd2d8 0xb4b9bec3))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3e804000 0x7605fc03 0x07e01000 0xa193ce10))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7f800000 0xfeff7f81 0x92e00000 0x66762ad0))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x10000000 0xde880be0))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xfffe0001 0x40000000 0x563f6cc4))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffe0000 0x7fff4002 0x52348000 0x3dfea22e))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x80807f80 0x00000000 0x48fcb2e0))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffc0004 0x90057fff 0x41010001 0x7e7f3f7e))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xbfff0002 0xa001bffe 0x00808001 0x7f7f7f7e))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffe 0x01fec000 0xbf000000 0x017fdf80))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x3fffffff 0x00000001 0xfffffffe))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432eb 0x3e8c4f3b 0x0000ffff 0x00000001))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd07 0x57f57e83 0x4f000000 0x80000001))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x9738a3b9 0x08377f18 0xfffe0001 0xfffe0000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xf8a432eb 0xbade68b0 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x05800000 0x3f3c4770 0x40800000 0x8f00f2b2))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x65432110 0xedcba987 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xf2a52100 0xa5e20890 0x00000019 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0xc1400000 0x3f5ffffe))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x11000000 0x00000000 0x80280000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0fe01000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x38501000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3f804000 0x00000000 0x70c01000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0xff800000 0x00000000 0x41504000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x32000000 0x57bc3a93 0xf5400000 0xe4506865))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x7e000000 0x00000000 0x8e810000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x171502f9 0x00000000 0xf9f10000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00000000 0x000bf000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x1ce2d631 0x52d2cff7 0x00000000 0xb6610000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x53800000 0xfd1f44ac 0x00000000 0x3d988000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xa0e00000 0xa345fd5f 0x00000000 0xda5e0ed3))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xd8b04000 0x00000000 0x42404000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x80100000 0x00000000 0x80080000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x53200000 0x00000000 0xd8ac0000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xf8000000 0x00000000 0x24200000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xf607f710 0xf2fd7e15 0xe6914b39 0x780cdaac))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x4c800000 0xfceefff1 0x62b00000 0xf728b70e))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x142f3200 0xbe2deae3 0x10013540 0x925711d7))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000400 0x00000000 0x00000100))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000400 0xd0000000 0xee460913))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x001fffe0 0x00000000 0xed412348))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000400 0x28890000 0x3b11e826))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xdc000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00400000 0x7c17ffe0 0x7f113d00 0xb95414cb))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x40200000 0x00000000 0x400c0000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdff00 0xffffffff 0xf8f9fafc 0xffffffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x00000000 0x80000001 0xffffffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x04010000 0x00090c0a 0x74492810 0x0031546a))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x807f8000 0xfffd0280 0x7ffdf800 0x01c51241))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x4abef000 0xfecf6bb1 0x9d51b1e0 0x007fdbf2))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3e804000 0x0000fd03 0xf8040000 0x10414101))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7f800000 0xffffff01 0xbf7e0200 0x2040feff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xdfbf0100))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0x00000001 0x3fffffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffe0000 0x00004000 0x80014000 0x3fff5fff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00007f80 0x00800000 0xffc00000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffc0004 0x10007fff 0x90000000 0x3ffec001))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xbfff0002 0x20007ffe 0x00000000 0xc000a000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffe 0x00000000 0x00000000 0x1f7fb140))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x3fffffff 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432eb 0x03ade68a 0x80000000 0x1d6f3458))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd07 0x280a817c 0x80000000 0x17f57e83))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x9738a3b9 0x00362622 0xf1cc4a61 0x0d8988a9))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xf8a432eb 0xffffffff 0xbade68b1 0xe290cba7))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x05800000 0x02490230 0xd8e5d3c0 0xef8ee1b3))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x3fffffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x65432110 0x00000000 0xedcba988 0x091a2b3b))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xf2a52100 0x280e09b0 0x1df4d840 0x014b66dc))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xf6e5d4c4))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x98800000 0x091907f6))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00400000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xe0040000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3f804000 0x00000000 0x00004000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0x00000000 0x00000000 0xffe08000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x32000000 0xc43c3a93 0xe2000000 0xffc0e40d))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x0f810000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x00000000 0x47000000 0xf4352bbb))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0xffffffff 0xc1000000 0x10361fff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x1ce2d631 0x190d2395 0x1ce2d631 0x08f8209c))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x53800000 0x18de866c 0x90c0be40 0xf29dde48))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xa0e00000 0xfff5fd5f 0x325afd07 0xf3a86974))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x18b04000 0xc0001000 0x13f84023))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xf8000000 0xee170fef))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ff00100))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1ff80080))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x80100000 0xffffffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0ffc0040))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0xde200000 0x1f8a074f))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0xd7e00000 0x01c14910))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xf607f710 0x383f91bd 0xed42ee10 0x3e3b10de))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x4c800000 0x000efff1 0x72481bc4 0x207aa1da))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x142f3200 0x00774bc7 0x76af8800 0x1fce1074))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000400 0x7c400001 0x10f383ff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000400 0x7c300001 0x10f7a1ff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffffe0 0x3e200001 0x20efffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000400 0x7c200001 0x10fbc0ff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x40200000 0xef7bc200))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00400000 0xfff7ffe0 0x80014000 0x20f7ad93))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10100400))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x20100000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0xfffffffe 0x00000001 0xfffffffe))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdff00 0x030200ff 0xf8f9fafc 0x07060503))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0xfffffffe 0x80000001 0x7ffffffe))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x04010000 0x00090c0a 0x74492810 0x0031546a))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x807f8000 0x02ff0380 0x7ffdf800 0x01c51241))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x4abef000 0x01d16cb1 0x9d51b1e0 0x007fdbf2))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3e804000 0xfe03fc03 0xf8040000 0x10414101))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7f800000 0x0000ff01 0xbf7e0200 0x2040feff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x2040ff00))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0x00000001 0x3fffffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffe0000 0x00004000 0x80014000 0x3fff5fff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00007f80 0x00800000 0x7fbfffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xfffc0004 0x10007fff 0x90000000 0x3ffec001))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xbfff0002 0x20007ffe 0x00000000 0x3fff6000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffe 0x00000000 0x00000000 0x1f7fb140))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x3fffffff 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432eb 0x03ade68a 0x80000000 0x6290cba7))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd07 0x280a817c 0x80000000 0x680a817c))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x9738a3b9 0x00362622 0xf1cc4a61 0x97ccb747))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xf8a432eb 0x075bcd14 0xbade68b1 0x6290cba6))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x05800000 0x02490230 0xd8e5d3c0 0x370ee1f3))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000001 0xfffffffe 0x00000001 0x3fffffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x65432110 0x9abcdeef 0xedcba988 0x091a2b3b))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xf2a52100 0x5d87c790 0x1df4d840 0x014b66dc))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x091a2b3c))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x98800000 0x091907f6))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7fc00000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1ffc0000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3f804000 0x00000000 0xff004000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0x00000000 0x00000000 0x3ee08000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x32000000 0x43bc3a93 0xe2000000 0x7df8c849))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x0f810000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x00000000 0x47000000 0x33352bbb))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x00000000 0xc1000000 0x10361fff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x1ce2d631 0x190d2395 0x1ce2d631 0xa922268e))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x53800000 0x18de866c 0x90c0be40 0x3a1dde88))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xa0e00000 0x500b0058 0x325afd07 0x35986973))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x18b04000 0xc0001000 0x13f84023))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xf8000000 0x3597102f))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ff00100))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x1ff80080))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x80100000 0x7fefffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0ffc0040))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0xde200000 0x1f8a074f))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0xd7e00000 0x01c14910))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xf607f710 0x48407cf5 0xed42ee10 0x3e3b10de))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x4c800000 0x87ef758d 0x72481bc4 0x207aa1da))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x142f3200 0x877940e3 0x76af8800 0x1fce1074))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xffc00400 0x7c400001 0x10f383ff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffc00400 0x7c300001 0x10f7a1ff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x0000ffe0 0x3e200001 0x20efffff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0xffc00400 0x7c200001 0x10fbc0ff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x40200000 0x316bc1ff))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00400000 0x3ff8ffde 0x80014000 0x20f7ad93))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x8fd00400))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x5ff00000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xf4f5f6f8 0xffffffff 0xf0f1f2f4 0xffffffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x64f19040 0x0079dd2b 0xd5fa3890 0x00e2a64c))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xd03fbe00 0xff5012cf 0xd83c4cfc 0xf89e8882))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xc8cdd2d8 0xffffffff 0xf0f1f2f4 0xffffffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x07e01000 0x00fdf910 0x71010039 0x3d878f00))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x92e00000 0xfedddfd0 0x68906464 0xff827f3d))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x10000000 0xfc080be0 0x0aa00000 0x1f50a540))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x40000000 0x014b6cc4 0xa8015f90 0x0000fffd))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x52348000 0x0009235a 0x7f00972c 0x007fff69))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x0915b2e0 0x343fbad8 0x007f7f34))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x41010001 0x00004080 0x00004101 0x3fffff7f))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00808001 0x00000000 0x80000081 0xffffffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xbf000000 0xffdf5f7f 0xafe00000 0x200fffdf))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0x00000000 0xffff0000 0xffffffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x4f000000 0x00000000 0x30ffffff 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xfffe0001 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff8000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x40800000 0xffffbf80 0xcccd0000 0x00003dcc))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000019 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xc1400000 0xfffffffe 0x3fe00000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x80080000 0xffffffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x0fe01000 0x00000000 0x0ff00400))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x1fd01000 0xb3c00000 0xe02ffd91))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0ff70080))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3fc01000 0x6d8d7d84 0x3f8009d4))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0xdf904000 0xb3c00000 0x1fefe260))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xf5400000 0xc5d596db 0xc8170430 0xc040245b))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x10810000 0x00000000 0x10100400))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xebf10000 0x40200000 0xf3b9e000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xfffbf000 0x00000000 0xf0703c00))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x18610000 0x9e000001 0x0960ffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0xec188000 0x00cccccd 0xf42bcccd))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x1dccd1d3 0x9fe158e4 0xf301ddfc))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x10404000 0xbc8f5c29 0x0eeb3d70))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0xe1199999))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xd99c0000 0x0f7618cc))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xc0040000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ff80040))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xe003ffe0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x80080000 0xffffffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x0ffe0010))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x49a00000 0x1ffdfb66))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x34300000 0xfc3cb8f5))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xe6914b39 0x3511ff66 0x0152ca40 0x3fffed98))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x62b00000 0xfff8b70e 0x83000000 0x1f0ffb88))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x10013540 0x07418ba5 0x6b6f2c88 0x3ec0a1cf))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000100 0x00000000 0x0f138400))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xd0000000 0x00060913 0xab800000 0xef86f04d))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00012348 0xcd800000 0xffc1e048))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x28890000 0x246d4026 0xb7a58b10 0x11ffbae4))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x2ab00000 0x10fb84c9))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x7f113d00 0x003078e7 0xcb72351c 0x21f0ac4f))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10060090))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xbff40000 0xffffffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000001 0xfffffffe 0x00000001 0xfffffffe))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xf4f5f6f8 0x0b0a0907 0xf0f1f2f4 0x0f0e0d0b))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x7fffffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x64f19040 0x0079dd2b 0xd5fa3890 0x00e2a64c))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xd03fbe00 0x0a5a1bd7 0xd83c4cfc 0x07ac958e))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xc8cdd2d8 0x0b0a0907 0xf0f1f2f4 0x0f0e0d0b))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x07e01000 0xe11e7890 0x71010039 0x4284e3aa))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x92e00000 0x11125fd0 0x68906464 0x00827e11))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x10000000 0x3bc80be0 0x0aa00000 0x61ef4f95))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x40000000 0x014b6cc4 0xa8015f90 0x0000fffd))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x52348000 0x0009235a 0x7f00972c 0x007fff69))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x0915b2e0 0x343fbad8 0x007f7f34))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x41010001 0x00004080 0x00004101 0x3fffff7f))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00808001 0x00000000 0x80000081 0x7fffff7e))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xbf000000 0x005fdf80 0xafe00000 0x200fffdf))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0xfffffffe))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ffff 0x00000000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x4f000000 0x00000000 0x30ffffff 0xcf000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xfffe0001 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x40800000 0x0000bf7f 0xcccd0000 0x00003dcc))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x7fffffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000019 0xfffffff6 0x00000001 0xfffffffe))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xc1400000 0x3fbffffe 0x3fe00000 0xc01fffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x80080000 0x7ff7ffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x0fe01000 0x00000000 0x90300400))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x1fd01000 0xb3c00000 0x5fafc72f))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x901b0080))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x3fc01000 0x6d8d7d84 0x3f8009d4))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x5f504000 0xb3c00000 0x1fefe260))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xf5400000 0x459596db 0xc8170430 0x3fbfedf9))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x8e810000 0x00000000 0x10100400))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x3af10000 0x40200000 0x33d9e000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x000bf000 0x00000000 0x30903c00))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x18610000 0x9e000001 0xa7610001))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x3b188000 0x00cccccd 0x31f8999a))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x1dccd1d3 0x9fe158e4 0x36e336e0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x8f404000 0xbc8f5c29 0x0eeb3d70))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x1ee66666))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0xd99c0000 0x0f7618cc))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3ff80040))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x5ffbffe0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x80080000 0x7ff7ffff))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x90060010))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x49a00000 0x60020dce))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x34300000 0x0b4ac601))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xe6914b39 0x4bf3cbbc 0x0152ca40 0x40001268))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x62b00000 0x0008b70e 0x83000000 0x60f00df0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x10013540 0x82c2b190 0x6b6f2c88 0x413f5e8c))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000100 0x00000000 0x92d38400))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xd0000000 0x00060913 0xab800000 0x33684931))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00012348 0xcd800000 0x00c1df1c))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x28890000 0x246d4026 0xb7a58b10 0x11ffbae4))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x2ab00000 0x10fb84c9))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x7f113d00 0x003078e7 0xcb72351c 0x21f0ac4f))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x10060090))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xbff40000 0x400bffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0xfffffffe 0xffffffff 0xfffffffe 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0xffffff80 0xffffffff 0xffffff80 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffff00 0xffffffff 0xffffff00 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffff8000 0xffffffff 0xffff8000 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffff0000 0xffffffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0x80000000 0xffffffff 0x80000000 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0x00000000 0xfffffffe 0x00000000 0xfffffffe))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0xfffffffe 0xffffffff 0xfffffffe 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0x00000000 0xfffffff0 0x00000000 0xfffffff0))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x81008000 0x83028201 0x85048400 0x87068605))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x02010000 0x06050403 0x0a090800 0x0e0d0c0b))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x00800000 0x02820181 0x04840000 0x06860585))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x01000000 0x05040302 0x09080000 0x0d0c0b0a))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x00000000 0x01810080 0x00000000 0x05850484))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x00000000 0x03020100 0x00000000 0x0b0a0908))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x00000000 0x06040200 0x00000000 0x16141210))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x00000000 0x30201000 0x00000000 0xb0a09080))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0xfe02ff00 0x8103fc01 0xe0207f80 0x04fd54ab))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0x80bfc000 0x40ff007f 0x081fe000 0x3f552af8))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0x017f8000 0x81fe00ff 0x103fc000 0x7eaa55f0))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0xbfc00000 0xff007f80 0x1fe00000 0x552af808))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0x7f800000 0xfe00ff01 0x3fc00000 0xaa55f010))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0x00000000 0x7f80bfc0 0x00000000 0xf8081fe0))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0x00000000 0xff017f80 0x00000000 0xf0103fc0))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0x00000000 0xfe02ff00 0x00000000 0xe0207f80))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0xfe02ff00 0x8103fc01 0xe0207f80 0x04fd54ab))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0x00000000 0xf017f800 0x00000000 0x0103fc00))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00020000 0xfffffffe 0x24690000 0x01fffda8))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0x00800000 0xffffff80 0x1a400000 0x7fff6a09))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0x01000000 0xffffff00 0x34800000 0xfffed412))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0x80000000 0xffff8000 0x40000000 0xff6a091a))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0x00000000 0xffff0001 0x80000000 0xfed41234))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0x00000000 0x80008000 0x00000000 0x091a4000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x00000000 0x00010000 0x00000000 0x12348000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x00000000 0x00020000 0x00000000 0x24690000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x00020000 0xfffffffe 0x24690000 0x01fffda8))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x00000000 0x00100000 0x00000000 0x23480000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x8001fffc 0xfffd8000 0x01010002 0xfffffefe))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x007fff00 0xff600020 0x40400080 0xffffbf80))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x00fffe00 0xfec00040 0x80800100 0xffff7f00))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x7fff0000 0x60002000 0x40008000 0xffbf8040))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0xfffe0000 0xc0004000 0x80010000 0xff7f0080))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0x00000000 0x20007fff 0x80000000 0x80404000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x00000000 0x4000fffe 0x00000000 0x00808001))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x00000000 0x8001fffc 0x00000000 0x01010002))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x8001fffc 0xfffd8000 0x01010002 0xfffffefe))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x00000000 0x000fffe0 0x00000000 0x08080010))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0xfffffffe 0x00000000 0x00000002 0xfffffffe))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0xffffff80 0x0000003f 0x00000080 0xffffff80))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffff00 0x0000007f 0x00000100 0xffffff00))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffff8000 0x00003fff 0x00008000 0xffff8000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffff0000 0x00007fff 0x00010000 0xffff0000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x80000000 0x3fffffff 0x80000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x00000000 0x7fffffff 0x00000000 0x00000001))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0x00000000 0xfffffffe 0x00000000 0x00000002))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0xfffffffe 0x00000000 0x00000002 0xfffffffe))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0x00000000 0xfffffff0 0x00000000 0x00000010))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x0eb79a2a 0x8a432e9e 0x0001fffe 0x00020000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0xade68a80 0x90cba783 0x007fff80 0x00800000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x5bcd1500 0x21974f07 0x00ffff00 0x01000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0xe68a8000 0xcba783ad 0x7fff8000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0xcd150000 0x974f075b 0xffff0000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x80000000 0x83ade68a 0x80000000 0x00007fff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0x00000000 0x075bcd15 0x00000000 0x0000ffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x00000000 0x0eb79a2a 0x00000000 0x0001fffe))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x0eb79a2a 0x8a432e9e 0x0001fffe 0x00020000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x00000000 0x75bcd150 0x00000000 0x000ffff0))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xfffffffe 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0xffffff80 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0xffffff00 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0xffff8000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffff0000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0x80000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0x00000000 0xfffffffe 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0xfffffffe 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0x00000000 0xfffffff0 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3579bde0 0x2468acf1 0xfffffff6 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0x5e6f7800 0x1a2b3c4d 0xfffffd80 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0xbcdef000 0x3456789a 0xfffffb00 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0x6f780000 0x2b3c4d5e 0xfffd8000 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0xdef00000 0x56789abc 0xfffb0000 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000000 0x4d5e6f78 0x80000000 0xfffffffd))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x00000000 0x9abcdef0 0x00000000 0xfffffffb))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0x00000000 0x3579bde0 0x00000000 0xfffffff6))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3579bde0 0x2468acf1 0xfffffff6 0xffffffff))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0x00000000 0xabcdef00 0x00000000 0xffffffb0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x08060402 0x000e0c0a 0x18161412 0x001e1c1a))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x04030201 0x00070605 0x0c0b0a09 0x000f0e0d))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x0a080604 0x00000e0c 0x1a181614 0x00001e1c))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x05040302 0x00000706 0x0d0c0b0a 0x00000f0e))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x0e0c0a08 0x00000000 0x1e1c1a18 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x07060504 0x00000000 0x0f0e0d0c 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x03830282 0x00000000 0x07870686 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x00706050 0x00000000 0x00f0e0d0 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0x7f80bfc0 0x2040ff00 0xf8081fe0 0xc13f552a))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0x01fe02ff 0x008103fc 0xabe0207f 0xff04fd54))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0x00ff017f 0x004081fe 0x55f0103f 0xff827eaa))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0xfc01fe02 0x00008103 0x54abe020 0xffff04fd))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0xfe00ff01 0x00004081 0xaa55f010 0xffff827e))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0x8103fc01 0x00000000 0x04fd54ab 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0x4081fe00 0x00000000 0x827eaa55 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0x2040ff00 0x00000000 0xc13f552a 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0x7f80bfc0 0x2040ff00 0xf8081fe0 0xc13f552a))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0x04081fe0 0x00000000 0xf827eaa5 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x80008000 0x3fffffff 0x091a4000 0x007fff6a))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0xfe000200 0x00ffffff 0xa8246900 0x0001fffd))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0xff000100 0x007fffff 0xd4123480 0x0000fffe))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0xfffe0002 0x0000ffff 0xfda82469 0x000001ff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0xffff0001 0x00007fff 0xfed41234 0x000000ff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0xfffffffe 0x00000000 0x01fffda8 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x00000000 0x00fffed4 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x3fffffff 0x00000000 0x007fff6a 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x80008000 0x3fffffff 0x091a4000 0x007fff6a))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x07ffffff 0x00000000 0x000fffed 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x20007fff 0x3fff6000 0x80404000 0x3fffffbf))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x008001ff 0x00fffd80 0xfe010100 0x00fffffe))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x004000ff 0x007ffec0 0x7f008080 0x007fffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x80008001 0x0000fffd 0xfefe0101 0x0000ffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0xc0004000 0x00007ffe 0xff7f0080 0x00007fff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0xfffd8000 0x00000000 0xfffffefe 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x7ffec000 0x00000000 0x7fffff7f 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x3fff6000 0x00000000 0x3fffffbf 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x20007fff 0x3fff6000 0x80404000 0x3fffffbf))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x07ffec00 0x00000000 0x07fffff7 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3fffffff 0xc0000000 0x80000000 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0x00ffffff 0xff000000 0xfe000000 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0x007fffff 0xff800000 0xff000000 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0x0000ffff 0xffff0000 0xfffe0000 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0x00007fff 0xffff8000 0xffff0000 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000000 0xffffffff 0xfffffffe 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x80000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0xc0000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3fffffff 0xc0000000 0x80000000 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0xf8000000 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x83ade68a 0xe290cba7 0x00007fff 0x00008000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0x9e0eb79a 0xff8a432e 0x000001ff 0x00000200))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x4f075bcd 0xffc52197 0x000000ff 0x00000100))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0x2e9e0eb7 0xffff8a43 0x00000001 0x00000002))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0x974f075b 0xffffc521 0x00000000 0x00000001))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x8a432e9e 0xffffffff 0x00020000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0xc521974f 0xffffffff 0x00010000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0xe290cba7 0xffffffff 0x00008000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x83ade68a 0xe290cba7 0x00007fff 0x00008000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0xfc521974 0xffffffff 0x00001000 0x00000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0xffffffff 0x007fffff 0x00000000 0xff800000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0xffff0000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffffffff 0x00007fff 0x00000000 0xffff8000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x00000000 0x80000000 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0x3fffffff 0x00000000 0xc0000000 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0x07ffffff 0x00000000 0xf8000000 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0xf13579bd 0x002468ac 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0x789abcde 0x00123456 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0xacf13579 0x00002468 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0x56789abc 0x00001234 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0x2468acf1 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x12345678 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0x091a2b3c 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0x01234567 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0xffffffff 0x7fffffff 0xffffffff 0x7fffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0xffffffff 0x01ffffff 0xffffffff 0x01ffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffffffff 0x0001ffff 0xffffffff 0x0001ffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0xffffffff 0x00000001 0xffffffff 0x00000001))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0x7fffffff 0x00000000 0x7fffffff 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0xffffffff 0x7fffffff 0xffffffff 0x7fffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0x0fffffff 0x00000000 0x0fffffff 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x08060402 0x000e0c0a 0x18161412 0x001e1c1a))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x04030201 0x00070605 0x0c0b0a09 0x000f0e0d))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x0a080604 0x00000e0c 0x1a181614 0x00001e1c))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x05040302 0x00000706 0x0d0c0b0a 0x00000f0e))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x0e0c0a08 0x00000000 0x1e1c1a18 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x07060504 0x00000000 0x0f0e0d0c 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x03830282 0x00000000 0x07870686 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x01810080 0x03830282 0x05850484 0x07870686))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x00706050 0x00000000 0x00f0e0d0 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0x7f80bfc0 0x2040ff00 0xf8081fe0 0x413f552a))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0x01fe02ff 0x008103fc 0xabe0207f 0x0104fd54))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0x00ff017f 0x004081fe 0x55f0103f 0x00827eaa))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0xfc01fe02 0x00008103 0x54abe020 0x000104fd))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0xfe00ff01 0x00004081 0xaa55f010 0x0000827e))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0x8103fc01 0x00000000 0x04fd54ab 0x00000001))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0x4081fe00 0x00000000 0x827eaa55 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0x2040ff00 0x00000000 0x413f552a 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0x7f80bfc0 0x2040ff00 0xf8081fe0 0x413f552a))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0x04081fe0 0x00000000 0x0827eaa5 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x80008000 0x3fffffff 0x091a4000 0x007fff6a))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0xfe000200 0x00ffffff 0xa8246900 0x0001fffd))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0xff000100 0x007fffff 0xd4123480 0x0000fffe))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0xfffe0002 0x0000ffff 0xfda82469 0x000001ff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0xffff0001 0x00007fff 0xfed41234 0x000000ff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0xfffffffe 0x00000000 0x01fffda8 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x00000000 0x00fffed4 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x3fffffff 0x00000000 0x007fff6a 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x80008000 0x3fffffff 0x091a4000 0x007fff6a))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x07ffffff 0x00000000 0x000fffed 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x20007fff 0x3fff6000 0x80404000 0x3fffffbf))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x008001ff 0x00fffd80 0xfe010100 0x00fffffe))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x004000ff 0x007ffec0 0x7f008080 0x007fffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x80008001 0x0000fffd 0xfefe0101 0x0000ffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0xc0004000 0x00007ffe 0xff7f0080 0x00007fff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0xfffd8000 0x00000000 0xfffffefe 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x7ffec000 0x00000000 0x7fffff7f 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x3fff6000 0x00000000 0x3fffffbf 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x20007fff 0x3fff6000 0x80404000 0x3fffffbf))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x07ffec00 0x00000000 0x07fffff7 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3fffffff 0x40000000 0x80000000 0x7fffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0x00ffffff 0x01000000 0xfe000000 0x01ffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0x007fffff 0x00800000 0xff000000 0x00ffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0x0000ffff 0x00010000 0xfffe0000 0x0001ffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0x00007fff 0x00008000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000001 0xfffffffe 0x00000001))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x80000000 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0x40000000 0x00000000 0x7fffffff 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3fffffff 0x40000000 0x80000000 0x7fffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0x08000000 0x00000000 0x0fffffff 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x83ade68a 0x6290cba7 0x00007fff 0x00008000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0x9e0eb79a 0x018a432e 0x000001ff 0x00000200))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x4f075bcd 0x00c52197 0x000000ff 0x00000100))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0x2e9e0eb7 0x00018a43 0x00000001 0x00000002))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0x974f075b 0x0000c521 0x00000000 0x00000001))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x8a432e9e 0x00000001 0x00020000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0xc521974f 0x00000000 0x00010000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x6290cba7 0x00000000 0x00008000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x83ade68a 0x6290cba7 0x00007fff 0x00008000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x00000001 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x0c521974 0x00000000 0x00001000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0x01000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0xffffffff 0x007fffff 0x00000000 0x00800000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x00010000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffffffff 0x00007fff 0x00000000 0x00008000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0x00000001))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x00000000 0x80000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0x3fffffff 0x00000000 0x40000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0x40000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0x07ffffff 0x00000000 0x08000000 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0xfffffffd 0x7fffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0xf13579bd 0x002468ac 0xffffffff 0x01ffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0x789abcde 0x00123456 0xffffffff 0x00ffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0xacf13579 0x00002468 0xffffffff 0x0001ffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0x56789abc 0x00001234 0xffffffff 0x0000ffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0x2468acf1 0x00000000 0xffffffff 0x00000001))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x12345678 0x00000000 0xffffffff 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0x091a2b3c 0x00000000 0x7fffffff 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0xfffffffd 0x7fffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0x01234567 0x00000000 0x0fffffff 0x00000000))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffff00 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000080 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffff00ff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00008000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00800000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00ffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffff00 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000080 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffff00ff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00008000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00800000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffff00 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000080 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffff00ff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xff00ffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00800000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0x00ffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffff00)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000080)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffff00ff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xff00ffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 2))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 1))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 2))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 2))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 1))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 2))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 2))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 2))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 2))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 1))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffff00 0xffffffff 0xffffffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000080 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffff00ff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00008000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00800000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00ffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffff00 0xffffffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000080 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffff00ff 0xffffffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00008000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00800000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff)) (i32.const 2))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000)) (i32.const 1))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffff00 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000080 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffff00ff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xff00ffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00800000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0x00ffffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffff00)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000080)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffff00ff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xff00ffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 2))
@@ -0,0 +1,2279 @@
+;; Generated lane by lane against V8, covering i8x16 operations
+
+(module
+  (func (export "i8x16.abs") (param v128) (result v128)
+    (i8x16.abs (local.get 0))
+  )
+  (func (export "i8x16.neg") (param v128) (result v128)
+    (i8x16.neg (local.get 0))
+  )
+  (func (export "i8x16.popcnt") (param v128) (result v128)
+    (i8x16.popcnt (local.get 0))
+  )
+  (func (export "i8x16.add") (param v128 v128) (result v128)
+    (i8x16.add (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.add_sat_s") (param v128 v128) (result v128)
+    (i8x16.add_sat_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.add_sat_u") (param v128 v128) (result v128)
+    (i8x16.add_sat_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.sub") (param v128 v128) (result v128)
+    (i8x16.sub (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.sub_sat_s") (param v128 v128) (result v128)
+    (i8x16.sub_sat_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.sub_sat_u") (param v128 v128) (result v128)
+    (i8x16.sub_sat_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.min_s") (param v128 v128) (result v128)
+    (i8x16.min_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.min_u") (param v128 v128) (result v128)
+    (i8x16.min_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.max_s") (param v128 v128) (result v128)
+    (i8x16.max_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.max_u") (param v128 v128) (result v128)
+    (i8x16.max_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.avgr_u") (param v128 v128) (result v128)
+    (i8x16.avgr_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.eq") (param v128 v128) (result v128)
+    (i8x16.eq (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.ne") (param v128 v128) (result v128)
+    (i8x16.ne (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.lt_s") (param v128 v128) (result v128)
+    (i8x16.lt_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.lt_u") (param v128 v128) (result v128)
+    (i8x16.lt_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.gt_s") (param v128 v128) (result v128)
+    (i8x16.gt_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.gt_u") (param v128 v128) (result v128)
+    (i8x16.gt_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.le_s") (param v128 v128) (result v128)
+    (i8x16.le_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.le_u") (param v128 v128) (result v128)
+    (i8x16.le_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.ge_s") (param v128 v128) (result v128)
+    (i8x16.ge_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.ge_u") (param v128 v128) (result v128)
+    (i8x16.ge_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.narrow_i16x8_s") (param v128 v128) (result v128)
+    (i8x16.narrow_i16x8_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.narrow_i16x8_u") (param v128 v128) (result v128)
+    (i8x16.narrow_i16x8_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.swizzle") (param v128 v128) (result v128)
+    (i8x16.swizzle (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.shl") (param v128 i32) (result v128)
+    (i8x16.shl (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.shr_s") (param v128 i32) (result v128)
+    (i8x16.shr_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.shr_u") (param v128 i32) (result v128)
+    (i8x16.shr_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.all_true") (param v128) (result i32)
+    (i8x16.all_true (local.get 0))
+  )
+  (func (export "i8x16.bitmask") (param v128) (result i32)
+    (i8x16.bitmask (local.get 0))
+  )
+)
+
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x01010101 0x01010101 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x01017f80 0x407f0200 0x10103f40 0x7e7e5655))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7f010101 0x12348000 0x0001022c))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000102 0x7f024000 0x00808001 0x7f01017f))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7f010101 0x80000000 0x00000001 0x01010101))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075b3315 0x3b21694f 0x00000101 0x00010000))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x01010101 0x7f010101 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x66442210 0x12345678 0x01010105 0x01010101))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3f400000 0x40200000))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x01800000 0x7f400000 0x7f7f3762))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0x41000000 0x40200000))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x50150207 0x30150207 0x4f000000 0x31000001))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0x41800000 0x3d343433))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7f100000 0x00000000 0x7f080000))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3f080000 0x00000000 0x40040000))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x78007564 0x7e371c3c 0x75701a2b 0x80001268))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x01200000 0x41210101 0x00100000 0x3f200000))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x01200000 0x41110101 0x606f3d00 0x431f581c))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x41200000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x01010101 0x01010101 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfdfeff00 0xf9fafbfc 0xf5f6f7f8 0xf1f2f3f4))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x01ff8180 0xc07f0200 0x10f0c140 0x7e8256ab))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00ff0000 0x81010101 0xeecc8000 0x0001022c))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xc0000102 0x81024000 0x008080ff 0x81010181))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x81010101 0x80000000 0x000000ff 0x01010101))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf9a533eb 0x3bdf69b1 0x00000101 0x00ff0000))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x01010101 0x81010101 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x66442210 0xeeccaa88 0x01010105 0x01010101))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0xc1400000 0x40e00000))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x81800000 0x01800000 0x81400000 0x81813762))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x000000ff 0xc1000000 0x41000000 0xc0e00000))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xb0ebfe07 0x30ebfe07 0xb1000000 0x310000ff))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xb1800000 0xb98000c0 0x41800000 0xc3343433))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x81100000 0x00000000 0x81080000))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xc1080000 0x00000000 0x40fc0000))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x78008b64 0x82c91cc4 0x75901ad5 0x8000ee98))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x01200000 0xbf210101 0x00f00000 0x3f200000))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x01200000 0xbf110101 0xa06fc300 0xbd1fa81c))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x41200000 0x00000000 0xc0f40000))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x08080808 0x08080808 0x08080808 0x08080808))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x02010100 0x03020201 0x03020201 0x04030302))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x08010701 0x01020700 0x04010602 0x02060404))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x07080808 0x02030100 0x00080704))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x01000807 0x07070200 0x00010101 0x07080807))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x07080808 0x01000000 0x00000001 0x08080808))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x03050503 0x04020505 0x00000808 0x00010000))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x08080808 0x07080808 0x00000000 0x01000000))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x04050604 0x02030404 0x08080807 0x08080808))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x01000000 0x06020000 0x02010000))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x07010000 0x08010000 0x07020000 0x07070405))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x06000000 0x07000000 0x01010000))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x02030106 0x03030106 0x05000000 0x06000001))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x05010000 0x04010001 0x07010000 0x05040405))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x01000000))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x07040000 0x00000000 0x07050000))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x06050000 0x00000000 0x02010000))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x02000504 0x06050404 0x04030504 0x01000203))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x08030000 0x02070808 0x00010000 0x03030000))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x08030000 0x02070808 0x02030500 0x03040304))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x07030000 0x00000000 0x01020000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfefefefe 0xfefefefe 0xfefefefe 0xfefefefe))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x020100ff 0x06050403 0x0a090807 0x0e0d0c0b))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfefefefe 0x7efefefe 0xffffffff 0x7fffffff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x02038080 0x47870304 0xfb1a48c8 0x918cb761))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9dbedff0 0x193a5b7c 0x0a090803 0x0e0d0c0b))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xfe02fe00 0x8002fc00 0xe0207e80 0x04fc54aa))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff027f80 0xbf80fdff 0x0244bfc0 0x827da829))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0x2fd03fc0 0x429eaa55))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00020000 0xfefefefe 0x24680000 0x00fefca8))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4001fffe 0xfefdbfff 0x12b40001 0x7ffefd53))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f810000 0x7e7fffff 0x91f48000 0x7f7ec772))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x8000fefc 0xfefc8000 0x00000002 0xfefefefe))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xbffffefd 0xfffec000 0x00808002 0x7efefe7e))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000ffff 0xbefec000 0xbf808001 0xbf1fff7f))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xfefefefe 0x00000000 0x00000002 0xfefefefe))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x865acc14 0x4521974f 0x0000ff00 0xff00ffff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xcf1401f8 0x501502f9 0x4f000001 0xceffff00))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0eb69a2a 0x8a422e9e 0x0000fefe 0x00020000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x065acc14 0x4420964e 0x0000ffff 0x80010000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x56dbcd15 0x0ca1978f 0xbf80ffff 0x3dcdcccd))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfefefefe 0xfefefefe 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x99bbddef 0x91335577 0xfffffffb 0x7fffffff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x3478bce0 0x2468acf0 0xfefefef6 0xfefefefe))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0x3ebffffb 0xbf1fffff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x91245678 0xfffffffb 0x7ef7ffff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x7e800000 0x80400000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x7f800000 0xbe800000 0x3f9fc99e))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x3fc00000 0x80240000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xfe000000 0xfe000000 0xfe800000 0xfefe923c))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0x3e800000 0x3ec00000 0xbf9fc99e))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0780759c 0x7db7e43c 0x0a30e62b 0xff7fdb06))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000002 0x7e000000 0x7e000000 0x80400000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502fa 0x0f1502f9 0x0e000000 0x0f200001))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00001 0x80dfffff 0xbf100000 0x01000000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xa02a04f2 0xa02a04f2 0x9e000000 0x9e000002))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9f9502f9 0x17950239 0x0e800000 0x0cccccce))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x4ff502f9 0x110401f8 0xaf913d00 0x12e158e5))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9e000000 0x8e000080 0x7e000000 0x7a98989a))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x06600040 0xbf800000 0x7dd8cccd))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff80000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xfee00000 0x00000000 0xfef00000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbee80000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0x7eefffff 0xffffffff 0x7ef7ffff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ef00000 0x00000000 0x80080000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xbd2fe43c 0x8b70e62b 0x40041268))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x46fe0504 0x0b0a0908 0xcf120d0c))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x1000ea38 0xfc6ec878 0x16e0cc56 0x000024d0))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x87e0759c 0xbf16e33b 0x8b80e62b 0x41e01268))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x8701f41c 0xbeb8e23c 0x7b8025eb 0x027ebcbd))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xfec00000 0x82befefe 0x00200000 0x82c00000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xfec00000 0x82cefefe 0x60a13d00 0x04c158e4))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe10000 0xc0defefe 0x12448000 0xc1dffed4))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xfec00000 0x82defefe 0xc0227a00 0x86c2b0c8))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x00cfffff 0x60913d00 0x83ed58e4))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x3fe0fffe 0xc0edbfff 0x6011bd01 0xc2e05763))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x7ec00000 0x00000000 0x80180000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x3fe00000 0x00000001 0x3f0bffff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfefefefe 0xfefefefe 0xfefefefe 0xfefefefe))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x020100ff 0x06050403 0x0a090807 0x0e0d0c0b))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfefefefe 0x7efefefe 0xffffffff 0x80ffffff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x02037f80 0x47870304 0xfb1a48c8 0x917fb761))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9dbedff0 0x193a5b7c 0x0a090803 0x0e0d0c0b))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xfe027f80 0x7f80fc00 0xe0207e80 0x807f807f))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff027f80 0x7f80fdff 0x0244bfc0 0x827da829))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0x2fd03fc0 0x807faa55))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00020000 0x7ffefefe 0x24688000 0x00fefca8))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4001fffe 0x7ffdbfff 0x12b48001 0x7ffefd53))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f810000 0x7e80ffff 0x7ff48000 0x7f7ec780))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x7f00fefc 0x7ffc8000 0x00808002 0x7ffefe7f))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7ffffefd 0xfffec000 0x00808002 0x7efefe7e))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000ffff 0x7ffec000 0xbf808001 0x7f1fff7f))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7ffefefe 0x80000000 0x00000002 0xfefefefe))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x7f5acc14 0x8021974f 0x0000ff00 0xff00ffff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7f1401f8 0x801502f9 0x4f000001 0xceffff00))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0e7f9a2a 0x8a42807f 0x0000fefe 0x00020000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x065acc14 0x4420964e 0x0000ffff 0x80010000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x56dbcd15 0x0ca1977f 0xbf80ffff 0x3dcdcccd))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xfefefefe 0x7ffefefe 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x99bbddef 0x7f335577 0xfffffffb 0x80ffffff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x8080bce0 0x24687f7f 0xfefefef6 0xfefefefe))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0x3ebffffb 0xbf1fffff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x7f245678 0xfffffffb 0x7ef7ffff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x7e800000 0x80400000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0x80800000 0x7f800000 0x3f7fc99e))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x3fc00000 0x80240000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xfe800000 0x7f800000 0x7f7f9280))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0x3e800000 0x3ec00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0780759c 0x7db7e43c 0x0a30e62b 0xff7fdb06))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000002 0x7e000000 0x80000000 0x7f400000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502fa 0x0f1502f9 0x0e000000 0x0f200001))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00001 0x7fdfffff 0xbf100000 0x01000000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7f2a04f2 0xa02a04f2 0x7f000000 0x9e000002))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x7f9502f9 0x17950239 0x0e800000 0x0cccccce))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x4ff502f9 0x110401f8 0x7f913d00 0x12e158e5))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x7f800000 0x7f80007f 0x80800000 0x7a98989a))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x06800040 0xbf800000 0x7dd8cccd))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff80000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7fe00000 0x00000000 0x7ff00000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7fe80000 0x00000000 0x3ffc0000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0x7eefffff 0xffffffff 0x7ef7ffff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ef00000 0x00000000 0x80080000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7f2fe43c 0x8b70e62b 0x80041268))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x46fe0504 0x0b0a0908 0xcf120d0c))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80007f80 0x7f6ec878 0x807fcc56 0x8000247f))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x87e0759c 0x7f16e33b 0x8b7fe62b 0x80e01268))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x87017f80 0x7fb8e23c 0x807f25eb 0x807ebc7f))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xfec00000 0x7fbefefe 0x00200000 0x82c00000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xfec00000 0x7fcefefe 0x60a13d00 0x04c158e4))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe10000 0x7fdefefe 0x12448000 0xc1dffed4))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xfec00000 0x7fdefefe 0x7f807a00 0x7fc27fc8))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x00cfffff 0x60913d00 0x7fed58e4))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x3fe0fffe 0x7fedbfff 0x6080bd01 0x7fe05763))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x80c00000 0x00000000 0x7f180000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80e00000 0x00000001 0x3f0bffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff038080 0x4787ff04 0xfb1a48c8 0x918cb761))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9dbedff0 0x193a5b7c 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff02feff 0x80ffff00 0xff207eff 0xfffcffaa))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff027f80 0xbfffffff 0xff44bfc0 0x82ffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0xffd03fc0 0xff9eaa55))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00020000 0xfeffffff 0x2468ff00 0x00ffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4001fffe 0xfeffffff 0x12b4ff01 0x7fffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f810000 0xffffffff 0x91f48000 0x7fffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x8000ffff 0xfeffff00 0x00ffff02 0xfefffffe))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xbfffffff 0xfffec000 0x00808002 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000ffff 0xbefec000 0xbf808001 0xbfffff7f))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xfeffffff 0xff000000 0x00000002 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x86ffffff 0xff21974f 0x0000ffff 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xcfffffff 0xff1502f9 0x4f000001 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0eb6ff2a 0xff42ff9e 0x0000ffff 0x00020000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0x80010000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x56dbcd15 0xffa1978f 0xbf80ffff 0x3dcdcccd))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xfeffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x91ffffff 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x2468acf0 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x91ff5678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xff000000 0x7eff0000 0xff400000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0xbeff0000 0xff9fc99e))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbff80000 0x3fc00000 0xff240000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xfeff0000 0xffff0000 0xfeff0000 0xfefeffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0xff800000 0xffc00000 0xbf9fc99e))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff80759c 0xffb7e43c 0xffffe62b 0xff7fdbff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000002 0x7e000000 0xff000000 0x80400000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502fa 0xff1502f9 0xff000000 0xff200001))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00001 0x80dfffff 0xbf100000 0xffff0000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xa02a04ff 0xff2a04ff 0x9e000000 0xff000002))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9f9502f9 0xff9502ff 0xff800000 0xffccccce))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xfff502f9 0xffffffff 0xaf913d00 0xffe158e5))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x9eff0000 0x8eff0080 0xffff0000 0x7affffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0xffff0040 0xbf800000 0x7dd8cccd))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff80000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xfeff0000 0x00000000 0xfeff0000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xbeff0000 0x00000000 0xfffc0000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7eff0000 0x00000000 0xff080000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0xbdffe43c 0x8b70e62b 0xff041268))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x46fe0504 0x0b0a0908 0xcf120d0c))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff00eaff 0xfc6eff78 0xffe0ff56 0xff0024d0))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe0759c 0xbfffffff 0x8b80e62b 0xffe01268))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff01f4ff 0xbeb8ff3c 0xff80ffeb 0xff7ebcbd))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0x82ffffff 0x00200000 0xffff0000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff0000 0x82ffffff 0x60a13d00 0xffff58e4))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe10000 0xc0ffffff 0x12448000 0xc1fffed4))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff0000 0x82ffffff 0xc0ff7a00 0x86ffb0ff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0xffffffff 0x60913d00 0x83ed58e4))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffe0fffe 0xc0ffffff 0x60ffbd01 0xc2ffffff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x80180000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0xffe00000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x01010101 0x01010101 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf9a533eb 0x3bdf69b1 0x00000101 0x00ff0000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0xffffffff 0x7fffffff))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x04018280 0xc7850704 0x1bfaca48 0x8d9063b7))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x69462310 0xf5d2af8c 0x0c0b0a0d 0x100f0e0d))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff007f80 0xc182ff01 0xdedcbfc0 0x827fac81))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0xc081fe00 0xb1503fc0 0xc25eaa55))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xc0010102 0x00013fff 0x12b400ff 0x8100ff55))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x81810000 0x807fffff 0x93748000 0x81803536))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xc10100ff 0xfffec000 0x00808000 0x80000080))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffd 0x40fec000 0x41808001 0x3fdfff7f))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432ea 0xbbdf69b1 0x00000102 0xfffeffff))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd06 0xb0ebfe07 0xb1000001 0x30fffffe))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x085cce16 0x46229850 0x0000ffff 0x80010000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xb8dbcd15 0x7ea1970f 0x4180ffff 0xc3353433))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x6543210f 0x6dcba987 0x01010105 0x81010101))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x92345678 0xc03ffffb 0x3fdfffff))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x93445678 0xfffffffb 0x8007ffff))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x81800000 0x81800000 0xc0000000 0x41a13762))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x41080000 0x3fc00000 0x001c0000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f8000ff 0xc0800000 0xc0c00000 0x3f5fc99e))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xf7808b64 0x81491cc4 0xf4501ad5 0xff7fb736))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xb0ebfe08 0x6febfe07 0x70000000 0x712000ff))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x01200001 0xfe210101 0xbff00000 0x7f400000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x019502f9 0x899502b9 0x90800000 0x92343434))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x513502f9 0x8f2603fa 0xef6fc300 0x8c1fa81d))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0xbdcccccd))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x88a00040 0xbf800000 0xfdc0cccd))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x81100000 0x00000000 0x01080000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40f80000 0x00000000 0xbff40000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x01010101 0x80f10101 0x01010101 0x80f90101))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x78008b64 0xc1c11cc4 0x75901ad5 0x4004ee98))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfdfeff00 0x38f2fbfc 0xf5f6f7f8 0xb1f6f3f4))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8920759c 0x3d58e53d 0x8b60e62b 0xbf201268))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x89fff61c 0x3eb6e63c 0x9b60a76b 0xfe826813))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00f00000 0xa07fc300 0x7effa81c))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffdf0000 0xc2e00000 0xeedc8000 0xc1e1022c))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x820fffff 0x60913d00 0x03d558e4))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xbfe00102 0xc2f13fff 0x6011bdff 0xc4e25965))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x81010101 0x3fe00000 0x000000ff 0x410d0101))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x01010101 0x01010101 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xf9a533eb 0x3bdf69b1 0x00000101 0x00ff0000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0xffffffff 0x7fffffff))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0401827f 0xc77f0704 0x1bfaca48 0x7f9063b7))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x69462310 0xf5d2af8c 0x0c0b0a0d 0x100f0e0d))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff007f80 0xc182ff01 0xdedc7fc0 0x827fac7f))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x7f81fe00 0xb1503fc0 0xc25eaa55))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xc0010102 0x00013fff 0x127f00ff 0x8100ff80))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x817f0000 0x7f7fffff 0x93748000 0x81803536))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xc10100ff 0x7ffec000 0x00808000 0x7f00007f))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffd 0x40fec000 0x41808001 0x3fdfff7f))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432ea 0xbbdf69b1 0x00000102 0xfffeffff))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd06 0xb0ebfe07 0xb1000001 0x30fffffe))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x085cce16 0x80229850 0x0000ffff 0x7f010000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xb87fcd15 0x807f970f 0x417fffff 0xc3353433))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x6543210f 0x6dcba987 0x01010105 0x81010101))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x7f345678 0xc03ffffb 0x3fdfffff))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x93445678 0xfffffffb 0x8007ffff))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x817f0000 0x817f0000 0xc0000000 0x80a13762))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80080000 0x3fc00000 0x001c0000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f8000ff 0xc0800000 0x7fc00000 0x3f5fc99e))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f808b64 0x81801cc4 0x7f801ad5 0x7f7fb780))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xb0ebfe08 0x6febfe07 0x80000000 0x712000ff))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x01200001 0xfe210101 0xbff00000 0x7f400000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x017f02f9 0x897f02b9 0x7f7f0000 0x92343434))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x513502f9 0x8f2603fa 0xef6fc300 0x8c1fa81d))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x7fcccccd))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x7fa00040 0xbf800000 0xfdc0cccd))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x81100000 0x00000000 0x80080000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40f80000 0x00000000 0x7ff40000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x01010101 0x7ff10101 0x01010101 0x7ff90101))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x78008b64 0xc1c11cc4 0x75901ad5 0x4004ee98))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfdfeff00 0x38f2fbfc 0xf5f6f7f8 0xb1f6f3f4))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x8920759c 0x3d58e53d 0x8b60e62b 0xbf201268))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x89fff61c 0x3e7fe63c 0x9b60a76b 0xfe826813))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00f00000 0xa07fc300 0x80ffa81c))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffdf0000 0xc2e00000 0xeedc7f00 0xc1e1022c))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x7f0fffff 0x60913d00 0x03d558e4))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xbfe00102 0xc2f13fff 0x60117fff 0xc4e25980))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x81010101 0x3fe00000 0x000000ff 0x410d0101))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0xffffffff 0x7fffffff))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00010000 0x00000004 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff007f80 0x00000000 0xde0000c0 0x82000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x0081fe00 0xb1003fc0 0x005eaa55))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00010000 0x00013fff 0x12000000 0x00000055))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x007fffff 0x00008000 0x00803536))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00fec000 0x00808000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffd 0x40fec000 0x00808001 0x3fdfff7f))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x78a432ea 0x00000000 0x00000000 0xfffeffff))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x2feafd06 0x00000000 0x00000001 0x30fffffe))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x46000000 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000cd15 0x7e00970f 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x6543210f 0x6dcba987 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x00345678 0xc03ffffb 0x3fdfffff))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x00005678 0xfffffffb 0x8007ffff))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x41000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x41000000 0x3fc00000 0x001c0000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800000 0xc0800000 0x00c00000 0x3f5fc99e))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00800000 0x81490000 0x00500000 0x007fb736))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x70000000 0x00200000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000001 0x00000000 0xbf000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x010002f9 0x890002b9 0x00000000 0x92000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x000002f9 0x8f000000 0x00000000 0x8c000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x00cccccd))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0x00000040 0xbf800000 0x00c0cccd))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x01000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x40000000 0x00000000 0x00f40000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00c10000 0x00000000 0x40040000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x38f20000 0x00000000 0xb1000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000759c 0x3d000000 0x8b60e62b 0x00001268))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0000001c 0x3e00003c 0x0060a700 0x00000013))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7e000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffdf0000 0x00000000 0x00000000 0xc1000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0x000fffff 0x60913d00 0x03d558e4))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xbfe00000 0x00003fff 0x60110000 0x00000065))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x3fe00000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000cd00 0xc5009700 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x80ffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff010180 0x0781fe00 0xf00a09c0 0x820eaa0c))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x07060504 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff010080 0x4081feff 0xf01080c0 0x82ffaad4))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff000080 0x8081fe00 0xf0c000c0 0x8220aa00))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000fffe 0x7ffec0ff 0x00808000 0x00fffed4))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00800000 0xff80ffff 0x12c08000 0x00ffc99e))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x40fffffe 0x80fec000 0x00808001 0xffffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000fffe 0x3ffec000 0xbf808000 0x40ffff00))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x07ffcdff 0x80009700 0x0000ffff 0xffffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x50fffff9 0x800000f9 0x00000000 0xcfffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffcdff 0xc5ff97ff 0x0000ffff 0x80000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0780cd00 0xc5809740 0xbf80ffff 0x00cccccd))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12ffffff 0xfffffffb 0x80ffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x80000000 0xffc0fffb 0xc0ffffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x12f00000 0xfffffffb 0xfff8ffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00800000 0x80800000 0x3fc00000 0xc020c99e))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80f80000 0x00c00000 0xc0040000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00800000 0xff800000 0xbfc00000 0x4020c99e))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8880009c 0xff80e400 0x8bc0e600 0x8000c99e))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000000f9 0xd00000f9 0xbf000000 0xcf000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x3fdfffff 0xbf000000 0xc1e00000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f8000f9 0xd08000f9 0xbf800000 0xcfcccccd))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe000f9 0xd0effff9 0x4f910000 0xcfe100e4))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00800000 0x00800000 0xbf800000 0x80cccccd))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00800000 0xbf800000 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00f00000 0x00000000 0x80f80000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0xc0f80000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xfff0ffff 0xffffffff 0xfff8ffff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800009c 0x3ff8e400 0x8b00e600 0x80000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x07f80000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x88e0009c 0x41dfe4ff 0x8b10e600 0x80e00000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x88007580 0x4081e400 0x8b10e6c0 0x8000aa55))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00910000 0xc1e000e4))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00108000 0xc1e0fed4))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0xbfe0ffff 0x00910000 0x40e100e4))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffe0fffe 0x41efc0ff 0x00808000 0x43e1ffe4))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00ffffff 0x80e00000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x03010100 0x07060500 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x4081fe00 0x12103f00 0x007eaa55))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x40000000 0x3f100000 0x82200000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x7ffec000 0x00348000 0x00fffe7f))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00010000 0x7f800000 0x12340000 0x007fc99e))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x4000fffe 0x7f000000 0x00000001 0x7fffff7f))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0x00000000 0x40200000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0x80000000 0x00000001 0x00010000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0x80000000 0x00000000 0xcf000001))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bcd15 0x7f21974f 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x075b0000 0x47210040 0x00000000 0x00010000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x12000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x12340000 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0x7f200000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3f000000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x3f000000 0x7f000000 0x40200000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f000000 0x7e370000 0x7f700000 0x7f001268))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000001 0x3f000000 0x4f000000 0x40000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x3f000000 0x00000000 0x40200000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f150000 0x47150040 0x4f000000 0x3d000001))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x50150000 0x411502f9 0x4f000000 0x43000001))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x3d000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x47800000 0x00000000 0x3d0c0000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7f000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff00000 0x00000000 0x7f040000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x3f370000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x07060000 0x00000000 0x0f040000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x88000000 0x4137e43c 0x00100000 0x80000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x88007580 0x4037e400 0x8b103f2b 0x80001255))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0x43e00000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x41dfffff 0x00100000 0x00e00000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x41e00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000000 0x41efc000 0x00803d00 0x43e1587f))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075b0015 0x0021004f 0x00000000 0x00010000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x03027f00 0x40060504 0x0b103f08 0x0f7e0d55))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x03020100 0x12345678 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00017f00 0x7fffff00 0x12343f00 0x007efe55))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00017f00 0x40000000 0x3f103f00 0xc07e0055))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40010000 0x7fffff00 0x12348001 0x7fffff7f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f010000 0x7fff0000 0x7f340000 0x7f7ffed4))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7f00ffff 0x7f000000 0x00000001 0x7fffff7f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x40000001 0x7f000000 0x00000001 0x7f20007f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x7f5bff15 0xc521004f 0x00000001 0x00010000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7f1502ff 0xd0150200 0x4f000001 0xff000001))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x075bff15 0x7f21ff4f 0x00000000 0x00010000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f5b0015 0x4721004f 0x00000000 0x3d010000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x7f345678 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x7f000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x12345678 0x3fff0000 0xff200000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7f345678 0x00000000 0x7fff0000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f000000 0xff000000 0x7fc00000 0x7f7f0000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3f000000 0x3f000000 0xc0200000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f000001 0x3f000000 0x7f000000 0x7f7f0000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f007500 0x7e37003c 0x7f70002b 0x7f7f1268))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x50150201 0x3f150200 0x4f000000 0x40200001))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000001 0x41000000 0x00100000 0x40200000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x50150200 0x47150240 0x4f000000 0x3d000001))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x50150200 0x411502ff 0x60003d00 0x43005801))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f000000 0x47000040 0x00000000 0x3d000000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f000000 0x47e00040 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7f000000 0x00000000 0x7f000000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff80000 0x00000000 0x7f040000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x7fff0000 0x00000000 0x7fff0000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00007500 0x7e37003c 0x0070002b 0xc0041268))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x3f060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xff007500 0x7e37ff3c 0x0070002b 0xc1001268))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f9c 0x7e37fe3c 0xf0703f2b 0x827e1268))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60103d00 0x43e15800))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12340000 0x00ff0000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x41ef0000 0x60003d00 0x430c5800))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x40000000 0x7ffeff00 0x60913d01 0x7fff587f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7f000000 0xbf000000 0x00000001 0x400c0000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff027f80 0x4081fe04 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff017f80 0x7fffffff 0xf03480c0 0x82fffed4))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff017f80 0x8081fe00 0xf0c03fc0 0xc07eaa55))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4001fffe 0x7fffffff 0x12808001 0x7fffffd4))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xffffffff 0x7fc08000 0x7ffffed4))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80fec000 0x00808001 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x4000fffe 0x7ffec000 0xbf808001 0x7fffff7f))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x7fffffff 0xc521974f 0x0000ffff 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7fffffff 0xd01502f9 0x4f000001 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xc5ffffff 0x0000ffff 0x80010000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f80cd15 0xc580974f 0xbf80ffff 0x3dcccccd))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x7fffffff 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x9abcdef0 0x80345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x9abcdef0 0x7ff05678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0xc07fc99e))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x80f80000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f800001 0xff800000 0xbfc00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8880759c 0xff80e43c 0x8bc0e62b 0x807fc99e))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0xbf000000 0xcf200001))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00001 0x41dfffff 0xbf100000 0xc1e00000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x508002f9 0xd08002f9 0xbf800000 0xcfcccccd))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe002f9 0xd0efffff 0x60913d00 0xcfe158e4))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x80cccccd))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x4f800000 0xbfe00040 0xbf800000 0x40cccccd))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x80f80000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x7ff80000 0x00000000 0xc0f80000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7ef8e43c 0x8b70e62b 0xc0041268))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x3ff80504 0x0b0a0908 0xc00e0d0c))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe0759c 0x7edfffff 0x8b70e62b 0xc1e01268))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f9c 0x7e81fe3c 0xf070e6c0 0x827eaa68))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0xc1e158e4))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffe00000 0x7fffffff 0x12348000 0xc1fffed4))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffe00000 0xbfefffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffe0fffe 0x7ffeffff 0x60918001 0x7fffffe4))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0xbfe00000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x80808080 0x80808080 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x042e670b 0x63114c28 0x00008080 0x00010000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x81818080 0x83838282 0x85858484 0x87878686))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xbfffffff 0x80808080 0xc0808080))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x81024040 0x24448202 0x7e0d2464 0x49465c31))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x4f5f7078 0x0d1d2e3e 0x85858482 0x87878686))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x80014040 0x60c0ff80 0x81226060 0x41bfd495))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x80014040 0x60417f00 0x98682060 0xa14f552b))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x2001807f 0x7fffe080 0x095a8001 0x40ffffaa))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x40410000 0xbfc08080 0x497a4000 0x40bfe4b9))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x6080ffff 0x807f6000 0x00404001 0xbfffffbf))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x20008080 0x5f7f6000 0x60404001 0x60908040))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x43ade68a 0xa3114c28 0x00008080 0x80808080))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x688a81fc 0xa80b017d 0x28000001 0xe7808080))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x83ade68a 0xa290cba7 0x00008080 0x40010000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x2b6e670b 0x86514c48 0x60408080 0x1f676667))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xcddeeff8 0x499aabbc 0x8080807e 0xc0808080))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x80808080 0x40808080 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x4d5e6f78 0x491a2b3c 0x9fe0807e 0xe0908080))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x4d5e6f78 0x49922b3c 0x8080807e 0xbffc8080))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x40400000 0xc0400000 0x5fc00000 0xa050654f))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x607c0000 0x20600000 0xc0120000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x40400001 0x9f400000 0x9f600000 0x6050654f))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x84403b4e 0xbf5c721e 0x85987316 0x80406e83))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x280b017d 0x880b017d 0x87000000 0x88100001))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x80700001 0x40708080 0x60080000 0x81800000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x504b017d 0x8c4b019d 0x87400000 0x86666667))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xa87b017d 0x898281fc 0x58491f00 0x89712c73))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x28400000 0x24400020 0x60400000 0x5f666667))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x28400000 0x83b00020 0x60400000 0x3f6c6667))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x40780000 0x00000000 0x807c0000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x40000000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x5ff40000 0x00000000 0xa07e0000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x80808080 0xbff88080 0x80808080 0xbffc8080))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x44003b4e 0x5f98721e 0x46387316 0xa0020934))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x02010100 0x237f0302 0x06050504 0x68090706))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xc4703b4e 0x608bf29e 0x46407316 0xa1700934))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xc4017a8e 0x5f5cf11e 0xbe409376 0x813f5e5f))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41e7ffff 0x30511f00 0x82e12c72))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x80710000 0x60efffff 0x09224000 0x61f07f6a))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x80700000 0x80e88080 0x30491f00 0x42772c72))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xa070807f 0x60f7e080 0x30895f01 0x61f0acb2))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x60700000 0x00000000 0x20060000))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x40808080 0xa0700000 0x00000001 0xa0868080))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0xff00ffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00ff0000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x000000ff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0xff000000 0x0000ff00 0x00ff0000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0x00000000 0x000000ff 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x0000ff00 0x000000ff 0xff0000ff 0x00ffff00))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00ff0000 0x000000ff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00ffff00 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0x0000ffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x00ffffff 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x0000ffff 0xff00ffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ff00 0x0000ffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00ffffff 0x0000ff00))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ff00 0x00000000 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x000000ff 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x0000ffff 0x0000ff00 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0x0000ff00 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00ff0000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x000000ff 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xff00ffff 0x000000ff 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x000000ff 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0x00000000 0x000000ff 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x0000ffff 0xffffff00 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0x00ff0000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xff000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffff00 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffff00ff 0xff00ffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0xffffffff 0xffffff00 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffff00ff 0xffffff00 0x00ffff00 0xff0000ff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xff00ffff 0xffffff00 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xff0000ff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0xffff0000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0xffff0000 0xff000000 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffff0000 0xffff0000 0x00ff0000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffff00ff 0xffff0000 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xff000000 0xffff00ff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff00ff 0xffffffff 0xffff0000 0xffff0000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffff00 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffff0000 0xffff00ff 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0xffff00ff 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffff00 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00ff0000 0xffffff00 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0xffff0000 0xffffff00 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0xffffffff 0xffffff00 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffff0000 0x000000ff 0xffffffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffff00ff 0x00ff00ff 0x00000000 0x00ff0000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xff000000 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0000ff00 0xff000000 0x00ffff00 0x00ff00ff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff0000ff 0xffffff00 0xffff00ff 0xff00ff00))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff0000ff 0x00ffff00 0xff0000ff 0xff00ff00))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xff000000 0x000000ff 0x000000ff 0xff00ffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xff000000 0x0000ffff 0xff00ff00 0xffff0000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xff0000ff 0x00ffff00 0x00ffff00 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ffff 0x00ffff00 0x00ffff00 0x00ffff00))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00ff00ff 0xffff00ff 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00ffff00 0xffffff00 0xff000000 0x00ffffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x0000ff00 0xff00ff00 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xff00ff00 0xff00ff00 0x0000ffff 0xff000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xff00ffff 0x00ffffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xff000000 0xffffffff 0xff00ffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xff000000 0xff000000 0xff000000 0xffff0000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xff000000 0x00ff0000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00ff00ff 0xffff0000 0x00ff0000 0x0000ffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00ffff00 0xffff00ff 0x00ff00ff 0x0000ffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffff00 0x00ffff00 0xff000000 0x000000ff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0xff000000 0xffff0000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x000000ff 0xff0000ff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x000000ff 0xff0000ff 0xff00ff00 0xff00ff00))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00ff0000 0x00ff0000 0xffff0000 0x00ffffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00ff0000 0x00ff0000 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00ff0000 0x00000000 0x00ff0000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00ff0000 0x00000000 0x00ff0000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000ff00 0xffff00ff 0x00ff00ff 0x0000ffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffff00 0x00ffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xff0000ff 0x0000ff00 0xff00ff00 0xff000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffff00 0x0000ff00 0xff00ff00 0xffff0000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00ff0000 0xff00ff00 0xffffff00))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0xffff0000 0xffff0000 0xffff0000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0x0000ffff 0x00ff0000 0x00ff00ff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0xffff00ff 0x000000ff 0xffff00ff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xff000000 0x00ff0000 0x000000ff 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0000ffff 0x00ff0000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff00ffff 0xffffff00 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffffffff 0x00ffff00 0x00ffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xff000000 0x00ff0000 0xff000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xff00ffff 0x00000000 0x00ff00ff 0xff00ff00))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0xff000000 0xffff0000 0xff000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffff00ff 0xff000000 0x00000000 0xff0000ff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffff0000 0x00ff0000 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffff0000 0xffff0000 0xff000000 0x00ffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00ff0000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x000000ff 0x00000000 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff00ffff 0x0000ffff 0xff00ffff 0xff000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0xff0000ff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0xffffffff 0x00ff0000 0xffff0000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00ff0000 0x00ff0000 0xffff0000 0x00ffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff0000 0x00ffffff 0xffffff00 0x00ffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00ff0000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00ff0000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff00ffff 0xff00ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffff00 0x0000ffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0x00ffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffff00 0x00ffff00 0xff0000ff 0xffffff00))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00ff0000 0xffffff00 0x00ffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffff0000 0xffffff00 0x00ffffff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000ffff 0xffff0000 0x0000ffff 0xffffff00))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0x000000ff 0xffffffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ff00 0xff00ff00 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffff00ff 0x00ffffff 0xff0000ff 0xff00ff00))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x0000ff00 0x000000ff 0x0000ff00 0x00ff00ff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00ffff00 0xff000000 0x00ffff00 0x00ff00ff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00ffffff 0x00ffff00 0xffff0000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00ff0000 0xffff0000 0x00ff0000 0x0000ffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00ff0000 0xff000000 0x00000000 0xff0000ff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xff000000 0xff000000 0xff0000ff 0xff0000ff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xff00ff00 0x0000ff00 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xff0000ff 0x000000ff 0x000000ff 0xff000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffff00ff 0x00ff00ff 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00ff00ff 0x00ff00ff 0xffff0000 0x00ffffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xff000000 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00ff0000 0xff000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00ffffff 0x00000000 0x00ff0000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00ff0000 0x00ff0000 0x00000000 0x0000ffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00ff0000 0xff000000 0x00ff0000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xff000000 0x00000000 0xff000000 0xffff0000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff0000ff 0x0000ff00 0xff00ff00 0xffff0000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000000ff 0xff0000ff 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff00ff 0x00ffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffff00 0x00ffff00 0xffff0000 0x00ffffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffff00 0x00ffff00 0x00ff0000 0x00ff00ff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xff000000 0xff0000ff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xff000000 0xff0000ff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00ff0000 0x00000000 0x00ff0000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xff00ffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff0000ff 0x0000ff00 0xff00ff00 0xffff0000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00ffff00 0xffff00ff 0x00ff00ff 0x00ffffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x000000ff 0xffff00ff 0x00ff00ff 0x0000ffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00ff0000 0x000000ff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x0000ff00 0x0000ffff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xffff0000 0xff00ff00 0xff00ff00))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000ffff 0x0000ff00 0xffffff00 0x0000ff00))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00ffffff 0xff000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xff000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00ff0000 0x000000ff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff00ffff 0x00000000 0xff0000ff 0xff000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00ffff00 0xff00ffff 0x00ffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00ff0000 0x00ffffff 0xff000000 0x000000ff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00ffffff 0x0000ff00 0x00ffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00ffff00 0x00ffff00 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xff00ffff 0xffffff00 0x00ffffff 0xffffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0x000000ff 0xffffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xff000000 0x0000ffff 0x00ff0000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000ffff 0xff00ffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xff000000 0xffff0000 0x00ff0000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffff0000 0xffff0000 0x00ff0000 0xffffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00ff0000 0xffff0000 0x00ff0000 0x00ffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xff000000 0x00ff0000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x000000ff 0x00000000 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xff00ffff 0xff00ffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0000ffff 0xff000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffff0000 0xffff00ff 0xffff0000 0x00ffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0x000000ff 0xffff0000 0x00ffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0x00ff0000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00ff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ffff 0xff000000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x000000ff 0xff0000ff 0x00ffff00 0x000000ff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0x00000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffff0000 0x00ffffff 0xffffff00 0xffffffff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0x000000ff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffff00ff 0x00ff00ff 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x0000ff00 0xff000000 0x00ffff00 0x00ff00ff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff00ff 0xffffff00 0xffff00ff 0xff00ff00))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff0000ff 0x00ffffff 0xff0000ff 0xff00ff00))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xff000000 0xff0000ff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xff00ffff 0x0000ffff 0xff00ffff 0xffff0000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xff00ffff 0x00ffffff 0xffffffff 0x00ffff00))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00ffffff 0x00ffffff 0x00ffff00 0x00ffff00))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00ff00ff 0xffff00ff 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00ffff00 0xffffff00 0xffffff00 0x00ffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x0000ff00 0xff00ff00 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xff00ff00 0xff00ff00 0x0000ffff 0xff000000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00000000 0xff00ffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xff000000 0xffffffff 0xff00ffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xff00ffff 0xff00ffff 0xffffffff 0xffff0000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xff00ffff 0x00ffffff 0xff00ffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00ffffff 0xffffffff 0x00ffffff 0x0000ffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00ffff00 0xffff00ff 0x00ff00ff 0x0000ffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffff00 0x00ffff00 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ff00 0xff000000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x000000ff 0xff0000ff 0x0000ffff 0xff000000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x000000ff 0xff0000ff 0xff00ffff 0xff00ff00))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00ffffff 0x00ffff00 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00ffffff 0x00ffff00 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xff00ffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00ff0000 0x00000000 0x00ff0000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00ffff00 0xffff00ff 0x00ff00ff 0x0000ffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xff0000ff 0x0000ff00 0xff00ff00 0xff000000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffff00 0x0000ff00 0xff00ff00 0xffff0000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xff00ffff 0xffffff00))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff00ff 0xffff0000))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00ff00ff 0x00ff00ff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0xffff00ff 0x000000ff 0xffff00ff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xff000000 0x00ffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff00ffff 0xffffff00 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00ff0000 0xffffffff 0x00ffff00 0x00ffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xff0000ff 0x00ff0000 0xff000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xff00ffff 0xff000000 0x00ffffff 0xffffff00))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xff000000 0xffff00ff 0xff000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xff0000ff 0xff0000ff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00ff0000 0x000000ff 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0xffffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0xffffffff 0xffffff00 0x00000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffff0000 0xff00ffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffff0000 0x00ff0000 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0xffff0000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00ffffff 0x0000ffff 0xff00ffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ffff 0x0000ffff 0xff00ffff 0x00000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff00ffff 0x0000ffff 0xff00ffff 0xff000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0x00ffffff 0xff00ffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffff00 0xffffffff 0x00ffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00ff0000 0x00ff0000 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffff0000 0x00ffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x0000ffff 0x0000ff00 0x0000ffff 0xff000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0xffffff00 0x0000ffff 0xff000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xff00ffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffff0000 0x00ffffff 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffff00 0x00ffff00 0xff0000ff 0xffffff00))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0xff000000 0x000000ff 0x00000000))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000ffff 0xffff0000 0x0000ffff 0xffffff00))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ff00 0xff00ff00 0xffffffff 0xff00ffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffff00ff 0x00ffffff 0xff0000ff 0xff00ff00))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00ffff00 0x000000ff 0x0000ff00 0x00ff00ff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00ffff00 0xff0000ff 0x00ffff00 0x00ff00ff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00ffffff 0xffffff00 0xffffff00 0x00ff0000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00ffffff 0xffff0000 0x00ff00ff 0x0000ffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00ffff00 0xff0000ff 0xff0000ff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffff0000 0xff0000ff 0xff0000ff 0xff0000ff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xff00ff00 0x0000ff00 0xffffffff 0x00000000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xff0000ff 0x000000ff 0x00ffffff 0xff000000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffff00ff 0x00ff00ff 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00ff00ff 0x00ff00ff 0xffff0000 0x00ffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xff000000 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xff000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0xffffffff 0x00ff0000 0xff000000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00ffffff 0x00000000 0x00ff0000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00ffffff 0x00ffffff 0x00ffffff 0x0000ffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0x00ffffff 0xff00ffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xff00ff00 0x0000ffff 0xff00ffff 0xffff0000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff0000ff 0x0000ff00 0xff00ff00 0xffff0000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x000000ff 0xff0000ff 0x00ffffff 0xffffff00))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0x00ffffff 0x0000ffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffff00 0x00ffff00 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffff00 0x00ffff00 0x00ff00ff 0x00ff00ff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xff00ffff 0xff00ffff 0x0000ffff 0xff000000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xff00ffff 0xff00ffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xff00ffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xff00ffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffff00ff 0x0000ff00 0xff00ff00 0xffff0000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x000000ff 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00ffff00 0xffff00ff 0x00ff00ff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x000000ff 0xffff00ff 0x00ff00ff 0x0000ffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xff00ffff 0x00ff00ff 0x000000ff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x0000ffff 0x0000ffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x0000ffff 0xffff0000 0xff00ffff 0xff00ff00))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x0000ffff 0x0000ff00 0xffffff00 0x0000ff00))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00ffffff 0xff00ffff 0xffffff00 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0xffff0000 0xff00ffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00ff0000 0x000000ff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x00000000 0xff0000ff 0xff000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00ffffff 0xff00ffff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00ff0000 0xffffffff 0xff00ff00 0x00ff00ff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0x00ffffff 0x0000ffff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x0000ff00 0x00ffffff 0xffffffff 0x00ffff00))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0x00ffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0x00000000 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0x00000000 0x00ffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0xff000000 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000ffff 0xff00ffff 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ffff 0x0000ffff 0x00ffffff 0xff000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffff00 0xffffffff 0x00ffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00ff0000 0xffff0000 0x00ff0000 0x00ffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00ffff00))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ffff 0x00000000 0xff00ffff 0x0000ffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xff00ffff 0xff00ffff 0x0000ffff 0xff000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0000ffff 0xff000000 0x000000ff 0xff000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0x0000ffff 0xffffffff 0xff00ffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00ff0000 0x00ff0000 0x00000000 0xffff0000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x000000ff 0xffff0000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x0000ffff 0xff000000 0xffffffff 0x0000ffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x000000ff 0xff0000ff 0x00ffff00 0x000000ff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xff00ffff 0x000000ff 0xff000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffffffff 0x0000ffff 0x000000ff 0xff000000))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xffff0000 0x0000ffff 0xffff0000 0x000000ff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xffffffff 0xffffff00 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x80807f80 0x010000ff))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0x7f7f7f7f 0x7f7f7f7f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0x7fffffff 0x80000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x7f7f7f7f 0x7f7f7f7f 0x7f7f7f7f 0x7f7f7f7f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f7f7f7f 0x7f7f7f7f 0x7f80807f 0x8080807f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x7f7f7f7f 0x7f7f7f7f 0x7f7f8080 0xfffffffb))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f80807f 0x8080807f 0x7f80807f 0x8080807f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7f80807f 0x8080807f 0x7fff0100 0x7f807f80))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x7f80807f 0x8080807f 0x80000000 0x80007f00))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7fff0100 0x7f807f80 0x7fff0100 0x7f807f80))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x7fff0100 0x7f807f80 0x7f807ffe 0x7f807f80))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x7fff0100 0x7f807f80 0x80007f00 0x7f807f00))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x7f807ffe 0x7f807f80 0x7f807ffe 0x7f807f80))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x7f807ffe 0x7f807f80 0x80007fff 0xffff0001))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f807ffe 0x7f807f80 0x7f000001 0x7f008000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80007fff 0xffff0001 0x80007fff 0xffff0001))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x80007fff 0xffff0001 0x80807f80 0x010000ff))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x80007fff 0xffff0001 0x807f7f7f 0x80017f00))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x80807f80 0x010000ff 0x80807f80 0x010000ff))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x80807f80 0x010000ff 0x7fffffff 0x80000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x80807f80 0x010000ff 0x7f407f00 0x7f808000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x7fffffff 0x80000000 0x7fffffff 0x80000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x7fffffff 0x80000000 0x7f7f8080 0xfffffffb))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x7f7f8080 0xfffffffb 0x7f7f8080 0xfffffffb))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x7f7f8080 0xfffffffb 0x80000000 0x80007f00))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x7f7f8080 0xfffffffb 0x7f000000 0x7f000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x80000000 0x80007f00 0x80000000 0x80007f00))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80000000 0x80007f00 0x80007f00 0x7f807f00))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x80000000 0x80007f00 0x7f000000 0x80000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x80007f00 0x7f807f00 0x80007f00 0x7f807f00))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x80007f00 0x7f807f00 0x7f000001 0x7f008000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x80007f00 0x7f807f00 0x7f80807f 0x807f8080))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x7f000001 0x7f008000 0x7f000001 0x7f008000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x7f000001 0x7f008000 0x807f7f7f 0x80017f00))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x7f000001 0x7f008000 0x7fffe000 0x80001000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x807f7f7f 0x80017f00 0x807f7f7f 0x80017f00))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x807f7f7f 0x80017f00 0x7f407f00 0x7f808000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x807f7f7f 0x80017f00 0x7fffe000 0x7f7f7f7f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x7f407f00 0x7f808000 0x7f407f00 0x7f808000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x7f407f00 0x7f808000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x7f407f00 0x7f808000 0x80000000 0x7f000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x80000000 0x7f000000 0x7f000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x7f000000 0x7f000000 0x7f000000 0x7f000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x7f000000 0x7f000000 0x7f000000 0x80000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x7f000000 0x7f000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x7f000000 0x80000000 0x7f000000 0x80000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f000000 0x80000000 0x7f80807f 0x807f8080))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x7f000000 0x80000000 0x7f7f7f7f 0x7f7f7f7f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x7f80807f 0x807f8080 0x7f80807f 0x807f8080))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x7f80807f 0x807f8080 0x7fffe000 0x80001000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f80807f 0x807f8080 0x7f80807f 0x8080807f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x7fffe000 0x80001000 0x7fffe000 0x80001000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x7fffe000 0x80001000 0x7fffe000 0x7f7f7f7f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x7fffe000 0x80001000 0x7fff0100 0x7f807f80))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x7fffe000 0x7f7f7f7f 0x7fffe000 0x7f7f7f7f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x7fffe000 0x7f7f7f7f 0x80000000 0x7f000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x7fffe000 0x7f7f7f7f 0x7f807ffe 0x7f807f80))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x80000000 0x7f000000 0x80000000 0x7f000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x80000000 0x7f000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x80000000 0x7f000000 0x80007fff 0xffff0001))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x0000ff00 0x01000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xffffffff 0xffffffff 0xff0000ff 0x000000ff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff0000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff0000ff 0x000000ff 0xff0000ff 0x000000ff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff0000ff 0x000000ff 0xff000100 0xff00ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xff0000ff 0x000000ff 0x00000000 0x0000ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff000100 0xff00ff00 0xff000100 0xff00ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xff000100 0xff00ff00 0xff00ff00 0xff008000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0xff000100 0xff00ff00 0x0000ff00 0xff00ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xff00ff00 0xff008000 0xff00ff00 0xff008000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0xff00ff00 0xff008000 0x0000ff00 0x00000001))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xff00ff00 0xff008000 0xff000001 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x0000ff00 0x00000001 0x0000ff00 0x00000001))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ff00 0x00000001 0x0000ff00 0x01000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0000ff00 0x00000001 0x00ffffff 0x0001ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x0000ff00 0x01000000 0x0000ff00 0x01000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x0000ff00 0x01000000 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000ff00 0x01000000 0xff40ff00 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0xff000000 0x00000000 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xff000000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xff000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0xffff0000 0x00000000 0xffff0000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xffff0000 0x00000000 0x00000000 0x0000ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xffff0000 0x00000000 0xff000000 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x0000ff00 0x00000000 0x0000ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x0000ff00 0x0000ff00 0xff00ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x0000ff00 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x0000ff00 0xff00ff00 0x0000ff00 0xff00ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x0000ff00 0xff00ff00 0xff000001 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x0000ff00 0xff00ff00 0xff0000ff 0x00ff0000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xff000001 0xff000000 0xff000001 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0xff000001 0xff000000 0x00ffffff 0x0001ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xff000001 0xff000000 0xff000000 0x00001000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00ffffff 0x0001ff00 0x00ffffff 0x0001ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00ffffff 0x0001ff00 0xff40ff00 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00ffffff 0x0001ff00 0xff000000 0xffffffff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0xff40ff00 0xff000000 0xff40ff00 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xff40ff00 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xff40ff00 0xff000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0xff000000 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xff000000 0xff000000 0xff000000 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xff000000 0xff000000 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xff000000 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0xff000000 0x00000000 0xff000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff000000 0x00000000 0xff0000ff 0x00ff0000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xff000000 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0xff0000ff 0x00ff0000 0xff0000ff 0x00ff0000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xff0000ff 0x00ff0000 0xff000000 0x00001000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff0000ff 0x00ff0000 0xff0000ff 0x000000ff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0xff000000 0x00001000 0xff000000 0x00001000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xff000000 0x00001000 0xff000000 0xffffffff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xff000000 0x00001000 0xff000100 0xff00ff00))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0xff000000 0xffffffff 0xff000000 0xffffffff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0xff000000 0xffffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0xff000000 0xffffffff 0xff00ff00 0xff008000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0xff000000 0x0000ff00 0x00000001))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00010000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x007f0000 0x00000080 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x807f8080 0x00000000 0x00000080 0x80000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x80808080 0x00808080 0x00008080 0x00008080))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00fe0000 0x000000fe 0xfe0000ff 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00fefefe 0xfefefeff 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0xfefefeff 0x00fefefe 0x00fefefe 0x0000fefe))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00ffffff 0xffffffff 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0x80000000 0x00000000 0xffff0000 0xffffffff))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x0000ff00 0x0000ff00 0x00ffffff 0x00ffffff))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (v128.const i32x4 0xc5000000 0x00000000 0x15150000 0x15cd1515))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x15151515 0x00151515))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00001515 0x00001500 0x00001515 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0xf0f0f0f0 0x00f0f0f0 0x0000f0f0 0x0000f0f0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0xf0f0f0f0 0x0000f0f0 0xf0f0f0f0 0x0000f0f0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (v128.const i32x4 0x01010100 0x00010101 0x00010101 0x00000101))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00000000 0x00000000 0x00010101 0x00010100))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000101 0x00000000 0x01000101 0x00000101))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (v128.const i32x4 0x00001500 0x00001500 0x00f9f9f9 0x00f9f902))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x0000f9f9 0x0000f900 0x0000f9f9 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x0000f9f9 0x00000000 0x000000f9 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00cd0000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (v128.const i32x4 0x009c0000 0x00000000 0x00000000 0x009c0000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00009c9c 0x00000000 0x9c009c9c 0x00009c9c))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00750000 0x0000009c 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00e40000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0xfefefefe 0xfefefefe 0xfefefefe 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0x80808080 0x80808080 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0x80808080 0x80808080 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0x80808080 0x80808080 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0xfefefefe 0xfefefefe 0xfefefefe 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0x80808080 0x80808080 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0xfefefefe 0xfefefefe 0xfefefefe 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0x80808080 0x80808080 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0xf0f0f0f0 0xf0f0f0f0 0xf0f0f0f0 0xf0f0f0f0))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x06040200 0x0e0c0a08 0x16141210 0x1e1c1a18))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x30201000 0x70605040 0xb0a09080 0xf0e0d0c0))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0xfe02fe00 0x8002fc00 0xe0207e80 0x04fc54aa))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0x80808000 0x00800000 0x00008000 0x00000080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0x80808000 0x00800000 0x00008000 0x00000080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0x80808000 0x00800000 0x00008000 0x00000080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0xfe02fe00 0x8002fc00 0xe0207e80 0x04fc54aa))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0x80808000 0x00800000 0x00008000 0x00000080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0xfe02fe00 0x8002fc00 0xe0207e80 0x04fc54aa))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0x80808000 0x00800000 0x00008000 0x00000080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0xf010f000 0x0010e000 0x0000f000 0x20e0a050))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00020000 0xfefefefe 0x24680000 0x00fefca8))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0x00800000 0x80808080 0x00000000 0x00800000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0x00800000 0x80808080 0x00000000 0x00800000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0x00800000 0x80808080 0x00000000 0x00800000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x00020000 0xfefefefe 0x24680000 0x00fefca8))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00800000 0x80808080 0x00000000 0x00800000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x00020000 0xfefefefe 0x24680000 0x00fefca8))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00800000 0x80808080 0x00000000 0x00800000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x00100000 0xf0f0f0f0 0x20400000 0x00f0e040))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x8000fefc 0xfefc8000 0x00000002 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x00008000 0x80000000 0x00000080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x00008000 0x80000000 0x00000080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0x00008000 0x80000000 0x00000080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x8000fefc 0xfefc8000 0x00000002 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x00008000 0x80000000 0x00000080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x8000fefc 0xfefc8000 0x00000002 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x00008000 0x80000000 0x00000080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x0000f0e0 0xf0e00000 0x00000010 0xf0f0f0f0))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0xfefefefe 0x00000000 0x00000002 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0x80808080 0x00000000 0x00000080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0x80808080 0x00000000 0x00000080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x80808080 0x00000000 0x00000080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0xfefefefe 0x00000000 0x00000002 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x80808080 0x00000000 0x00000080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0xfefefefe 0x00000000 0x00000002 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x80808080 0x00000000 0x00000080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0xf0f0f0f0 0x00000000 0x00000010 0xf0f0f0f0))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x0eb69a2a 0x8a422e9e 0x0000fefe 0x00020000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0x80808080 0x80808080 0x00008080 0x00800000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0x80808080 0x80808080 0x00008080 0x00800000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x80808080 0x80808080 0x00008080 0x00800000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x0eb69a2a 0x8a422e9e 0x0000fefe 0x00020000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x80808080 0x80808080 0x00008080 0x00800000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x0eb69a2a 0x8a422e9e 0x0000fefe 0x00020000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x80808080 0x80808080 0x00008080 0x00800000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x70b0d050 0x501070f0 0x0000f0f0 0x00100000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xfefefefe 0xfefefefe 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0x80808080 0x80808080 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0x80808080 0x80808080 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0x80808080 0x80808080 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0xfefefefe 0xfefefefe 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0x80808080 0x80808080 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0xfefefefe 0xfefefefe 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0x80808080 0x80808080 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0xf0f0f0f0 0xf0f0f0f0 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3478bce0 0x2468acf0 0xfefefef6 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0x3478bce0 0x2468acf0 0xfefefef6 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3478bce0 0x2468acf0 0xfefefef6 0xfefefefe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0xa0c0e000 0x20406080 0xf0f0f0b0 0xf0f0f0f0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x01010000 0x03030202 0x05050404 0x07070606))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x01010000 0x03030202 0x05050404 0x07070606))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x01010000 0x03030202 0x05050404 0x07070606))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0xff003fc0 0x20c0ff00 0xf8081fe0 0xc13fd52a))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0xff0000ff 0x00ffff00 0xff0000ff 0xff00ff00))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0xff0000ff 0x00ffff00 0xff0000ff 0xff00ff00))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0xff0000ff 0x00ffff00 0xff0000ff 0xff00ff00))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0xff003fc0 0x20c0ff00 0xf8081fe0 0xc13fd52a))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0xff0000ff 0x00ffff00 0xff0000ff 0xff00ff00))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0xff003fc0 0x20c0ff00 0xf8081fe0 0xc13fd52a))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0xff0000ff 0x00ffff00 0xff0000ff 0xff00ff00))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0xff0007f8 0x04f8ff00 0xff0103fc 0xf807fa05))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00000000 0x3fffffff 0x091ac000 0x00ffffea))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0x00000000 0x00ffffff 0x0000ff00 0x00ffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0x00000000 0x00ffffff 0x0000ff00 0x00ffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0x00000000 0x00ffffff 0x0000ff00 0x00ffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x00000000 0x3fffffff 0x091ac000 0x00ffffea))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00000000 0x00ffffff 0x0000ff00 0x00ffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x00000000 0x3fffffff 0x091ac000 0x00ffffea))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00000000 0x00ffffff 0x0000ff00 0x00ffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x00000000 0x07ffffff 0x0103f800 0x00fffffd))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x2000ffff 0x3fffe000 0x00c0c000 0x3fffff3f))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x0000ffff 0x00ffff00 0x00ffff00 0x00ffff00))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x0000ffff 0x00ffff00 0x00ffff00 0x00ffff00))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0x0000ffff 0x00ffff00 0x00ffff00 0x00ffff00))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x2000ffff 0x3fffe000 0x00c0c000 0x3fffff3f))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x0000ffff 0x00ffff00 0x00ffff00 0x00ffff00))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x2000ffff 0x3fffe000 0x00c0c000 0x3fffff3f))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x0000ffff 0x00ffff00 0x00ffff00 0x00ffff00))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x0400ffff 0x07fffc00 0x00f8f800 0x07ffff07))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3fffffff 0xc0000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0x00ffffff 0xff000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0x00ffffff 0xff000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00ffffff 0xff000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0x3fffffff 0xc0000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00ffffff 0xff000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3fffffff 0xc0000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00ffffff 0xff000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0x07ffffff 0xf8000000 0x00000000 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x032de60a 0xe210cb27 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0x0000ff00 0xff00ff00 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0x0000ff00 0xff00ff00 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x0000ff00 0xff00ff00 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x032de60a 0xe210cb27 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x0000ff00 0xff00ff00 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x032de60a 0xe210cb27 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x0000ff00 0xff00ff00 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x0005fc01 0xfc02f904 0x0000ffff 0x00000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0xffffffff 0x3fffffff 0x00000000 0xc0000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0xffffffff 0x00ffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0xffffffff 0x07ffffff 0x00000000 0xf8000000))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0xcddeeff8 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0xcddeeff8 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0xcddeeff8 0x091a2b3c 0xfffffffd 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0xf9fbfdff 0x01030507 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 8)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 16)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 32)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 33)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 64)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 65)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 1)) (v128.const i32x4 0x7f7f7f7f 0x7f7f7f7f 0x7f7f7f7f 0x7f7f7f7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 7)) (v128.const i32x4 0x01010101 0x01010101 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 8)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 15)) (v128.const i32x4 0x01010101 0x01010101 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 16)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 31)) (v128.const i32x4 0x01010101 0x01010101 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 32)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 33)) (v128.const i32x4 0x7f7f7f7f 0x7f7f7f7f 0x7f7f7f7f 0x7f7f7f7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 63)) (v128.const i32x4 0x01010101 0x01010101 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 64)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 65)) (v128.const i32x4 0x7f7f7f7f 0x7f7f7f7f 0x7f7f7f7f 0x7f7f7f7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -1)) (v128.const i32x4 0x01010101 0x01010101 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 100)) (v128.const i32x4 0x0f0f0f0f 0x0f0f0f0f 0x0f0f0f0f 0x0f0f0f0f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 1)) (v128.const i32x4 0x01010000 0x03030202 0x05050404 0x07070606))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 7)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 8)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 15)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 16)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 31)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 32)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 33)) (v128.const i32x4 0x01010000 0x03030202 0x05050404 0x07070606))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 63)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 64)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 65)) (v128.const i32x4 0x01010000 0x03030202 0x05050404 0x07070606))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 100)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0x7f003f40 0x20407f00 0x78081f60 0x413f552a))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 7)) (v128.const i32x4 0x01000001 0x00010100 0x01000001 0x01000100))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 8)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 15)) (v128.const i32x4 0x01000001 0x00010100 0x01000001 0x01000100))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 16)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 31)) (v128.const i32x4 0x01000001 0x00010100 0x01000001 0x01000100))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 32)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 33)) (v128.const i32x4 0x7f003f40 0x20407f00 0x78081f60 0x413f552a))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 63)) (v128.const i32x4 0x01000001 0x00010100 0x01000001 0x01000100))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 64)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 65)) (v128.const i32x4 0x7f003f40 0x20407f00 0x78081f60 0x413f552a))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -1)) (v128.const i32x4 0x01000001 0x00010100 0x01000001 0x01000100))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 100)) (v128.const i32x4 0x0f000708 0x04080f00 0x0f01030c 0x08070a05))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00000000 0x3f7f7f7f 0x091a4000 0x007f7f6a))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 7)) (v128.const i32x4 0x00000000 0x00010101 0x00000100 0x00010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 8)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 15)) (v128.const i32x4 0x00000000 0x00010101 0x00000100 0x00010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 16)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 31)) (v128.const i32x4 0x00000000 0x00010101 0x00000100 0x00010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 32)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 33)) (v128.const i32x4 0x00000000 0x3f7f7f7f 0x091a4000 0x007f7f6a))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 63)) (v128.const i32x4 0x00000000 0x00010101 0x00000100 0x00010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 64)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 65)) (v128.const i32x4 0x00000000 0x3f7f7f7f 0x091a4000 0x007f7f6a))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -1)) (v128.const i32x4 0x00000000 0x00010101 0x00000100 0x00010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 100)) (v128.const i32x4 0x00000000 0x070f0f0f 0x01030800 0x000f0f0d))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x20007f7f 0x3f7f6000 0x00404000 0x3f7f7f3f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 7)) (v128.const i32x4 0x00000101 0x00010100 0x00010100 0x00010100))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 8)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 15)) (v128.const i32x4 0x00000101 0x00010100 0x00010100 0x00010100))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 16)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 31)) (v128.const i32x4 0x00000101 0x00010100 0x00010100 0x00010100))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 32)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 33)) (v128.const i32x4 0x20007f7f 0x3f7f6000 0x00404000 0x3f7f7f3f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 63)) (v128.const i32x4 0x00000101 0x00010100 0x00010100 0x00010100))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 64)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 65)) (v128.const i32x4 0x20007f7f 0x3f7f6000 0x00404000 0x3f7f7f3f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -1)) (v128.const i32x4 0x00000101 0x00010100 0x00010100 0x00010100))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 100)) (v128.const i32x4 0x04000f0f 0x070f0c00 0x00080800 0x070f0f07))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x3f7f7f7f 0x40000000 0x00000000 0x7f7f7f7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 7)) (v128.const i32x4 0x00010101 0x01000000 0x00000000 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 8)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 15)) (v128.const i32x4 0x00010101 0x01000000 0x00000000 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 16)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 31)) (v128.const i32x4 0x00010101 0x01000000 0x00000000 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 32)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 33)) (v128.const i32x4 0x3f7f7f7f 0x40000000 0x00000000 0x7f7f7f7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 63)) (v128.const i32x4 0x00010101 0x01000000 0x00000000 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 64)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 65)) (v128.const i32x4 0x3f7f7f7f 0x40000000 0x00000000 0x7f7f7f7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x00010101 0x01000000 0x00000000 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 100)) (v128.const i32x4 0x070f0f0f 0x08000000 0x00000000 0x0f0f0f0f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x032d660a 0x62104b27 0x00007f7f 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 7)) (v128.const i32x4 0x00000100 0x01000100 0x00000101 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 8)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 15)) (v128.const i32x4 0x00000100 0x01000100 0x00000101 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 16)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 31)) (v128.const i32x4 0x00000100 0x01000100 0x00000101 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 32)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 33)) (v128.const i32x4 0x032d660a 0x62104b27 0x00007f7f 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 63)) (v128.const i32x4 0x00000100 0x01000100 0x00000101 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 64)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 65)) (v128.const i32x4 0x032d660a 0x62104b27 0x00007f7f 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x00000100 0x01000100 0x00000101 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 100)) (v128.const i32x4 0x00050c01 0x0c020904 0x00000f0f 0x00000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0x7f7f7f7f 0x3f7f7f7f 0x00000000 0x40000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 7)) (v128.const i32x4 0x01010101 0x00010101 0x00000000 0x01000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 8)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 15)) (v128.const i32x4 0x01010101 0x00010101 0x00000000 0x01000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 16)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 31)) (v128.const i32x4 0x01010101 0x00010101 0x00000000 0x01000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 32)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 33)) (v128.const i32x4 0x7f7f7f7f 0x3f7f7f7f 0x00000000 0x40000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 63)) (v128.const i32x4 0x01010101 0x00010101 0x00000000 0x01000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 64)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 65)) (v128.const i32x4 0x7f7f7f7f 0x3f7f7f7f 0x00000000 0x40000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0x01010101 0x00010101 0x00000000 0x01000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 100)) (v128.const i32x4 0x0f0f0f0f 0x070f0f0f 0x00000000 0x08000000))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0x7f7f7f7d 0x7f7f7f7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 7)) (v128.const i32x4 0x01010101 0x00000000 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 8)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 15)) (v128.const i32x4 0x01010101 0x00000000 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 16)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 31)) (v128.const i32x4 0x01010101 0x00000000 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 32)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 33)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0x7f7f7f7d 0x7f7f7f7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 63)) (v128.const i32x4 0x01010101 0x00000000 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 64)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 65)) (v128.const i32x4 0x4d5e6f78 0x091a2b3c 0x7f7f7f7d 0x7f7f7f7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x01010101 0x00000000 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 100)) (v128.const i32x4 0x090b0d0f 0x01030507 0x0f0f0f0f 0x0f0f0f0f))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffff00 0xffffffff 0xffffffff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000080 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffff00ff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00008000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00800000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00ffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffff00 0xffffffff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000080 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffff00ff 0xffffffff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00008000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00800000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffff00 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000080 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffff00ff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xff00ffff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00800000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0x00ffffff 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffff00)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000080)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffff00ff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xff00ffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 0))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 43369))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 29296))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 26211))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 61575))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 930))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 32895))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 65295))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 33920))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 13508))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 2048))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 32913))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 31812))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 32768))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 16448))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 32832))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 35369))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 49276))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 21628))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 192))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffff00 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65534))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000080 0x00000000 0x00000000 0x00000000)) (i32.const 1))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffff00ff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65533))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00008000 0x00000000 0x00000000 0x00000000)) (i32.const 2))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65531))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00800000 0x00000000 0x00000000 0x00000000)) (i32.const 4))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00ffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65527))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x00000000)) (i32.const 8))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffff00 0xffffffff 0xffffffff)) (i32.const 65519))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000080 0x00000000 0x00000000)) (i32.const 16))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffff00ff 0xffffffff 0xffffffff)) (i32.const 65503))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00008000 0x00000000 0x00000000)) (i32.const 32))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xff00ffff 0xffffffff 0xffffffff)) (i32.const 65471))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00800000 0x00000000 0x00000000)) (i32.const 64))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0x00ffffff 0xffffffff 0xffffffff)) (i32.const 65407))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000)) (i32.const 128))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffff00 0xffffffff)) (i32.const 65279))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000080 0x00000000)) (i32.const 256))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffff00ff 0xffffffff)) (i32.const 65023))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00008000 0x00000000)) (i32.const 512))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xff00ffff 0xffffffff)) (i32.const 64511))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00800000 0x00000000)) (i32.const 1024))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0x00ffffff 0xffffffff)) (i32.const 63487))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x80000000 0x00000000)) (i32.const 2048))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffff00)) (i32.const 61439))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000080)) (i32.const 4096))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffff00ff)) (i32.const 57343))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00008000)) (i32.const 8192))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xff00ffff)) (i32.const 49151))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00800000)) (i32.const 16384))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x00ffffff)) (i32.const 32767))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 32768))
@@ -0,0 +1,2031 @@
+;; Generated lane by lane against V8, covering splat and lane operations
+
+(module
+  (func (export "i8x16.splat") (param i32) (result v128)
+    (i8x16.splat (local.get 0))
+  )
+  (func (export "i16x8.splat") (param i32) (result v128)
+    (i16x8.splat (local.get 0))
+  )
+  (func (export "i32x4.splat") (param i32) (result v128)
+    (i32x4.splat (local.get 0))
+  )
+  (func (export "i64x2.splat") (param i64) (result v128)
+    (i64x2.splat (local.get 0))
+  )
+  (func (export "f32x4.splat") (param f32) (result v128)
+    (f32x4.splat (local.get 0))
+  )
+  (func (export "f64x2.splat") (param f64) (result v128)
+    (f64x2.splat (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_s 0") (param v128) (result i32)
+    (i8x16.extract_lane_s 0 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 0") (param v128) (result i32)
+    (i8x16.extract_lane_u 0 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 0") (param v128 i32) (result v128)
+    (i8x16.replace_lane 0 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 1") (param v128) (result i32)
+    (i8x16.extract_lane_s 1 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 1") (param v128) (result i32)
+    (i8x16.extract_lane_u 1 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 1") (param v128 i32) (result v128)
+    (i8x16.replace_lane 1 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 2") (param v128) (result i32)
+    (i8x16.extract_lane_s 2 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 2") (param v128) (result i32)
+    (i8x16.extract_lane_u 2 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 2") (param v128 i32) (result v128)
+    (i8x16.replace_lane 2 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 3") (param v128) (result i32)
+    (i8x16.extract_lane_s 3 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 3") (param v128) (result i32)
+    (i8x16.extract_lane_u 3 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 3") (param v128 i32) (result v128)
+    (i8x16.replace_lane 3 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 4") (param v128) (result i32)
+    (i8x16.extract_lane_s 4 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 4") (param v128) (result i32)
+    (i8x16.extract_lane_u 4 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 4") (param v128 i32) (result v128)
+    (i8x16.replace_lane 4 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 5") (param v128) (result i32)
+    (i8x16.extract_lane_s 5 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 5") (param v128) (result i32)
+    (i8x16.extract_lane_u 5 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 5") (param v128 i32) (result v128)
+    (i8x16.replace_lane 5 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 6") (param v128) (result i32)
+    (i8x16.extract_lane_s 6 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 6") (param v128) (result i32)
+    (i8x16.extract_lane_u 6 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 6") (param v128 i32) (result v128)
+    (i8x16.replace_lane 6 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 7") (param v128) (result i32)
+    (i8x16.extract_lane_s 7 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 7") (param v128) (result i32)
+    (i8x16.extract_lane_u 7 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 7") (param v128 i32) (result v128)
+    (i8x16.replace_lane 7 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 8") (param v128) (result i32)
+    (i8x16.extract_lane_s 8 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 8") (param v128) (result i32)
+    (i8x16.extract_lane_u 8 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 8") (param v128 i32) (result v128)
+    (i8x16.replace_lane 8 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 9") (param v128) (result i32)
+    (i8x16.extract_lane_s 9 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 9") (param v128) (result i32)
+    (i8x16.extract_lane_u 9 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 9") (param v128 i32) (result v128)
+    (i8x16.replace_lane 9 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 10") (param v128) (result i32)
+    (i8x16.extract_lane_s 10 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 10") (param v128) (result i32)
+    (i8x16.extract_lane_u 10 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 10") (param v128 i32) (result v128)
+    (i8x16.replace_lane 10 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 11") (param v128) (result i32)
+    (i8x16.extract_lane_s 11 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 11") (param v128) (result i32)
+    (i8x16.extract_lane_u 11 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 11") (param v128 i32) (result v128)
+    (i8x16.replace_lane 11 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 12") (param v128) (result i32)
+    (i8x16.extract_lane_s 12 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 12") (param v128) (result i32)
+    (i8x16.extract_lane_u 12 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 12") (param v128 i32) (result v128)
+    (i8x16.replace_lane 12 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 13") (param v128) (result i32)
+    (i8x16.extract_lane_s 13 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 13") (param v128) (result i32)
+    (i8x16.extract_lane_u 13 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 13") (param v128 i32) (result v128)
+    (i8x16.replace_lane 13 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 14") (param v128) (result i32)
+    (i8x16.extract_lane_s 14 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 14") (param v128) (result i32)
+    (i8x16.extract_lane_u 14 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 14") (param v128 i32) (result v128)
+    (i8x16.replace_lane 14 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.extract_lane_s 15") (param v128) (result i32)
+    (i8x16.extract_lane_s 15 (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_u 15") (param v128) (result i32)
+    (i8x16.extract_lane_u 15 (local.get 0))
+  )
+  (func (export "i8x16.replace_lane 15") (param v128 i32) (result v128)
+    (i8x16.replace_lane 15 (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extract_lane_s 0") (param v128) (result i32)
+    (i16x8.extract_lane_s 0 (local.get 0))
+  )
+  (func (export "i16x8.extract_lane_u 0") (param v128) (result i32)
+    (i16x8.extract_lane_u 0 (local.get 0))
+  )
+  (func (export "i16x8.replace_lane 0") (param v128 i32) (result v128)
+    (i16x8.replace_lane 0 (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extract_lane_s 1") (param v128) (result i32)
+    (i16x8.extract_lane_s 1 (local.get 0))
+  )
+  (func (export "i16x8.extract_lane_u 1") (param v128) (result i32)
+    (i16x8.extract_lane_u 1 (local.get 0))
+  )
+  (func (export "i16x8.replace_lane 1") (param v128 i32) (result v128)
+    (i16x8.replace_lane 1 (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extract_lane_s 2") (param v128) (result i32)
+    (i16x8.extract_lane_s 2 (local.get 0))
+  )
+  (func (export "i16x8.extract_lane_u 2") (param v128) (result i32)
+    (i16x8.extract_lane_u 2 (local.get 0))
+  )
+  (func (export "i16x8.replace_lane 2") (param v128 i32) (result v128)
+    (i16x8.replace_lane 2 (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extract_lane_s 3") (param v128) (result i32)
+    (i16x8.extract_lane_s 3 (local.get 0))
+  )
+  (func (export "i16x8.extract_lane_u 3") (param v128) (result i32)
+    (i16x8.extract_lane_u 3 (local.get 0))
+  )
+  (func (export "i16x8.replace_lane 3") (param v128 i32) (result v128)
+    (i16x8.replace_lane 3 (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extract_lane_s 4") (param v128) (result i32)
+    (i16x8.extract_lane_s 4 (local.get 0))
+  )
+  (func (export "i16x8.extract_lane_u 4") (param v128) (result i32)
+    (i16x8.extract_lane_u 4 (local.get 0))
+  )
+  (func (export "i16x8.replace_lane 4") (param v128 i32) (result v128)
+    (i16x8.replace_lane 4 (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extract_lane_s 5") (param v128) (result i32)
+    (i16x8.extract_lane_s 5 (local.get 0))
+  )
+  (func (export "i16x8.extract_lane_u 5") (param v128) (result i32)
+    (i16x8.extract_lane_u 5 (local.get 0))
+  )
+  (func (export "i16x8.replace_lane 5") (param v128 i32) (result v128)
+    (i16x8.replace_lane 5 (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extract_lane_s 6") (param v128) (result i32)
+    (i16x8.extract_lane_s 6 (local.get 0))
+  )
+  (func (export "i16x8.extract_lane_u 6") (param v128) (result i32)
+    (i16x8.extract_lane_u 6 (local.get 0))
+  )
+  (func (export "i16x8.replace_lane 6") (param v128 i32) (result v128)
+    (i16x8.replace_lane 6 (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extract_lane_s 7") (param v128) (result i32)
+    (i16x8.extract_lane_s 7 (local.get 0))
+  )
+  (func (export "i16x8.extract_lane_u 7") (param v128) (result i32)
+    (i16x8.extract_lane_u 7 (local.get 0))
+  )
+  (func (export "i16x8.replace_lane 7") (param v128 i32) (result v128)
+    (i16x8.replace_lane 7 (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.extract_lane 0") (param v128) (result i32)
+    (i32x4.extract_lane 0 (local.get 0))
+  )
+  (func (export "i32x4.replace_lane 0") (param v128 i32) (result v128)
+    (i32x4.replace_lane 0 (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.extract_lane 1") (param v128) (result i32)
+    (i32x4.extract_lane 1 (local.get 0))
+  )
+  (func (export "i32x4.replace_lane 1") (param v128 i32) (result v128)
+    (i32x4.replace_lane 1 (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.extract_lane 2") (param v128) (result i32)
+    (i32x4.extract_lane 2 (local.get 0))
+  )
+  (func (export "i32x4.replace_lane 2") (param v128 i32) (result v128)
+    (i32x4.replace_lane 2 (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.extract_lane 3") (param v128) (result i32)
+    (i32x4.extract_lane 3 (local.get 0))
+  )
+  (func (export "i32x4.replace_lane 3") (param v128 i32) (result v128)
+    (i32x4.replace_lane 3 (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.extract_lane 0") (param v128) (result i64)
+    (i64x2.extract_lane 0 (local.get 0))
+  )
+  (func (export "i64x2.replace_lane 0") (param v128 i64) (result v128)
+    (i64x2.replace_lane 0 (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.extract_lane 1") (param v128) (result i64)
+    (i64x2.extract_lane 1 (local.get 0))
+  )
+  (func (export "i64x2.replace_lane 1") (param v128 i64) (result v128)
+    (i64x2.replace_lane 1 (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.extract_lane 0") (param v128) (result f32)
+    (f32x4.extract_lane 0 (local.get 0))
+  )
+  (func (export "f32x4.replace_lane 0") (param v128 f32) (result v128)
+    (f32x4.replace_lane 0 (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.extract_lane 1") (param v128) (result f32)
+    (f32x4.extract_lane 1 (local.get 0))
+  )
+  (func (export "f32x4.replace_lane 1") (param v128 f32) (result v128)
+    (f32x4.replace_lane 1 (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.extract_lane 2") (param v128) (result f32)
+    (f32x4.extract_lane 2 (local.get 0))
+  )
+  (func (export "f32x4.replace_lane 2") (param v128 f32) (result v128)
+    (f32x4.replace_lane 2 (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.extract_lane 3") (param v128) (result f32)
+    (f32x4.extract_lane 3 (local.get 0))
+  )
+  (func (export "f32x4.replace_lane 3") (param v128 f32) (result v128)
+    (f32x4.replace_lane 3 (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.extract_lane 0") (param v128) (result f64)
+    (f64x2.extract_lane 0 (local.get 0))
+  )
+  (func (export "f64x2.replace_lane 0") (param v128 f64) (result v128)
+    (f64x2.replace_lane 0 (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.extract_lane 1") (param v128) (result f64)
+    (f64x2.extract_lane 1 (local.get 0))
+  )
+  (func (export "f64x2.replace_lane 1") (param v128 f64) (result v128)
+    (f64x2.replace_lane 1 (local.get 0) (local.get 1))
+  )
+)
+
+(assert_return (invoke "i8x16.splat" (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.splat" (i32.const 1)) (v128.const i32x4 0x01010101 0x01010101 0x01010101 0x01010101))
+(assert_return (invoke "i8x16.splat" (i32.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.splat" (i32.const 127)) (v128.const i32x4 0x7f7f7f7f 0x7f7f7f7f 0x7f7f7f7f 0x7f7f7f7f))
+(assert_return (invoke "i8x16.splat" (i32.const 128)) (v128.const i32x4 0x80808080 0x80808080 0x80808080 0x80808080))
+(assert_return (invoke "i8x16.splat" (i32.const 255)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.splat" (i32.const 4660)) (v128.const i32x4 0x34343434 0x34343434 0x34343434 0x34343434))
+(assert_return (invoke "i8x16.splat" (i32.const -32768)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.splat" (i32.const 2147483647)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.splat" (i32.const -2147483648)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.splat" (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.splat" (i32.const 1)) (v128.const i32x4 0x00010001 0x00010001 0x00010001 0x00010001))
+(assert_return (invoke "i16x8.splat" (i32.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.splat" (i32.const 127)) (v128.const i32x4 0x007f007f 0x007f007f 0x007f007f 0x007f007f))
+(assert_return (invoke "i16x8.splat" (i32.const 128)) (v128.const i32x4 0x00800080 0x00800080 0x00800080 0x00800080))
+(assert_return (invoke "i16x8.splat" (i32.const 255)) (v128.const i32x4 0x00ff00ff 0x00ff00ff 0x00ff00ff 0x00ff00ff))
+(assert_return (invoke "i16x8.splat" (i32.const 4660)) (v128.const i32x4 0x12341234 0x12341234 0x12341234 0x12341234))
+(assert_return (invoke "i16x8.splat" (i32.const -32768)) (v128.const i32x4 0x80008000 0x80008000 0x80008000 0x80008000))
+(assert_return (invoke "i16x8.splat" (i32.const 2147483647)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.splat" (i32.const -2147483648)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.splat" (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.splat" (i32.const 1)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.splat" (i32.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.splat" (i32.const 127)) (v128.const i32x4 0x0000007f 0x0000007f 0x0000007f 0x0000007f))
+(assert_return (invoke "i32x4.splat" (i32.const 128)) (v128.const i32x4 0x00000080 0x00000080 0x00000080 0x00000080))
+(assert_return (invoke "i32x4.splat" (i32.const 255)) (v128.const i32x4 0x000000ff 0x000000ff 0x000000ff 0x000000ff))
+(assert_return (invoke "i32x4.splat" (i32.const 4660)) (v128.const i32x4 0x00001234 0x00001234 0x00001234 0x00001234))
+(assert_return (invoke "i32x4.splat" (i32.const -32768)) (v128.const i32x4 0xffff8000 0xffff8000 0xffff8000 0xffff8000))
+(assert_return (invoke "i32x4.splat" (i32.const 2147483647)) (v128.const i32x4 0x7fffffff 0x7fffffff 0x7fffffff 0x7fffffff))
+(assert_return (invoke "i32x4.splat" (i32.const -2147483648)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x80000000))
+(assert_return (invoke "i64x2.splat" (i64.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.splat" (i64.const 1)) (v128.const i32x4 0x00000001 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.splat" (i64.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.splat" (i64.const 9223372036854775807)) (v128.const i32x4 0xffffffff 0x7fffffff 0xffffffff 0x7fffffff))
+(assert_return (invoke "i64x2.splat" (i64.const -9223372036854775808)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.splat" (i64.const 1311768467463790320)) (v128.const i32x4 0x9abcdef0 0x12345678 0x9abcdef0 0x12345678))
+(assert_return (invoke "f32x4.splat" (f32.const 0x0.0p+0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.splat" (f32.const -0x0.0p+0)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x80000000))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1.8000000000000p+0)) (v128.const i32x4 0x3fc00000 0x3fc00000 0x3fc00000 0x3fc00000))
+(assert_return (invoke "f32x4.splat" (f32.const -0x1.4000000000000p+1)) (v128.const i32x4 0xc0200000 0xc0200000 0xc0200000 0xc0200000))
+(assert_return (invoke "f32x4.splat" (f32.const inf)) (v128.const i32x4 0x7f800000 0x7f800000 0x7f800000 0x7f800000))
+(assert_return (invoke "f32x4.splat" (f32.const -inf)) (v128.const i32x4 0xff800000 0xff800000 0xff800000 0xff800000))
+(assert_return (invoke "f32x4.splat" (f32.const nan:0x400000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1.ff933c78cdfadp+127)) (v128.const i32x4 0x7f7fc99e 0x7f7fc99e 0x7f7fc99e 0x7f7fc99e))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1.6d601ad376ab9p-150)) (v128.const i32x4 0x00000001 0x00000001 0x00000001 0x00000001))
+(assert_return (invoke "f64x2.splat" (f64.const 0x0.0p+0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.splat" (f64.const -0x0.0p+0)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1.8000000000000p+0)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0x3ff80000))
+(assert_return (invoke "f64x2.splat" (f64.const -0x1.4000000000000p+1)) (v128.const i32x4 0x00000000 0xc0040000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.splat" (f64.const inf)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff00000))
+(assert_return (invoke "f64x2.splat" (f64.const -inf)) (v128.const i32x4 0x00000000 0xfff00000 0x00000000 0xfff00000))
+(assert_return (invoke "f64x2.splat" (f64.const nan:0x8000000000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1.7e43c8800759cp+996)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8800759c 0x7e37e43c))
+(assert_return (invoke "f64x2.splat" (f64.const -0x0.012688b70e62bp-1022)) (v128.const i32x4 0x8b70e62b 0x80001268 0x8b70e62b 0x80001268))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -2))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 21))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -16))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const -7))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -100))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 254))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 21))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 240))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 249))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 156))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0xff017f01 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 127)) (v128.const i32x4 0x9abcde7f 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const 128)) (v128.const i32x4 0x00000080 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 255)) (v128.const i32x4 0x000000ff 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const 4660)) (v128.const i32x4 0x88007534 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const -32768)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 2147483647)) (v128.const i32x4 0x030201ff 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -2147483648)) (v128.const i32x4 0x4000ff00 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const -51))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -34))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 2))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 117))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 1" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 205))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 222))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 2))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 117))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 1" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffff00ff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00010100 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.replace_lane 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x075bff15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.replace_lane 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const 127)) (v128.const i32x4 0x00007f00 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.replace_lane 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const 128)) (v128.const i32x4 0x501580f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.replace_lane 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const 255)) (v128.const i32x4 0x0000ff00 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.replace_lane 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (i32.const 4660)) (v128.const i32x4 0xffe03400 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i8x16.replace_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -32768)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.replace_lane 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 2147483647)) (v128.const i32x4 0xff01ff80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.replace_lane 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -2147483648)) (v128.const i32x4 0x7fff00ff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 2))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 91))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -68))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 21))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -32))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -32))
+(assert_return (invoke "i8x16.extract_lane_s 2" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 2))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 91))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 188))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 21))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 224))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 224))
+(assert_return (invoke "i8x16.extract_lane_u 2" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 2" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03000100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.replace_lane 2" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x4001fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.replace_lane 2" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.replace_lane 2" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const 127)) (v128.const i32x4 0x7f7f0000 0xff800000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.replace_lane 2" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const 128)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i8x16.replace_lane 2" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (i32.const 255)) (v128.const i32x4 0x00ff0000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.replace_lane 2" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (i32.const 4660)) (v128.const i32x4 0xff340000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i8x16.replace_lane 2" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -32768)) (v128.const i32x4 0xff00ffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 2" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 2147483647)) (v128.const i32x4 0x00ff0000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.replace_lane 2" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -2147483648)) (v128.const i32x4 0x0700cd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 3))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 64))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 7))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -102))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 80))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 79))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -120))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 3" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 3))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 64))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 7))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 154))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 80))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 79))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 136))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 3" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 3" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0x00017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.replace_lane 3" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x01ffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 3" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0xffbcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 3" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const 127)) (v128.const i32x4 0x7f000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.replace_lane 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 128)) (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.replace_lane 3" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const 255)) (v128.const i32x4 0xff00759c 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i8x16.replace_lane 3" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const 4660)) (v128.const i32x4 0x34000000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.replace_lane 3" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -32768)) (v128.const i32x4 0x00020100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.replace_lane 3" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 2147483647)) (v128.const i32x4 0xff00fffe 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.replace_lane 3" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -2147483648)) (v128.const i32x4 0x00ffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 4))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 79))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 120))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const -7))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 64))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 60))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 4" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 4))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 79))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 120))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 249))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 64))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 60))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 4" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 4" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffff00 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.replace_lane 4" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x075bcd15 0xc5219701 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.replace_lane 4" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const -1)) (v128.const i32x4 0x00000000 0x800000ff 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.replace_lane 4" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const 127)) (v128.const i32x4 0x501502f9 0xd015027f 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.replace_lane 4" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const 128)) (v128.const i32x4 0x00000000 0x7ff00080 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.replace_lane 4" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (i32.const 255)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i8x16.replace_lane 4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 4660)) (v128.const i32x4 0x00000000 0x00000034 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.replace_lane 4" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -32768)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.replace_lane 4" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 2147483647)) (v128.const i32x4 0x7fffffff 0x800000ff 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 4" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -2147483648)) (v128.const i32x4 0x9abcdef0 0x12345600 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 5))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -2))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -64))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const -105))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 86))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 2))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -28))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 5" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 5))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 254))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 192))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 151))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 86))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 2))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 228))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 5" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 5" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffe0000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.replace_lane 5" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xffffffff 0x7fff01ff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.replace_lane 5" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const -1)) (v128.const i32x4 0x7f800000 0xff80ff00 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.replace_lane 5" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const 127)) (v128.const i32x4 0x4f800000 0x47807f40 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i8x16.replace_lane 5" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (i32.const 128)) (v128.const i32x4 0x00000000 0x3ff88000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.replace_lane 5" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (i32.const 255)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4))
+(assert_return (invoke "i8x16.replace_lane 5" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 4660)) (v128.const i32x4 0xffffffff 0xffff34ff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 5" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -32768)) (v128.const i32x4 0x00010000 0x7fff00ff 0x12348000 0x00fffed4))
+(assert_return (invoke "i8x16.replace_lane 5" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 2147483647)) (v128.const i32x4 0x075bcd15 0xc521ff4f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.replace_lane 5" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const -2147483648)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 6))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -127))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -2))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 33))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 52))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 21))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const -16))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const -8))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 55))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -33))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -17))
+(assert_return (invoke "i8x16.extract_lane_s 6" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const -32))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 6))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 129))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 254))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 33))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 52))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 21))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 240))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 248))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 55))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 223))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 239))
+(assert_return (invoke "i8x16.extract_lane_u 6" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 224))
+(assert_return (invoke "i8x16.replace_lane 6" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 6" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x9abcdef0 0x12015678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 6" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const -1)) (v128.const i32x4 0x00000001 0x3fff0000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.replace_lane 6" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 127)) (v128.const i32x4 0x00000000 0x007f0000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.replace_lane 6" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const 128)) (v128.const i32x4 0x8800759c 0x7e80e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i8x16.replace_lane 6" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const 255)) (v128.const i32x4 0x00000000 0xbfff0000 0x00000000 0x400c0000))
+(assert_return (invoke "i8x16.replace_lane 6" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 4660)) (v128.const i32x4 0x03020100 0x07340504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i8x16.replace_lane 6" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -32768)) (v128.const i32x4 0x4000fffe 0x7f00c000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i8x16.replace_lane 6" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 2147483647)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.replace_lane 6" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const -2147483648)) (v128.const i32x4 0x7f800000 0xff000000 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 7))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 64))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const -59))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 18))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 63))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const -48))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 71))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 63))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 126))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 65))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 65))
+(assert_return (invoke "i8x16.extract_lane_s 7" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const -65))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 7))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 64))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 197))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 18))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 63))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 208))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 71))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 63))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 126))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 65))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 65))
+(assert_return (invoke "i8x16.extract_lane_u 7" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 191))
+(assert_return (invoke "i8x16.replace_lane 7" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0x0021974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i8x16.replace_lane 7" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const 1)) (v128.const i32x4 0x00000000 0x01000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i8x16.replace_lane 7" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const -1)) (v128.const i32x4 0x501502f9 0xff1502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.replace_lane 7" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const 127)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.replace_lane 7" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (i32.const 128)) (v128.const i32x4 0xffe00000 0x80dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i8x16.replace_lane 7" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 255)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.replace_lane 7" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 4660)) (v128.const i32x4 0xff017f80 0x3481fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i8x16.replace_lane 7" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -32768)) (v128.const i32x4 0x7fffffff 0x00000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 7" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 2147483647)) (v128.const i32x4 0x9abcdef0 0xff345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 7" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const -2147483648)) (v128.const i32x4 0x00000001 0x00000000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 8))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -64))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -5))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 43))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 8" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 8))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 192))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 251))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 43))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 8" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 8" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.replace_lane 8" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const 1)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00001 0x7f7fc99e))
+(assert_return (invoke "i8x16.replace_lane 8" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const -1)) (v128.const i32x4 0x4f800000 0x47800040 0xbf8000ff 0x3dcccccd))
+(assert_return (invoke "i8x16.replace_lane 8" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (i32.const 127)) (v128.const i32x4 0x00000000 0x3ff80000 0x0000007f 0xc0040000))
+(assert_return (invoke "i8x16.replace_lane 8" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (i32.const 128)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d80 0x43e158e4))
+(assert_return (invoke "i8x16.replace_lane 8" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 255)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 8" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 4660)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348034 0x00fffed4))
+(assert_return (invoke "i8x16.replace_lane 8" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -32768)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ff00 0x00010000))
+(assert_return (invoke "i8x16.replace_lane 8" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const 2147483647)) (v128.const i32x4 0x00000000 0x80000000 0x3fc000ff 0xc0200000))
+(assert_return (invoke "i8x16.replace_lane 8" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const -2147483648)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 9))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 63))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -26))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 61))
+(assert_return (invoke "i8x16.extract_lane_s 9" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 9))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 63))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 230))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 61))
+(assert_return (invoke "i8x16.extract_lane_u 9" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 9" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 0)) (v128.const i32x4 0x9abcdef0 0x12345678 0xffff00fb 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 9" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const 1)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000100 0x40200000))
+(assert_return (invoke "i8x16.replace_lane 9" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0x00000000 0x00000000 0x0000ff00 0x80000000))
+(assert_return (invoke "i8x16.replace_lane 9" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const 127)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b707f2b 0x80001268))
+(assert_return (invoke "i8x16.replace_lane 9" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const 128)) (v128.const i32x4 0x00000000 0xbfe00000 0x00008000 0x400c0000))
+(assert_return (invoke "i8x16.replace_lane 9" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 255)) (v128.const i32x4 0x03020100 0x07060504 0x0b0aff08 0x0f0e0d0c))
+(assert_return (invoke "i8x16.replace_lane 9" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 4660)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00803401 0x7fffff7f))
+(assert_return (invoke "i8x16.replace_lane 9" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -32768)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.replace_lane 9" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const 2147483647)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc0ff00 0x7f7fc99e))
+(assert_return (invoke "i8x16.replace_lane 9" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const -2147483648)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 10))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 16))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 52))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const -64))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const -64))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 112))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 16))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -111))
+(assert_return (invoke "i8x16.extract_lane_s 10" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 10))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 16))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 52))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 192))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 192))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 112))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 16))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 145))
+(assert_return (invoke "i8x16.extract_lane_u 10" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 10" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const 0)) (v128.const i32x4 0x00000000 0x80000000 0x3f000000 0xc0200000))
+(assert_return (invoke "i8x16.replace_lane 10" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const 1)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f010000 0xcf000001))
+(assert_return (invoke "i8x16.replace_lane 10" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const -1)) (v128.const i32x4 0x00000000 0x7ff00000 0x00ff0000 0x7ff80000))
+(assert_return (invoke "i8x16.replace_lane 10" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (i32.const 127)) (v128.const i32x4 0xffe00000 0x41dfffff 0x007f0000 0xc1e00000))
+(assert_return (invoke "i8x16.replace_lane 10" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 128)) (v128.const i32x4 0x00000000 0x00000000 0x00800000 0x00000000))
+(assert_return (invoke "i8x16.replace_lane 10" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 255)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0ff3fc0 0x827eaa55))
+(assert_return (invoke "i8x16.replace_lane 10" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 4660)) (v128.const i32x4 0x7fffffff 0x80000000 0x00340001 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 10" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -32768)) (v128.const i32x4 0x9abcdef0 0x12345678 0xff00fffb 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 10" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const 2147483647)) (v128.const i32x4 0x00000001 0x3f000000 0xbfff0000 0x40200000))
+(assert_return (invoke "i8x16.replace_lane 10" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const -2147483648)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 11))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -16))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 18))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 63))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const -65))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 79))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const -65))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -117))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 96))
+(assert_return (invoke "i8x16.extract_lane_s 11" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 11))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 240))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 18))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 63))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 191))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 79))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 191))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 139))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 96))
+(assert_return (invoke "i8x16.extract_lane_u 11" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 11" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const 0)) (v128.const i32x4 0x7f800000 0xff800000 0x00c00000 0x7f7fc99e))
+(assert_return (invoke "i8x16.replace_lane 11" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const 1)) (v128.const i32x4 0x4f800000 0x47800040 0x01800000 0x3dcccccd))
+(assert_return (invoke "i8x16.replace_lane 11" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (i32.const -1)) (v128.const i32x4 0x00000000 0x3ff80000 0xff000000 0xc0040000))
+(assert_return (invoke "i8x16.replace_lane 11" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (i32.const 127)) (v128.const i32x4 0xffe00000 0x41efffff 0x7f913d00 0x43e158e4))
+(assert_return (invoke "i8x16.replace_lane 11" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 128)) (v128.const i32x4 0xffffffff 0xffffffff 0x80ffffff 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 11" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 255)) (v128.const i32x4 0x00010000 0x7fffffff 0xff348000 0x00fffed4))
+(assert_return (invoke "i8x16.replace_lane 11" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 4660)) (v128.const i32x4 0x075bcd15 0xc521974f 0x3400ffff 0x00010000))
+(assert_return (invoke "i8x16.replace_lane 11" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const -32768)) (v128.const i32x4 0x00000000 0x80000000 0x00c00000 0xc0200000))
+(assert_return (invoke "i8x16.replace_lane 11" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const 2147483647)) (v128.const i32x4 0x501502f9 0xd01502f9 0xff000000 0xcf000001))
+(assert_return (invoke "i8x16.replace_lane 11" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const -2147483648)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 12))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 85))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const -44))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const -98))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const -51))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 104))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -28))
+(assert_return (invoke "i8x16.extract_lane_s 12" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 12))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 85))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 212))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 158))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 205))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 104))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 228))
+(assert_return (invoke "i8x16.extract_lane_u 12" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 12" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const 0)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.replace_lane 12" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000001))
+(assert_return (invoke "i8x16.replace_lane 12" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const -1)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x800012ff))
+(assert_return (invoke "i8x16.replace_lane 12" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const 127)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c007f))
+(assert_return (invoke "i8x16.replace_lane 12" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 128)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d80))
+(assert_return (invoke "i8x16.replace_lane 12" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 255)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffffff))
+(assert_return (invoke "i8x16.replace_lane 12" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 4660)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000034))
+(assert_return (invoke "i8x16.replace_lane 12" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const -32768)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc900))
+(assert_return (invoke "i8x16.replace_lane 12" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const 2147483647)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dccccff))
+(assert_return (invoke "i8x16.replace_lane 12" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (i32.const -2147483648)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 13))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -86))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const -2))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const -55))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const -52))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 18))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 88))
+(assert_return (invoke "i8x16.extract_lane_s 13" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 13))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 170))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 254))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 201))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 204))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 18))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 88))
+(assert_return (invoke "i8x16.extract_lane_u 13" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i8x16.replace_lane 13" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const 0)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.replace_lane 13" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const 1)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80100))
+(assert_return (invoke "i8x16.replace_lane 13" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (i32.const -1)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e0ff00))
+(assert_return (invoke "i8x16.replace_lane 13" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 127)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00007f00))
+(assert_return (invoke "i8x16.replace_lane 13" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 128)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827e8055))
+(assert_return (invoke "i8x16.replace_lane 13" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 255)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i8x16.replace_lane 13" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 4660)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffff34ff))
+(assert_return (invoke "i8x16.replace_lane 13" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const -32768)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i8x16.replace_lane 13" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 2147483647)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x8000ff00))
+(assert_return (invoke "i8x16.replace_lane 13" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const -2147483648)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80000068))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 14))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 126))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 32))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 32))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const -52))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const -8))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 4))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -32))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -31))
+(assert_return (invoke "i8x16.extract_lane_s 14" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 12))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 14))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 126))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 32))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 32))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 204))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 248))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 4))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 224))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 225))
+(assert_return (invoke "i8x16.extract_lane_u 14" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 12))
+(assert_return (invoke "i8x16.replace_lane 14" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const 0)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3d00cccd))
+(assert_return (invoke "i8x16.replace_lane 14" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (i32.const 1)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0010000))
+(assert_return (invoke "i8x16.replace_lane 14" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (i32.const -1)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43ff58e4))
+(assert_return (invoke "i8x16.replace_lane 14" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 127)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xff7fffff))
+(assert_return (invoke "i8x16.replace_lane 14" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 128)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x0080fed4))
+(assert_return (invoke "i8x16.replace_lane 14" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 255)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00ff0000))
+(assert_return (invoke "i8x16.replace_lane 14" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const 4660)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0340000))
+(assert_return (invoke "i8x16.replace_lane 14" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const -32768)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i8x16.replace_lane 14" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const 2147483647)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7fff0000))
+(assert_return (invoke "i8x16.replace_lane 14" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (i32.const -2147483648)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1000000))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 15))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -126))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const -64))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 64))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const -49))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 61))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const -64))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -128))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -63))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 67))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 64))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 15))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 130))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 192))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 64))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 207))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 61))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 192))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 128))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 193))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 67))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 64))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const 1)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x01001268))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const -1)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0xff0c0000))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 127)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x7f0e0d0c))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 128)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x80ffff7f))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 255)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0xff000000))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const 4660)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x347fc99e))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const -32768)) (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x00cccccd))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (i32.const 2147483647)) (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xff040000))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (i32.const -2147483648)) (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x00e158e4))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 256))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 32640))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -2))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const -13035))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -8464))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 761))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 30108))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 256))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 32640))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 65534))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 52501))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 57072))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 761))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 30108))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0xff010001 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 127)) (v128.const i32x4 0x9abc007f 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const 128)) (v128.const i32x4 0x00000080 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 255)) (v128.const i32x4 0x000000ff 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const 4660)) (v128.const i32x4 0x88001234 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const -32768)) (v128.const i32x4 0x00008000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 2147483647)) (v128.const i32x4 0x0302ffff 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -2147483648)) (v128.const i32x4 0x40000000 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 770))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -255))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 16384))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 32767))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 1883))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -25924))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 32640))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 20501))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 20352))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -30720))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -32))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -32))
+(assert_return (invoke "i16x8.extract_lane_s 1" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 770))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 65281))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 16384))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 32767))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 1883))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 39612))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 32640))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 20501))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 20352))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 34816))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 65504))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 65504))
+(assert_return (invoke "i16x8.extract_lane_u 1" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.replace_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0x0000ffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.replace_lane 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.replace_lane 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0xffffcd15 0xc521974f 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.replace_lane 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const 127)) (v128.const i32x4 0x007f0000 0x80000000 0x3fc00000 0xc0200000))
+(assert_return (invoke "i16x8.replace_lane 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const 128)) (v128.const i32x4 0x008002f9 0xd01502f9 0x4f000000 0xcf000001))
+(assert_return (invoke "i16x8.replace_lane 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const 255)) (v128.const i32x4 0x00ff0000 0x7ff00000 0x00000000 0x7ff80000))
+(assert_return (invoke "i16x8.replace_lane 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (i32.const 4660)) (v128.const i32x4 0x12340000 0x41dfffff 0x00100000 0xc1e00000))
+(assert_return (invoke "i16x8.replace_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -32768)) (v128.const i32x4 0x80000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i16x8.replace_lane 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 2147483647)) (v128.const i32x4 0xffff7f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.replace_lane 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -2147483648)) (v128.const i32x4 0x0000ffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 1284))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -512))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -16384))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const -26801))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 22136))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 761))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 64))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -7108))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 2" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 1284))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 65024))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 49152))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 38735))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 22136))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 761))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 64))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 58428))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 2" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.replace_lane 2" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060000 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.replace_lane 2" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x4000fffe 0x7ffe0001 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.replace_lane 2" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.replace_lane 2" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const 127)) (v128.const i32x4 0x7f800000 0xff80007f 0x7fc00000 0x7f7fc99e))
+(assert_return (invoke "i16x8.replace_lane 2" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const 128)) (v128.const i32x4 0x4f800000 0x47800080 0xbf800000 0x3dcccccd))
+(assert_return (invoke "i16x8.replace_lane 2" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (i32.const 255)) (v128.const i32x4 0x00000000 0x3ff800ff 0x00000000 0xc0040000))
+(assert_return (invoke "i16x8.replace_lane 2" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (i32.const 4660)) (v128.const i32x4 0xffe00000 0x41ef1234 0x60913d00 0x43e158e4))
+(assert_return (invoke "i16x8.replace_lane 2" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -32768)) (v128.const i32x4 0xffffffff 0xffff8000 0xffffffff 0xffffffff))
+(assert_return (invoke "i16x8.replace_lane 2" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 2147483647)) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4))
+(assert_return (invoke "i16x8.replace_lane 2" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -2147483648)) (v128.const i32x4 0x075bcd15 0xc5210000 0x0000ffff 0x00010000))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 1798))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 16513))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 32767))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 32766))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -32768))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const -15071))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 32767))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 4660))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const -32768))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const -128))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 16128))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const -12267))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 18304))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 32752))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 16376))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 32311))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 16863))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 16879))
+(assert_return (invoke "i16x8.extract_lane_s 3" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const -16416))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 1798))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 16513))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 32767))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 32766))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 32768))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 50465))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 32767))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 4660))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 32768))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 65408))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 16128))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 53269))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 18304))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 32752))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 16376))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 32311))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 16863))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 16879))
+(assert_return (invoke "i16x8.extract_lane_u 3" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 49120))
+(assert_return (invoke "i16x8.replace_lane 3" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x0000fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i16x8.replace_lane 3" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x7fffffff 0x00010000 0x00000001 0xffffffff))
+(assert_return (invoke "i16x8.replace_lane 3" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x9abcdef0 0xffff5678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.replace_lane 3" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const 127)) (v128.const i32x4 0x00000001 0x007f0000 0xbf000000 0x40200000))
+(assert_return (invoke "i16x8.replace_lane 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 128)) (v128.const i32x4 0x00000000 0x00800000 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.replace_lane 3" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const 255)) (v128.const i32x4 0x8800759c 0x00ffe43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i16x8.replace_lane 3" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const 4660)) (v128.const i32x4 0x00000000 0x12340000 0x00000000 0x400c0000))
+(assert_return (invoke "i16x8.replace_lane 3" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -32768)) (v128.const i32x4 0x03020100 0x80000504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i16x8.replace_lane 3" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 2147483647)) (v128.const i32x4 0x4000fffe 0xffffc000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i16x8.replace_lane 3" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -2147483648)) (v128.const i32x4 0xffffffff 0x0000ffff 0x00000000 0x80000000))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 2312))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 16320))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const -32768))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -32767))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -5))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -6613))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 15616))
+(assert_return (invoke "i16x8.extract_lane_s 4" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 2312))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 16320))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 32768))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 32769))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 65531))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 58923))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 15616))
+(assert_return (invoke "i16x8.extract_lane_u 4" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.replace_lane 4" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 0)) (v128.const i32x4 0x00010000 0x7fffffff 0x12340000 0x00fffed4))
+(assert_return (invoke "i16x8.replace_lane 4" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 1)) (v128.const i32x4 0x075bcd15 0xc521974f 0x00000001 0x00010000))
+(assert_return (invoke "i16x8.replace_lane 4" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const -1)) (v128.const i32x4 0x00000000 0x80000000 0x3fc0ffff 0xc0200000))
+(assert_return (invoke "i16x8.replace_lane 4" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const 127)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f00007f 0xcf000001))
+(assert_return (invoke "i16x8.replace_lane 4" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const 128)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000080 0x7ff80000))
+(assert_return (invoke "i16x8.replace_lane 4" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (i32.const 255)) (v128.const i32x4 0xffe00000 0x41dfffff 0x001000ff 0xc1e00000))
+(assert_return (invoke "i16x8.replace_lane 4" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 4660)) (v128.const i32x4 0x00000000 0x00000000 0x00001234 0x00000000))
+(assert_return (invoke "i16x8.replace_lane 4" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const -32768)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0108000 0x827eaa55))
+(assert_return (invoke "i16x8.replace_lane 4" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 2147483647)) (v128.const i32x4 0x7fffffff 0x80000000 0x0000ffff 0xffffffff))
+(assert_return (invoke "i16x8.replace_lane 4" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -2147483648)) (v128.const i32x4 0x9abcdef0 0x12345678 0xffff0000 0xffffffff))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 2826))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -4080))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 4660))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 128))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 16320))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 32704))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const -16640))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 20224))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const -16512))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -29840))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 16))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 24721))
+(assert_return (invoke "i16x8.extract_lane_s 5" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 2826))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 61456))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 4660))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 128))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 16320))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 32704))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 48896))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 20224))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 49024))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 35696))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 16))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 24721))
+(assert_return (invoke "i16x8.extract_lane_u 5" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.replace_lane 5" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 0)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00008001 0x7fffff7f))
+(assert_return (invoke "i16x8.replace_lane 5" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 1)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00010000 0x80000000))
+(assert_return (invoke "i16x8.replace_lane 5" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const -1)) (v128.const i32x4 0x7f800000 0xff800000 0xffff0000 0x7f7fc99e))
+(assert_return (invoke "i16x8.replace_lane 5" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const 127)) (v128.const i32x4 0x4f800000 0x47800040 0x007f0000 0x3dcccccd))
+(assert_return (invoke "i16x8.replace_lane 5" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (i32.const 128)) (v128.const i32x4 0x00000000 0x3ff80000 0x00800000 0xc0040000))
+(assert_return (invoke "i16x8.replace_lane 5" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (i32.const 255)) (v128.const i32x4 0xffe00000 0x41efffff 0x00ff3d00 0x43e158e4))
+(assert_return (invoke "i16x8.replace_lane 5" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 4660)) (v128.const i32x4 0xffffffff 0xffffffff 0x1234ffff 0xffffffff))
+(assert_return (invoke "i16x8.replace_lane 5" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const -32768)) (v128.const i32x4 0x00010000 0x7fffffff 0x80008000 0x00fffed4))
+(assert_return (invoke "i16x8.replace_lane 5" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 2147483647)) (v128.const i32x4 0x075bcd15 0xc521974f 0xffffffff 0x00010000))
+(assert_return (invoke "i16x8.replace_lane 5" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const -2147483648)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xc0200000))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 3340))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -21931))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const -300))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const -129))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const -13922))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const -13107))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 4712))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 22756))
+(assert_return (invoke "i16x8.extract_lane_s 6" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 3340))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 43605))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 65236))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 65407))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 51614))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 52429))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 4712))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 22756))
+(assert_return (invoke "i16x8.extract_lane_u 6" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i16x8.replace_lane 6" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 0)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffff0000))
+(assert_return (invoke "i16x8.replace_lane 6" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 1)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffff0001))
+(assert_return (invoke "i16x8.replace_lane 6" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const -1)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x4020ffff))
+(assert_return (invoke "i16x8.replace_lane 6" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 127)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x8000007f))
+(assert_return (invoke "i16x8.replace_lane 6" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const 128)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80000080))
+(assert_return (invoke "i16x8.replace_lane 6" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const 255)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c00ff))
+(assert_return (invoke "i16x8.replace_lane 6" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 4660)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e1234))
+(assert_return (invoke "i16x8.replace_lane 6" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -32768)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fff8000))
+(assert_return (invoke "i16x8.replace_lane 6" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const 2147483647)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x8000ffff))
+(assert_return (invoke "i16x8.replace_lane 6" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const -2147483648)) (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7f0000))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 3854))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -32130))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 255))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 32767))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -32768))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const -16352))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 32639))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 16416))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const -12544))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 15820))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const -32768))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 32760))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const -16380))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -32768))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -15904))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 17377))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 16396))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 3854))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 33406))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 255))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 32767))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 32768))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 49184))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 32639))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 16416))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 52992))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 15820))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 32768))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 32760))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 49156))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 32768))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 49632))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 17377))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 16396))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const 0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00000000))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const 1)) (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0x00010000))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const -1)) (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xffff0001))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const 127)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x007f0000))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (i32.const 128)) (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0x00800000))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 255)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00ff0000))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 4660)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x1234aa55))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -32768)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0x8000ffff))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 2147483647)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const -2147483648)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x00000000))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 50462976))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -16679040))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 65536))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 1073807358))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 2147483647))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 123456789))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -1))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1698898192))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 2139095040))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 1))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 1343554297))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 1333788672))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -2013235812))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -2097152))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const -2097152))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 1)) (v128.const i32x4 0x00000001 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -1)) (v128.const i32x4 0xffffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const 127)) (v128.const i32x4 0x0000007f 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const 128)) (v128.const i32x4 0x00000080 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 255)) (v128.const i32x4 0x000000ff 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const 4660)) (v128.const i32x4 0x00001234 0x7e37e43c 0x8b70e62b 0x80001268))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const -32768)) (v128.const i32x4 0xffff8000 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 2147483647)) (v128.const i32x4 0x7fffffff 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const -2147483648)) (v128.const i32x4 0x80000000 0x7ffec000 0x00808001 0x7fffff7f))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 117835012))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const 1082260992))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 2147483647))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 2147401728))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -2147483648))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const -987654321))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 2147483647))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const 305419896))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const -2147483648))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const -8388608))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 1056964608))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const -803929351))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 1199571008))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 2146435072))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 1073217536))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const 2117592124))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 1105199103))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 1106247679))
+(assert_return (invoke "i32x4.extract_lane 1" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const -1075838976))
+(assert_return (invoke "i32x4.replace_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const 0)) (v128.const i32x4 0xffffffff 0x00000000 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.replace_lane 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 1)) (v128.const i32x4 0x00010000 0x00000001 0x12348000 0x00fffed4))
+(assert_return (invoke "i32x4.replace_lane 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -1)) (v128.const i32x4 0x075bcd15 0xffffffff 0x0000ffff 0x00010000))
+(assert_return (invoke "i32x4.replace_lane 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i32.const 127)) (v128.const i32x4 0x00000000 0x0000007f 0x3fc00000 0xc0200000))
+(assert_return (invoke "i32x4.replace_lane 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i32.const 128)) (v128.const i32x4 0x501502f9 0x00000080 0x4f000000 0xcf000001))
+(assert_return (invoke "i32x4.replace_lane 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i32.const 255)) (v128.const i32x4 0x00000000 0x000000ff 0x00000000 0x7ff80000))
+(assert_return (invoke "i32x4.replace_lane 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (i32.const 4660)) (v128.const i32x4 0xffe00000 0x00001234 0x00100000 0xc1e00000))
+(assert_return (invoke "i32x4.replace_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i32.const -32768)) (v128.const i32x4 0x00000000 0xffff8000 0x00000000 0x00000000))
+(assert_return (invoke "i32x4.replace_lane 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 2147483647)) (v128.const i32x4 0xff017f80 0x7fffffff 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i32x4.replace_lane 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const -2147483648)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 185207048))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -267370560))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 305430528))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 8421377))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 65535))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -5))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const 1069547520))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 2143289344))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const -1090519040))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const 1325400064))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const -1082130432))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -1955535317))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const 1048576))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 1620131072))
+(assert_return (invoke "i32x4.extract_lane 2" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 0))
+(assert_return (invoke "i32x4.replace_lane 2" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const 0)) (v128.const i32x4 0x03020100 0x07060504 0x00000000 0x0f0e0d0c))
+(assert_return (invoke "i32x4.replace_lane 2" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 1)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00000001 0x7fffff7f))
+(assert_return (invoke "i32x4.replace_lane 2" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -1)) (v128.const i32x4 0xffffffff 0x7fffffff 0xffffffff 0x80000000))
+(assert_return (invoke "i32x4.replace_lane 2" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (i32.const 127)) (v128.const i32x4 0x7f800000 0xff800000 0x0000007f 0x7f7fc99e))
+(assert_return (invoke "i32x4.replace_lane 2" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (i32.const 128)) (v128.const i32x4 0x4f800000 0x47800040 0x00000080 0x3dcccccd))
+(assert_return (invoke "i32x4.replace_lane 2" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (i32.const 255)) (v128.const i32x4 0x00000000 0x3ff80000 0x000000ff 0xc0040000))
+(assert_return (invoke "i32x4.replace_lane 2" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (i32.const 4660)) (v128.const i32x4 0xffe00000 0x41efffff 0x00001234 0x43e158e4))
+(assert_return (invoke "i32x4.replace_lane 2" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i32.const -32768)) (v128.const i32x4 0xffffffff 0xffffffff 0xffff8000 0xffffffff))
+(assert_return (invoke "i32x4.replace_lane 2" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i32.const 2147483647)) (v128.const i32x4 0x00010000 0x7fffffff 0x7fffffff 0x00fffed4))
+(assert_return (invoke "i32x4.replace_lane 2" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i32.const -2147483648)) (v128.const i32x4 0x075bcd15 0xc521974f 0x80000000 0x00010000))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i32.const 252579084))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i32.const -2105628075))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i32.const 16776916))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i32.const 2147483519))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i32.const 65536))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i32.const -2147483648))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i32.const -1071644672))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i32.const 2139081118))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i32.const 1075838976))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i32.const -822083583))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i32.const 1036831949))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i32.const -2147483648))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i32.const 2146959360))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i32.const -1073479680))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i32.const -2147478936))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i32.const -1042284544))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i32.const 1138841828))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i32.const 1074528256))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i32.const 0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x00000000))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i32.const 1)) (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0x00000001))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i32.const -1)) (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i32.const 127)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x0000007f))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i32.const 128)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000080))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (i32.const 255)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x000000ff))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (i32.const 4660)) (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x00001234))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (i32.const -32768)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0xffff8000))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (i32.const 2147483647)) (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffffff))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (i32.const -2147483648)) (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i64.const 0))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i64.const -1))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i64.const 506097522914230528))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i64.const 4648275570654805888))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i64.const 9223372032559874048))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i64.const 9223020194207694846))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i64.const -9223372034707292161))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i64.const -4241943008324629227))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i64.const 1311768467463790320))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i64.const -36028794879868928))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i64.const 4539628424389459969))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i64.const -3452850269495950599))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i64.const 5152118249923543040))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i64.const 0))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i64.const 9218868437227405312))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i64.const 4609434218613702656))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i64.const 9094988921128908188))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i64.const 4746794007246405632))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i64.const 4751297606873776128))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i64.const -4620693217682128896))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (i64.const 0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (i64.const 1)) (v128.const i32x4 0x00000001 0x00000000 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (i64.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000001 0xffffffff))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (i64.const 9223372036854775807)) (v128.const i32x4 0xffffffff 0x7fffffff 0xfffffffb 0xffffffff))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (i64.const -9223372036854775808)) (v128.const i32x4 0x00000000 0x80000000 0xbf000000 0x40200000))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (i64.const 1311768467463790320)) (v128.const i32x4 0x9abcdef0 0x12345678 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (i64.const 0))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i64.const -1))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (i64.const 1084818905618843912))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (i64.const -9043603715636838464))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (i64.const 72056305853169664))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (i64.const 9223371482812416001))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (i64.const -4294967295))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (i64.const 281474976776191))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (i64.const -5))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (i64.const -4602678818103099392))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (i64.const 9187283447444406272))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (i64.const 4620693220886577152))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (i64.const -3530822102238101504))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (i64.const 4453159315615776768))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (i64.const 9221120237041090560))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (i64.const -4610560118520545280))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (i64.const -9223351796629445077))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (i64.const -4476578029605224448))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (i64.const 4891288408196988160))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (i64.const 4615063718147915776))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (i64.const 0)) (v128.const i32x4 0xffffffff 0xffffffff 0x00000000 0x00000000))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (i64.const 1)) (v128.const i32x4 0x00010000 0x7fffffff 0x00000001 0x00000000))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (i64.const -1)) (v128.const i32x4 0x075bcd15 0xc521974f 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (i64.const 9223372036854775807)) (v128.const i32x4 0x00000000 0x80000000 0xffffffff 0x7fffffff))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (i64.const -9223372036854775808)) (v128.const i32x4 0x501502f9 0xd01502f9 0x00000000 0x80000000))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (i64.const 1311768467463790320)) (v128.const i32x4 0x00000000 0x7ff00000 0x9abcdef0 0x12345678))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (f32.const 0x1.0402000000000p-121))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (f32.const -0x1.02ff000000000p+127))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (f32.const 0x1.0000000000000p-133))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (f32.const 0x1.01fffc0000000p+1))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (f32.const 0x1.b79a2a0000000p-113))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (f32.const -0x1.79bde00000000p-74))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (f32.const inf))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (f32.const 0x1.0000000000000p-149))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (f32.const 0x1.2a05f20000000p+33))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (f32.const 0x1.0000000000000p+32))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (f32.const -0x1.00eb380000000p-111))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (f32.const 0x0.0p+0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (f32.const -0x0.0p+0)) (v128.const i32x4 0x80000000 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (f32.const 0x1.8000000000000p+0)) (v128.const f32x4 0x1.8000000000000p+0 -0x0.0p+0 0x1.0000000000000p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (f32.const -0x1.4000000000000p+1)) (v128.const f32x4 -0x1.4000000000000p+1 0x1.68acf00000000p-91 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (f32.const inf)) (v128.const i32x4 0x7f800000 0x3f000000 0xbf000000 0x40200000))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (f32.const -inf)) (v128.const i32x4 0xff800000 0x00000000 0x00000000 0x80000000))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (f32.const nan:0x400000)) (v128.const f32x4 nan:arithmetic 0x1.6fc8780000000p+125 -0x1.e1cc560000000p-105 -0x1.2680000000000p-137))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (f32.const 0x1.ff933c78cdfadp+127)) (v128.const i32x4 0x7f7fc99e 0xbfe00000 0x00000000 0x400c0000))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (f32.const 0x1.6d601ad376ab9p-150)) (v128.const i32x4 0x00000001 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (f32.const 0x1.0c0a080000000p-113))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (f32.const 0x1.03fc000000000p+2))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (f32.const -0x1.432e9e0000000p+11))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (f32.const 0x1.68acf00000000p-91))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (f32.const -inf))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (f32.const 0x1.0000000000000p-1))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (f32.const -0x1.2a05f20000000p+33))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (f32.const 0x1.0000800000000p+16))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (f32.const 0x1.f000000000000p+0))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (f32.const 0x1.6fc8780000000p+125))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (f32.const 0x1.bffffe0000000p+4))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (f32.const 0x1.dffffe0000000p+4))
+(assert_return (invoke "f32x4.extract_lane 1" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (f32.const -0x1.c000000000000p+0))
+(assert_return (invoke "f32x4.replace_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (f32.const 0x0.0p+0)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (f32.const -0x0.0p+0)) (v128.const i32x4 0x00010000 0x80000000 0x12348000 0x00fffed4))
+(assert_return (invoke "f32x4.replace_lane 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (f32.const 0x1.8000000000000p+0)) (v128.const i32x4 0x075bcd15 0x3fc00000 0x0000ffff 0x00010000))
+(assert_return (invoke "f32x4.replace_lane 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (f32.const -0x1.4000000000000p+1)) (v128.const i32x4 0x00000000 0xc0200000 0x3fc00000 0xc0200000))
+(assert_return (invoke "f32x4.replace_lane 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (f32.const inf)) (v128.const i32x4 0x501502f9 0x7f800000 0x4f000000 0xcf000001))
+(assert_return (invoke "f32x4.replace_lane 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (f32.const -inf)) (v128.const f32x4 0x0.0p+0 -inf 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (f32.const nan:0x400000)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.0000000000000p-129 -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.replace_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (f32.const 0x1.ff933c78cdfadp+127)) (v128.const i32x4 0x00000000 0x7f7fc99e 0x00000000 0x00000000))
+(assert_return (invoke "f32x4.replace_lane 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (f32.const 0x1.6d601ad376ab9p-150)) (v128.const i32x4 0xff017f80 0x00000001 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (f32.const 0x1.1412100000000p-105))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (f32.const -0x1.207f800000000p+97))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (f32.const 0x1.6900000000000p-91))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (f32.const 0x1.0100020000000p-126))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (f32.const 0x1.0000000000000p-149))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (f32.const 0x1.fffe000000000p-134))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (f32.const -0x1.0000000000000p-1))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (f32.const 0x1.0000000000000p+31))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (f32.const -0x1.0000000000000p+0))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (f32.const -0x1.e1cc560000000p-105))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (f32.const 0x1.0000000000000p-129))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (f32.const 0x1.227a000000000p+66))
+(assert_return (invoke "f32x4.extract_lane 2" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 2" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (f32.const 0x0.0p+0)) (v128.const i32x4 0x03020100 0x07060504 0x00000000 0x0f0e0d0c))
+(assert_return (invoke "f32x4.replace_lane 2" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (f32.const -0x0.0p+0)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 2" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000) (f32.const 0x1.8000000000000p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.8000000000000p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 2" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e) (f32.const -0x1.4000000000000p+1)) (v128.const i32x4 0x7f800000 0xff800000 0xc0200000 0x7f7fc99e))
+(assert_return (invoke "f32x4.replace_lane 2" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd) (f32.const inf)) (v128.const i32x4 0x4f800000 0x47800040 0x7f800000 0x3dcccccd))
+(assert_return (invoke "f32x4.replace_lane 2" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000) (f32.const -inf)) (v128.const i32x4 0x00000000 0x3ff80000 0xff800000 0xc0040000))
+(assert_return (invoke "f32x4.replace_lane 2" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4) (f32.const nan:0x400000)) (v128.const f32x4 nan:arithmetic 0x1.dffffe0000000p+4 nan:arithmetic 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.replace_lane 2" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (f32.const 0x1.ff933c78cdfadp+127)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.ff933c0000000p+127 nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 2" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (f32.const 0x1.6d601ad376ab9p-150)) (v128.const f32x4 0x1.0000000000000p-133 nan:arithmetic 0x1.0000000000000p-149 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (f32.const 0x1.1c1a180000000p-97))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (f32.const -0x1.fd54aa0000000p-123))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (f32.const 0x1.fffda80000000p-126))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (f32.const 0x1.0000000000000p-133))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (f32.const -0x1.4000000000000p+1))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (f32.const 0x1.ff933c0000000p+127))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (f32.const -0x1.0000020000000p+31))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (f32.const 0x1.99999a0000000p-4))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (f32.const -0x1.0800000000000p+1))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (f32.const -0x1.2680000000000p-137))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (f32.const -0x1.c000000000000p+4))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (f32.const 0x1.c2b1c80000000p+8))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (f32.const 0x1.1800000000000p+1))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (f32.const 0x0.0p+0)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x00000000))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (f32.const -0x0.0p+0)) (v128.const f32x4 nan:arithmetic -0x0.0p+0 0x1.0000000000000p-149 -0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (f32.const 0x1.8000000000000p+0)) (v128.const f32x4 -0x1.79bde00000000p-74 0x1.68acf00000000p-91 nan:arithmetic 0x1.8000000000000p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (f32.const -0x1.4000000000000p+1)) (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0xc0200000))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (f32.const inf)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x7f800000))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (f32.const -inf)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0xff800000))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (f32.const nan:0x400000)) (v128.const f32x4 0x0.0p+0 -0x1.c000000000000p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (f32.const 0x1.ff933c78cdfadp+127)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x7f7fc99e))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f) (f32.const 0x1.6d601ad376ab9p-150)) (v128.const f32x4 0x1.01fffc0000000p+1 nan:arithmetic 0x1.0100020000000p-126 0x1.0000000000000p-149))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (f64.const 0x1.6050403020100p-911))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (f64.const 0x1.1fe00ff017f80p+9))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (f64.const -0x0.000007fffffffp-1022))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (f64.const -0x1.1974f075bcd15p+83))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (f64.const 0x1.456789abcdef0p-732))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (f64.const -0x1.000007f800000p+1017))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (f64.const 0x1.0000000000001p-15))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (f64.const -0x1.502f9501502f9p+258))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (f64.const 0x1.000404f800000p+121))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (f64.const inf))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (f64.const 0x1.7e43c8800759cp+996))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (f64.const 0x1.fffffffe00000p+30))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (f64.const 0x1.fffffffe00000p+31))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (f64.const -0x1.0000000000000p-1))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (f64.const 0x0.0p+0)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (f64.const -0x0.0p+0)) (v128.const i32x4 0x00000000 0x80000000 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff) (f64.const 0x1.8000000000000p+0)) (v128.const f64x2 0x1.8000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff) (f64.const -0x1.4000000000000p+1)) (v128.const f64x2 -0x1.4000000000000p+1 nan:arithmetic))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000) (f64.const inf)) (v128.const i32x4 0x00000000 0x7ff00000 0xbf000000 0x40200000))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000) (f64.const -inf)) (v128.const i32x4 0x00000000 0xfff00000 0x00000000 0x80000000))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268) (f64.const nan:0x8000000000000)) (v128.const f64x2 nan:arithmetic -0x0.012688b70e62bp-1022))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000) (f64.const 0x1.7e43c8800759cp+996)) (v128.const i32x4 0x8800759c 0x7e37e43c 0x00000000 0x400c0000))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c) (f64.const -0x0.012688b70e62bp-1022)) (v128.const i32x4 0x8b70e62b 0x80001268 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (f64.const 0x1.e0d0c0b0a0908p-783))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (f64.const -0x1.eaa55f0103fc0p-984))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (f64.const 0x1.ffed412348000p-1008))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x4000fffe 0x7ffec000 0x00808001 0x7fffff7f)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x7fffffff 0x80000000 0x00000001 0xffffffff)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000)) (f64.const 0x0.100000000ffffp-1022))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0xffffffff 0x7fffffff 0x00000000 0x80000000)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x9abcdef0 0x12345678 0xfffffffb 0xffffffff)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000)) (f64.const -0x1.000003fc00000p+3))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x7f800000 0xff800000 0x7fc00000 0x7f7fc99e)) (f64.const 0x1.fc99e7fc00000p+1016))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x00000001 0x3f000000 0xbf000000 0x40200000)) (f64.const 0x1.00000bf000000p+3))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001)) (f64.const -0x1.000014f000000p+241))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x4f800000 0x47800040 0xbf800000 0x3dcccccd)) (f64.const 0x1.ccccdbf800000p-35))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x80000000)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x00000000 0x3ff80000 0x00000000 0xc0040000)) (f64.const -0x1.4000000000000p+1))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x8800759c 0x7e37e43c 0x8b70e62b 0x80001268)) (f64.const -0x0.012688b70e62bp-1022))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000)) (f64.const -0x1.0000000100000p+31))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0xffe00000 0x41efffff 0x60913d00 0x43e158e4)) (f64.const 0x1.158e460913d00p+63))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x00000000 0xbfe00000 0x00000000 0x400c0000)) (f64.const 0x1.c000000000000p+1))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff) (f64.const 0x0.0p+0)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4) (f64.const -0x0.0p+0)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x075bcd15 0xc521974f 0x0000ffff 0x00010000) (f64.const 0x1.8000000000000p+0)) (v128.const i32x4 0x075bcd15 0xc521974f 0x00000000 0x3ff80000))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x00000000 0x80000000 0x3fc00000 0xc0200000) (f64.const -0x1.4000000000000p+1)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0xc0040000))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x501502f9 0xd01502f9 0x4f000000 0xcf000001) (f64.const inf)) (v128.const i32x4 0x501502f9 0xd01502f9 0x00000000 0x7ff00000))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0x7ff80000) (f64.const -inf)) (v128.const i32x4 0x00000000 0x7ff00000 0x00000000 0xfff00000))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0xffe00000 0x41dfffff 0x00100000 0xc1e00000) (f64.const nan:0x8000000000000)) (v128.const f64x2 0x1.fffffffe00000p+30 nan:arithmetic))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000) (f64.const 0x1.7e43c8800759cp+996)) (v128.const i32x4 0x00000000 0x00000000 0x8800759c 0x7e37e43c))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55) (f64.const -0x0.012688b70e62bp-1022)) (v128.const i32x4 0xff017f80 0x4081fe00 0x8b70e62b 0x80001268))
@@ -0,0 +1,448 @@
+;; Generated lane by lane against V8, covering load and store operations
+
+(module
+  (memory 1)
+  (data (i32.const 256) "\0b\30\55\7a\9f\c4\e9\0e\33\58\7d\a2\c7\ec\11\36\5b\80\a5\ca\ef\14\39\5e\83\a8\cd\f2\17\3c\61\86\ab\d0\f5\1a\3f\64\89\ae\d3\f8\1d\42\67\8c\b1\d6\fb\20\45\6a\8f\b4\d9\fe\23\48\6d\92\b7\dc\01\26")
+  (func (export "v128.load offset=0") (param i32) (result v128)
+    (v128.load offset=0 (local.get 0))
+  )
+  (func (export "v128.load offset=3") (param i32) (result v128)
+    (v128.load offset=3 (local.get 0))
+  )
+  (func (export "v128.load8x8_s offset=0") (param i32) (result v128)
+    (v128.load8x8_s offset=0 (local.get 0))
+  )
+  (func (export "v128.load8x8_s offset=3") (param i32) (result v128)
+    (v128.load8x8_s offset=3 (local.get 0))
+  )
+  (func (export "v128.load8x8_u offset=0") (param i32) (result v128)
+    (v128.load8x8_u offset=0 (local.get 0))
+  )
+  (func (export "v128.load8x8_u offset=3") (param i32) (result v128)
+    (v128.load8x8_u offset=3 (local.get 0))
+  )
+  (func (export "v128.load16x4_s offset=0") (param i32) (result v128)
+    (v128.load16x4_s offset=0 (local.get 0))
+  )
+  (func (export "v128.load16x4_s offset=3") (param i32) (result v128)
+    (v128.load16x4_s offset=3 (local.get 0))
+  )
+  (func (export "v128.load16x4_u offset=0") (param i32) (result v128)
+    (v128.load16x4_u offset=0 (local.get 0))
+  )
+  (func (export "v128.load16x4_u offset=3") (param i32) (result v128)
+    (v128.load16x4_u offset=3 (local.get 0))
+  )
+  (func (export "v128.load32x2_s offset=0") (param i32) (result v128)
+    (v128.load32x2_s offset=0 (local.get 0))
+  )
+  (func (export "v128.load32x2_s offset=3") (param i32) (result v128)
+    (v128.load32x2_s offset=3 (local.get 0))
+  )
+  (func (export "v128.load32x2_u offset=0") (param i32) (result v128)
+    (v128.load32x2_u offset=0 (local.get 0))
+  )
+  (func (export "v128.load32x2_u offset=3") (param i32) (result v128)
+    (v128.load32x2_u offset=3 (local.get 0))
+  )
+  (func (export "v128.load8_splat offset=0") (param i32) (result v128)
+    (v128.load8_splat offset=0 (local.get 0))
+  )
+  (func (export "v128.load8_splat offset=3") (param i32) (result v128)
+    (v128.load8_splat offset=3 (local.get 0))
+  )
+  (func (export "v128.load16_splat offset=0") (param i32) (result v128)
+    (v128.load16_splat offset=0 (local.get 0))
+  )
+  (func (export "v128.load16_splat offset=3") (param i32) (result v128)
+    (v128.load16_splat offset=3 (local.get 0))
+  )
+  (func (export "v128.load32_splat offset=0") (param i32) (result v128)
+    (v128.load32_splat offset=0 (local.get 0))
+  )
+  (func (export "v128.load32_splat offset=3") (param i32) (result v128)
+    (v128.load32_splat offset=3 (local.get 0))
+  )
+  (func (export "v128.load64_splat offset=0") (param i32) (result v128)
+    (v128.load64_splat offset=0 (local.get 0))
+  )
+  (func (export "v128.load64_splat offset=3") (param i32) (result v128)
+    (v128.load64_splat offset=3 (local.get 0))
+  )
+  (func (export "v128.load32_zero offset=0") (param i32) (result v128)
+    (v128.load32_zero offset=0 (local.get 0))
+  )
+  (func (export "v128.load32_zero offset=3") (param i32) (result v128)
+    (v128.load32_zero offset=3 (local.get 0))
+  )
+  (func (export "v128.load64_zero offset=0") (param i32) (result v128)
+    (v128.load64_zero offset=0 (local.get 0))
+  )
+  (func (export "v128.load64_zero offset=3") (param i32) (result v128)
+    (v128.load64_zero offset=3 (local.get 0))
+  )
+  (func (export "v128.store") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store offset=1 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load8_lane 0") (param i32 v128) (result v128)
+    (v128.load8_lane offset=2 0 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store8_lane 0") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store8_lane offset=2 0 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load8_lane 1") (param i32 v128) (result v128)
+    (v128.load8_lane offset=2 1 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store8_lane 1") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store8_lane offset=2 1 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load8_lane 15") (param i32 v128) (result v128)
+    (v128.load8_lane offset=2 15 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store8_lane 15") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store8_lane offset=2 15 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load16_lane 0") (param i32 v128) (result v128)
+    (v128.load16_lane offset=2 0 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store16_lane 0") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store16_lane offset=2 0 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load16_lane 1") (param i32 v128) (result v128)
+    (v128.load16_lane offset=2 1 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store16_lane 1") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store16_lane offset=2 1 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load16_lane 7") (param i32 v128) (result v128)
+    (v128.load16_lane offset=2 7 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store16_lane 7") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store16_lane offset=2 7 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load32_lane 0") (param i32 v128) (result v128)
+    (v128.load32_lane offset=2 0 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store32_lane 0") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store32_lane offset=2 0 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load32_lane 1") (param i32 v128) (result v128)
+    (v128.load32_lane offset=2 1 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store32_lane 1") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store32_lane offset=2 1 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load32_lane 3") (param i32 v128) (result v128)
+    (v128.load32_lane offset=2 3 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store32_lane 3") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store32_lane offset=2 3 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load64_lane 0") (param i32 v128) (result v128)
+    (v128.load64_lane offset=2 0 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store64_lane 0") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store64_lane offset=2 0 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load64_lane 1") (param i32 v128) (result v128)
+    (v128.load64_lane offset=2 1 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store64_lane 1") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store64_lane offset=2 1 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+)
+
+(assert_return (invoke "v128.load offset=0" (i32.const 256)) (v128.const i32x4 0x7a55300b 0x0ee9c49f 0xa27d5833 0x3611ecc7))
+(assert_return (invoke "v128.load offset=0" (i32.const 257)) (v128.const i32x4 0x9f7a5530 0x330ee9c4 0xc7a27d58 0x5b3611ec))
+(assert_return (invoke "v128.load offset=0" (i32.const 263)) (v128.const i32x4 0x7d58330e 0x11ecc7a2 0xa5805b36 0x3914efca))
+(assert_return (invoke "v128.load offset=0" (i32.const 286)) (v128.const i32x4 0xd0ab8661 0x643f1af5 0xf8d3ae89 0x8c67421d))
+(assert_return (invoke "v128.load offset=0" (i32.const 65520)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load offset=3" (i32.const 256)) (v128.const i32x4 0xe9c49f7a 0x7d58330e 0x11ecc7a2 0xa5805b36))
+(assert_return (invoke "v128.load offset=3" (i32.const 257)) (v128.const i32x4 0x0ee9c49f 0xa27d5833 0x3611ecc7 0xcaa5805b))
+(assert_return (invoke "v128.load offset=3" (i32.const 263)) (v128.const i32x4 0xecc7a27d 0x805b3611 0x14efcaa5 0xa8835e39))
+(assert_return (invoke "v128.load offset=3" (i32.const 286)) (v128.const i32x4 0x3f1af5d0 0xd3ae8964 0x67421df8 0xfbd6b18c))
+(assert_return (invoke "v128.load offset=3" (i32.const 65517)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load8x8_s offset=0" (i32.const 256)) (v128.const i32x4 0x0030000b 0x007a0055 0xffc4ff9f 0x000effe9))
+(assert_return (invoke "v128.load8x8_s offset=0" (i32.const 257)) (v128.const i32x4 0x00550030 0xff9f007a 0xffe9ffc4 0x0033000e))
+(assert_return (invoke "v128.load8x8_s offset=0" (i32.const 263)) (v128.const i32x4 0x0033000e 0x007d0058 0xffc7ffa2 0x0011ffec))
+(assert_return (invoke "v128.load8x8_s offset=0" (i32.const 286)) (v128.const i32x4 0xff860061 0xffd0ffab 0x001afff5 0x0064003f))
+(assert_return (invoke "v128.load8x8_s offset=0" (i32.const 65528)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load8x8_s offset=3" (i32.const 256)) (v128.const i32x4 0xff9f007a 0xffe9ffc4 0x0033000e 0x007d0058))
+(assert_return (invoke "v128.load8x8_s offset=3" (i32.const 257)) (v128.const i32x4 0xffc4ff9f 0x000effe9 0x00580033 0xffa2007d))
+(assert_return (invoke "v128.load8x8_s offset=3" (i32.const 263)) (v128.const i32x4 0xffa2007d 0xffecffc7 0x00360011 0xff80005b))
+(assert_return (invoke "v128.load8x8_s offset=3" (i32.const 286)) (v128.const i32x4 0xfff5ffd0 0x003f001a 0xff890064 0xffd3ffae))
+(assert_return (invoke "v128.load8x8_s offset=3" (i32.const 65525)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load8x8_u offset=0" (i32.const 256)) (v128.const i32x4 0x0030000b 0x007a0055 0x00c4009f 0x000e00e9))
+(assert_return (invoke "v128.load8x8_u offset=0" (i32.const 257)) (v128.const i32x4 0x00550030 0x009f007a 0x00e900c4 0x0033000e))
+(assert_return (invoke "v128.load8x8_u offset=0" (i32.const 263)) (v128.const i32x4 0x0033000e 0x007d0058 0x00c700a2 0x001100ec))
+(assert_return (invoke "v128.load8x8_u offset=0" (i32.const 286)) (v128.const i32x4 0x00860061 0x00d000ab 0x001a00f5 0x0064003f))
+(assert_return (invoke "v128.load8x8_u offset=0" (i32.const 65528)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load8x8_u offset=3" (i32.const 256)) (v128.const i32x4 0x009f007a 0x00e900c4 0x0033000e 0x007d0058))
+(assert_return (invoke "v128.load8x8_u offset=3" (i32.const 257)) (v128.const i32x4 0x00c4009f 0x000e00e9 0x00580033 0x00a2007d))
+(assert_return (invoke "v128.load8x8_u offset=3" (i32.const 263)) (v128.const i32x4 0x00a2007d 0x00ec00c7 0x00360011 0x0080005b))
+(assert_return (invoke "v128.load8x8_u offset=3" (i32.const 286)) (v128.const i32x4 0x00f500d0 0x003f001a 0x00890064 0x00d300ae))
+(assert_return (invoke "v128.load8x8_u offset=3" (i32.const 65525)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load16x4_s offset=0" (i32.const 256)) (v128.const i32x4 0x0000300b 0x00007a55 0xffffc49f 0x00000ee9))
+(assert_return (invoke "v128.load16x4_s offset=0" (i32.const 257)) (v128.const i32x4 0x00005530 0xffff9f7a 0xffffe9c4 0x0000330e))
+(assert_return (invoke "v128.load16x4_s offset=0" (i32.const 263)) (v128.const i32x4 0x0000330e 0x00007d58 0xffffc7a2 0x000011ec))
+(assert_return (invoke "v128.load16x4_s offset=0" (i32.const 286)) (v128.const i32x4 0xffff8661 0xffffd0ab 0x00001af5 0x0000643f))
+(assert_return (invoke "v128.load16x4_s offset=0" (i32.const 65528)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load16x4_s offset=3" (i32.const 256)) (v128.const i32x4 0xffff9f7a 0xffffe9c4 0x0000330e 0x00007d58))
+(assert_return (invoke "v128.load16x4_s offset=3" (i32.const 257)) (v128.const i32x4 0xffffc49f 0x00000ee9 0x00005833 0xffffa27d))
+(assert_return (invoke "v128.load16x4_s offset=3" (i32.const 263)) (v128.const i32x4 0xffffa27d 0xffffecc7 0x00003611 0xffff805b))
+(assert_return (invoke "v128.load16x4_s offset=3" (i32.const 286)) (v128.const i32x4 0xfffff5d0 0x00003f1a 0xffff8964 0xffffd3ae))
+(assert_return (invoke "v128.load16x4_s offset=3" (i32.const 65525)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load16x4_u offset=0" (i32.const 256)) (v128.const i32x4 0x0000300b 0x00007a55 0x0000c49f 0x00000ee9))
+(assert_return (invoke "v128.load16x4_u offset=0" (i32.const 257)) (v128.const i32x4 0x00005530 0x00009f7a 0x0000e9c4 0x0000330e))
+(assert_return (invoke "v128.load16x4_u offset=0" (i32.const 263)) (v128.const i32x4 0x0000330e 0x00007d58 0x0000c7a2 0x000011ec))
+(assert_return (invoke "v128.load16x4_u offset=0" (i32.const 286)) (v128.const i32x4 0x00008661 0x0000d0ab 0x00001af5 0x0000643f))
+(assert_return (invoke "v128.load16x4_u offset=0" (i32.const 65528)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load16x4_u offset=3" (i32.const 256)) (v128.const i32x4 0x00009f7a 0x0000e9c4 0x0000330e 0x00007d58))
+(assert_return (invoke "v128.load16x4_u offset=3" (i32.const 257)) (v128.const i32x4 0x0000c49f 0x00000ee9 0x00005833 0x0000a27d))
+(assert_return (invoke "v128.load16x4_u offset=3" (i32.const 263)) (v128.const i32x4 0x0000a27d 0x0000ecc7 0x00003611 0x0000805b))
+(assert_return (invoke "v128.load16x4_u offset=3" (i32.const 286)) (v128.const i32x4 0x0000f5d0 0x00003f1a 0x00008964 0x0000d3ae))
+(assert_return (invoke "v128.load16x4_u offset=3" (i32.const 65525)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32x2_s offset=0" (i32.const 256)) (v128.const i32x4 0x7a55300b 0x00000000 0x0ee9c49f 0x00000000))
+(assert_return (invoke "v128.load32x2_s offset=0" (i32.const 257)) (v128.const i32x4 0x9f7a5530 0xffffffff 0x330ee9c4 0x00000000))
+(assert_return (invoke "v128.load32x2_s offset=0" (i32.const 263)) (v128.const i32x4 0x7d58330e 0x00000000 0x11ecc7a2 0x00000000))
+(assert_return (invoke "v128.load32x2_s offset=0" (i32.const 286)) (v128.const i32x4 0xd0ab8661 0xffffffff 0x643f1af5 0x00000000))
+(assert_return (invoke "v128.load32x2_s offset=0" (i32.const 65528)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32x2_s offset=3" (i32.const 256)) (v128.const i32x4 0xe9c49f7a 0xffffffff 0x7d58330e 0x00000000))
+(assert_return (invoke "v128.load32x2_s offset=3" (i32.const 257)) (v128.const i32x4 0x0ee9c49f 0x00000000 0xa27d5833 0xffffffff))
+(assert_return (invoke "v128.load32x2_s offset=3" (i32.const 263)) (v128.const i32x4 0xecc7a27d 0xffffffff 0x805b3611 0xffffffff))
+(assert_return (invoke "v128.load32x2_s offset=3" (i32.const 286)) (v128.const i32x4 0x3f1af5d0 0x00000000 0xd3ae8964 0xffffffff))
+(assert_return (invoke "v128.load32x2_s offset=3" (i32.const 65525)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32x2_u offset=0" (i32.const 256)) (v128.const i32x4 0x7a55300b 0x00000000 0x0ee9c49f 0x00000000))
+(assert_return (invoke "v128.load32x2_u offset=0" (i32.const 257)) (v128.const i32x4 0x9f7a5530 0x00000000 0x330ee9c4 0x00000000))
+(assert_return (invoke "v128.load32x2_u offset=0" (i32.const 263)) (v128.const i32x4 0x7d58330e 0x00000000 0x11ecc7a2 0x00000000))
+(assert_return (invoke "v128.load32x2_u offset=0" (i32.const 286)) (v128.const i32x4 0xd0ab8661 0x00000000 0x643f1af5 0x00000000))
+(assert_return (invoke "v128.load32x2_u offset=0" (i32.const 65528)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32x2_u offset=3" (i32.const 256)) (v128.const i32x4 0xe9c49f7a 0x00000000 0x7d58330e 0x00000000))
+(assert_return (invoke "v128.load32x2_u offset=3" (i32.const 257)) (v128.const i32x4 0x0ee9c49f 0x00000000 0xa27d5833 0x00000000))
+(assert_return (invoke "v128.load32x2_u offset=3" (i32.const 263)) (v128.const i32x4 0xecc7a27d 0x00000000 0x805b3611 0x00000000))
+(assert_return (invoke "v128.load32x2_u offset=3" (i32.const 286)) (v128.const i32x4 0x3f1af5d0 0x00000000 0xd3ae8964 0x00000000))
+(assert_return (invoke "v128.load32x2_u offset=3" (i32.const 65525)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load8_splat offset=0" (i32.const 256)) (v128.const i32x4 0x0b0b0b0b 0x0b0b0b0b 0x0b0b0b0b 0x0b0b0b0b))
+(assert_return (invoke "v128.load8_splat offset=0" (i32.const 257)) (v128.const i32x4 0x30303030 0x30303030 0x30303030 0x30303030))
+(assert_return (invoke "v128.load8_splat offset=0" (i32.const 263)) (v128.const i32x4 0x0e0e0e0e 0x0e0e0e0e 0x0e0e0e0e 0x0e0e0e0e))
+(assert_return (invoke "v128.load8_splat offset=0" (i32.const 286)) (v128.const i32x4 0x61616161 0x61616161 0x61616161 0x61616161))
+(assert_return (invoke "v128.load8_splat offset=0" (i32.const 65535)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load8_splat offset=3" (i32.const 256)) (v128.const i32x4 0x7a7a7a7a 0x7a7a7a7a 0x7a7a7a7a 0x7a7a7a7a))
+(assert_return (invoke "v128.load8_splat offset=3" (i32.const 257)) (v128.const i32x4 0x9f9f9f9f 0x9f9f9f9f 0x9f9f9f9f 0x9f9f9f9f))
+(assert_return (invoke "v128.load8_splat offset=3" (i32.const 263)) (v128.const i32x4 0x7d7d7d7d 0x7d7d7d7d 0x7d7d7d7d 0x7d7d7d7d))
+(assert_return (invoke "v128.load8_splat offset=3" (i32.const 286)) (v128.const i32x4 0xd0d0d0d0 0xd0d0d0d0 0xd0d0d0d0 0xd0d0d0d0))
+(assert_return (invoke "v128.load8_splat offset=3" (i32.const 65532)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load16_splat offset=0" (i32.const 256)) (v128.const i32x4 0x300b300b 0x300b300b 0x300b300b 0x300b300b))
+(assert_return (invoke "v128.load16_splat offset=0" (i32.const 257)) (v128.const i32x4 0x55305530 0x55305530 0x55305530 0x55305530))
+(assert_return (invoke "v128.load16_splat offset=0" (i32.const 263)) (v128.const i32x4 0x330e330e 0x330e330e 0x330e330e 0x330e330e))
+(assert_return (invoke "v128.load16_splat offset=0" (i32.const 286)) (v128.const i32x4 0x86618661 0x86618661 0x86618661 0x86618661))
+(assert_return (invoke "v128.load16_splat offset=0" (i32.const 65534)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load16_splat offset=3" (i32.const 256)) (v128.const i32x4 0x9f7a9f7a 0x9f7a9f7a 0x9f7a9f7a 0x9f7a9f7a))
+(assert_return (invoke "v128.load16_splat offset=3" (i32.const 257)) (v128.const i32x4 0xc49fc49f 0xc49fc49f 0xc49fc49f 0xc49fc49f))
+(assert_return (invoke "v128.load16_splat offset=3" (i32.const 263)) (v128.const i32x4 0xa27da27d 0xa27da27d 0xa27da27d 0xa27da27d))
+(assert_return (invoke "v128.load16_splat offset=3" (i32.const 286)) (v128.const i32x4 0xf5d0f5d0 0xf5d0f5d0 0xf5d0f5d0 0xf5d0f5d0))
+(assert_return (invoke "v128.load16_splat offset=3" (i32.const 65531)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_splat offset=0" (i32.const 256)) (v128.const i32x4 0x7a55300b 0x7a55300b 0x7a55300b 0x7a55300b))
+(assert_return (invoke "v128.load32_splat offset=0" (i32.const 257)) (v128.const i32x4 0x9f7a5530 0x9f7a5530 0x9f7a5530 0x9f7a5530))
+(assert_return (invoke "v128.load32_splat offset=0" (i32.const 263)) (v128.const i32x4 0x7d58330e 0x7d58330e 0x7d58330e 0x7d58330e))
+(assert_return (invoke "v128.load32_splat offset=0" (i32.const 286)) (v128.const i32x4 0xd0ab8661 0xd0ab8661 0xd0ab8661 0xd0ab8661))
+(assert_return (invoke "v128.load32_splat offset=0" (i32.const 65532)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_splat offset=3" (i32.const 256)) (v128.const i32x4 0xe9c49f7a 0xe9c49f7a 0xe9c49f7a 0xe9c49f7a))
+(assert_return (invoke "v128.load32_splat offset=3" (i32.const 257)) (v128.const i32x4 0x0ee9c49f 0x0ee9c49f 0x0ee9c49f 0x0ee9c49f))
+(assert_return (invoke "v128.load32_splat offset=3" (i32.const 263)) (v128.const i32x4 0xecc7a27d 0xecc7a27d 0xecc7a27d 0xecc7a27d))
+(assert_return (invoke "v128.load32_splat offset=3" (i32.const 286)) (v128.const i32x4 0x3f1af5d0 0x3f1af5d0 0x3f1af5d0 0x3f1af5d0))
+(assert_return (invoke "v128.load32_splat offset=3" (i32.const 65529)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_splat offset=0" (i32.const 256)) (v128.const i32x4 0x7a55300b 0x0ee9c49f 0x7a55300b 0x0ee9c49f))
+(assert_return (invoke "v128.load64_splat offset=0" (i32.const 257)) (v128.const i32x4 0x9f7a5530 0x330ee9c4 0x9f7a5530 0x330ee9c4))
+(assert_return (invoke "v128.load64_splat offset=0" (i32.const 263)) (v128.const i32x4 0x7d58330e 0x11ecc7a2 0x7d58330e 0x11ecc7a2))
+(assert_return (invoke "v128.load64_splat offset=0" (i32.const 286)) (v128.const i32x4 0xd0ab8661 0x643f1af5 0xd0ab8661 0x643f1af5))
+(assert_return (invoke "v128.load64_splat offset=0" (i32.const 65528)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_splat offset=3" (i32.const 256)) (v128.const i32x4 0xe9c49f7a 0x7d58330e 0xe9c49f7a 0x7d58330e))
+(assert_return (invoke "v128.load64_splat offset=3" (i32.const 257)) (v128.const i32x4 0x0ee9c49f 0xa27d5833 0x0ee9c49f 0xa27d5833))
+(assert_return (invoke "v128.load64_splat offset=3" (i32.const 263)) (v128.const i32x4 0xecc7a27d 0x805b3611 0xecc7a27d 0x805b3611))
+(assert_return (invoke "v128.load64_splat offset=3" (i32.const 286)) (v128.const i32x4 0x3f1af5d0 0xd3ae8964 0x3f1af5d0 0xd3ae8964))
+(assert_return (invoke "v128.load64_splat offset=3" (i32.const 65525)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_zero offset=0" (i32.const 256)) (v128.const i32x4 0x7a55300b 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_zero offset=0" (i32.const 257)) (v128.const i32x4 0x9f7a5530 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_zero offset=0" (i32.const 263)) (v128.const i32x4 0x7d58330e 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_zero offset=0" (i32.const 286)) (v128.const i32x4 0xd0ab8661 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_zero offset=0" (i32.const 65532)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_zero offset=3" (i32.const 256)) (v128.const i32x4 0xe9c49f7a 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_zero offset=3" (i32.const 257)) (v128.const i32x4 0x0ee9c49f 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_zero offset=3" (i32.const 263)) (v128.const i32x4 0xecc7a27d 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_zero offset=3" (i32.const 286)) (v128.const i32x4 0x3f1af5d0 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_zero offset=3" (i32.const 65529)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_zero offset=0" (i32.const 256)) (v128.const i32x4 0x7a55300b 0x0ee9c49f 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_zero offset=0" (i32.const 257)) (v128.const i32x4 0x9f7a5530 0x330ee9c4 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_zero offset=0" (i32.const 263)) (v128.const i32x4 0x7d58330e 0x11ecc7a2 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_zero offset=0" (i32.const 286)) (v128.const i32x4 0xd0ab8661 0x643f1af5 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_zero offset=0" (i32.const 65528)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_zero offset=3" (i32.const 256)) (v128.const i32x4 0xe9c49f7a 0x7d58330e 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_zero offset=3" (i32.const 257)) (v128.const i32x4 0x0ee9c49f 0xa27d5833 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_zero offset=3" (i32.const 263)) (v128.const i32x4 0xecc7a27d 0x805b3611 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_zero offset=3" (i32.const 286)) (v128.const i32x4 0x3f1af5d0 0xd3ae8964 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_zero offset=3" (i32.const 65525)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x0201001e 0x06050403 0x0a090807 0x0e0d0c0b))
+(assert_return (invoke "v128.store" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x017f8004 0x81fe00ff 0x103fc040 0x7eaa55f0))
+(assert_return (invoke "v128.store" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x01000000 0xffffff00 0x3480007f 0xfffed412))
+(assert_return (invoke "v128.store" (i32.const 515) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x1e1c1a18 0x03020100 0x07060504 0x0b0a0908))
+(assert_return (invoke "v128.store" (i32.const 515) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x04fc54aa 0xff017f80 0x4081fe00 0xf0103fc0))
+(assert_return (invoke "v128.store" (i32.const 515) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00fefca8 0x00010000 0x7fffffff 0x12348000))
+(assert_return (invoke "v128.store" (i32.const 526) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x08060402 0x100e0c0a 0x18161412 0x001e1c1a))
+(assert_return (invoke "v128.store" (i32.const 526) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00fe02fe 0x808002fc 0xaae0207e 0x8004fc54))
+(assert_return (invoke "v128.store" (i32.const 526) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xfe000200 0x00fefefe 0xa8246800 0x0000fefc))
+(assert_return (invoke "v128.store" (i32.const 65519) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load8_lane 0" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffff55 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load8_lane 0" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020155 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load8_lane 0" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f55 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load8_lane 0" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffff0e 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load8_lane 0" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x0302010e 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load8_lane 0" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f0e 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load8_lane 0" (i32.const 65533) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffff0f 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.store8_lane 0" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 0" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00800000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 0" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 0" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 0" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x80000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 0" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 0" (i32.const 65533) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load8_lane 1" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffff55ff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load8_lane 1" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03025500 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load8_lane 1" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff015580 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load8_lane 1" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffff0eff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load8_lane 1" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020e00 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load8_lane 1" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff010e80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load8_lane 1" (i32.const 65533) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffff00ff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.store8_lane 1" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00010000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 1" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x007f0000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 1" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 1" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x01000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 1" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x7f000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 1" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 1" (i32.const 65533) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load8_lane 15" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x55ffffff))
+(assert_return (invoke "v128.load8_lane 15" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x550e0d0c))
+(assert_return (invoke "v128.load8_lane 15" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x557eaa55))
+(assert_return (invoke "v128.load8_lane 15" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0effffff))
+(assert_return (invoke "v128.load8_lane 15" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0e0e0d0c))
+(assert_return (invoke "v128.load8_lane 15" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x0e7eaa55))
+(assert_return (invoke "v128.load8_lane 15" (i32.const 65533) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x01ffffff))
+(assert_return (invoke "v128.store8_lane 15" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x000f0000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 15" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00820000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 15" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 15" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x0f000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 15" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x82000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 15" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store8_lane 15" (i32.const 65533) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load16_lane 0" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffff7a55 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load16_lane 0" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03027a55 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load16_lane 0" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017a55 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load16_lane 0" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffff330e 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load16_lane 0" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x0302330e 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load16_lane 0" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff01330e 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load16_lane 0" (i32.const 65532) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffff0f0e 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.store16_lane 0" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x01000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 0" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f800000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 0" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 0" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000001 0x00000000))
+(assert_return (invoke "v128.store16_lane 0" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x80000000 0x0000007f 0x00000000))
+(assert_return (invoke "v128.store16_lane 0" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 0" (i32.const 65532) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load16_lane 1" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x7a55ffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load16_lane 1" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x7a550100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load16_lane 1" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7a557f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load16_lane 1" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x330effff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load16_lane 1" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x330e0100 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load16_lane 1" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x330e7f80 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load16_lane 1" (i32.const 65532) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0100ffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.store16_lane 1" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 1" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff010000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 1" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00010000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 1" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x02000000 0x00000003 0x00000000))
+(assert_return (invoke "v128.store16_lane 1" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x01000000 0x000000ff 0x00000000))
+(assert_return (invoke "v128.store16_lane 1" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x01000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 1" (i32.const 65532) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load16_lane 7" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x7a55ffff))
+(assert_return (invoke "v128.load16_lane 7" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x7a550d0c))
+(assert_return (invoke "v128.load16_lane 7" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x7a55aa55))
+(assert_return (invoke "v128.load16_lane 7" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x330effff))
+(assert_return (invoke "v128.load16_lane 7" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x330e0d0c))
+(assert_return (invoke "v128.load16_lane 7" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x330eaa55))
+(assert_return (invoke "v128.load16_lane 7" (i32.const 65532) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0302ffff))
+(assert_return (invoke "v128.store16_lane 7" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x0f0e0000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 7" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x827e0000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 7" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00ff0000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 7" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x0e000000 0x0000000f 0x00000000))
+(assert_return (invoke "v128.store16_lane 7" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x7e000000 0x00000082 0x00000000))
+(assert_return (invoke "v128.store16_lane 7" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xff000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.store16_lane 7" (i32.const 65532) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_lane 0" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xc49f7a55 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load32_lane 0" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xc49f7a55 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load32_lane 0" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xc49f7a55 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load32_lane 0" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x7d58330e 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load32_lane 0" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x7d58330e 0x07060504 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load32_lane 0" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7d58330e 0x4081fe00 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load32_lane 0" (i32.const 65530) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0f0e0d0c 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.store32_lane 0" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x01000000 0x00000302 0x00000000 0x00000000))
+(assert_return (invoke "v128.store32_lane 0" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f800000 0x0000ff01 0x00000000 0x00000000))
+(assert_return (invoke "v128.store32_lane 0" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000001 0x00000000 0x00000000))
+(assert_return (invoke "v128.store32_lane 0" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00030201 0x00000000))
+(assert_return (invoke "v128.store32_lane 0" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x80000000 0x00ff017f 0x00000000))
+(assert_return (invoke "v128.store32_lane 0" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0x00000100 0x00000000))
+(assert_return (invoke "v128.store32_lane 0" (i32.const 65530) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_lane 1" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xc49f7a55 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load32_lane 1" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0xc49f7a55 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load32_lane 1" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0xc49f7a55 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load32_lane 1" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0x7d58330e 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load32_lane 1" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x7d58330e 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load32_lane 1" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x7d58330e 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load32_lane 1" (i32.const 65530) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0x03020100 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.store32_lane 1" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x05040000 0x00000706 0x00000000 0x00000000))
+(assert_return (invoke "v128.store32_lane 1" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xfe000000 0x00004081 0x00000000 0x00000000))
+(assert_return (invoke "v128.store32_lane 1" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xffff0000 0x00007fff 0x00000000 0x00000000))
+(assert_return (invoke "v128.store32_lane 1" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x04000000 0x00070605 0x00000000))
+(assert_return (invoke "v128.store32_lane 1" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x00000000 0x004081fe 0x00000000))
+(assert_return (invoke "v128.store32_lane 1" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xff000000 0x007fffff 0x00000000))
+(assert_return (invoke "v128.store32_lane 1" (i32.const 65530) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load32_lane 3" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xc49f7a55))
+(assert_return (invoke "v128.load32_lane 3" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0xc49f7a55))
+(assert_return (invoke "v128.load32_lane 3" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0xc49f7a55))
+(assert_return (invoke "v128.load32_lane 3" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x7d58330e))
+(assert_return (invoke "v128.load32_lane 3" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x7d58330e))
+(assert_return (invoke "v128.load32_lane 3" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x7d58330e))
+(assert_return (invoke "v128.load32_lane 3" (i32.const 65530) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x07060504))
+(assert_return (invoke "v128.store32_lane 3" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x0d0c0000 0x00000f0e 0x00000000 0x00000000))
+(assert_return (invoke "v128.store32_lane 3" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xaa550000 0x0000827e 0x00000000 0x00000000))
+(assert_return (invoke "v128.store32_lane 3" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0xfed40000 0x000000ff 0x00000000 0x00000000))
+(assert_return (invoke "v128.store32_lane 3" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x0c000000 0x000f0e0d 0x00000000))
+(assert_return (invoke "v128.store32_lane 3" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x55000000 0x00827eaa 0x00000000))
+(assert_return (invoke "v128.store32_lane 3" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xd4000000 0x0000fffe 0x00000000))
+(assert_return (invoke "v128.store32_lane 3" (i32.const 65530) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_lane 0" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xc49f7a55 0x58330ee9 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load64_lane 0" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0xc49f7a55 0x58330ee9 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load64_lane 0" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xc49f7a55 0x58330ee9 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load64_lane 0" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x7d58330e 0x11ecc7a2 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.load64_lane 0" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x7d58330e 0x11ecc7a2 0x0b0a0908 0x0f0e0d0c))
+(assert_return (invoke "v128.load64_lane 0" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7d58330e 0x11ecc7a2 0xf0103fc0 0x827eaa55))
+(assert_return (invoke "v128.load64_lane 0" (i32.const 65526) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0b0a0908 0x0f0e0d0c 0xffffffff 0xffffffff))
+(assert_return (invoke "v128.store64_lane 0" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x01000000 0x05040302 0x00000706 0x00000000))
+(assert_return (invoke "v128.store64_lane 0" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x7f800000 0xfe00ff01 0x00004081 0x00000000))
+(assert_return (invoke "v128.store64_lane 0" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0xffff0001 0x00007fff 0x00000000))
+(assert_return (invoke "v128.store64_lane 0" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x04030201 0x00070605))
+(assert_return (invoke "v128.store64_lane 0" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0x80000000 0x00ff017f 0x004081fe))
+(assert_return (invoke "v128.store64_lane 0" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xff000100 0x007fffff))
+(assert_return (invoke "v128.store64_lane 0" (i32.const 65526) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
+(assert_return (invoke "v128.load64_lane 1" (i32.const 256) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xc49f7a55 0x58330ee9))
+(assert_return (invoke "v128.load64_lane 1" (i32.const 256) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0xc49f7a55 0x58330ee9))
+(assert_return (invoke "v128.load64_lane 1" (i32.const 256) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0xc49f7a55 0x58330ee9))
+(assert_return (invoke "v128.load64_lane 1" (i32.const 261) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x7d58330e 0x11ecc7a2))
+(assert_return (invoke "v128.load64_lane 1" (i32.const 261) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x03020100 0x07060504 0x7d58330e 0x11ecc7a2))
+(assert_return (invoke "v128.load64_lane 1" (i32.const 261) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0xff017f80 0x4081fe00 0x7d58330e 0x11ecc7a2))
+(assert_return (invoke "v128.load64_lane 1" (i32.const 65526) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0x03020100 0x07060504))
+(assert_return (invoke "v128.store64_lane 1" (i32.const 512) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x09080000 0x0d0c0b0a 0x00000f0e 0x00000000))
+(assert_return (invoke "v128.store64_lane 1" (i32.const 512) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x3fc00000 0xaa55f010 0x0000827e 0x00000000))
+(assert_return (invoke "v128.store64_lane 1" (i32.const 512) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x80000000 0xfed41234 0x000000ff 0x00000000))
+(assert_return (invoke "v128.store64_lane 1" (i32.const 517) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x08000000 0x0c0b0a09 0x000f0e0d))
+(assert_return (invoke "v128.store64_lane 1" (i32.const 517) (v128.const i32x4 0xff017f80 0x4081fe00 0xf0103fc0 0x827eaa55)) (v128.const i32x4 0x00000000 0xc0000000 0x55f0103f 0x00827eaa))
+(assert_return (invoke "v128.store64_lane 1" (i32.const 517) (v128.const i32x4 0x00010000 0x7fffffff 0x12348000 0x00fffed4)) (v128.const i32x4 0x00000000 0x00000000 0xd4123480 0x0000fffe))
+(assert_return (invoke "v128.store64_lane 1" (i32.const 65526) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) (v128.const i32x4 0x00000000 0x00000000 0x00000000 0x00000000))
@@ -0,0 +1,229 @@
+;; Generated lane by lane against V8, covering out of bounds load and store operations
+
+(module
+  (memory 1)
+  (data (i32.const 256) "\0b\30\55\7a\9f\c4\e9\0e\33\58\7d\a2\c7\ec\11\36\5b\80\a5\ca\ef\14\39\5e\83\a8\cd\f2\17\3c\61\86\ab\d0\f5\1a\3f\64\89\ae\d3\f8\1d\42\67\8c\b1\d6\fb\20\45\6a\8f\b4\d9\fe\23\48\6d\92\b7\dc\01\26")
+  (func (export "v128.load offset=0") (param i32) (result v128)
+    (v128.load offset=0 (local.get 0))
+  )
+  (func (export "v128.load offset=3") (param i32) (result v128)
+    (v128.load offset=3 (local.get 0))
+  )
+  (func (export "v128.load8x8_s offset=0") (param i32) (result v128)
+    (v128.load8x8_s offset=0 (local.get 0))
+  )
+  (func (export "v128.load8x8_s offset=3") (param i32) (result v128)
+    (v128.load8x8_s offset=3 (local.get 0))
+  )
+  (func (export "v128.load8x8_u offset=0") (param i32) (result v128)
+    (v128.load8x8_u offset=0 (local.get 0))
+  )
+  (func (export "v128.load8x8_u offset=3") (param i32) (result v128)
+    (v128.load8x8_u offset=3 (local.get 0))
+  )
+  (func (export "v128.load16x4_s offset=0") (param i32) (result v128)
+    (v128.load16x4_s offset=0 (local.get 0))
+  )
+  (func (export "v128.load16x4_s offset=3") (param i32) (result v128)
+    (v128.load16x4_s offset=3 (local.get 0))
+  )
+  (func (export "v128.load16x4_u offset=0") (param i32) (result v128)
+    (v128.load16x4_u offset=0 (local.get 0))
+  )
+  (func (export "v128.load16x4_u offset=3") (param i32) (result v128)
+    (v128.load16x4_u offset=3 (local.get 0))
+  )
+  (func (export "v128.load32x2_s offset=0") (param i32) (result v128)
+    (v128.load32x2_s offset=0 (local.get 0))
+  )
+  (func (export "v128.load32x2_s offset=3") (param i32) (result v128)
+    (v128.load32x2_s offset=3 (local.get 0))
+  )
+  (func (export "v128.load32x2_u offset=0") (param i32) (result v128)
+    (v128.load32x2_u offset=0 (local.get 0))
+  )
+  (func (export "v128.load32x2_u offset=3") (param i32) (result v128)
+    (v128.load32x2_u offset=3 (local.get 0))
+  )
+  (func (export "v128.load8_splat offset=0") (param i32) (result v128)
+    (v128.load8_splat offset=0 (local.get 0))
+  )
+  (func (export "v128.load8_splat offset=3") (param i32) (result v128)
+    (v128.load8_splat offset=3 (local.get 0))
+  )
+  (func (export "v128.load16_splat offset=0") (param i32) (result v128)
+    (v128.load16_splat offset=0 (local.get 0))
+  )
+  (func (export "v128.load16_splat offset=3") (param i32) (result v128)
+    (v128.load16_splat offset=3 (local.get 0))
+  )
+  (func (export "v128.load32_splat offset=0") (param i32) (result v128)
+    (v128.load32_splat offset=0 (local.get 0))
+  )
+  (func (export "v128.load32_splat offset=3") (param i32) (result v128)
+    (v128.load32_splat offset=3 (local.get 0))
+  )
+  (func (export "v128.load64_splat offset=0") (param i32) (result v128)
+    (v128.load64_splat offset=0 (local.get 0))
+  )
+  (func (export "v128.load64_splat offset=3") (param i32) (result v128)
+    (v128.load64_splat offset=3 (local.get 0))
+  )
+  (func (export "v128.load32_zero offset=0") (param i32) (result v128)
+    (v128.load32_zero offset=0 (local.get 0))
+  )
+  (func (export "v128.load32_zero offset=3") (param i32) (result v128)
+    (v128.load32_zero offset=3 (local.get 0))
+  )
+  (func (export "v128.load64_zero offset=0") (param i32) (result v128)
+    (v128.load64_zero offset=0 (local.get 0))
+  )
+  (func (export "v128.load64_zero offset=3") (param i32) (result v128)
+    (v128.load64_zero offset=3 (local.get 0))
+  )
+  (func (export "v128.store") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store offset=1 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load8_lane 0") (param i32 v128) (result v128)
+    (v128.load8_lane offset=2 0 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store8_lane 0") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store8_lane offset=2 0 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load8_lane 1") (param i32 v128) (result v128)
+    (v128.load8_lane offset=2 1 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store8_lane 1") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store8_lane offset=2 1 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load8_lane 15") (param i32 v128) (result v128)
+    (v128.load8_lane offset=2 15 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store8_lane 15") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store8_lane offset=2 15 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load16_lane 0") (param i32 v128) (result v128)
+    (v128.load16_lane offset=2 0 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store16_lane 0") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store16_lane offset=2 0 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load16_lane 1") (param i32 v128) (result v128)
+    (v128.load16_lane offset=2 1 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store16_lane 1") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store16_lane offset=2 1 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load16_lane 7") (param i32 v128) (result v128)
+    (v128.load16_lane offset=2 7 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store16_lane 7") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store16_lane offset=2 7 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load32_lane 0") (param i32 v128) (result v128)
+    (v128.load32_lane offset=2 0 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store32_lane 0") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store32_lane offset=2 0 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load32_lane 1") (param i32 v128) (result v128)
+    (v128.load32_lane offset=2 1 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store32_lane 1") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store32_lane offset=2 1 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load32_lane 3") (param i32 v128) (result v128)
+    (v128.load32_lane offset=2 3 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store32_lane 3") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store32_lane offset=2 3 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load64_lane 0") (param i32 v128) (result v128)
+    (v128.load64_lane offset=2 0 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store64_lane 0") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store64_lane offset=2 0 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+  (func (export "v128.load64_lane 1") (param i32 v128) (result v128)
+    (v128.load64_lane offset=2 1 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store64_lane 1") (param i32 v128) (result v128)
+    (block (result v128) (v128.store (i32.const 512) (v128.const i64x2 0 0)) (v128.store (i32.const 528) (v128.const i64x2 0 0)) (v128.store64_lane offset=2 1 (local.get 0) (local.get 1)) (i8x16.add (v128.load (i32.const 512)) (i8x16.shl (v128.load (i32.const 528)) (i32.const 1))))
+  )
+)
+
+(assert_trap (invoke "v128.load offset=0" (i32.const 65521)) "out of bounds memory access")
+(assert_trap (invoke "v128.load offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load offset=3" (i32.const 65518)) "out of bounds memory access")
+(assert_trap (invoke "v128.load offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8x8_s offset=0" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8x8_s offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8x8_s offset=3" (i32.const 65526)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8x8_s offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8x8_u offset=0" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8x8_u offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8x8_u offset=3" (i32.const 65526)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8x8_u offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16x4_s offset=0" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16x4_s offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16x4_s offset=3" (i32.const 65526)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16x4_s offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16x4_u offset=0" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16x4_u offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16x4_u offset=3" (i32.const 65526)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16x4_u offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32x2_s offset=0" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32x2_s offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32x2_s offset=3" (i32.const 65526)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32x2_s offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32x2_u offset=0" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32x2_u offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32x2_u offset=3" (i32.const 65526)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32x2_u offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8_splat offset=0" (i32.const 65536)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8_splat offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8_splat offset=3" (i32.const 65533)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8_splat offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16_splat offset=0" (i32.const 65535)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16_splat offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16_splat offset=3" (i32.const 65532)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16_splat offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_splat offset=0" (i32.const 65533)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_splat offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_splat offset=3" (i32.const 65530)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_splat offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load64_splat offset=0" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load64_splat offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load64_splat offset=3" (i32.const 65526)) "out of bounds memory access")
+(assert_trap (invoke "v128.load64_splat offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_zero offset=0" (i32.const 65533)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_zero offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_zero offset=3" (i32.const 65530)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_zero offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load64_zero offset=0" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load64_zero offset=0" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.load64_zero offset=3" (i32.const 65526)) "out of bounds memory access")
+(assert_trap (invoke "v128.load64_zero offset=3" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "v128.store" (i32.const 65520) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8_lane 0" (i32.const 65534) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store8_lane 0" (i32.const 65534) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8_lane 1" (i32.const 65534) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store8_lane 1" (i32.const 65534) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8_lane 15" (i32.const 65534) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store8_lane 15" (i32.const 65534) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16_lane 0" (i32.const 65533) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store16_lane 0" (i32.const 65533) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16_lane 1" (i32.const 65533) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store16_lane 1" (i32.const 65533) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16_lane 7" (i32.const 65533) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store16_lane 7" (i32.const 65533) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_lane 0" (i32.const 65531) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store32_lane 0" (i32.const 65531) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_lane 1" (i32.const 65531) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store32_lane 1" (i32.const 65531) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32_lane 3" (i32.const 65531) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store32_lane 3" (i32.const 65531) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load64_lane 0" (i32.const 65527) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store64_lane 0" (i32.const 65527) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
+(assert_trap (invoke "v128.load64_lane 1" (i32.const 65527) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) "out of bounds memory access")
+(assert_trap (invoke "v128.store64_lane 1" (i32.const 65527) (v128.const i32x4 0x03020100 0x07060504 0x0b0a0908 0x0f0e0d0c)) "out of bounds memory access")
@@ -0,0 +1,77 @@
+;; Tail calls deep enough to overflow the stack unless frames are reused
+
+(module
+  (type $countdown (func (param i64) (result i64)))
+
+  (table $table funcref (elem $count-indirect $plain))
+
+
+  (func $count (export "count") (param i64 i64) (result i64)
+    (if (result i64) (i64.eqz (local.get 0))
+      (then (local.get 1))
+      (else
+        (return_call $count
+          (i64.sub (local.get 0) (i64.const 1))
+          (i64.add (local.get 1) (local.get 0))))))
+
+  (func $even (export "even") (param i64) (result i32)
+    (if (result i32) (i64.eqz (local.get 0))
+      (then (i32.const 1))
+      (else (return_call $odd (i64.sub (local.get 0) (i64.const 1))))))
+
+  (func $odd (export "odd") (param i64) (result i32)
+    (if (result i32) (i64.eqz (local.get 0))
+      (then (i32.const 0))
+      (else (return_call $even (i64.sub (local.get 0) (i64.const 1))))))
+
+  (func $count-indirect (type $countdown)
+    (if (result i64) (i64.eqz (local.get 0))
+      (then (i64.const 42))
+      (else
+        (return_call_indirect (type $countdown)
+          (i64.sub (local.get 0) (i64.const 1))
+          (i32.const 0)))))
+
+  (func (export "count-indirect") (param i64) (result i64)
+    (call_indirect (type $countdown) (local.get 0) (i32.const 0)))
+
+  (func $plain (type $countdown)
+    (i64.mul (local.get 0) (i64.const 2)))
+
+  (func (export "to-plain") (param i64) (result i64)
+    (return_call $plain (local.get 0)))
+
+  (func (export "to-plain-indirect") (param i64) (result i64)
+    (return_call_indirect (type $countdown) (local.get 0) (i32.const 1)))
+
+  (func (export "to-null") (param i64) (result i64)
+    (return_call_indirect (type $countdown) (local.get 0) (i32.const 2)))
+
+  (func $swap (param i32 i64) (result i64 i32)
+    (local.get 1)
+    (local.get 0))
+
+  (func (export "multi") (param i32 i64) (result i64 i32)
+    (return_call $swap (local.get 0) (local.get 1)))
+
+  (func $none (export "none") (param i32)
+    (if (local.get 0)
+      (then (return_call $none (i32.sub (local.get 0) (i32.const 1))))))
+
+  (func (export "nested") (param i64) (result i64)
+    (i64.add
+      (call $count (local.get 0) (i64.const 0))
+      (call $count (local.get 0) (i64.const 0))))
+)
+
+(assert_return (invoke "count" (i64.const 100000) (i64.const 0)) (i64.const 5000050000))
+(assert_return (invoke "even" (i64.const 100000)) (i32.const 1))
+(assert_return (invoke "even" (i64.const 100001)) (i32.const 0))
+(assert_return (invoke "odd" (i64.const 100001)) (i32.const 1))
+(assert_return (invoke "count-indirect" (i64.const 100000)) (i64.const 42))
+(assert_return (invoke "to-plain" (i64.const 21)) (i64.const 42))
+(assert_return (invoke "to-plain-indirect" (i64.const 4)) (i64.const 8))
+(assert_trap (invoke "to-null" (i64.const 0)) "undefined element")
+(assert_return (invoke "multi" (i32.const 1) (i64.const 2)) (i64.const 2) (i32.const 1))
+(assert_return (invoke "none" (i32.const 100000)))
+(assert_return (invoke "nested" (i64.const 100000)) (i64.const 10000100000))
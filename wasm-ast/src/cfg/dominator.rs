@@ -0,0 +1,270 @@
+use super::{Cfg, ENTRY};
+
+const UNREACHABLE: usize = usize::MAX;
+
+// Immediate dominators found with the iterative algorithm of Cooper,
+// Harvey and Kennedy, which converges in a couple of passes over the
+// reducible graphs that structured control flow produces
+pub struct Dominators {
+	idom_list: Vec<Option<usize>>,
+	order_list: Vec<usize>,
+}
+
+impl Dominators {
+	#[must_use]
+	pub fn from_cfg(cfg: &Cfg) -> Self {
+		let order = cfg.reverse_post_order();
+		let mut order_list = vec![UNREACHABLE; cfg.len()];
+		let mut idom_list = vec![None; cfg.len()];
+
+		for (i, &id) in order.iter().enumerate() {
+			order_list[id] = i;
+		}
+
+		idom_list[ENTRY] = Some(ENTRY);
+
+		let mut has_changed = true;
+
+		while has_changed {
+			has_changed = false;
+
+			for &id in &order[1..] {
+				let mut idom = None;
+
+				for &predecessor in cfg.node_list()[id].predecessor_list() {
+					if idom_list[predecessor].is_none() {
+						continue;
+					}
+
+					idom = Some(idom.map_or(predecessor, |idom| {
+						intersect(&idom_list, &order_list, predecessor, idom)
+					}));
+				}
+
+				if idom.is_some() && idom_list[id] != idom {
+					idom_list[id] = idom;
+					has_changed = true;
+				}
+			}
+		}
+
+		idom_list[ENTRY] = None;
+
+		Self {
+			idom_list,
+			order_list,
+		}
+	}
+
+	#[must_use]
+	pub fn is_reachable(&self, id: usize) -> bool {
+		self.order_list[id] != UNREACHABLE
+	}
+
+	/// Position of the node in the reverse post order the dominators were
+	/// computed on, or `None` if it is unreachable.
+	#[must_use]
+	pub fn order(&self, id: usize) -> Option<usize> {
+		self.is_reachable(id).then_some(self.order_list[id])
+	}
+
+	/// The closest strict dominator of the node, or `None` for the entry
+	/// and for unreachable nodes.
+	#[must_use]
+	pub fn immediate_dominator(&self, id: usize) -> Option<usize> {
+		self.idom_list[id]
+	}
+
+	/// Every node dominates itself. Unreachable nodes are dominated by none.
+	#[must_use]
+	pub fn dominates(&self, dominator: usize, id: usize) -> bool {
+		if !self.is_reachable(id) {
+			return false;
+		}
+
+		let mut current = Some(id);
+
+		while let Some(now) = current {
+			if now == dominator {
+				return true;
+			}
+
+			current = self.idom_list[now];
+		}
+
+		false
+	}
+
+	/// Strict dominators of the node, from the closest up to the entry.
+	pub fn dominator_list(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+		std::iter::successors(self.idom_list[id], |&v| self.idom_list[v])
+	}
+}
+
+fn intersect(idom_list: &[Option<usize>], order_list: &[usize], lhs: usize, rhs: usize) -> usize {
+	let (mut lhs, mut rhs) = (lhs, rhs);
+
+	while lhs != rhs {
+		while order_list[lhs] > order_list[rhs] {
+			lhs = idom_list[lhs].unwrap();
+		}
+
+		while order_list[rhs] > order_list[lhs] {
+			rhs = idom_list[rhs].unwrap();
+		}
+	}
+
+	lhs
+}
+
+pub struct Loop {
+	header: usize,
+	body: Vec<usize>,
+	parent: Option<usize>,
+	depth: usize,
+}
+
+impl Loop {
+	#[must_use]
+	pub const fn header(&self) -> usize {
+		self.header
+	}
+
+	/// Sorted ids of the nodes in the loop, header included.
+	#[must_use]
+	pub fn body(&self) -> &[usize] {
+		&self.body
+	}
+
+	/// Index of the closest enclosing loop in the `LoopNest`.
+	#[must_use]
+	pub const fn parent(&self) -> Option<usize> {
+		self.parent
+	}
+
+	/// Number of loops this one is nested in, counting itself.
+	#[must_use]
+	pub const fn depth(&self) -> usize {
+		self.depth
+	}
+}
+
+// Natural loops, one per header, ordered so that every loop comes after
+// the loops it is nested in
+pub struct LoopNest {
+	loop_list: Vec<Loop>,
+	innermost_list: Vec<Option<usize>>,
+	is_reducible: bool,
+}
+
+impl LoopNest {
+	#[must_use]
+	pub fn from_cfg(cfg: &Cfg, dominators: &Dominators) -> Self {
+		let mut loop_list = Vec::new();
+		let mut is_reducible = true;
+
+		for (id, node) in cfg.node_list().iter().enumerate() {
+			let Some(order) = dominators.order(id) else {
+				continue;
+			};
+
+			// Retreating edges have to go to a dominator to form a loop
+			let mut latch_list = Vec::new();
+
+			let retreating_list = node
+				.predecessor_list()
+				.iter()
+				.filter(|&&v| dominators.order(v).is_some_and(|v| v >= order));
+
+			for &latch in retreating_list {
+				if dominators.dominates(id, latch) {
+					latch_list.push(latch);
+				} else {
+					is_reducible = false;
+				}
+			}
+
+			if !latch_list.is_empty() {
+				loop_list.push(Loop {
+					header: id,
+					body: find_loop_body(cfg, dominators, id, latch_list),
+					parent: None,
+					depth: 1,
+				});
+			}
+		}
+
+		// Natural loops with distinct headers are either nested or disjoint
+		loop_list.sort_by_key(|v| std::cmp::Reverse(v.body.len()));
+
+		let mut innermost_list = vec![None; cfg.len()];
+
+		for i in 0..loop_list.len() {
+			let header = loop_list[i].header;
+
+			if let Some(parent) = innermost_list[header] {
+				loop_list[i].parent = Some(parent);
+				loop_list[i].depth = loop_list[parent].depth + 1;
+			}
+
+			for &id in &loop_list[i].body {
+				innermost_list[id] = Some(i);
+			}
+		}
+
+		Self {
+			loop_list,
+			innermost_list,
+			is_reducible,
+		}
+	}
+
+	#[must_use]
+	pub fn loop_list(&self) -> &[Loop] {
+		&self.loop_list
+	}
+
+	/// Index of the innermost loop containing the node.
+	#[must_use]
+	pub fn innermost(&self, id: usize) -> Option<usize> {
+		self.innermost_list[id]
+	}
+
+	/// Number of loops containing the node, or 0 outside of any.
+	#[must_use]
+	pub fn loop_depth(&self, id: usize) -> usize {
+		self.innermost_list[id].map_or(0, |v| self.loop_list[v].depth)
+	}
+
+	/// Whether every cycle in the graph has a single entry. This holds for
+	/// anything built from wasm, but is checked rather than assumed.
+	#[must_use]
+	pub const fn is_reducible(&self) -> bool {
+		self.is_reducible
+	}
+}
+
+fn find_loop_body(
+	cfg: &Cfg,
+	dominators: &Dominators,
+	header: usize,
+	mut pending: Vec<usize>,
+) -> Vec<usize> {
+	let mut is_visited = vec![false; cfg.len()];
+
+	is_visited[header] = true;
+
+	while let Some(id) = pending.pop() {
+		if !dominators.is_reachable(id) || std::mem::replace(&mut is_visited[id], true) {
+			continue;
+		}
+
+		pending.extend_from_slice(cfg.node_list()[id].predecessor_list());
+	}
+
+	is_visited
+		.iter()
+		.enumerate()
+		.filter_map(|(id, &v)| v.then_some(id))
+		.collect()
+}
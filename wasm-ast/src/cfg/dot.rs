@@ -0,0 +1,94 @@
+use std::io::{Result, Write};
+
+use crate::{
+	node::Terminator,
+	print::{Print, Printer},
+};
+
+use super::{BasicBlock, Branch, Cfg, ENTRY, EXIT};
+
+// Statements are printed one per line, and the branch ending the node is
+// summarized since its targets are already shown as edges
+fn write_label_text(node: &BasicBlock, w: &mut dyn Write) -> Result<()> {
+	let printer = &mut Printer::default();
+
+	for stat in node.code() {
+		stat.print(printer, w)?;
+	}
+
+	match node.last() {
+		Some(Branch::BrIf(v)) => {
+			write!(w, "br_if ")?;
+			v.condition().print(printer, w)?;
+		}
+		Some(Branch::If(v)) => {
+			write!(w, "if ")?;
+			v.condition().print(printer, w)?;
+		}
+		Some(Branch::Terminator(Terminator::Br(_))) => write!(w, "br")?,
+		Some(Branch::Terminator(Terminator::BrTable(v))) => {
+			write!(w, "br_table ")?;
+			v.condition().print(printer, w)?;
+		}
+		Some(Branch::Terminator(Terminator::Rethrow(_))) => write!(w, "rethrow")?,
+		Some(Branch::Terminator(v)) => v.print(printer, w)?,
+		None => {}
+	}
+
+	Ok(())
+}
+
+fn write_escaped(data: &[u8], w: &mut dyn Write) -> Result<()> {
+	for line in String::from_utf8_lossy(data).lines() {
+		for c in line.trim_start().chars() {
+			match c {
+				'"' | '\\' => write!(w, "\\{c}")?,
+				c => write!(w, "{c}")?,
+			}
+		}
+
+		write!(w, "\\l")?;
+	}
+
+	Ok(())
+}
+
+impl Cfg<'_> {
+	/// Writes the graph in the DOT format of Graphviz as a `digraph` called
+	/// `name`. Exceptional edges are drawn dashed.
+	///
+	/// # Errors
+	/// Returns `Err` if writing to `Write` failed.
+	pub fn write_dot(&self, name: &str, w: &mut dyn Write) -> Result<()> {
+		writeln!(w, "digraph \"{name}\" {{")?;
+		writeln!(w, "\tnode [shape=box, fontname=monospace];")?;
+
+		for (id, node) in self.node_list().iter().enumerate() {
+			let mut text = Vec::new();
+
+			write_label_text(node, &mut text)?;
+			write!(w, "\tn{id} [label=\"")?;
+
+			match id {
+				ENTRY => write!(w, "entry\\l")?,
+				EXIT => write!(w, "exit\\l")?,
+				_ => write!(w, "#{id}\\l")?,
+			}
+
+			write_escaped(&text, w)?;
+			writeln!(w, "\"];")?;
+		}
+
+		for (id, node) in self.node_list().iter().enumerate() {
+			for successor in node.successor_list() {
+				writeln!(w, "\tn{id} -> n{successor};")?;
+			}
+
+			for handler in node.handler_list() {
+				writeln!(w, "\tn{id} -> n{handler} [style=dashed];")?;
+			}
+		}
+
+		writeln!(w, "}}")
+	}
+}
@@ -0,0 +1,297 @@
+pub mod dominator;
+pub mod dot;
+
+use crate::node::{Block, BrIf, FuncData, If, LabelType, Statement, Terminator, Try};
+
+// Every graph starts with an empty entry node, and all returns, traps and
+// throws that leave the function meet in a single exit node
+pub const ENTRY: usize = 0;
+pub const EXIT: usize = 1;
+
+#[derive(Clone, Copy)]
+pub enum Branch<'a> {
+	/// Successors are the branch target, then the following code.
+	BrIf(&'a BrIf),
+	/// Successors are the start of `on_true`, then the start of `on_false`
+	/// or the code after the `If` if there is none.
+	If(&'a If),
+	/// Successors are the distinct branch targets in the order they appear,
+	/// or the exit node for anything other than `Br` and `BrTable`.
+	Terminator(&'a Terminator),
+}
+
+#[derive(Default)]
+pub struct BasicBlock<'a> {
+	code: Vec<&'a Statement>,
+	last: Option<Branch<'a>>,
+	successor_list: Vec<usize>,
+	handler_list: Vec<usize>,
+	predecessor_list: Vec<usize>,
+}
+
+impl<'a> BasicBlock<'a> {
+	/// Straight-line statements, which never contain control flow of their own.
+	#[must_use]
+	pub fn code(&self) -> &[&'a Statement] {
+		&self.code
+	}
+
+	/// How control leaves the node, or `None` if it falls through to its
+	/// only successor.
+	#[must_use]
+	pub const fn last(&self) -> Option<Branch<'a>> {
+		self.last
+	}
+
+	#[must_use]
+	pub fn successor_list(&self) -> &[usize] {
+		&self.successor_list
+	}
+
+	/// Entries of the `catch` blocks that an exception raised in this node
+	/// may land in. Any node inside a `try` body is assumed to throw.
+	#[must_use]
+	pub fn handler_list(&self) -> &[usize] {
+		&self.handler_list
+	}
+
+	/// Predecessors through both normal and exceptional edges.
+	#[must_use]
+	pub fn predecessor_list(&self) -> &[usize] {
+		&self.predecessor_list
+	}
+
+	pub fn successors(&self) -> impl Iterator<Item = usize> + '_ {
+		self.successor_list
+			.iter()
+			.chain(&self.handler_list)
+			.copied()
+	}
+}
+
+pub struct Cfg<'a> {
+	node_list: Vec<BasicBlock<'a>>,
+}
+
+impl<'a> Cfg<'a> {
+	#[must_use]
+	pub fn from_func(func: &'a FuncData) -> Self {
+		let mut builder = Builder {
+			node_list: Vec::new(),
+			label_list: Vec::new(),
+			current: ENTRY,
+		};
+
+		builder.add_node();
+		builder.add_node();
+		builder.add_scope(func.code(), EXIT, EXIT);
+
+		Self {
+			node_list: builder.node_list,
+		}
+	}
+
+	/// Nodes indexed by their id. Code that can never run, such as the
+	/// end of a block that always branches away, is kept as nodes without
+	/// predecessors.
+	#[must_use]
+	pub fn node_list(&self) -> &[BasicBlock<'a>] {
+		&self.node_list
+	}
+
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.node_list.len()
+	}
+
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.node_list.is_empty()
+	}
+
+	/// Nodes reachable from the entry, in reverse post order of a depth
+	/// first search. Edges going to a node that is not later in the order
+	/// are exactly the retreating edges of that search.
+	#[must_use]
+	pub fn reverse_post_order(&self) -> Vec<usize> {
+		let mut visited = vec![false; self.len()];
+		let mut stack = vec![(ENTRY, 0)];
+		let mut order = Vec::new();
+
+		visited[ENTRY] = true;
+
+		while let Some(last) = stack.last_mut() {
+			let (id, next) = *last;
+
+			if let Some(successor) = self.node_list[id].successors().nth(next) {
+				last.1 += 1;
+
+				if !visited[successor] {
+					visited[successor] = true;
+					stack.push((successor, 0));
+				}
+			} else {
+				order.push(id);
+				stack.pop();
+			}
+		}
+
+		order.reverse();
+		order
+	}
+}
+
+struct Builder<'a> {
+	node_list: Vec<BasicBlock<'a>>,
+	label_list: Vec<usize>,
+	current: usize,
+}
+
+impl<'a> Builder<'a> {
+	fn add_node(&mut self) -> usize {
+		self.node_list.push(BasicBlock::default());
+		self.node_list.len() - 1
+	}
+
+	fn add_edge(&mut self, from: usize, to: usize) {
+		if self.node_list[from].successor_list.contains(&to) {
+			return;
+		}
+
+		self.node_list[from].successor_list.push(to);
+		self.node_list[to].predecessor_list.push(from);
+	}
+
+	fn add_handler_edge(&mut self, from: usize, to: usize) {
+		self.node_list[from].handler_list.push(to);
+		self.node_list[to].predecessor_list.push(from);
+	}
+
+	fn set_last(&mut self, last: Branch<'a>) {
+		self.node_list[self.current].last = Some(last);
+	}
+
+	fn get_label(&self, target: usize) -> usize {
+		self.label_list[self.label_list.len() - 1 - target]
+	}
+
+	// Code of `block` continues the current node, with branches to its
+	// label going to `label` and the end of the code falling into `after`
+	fn add_scope(&mut self, block: &'a Block, label: usize, after: usize) {
+		self.label_list.push(label);
+
+		block.code().iter().for_each(|v| self.add_statement(v));
+
+		match block.last() {
+			Some(last) => self.add_terminator(last),
+			None => self.add_edge(self.current, after),
+		}
+
+		self.label_list.pop();
+		self.current = after;
+	}
+
+	fn add_block(&mut self, block: &'a Block) {
+		if block.label_type() == Some(LabelType::Backward) {
+			let header = self.add_node();
+			let after = self.add_node();
+
+			self.add_edge(self.current, header);
+			self.current = header;
+			self.add_scope(block, header, after);
+		} else {
+			let after = self.add_node();
+
+			self.add_scope(block, after, after);
+		}
+	}
+
+	fn add_br_if(&mut self, br_if: &'a BrIf) {
+		let from = self.current;
+		let next = self.add_node();
+
+		self.set_last(Branch::BrIf(br_if));
+		self.add_edge(from, self.get_label(br_if.target().target()));
+		self.add_edge(from, next);
+		self.current = next;
+	}
+
+	fn add_if(&mut self, if_: &'a If) {
+		let from = self.current;
+		let on_true = self.add_node();
+		let on_false = if_.on_false().map(|v| (v, self.add_node()));
+		let after = self.add_node();
+
+		self.set_last(Branch::If(if_));
+		self.add_edge(from, on_true);
+		self.add_edge(from, on_false.map_or(after, |v| v.1));
+
+		self.current = on_true;
+		self.add_scope(if_.on_true(), after, after);
+
+		if let Some((block, entry)) = on_false {
+			self.current = entry;
+			self.add_scope(block, after, after);
+		}
+	}
+
+	fn add_try(&mut self, try_: &'a Try) {
+		let body = self.add_node();
+		let after = self.add_node();
+		let handler_list: Vec<_> = try_
+			.catch_list()
+			.iter()
+			.map(|v| v.block())
+			.chain(try_.catch_all())
+			.map(|v| (v, self.add_node()))
+			.collect();
+
+		self.add_edge(self.current, body);
+
+		let start = self.node_list.len();
+
+		self.current = body;
+		self.add_scope(try_.body(), after, after);
+
+		for id in std::iter::once(body).chain(start..self.node_list.len()) {
+			for &(_, handler) in &handler_list {
+				self.add_handler_edge(id, handler);
+			}
+		}
+
+		for (block, entry) in handler_list {
+			self.current = entry;
+			self.add_scope(block, after, after);
+		}
+	}
+
+	fn add_statement(&mut self, stat: &'a Statement) {
+		match stat {
+			Statement::Block(v) => self.add_block(v),
+			Statement::BrIf(v) => self.add_br_if(v),
+			Statement::If(v) => self.add_if(v),
+			Statement::Try(v) => self.add_try(v),
+			_ => self.node_list[self.current].code.push(stat),
+		}
+	}
+
+	fn add_terminator(&mut self, term: &'a Terminator) {
+		let from = self.current;
+
+		self.set_last(Branch::Terminator(term));
+
+		match term {
+			Terminator::Br(v) => self.add_edge(from, self.get_label(v.target())),
+			Terminator::BrTable(v) => {
+				for br in v.data().iter().copied().chain(std::iter::once(v.default())) {
+					self.add_edge(from, self.get_label(br.target()));
+				}
+			}
+			Terminator::Unreachable(_)
+			| Terminator::ReturnCall(_)
+			| Terminator::ReturnCallIndirect(_)
+			| Terminator::Throw(_)
+			| Terminator::Rethrow(_) => self.add_edge(from, EXIT),
+		}
+	}
+}
@@ -0,0 +1,189 @@
+use std::{
+	collections::{hash_map::Entry, HashMap},
+	path::PathBuf,
+};
+
+use gimli::{ColumnType, Dwarf, EndianSlice, FileEntry, LineProgramHeader, LittleEndian, Unit};
+
+use crate::{error::Result, module::Module};
+
+type Slice<'a> = EndianSlice<'a, LittleEndian>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Location {
+	file: usize,
+	line: u64,
+	column: u64,
+}
+
+impl Location {
+	/// Index of the file in [`LineTable::file_list`].
+	#[must_use]
+	pub const fn file(self) -> usize {
+		self.file
+	}
+
+	#[must_use]
+	pub const fn line(self) -> u64 {
+		self.line
+	}
+
+	/// Column on the line, or 0 when the compiler did not record one.
+	#[must_use]
+	pub const fn column(self) -> u64 {
+		self.column
+	}
+}
+
+struct Row {
+	address: u64,
+	location: Option<Location>,
+}
+
+fn get_file_name(
+	dwarf: &Dwarf<Slice>,
+	unit: &Unit<Slice>,
+	header: &LineProgramHeader<Slice>,
+	file: &FileEntry<Slice>,
+) -> Result<String> {
+	let mut path = PathBuf::new();
+
+	// Pushing an absolute path replaces whatever was there, so relative
+	// names end up resolved against their directory and the unit
+	if let Some(directory) = unit.comp_dir {
+		path.push(&*directory.to_string_lossy());
+	}
+
+	if let Some(directory) = file.directory(header) {
+		path.push(&*dwarf.attr_string(unit, directory)?.to_string_lossy());
+	}
+
+	path.push(&*dwarf.attr_string(unit, file.path_name())?.to_string_lossy());
+
+	Ok(path.to_string_lossy().into_owned())
+}
+
+// Rows from the line programs of every unit, sorted by address so the
+// location of a code offset can be found with a binary search
+#[derive(Default)]
+pub struct LineTable {
+	file_list: Vec<String>,
+	row_list: Vec<Row>,
+	code_section_offset: usize,
+}
+
+impl LineTable {
+	/// Reads the line programs from the `.debug_*` custom sections. A module
+	/// without them gives an empty table.
+	///
+	/// # Errors
+	/// Returns `Err` if the debug information is malformed.
+	pub fn from_module(wasm: &Module) -> Result<Self> {
+		let dwarf = Dwarf::load(|id| -> Result<Slice> {
+			let data = wasm.custom_section(id.name()).unwrap_or_default();
+
+			Ok(EndianSlice::new(data, LittleEndian))
+		})?;
+
+		let mut table = Self {
+			code_section_offset: wasm.code_section_offset(),
+			..Self::default()
+		};
+
+		let mut file_map = HashMap::new();
+		let mut iter = dwarf.units();
+
+		while let Some(header) = iter.next()? {
+			let unit = dwarf.unit(header)?;
+
+			table.load_unit(&dwarf, &unit, &mut file_map)?;
+		}
+
+		// A sequence may start where another ends, and then the start wins
+		table
+			.row_list
+			.sort_by_key(|row| (row.address, row.location.is_some()));
+
+		Ok(table)
+	}
+
+	// Units list their files separately, so the same name is shared here
+	fn add_file(&mut self, name: String, file_map: &mut HashMap<String, usize>) -> usize {
+		*file_map.entry(name).or_insert_with_key(|name| {
+			self.file_list.push(name.clone());
+			self.file_list.len() - 1
+		})
+	}
+
+	fn load_unit(
+		&mut self,
+		dwarf: &Dwarf<Slice>,
+		unit: &Unit<Slice>,
+		file_map: &mut HashMap<String, usize>,
+	) -> Result<()> {
+		let Some(program) = unit.line_program.clone() else {
+			return Ok(());
+		};
+
+		let mut index_map = HashMap::new();
+		let mut rows = program.rows();
+
+		while let Some((header, row)) = rows.next_row()? {
+			let line = row.line().filter(|_| !row.end_sequence());
+			let file = row.file(header);
+
+			let location = match (line, file) {
+				(Some(line), Some(file)) => {
+					let file = match index_map.entry(row.file_index()) {
+						Entry::Occupied(v) => *v.get(),
+						Entry::Vacant(v) => {
+							let name = get_file_name(dwarf, unit, header, file)?;
+
+							*v.insert(self.add_file(name, file_map))
+						}
+					};
+
+					let column = match row.column() {
+						ColumnType::LeftEdge => 0,
+						ColumnType::Column(column) => column.get(),
+					};
+
+					Some(Location {
+						file,
+						line: line.get(),
+						column,
+					})
+				}
+				_ => None,
+			};
+
+			self.row_list.push(Row {
+				address: row.address(),
+				location,
+			});
+		}
+
+		Ok(())
+	}
+
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.row_list.is_empty()
+	}
+
+	#[must_use]
+	pub fn file_list(&self) -> &[String] {
+		&self.file_list
+	}
+
+	/// Finds the source location of the operator at `code_offset` in the
+	/// module, if the debug information covers it.
+	#[must_use]
+	pub fn find(&self, code_offset: usize) -> Option<Location> {
+		let address = code_offset.checked_sub(self.code_section_offset)?;
+		let address = u64::try_from(address).ok()?;
+		let index = self.row_list.partition_point(|row| row.address <= address);
+
+		self.row_list[..index].last()?.location
+	}
+}
@@ -0,0 +1,801 @@
+use wasm_encoder::{Instruction, MemArg};
+use wasmparser::ValType;
+
+use crate::node::{
+	BinOpType, CmpOpType, ExtractLaneType, LoadType, ReplaceLaneType, StoreType, UnOpType,
+};
+
+// The inverse of the operator tables in `node`, so every
+// operation goes back to the one instruction it came from
+
+pub const fn load(load_type: LoadType, memarg: MemArg) -> Instruction<'static> {
+	match load_type {
+		LoadType::I32 => Instruction::I32Load(memarg),
+		LoadType::I64 => Instruction::I64Load(memarg),
+		LoadType::F32 => Instruction::F32Load(memarg),
+		LoadType::F64 => Instruction::F64Load(memarg),
+		LoadType::I32_I8 => Instruction::I32Load8S(memarg),
+		LoadType::I32_U8 => Instruction::I32Load8U(memarg),
+		LoadType::I32_I16 => Instruction::I32Load16S(memarg),
+		LoadType::I32_U16 => Instruction::I32Load16U(memarg),
+		LoadType::I64_I8 => Instruction::I64Load8S(memarg),
+		LoadType::I64_U8 => Instruction::I64Load8U(memarg),
+		LoadType::I64_I16 => Instruction::I64Load16S(memarg),
+		LoadType::I64_U16 => Instruction::I64Load16U(memarg),
+		LoadType::I64_I32 => Instruction::I64Load32S(memarg),
+		LoadType::I64_U32 => Instruction::I64Load32U(memarg),
+		LoadType::V128 => Instruction::V128Load(memarg),
+		LoadType::V128_I8X8 => Instruction::V128Load8x8S(memarg),
+		LoadType::V128_U8X8 => Instruction::V128Load8x8U(memarg),
+		LoadType::V128_I16X4 => Instruction::V128Load16x4S(memarg),
+		LoadType::V128_U16X4 => Instruction::V128Load16x4U(memarg),
+		LoadType::V128_I32X2 => Instruction::V128Load32x2S(memarg),
+		LoadType::V128_U32X2 => Instruction::V128Load32x2U(memarg),
+	}
+}
+
+// Loads and stores are written with their natural alignment,
+// as the original hint is not kept by the syntax tree
+pub const fn load_align(load_type: LoadType) -> u32 {
+	match load_type {
+		LoadType::I32_I8 | LoadType::I32_U8 | LoadType::I64_I8 | LoadType::I64_U8 => 0,
+		LoadType::I32_I16 | LoadType::I32_U16 | LoadType::I64_I16 | LoadType::I64_U16 => 1,
+		LoadType::I32 | LoadType::F32 | LoadType::I64_I32 | LoadType::I64_U32 => 2,
+		LoadType::I64
+		| LoadType::F64
+		| LoadType::V128_I8X8
+		| LoadType::V128_U8X8
+		| LoadType::V128_I16X4
+		| LoadType::V128_U16X4
+		| LoadType::V128_I32X2
+		| LoadType::V128_U32X2 => 3,
+		LoadType::V128 => 4,
+	}
+}
+
+pub const fn load_result(load_type: LoadType) -> ValType {
+	match load_type {
+		LoadType::I32
+		| LoadType::I32_I8
+		| LoadType::I32_U8
+		| LoadType::I32_I16
+		| LoadType::I32_U16 => ValType::I32,
+		LoadType::I64
+		| LoadType::I64_I8
+		| LoadType::I64_U8
+		| LoadType::I64_I16
+		| LoadType::I64_U16
+		| LoadType::I64_I32
+		| LoadType::I64_U32 => ValType::I64,
+		LoadType::F32 => ValType::F32,
+		LoadType::F64 => ValType::F64,
+		LoadType::V128
+		| LoadType::V128_I8X8
+		| LoadType::V128_U8X8
+		| LoadType::V128_I16X4
+		| LoadType::V128_U16X4
+		| LoadType::V128_I32X2
+		| LoadType::V128_U32X2 => ValType::V128,
+	}
+}
+
+pub const fn store(store_type: StoreType, memarg: MemArg) -> Instruction<'static> {
+	match store_type {
+		StoreType::I32 => Instruction::I32Store(memarg),
+		StoreType::I64 => Instruction::I64Store(memarg),
+		StoreType::F32 => Instruction::F32Store(memarg),
+		StoreType::F64 => Instruction::F64Store(memarg),
+		StoreType::I32_N8 => Instruction::I32Store8(memarg),
+		StoreType::I32_N16 => Instruction::I32Store16(memarg),
+		StoreType::I64_N8 => Instruction::I64Store8(memarg),
+		StoreType::I64_N16 => Instruction::I64Store16(memarg),
+		StoreType::I64_N32 => Instruction::I64Store32(memarg),
+		StoreType::V128 => Instruction::V128Store(memarg),
+	}
+}
+
+pub const fn store_align(store_type: StoreType) -> u32 {
+	match store_type {
+		StoreType::I32_N8 | StoreType::I64_N8 => 0,
+		StoreType::I32_N16 | StoreType::I64_N16 => 1,
+		StoreType::I32 | StoreType::F32 | StoreType::I64_N32 => 2,
+		StoreType::I64 | StoreType::F64 => 3,
+		StoreType::V128 => 4,
+	}
+}
+
+pub const fn extract_lane(lane_type: ExtractLaneType, lane: u8) -> Instruction<'static> {
+	match lane_type {
+		ExtractLaneType::I8X16 => Instruction::I8x16ExtractLaneS(lane),
+		ExtractLaneType::U8X16 => Instruction::I8x16ExtractLaneU(lane),
+		ExtractLaneType::I16X8 => Instruction::I16x8ExtractLaneS(lane),
+		ExtractLaneType::U16X8 => Instruction::I16x8ExtractLaneU(lane),
+		ExtractLaneType::I32X4 => Instruction::I32x4ExtractLane(lane),
+		ExtractLaneType::I64X2 => Instruction::I64x2ExtractLane(lane),
+		ExtractLaneType::F32X4 => Instruction::F32x4ExtractLane(lane),
+		ExtractLaneType::F64X2 => Instruction::F64x2ExtractLane(lane),
+	}
+}
+
+pub const fn extract_lane_result(lane_type: ExtractLaneType) -> ValType {
+	match lane_type {
+		ExtractLaneType::I8X16
+		| ExtractLaneType::U8X16
+		| ExtractLaneType::I16X8
+		| ExtractLaneType::U16X8
+		| ExtractLaneType::I32X4 => ValType::I32,
+		ExtractLaneType::I64X2 => ValType::I64,
+		ExtractLaneType::F32X4 => ValType::F32,
+		ExtractLaneType::F64X2 => ValType::F64,
+	}
+}
+
+pub const fn replace_lane(lane_type: ReplaceLaneType, lane: u8) -> Instruction<'static> {
+	match lane_type {
+		ReplaceLaneType::I8X16 => Instruction::I8x16ReplaceLane(lane),
+		ReplaceLaneType::I16X8 => Instruction::I16x8ReplaceLane(lane),
+		ReplaceLaneType::I32X4 => Instruction::I32x4ReplaceLane(lane),
+		ReplaceLaneType::I64X2 => Instruction::I64x2ReplaceLane(lane),
+		ReplaceLaneType::F32X4 => Instruction::F32x4ReplaceLane(lane),
+		ReplaceLaneType::F64X2 => Instruction::F64x2ReplaceLane(lane),
+	}
+}
+
+#[allow(clippy::too_many_lines)]
+pub const fn un_op(op_type: UnOpType) -> Instruction<'static> {
+	match op_type {
+		UnOpType::Clz_I32 => Instruction::I32Clz,
+		UnOpType::Ctz_I32 => Instruction::I32Ctz,
+		UnOpType::Popcnt_I32 => Instruction::I32Popcnt,
+		UnOpType::Clz_I64 => Instruction::I64Clz,
+		UnOpType::Ctz_I64 => Instruction::I64Ctz,
+		UnOpType::Popcnt_I64 => Instruction::I64Popcnt,
+		UnOpType::Abs_F32 => Instruction::F32Abs,
+		UnOpType::Neg_F32 => Instruction::F32Neg,
+		UnOpType::Ceil_F32 => Instruction::F32Ceil,
+		UnOpType::Floor_F32 => Instruction::F32Floor,
+		UnOpType::Truncate_F32 => Instruction::F32Trunc,
+		UnOpType::Nearest_F32 => Instruction::F32Nearest,
+		UnOpType::Sqrt_F32 => Instruction::F32Sqrt,
+		UnOpType::Abs_F64 => Instruction::F64Abs,
+		UnOpType::Neg_F64 => Instruction::F64Neg,
+		UnOpType::Ceil_F64 => Instruction::F64Ceil,
+		UnOpType::Floor_F64 => Instruction::F64Floor,
+		UnOpType::Truncate_F64 => Instruction::F64Trunc,
+		UnOpType::Nearest_F64 => Instruction::F64Nearest,
+		UnOpType::Sqrt_F64 => Instruction::F64Sqrt,
+		UnOpType::Wrap_I32_I64 => Instruction::I32WrapI64,
+		UnOpType::Truncate_I32_F32 => Instruction::I32TruncF32S,
+		UnOpType::Truncate_I32_F64 => Instruction::I32TruncF64S,
+		UnOpType::Truncate_U32_F32 => Instruction::I32TruncF32U,
+		UnOpType::Truncate_U32_F64 => Instruction::I32TruncF64U,
+		UnOpType::Truncate_I64_F32 => Instruction::I64TruncF32S,
+		UnOpType::Truncate_I64_F64 => Instruction::I64TruncF64S,
+		UnOpType::Truncate_U64_F32 => Instruction::I64TruncF32U,
+		UnOpType::Truncate_U64_F64 => Instruction::I64TruncF64U,
+		UnOpType::Saturate_I32_F32 => Instruction::I32TruncSatF32S,
+		UnOpType::Saturate_I32_F64 => Instruction::I32TruncSatF64S,
+		UnOpType::Saturate_U32_F32 => Instruction::I32TruncSatF32U,
+		UnOpType::Saturate_U32_F64 => Instruction::I32TruncSatF64U,
+		UnOpType::Saturate_I64_F32 => Instruction::I64TruncSatF32S,
+		UnOpType::Saturate_I64_F64 => Instruction::I64TruncSatF64S,
+		UnOpType::Saturate_U64_F32 => Instruction::I64TruncSatF32U,
+		UnOpType::Saturate_U64_F64 => Instruction::I64TruncSatF64U,
+		UnOpType::Extend_I32_N8 => Instruction::I32Extend8S,
+		UnOpType::Extend_I32_N16 => Instruction::I32Extend16S,
+		UnOpType::Extend_I64_N8 => Instruction::I64Extend8S,
+		UnOpType::Extend_I64_N16 => Instruction::I64Extend16S,
+		UnOpType::Extend_I64_N32 => Instruction::I64Extend32S,
+		UnOpType::Extend_I64_I32 => Instruction::I64ExtendI32S,
+		UnOpType::Extend_I64_U32 => Instruction::I64ExtendI32U,
+		UnOpType::Convert_F32_I32 => Instruction::F32ConvertI32S,
+		UnOpType::Convert_F32_U32 => Instruction::F32ConvertI32U,
+		UnOpType::Convert_F32_I64 => Instruction::F32ConvertI64S,
+		UnOpType::Convert_F32_U64 => Instruction::F32ConvertI64U,
+		UnOpType::Demote_F32_F64 => Instruction::F32DemoteF64,
+		UnOpType::Convert_F64_I32 => Instruction::F64ConvertI32S,
+		UnOpType::Convert_F64_U32 => Instruction::F64ConvertI32U,
+		UnOpType::Convert_F64_I64 => Instruction::F64ConvertI64S,
+		UnOpType::Convert_F64_U64 => Instruction::F64ConvertI64U,
+		UnOpType::Promote_F64_F32 => Instruction::F64PromoteF32,
+		UnOpType::Reinterpret_I32_F32 => Instruction::I32ReinterpretF32,
+		UnOpType::Reinterpret_I64_F64 => Instruction::I64ReinterpretF64,
+		UnOpType::Reinterpret_F32_I32 => Instruction::F32ReinterpretI32,
+		UnOpType::Reinterpret_F64_I64 => Instruction::F64ReinterpretI64,
+		UnOpType::Not_V128 => Instruction::V128Not,
+		UnOpType::AnyTrue_V128 => Instruction::V128AnyTrue,
+		UnOpType::Splat_I8X16 => Instruction::I8x16Splat,
+		UnOpType::Splat_I16X8 => Instruction::I16x8Splat,
+		UnOpType::Splat_I32X4 => Instruction::I32x4Splat,
+		UnOpType::Splat_I64X2 => Instruction::I64x2Splat,
+		UnOpType::Splat_F32X4 => Instruction::F32x4Splat,
+		UnOpType::Splat_F64X2 => Instruction::F64x2Splat,
+		UnOpType::Abs_I8X16 => Instruction::I8x16Abs,
+		UnOpType::Neg_I8X16 => Instruction::I8x16Neg,
+		UnOpType::Abs_I16X8 => Instruction::I16x8Abs,
+		UnOpType::Neg_I16X8 => Instruction::I16x8Neg,
+		UnOpType::Abs_I32X4 => Instruction::I32x4Abs,
+		UnOpType::Neg_I32X4 => Instruction::I32x4Neg,
+		UnOpType::Abs_I64X2 => Instruction::I64x2Abs,
+		UnOpType::Neg_I64X2 => Instruction::I64x2Neg,
+		UnOpType::Abs_F32X4 => Instruction::F32x4Abs,
+		UnOpType::Neg_F32X4 => Instruction::F32x4Neg,
+		UnOpType::Abs_F64X2 => Instruction::F64x2Abs,
+		UnOpType::Neg_F64X2 => Instruction::F64x2Neg,
+		UnOpType::Popcnt_I8X16 => Instruction::I8x16Popcnt,
+		UnOpType::AllTrue_I8X16 => Instruction::I8x16AllTrue,
+		UnOpType::Bitmask_I8X16 => Instruction::I8x16Bitmask,
+		UnOpType::AllTrue_I16X8 => Instruction::I16x8AllTrue,
+		UnOpType::Bitmask_I16X8 => Instruction::I16x8Bitmask,
+		UnOpType::AllTrue_I32X4 => Instruction::I32x4AllTrue,
+		UnOpType::Bitmask_I32X4 => Instruction::I32x4Bitmask,
+		UnOpType::AllTrue_I64X2 => Instruction::I64x2AllTrue,
+		UnOpType::Bitmask_I64X2 => Instruction::I64x2Bitmask,
+		UnOpType::Ceil_F32X4 => Instruction::F32x4Ceil,
+		UnOpType::Floor_F32X4 => Instruction::F32x4Floor,
+		UnOpType::Truncate_F32X4 => Instruction::F32x4Trunc,
+		UnOpType::Nearest_F32X4 => Instruction::F32x4Nearest,
+		UnOpType::Sqrt_F32X4 => Instruction::F32x4Sqrt,
+		UnOpType::Ceil_F64X2 => Instruction::F64x2Ceil,
+		UnOpType::Floor_F64X2 => Instruction::F64x2Floor,
+		UnOpType::Truncate_F64X2 => Instruction::F64x2Trunc,
+		UnOpType::Nearest_F64X2 => Instruction::F64x2Nearest,
+		UnOpType::Sqrt_F64X2 => Instruction::F64x2Sqrt,
+		UnOpType::ExtAddPairwise_I16X8_I8X16 => Instruction::I16x8ExtAddPairwiseI8x16S,
+		UnOpType::ExtAddPairwise_I16X8_U8X16 => Instruction::I16x8ExtAddPairwiseI8x16U,
+		UnOpType::ExtAddPairwise_I32X4_I16X8 => Instruction::I32x4ExtAddPairwiseI16x8S,
+		UnOpType::ExtAddPairwise_I32X4_U16X8 => Instruction::I32x4ExtAddPairwiseI16x8U,
+		UnOpType::ExtendLow_I16X8_I8X16 => Instruction::I16x8ExtendLowI8x16S,
+		UnOpType::ExtendLow_I16X8_U8X16 => Instruction::I16x8ExtendLowI8x16U,
+		UnOpType::ExtendHigh_I16X8_I8X16 => Instruction::I16x8ExtendHighI8x16S,
+		UnOpType::ExtendHigh_I16X8_U8X16 => Instruction::I16x8ExtendHighI8x16U,
+		UnOpType::ExtendLow_I32X4_I16X8 => Instruction::I32x4ExtendLowI16x8S,
+		UnOpType::ExtendLow_I32X4_U16X8 => Instruction::I32x4ExtendLowI16x8U,
+		UnOpType::ExtendHigh_I32X4_I16X8 => Instruction::I32x4ExtendHighI16x8S,
+		UnOpType::ExtendHigh_I32X4_U16X8 => Instruction::I32x4ExtendHighI16x8U,
+		UnOpType::ExtendLow_I64X2_I32X4 => Instruction::I64x2ExtendLowI32x4S,
+		UnOpType::ExtendLow_I64X2_U32X4 => Instruction::I64x2ExtendLowI32x4U,
+		UnOpType::ExtendHigh_I64X2_I32X4 => Instruction::I64x2ExtendHighI32x4S,
+		UnOpType::ExtendHigh_I64X2_U32X4 => Instruction::I64x2ExtendHighI32x4U,
+		UnOpType::Saturate_I32X4_F32X4 => Instruction::I32x4TruncSatF32x4S,
+		UnOpType::Saturate_U32X4_F32X4 => Instruction::I32x4TruncSatF32x4U,
+		UnOpType::SaturateZero_I32X4_F64X2 => Instruction::I32x4TruncSatF64x2SZero,
+		UnOpType::SaturateZero_U32X4_F64X2 => Instruction::I32x4TruncSatF64x2UZero,
+		UnOpType::Convert_F32X4_I32X4 => Instruction::F32x4ConvertI32x4S,
+		UnOpType::Convert_F32X4_U32X4 => Instruction::F32x4ConvertI32x4U,
+		UnOpType::ConvertLow_F64X2_I32X4 => Instruction::F64x2ConvertLowI32x4S,
+		UnOpType::ConvertLow_F64X2_U32X4 => Instruction::F64x2ConvertLowI32x4U,
+		UnOpType::DemoteZero_F32X4_F64X2 => Instruction::F32x4DemoteF64x2Zero,
+		UnOpType::PromoteLow_F64X2_F32X4 => Instruction::F64x2PromoteLowF32x4,
+	}
+}
+
+#[allow(clippy::too_many_lines)]
+pub const fn bin_op(op_type: BinOpType) -> Instruction<'static> {
+	match op_type {
+		BinOpType::Add_I32 => Instruction::I32Add,
+		BinOpType::Sub_I32 => Instruction::I32Sub,
+		BinOpType::Mul_I32 => Instruction::I32Mul,
+		BinOpType::DivS_I32 => Instruction::I32DivS,
+		BinOpType::DivU_I32 => Instruction::I32DivU,
+		BinOpType::RemS_I32 => Instruction::I32RemS,
+		BinOpType::RemU_I32 => Instruction::I32RemU,
+		BinOpType::And_I32 => Instruction::I32And,
+		BinOpType::Or_I32 => Instruction::I32Or,
+		BinOpType::Xor_I32 => Instruction::I32Xor,
+		BinOpType::Shl_I32 => Instruction::I32Shl,
+		BinOpType::ShrS_I32 => Instruction::I32ShrS,
+		BinOpType::ShrU_I32 => Instruction::I32ShrU,
+		BinOpType::Rotl_I32 => Instruction::I32Rotl,
+		BinOpType::Rotr_I32 => Instruction::I32Rotr,
+		BinOpType::Add_I64 => Instruction::I64Add,
+		BinOpType::Sub_I64 => Instruction::I64Sub,
+		BinOpType::Mul_I64 => Instruction::I64Mul,
+		BinOpType::DivS_I64 => Instruction::I64DivS,
+		BinOpType::DivU_I64 => Instruction::I64DivU,
+		BinOpType::RemS_I64 => Instruction::I64RemS,
+		BinOpType::RemU_I64 => Instruction::I64RemU,
+		BinOpType::And_I64 => Instruction::I64And,
+		BinOpType::Or_I64 => Instruction::I64Or,
+		BinOpType::Xor_I64 => Instruction::I64Xor,
+		BinOpType::Shl_I64 => Instruction::I64Shl,
+		BinOpType::ShrS_I64 => Instruction::I64ShrS,
+		BinOpType::ShrU_I64 => Instruction::I64ShrU,
+		BinOpType::Rotl_I64 => Instruction::I64Rotl,
+		BinOpType::Rotr_I64 => Instruction::I64Rotr,
+		BinOpType::Add_F32 => Instruction::F32Add,
+		BinOpType::Sub_F32 => Instruction::F32Sub,
+		BinOpType::Mul_F32 => Instruction::F32Mul,
+		BinOpType::Div_F32 => Instruction::F32Div,
+		BinOpType::Min_F32 => Instruction::F32Min,
+		BinOpType::Max_F32 => Instruction::F32Max,
+		BinOpType::Copysign_F32 => Instruction::F32Copysign,
+		BinOpType::Add_F64 => Instruction::F64Add,
+		BinOpType::Sub_F64 => Instruction::F64Sub,
+		BinOpType::Mul_F64 => Instruction::F64Mul,
+		BinOpType::Div_F64 => Instruction::F64Div,
+		BinOpType::Min_F64 => Instruction::F64Min,
+		BinOpType::Max_F64 => Instruction::F64Max,
+		BinOpType::Copysign_F64 => Instruction::F64Copysign,
+		BinOpType::And_V128 => Instruction::V128And,
+		BinOpType::AndNot_V128 => Instruction::V128AndNot,
+		BinOpType::Or_V128 => Instruction::V128Or,
+		BinOpType::Xor_V128 => Instruction::V128Xor,
+		BinOpType::Swizzle_I8X16 => Instruction::I8x16Swizzle,
+		BinOpType::Eq_I8X16 => Instruction::I8x16Eq,
+		BinOpType::Ne_I8X16 => Instruction::I8x16Ne,
+		BinOpType::LtS_I8X16 => Instruction::I8x16LtS,
+		BinOpType::LtU_I8X16 => Instruction::I8x16LtU,
+		BinOpType::GtS_I8X16 => Instruction::I8x16GtS,
+		BinOpType::GtU_I8X16 => Instruction::I8x16GtU,
+		BinOpType::LeS_I8X16 => Instruction::I8x16LeS,
+		BinOpType::LeU_I8X16 => Instruction::I8x16LeU,
+		BinOpType::GeS_I8X16 => Instruction::I8x16GeS,
+		BinOpType::GeU_I8X16 => Instruction::I8x16GeU,
+		BinOpType::Eq_I16X8 => Instruction::I16x8Eq,
+		BinOpType::Ne_I16X8 => Instruction::I16x8Ne,
+		BinOpType::LtS_I16X8 => Instruction::I16x8LtS,
+		BinOpType::LtU_I16X8 => Instruction::I16x8LtU,
+		BinOpType::GtS_I16X8 => Instruction::I16x8GtS,
+		BinOpType::GtU_I16X8 => Instruction::I16x8GtU,
+		BinOpType::LeS_I16X8 => Instruction::I16x8LeS,
+		BinOpType::LeU_I16X8 => Instruction::I16x8LeU,
+		BinOpType::GeS_I16X8 => Instruction::I16x8GeS,
+		BinOpType::GeU_I16X8 => Instruction::I16x8GeU,
+		BinOpType::Eq_I32X4 => Instruction::I32x4Eq,
+		BinOpType::Ne_I32X4 => Instruction::I32x4Ne,
+		BinOpType::LtS_I32X4 => Instruction::I32x4LtS,
+		BinOpType::LtU_I32X4 => Instruction::I32x4LtU,
+		BinOpType::GtS_I32X4 => Instruction::I32x4GtS,
+		BinOpType::GtU_I32X4 => Instruction::I32x4GtU,
+		BinOpType::LeS_I32X4 => Instruction::I32x4LeS,
+		BinOpType::LeU_I32X4 => Instruction::I32x4LeU,
+		BinOpType::GeS_I32X4 => Instruction::I32x4GeS,
+		BinOpType::GeU_I32X4 => Instruction::I32x4GeU,
+		BinOpType::Eq_I64X2 => Instruction::I64x2Eq,
+		BinOpType::Ne_I64X2 => Instruction::I64x2Ne,
+		BinOpType::LtS_I64X2 => Instruction::I64x2LtS,
+		BinOpType::GtS_I64X2 => Instruction::I64x2GtS,
+		BinOpType::LeS_I64X2 => Instruction::I64x2LeS,
+		BinOpType::GeS_I64X2 => Instruction::I64x2GeS,
+		BinOpType::Eq_F32X4 => Instruction::F32x4Eq,
+		BinOpType::Ne_F32X4 => Instruction::F32x4Ne,
+		BinOpType::Lt_F32X4 => Instruction::F32x4Lt,
+		BinOpType::Gt_F32X4 => Instruction::F32x4Gt,
+		BinOpType::Le_F32X4 => Instruction::F32x4Le,
+		BinOpType::Ge_F32X4 => Instruction::F32x4Ge,
+		BinOpType::Eq_F64X2 => Instruction::F64x2Eq,
+		BinOpType::Ne_F64X2 => Instruction::F64x2Ne,
+		BinOpType::Lt_F64X2 => Instruction::F64x2Lt,
+		BinOpType::Gt_F64X2 => Instruction::F64x2Gt,
+		BinOpType::Le_F64X2 => Instruction::F64x2Le,
+		BinOpType::Ge_F64X2 => Instruction::F64x2Ge,
+		BinOpType::Narrow_I8X16_I16X8 => Instruction::I8x16NarrowI16x8S,
+		BinOpType::Narrow_U8X16_I16X8 => Instruction::I8x16NarrowI16x8U,
+		BinOpType::Narrow_I16X8_I32X4 => Instruction::I16x8NarrowI32x4S,
+		BinOpType::Narrow_U16X8_I32X4 => Instruction::I16x8NarrowI32x4U,
+		BinOpType::Shl_I8X16 => Instruction::I8x16Shl,
+		BinOpType::ShrS_I8X16 => Instruction::I8x16ShrS,
+		BinOpType::ShrU_I8X16 => Instruction::I8x16ShrU,
+		BinOpType::Add_I8X16 => Instruction::I8x16Add,
+		BinOpType::Sub_I8X16 => Instruction::I8x16Sub,
+		BinOpType::Shl_I16X8 => Instruction::I16x8Shl,
+		BinOpType::ShrS_I16X8 => Instruction::I16x8ShrS,
+		BinOpType::ShrU_I16X8 => Instruction::I16x8ShrU,
+		BinOpType::Add_I16X8 => Instruction::I16x8Add,
+		BinOpType::Sub_I16X8 => Instruction::I16x8Sub,
+		BinOpType::Mul_I16X8 => Instruction::I16x8Mul,
+		BinOpType::Shl_I32X4 => Instruction::I32x4Shl,
+		BinOpType::ShrS_I32X4 => Instruction::I32x4ShrS,
+		BinOpType::ShrU_I32X4 => Instruction::I32x4ShrU,
+		BinOpType::Add_I32X4 => Instruction::I32x4Add,
+		BinOpType::Sub_I32X4 => Instruction::I32x4Sub,
+		BinOpType::Mul_I32X4 => Instruction::I32x4Mul,
+		BinOpType::Shl_I64X2 => Instruction::I64x2Shl,
+		BinOpType::ShrS_I64X2 => Instruction::I64x2ShrS,
+		BinOpType::ShrU_I64X2 => Instruction::I64x2ShrU,
+		BinOpType::Add_I64X2 => Instruction::I64x2Add,
+		BinOpType::Sub_I64X2 => Instruction::I64x2Sub,
+		BinOpType::Mul_I64X2 => Instruction::I64x2Mul,
+		BinOpType::AddSatS_I8X16 => Instruction::I8x16AddSatS,
+		BinOpType::AddSatU_I8X16 => Instruction::I8x16AddSatU,
+		BinOpType::SubSatS_I8X16 => Instruction::I8x16SubSatS,
+		BinOpType::SubSatU_I8X16 => Instruction::I8x16SubSatU,
+		BinOpType::AddSatS_I16X8 => Instruction::I16x8AddSatS,
+		BinOpType::AddSatU_I16X8 => Instruction::I16x8AddSatU,
+		BinOpType::SubSatS_I16X8 => Instruction::I16x8SubSatS,
+		BinOpType::SubSatU_I16X8 => Instruction::I16x8SubSatU,
+		BinOpType::MinS_I8X16 => Instruction::I8x16MinS,
+		BinOpType::MinU_I8X16 => Instruction::I8x16MinU,
+		BinOpType::MaxS_I8X16 => Instruction::I8x16MaxS,
+		BinOpType::MaxU_I8X16 => Instruction::I8x16MaxU,
+		BinOpType::MinS_I16X8 => Instruction::I16x8MinS,
+		BinOpType::MinU_I16X8 => Instruction::I16x8MinU,
+		BinOpType::MaxS_I16X8 => Instruction::I16x8MaxS,
+		BinOpType::MaxU_I16X8 => Instruction::I16x8MaxU,
+		BinOpType::MinS_I32X4 => Instruction::I32x4MinS,
+		BinOpType::MinU_I32X4 => Instruction::I32x4MinU,
+		BinOpType::MaxS_I32X4 => Instruction::I32x4MaxS,
+		BinOpType::MaxU_I32X4 => Instruction::I32x4MaxU,
+		BinOpType::AvgrU_I8X16 => Instruction::I8x16AvgrU,
+		BinOpType::AvgrU_I16X8 => Instruction::I16x8AvgrU,
+		BinOpType::Q15MulrSatS_I16X8 => Instruction::I16x8Q15MulrSatS,
+		BinOpType::ExtMulLow_I16X8_I8X16 => Instruction::I16x8ExtMulLowI8x16S,
+		BinOpType::ExtMulLow_I16X8_U8X16 => Instruction::I16x8ExtMulLowI8x16U,
+		BinOpType::ExtMulHigh_I16X8_I8X16 => Instruction::I16x8ExtMulHighI8x16S,
+		BinOpType::ExtMulHigh_I16X8_U8X16 => Instruction::I16x8ExtMulHighI8x16U,
+		BinOpType::ExtMulLow_I32X4_I16X8 => Instruction::I32x4ExtMulLowI16x8S,
+		BinOpType::ExtMulLow_I32X4_U16X8 => Instruction::I32x4ExtMulLowI16x8U,
+		BinOpType::ExtMulHigh_I32X4_I16X8 => Instruction::I32x4ExtMulHighI16x8S,
+		BinOpType::ExtMulHigh_I32X4_U16X8 => Instruction::I32x4ExtMulHighI16x8U,
+		BinOpType::ExtMulLow_I64X2_I32X4 => Instruction::I64x2ExtMulLowI32x4S,
+		BinOpType::ExtMulLow_I64X2_U32X4 => Instruction::I64x2ExtMulLowI32x4U,
+		BinOpType::ExtMulHigh_I64X2_I32X4 => Instruction::I64x2ExtMulHighI32x4S,
+		BinOpType::ExtMulHigh_I64X2_U32X4 => Instruction::I64x2ExtMulHighI32x4U,
+		BinOpType::Dot_I32X4_I16X8 => Instruction::I32x4DotI16x8S,
+		BinOpType::Add_F32X4 => Instruction::F32x4Add,
+		BinOpType::Sub_F32X4 => Instruction::F32x4Sub,
+		BinOpType::Mul_F32X4 => Instruction::F32x4Mul,
+		BinOpType::Div_F32X4 => Instruction::F32x4Div,
+		BinOpType::Min_F32X4 => Instruction::F32x4Min,
+		BinOpType::Max_F32X4 => Instruction::F32x4Max,
+		BinOpType::PMin_F32X4 => Instruction::F32x4PMin,
+		BinOpType::PMax_F32X4 => Instruction::F32x4PMax,
+		BinOpType::Add_F64X2 => Instruction::F64x2Add,
+		BinOpType::Sub_F64X2 => Instruction::F64x2Sub,
+		BinOpType::Mul_F64X2 => Instruction::F64x2Mul,
+		BinOpType::Div_F64X2 => Instruction::F64x2Div,
+		BinOpType::Min_F64X2 => Instruction::F64x2Min,
+		BinOpType::Max_F64X2 => Instruction::F64x2Max,
+		BinOpType::PMin_F64X2 => Instruction::F64x2PMin,
+		BinOpType::PMax_F64X2 => Instruction::F64x2PMax,
+	}
+}
+
+pub const fn cmp_op(op_type: CmpOpType) -> Instruction<'static> {
+	match op_type {
+		CmpOpType::Eq_I32 => Instruction::I32Eq,
+		CmpOpType::Ne_I32 => Instruction::I32Ne,
+		CmpOpType::LtS_I32 => Instruction::I32LtS,
+		CmpOpType::LtU_I32 => Instruction::I32LtU,
+		CmpOpType::GtS_I32 => Instruction::I32GtS,
+		CmpOpType::GtU_I32 => Instruction::I32GtU,
+		CmpOpType::LeS_I32 => Instruction::I32LeS,
+		CmpOpType::LeU_I32 => Instruction::I32LeU,
+		CmpOpType::GeS_I32 => Instruction::I32GeS,
+		CmpOpType::GeU_I32 => Instruction::I32GeU,
+		CmpOpType::Eq_I64 => Instruction::I64Eq,
+		CmpOpType::Ne_I64 => Instruction::I64Ne,
+		CmpOpType::LtS_I64 => Instruction::I64LtS,
+		CmpOpType::LtU_I64 => Instruction::I64LtU,
+		CmpOpType::GtS_I64 => Instruction::I64GtS,
+		CmpOpType::GtU_I64 => Instruction::I64GtU,
+		CmpOpType::LeS_I64 => Instruction::I64LeS,
+		CmpOpType::LeU_I64 => Instruction::I64LeU,
+		CmpOpType::GeS_I64 => Instruction::I64GeS,
+		CmpOpType::GeU_I64 => Instruction::I64GeU,
+		CmpOpType::Eq_F32 => Instruction::F32Eq,
+		CmpOpType::Ne_F32 => Instruction::F32Ne,
+		CmpOpType::Lt_F32 => Instruction::F32Lt,
+		CmpOpType::Gt_F32 => Instruction::F32Gt,
+		CmpOpType::Le_F32 => Instruction::F32Le,
+		CmpOpType::Ge_F32 => Instruction::F32Ge,
+		CmpOpType::Eq_F64 => Instruction::F64Eq,
+		CmpOpType::Ne_F64 => Instruction::F64Ne,
+		CmpOpType::Lt_F64 => Instruction::F64Lt,
+		CmpOpType::Gt_F64 => Instruction::F64Gt,
+		CmpOpType::Le_F64 => Instruction::F64Le,
+		CmpOpType::Ge_F64 => Instruction::F64Ge,
+	}
+}
+
+#[allow(clippy::too_many_lines)]
+pub const fn un_op_result(op_type: UnOpType) -> ValType {
+	match op_type {
+		UnOpType::Clz_I32
+		| UnOpType::Ctz_I32
+		| UnOpType::Popcnt_I32
+		| UnOpType::Wrap_I32_I64
+		| UnOpType::Truncate_I32_F32
+		| UnOpType::Truncate_I32_F64
+		| UnOpType::Truncate_U32_F32
+		| UnOpType::Truncate_U32_F64
+		| UnOpType::Saturate_I32_F32
+		| UnOpType::Saturate_I32_F64
+		| UnOpType::Saturate_U32_F32
+		| UnOpType::Saturate_U32_F64
+		| UnOpType::Extend_I32_N8
+		| UnOpType::Extend_I32_N16
+		| UnOpType::Reinterpret_I32_F32
+		| UnOpType::AnyTrue_V128
+		| UnOpType::AllTrue_I8X16
+		| UnOpType::Bitmask_I8X16
+		| UnOpType::AllTrue_I16X8
+		| UnOpType::Bitmask_I16X8
+		| UnOpType::AllTrue_I32X4
+		| UnOpType::Bitmask_I32X4
+		| UnOpType::AllTrue_I64X2
+		| UnOpType::Bitmask_I64X2 => ValType::I32,
+		UnOpType::Clz_I64
+		| UnOpType::Ctz_I64
+		| UnOpType::Popcnt_I64
+		| UnOpType::Truncate_I64_F32
+		| UnOpType::Truncate_I64_F64
+		| UnOpType::Truncate_U64_F32
+		| UnOpType::Truncate_U64_F64
+		| UnOpType::Saturate_I64_F32
+		| UnOpType::Saturate_I64_F64
+		| UnOpType::Saturate_U64_F32
+		| UnOpType::Saturate_U64_F64
+		| UnOpType::Extend_I64_N8
+		| UnOpType::Extend_I64_N16
+		| UnOpType::Extend_I64_N32
+		| UnOpType::Extend_I64_I32
+		| UnOpType::Extend_I64_U32
+		| UnOpType::Reinterpret_I64_F64 => ValType::I64,
+		UnOpType::Abs_F32
+		| UnOpType::Neg_F32
+		| UnOpType::Ceil_F32
+		| UnOpType::Floor_F32
+		| UnOpType::Truncate_F32
+		| UnOpType::Nearest_F32
+		| UnOpType::Sqrt_F32
+		| UnOpType::Convert_F32_I32
+		| UnOpType::Convert_F32_U32
+		| UnOpType::Convert_F32_I64
+		| UnOpType::Convert_F32_U64
+		| UnOpType::Demote_F32_F64
+		| UnOpType::Reinterpret_F32_I32 => ValType::F32,
+		UnOpType::Abs_F64
+		| UnOpType::Neg_F64
+		| UnOpType::Ceil_F64
+		| UnOpType::Floor_F64
+		| UnOpType::Truncate_F64
+		| UnOpType::Nearest_F64
+		| UnOpType::Sqrt_F64
+		| UnOpType::Convert_F64_I32
+		| UnOpType::Convert_F64_U32
+		| UnOpType::Convert_F64_I64
+		| UnOpType::Convert_F64_U64
+		| UnOpType::Promote_F64_F32
+		| UnOpType::Reinterpret_F64_I64 => ValType::F64,
+		UnOpType::Not_V128
+		| UnOpType::Splat_I8X16
+		| UnOpType::Splat_I16X8
+		| UnOpType::Splat_I32X4
+		| UnOpType::Splat_I64X2
+		| UnOpType::Splat_F32X4
+		| UnOpType::Splat_F64X2
+		| UnOpType::Abs_I8X16
+		| UnOpType::Neg_I8X16
+		| UnOpType::Abs_I16X8
+		| UnOpType::Neg_I16X8
+		| UnOpType::Abs_I32X4
+		| UnOpType::Neg_I32X4
+		| UnOpType::Abs_I64X2
+		| UnOpType::Neg_I64X2
+		| UnOpType::Abs_F32X4
+		| UnOpType::Neg_F32X4
+		| UnOpType::Abs_F64X2
+		| UnOpType::Neg_F64X2
+		| UnOpType::Popcnt_I8X16
+		| UnOpType::Ceil_F32X4
+		| UnOpType::Floor_F32X4
+		| UnOpType::Truncate_F32X4
+		| UnOpType::Nearest_F32X4
+		| UnOpType::Sqrt_F32X4
+		| UnOpType::Ceil_F64X2
+		| UnOpType::Floor_F64X2
+		| UnOpType::Truncate_F64X2
+		| UnOpType::Nearest_F64X2
+		| UnOpType::Sqrt_F64X2
+		| UnOpType::ExtAddPairwise_I16X8_I8X16
+		| UnOpType::ExtAddPairwise_I16X8_U8X16
+		| UnOpType::ExtAddPairwise_I32X4_I16X8
+		| UnOpType::ExtAddPairwise_I32X4_U16X8
+		| UnOpType::ExtendLow_I16X8_I8X16
+		| UnOpType::ExtendLow_I16X8_U8X16
+		| UnOpType::ExtendHigh_I16X8_I8X16
+		| UnOpType::ExtendHigh_I16X8_U8X16
+		| UnOpType::ExtendLow_I32X4_I16X8
+		| UnOpType::ExtendLow_I32X4_U16X8
+		| UnOpType::ExtendHigh_I32X4_I16X8
+		| UnOpType::ExtendHigh_I32X4_U16X8
+		| UnOpType::ExtendLow_I64X2_I32X4
+		| UnOpType::ExtendLow_I64X2_U32X4
+		| UnOpType::ExtendHigh_I64X2_I32X4
+		| UnOpType::ExtendHigh_I64X2_U32X4
+		| UnOpType::Saturate_I32X4_F32X4
+		| UnOpType::Saturate_U32X4_F32X4
+		| UnOpType::SaturateZero_I32X4_F64X2
+		| UnOpType::SaturateZero_U32X4_F64X2
+		| UnOpType::Convert_F32X4_I32X4
+		| UnOpType::Convert_F32X4_U32X4
+		| UnOpType::ConvertLow_F64X2_I32X4
+		| UnOpType::ConvertLow_F64X2_U32X4
+		| UnOpType::DemoteZero_F32X4_F64X2
+		| UnOpType::PromoteLow_F64X2_F32X4 => ValType::V128,
+	}
+}
+
+#[allow(clippy::too_many_lines)]
+pub const fn bin_op_result(op_type: BinOpType) -> ValType {
+	match op_type {
+		BinOpType::Add_I32
+		| BinOpType::Sub_I32
+		| BinOpType::Mul_I32
+		| BinOpType::DivS_I32
+		| BinOpType::DivU_I32
+		| BinOpType::RemS_I32
+		| BinOpType::RemU_I32
+		| BinOpType::And_I32
+		| BinOpType::Or_I32
+		| BinOpType::Xor_I32
+		| BinOpType::Shl_I32
+		| BinOpType::ShrS_I32
+		| BinOpType::ShrU_I32
+		| BinOpType::Rotl_I32
+		| BinOpType::Rotr_I32 => ValType::I32,
+		BinOpType::Add_I64
+		| BinOpType::Sub_I64
+		| BinOpType::Mul_I64
+		| BinOpType::DivS_I64
+		| BinOpType::DivU_I64
+		| BinOpType::RemS_I64
+		| BinOpType::RemU_I64
+		| BinOpType::And_I64
+		| BinOpType::Or_I64
+		| BinOpType::Xor_I64
+		| BinOpType::Shl_I64
+		| BinOpType::ShrS_I64
+		| BinOpType::ShrU_I64
+		| BinOpType::Rotl_I64
+		| BinOpType::Rotr_I64 => ValType::I64,
+		BinOpType::Add_F32
+		| BinOpType::Sub_F32
+		| BinOpType::Mul_F32
+		| BinOpType::Div_F32
+		| BinOpType::Min_F32
+		| BinOpType::Max_F32
+		| BinOpType::Copysign_F32 => ValType::F32,
+		BinOpType::Add_F64
+		| BinOpType::Sub_F64
+		| BinOpType::Mul_F64
+		| BinOpType::Div_F64
+		| BinOpType::Min_F64
+		| BinOpType::Max_F64
+		| BinOpType::Copysign_F64 => ValType::F64,
+		BinOpType::And_V128
+		| BinOpType::AndNot_V128
+		| BinOpType::Or_V128
+		| BinOpType::Xor_V128
+		| BinOpType::Swizzle_I8X16
+		| BinOpType::Eq_I8X16
+		| BinOpType::Ne_I8X16
+		| BinOpType::LtS_I8X16
+		| BinOpType::LtU_I8X16
+		| BinOpType::GtS_I8X16
+		| BinOpType::GtU_I8X16
+		| BinOpType::LeS_I8X16
+		| BinOpType::LeU_I8X16
+		| BinOpType::GeS_I8X16
+		| BinOpType::GeU_I8X16
+		| BinOpType::Eq_I16X8
+		| BinOpType::Ne_I16X8
+		| BinOpType::LtS_I16X8
+		| BinOpType::LtU_I16X8
+		| BinOpType::GtS_I16X8
+		| BinOpType::GtU_I16X8
+		| BinOpType::LeS_I16X8
+		| BinOpType::LeU_I16X8
+		| BinOpType::GeS_I16X8
+		| BinOpType::GeU_I16X8
+		| BinOpType::Eq_I32X4
+		| BinOpType::Ne_I32X4
+		| BinOpType::LtS_I32X4
+		| BinOpType::LtU_I32X4
+		| BinOpType::GtS_I32X4
+		| BinOpType::GtU_I32X4
+		| BinOpType::LeS_I32X4
+		| BinOpType::LeU_I32X4
+		| BinOpType::GeS_I32X4
+		| BinOpType::GeU_I32X4
+		| BinOpType::Eq_I64X2
+		| BinOpType::Ne_I64X2
+		| BinOpType::LtS_I64X2
+		| BinOpType::GtS_I64X2
+		| BinOpType::LeS_I64X2
+		| BinOpType::GeS_I64X2
+		| BinOpType::Eq_F32X4
+		| BinOpType::Ne_F32X4
+		| BinOpType::Lt_F32X4
+		| BinOpType::Gt_F32X4
+		| BinOpType::Le_F32X4
+		| BinOpType::Ge_F32X4
+		| BinOpType::Eq_F64X2
+		| BinOpType::Ne_F64X2
+		| BinOpType::Lt_F64X2
+		| BinOpType::Gt_F64X2
+		| BinOpType::Le_F64X2
+		| BinOpType::Ge_F64X2
+		| BinOpType::Narrow_I8X16_I16X8
+		| BinOpType::Narrow_U8X16_I16X8
+		| BinOpType::Narrow_I16X8_I32X4
+		| BinOpType::Narrow_U16X8_I32X4
+		| BinOpType::Shl_I8X16
+		| BinOpType::ShrS_I8X16
+		| BinOpType::ShrU_I8X16
+		| BinOpType::Add_I8X16
+		| BinOpType::Sub_I8X16
+		| BinOpType::Shl_I16X8
+		| BinOpType::ShrS_I16X8
+		| BinOpType::ShrU_I16X8
+		| BinOpType::Add_I16X8
+		| BinOpType::Sub_I16X8
+		| BinOpType::Mul_I16X8
+		| BinOpType::Shl_I32X4
+		| BinOpType::ShrS_I32X4
+		| BinOpType::ShrU_I32X4
+		| BinOpType::Add_I32X4
+		| BinOpType::Sub_I32X4
+		| BinOpType::Mul_I32X4
+		| BinOpType::Shl_I64X2
+		| BinOpType::ShrS_I64X2
+		| BinOpType::ShrU_I64X2
+		| BinOpType::Add_I64X2
+		| BinOpType::Sub_I64X2
+		| BinOpType::Mul_I64X2
+		| BinOpType::AddSatS_I8X16
+		| BinOpType::AddSatU_I8X16
+		| BinOpType::SubSatS_I8X16
+		| BinOpType::SubSatU_I8X16
+		| BinOpType::AddSatS_I16X8
+		| BinOpType::AddSatU_I16X8
+		| BinOpType::SubSatS_I16X8
+		| BinOpType::SubSatU_I16X8
+		| BinOpType::MinS_I8X16
+		| BinOpType::MinU_I8X16
+		| BinOpType::MaxS_I8X16
+		| BinOpType::MaxU_I8X16
+		| BinOpType::MinS_I16X8
+		| BinOpType::MinU_I16X8
+		| BinOpType::MaxS_I16X8
+		| BinOpType::MaxU_I16X8
+		| BinOpType::MinS_I32X4
+		| BinOpType::MinU_I32X4
+		| BinOpType::MaxS_I32X4
+		| BinOpType::MaxU_I32X4
+		| BinOpType::AvgrU_I8X16
+		| BinOpType::AvgrU_I16X8
+		| BinOpType::Q15MulrSatS_I16X8
+		| BinOpType::ExtMulLow_I16X8_I8X16
+		| BinOpType::ExtMulLow_I16X8_U8X16
+		| BinOpType::ExtMulHigh_I16X8_I8X16
+		| BinOpType::ExtMulHigh_I16X8_U8X16
+		| BinOpType::ExtMulLow_I32X4_I16X8
+		| BinOpType::ExtMulLow_I32X4_U16X8
+		| BinOpType::ExtMulHigh_I32X4_I16X8
+		| BinOpType::ExtMulHigh_I32X4_U16X8
+		| BinOpType::ExtMulLow_I64X2_I32X4
+		| BinOpType::ExtMulLow_I64X2_U32X4
+		| BinOpType::ExtMulHigh_I64X2_I32X4
+		| BinOpType::ExtMulHigh_I64X2_U32X4
+		| BinOpType::Dot_I32X4_I16X8
+		| BinOpType::Add_F32X4
+		| BinOpType::Sub_F32X4
+		| BinOpType::Mul_F32X4
+		| BinOpType::Div_F32X4
+		| BinOpType::Min_F32X4
+		| BinOpType::Max_F32X4
+		| BinOpType::PMin_F32X4
+		| BinOpType::PMax_F32X4
+		| BinOpType::Add_F64X2
+		| BinOpType::Sub_F64X2
+		| BinOpType::Mul_F64X2
+		| BinOpType::Div_F64X2
+		| BinOpType::Min_F64X2
+		| BinOpType::Max_F64X2
+		| BinOpType::PMin_F64X2
+		| BinOpType::PMax_F64X2 => ValType::V128,
+	}
+}
@@ -0,0 +1,916 @@
+use std::collections::HashMap;
+
+use wasm_encoder::{
+	BlockType, CodeSection, Function, Instruction, MemArg, Module as Binary, RawSection, SectionId,
+};
+use wasmparser::{FuncType, RecGroup as Type, RefType, TypeRef, ValType};
+
+use crate::{
+	module::{External, Module},
+	node::{
+		Align, AtomicWait, BinOp, BitSelect, Block, Br, BrIf, BrTable, Call, CallIndirect, CmpOp,
+		Expression, ExtractLane, FuncData, If, IndexType, LabelType, LoadAt, MemoryArgument,
+		MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, ReplaceLane, ResultList, ReturnCall,
+		ReturnCallIndirect, Select, Shuffle, Statement, StoreAt, TableArgument, TableCopy,
+		TableFill, TableGet, TableGrow, TableInit, TableSet, Temporary, Terminator, Throw, Try,
+		UnOp, Value,
+	},
+};
+
+mod instruction;
+
+fn into_ref_type(ty: RefType) -> wasm_encoder::RefType {
+	if ty.heap_type() == RefType::EXTERNREF.heap_type() {
+		wasm_encoder::RefType::EXTERNREF
+	} else {
+		wasm_encoder::RefType::FUNCREF
+	}
+}
+
+fn into_val_type(ty: ValType) -> wasm_encoder::ValType {
+	match ty {
+		ValType::I32 => wasm_encoder::ValType::I32,
+		ValType::I64 => wasm_encoder::ValType::I64,
+		ValType::F32 => wasm_encoder::ValType::F32,
+		ValType::F64 => wasm_encoder::ValType::F64,
+		ValType::V128 => wasm_encoder::ValType::V128,
+		ValType::Ref(ty) => wasm_encoder::ValType::Ref(into_ref_type(ty)),
+	}
+}
+
+const fn index_result(index_type: IndexType) -> ValType {
+	match index_type {
+		IndexType::I32 => ValType::I32,
+		IndexType::I64 => ValType::I64,
+	}
+}
+
+fn to_u32(index: usize) -> u32 {
+	index.try_into().expect("index should fit in 32 bits")
+}
+
+fn memory_argument(memory: usize, offset: u64, align: u32) -> MemArg {
+	MemArg {
+		offset,
+		align,
+		memory_index: to_u32(memory),
+	}
+}
+
+// Signatures of everything a function body can refer to by index
+struct Context<'a> {
+	type_list: &'a [Type],
+	func_list: Vec<u32>,
+	global_list: Vec<ValType>,
+	table_list: Vec<RefType>,
+	tag_list: Vec<u32>,
+}
+
+impl<'a> Context<'a> {
+	fn from_module(wasm: &'a Module) -> Self {
+		let mut temp = Self {
+			type_list: wasm.type_section(),
+			func_list: Vec::new(),
+			global_list: Vec::new(),
+			table_list: Vec::new(),
+			tag_list: Vec::new(),
+		};
+
+		for import in wasm.import_section() {
+			match import.ty {
+				TypeRef::Func(ty) => temp.func_list.push(ty),
+				TypeRef::Table(ty) => temp.table_list.push(ty.element_type),
+				TypeRef::Global(ty) => temp.global_list.push(ty.content_type),
+				TypeRef::Tag(ty) => temp.tag_list.push(ty.func_type_idx),
+				TypeRef::Memory(_) => {}
+			}
+		}
+
+		temp.func_list.extend(wasm.func_section());
+		temp.table_list
+			.extend(wasm.table_section().iter().map(|v| v.ty.element_type));
+		temp.global_list
+			.extend(wasm.global_section().iter().map(|v| v.ty.content_type));
+		temp.tag_list
+			.extend(wasm.tag_section().iter().map(|v| v.func_type_idx));
+
+		temp
+	}
+
+	fn by_type_index(&self, index: u32) -> &'a FuncType {
+		let index = usize::try_from(index).unwrap();
+
+		self.type_list[index].types().next().unwrap().unwrap_func()
+	}
+
+	fn by_func_index(&self, index: usize) -> &'a FuncType {
+		self.by_type_index(self.func_list[index])
+	}
+
+	fn by_tag_index(&self, index: usize) -> &'a FuncType {
+		self.by_type_index(self.tag_list[index])
+	}
+}
+
+struct Label {
+	frame: usize,
+	label_type: Option<LabelType>,
+	has_reference: bool,
+	result_list: Vec<(usize, ValType)>,
+}
+
+// Temporaries become locals after the declared ones, one for every
+// slot and type pair, since a slot may hold different types over time.
+// The type held by each slot is tracked along the path that falls
+// through, and the types written by branches are joined in at the end
+// of their block.
+struct Encoder<'a, 'b> {
+	context: &'b Context<'a>,
+
+	local_list: Vec<ValType>,
+	num_local: u32,
+
+	temporary_map: HashMap<(usize, ValType), u32>,
+	temporary_list: Vec<ValType>,
+	type_map: HashMap<usize, ValType>,
+
+	label_list: Vec<Label>,
+	num_frame: usize,
+
+	code: Vec<Instruction<'static>>,
+}
+
+impl<'a, 'b> Encoder<'a, 'b> {
+	fn new(context: &'b Context<'a>, local_list: Vec<ValType>) -> Self {
+		Self {
+			context,
+			num_local: to_u32(local_list.len()),
+			local_list,
+			temporary_map: HashMap::new(),
+			temporary_list: Vec::new(),
+			type_map: HashMap::new(),
+			label_list: Vec::new(),
+			num_frame: 0,
+			code: Vec::new(),
+		}
+	}
+
+	fn push(&mut self, inst: Instruction<'static>) {
+		self.code.push(inst);
+	}
+
+	fn open_frame(&mut self, inst: Instruction<'static>) {
+		self.push(inst);
+		self.num_frame += 1;
+	}
+
+	fn close_frame(&mut self) {
+		self.push(Instruction::End);
+		self.num_frame -= 1;
+	}
+
+	fn get_local_index(&mut self, var: usize, ty: ValType) -> u32 {
+		let index = self.num_local + to_u32(self.temporary_list.len());
+		let temporary_list = &mut self.temporary_list;
+
+		*self.temporary_map.entry((var, ty)).or_insert_with(|| {
+			temporary_list.push(ty);
+
+			index
+		})
+	}
+
+	fn get_temporary_type(&self, var: Temporary) -> ValType {
+		self.type_map[&var.var()]
+	}
+
+	fn write_get_temporary(&mut self, var: usize, ty: ValType) {
+		let index = self.get_local_index(var, ty);
+
+		self.push(Instruction::LocalGet(index));
+	}
+
+	fn write_set_temporary(&mut self, var: usize, ty: ValType) {
+		let index = self.get_local_index(var, ty);
+
+		self.type_map.insert(var, ty);
+		self.push(Instruction::LocalSet(index));
+	}
+
+	// Values come off the stack last to first
+	fn write_result_list(&mut self, result_list: ResultList, type_list: &[ValType]) {
+		let var_list: Vec<_> = result_list.iter().collect();
+
+		for (var, &ty) in var_list.into_iter().zip(type_list).rev() {
+			self.write_set_temporary(var.var(), ty);
+		}
+	}
+
+	fn get_relative_depth(&self, target: usize) -> u32 {
+		let label = &self.label_list[self.label_list.len() - 1 - target];
+
+		to_u32(self.num_frame - 1 - label.frame)
+	}
+
+	// Branch moves only happen when the branch is taken, so the types
+	// they write are handed to the target instead of the current path
+	fn write_br_align(&mut self, target: usize, align: Align) {
+		let type_list: Vec<_> = align
+			.old_range()
+			.iter()
+			.map(|var| (var.var(), self.get_temporary_type(var)))
+			.collect();
+
+		let new_list: Vec<_> = align
+			.new_range()
+			.iter()
+			.zip(&type_list)
+			.map(|(var, &(_, ty))| (var.var(), ty))
+			.collect();
+
+		if !align.is_aligned() {
+			for &(var, ty) in &type_list {
+				self.write_get_temporary(var, ty);
+			}
+
+			for &(var, ty) in new_list.iter().rev() {
+				let index = self.get_local_index(var, ty);
+
+				self.push(Instruction::LocalSet(index));
+			}
+		}
+
+		let index = self.label_list.len() - 1 - target;
+		let label = &mut self.label_list[index];
+
+		label.has_reference = true;
+
+		if label.label_type != Some(LabelType::Backward) {
+			label.result_list.extend(new_list);
+		}
+	}
+
+	fn get_br_depth(&mut self, br: Br) -> u32 {
+		self.write_br_align(br.target(), br.align());
+		self.get_relative_depth(br.target())
+	}
+
+	fn write_br(&mut self, br: Br) {
+		let depth = self.get_br_depth(br);
+
+		self.push(Instruction::Br(depth));
+	}
+
+	fn write_expression_list(&mut self, list: &[Expression]) {
+		for v in list {
+			self.write_expression(v);
+		}
+	}
+
+	fn write_select(&mut self, v: &Select) -> ValType {
+		let ty = self.write_expression(v.on_true());
+
+		self.write_expression(v.on_false());
+		self.write_expression(v.condition());
+
+		match ty {
+			ValType::Ref(_) => self.push(Instruction::TypedSelect(into_val_type(ty))),
+			_ => self.push(Instruction::Select),
+		}
+
+		ty
+	}
+
+	fn write_load_at(&mut self, v: &LoadAt) -> ValType {
+		let align = instruction::load_align(v.load_type());
+		let memarg = memory_argument(v.memory(), v.offset(), align);
+
+		self.write_expression(v.pointer());
+		self.push(instruction::load(v.load_type(), memarg));
+
+		instruction::load_result(v.load_type())
+	}
+
+	fn write_value(&mut self, v: Value) -> ValType {
+		let (inst, ty) = match v {
+			Value::I32(v) => (Instruction::I32Const(v), ValType::I32),
+			Value::I64(v) => (Instruction::I64Const(v), ValType::I64),
+			Value::F32(v) => (Instruction::F32Const(v), ValType::F32),
+			Value::F64(v) => (Instruction::F64Const(v), ValType::F64),
+			Value::RefNull(v) => {
+				let ty = if v == RefType::EXTERNREF.heap_type() {
+					RefType::EXTERNREF
+				} else {
+					RefType::FUNCREF
+				};
+
+				let heap_type = into_ref_type(ty).heap_type;
+
+				(Instruction::RefNull(heap_type), ValType::Ref(ty))
+			}
+			Value::RefFunc(v) => (Instruction::RefFunc(to_u32(v)), ValType::FUNCREF),
+			#[allow(clippy::cast_possible_wrap)]
+			Value::V128(v) => (Instruction::V128Const(v as i128), ValType::V128),
+		};
+
+		self.push(inst);
+
+		ty
+	}
+
+	fn write_un_op(&mut self, v: &UnOp) -> ValType {
+		self.write_expression(v.rhs());
+		self.push(instruction::un_op(v.op_type()));
+
+		instruction::un_op_result(v.op_type())
+	}
+
+	fn write_bin_op(&mut self, v: &BinOp) -> ValType {
+		self.write_expression(v.lhs());
+		self.write_expression(v.rhs());
+		self.push(instruction::bin_op(v.op_type()));
+
+		instruction::bin_op_result(v.op_type())
+	}
+
+	fn write_cmp_op(&mut self, v: &CmpOp) -> ValType {
+		self.write_expression(v.lhs());
+		self.write_expression(v.rhs());
+		self.push(instruction::cmp_op(v.op_type()));
+
+		ValType::I32
+	}
+
+	fn write_extract_lane(&mut self, v: &ExtractLane) -> ValType {
+		self.write_expression(v.vector());
+		self.push(instruction::extract_lane(v.lane_type(), v.lane()));
+
+		instruction::extract_lane_result(v.lane_type())
+	}
+
+	fn write_replace_lane(&mut self, v: &ReplaceLane) -> ValType {
+		self.write_expression(v.vector());
+		self.write_expression(v.value());
+		self.push(instruction::replace_lane(v.lane_type(), v.lane()));
+
+		ValType::V128
+	}
+
+	fn write_shuffle(&mut self, v: &Shuffle) -> ValType {
+		self.write_expression(v.lhs());
+		self.write_expression(v.rhs());
+		self.push(Instruction::I8x16Shuffle(v.lane_list()));
+
+		ValType::V128
+	}
+
+	fn write_bit_select(&mut self, v: &BitSelect) -> ValType {
+		self.write_expression(v.on_true());
+		self.write_expression(v.on_false());
+		self.write_expression(v.condition());
+		self.push(Instruction::V128Bitselect);
+
+		ValType::V128
+	}
+
+	fn write_expression(&mut self, v: &Expression) -> ValType {
+		match v {
+			Expression::Select(e) => self.write_select(e),
+			Expression::GetTemporary(e) => {
+				let ty = self.get_temporary_type(*e);
+
+				self.write_get_temporary(e.var(), ty);
+
+				ty
+			}
+			Expression::GetLocal(e) => {
+				self.push(Instruction::LocalGet(to_u32(e.var())));
+
+				self.local_list[e.var()]
+			}
+			Expression::GetGlobal(e) => {
+				self.push(Instruction::GlobalGet(to_u32(e.var())));
+
+				self.context.global_list[e.var()]
+			}
+			Expression::LoadAt(e) => self.write_load_at(e),
+			Expression::MemorySize(e) => {
+				self.push(Instruction::MemorySize(to_u32(e.memory())));
+
+				index_result(e.index_type())
+			}
+			Expression::Value(e) => self.write_value(*e),
+			Expression::UnOp(e) => self.write_un_op(e),
+			Expression::BinOp(e) => self.write_bin_op(e),
+			Expression::CmpOp(e) => self.write_cmp_op(e),
+			Expression::RefIsNull(e) => {
+				self.write_expression(e.value());
+				self.push(Instruction::RefIsNull);
+
+				ValType::I32
+			}
+			Expression::ExtractLane(e) => self.write_extract_lane(e),
+			Expression::ReplaceLane(e) => self.write_replace_lane(e),
+			Expression::Shuffle(e) => self.write_shuffle(e),
+			Expression::BitSelect(e) => self.write_bit_select(e),
+		}
+	}
+
+	// A block is one frame; the types on the way out are those of the path
+	// falling through, or of the path entering if the end can't be reached,
+	// joined with those written by every branch to it
+	fn write_labeled_block(&mut self, block: &Block) -> bool {
+		let entry = self.type_map.clone();
+
+		self.label_list.push(Label {
+			frame: self.num_frame - 1,
+			label_type: block.label_type(),
+			has_reference: false,
+			result_list: Vec::new(),
+		});
+
+		let falls_through = self.write_block_code(block);
+		let label = self.label_list.pop().unwrap();
+
+		if !falls_through {
+			self.type_map = entry;
+		}
+
+		self.type_map.extend(label.result_list);
+
+		falls_through || (label.has_reference && label.label_type != Some(LabelType::Backward))
+	}
+
+	// Code after a statement that can't fall through is dead, and is
+	// left out so its temporaries never need a type
+	fn write_block_code(&mut self, block: &Block) -> bool {
+		for stat in block.code() {
+			if !self.write_statement(stat) {
+				return false;
+			}
+		}
+
+		match block.last() {
+			Some(last) => {
+				self.write_terminator(last);
+
+				false
+			}
+			None => true,
+		}
+	}
+
+	fn write_block(&mut self, block: &Block) -> bool {
+		let inst = if block.label_type() == Some(LabelType::Backward) {
+			Instruction::Loop(BlockType::Empty)
+		} else {
+			Instruction::Block(BlockType::Empty)
+		};
+
+		self.open_frame(inst);
+
+		let falls_through = self.write_labeled_block(block);
+
+		self.close_frame();
+
+		falls_through
+	}
+
+	fn write_br_if(&mut self, v: &BrIf) {
+		self.write_expression(v.condition());
+
+		let br = v.target();
+
+		if br.align().is_aligned() {
+			let depth = self.get_br_depth(br);
+
+			self.push(Instruction::BrIf(depth));
+		} else {
+			self.open_frame(Instruction::If(BlockType::Empty));
+			self.write_br(br);
+			self.close_frame();
+		}
+	}
+
+	fn write_if(&mut self, v: &If) -> bool {
+		self.write_expression(v.condition());
+		self.open_frame(Instruction::If(BlockType::Empty));
+
+		let entry = self.type_map.clone();
+		let mut exit = None;
+
+		if self.write_labeled_block(v.on_true()) {
+			exit = Some(std::mem::take(&mut self.type_map));
+		}
+
+		self.type_map.clone_from(&entry);
+
+		match v.on_false() {
+			Some(on_false) => {
+				self.push(Instruction::Else);
+
+				if self.write_labeled_block(on_false) {
+					exit = Some(std::mem::take(&mut self.type_map));
+				}
+			}
+			None => exit = Some(entry.clone()),
+		}
+
+		self.close_frame();
+
+		let falls_through = exit.is_some();
+
+		self.type_map = exit.unwrap_or(entry);
+
+		falls_through
+	}
+
+	fn write_try(&mut self, v: &Try) -> bool {
+		self.open_frame(Instruction::Try(BlockType::Empty));
+
+		let entry = self.type_map.clone();
+		let mut exit = None;
+
+		if self.write_labeled_block(v.body()) {
+			exit = Some(std::mem::take(&mut self.type_map));
+		}
+
+		for catch in v.catch_list() {
+			let type_list = self.context.by_tag_index(catch.tag()).params();
+
+			self.type_map.clone_from(&entry);
+			self.push(Instruction::Catch(to_u32(catch.tag())));
+			self.write_result_list(catch.payload(), type_list);
+
+			if self.write_labeled_block(catch.block()) {
+				exit = Some(std::mem::take(&mut self.type_map));
+			}
+		}
+
+		if let Some(catch_all) = v.catch_all() {
+			self.type_map.clone_from(&entry);
+			self.push(Instruction::CatchAll);
+
+			if self.write_labeled_block(catch_all) {
+				exit = Some(std::mem::take(&mut self.type_map));
+			}
+		}
+
+		match v.delegate() {
+			Some(target) => {
+				self.push(Instruction::Delegate(to_u32(target)));
+				self.num_frame -= 1;
+			}
+			None => self.close_frame(),
+		}
+
+		let falls_through = exit.is_some();
+
+		self.type_map = exit.unwrap_or(entry);
+
+		falls_through
+	}
+
+	fn write_call(&mut self, v: &Call) {
+		let type_list = self.context.by_func_index(v.function()).results();
+
+		self.write_expression_list(v.param_list());
+		self.push(Instruction::Call(to_u32(v.function())));
+		self.write_result_list(v.result_list(), type_list);
+	}
+
+	fn write_call_indirect(&mut self, v: &CallIndirect) {
+		let type_list = self.context.by_type_index(to_u32(v.ty())).results();
+
+		self.write_expression_list(v.param_list());
+		self.write_expression(v.index());
+		self.push(Instruction::CallIndirect {
+			ty: to_u32(v.ty()),
+			table: to_u32(v.table()),
+		});
+
+		self.write_result_list(v.result_list(), type_list);
+	}
+
+	fn write_store_at(&mut self, v: &StoreAt) {
+		let align = instruction::store_align(v.store_type());
+		let memarg = memory_argument(v.memory(), v.offset(), align);
+
+		self.write_expression(v.pointer());
+		self.write_expression(v.value());
+		self.push(instruction::store(v.store_type(), memarg));
+	}
+
+	fn write_memory_grow(&mut self, v: &MemoryGrow) {
+		self.write_expression(v.size());
+		self.push(Instruction::MemoryGrow(to_u32(v.memory())));
+		self.write_set_temporary(v.result().var(), index_result(v.index_type()));
+	}
+
+	fn write_atomic_wait(&mut self, v: &AtomicWait) {
+		let load = v.value();
+		let align = instruction::load_align(load.load_type());
+		let memarg = memory_argument(load.memory(), load.offset(), align);
+
+		self.write_expression(load.pointer());
+		self.write_expression(v.expected());
+		self.write_expression(v.timeout());
+
+		match instruction::load_result(load.load_type()) {
+			ValType::I64 => self.push(Instruction::MemoryAtomicWait64(memarg)),
+			_ => self.push(Instruction::MemoryAtomicWait32(memarg)),
+		}
+
+		self.write_set_temporary(v.result().var(), ValType::I32);
+	}
+
+	fn write_memory_pointer(&mut self, v: &MemoryArgument) {
+		self.write_expression(v.pointer());
+	}
+
+	fn write_memory_copy(&mut self, v: &MemoryCopy) {
+		self.write_memory_pointer(v.destination());
+		self.write_memory_pointer(v.source());
+		self.write_expression(v.size());
+		self.push(Instruction::MemoryCopy {
+			src_mem: to_u32(v.source().memory()),
+			dst_mem: to_u32(v.destination().memory()),
+		});
+	}
+
+	fn write_memory_fill(&mut self, v: &MemoryFill) {
+		self.write_memory_pointer(v.destination());
+		self.write_expression(v.value());
+		self.write_expression(v.size());
+		self.push(Instruction::MemoryFill(to_u32(v.destination().memory())));
+	}
+
+	fn write_memory_init(&mut self, v: &MemoryInit) {
+		self.write_memory_pointer(v.destination());
+		self.write_expression(v.offset());
+		self.write_expression(v.size());
+		self.push(Instruction::MemoryInit {
+			mem: to_u32(v.destination().memory()),
+			data_index: to_u32(v.data()),
+		});
+	}
+
+	fn write_table_get(&mut self, v: &TableGet) {
+		let ty = self.context.table_list[v.table()];
+
+		self.write_expression(v.index());
+		self.push(Instruction::TableGet(to_u32(v.table())));
+		self.write_set_temporary(v.result().var(), ValType::Ref(ty));
+	}
+
+	fn write_table_set(&mut self, v: &TableSet) {
+		self.write_expression(v.index());
+		self.write_expression(v.value());
+		self.push(Instruction::TableSet(to_u32(v.table())));
+	}
+
+	fn write_table_grow(&mut self, v: &TableGrow) {
+		self.write_expression(v.value());
+		self.write_expression(v.size());
+		self.push(Instruction::TableGrow(to_u32(v.table())));
+		self.write_set_temporary(v.result().var(), ValType::I32);
+	}
+
+	fn write_table_index(&mut self, v: &TableArgument) {
+		self.write_expression(v.index());
+	}
+
+	fn write_table_fill(&mut self, v: &TableFill) {
+		self.write_table_index(v.destination());
+		self.write_expression(v.value());
+		self.write_expression(v.size());
+		self.push(Instruction::TableFill(to_u32(v.destination().table())));
+	}
+
+	fn write_table_copy(&mut self, v: &TableCopy) {
+		self.write_table_index(v.destination());
+		self.write_table_index(v.source());
+		self.write_expression(v.size());
+		self.push(Instruction::TableCopy {
+			src_table: to_u32(v.source().table()),
+			dst_table: to_u32(v.destination().table()),
+		});
+	}
+
+	fn write_table_init(&mut self, v: &TableInit) {
+		self.write_table_index(v.destination());
+		self.write_expression(v.offset());
+		self.write_expression(v.size());
+		self.push(Instruction::TableInit {
+			elem_index: to_u32(v.element()),
+			table: to_u32(v.destination().table()),
+		});
+	}
+
+	// Returns whether execution can continue past the statement
+	fn write_statement(&mut self, v: &Statement) -> bool {
+		match v {
+			Statement::Block(s) => return self.write_block(s),
+			Statement::BrIf(s) => self.write_br_if(s),
+			Statement::If(s) => return self.write_if(s),
+			Statement::Call(s) => self.write_call(s),
+			Statement::CallIndirect(s) => self.write_call_indirect(s),
+			Statement::SetTemporary(s) => {
+				let ty = self.write_expression(s.value());
+
+				self.write_set_temporary(s.var().var(), ty);
+			}
+			Statement::SetLocal(s) => {
+				self.write_expression(s.value());
+				self.push(Instruction::LocalSet(to_u32(s.var().var())));
+			}
+			Statement::SetGlobal(s) => {
+				self.write_expression(s.value());
+				self.push(Instruction::GlobalSet(to_u32(s.var())));
+			}
+			Statement::StoreAt(s) => self.write_store_at(s),
+			Statement::MemoryGrow(s) => self.write_memory_grow(s),
+			Statement::AtomicWait(s) => self.write_atomic_wait(s),
+			Statement::MemoryCopy(s) => self.write_memory_copy(s),
+			Statement::MemoryFill(s) => self.write_memory_fill(s),
+			Statement::MemoryInit(s) => self.write_memory_init(s),
+			Statement::DataDrop(s) => self.push(Instruction::DataDrop(to_u32(s.data()))),
+			Statement::TableGet(s) => self.write_table_get(s),
+			Statement::TableSet(s) => self.write_table_set(s),
+			Statement::TableSize(s) => {
+				self.push(Instruction::TableSize(to_u32(s.table())));
+				self.write_set_temporary(s.result().var(), ValType::I32);
+			}
+			Statement::TableGrow(s) => self.write_table_grow(s),
+			Statement::TableFill(s) => self.write_table_fill(s),
+			Statement::TableCopy(s) => self.write_table_copy(s),
+			Statement::TableInit(s) => self.write_table_init(s),
+			Statement::ElemDrop(s) => self.push(Instruction::ElemDrop(to_u32(s.element()))),
+			Statement::Try(s) => return self.write_try(s),
+		}
+
+		true
+	}
+
+	// Tables that need moves jump to a block per distinct branch,
+	// where the moves are done before branching to the real target
+	fn write_br_table(&mut self, v: &BrTable) {
+		let is_aligned = |br: &Br| br.align().is_aligned();
+
+		if v.data().iter().all(is_aligned) && is_aligned(&v.default()) {
+			self.write_expression(v.condition());
+
+			let list: Vec<_> = v.data().iter().map(|&br| self.get_br_depth(br)).collect();
+			let default = self.get_br_depth(v.default());
+
+			self.push(Instruction::BrTable(list.into(), default));
+
+			return;
+		}
+
+		let key = |br: &Br| {
+			let align = br.align();
+
+			(br.target(), align.new, align.old, align.length)
+		};
+
+		let mut case_list: Vec<Br> = Vec::new();
+		let mut index_list = Vec::new();
+
+		for br in v.data().iter().copied().chain(std::iter::once(v.default())) {
+			let index = case_list
+				.iter()
+				.position(|v| key(v) == key(&br))
+				.unwrap_or_else(|| {
+					case_list.push(br);
+
+					case_list.len() - 1
+				});
+
+			index_list.push(to_u32(index));
+		}
+
+		for _ in &case_list {
+			self.open_frame(Instruction::Block(BlockType::Empty));
+		}
+
+		self.write_expression(v.condition());
+
+		let default = index_list.pop().unwrap();
+
+		self.push(Instruction::BrTable(index_list.into(), default));
+
+		for br in case_list {
+			self.close_frame();
+			self.write_br(br);
+		}
+	}
+
+	fn write_terminator(&mut self, v: &Terminator) {
+		match v {
+			Terminator::Unreachable(_) => self.push(Instruction::Unreachable),
+			Terminator::Br(s) => self.write_br(*s),
+			Terminator::BrTable(s) => self.write_br_table(s),
+			Terminator::ReturnCall(s) => self.write_return_call(s),
+			Terminator::ReturnCallIndirect(s) => self.write_return_call_indirect(s),
+			Terminator::Throw(s) => self.write_throw(s),
+			Terminator::Rethrow(s) => {
+				let depth = self.get_relative_depth(s.target());
+
+				self.push(Instruction::Rethrow(depth));
+			}
+		}
+	}
+
+	fn write_return_call(&mut self, v: &ReturnCall) {
+		self.write_expression_list(v.param_list());
+		self.push(Instruction::ReturnCall(to_u32(v.function())));
+	}
+
+	fn write_return_call_indirect(&mut self, v: &ReturnCallIndirect) {
+		self.write_expression_list(v.param_list());
+		self.write_expression(v.index());
+		self.push(Instruction::ReturnCallIndirect {
+			ty: to_u32(v.ty()),
+			table: to_u32(v.table()),
+		});
+	}
+
+	fn write_throw(&mut self, v: &Throw) {
+		self.write_expression_list(v.param_list());
+		self.push(Instruction::Throw(to_u32(v.tag())));
+	}
+
+	// The body is wrapped in a block so branches to it, which
+	// are returns, still push the results from their temporaries
+	fn write_func_data(&mut self, func: &FuncData, result_list: &[ValType]) {
+		self.write_block(func.code());
+
+		for (i, &ty) in result_list.iter().enumerate() {
+			self.write_get_temporary(i, ty);
+		}
+
+		self.push(Instruction::End);
+	}
+
+	fn into_function(self, local_data: &[ValType]) -> Function {
+		let mut local_list: Vec<(u32, wasm_encoder::ValType)> = Vec::new();
+
+		for &ty in local_data.iter().chain(&self.temporary_list) {
+			let ty = into_val_type(ty);
+
+			match local_list.last_mut() {
+				Some((count, last)) if *last == ty => *count += 1,
+				_ => local_list.push((1, ty)),
+			}
+		}
+
+		let mut function = Function::new(local_list);
+
+		for inst in &self.code {
+			function.instruction(inst);
+		}
+
+		function
+	}
+}
+
+fn encode_function(context: &Context, index: usize, func: &FuncData) -> Function {
+	let ty = context.by_func_index(index);
+	let local_list = ty
+		.params()
+		.iter()
+		.chain(func.local_data())
+		.copied()
+		.collect();
+
+	let mut encoder = Encoder::new(context, local_list);
+
+	encoder.write_func_data(func, ty.results());
+	encoder.into_function(func.local_data())
+}
+
+// Only the code section is rebuilt from the tree; every other section
+// is copied as it was, so names, custom sections, and their order stay
+#[must_use]
+pub fn encode_module(wasm: &Module, func_list: &[FuncData]) -> Vec<u8> {
+	let context = Context::from_module(wasm);
+	let offset = wasm.import_count(External::Func);
+	let mut code = CodeSection::new();
+
+	for (i, func) in func_list.iter().enumerate() {
+		code.function(&encode_function(&context, i + offset, func));
+	}
+
+	let mut binary = Binary::new();
+
+	for &(id, data) in wasm.section_list() {
+		if id == SectionId::Code as u8 {
+			binary.section(&code);
+		} else {
+			binary.section(&RawSection { id, data });
+		}
+	}
+
+	binary.finish()
+}
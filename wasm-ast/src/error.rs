@@ -0,0 +1,128 @@
+use std::fmt::{Display, Formatter};
+
+use wasmparser::BinaryReaderError;
+
+#[derive(Debug)]
+pub enum Error {
+	/// A section or function body could not be decoded.
+	Malformed {
+		function: Option<usize>,
+		source: BinaryReaderError,
+	},
+	/// An operator has no representation in the syntax tree.
+	UnsupportedOperator {
+		function: Option<usize>,
+		offset: usize,
+		operator: String,
+	},
+	/// An initializer expression contains an operator that is not constant.
+	NonConstant { offset: usize, operator: String },
+	/// The DWARF debug information in the custom sections could not be read.
+	Dwarf(gimli::Error),
+	/// The translated output could not be written.
+	Io(std::io::Error),
+}
+
+impl Error {
+	#[must_use]
+	pub fn function(&self) -> Option<usize> {
+		match self {
+			Self::Malformed { function, .. } | Self::UnsupportedOperator { function, .. } => {
+				*function
+			}
+			Self::NonConstant { .. } | Self::Dwarf(_) | Self::Io(_) => None,
+		}
+	}
+
+	#[must_use]
+	pub fn offset(&self) -> Option<usize> {
+		match self {
+			Self::Malformed { source, .. } => Some(source.offset()),
+			Self::UnsupportedOperator { offset, .. } | Self::NonConstant { offset, .. } => {
+				Some(*offset)
+			}
+			Self::Dwarf(_) | Self::Io(_) => None,
+		}
+	}
+}
+
+fn write_location(
+	function: Option<usize>,
+	offset: usize,
+	f: &mut Formatter<'_>,
+) -> std::fmt::Result {
+	if let Some(function) = function {
+		write!(f, "function {function} ")?;
+	}
+
+	write!(f, "at offset {offset:#x}")
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Malformed { function, source } => {
+				write!(f, "malformed module, ")?;
+				write_location(*function, source.offset(), f)?;
+				write!(f, ": {}", source.message())
+			}
+			Self::UnsupportedOperator {
+				function,
+				offset,
+				operator,
+			} => {
+				write!(f, "unsupported operator `{operator}`, ")?;
+				write_location(*function, *offset, f)
+			}
+			Self::NonConstant { offset, operator } => {
+				write!(f, "non-constant operator `{operator}` in initializer, ")?;
+				write_location(None, *offset, f)
+			}
+			Self::Dwarf(error) => write!(f, "malformed debug information: {error}"),
+			Self::Io(error) => error.fmt(f),
+		}
+	}
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::Malformed { source, .. } => Some(source),
+			Self::Dwarf(error) => Some(error),
+			Self::Io(error) => Some(error),
+			Self::UnsupportedOperator { .. } | Self::NonConstant { .. } => None,
+		}
+	}
+}
+
+impl From<BinaryReaderError> for Error {
+	fn from(source: BinaryReaderError) -> Self {
+		Self::Malformed {
+			function: None,
+			source,
+		}
+	}
+}
+
+impl From<gimli::Error> for Error {
+	fn from(error: gimli::Error) -> Self {
+		Self::Dwarf(error)
+	}
+}
+
+impl From<std::io::Error> for Error {
+	fn from(error: std::io::Error) -> Self {
+		Self::Io(error)
+	}
+}
+
+impl From<Error> for std::io::Error {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::Io(error) => error,
+			_ => Self::new(std::io::ErrorKind::InvalidData, error),
+		}
+	}
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
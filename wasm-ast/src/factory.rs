@@ -1,29 +1,67 @@
-use wasmparser::{BlockType, FunctionBody, MemArg, Operator, Result};
+use wasmparser::{BlockType, ConstExpr, FunctionBody, MemArg, Operator};
 
 use crate::{
+	error::{Error, Result},
 	module::{read_checked, read_checked_locals, TypeInfo},
 	node::{
-		BinOp, BinOpType, Block, Br, BrIf, BrTable, Call, CallIndirect, CmpOp, CmpOpType,
-		Expression, FuncData, GetGlobal, If, LabelType, LoadAt, LoadType, Local, MemoryArgument,
-		MemoryCopy, MemoryFill, MemoryGrow, MemorySize, Select, SetGlobal, SetLocal, Statement,
-		StoreAt, StoreType, Terminator, UnOp, UnOpType, Value,
+		BinOp, BinOpType, BitSelect, Block, Br, BrIf, BrTable, Call, CallIndirect, Catch, CmpOp,
+		CmpOpType, DataDrop, ElemDrop, Expression, ExtractLane, ExtractLaneType, FuncData,
+		GetGlobal, If, IndexType, LabelType, LoadAt, LoadType, Local, MemoryArgument, MemoryCopy,
+		MemoryFill, MemoryGrow, MemoryInit, MemorySize, RefIsNull, ReplaceLane, ReplaceLaneType,
+		ResultList, Rethrow, ReturnCall, ReturnCallIndirect, Select, SetGlobal, SetLocal, Shuffle,
+		Statement, StoreAt, StoreType, TableArgument, TableCopy, TableFill, TableGet, TableGrow,
+		TableInit, TableSet, TableSize, Terminator, Throw, Try, UnOp, UnOpType, Unreachable, Value,
 	},
-	stack::{ReadGet, Stack},
+	stack::{ReadGet, Stack, Trap},
 };
 
+#[cfg(feature = "atomics")]
+use crate::node::{AtomicWait, Temporary};
+
+// Lua hosts are single threaded, so a read-modify-write is
+// just a load followed by a store of the same width
+#[cfg(feature = "atomics")]
+#[derive(Clone, Copy)]
+enum AtomicRmw {
+	BinOp(BinOpType),
+	Exchange,
+	CompareExchange,
+}
+
 #[derive(Clone, Copy)]
 enum BlockVariant {
 	Forward,
 	Backward,
 	If,
 	Else,
+	Try,
+	Catch(Option<usize>),
 }
 
 enum BlockData {
-	Forward { num_result: usize },
-	Backward { num_param: usize },
-	If { num_result: usize, ty: BlockType },
-	Else { num_result: usize },
+	Forward {
+		num_result: usize,
+	},
+	Backward {
+		num_param: usize,
+	},
+	If {
+		num_result: usize,
+		ty: BlockType,
+	},
+	Else {
+		num_result: usize,
+	},
+	Try {
+		num_result: usize,
+		ty: BlockType,
+	},
+	Catch {
+		num_result: usize,
+		ty: BlockType,
+		tag: Option<usize>,
+		payload: ResultList,
+	},
 }
 
 impl Default for BlockData {
@@ -35,14 +73,25 @@ impl Default for BlockData {
 impl From<BlockData> for LabelType {
 	fn from(data: BlockData) -> Self {
 		match data {
-			BlockData::Forward { .. } | BlockData::If { .. } | BlockData::Else { .. } => {
-				Self::Forward
-			}
+			BlockData::Forward { .. }
+			| BlockData::If { .. }
+			| BlockData::Else { .. }
+			| BlockData::Try { .. }
+			| BlockData::Catch { .. } => Self::Forward,
 			BlockData::Backward { .. } => Self::Backward,
 		}
 	}
 }
 
+// Memory arguments resolved against the type of the memory they access
+#[derive(Clone, Copy)]
+struct MemoryAccess {
+	memory: usize,
+	index_type: IndexType,
+	offset: u64,
+	is_atomic: bool,
+}
+
 #[derive(Default)]
 struct StatList {
 	stack: Stack,
@@ -51,6 +100,11 @@ struct StatList {
 
 	block_data: BlockData,
 	has_reference: bool,
+
+	// Offsets of the operator that opened the block and of the one
+	// currently being translated into it
+	start_offset: usize,
+	code_offset: usize,
 }
 
 impl StatList {
@@ -59,63 +113,217 @@ impl StatList {
 	}
 
 	fn leak_all(&mut self) {
-		self.stack.leak_into(&mut self.code, |_| true);
+		self.stack
+			.leak_into(&mut self.code, self.code_offset, |_| true);
 	}
 
 	fn leak_pre_call(&mut self) {
-		self.stack.leak_into(&mut self.code, |node| {
-			ReadGet::run(node, |_| false, |_| true, |_| true)
-		});
+		self.stack
+			.leak_into(&mut self.code, self.code_offset, |node| {
+				ReadGet::run(node, |_| false, |_| true, |_| true)
+			});
+	}
+
+	// Values that can trap are still computed when dropped
+	fn leak_trap(&mut self) {
+		self.stack
+			.leak_into(&mut self.code, self.code_offset, Trap::run);
 	}
 
 	fn leak_local_write(&mut self, id: usize) {
-		self.stack.leak_into(&mut self.code, |node| {
-			ReadGet::run(node, |var| var.var() == id, |_| false, |_| false)
-		});
+		self.stack
+			.leak_into(&mut self.code, self.code_offset, |node| {
+				ReadGet::run(node, |var| var.var() == id, |_| false, |_| false)
+			});
 	}
 
 	fn leak_global_write(&mut self, id: usize) {
-		self.stack.leak_into(&mut self.code, |node| {
-			ReadGet::run(node, |_| false, |var| var.var() == id, |_| false)
-		});
+		self.stack
+			.leak_into(&mut self.code, self.code_offset, |node| {
+				ReadGet::run(node, |_| false, |var| var.var() == id, |_| false)
+			});
 	}
 
 	fn leak_memory_write(&mut self, id: usize) {
-		self.stack.leak_into(&mut self.code, |node| {
-			ReadGet::run(node, |_| false, |_| false, |var| var.memory() == id)
-		});
+		self.stack
+			.leak_into(&mut self.code, self.code_offset, |node| {
+				ReadGet::run(node, |_| false, |_| false, |var| var.memory() == id)
+			});
 	}
 
-	fn push_load(&mut self, load_type: LoadType, memarg: MemArg) {
-		let memory = memarg.memory.try_into().unwrap();
-		let offset = memarg.offset.try_into().unwrap();
-
+	fn push_load(&mut self, load_type: LoadType, access: MemoryAccess) {
 		let data = Expression::LoadAt(LoadAt {
 			load_type,
-			memory,
-			offset,
+			memory: access.memory,
+			index_type: access.index_type,
+			offset: access.offset,
 			pointer: self.stack.pop().into(),
+			is_atomic: access.is_atomic,
 		});
 
 		self.stack.push(data);
 	}
 
-	fn add_store(&mut self, store_type: StoreType, memarg: MemArg) {
-		let memory = memarg.memory.try_into().unwrap();
-		let offset = memarg.offset.try_into().unwrap();
-
+	fn add_store(&mut self, store_type: StoreType, access: MemoryAccess) {
 		let data = Statement::StoreAt(StoreAt {
 			store_type,
-			memory,
-			offset,
+			memory: access.memory,
+			index_type: access.index_type,
+			offset: access.offset,
 			value: self.stack.pop().into(),
 			pointer: self.stack.pop().into(),
+			is_atomic: access.is_atomic,
+			code_offset: self.code_offset,
 		});
 
-		self.leak_memory_write(memory);
+		self.leak_memory_write(access.memory);
 		self.code.push(data);
 	}
 
+	// The operands are all leaked first so the pointer can be read twice,
+	// and the old value ends up in the slot of the pointer afterwards
+	#[cfg(feature = "atomics")]
+	fn add_atomic_rmw(&mut self, store_type: StoreType, rmw: AtomicRmw, access: MemoryAccess) {
+		// Narrow exchanges compare the zero extended old value against
+		// the expected value truncated to the same width
+		let (load_type, eq_type, mask) = match store_type {
+			StoreType::I32 => (LoadType::I32, CmpOpType::Eq_I32, None),
+			StoreType::I64 => (LoadType::I64, CmpOpType::Eq_I64, None),
+			StoreType::I32_N8 => (LoadType::I32_U8, CmpOpType::Eq_I32, Some(Value::I32(0xFF))),
+			StoreType::I32_N16 => (
+				LoadType::I32_U16,
+				CmpOpType::Eq_I32,
+				Some(Value::I32(0xFFFF)),
+			),
+			StoreType::I64_N8 => (LoadType::I64_U8, CmpOpType::Eq_I64, Some(Value::I64(0xFF))),
+			StoreType::I64_N16 => (
+				LoadType::I64_U16,
+				CmpOpType::Eq_I64,
+				Some(Value::I64(0xFFFF)),
+			),
+			StoreType::I64_N32 => (
+				LoadType::I64_U32,
+				CmpOpType::Eq_I64,
+				Some(Value::I64(0xFFFF_FFFF)),
+			),
+			StoreType::F32 | StoreType::F64 | StoreType::V128 => unreachable!(),
+		};
+
+		let num_operand = if matches!(rmw, AtomicRmw::CompareExchange) {
+			2
+		} else {
+			1
+		};
+
+		self.leak_all();
+
+		let pointer = Temporary {
+			var: self.stack.previous + self.stack.len() - num_operand - 1,
+		};
+
+		self.stack.push(Expression::GetTemporary(pointer));
+		self.push_load(load_type, access);
+		self.leak_all();
+
+		let old = Temporary {
+			var: pointer.var + num_operand + 1,
+		};
+
+		self.stack.pop();
+
+		let value = match rmw {
+			AtomicRmw::BinOp(op_type) => Expression::BinOp(BinOp {
+				op_type,
+				rhs: self.stack.pop().into(),
+				lhs: Expression::GetTemporary(old).into(),
+			}),
+			AtomicRmw::Exchange => self.stack.pop(),
+			AtomicRmw::CompareExchange => {
+				let replacement = self.stack.pop();
+				let expected = match mask {
+					Some(Value::I32(mask)) => Expression::BinOp(BinOp {
+						op_type: BinOpType::And_I32,
+						lhs: self.stack.pop().into(),
+						rhs: Expression::Value(Value::I32(mask)).into(),
+					}),
+					Some(Value::I64(mask)) => Expression::BinOp(BinOp {
+						op_type: BinOpType::And_I64,
+						lhs: self.stack.pop().into(),
+						rhs: Expression::Value(Value::I64(mask)).into(),
+					}),
+					_ => self.stack.pop(),
+				};
+
+				Expression::Select(Select {
+					condition: Expression::CmpOp(CmpOp {
+						op_type: eq_type,
+						lhs: Expression::GetTemporary(old).into(),
+						rhs: expected.into(),
+					})
+					.into(),
+					on_true: replacement.into(),
+					on_false: Expression::GetTemporary(old).into(),
+				})
+			}
+		};
+
+		self.stack.push(value);
+		self.add_store(store_type, access);
+		self.stack.push(Expression::GetTemporary(old));
+		self.leak_all();
+	}
+
+	// Lane loads and stores are a scalar memory access paired with
+	// the matching lane operation on the vector
+	fn push_load_lane(
+		&mut self,
+		load_type: LoadType,
+		lane_type: ReplaceLaneType,
+		access: MemoryAccess,
+		lane: u8,
+	) {
+		let vector = self.stack.pop();
+
+		self.push_load(load_type, access);
+
+		let value = self.stack.pop();
+
+		self.stack.push(vector);
+		self.stack.push(value);
+		self.push_replace_lane(lane_type, lane);
+	}
+
+	fn push_load_splat(&mut self, load_type: LoadType, op_type: UnOpType, access: MemoryAccess) {
+		self.push_load(load_type, access);
+		self.push_un_op(op_type);
+	}
+
+	fn push_load_zero(
+		&mut self,
+		load_type: LoadType,
+		lane_type: ReplaceLaneType,
+		access: MemoryAccess,
+	) {
+		self.push_load(load_type, access);
+
+		let value = self.stack.pop();
+
+		self.push_constant(Value::V128(0));
+		self.stack.push(value);
+		self.push_replace_lane(lane_type, 0);
+	}
+
+	fn add_store_lane(
+		&mut self,
+		store_type: StoreType,
+		lane_type: ExtractLaneType,
+		access: MemoryAccess,
+		lane: u8,
+	) {
+		self.push_extract_lane(lane_type, lane);
+		self.add_store(store_type, access);
+	}
+
 	fn push_constant<T: Into<Value>>(&mut self, value: T) {
 		let value = Expression::Value(value.into());
 
@@ -141,6 +349,27 @@ impl StatList {
 		self.stack.push(data);
 	}
 
+	fn push_extract_lane(&mut self, lane_type: ExtractLaneType, lane: u8) {
+		let data = Expression::ExtractLane(ExtractLane {
+			lane_type,
+			lane,
+			vector: self.stack.pop().into(),
+		});
+
+		self.stack.push(data);
+	}
+
+	fn push_replace_lane(&mut self, lane_type: ReplaceLaneType, lane: u8) {
+		let data = Expression::ReplaceLane(ReplaceLane {
+			lane_type,
+			lane,
+			value: self.stack.pop().into(),
+			vector: self.stack.pop().into(),
+		});
+
+		self.stack.push(data);
+	}
+
 	fn push_cmp_op(&mut self, op_type: CmpOpType) {
 		let data = Expression::CmpOp(CmpOp {
 			op_type,
@@ -204,6 +433,7 @@ impl From<StatList> for Block {
 			label_type,
 			code: stat.code,
 			last: stat.last,
+			code_offset: stat.start_offset,
 		}
 	}
 }
@@ -215,6 +445,9 @@ pub struct Factory<'a> {
 	target: StatList,
 
 	nested_unreachable: usize,
+
+	function: Option<usize>,
+	offset: usize,
 }
 
 impl<'a> Factory<'a> {
@@ -225,31 +458,51 @@ impl<'a> Factory<'a> {
 			pending: Vec::new(),
 			target: StatList::new(),
 			nested_unreachable: 0,
+			function: None,
+			offset: 0,
 		}
 	}
 
-	#[must_use]
-	pub fn create_anonymous(&mut self, list: &[Operator]) -> FuncData {
-		let data = self.build_stat_list(list, 1);
+	/// Anonymous code has no backing binary, so any error offsets
+	/// refer to the operator's position in `list`.
+	///
+	/// # Errors
+	///
+	/// Returns an error if an operator is unsupported.
+	pub fn create_anonymous(&mut self, list: &[Operator]) -> Result<FuncData> {
+		let code: Vec<_> = list.iter().cloned().zip(0..).collect();
 
-		FuncData {
+		self.function = None;
+
+		let data = self.build_stat_list(&code, 1)?;
+
+		Ok(FuncData {
 			local_data: Vec::new(),
 			num_result: 1,
 			num_param: 0,
 			num_stack: data.stack.capacity,
 			code: data.into(),
-		}
+		})
 	}
 
 	/// # Errors
 	///
-	/// Returns an error if the function is malformed.
+	/// Returns an error if the function is malformed or an operator is unsupported.
 	pub fn create_indexed(&mut self, index: usize, func: &FunctionBody) -> Result<FuncData> {
-		let code = read_checked(func.get_operators_reader()?)?;
-		let local_data = read_checked_locals(func.get_locals_reader()?)?;
+		let malformed = |source| Error::Malformed {
+			function: Some(index),
+			source,
+		};
+
+		let reader = func.get_operators_reader().map_err(malformed)?;
+		let code = read_checked(reader.into_iter_with_offsets()).map_err(malformed)?;
+		let reader = func.get_locals_reader().map_err(malformed)?;
+		let local_data = read_checked_locals(reader).map_err(malformed)?;
+
+		self.function = Some(index);
 
 		let (num_param, num_result) = self.type_info.by_func_index(index);
-		let data = self.build_stat_list(&code, num_result);
+		let data = self.build_stat_list(&code, num_result)?;
 
 		Ok(FuncData {
 			local_data,
@@ -260,12 +513,64 @@ impl<'a> Factory<'a> {
 		})
 	}
 
+	/// # Errors
+	///
+	/// Returns an error if the expression is malformed or an operator is not constant.
+	pub fn create_constant(&mut self, init: &ConstExpr) -> Result<Expression> {
+		let reader = init.get_operators_reader();
+		let code = read_checked(reader.into_iter_with_offsets())?;
+
+		// Extended constant expressions only add arithmetic on top of
+		// the plain values and `global.get`
+		let non_constant =
+			code.iter().find(|(op, _)| {
+				!matches!(
+					op,
+					Operator::I32Const { .. }
+						| Operator::I64Const { .. }
+						| Operator::F32Const { .. }
+						| Operator::F64Const { .. }
+						| Operator::V128Const { .. }
+						| Operator::RefNull { .. }
+						| Operator::RefFunc { .. }
+						| Operator::GlobalGet { .. }
+						| Operator::I32Add | Operator::I32Sub
+						| Operator::I32Mul | Operator::I64Add
+						| Operator::I64Sub | Operator::I64Mul
+						| Operator::End
+				)
+			});
+
+		if let Some((op, offset)) = non_constant {
+			return Err(Error::NonConstant {
+				offset: *offset,
+				operator: format!("{op:?}"),
+			});
+		}
+
+		self.function = None;
+
+		let mut data = self.build_stat_list(&code, 1)?;
+
+		// The single result is always leaked into the last statement
+		match data.code.pop() {
+			Some(Statement::SetTemporary(stat)) if data.code.is_empty() => Ok(*stat.value),
+			_ => Err(Error::NonConstant {
+				offset: code.last().map_or(0, |v| v.1),
+				operator: "End".to_string(),
+			}),
+		}
+	}
+
 	fn start_block(&mut self, ty: BlockType, variant: BlockVariant) {
-		let (num_param, num_result) = self.type_info.by_block_type(ty);
+		let (mut num_param, num_result) = self.type_info.by_block_type(ty);
 		let mut old = std::mem::take(&mut self.target);
 
 		old.leak_all();
 
+		self.target.start_offset = self.offset;
+		self.target.code_offset = self.offset;
+
 		self.target.block_data = match variant {
 			BlockVariant::Forward => BlockData::Forward { num_result },
 			BlockVariant::Backward => BlockData::Backward { num_param },
@@ -276,6 +581,22 @@ impl<'a> Factory<'a> {
 
 				BlockData::Else { num_result }
 			}
+			BlockVariant::Try => BlockData::Try { num_result, ty },
+			// Handlers start with the exception payload instead of the block parameters
+			BlockVariant::Catch(tag) => {
+				num_param = tag.map_or(0, |tag| self.type_info.by_tag_index(tag));
+
+				old.stack.pop_len(num_result).for_each(drop);
+
+				let payload = old.stack.push_temporaries(num_param);
+
+				BlockData::Catch {
+					num_result,
+					ty,
+					tag,
+					payload,
+				}
+			}
 		};
 
 		self.target.stack = old.stack.split_last(num_param, num_result);
@@ -295,16 +616,29 @@ impl<'a> Factory<'a> {
 		self.start_block(ty, BlockVariant::Else);
 	}
 
+	fn start_catch(&mut self, tag: Option<usize>) {
+		let (BlockData::Try { ty, .. } | BlockData::Catch { ty, .. }) = self.target.block_data
+		else {
+			unreachable!()
+		};
+
+		self.target.leak_all();
+		self.end_block();
+		self.start_block(ty, BlockVariant::Catch(tag));
+	}
+
 	fn end_block(&mut self) {
 		let old = self.pending.pop().unwrap();
 		let now = std::mem::replace(&mut self.target, old);
 
 		self.target.stack.capacity = now.stack.capacity;
+		self.target.code_offset = self.offset;
 
 		let stat = match now.block_data {
 			BlockData::Forward { .. } | BlockData::Backward { .. } => Statement::Block(now.into()),
 			BlockData::If { .. } => Statement::If(If {
 				condition: self.target.stack.pop().into(),
+				code_offset: now.start_offset,
 				on_true: Box::new(now.into()),
 				on_false: None,
 			}),
@@ -315,6 +649,29 @@ impl<'a> Factory<'a> {
 
 				last.on_false = Some(Box::new(now.into()));
 
+				return;
+			}
+			BlockData::Try { .. } => Statement::Try(Try {
+				code_offset: now.start_offset,
+				body: Box::new(now.into()),
+				catch_list: Vec::new(),
+				catch_all: None,
+				delegate: None,
+			}),
+			BlockData::Catch { tag, payload, .. } => {
+				let Statement::Try(last) = self.target.code.last_mut().unwrap() else {
+					unreachable!()
+				};
+
+				match tag {
+					Some(tag) => last.catch_list.push(Catch {
+						tag,
+						payload,
+						block: now.into(),
+					}),
+					None => last.catch_all = Some(Box::new(now.into())),
+				}
+
 				return;
 			}
 		};
@@ -322,6 +679,16 @@ impl<'a> Factory<'a> {
 		self.target.code.push(stat);
 	}
 
+	fn end_delegate(&mut self, relative_depth: u32) {
+		self.end_block();
+
+		let Some(Statement::Try(last)) = self.target.code.last_mut() else {
+			unreachable!()
+		};
+
+		last.delegate = Some(relative_depth.try_into().unwrap());
+	}
+
 	fn get_relative_block(&mut self, index: usize) -> &mut StatList {
 		if index == 0 {
 			&mut self.target
@@ -332,13 +699,44 @@ impl<'a> Factory<'a> {
 		}
 	}
 
+	fn memory_access(&self, memarg: MemArg) -> MemoryAccess {
+		let memory = memarg.memory.try_into().unwrap();
+
+		MemoryAccess {
+			memory,
+			index_type: self.type_info.by_memory_index(memory),
+			offset: memarg.offset,
+			is_atomic: false,
+		}
+	}
+
+	#[cfg(feature = "atomics")]
+	fn atomic_access(&self, memarg: MemArg) -> MemoryAccess {
+		MemoryAccess {
+			is_atomic: true,
+			..self.memory_access(memarg)
+		}
+	}
+
+	fn pop_memory_argument(&mut self, memory: u32) -> MemoryArgument {
+		let memory = memory.try_into().unwrap();
+
+		MemoryArgument {
+			memory,
+			index_type: self.type_info.by_memory_index(memory),
+			pointer: self.target.stack.pop().into(),
+		}
+	}
+
 	fn get_br_terminator(&mut self, target: usize) -> Br {
 		let block = self.get_relative_block(target);
 		let previous = block.stack.previous;
 		let result = match block.block_data {
 			BlockData::Forward { num_result }
 			| BlockData::If { num_result, .. }
-			| BlockData::Else { num_result } => num_result,
+			| BlockData::Else { num_result }
+			| BlockData::Try { num_result, .. }
+			| BlockData::Catch { num_result, .. } => num_result,
 			BlockData::Backward { num_param } => num_param,
 		};
 
@@ -346,7 +744,11 @@ impl<'a> Factory<'a> {
 
 		let align = self.target.stack.get_br_alignment(previous, result);
 
-		Br { target, align }
+		Br {
+			target,
+			align,
+			code_offset: self.offset,
+		}
 	}
 
 	fn add_call(&mut self, function: usize) {
@@ -361,6 +763,7 @@ impl<'a> Factory<'a> {
 			function,
 			param_list,
 			result_list,
+			code_offset: self.offset,
 		});
 
 		self.target.code.push(data);
@@ -376,19 +779,526 @@ impl<'a> Factory<'a> {
 		let result_list = self.target.stack.push_temporaries(num_result);
 
 		let data = Statement::CallIndirect(CallIndirect {
+			ty,
 			table,
 			index,
 			param_list,
 			result_list,
+			code_offset: self.offset,
 		});
 
 		self.target.code.push(data);
 	}
 
+	// Handlers are only active while the `try` body runs, which Lua cannot leave
+	// with a tail call, so calls in there are made normally before returning.
+	fn is_in_try(&self) -> bool {
+		let mut iter = self.pending.iter().chain(std::iter::once(&self.target));
+
+		iter.any(|v| matches!(v.block_data, BlockData::Try { .. }))
+	}
+
+	fn add_return(&mut self) {
+		let target = self.pending.len();
+		let term = Terminator::Br(self.get_br_terminator(target));
+
+		self.target.set_terminator(term);
+		self.nested_unreachable += 1;
+	}
+
+	fn add_return_call(&mut self, function: usize) {
+		if self.is_in_try() {
+			self.add_call(function);
+			self.add_return();
+
+			return;
+		}
+
+		let (num_param, _) = self.type_info.by_func_index(function);
+		let param_list = self.target.stack.pop_len(num_param).collect();
+
+		let term = Terminator::ReturnCall(ReturnCall {
+			function,
+			param_list,
+			code_offset: self.offset,
+		});
+
+		self.target.set_terminator(term);
+		self.nested_unreachable += 1;
+	}
+
+	fn add_return_call_indirect(&mut self, ty: usize, table: usize) {
+		if self.is_in_try() {
+			self.add_call_indirect(ty, table);
+			self.add_return();
+
+			return;
+		}
+
+		let (num_param, _) = self.type_info.by_type_index(ty);
+		let index = self.target.stack.pop().into();
+		let param_list = self.target.stack.pop_len(num_param).collect();
+
+		let term = Terminator::ReturnCallIndirect(ReturnCallIndirect {
+			ty,
+			table,
+			index,
+			param_list,
+			code_offset: self.offset,
+		});
+
+		self.target.set_terminator(term);
+		self.nested_unreachable += 1;
+	}
+
+	// Waiting can only trap or yield to the host, so the value is
+	// compared by the runtime without any notion of other threads
+	#[cfg(feature = "atomics")]
+	fn add_atomic_wait(&mut self, load_type: LoadType, memarg: MemArg) {
+		let timeout = self.target.stack.pop().into();
+		let expected = self.target.stack.pop().into();
+
+		self.target.push_load(load_type, self.atomic_access(memarg));
+
+		let Expression::LoadAt(value) = self.target.stack.pop() else {
+			unreachable!()
+		};
+
+		self.target.leak_pre_call();
+
+		let result = self.target.stack.push_temporary();
+
+		let data = Statement::AtomicWait(AtomicWait {
+			result,
+			value,
+			expected,
+			timeout,
+			code_offset: self.offset,
+		});
+
+		self.target.code.push(data);
+	}
+
+	#[cfg(feature = "atomics")]
+	#[allow(clippy::too_many_lines)]
+	fn try_add_atomic(&mut self, op: &Operator) -> bool {
+		match *op {
+			Operator::I32AtomicLoad { memarg } => self
+				.target
+				.push_load(LoadType::I32, self.atomic_access(memarg)),
+			Operator::I64AtomicLoad { memarg } => self
+				.target
+				.push_load(LoadType::I64, self.atomic_access(memarg)),
+			Operator::I32AtomicLoad8U { memarg } => self
+				.target
+				.push_load(LoadType::I32_U8, self.atomic_access(memarg)),
+			Operator::I32AtomicLoad16U { memarg } => self
+				.target
+				.push_load(LoadType::I32_U16, self.atomic_access(memarg)),
+			Operator::I64AtomicLoad8U { memarg } => self
+				.target
+				.push_load(LoadType::I64_U8, self.atomic_access(memarg)),
+			Operator::I64AtomicLoad16U { memarg } => self
+				.target
+				.push_load(LoadType::I64_U16, self.atomic_access(memarg)),
+			Operator::I64AtomicLoad32U { memarg } => self
+				.target
+				.push_load(LoadType::I64_U32, self.atomic_access(memarg)),
+			Operator::I32AtomicStore { memarg } => self
+				.target
+				.add_store(StoreType::I32, self.atomic_access(memarg)),
+			Operator::I64AtomicStore { memarg } => self
+				.target
+				.add_store(StoreType::I64, self.atomic_access(memarg)),
+			Operator::I32AtomicStore8 { memarg } => self
+				.target
+				.add_store(StoreType::I32_N8, self.atomic_access(memarg)),
+			Operator::I32AtomicStore16 { memarg } => self
+				.target
+				.add_store(StoreType::I32_N16, self.atomic_access(memarg)),
+			Operator::I64AtomicStore8 { memarg } => self
+				.target
+				.add_store(StoreType::I64_N8, self.atomic_access(memarg)),
+			Operator::I64AtomicStore16 { memarg } => self
+				.target
+				.add_store(StoreType::I64_N16, self.atomic_access(memarg)),
+			Operator::I64AtomicStore32 { memarg } => self
+				.target
+				.add_store(StoreType::I64_N32, self.atomic_access(memarg)),
+			Operator::I32AtomicRmwAdd { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32,
+					AtomicRmw::BinOp(BinOpType::Add_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmwAdd { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64,
+					AtomicRmw::BinOp(BinOpType::Add_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw8AddU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N8,
+					AtomicRmw::BinOp(BinOpType::Add_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw16AddU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N16,
+					AtomicRmw::BinOp(BinOpType::Add_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw8AddU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N8,
+					AtomicRmw::BinOp(BinOpType::Add_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw16AddU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N16,
+					AtomicRmw::BinOp(BinOpType::Add_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw32AddU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N32,
+					AtomicRmw::BinOp(BinOpType::Add_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmwSub { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32,
+					AtomicRmw::BinOp(BinOpType::Sub_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmwSub { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64,
+					AtomicRmw::BinOp(BinOpType::Sub_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw8SubU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N8,
+					AtomicRmw::BinOp(BinOpType::Sub_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw16SubU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N16,
+					AtomicRmw::BinOp(BinOpType::Sub_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw8SubU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N8,
+					AtomicRmw::BinOp(BinOpType::Sub_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw16SubU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N16,
+					AtomicRmw::BinOp(BinOpType::Sub_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw32SubU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N32,
+					AtomicRmw::BinOp(BinOpType::Sub_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmwAnd { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32,
+					AtomicRmw::BinOp(BinOpType::And_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmwAnd { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64,
+					AtomicRmw::BinOp(BinOpType::And_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw8AndU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N8,
+					AtomicRmw::BinOp(BinOpType::And_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw16AndU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N16,
+					AtomicRmw::BinOp(BinOpType::And_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw8AndU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N8,
+					AtomicRmw::BinOp(BinOpType::And_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw16AndU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N16,
+					AtomicRmw::BinOp(BinOpType::And_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw32AndU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N32,
+					AtomicRmw::BinOp(BinOpType::And_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmwOr { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32,
+					AtomicRmw::BinOp(BinOpType::Or_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmwOr { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64,
+					AtomicRmw::BinOp(BinOpType::Or_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw8OrU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N8,
+					AtomicRmw::BinOp(BinOpType::Or_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw16OrU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N16,
+					AtomicRmw::BinOp(BinOpType::Or_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw8OrU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N8,
+					AtomicRmw::BinOp(BinOpType::Or_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw16OrU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N16,
+					AtomicRmw::BinOp(BinOpType::Or_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw32OrU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N32,
+					AtomicRmw::BinOp(BinOpType::Or_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmwXor { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32,
+					AtomicRmw::BinOp(BinOpType::Xor_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmwXor { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64,
+					AtomicRmw::BinOp(BinOpType::Xor_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw8XorU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N8,
+					AtomicRmw::BinOp(BinOpType::Xor_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw16XorU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N16,
+					AtomicRmw::BinOp(BinOpType::Xor_I32),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw8XorU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N8,
+					AtomicRmw::BinOp(BinOpType::Xor_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw16XorU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N16,
+					AtomicRmw::BinOp(BinOpType::Xor_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw32XorU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N32,
+					AtomicRmw::BinOp(BinOpType::Xor_I64),
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmwXchg { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32,
+					AtomicRmw::Exchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmwXchg { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64,
+					AtomicRmw::Exchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw8XchgU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N8,
+					AtomicRmw::Exchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw16XchgU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N16,
+					AtomicRmw::Exchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw8XchgU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N8,
+					AtomicRmw::Exchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw16XchgU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N16,
+					AtomicRmw::Exchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw32XchgU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N32,
+					AtomicRmw::Exchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmwCmpxchg { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32,
+					AtomicRmw::CompareExchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmwCmpxchg { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64,
+					AtomicRmw::CompareExchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw8CmpxchgU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N8,
+					AtomicRmw::CompareExchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I32AtomicRmw16CmpxchgU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I32_N16,
+					AtomicRmw::CompareExchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw8CmpxchgU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N8,
+					AtomicRmw::CompareExchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw16CmpxchgU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N16,
+					AtomicRmw::CompareExchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::I64AtomicRmw32CmpxchgU { memarg } => {
+				self.target.add_atomic_rmw(
+					StoreType::I64_N32,
+					AtomicRmw::CompareExchange,
+					self.atomic_access(memarg),
+				);
+			}
+			Operator::MemoryAtomicNotify { memarg } => {
+				// There are never any other threads waiting to be woken, but
+				// the address is still loaded to trap when it is invalid
+				self.target.leak_trap();
+				self.target.stack.pop();
+				self.target
+					.push_load(LoadType::I32, self.atomic_access(memarg));
+				self.target.leak_trap();
+				self.target.stack.pop();
+				self.target.push_constant(0_i32);
+			}
+			Operator::MemoryAtomicWait32 { memarg } => {
+				self.add_atomic_wait(LoadType::I32, memarg);
+			}
+			Operator::MemoryAtomicWait64 { memarg } => {
+				self.add_atomic_wait(LoadType::I64, memarg);
+			}
+			Operator::AtomicFence => {}
+			_ => return false,
+		}
+
+		true
+	}
+
 	#[cold]
-	fn drop_unreachable(&mut self, op: &Operator) {
+	fn drop_unreachable(&mut self, op: &Operator) -> Result<()> {
 		match op {
-			Operator::Block { .. } | Operator::Loop { .. } | Operator::If { .. } => {
+			Operator::Block { .. }
+			| Operator::Loop { .. }
+			| Operator::If { .. }
+			| Operator::Try { .. } => {
 				self.nested_unreachable += 1;
 			}
 			Operator::Else if self.nested_unreachable == 1 => {
@@ -396,29 +1306,62 @@ impl<'a> Factory<'a> {
 
 				self.start_else();
 			}
+			Operator::Catch { tag_index } if self.nested_unreachable == 1 => {
+				self.nested_unreachable -= 1;
+
+				self.start_catch(Some((*tag_index).try_into().unwrap()));
+			}
+			Operator::CatchAll if self.nested_unreachable == 1 => {
+				self.nested_unreachable -= 1;
+
+				self.start_catch(None);
+			}
+			Operator::Delegate { relative_depth } if self.nested_unreachable == 1 => {
+				self.nested_unreachable -= 1;
+
+				self.end_delegate(*relative_depth);
+			}
 			Operator::End if self.nested_unreachable == 1 => {
 				self.nested_unreachable -= 1;
 
 				self.end_block();
 			}
-			Operator::End => {
+			Operator::End | Operator::Delegate { .. } => {
 				self.nested_unreachable -= 1;
 			}
 			_ => {}
 		}
+
+		Ok(())
+	}
+
+	fn unsupported(&self, op: &Operator) -> Error {
+		Error::UnsupportedOperator {
+			function: self.function,
+			offset: self.offset,
+			operator: format!("{op:?}"),
+		}
 	}
 
 	#[allow(clippy::too_many_lines)]
-	fn add_instruction(&mut self, op: &Operator) {
+	fn add_instruction(&mut self, op: &Operator) -> Result<()> {
 		if self.target.try_add_operation(op) {
-			return;
+			return Ok(());
+		}
+
+		#[cfg(feature = "atomics")]
+		if self.try_add_atomic(op) {
+			return Ok(());
 		}
 
 		match *op {
 			Operator::Unreachable => {
 				self.nested_unreachable += 1;
 
-				self.target.set_terminator(Terminator::Unreachable);
+				self.target
+					.set_terminator(Terminator::Unreachable(Unreachable {
+						code_offset: self.offset,
+					}));
 			}
 			Operator::Nop => {}
 			Operator::Block { blockty } => {
@@ -440,6 +1383,45 @@ impl<'a> Factory<'a> {
 				self.target.leak_all();
 				self.end_block();
 			}
+			Operator::Try { blockty } => {
+				self.start_block(blockty, BlockVariant::Try);
+			}
+			Operator::Catch { tag_index } => {
+				let tag = tag_index.try_into().unwrap();
+
+				self.start_catch(Some(tag));
+			}
+			Operator::CatchAll => {
+				self.start_catch(None);
+			}
+			Operator::Delegate { relative_depth } => {
+				self.target.leak_all();
+				self.end_delegate(relative_depth);
+			}
+			Operator::Throw { tag_index } => {
+				let tag = tag_index.try_into().unwrap();
+				let num_param = self.type_info.by_tag_index(tag);
+				let param_list = self.target.stack.pop_len(num_param).collect();
+
+				let term = Terminator::Throw(Throw {
+					tag,
+					param_list,
+					code_offset: self.offset,
+				});
+
+				self.target.set_terminator(term);
+				self.nested_unreachable += 1;
+			}
+			Operator::Rethrow { relative_depth } => {
+				let target = relative_depth.try_into().unwrap();
+				let term = Terminator::Rethrow(Rethrow {
+					target,
+					code_offset: self.offset,
+				});
+
+				self.target.set_terminator(term);
+				self.nested_unreachable += 1;
+			}
 			Operator::Br { relative_depth } => {
 				let target = relative_depth.try_into().unwrap();
 				let term = Terminator::Br(self.get_br_terminator(target));
@@ -452,6 +1434,7 @@ impl<'a> Factory<'a> {
 				let data = Statement::BrIf(BrIf {
 					condition: self.target.stack.pop().into(),
 					target: self.get_br_terminator(target),
+					code_offset: self.offset,
 				});
 
 				self.target.leak_all();
@@ -461,9 +1444,14 @@ impl<'a> Factory<'a> {
 				let condition = self.target.stack.pop().into();
 				let data = targets
 					.targets()
-					.map(Result::unwrap)
-					.map(|v| self.get_br_terminator(v.try_into().unwrap()))
-					.collect();
+					.map(|v| {
+						v.map(|v| self.get_br_terminator(v.try_into().unwrap()))
+							.map_err(|source| Error::Malformed {
+								function: self.function,
+								source,
+							})
+					})
+					.collect::<Result<_>>()?;
 
 				let default = self.get_br_terminator(targets.default().try_into().unwrap());
 
@@ -471,18 +1459,13 @@ impl<'a> Factory<'a> {
 					condition,
 					data,
 					default,
+					code_offset: self.offset,
 				});
 
 				self.target.set_terminator(term);
 				self.nested_unreachable += 1;
 			}
-			Operator::Return => {
-				let target = self.pending.len();
-				let term = Terminator::Br(self.get_br_terminator(target));
-
-				self.target.set_terminator(term);
-				self.nested_unreachable += 1;
-			}
+			Operator::Return => self.add_return(),
 			Operator::Call { function_index } => {
 				let index = function_index.try_into().unwrap();
 
@@ -490,17 +1473,31 @@ impl<'a> Factory<'a> {
 			}
 			Operator::CallIndirect {
 				type_index,
-				table_byte,
+				table_index,
 				..
 			} => {
 				let index = type_index.try_into().unwrap();
 
-				self.add_call_indirect(index, table_byte.into());
+				self.add_call_indirect(index, table_index.try_into().unwrap());
+			}
+			Operator::ReturnCall { function_index } => {
+				let index = function_index.try_into().unwrap();
+
+				self.add_return_call(index);
+			}
+			Operator::ReturnCallIndirect {
+				type_index,
+				table_index,
+			} => {
+				let index = type_index.try_into().unwrap();
+
+				self.add_return_call_indirect(index, table_index.try_into().unwrap());
 			}
 			Operator::Drop => {
+				self.target.leak_trap();
 				self.target.stack.pop();
 			}
-			Operator::Select => {
+			Operator::Select | Operator::TypedSelect { .. } => {
 				let data = Expression::Select(Select {
 					condition: self.target.stack.pop().into(),
 					on_false: self.target.stack.pop().into(),
@@ -520,6 +1517,7 @@ impl<'a> Factory<'a> {
 				let data = Statement::SetLocal(SetLocal {
 					var: Local { var },
 					value: self.target.stack.pop().into(),
+					code_offset: self.offset,
 				});
 
 				self.target.leak_local_write(var);
@@ -531,6 +1529,7 @@ impl<'a> Factory<'a> {
 				let set = Statement::SetLocal(SetLocal {
 					var: Local { var },
 					value: self.target.stack.pop().into(),
+					code_offset: self.offset,
 				});
 
 				self.target.leak_local_write(var);
@@ -548,37 +1547,221 @@ impl<'a> Factory<'a> {
 				let data = Statement::SetGlobal(SetGlobal {
 					var,
 					value: self.target.stack.pop().into(),
+					code_offset: self.offset,
 				});
 
 				self.target.leak_global_write(var);
 				self.target.code.push(data);
 			}
-			Operator::I32Load { memarg } => self.target.push_load(LoadType::I32, memarg),
-			Operator::I64Load { memarg } => self.target.push_load(LoadType::I64, memarg),
-			Operator::F32Load { memarg } => self.target.push_load(LoadType::F32, memarg),
-			Operator::F64Load { memarg } => self.target.push_load(LoadType::F64, memarg),
-			Operator::I32Load8S { memarg } => self.target.push_load(LoadType::I32_I8, memarg),
-			Operator::I32Load8U { memarg } => self.target.push_load(LoadType::I32_U8, memarg),
-			Operator::I32Load16S { memarg } => self.target.push_load(LoadType::I32_I16, memarg),
-			Operator::I32Load16U { memarg } => self.target.push_load(LoadType::I32_U16, memarg),
-			Operator::I64Load8S { memarg } => self.target.push_load(LoadType::I64_I8, memarg),
-			Operator::I64Load8U { memarg } => self.target.push_load(LoadType::I64_U8, memarg),
-			Operator::I64Load16S { memarg } => self.target.push_load(LoadType::I64_I16, memarg),
-			Operator::I64Load16U { memarg } => self.target.push_load(LoadType::I64_U16, memarg),
-			Operator::I64Load32S { memarg } => self.target.push_load(LoadType::I64_I32, memarg),
-			Operator::I64Load32U { memarg } => self.target.push_load(LoadType::I64_U32, memarg),
-			Operator::I32Store { memarg } => self.target.add_store(StoreType::I32, memarg),
-			Operator::I64Store { memarg } => self.target.add_store(StoreType::I64, memarg),
-			Operator::F32Store { memarg } => self.target.add_store(StoreType::F32, memarg),
-			Operator::F64Store { memarg } => self.target.add_store(StoreType::F64, memarg),
-			Operator::I32Store8 { memarg } => self.target.add_store(StoreType::I32_N8, memarg),
-			Operator::I32Store16 { memarg } => self.target.add_store(StoreType::I32_N16, memarg),
-			Operator::I64Store8 { memarg } => self.target.add_store(StoreType::I64_N8, memarg),
-			Operator::I64Store16 { memarg } => self.target.add_store(StoreType::I64_N16, memarg),
-			Operator::I64Store32 { memarg } => self.target.add_store(StoreType::I64_N32, memarg),
+			Operator::I32Load { memarg } => self
+				.target
+				.push_load(LoadType::I32, self.memory_access(memarg)),
+			Operator::I64Load { memarg } => self
+				.target
+				.push_load(LoadType::I64, self.memory_access(memarg)),
+			Operator::F32Load { memarg } => self
+				.target
+				.push_load(LoadType::F32, self.memory_access(memarg)),
+			Operator::F64Load { memarg } => self
+				.target
+				.push_load(LoadType::F64, self.memory_access(memarg)),
+			Operator::I32Load8S { memarg } => self
+				.target
+				.push_load(LoadType::I32_I8, self.memory_access(memarg)),
+			Operator::I32Load8U { memarg } => self
+				.target
+				.push_load(LoadType::I32_U8, self.memory_access(memarg)),
+			Operator::I32Load16S { memarg } => self
+				.target
+				.push_load(LoadType::I32_I16, self.memory_access(memarg)),
+			Operator::I32Load16U { memarg } => self
+				.target
+				.push_load(LoadType::I32_U16, self.memory_access(memarg)),
+			Operator::I64Load8S { memarg } => self
+				.target
+				.push_load(LoadType::I64_I8, self.memory_access(memarg)),
+			Operator::I64Load8U { memarg } => self
+				.target
+				.push_load(LoadType::I64_U8, self.memory_access(memarg)),
+			Operator::I64Load16S { memarg } => self
+				.target
+				.push_load(LoadType::I64_I16, self.memory_access(memarg)),
+			Operator::I64Load16U { memarg } => self
+				.target
+				.push_load(LoadType::I64_U16, self.memory_access(memarg)),
+			Operator::I64Load32S { memarg } => self
+				.target
+				.push_load(LoadType::I64_I32, self.memory_access(memarg)),
+			Operator::I64Load32U { memarg } => self
+				.target
+				.push_load(LoadType::I64_U32, self.memory_access(memarg)),
+			Operator::V128Load { memarg } => self
+				.target
+				.push_load(LoadType::V128, self.memory_access(memarg)),
+			Operator::V128Load8x8S { memarg } => self
+				.target
+				.push_load(LoadType::V128_I8X8, self.memory_access(memarg)),
+			Operator::V128Load8x8U { memarg } => self
+				.target
+				.push_load(LoadType::V128_U8X8, self.memory_access(memarg)),
+			Operator::V128Load16x4S { memarg } => {
+				self.target
+					.push_load(LoadType::V128_I16X4, self.memory_access(memarg));
+			}
+			Operator::V128Load16x4U { memarg } => {
+				self.target
+					.push_load(LoadType::V128_U16X4, self.memory_access(memarg));
+			}
+			Operator::V128Load32x2S { memarg } => {
+				self.target
+					.push_load(LoadType::V128_I32X2, self.memory_access(memarg));
+			}
+			Operator::V128Load32x2U { memarg } => {
+				self.target
+					.push_load(LoadType::V128_U32X2, self.memory_access(memarg));
+			}
+			Operator::V128Load8Splat { memarg } => {
+				self.target.push_load_splat(
+					LoadType::I32_U8,
+					UnOpType::Splat_I8X16,
+					self.memory_access(memarg),
+				);
+			}
+			Operator::V128Load16Splat { memarg } => {
+				self.target.push_load_splat(
+					LoadType::I32_U16,
+					UnOpType::Splat_I16X8,
+					self.memory_access(memarg),
+				);
+			}
+			Operator::V128Load32Splat { memarg } => {
+				self.target.push_load_splat(
+					LoadType::I32,
+					UnOpType::Splat_I32X4,
+					self.memory_access(memarg),
+				);
+			}
+			Operator::V128Load64Splat { memarg } => {
+				self.target.push_load_splat(
+					LoadType::I64,
+					UnOpType::Splat_I64X2,
+					self.memory_access(memarg),
+				);
+			}
+			Operator::V128Load32Zero { memarg } => {
+				self.target.push_load_zero(
+					LoadType::I32,
+					ReplaceLaneType::I32X4,
+					self.memory_access(memarg),
+				);
+			}
+			Operator::V128Load64Zero { memarg } => {
+				self.target.push_load_zero(
+					LoadType::I64,
+					ReplaceLaneType::I64X2,
+					self.memory_access(memarg),
+				);
+			}
+			Operator::V128Load8Lane { memarg, lane } => {
+				self.target.push_load_lane(
+					LoadType::I32_U8,
+					ReplaceLaneType::I8X16,
+					self.memory_access(memarg),
+					lane,
+				);
+			}
+			Operator::V128Load16Lane { memarg, lane } => {
+				self.target.push_load_lane(
+					LoadType::I32_U16,
+					ReplaceLaneType::I16X8,
+					self.memory_access(memarg),
+					lane,
+				);
+			}
+			Operator::V128Load32Lane { memarg, lane } => {
+				self.target.push_load_lane(
+					LoadType::I32,
+					ReplaceLaneType::I32X4,
+					self.memory_access(memarg),
+					lane,
+				);
+			}
+			Operator::V128Load64Lane { memarg, lane } => {
+				self.target.push_load_lane(
+					LoadType::I64,
+					ReplaceLaneType::I64X2,
+					self.memory_access(memarg),
+					lane,
+				);
+			}
+			Operator::I32Store { memarg } => self
+				.target
+				.add_store(StoreType::I32, self.memory_access(memarg)),
+			Operator::I64Store { memarg } => self
+				.target
+				.add_store(StoreType::I64, self.memory_access(memarg)),
+			Operator::F32Store { memarg } => self
+				.target
+				.add_store(StoreType::F32, self.memory_access(memarg)),
+			Operator::F64Store { memarg } => self
+				.target
+				.add_store(StoreType::F64, self.memory_access(memarg)),
+			Operator::I32Store8 { memarg } => self
+				.target
+				.add_store(StoreType::I32_N8, self.memory_access(memarg)),
+			Operator::I32Store16 { memarg } => self
+				.target
+				.add_store(StoreType::I32_N16, self.memory_access(memarg)),
+			Operator::I64Store8 { memarg } => self
+				.target
+				.add_store(StoreType::I64_N8, self.memory_access(memarg)),
+			Operator::I64Store16 { memarg } => self
+				.target
+				.add_store(StoreType::I64_N16, self.memory_access(memarg)),
+			Operator::I64Store32 { memarg } => self
+				.target
+				.add_store(StoreType::I64_N32, self.memory_access(memarg)),
+			Operator::V128Store { memarg } => self
+				.target
+				.add_store(StoreType::V128, self.memory_access(memarg)),
+			Operator::V128Store8Lane { memarg, lane } => {
+				self.target.add_store_lane(
+					StoreType::I32_N8,
+					ExtractLaneType::U8X16,
+					self.memory_access(memarg),
+					lane,
+				);
+			}
+			Operator::V128Store16Lane { memarg, lane } => {
+				self.target.add_store_lane(
+					StoreType::I32_N16,
+					ExtractLaneType::U16X8,
+					self.memory_access(memarg),
+					lane,
+				);
+			}
+			Operator::V128Store32Lane { memarg, lane } => {
+				self.target.add_store_lane(
+					StoreType::I32,
+					ExtractLaneType::I32X4,
+					self.memory_access(memarg),
+					lane,
+				);
+			}
+			Operator::V128Store64Lane { memarg, lane } => {
+				self.target.add_store_lane(
+					StoreType::I64,
+					ExtractLaneType::I64X2,
+					self.memory_access(memarg),
+					lane,
+				);
+			}
 			Operator::MemorySize { mem, .. } => {
 				let memory = mem.try_into().unwrap();
-				let data = Expression::MemorySize(MemorySize { memory });
+				let data = Expression::MemorySize(MemorySize {
+					memory,
+					index_type: self.type_info.by_memory_index(memory),
+				});
 
 				self.target.stack.push(data);
 			}
@@ -589,8 +1772,10 @@ impl<'a> Factory<'a> {
 
 				let data = Statement::MemoryGrow(MemoryGrow {
 					memory,
+					index_type: self.type_info.by_memory_index(memory),
 					result,
 					size,
+					code_offset: self.offset,
 				});
 
 				self.target.leak_memory_write(memory);
@@ -599,15 +1784,9 @@ impl<'a> Factory<'a> {
 			Operator::MemoryCopy { dst_mem, src_mem } => {
 				let size = self.target.stack.pop().into();
 
-				let source = MemoryArgument {
-					memory: src_mem.try_into().unwrap(),
-					pointer: self.target.stack.pop().into(),
-				};
+				let source = self.pop_memory_argument(src_mem);
 
-				let destination = MemoryArgument {
-					memory: dst_mem.try_into().unwrap(),
-					pointer: self.target.stack.pop().into(),
-				};
+				let destination = self.pop_memory_argument(dst_mem);
 
 				self.target.leak_memory_write(source.memory);
 				self.target.leak_memory_write(destination.memory);
@@ -616,6 +1795,7 @@ impl<'a> Factory<'a> {
 					destination,
 					source,
 					size,
+					code_offset: self.offset,
 				});
 
 				self.target.code.push(data);
@@ -624,10 +1804,7 @@ impl<'a> Factory<'a> {
 				let size = self.target.stack.pop().into();
 				let value = self.target.stack.pop().into();
 
-				let destination = MemoryArgument {
-					memory: mem.try_into().unwrap(),
-					pointer: self.target.stack.pop().into(),
-				};
+				let destination = self.pop_memory_argument(mem);
 
 				self.target.leak_memory_write(destination.memory);
 
@@ -635,27 +1812,260 @@ impl<'a> Factory<'a> {
 					destination,
 					size,
 					value,
+					code_offset: self.offset,
 				});
 
 				self.target.code.push(data);
 			}
+			Operator::MemoryInit { data_index, mem } => {
+				let size = self.target.stack.pop().into();
+				let offset = self.target.stack.pop().into();
+
+				let destination = self.pop_memory_argument(mem);
+
+				self.target.leak_memory_write(destination.memory);
+
+				let data = Statement::MemoryInit(MemoryInit {
+					destination,
+					data: data_index.try_into().unwrap(),
+					offset,
+					size,
+					code_offset: self.offset,
+				});
+
+				self.target.code.push(data);
+			}
+			Operator::DataDrop { data_index } => {
+				let data = Statement::DataDrop(DataDrop {
+					data: data_index.try_into().unwrap(),
+					code_offset: self.offset,
+				});
+
+				self.target.code.push(data);
+			}
+			Operator::TableGet { table } => {
+				let index = self.target.stack.pop().into();
+				let result = self.target.stack.push_temporary();
+
+				let data = Statement::TableGet(TableGet {
+					table: table.try_into().unwrap(),
+					index,
+					result,
+					code_offset: self.offset,
+				});
+
+				self.target.code.push(data);
+			}
+			Operator::TableSet { table } => {
+				let data = Statement::TableSet(TableSet {
+					table: table.try_into().unwrap(),
+					value: self.target.stack.pop().into(),
+					index: self.target.stack.pop().into(),
+					code_offset: self.offset,
+				});
+
+				self.target.code.push(data);
+			}
+			Operator::TableSize { table } => {
+				let data = Statement::TableSize(TableSize {
+					table: table.try_into().unwrap(),
+					result: self.target.stack.push_temporary(),
+					code_offset: self.offset,
+				});
+
+				self.target.code.push(data);
+			}
+			Operator::TableGrow { table } => {
+				let size = self.target.stack.pop().into();
+				let value = self.target.stack.pop().into();
+				let result = self.target.stack.push_temporary();
+
+				let data = Statement::TableGrow(TableGrow {
+					table: table.try_into().unwrap(),
+					result,
+					value,
+					size,
+					code_offset: self.offset,
+				});
+
+				self.target.code.push(data);
+			}
+			Operator::TableFill { table } => {
+				let size = self.target.stack.pop().into();
+				let value = self.target.stack.pop().into();
+
+				let destination = TableArgument {
+					table: table.try_into().unwrap(),
+					index: self.target.stack.pop().into(),
+				};
+
+				let data = Statement::TableFill(TableFill {
+					destination,
+					value,
+					size,
+					code_offset: self.offset,
+				});
+
+				self.target.code.push(data);
+			}
+			Operator::TableCopy {
+				dst_table,
+				src_table,
+			} => {
+				let size = self.target.stack.pop().into();
+
+				let source = TableArgument {
+					table: src_table.try_into().unwrap(),
+					index: self.target.stack.pop().into(),
+				};
+
+				let destination = TableArgument {
+					table: dst_table.try_into().unwrap(),
+					index: self.target.stack.pop().into(),
+				};
+
+				let data = Statement::TableCopy(TableCopy {
+					destination,
+					source,
+					size,
+					code_offset: self.offset,
+				});
+
+				self.target.code.push(data);
+			}
+			Operator::TableInit { elem_index, table } => {
+				let size = self.target.stack.pop().into();
+				let offset = self.target.stack.pop().into();
+
+				let destination = TableArgument {
+					table: table.try_into().unwrap(),
+					index: self.target.stack.pop().into(),
+				};
+
+				let data = Statement::TableInit(TableInit {
+					destination,
+					element: elem_index.try_into().unwrap(),
+					offset,
+					size,
+					code_offset: self.offset,
+				});
+
+				self.target.code.push(data);
+			}
+			Operator::ElemDrop { elem_index } => {
+				let data = Statement::ElemDrop(ElemDrop {
+					element: elem_index.try_into().unwrap(),
+					code_offset: self.offset,
+				});
+
+				self.target.code.push(data);
+			}
+			Operator::RefNull { hty } => self.target.push_constant(Value::RefNull(hty)),
+			Operator::RefFunc { function_index } => {
+				let function = function_index.try_into().unwrap();
+
+				self.target.push_constant(Value::RefFunc(function));
+			}
+			Operator::RefIsNull => {
+				let data = Expression::RefIsNull(RefIsNull {
+					value: self.target.stack.pop().into(),
+				});
+
+				self.target.stack.push(data);
+			}
 			Operator::I32Const { value } => self.target.push_constant(value),
 			Operator::I64Const { value } => self.target.push_constant(value),
 			Operator::F32Const { value } => self.target.push_constant(value.bits()),
 			Operator::F64Const { value } => self.target.push_constant(value.bits()),
-			_ => panic!("Unsupported instruction: {op:?}"),
+			Operator::V128Const { value } => {
+				let value = u128::from_le_bytes(*value.bytes());
+
+				self.target.push_constant(Value::V128(value));
+			}
+			Operator::I8x16ExtractLaneS { lane } => {
+				self.target.push_extract_lane(ExtractLaneType::I8X16, lane);
+			}
+			Operator::I8x16ExtractLaneU { lane } => {
+				self.target.push_extract_lane(ExtractLaneType::U8X16, lane);
+			}
+			Operator::I16x8ExtractLaneS { lane } => {
+				self.target.push_extract_lane(ExtractLaneType::I16X8, lane);
+			}
+			Operator::I16x8ExtractLaneU { lane } => {
+				self.target.push_extract_lane(ExtractLaneType::U16X8, lane);
+			}
+			Operator::I32x4ExtractLane { lane } => {
+				self.target.push_extract_lane(ExtractLaneType::I32X4, lane);
+			}
+			Operator::I64x2ExtractLane { lane } => {
+				self.target.push_extract_lane(ExtractLaneType::I64X2, lane);
+			}
+			Operator::F32x4ExtractLane { lane } => {
+				self.target.push_extract_lane(ExtractLaneType::F32X4, lane);
+			}
+			Operator::F64x2ExtractLane { lane } => {
+				self.target.push_extract_lane(ExtractLaneType::F64X2, lane);
+			}
+			Operator::I8x16ReplaceLane { lane } => {
+				self.target.push_replace_lane(ReplaceLaneType::I8X16, lane);
+			}
+			Operator::I16x8ReplaceLane { lane } => {
+				self.target.push_replace_lane(ReplaceLaneType::I16X8, lane);
+			}
+			Operator::I32x4ReplaceLane { lane } => {
+				self.target.push_replace_lane(ReplaceLaneType::I32X4, lane);
+			}
+			Operator::I64x2ReplaceLane { lane } => {
+				self.target.push_replace_lane(ReplaceLaneType::I64X2, lane);
+			}
+			Operator::F32x4ReplaceLane { lane } => {
+				self.target.push_replace_lane(ReplaceLaneType::F32X4, lane);
+			}
+			Operator::F64x2ReplaceLane { lane } => {
+				self.target.push_replace_lane(ReplaceLaneType::F64X2, lane);
+			}
+			Operator::I8x16Shuffle { lanes } => {
+				let data = Expression::Shuffle(Shuffle {
+					lane_list: lanes,
+					rhs: self.target.stack.pop().into(),
+					lhs: self.target.stack.pop().into(),
+				});
+
+				self.target.stack.push(data);
+			}
+			Operator::V128Bitselect => {
+				let data = Expression::BitSelect(BitSelect {
+					condition: self.target.stack.pop().into(),
+					on_false: self.target.stack.pop().into(),
+					on_true: self.target.stack.pop().into(),
+				});
+
+				self.target.stack.push(data);
+			}
+			_ => return Err(self.unsupported(op)),
 		}
+
+		Ok(())
 	}
 
-	fn build_stat_list(&mut self, list: &[Operator], num_result: usize) -> StatList {
+	fn build_stat_list(
+		&mut self,
+		list: &[(Operator, usize)],
+		num_result: usize,
+	) -> Result<StatList> {
+		self.pending.clear();
+		self.target = StatList::new();
 		self.target.block_data = BlockData::Forward { num_result };
 		self.nested_unreachable = 0;
 
-		for op in list.iter().take(list.len() - 1) {
+		for (op, offset) in list.iter().take(list.len() - 1) {
+			self.offset = *offset;
+			self.target.code_offset = *offset;
+
 			if self.nested_unreachable == 0 {
-				self.add_instruction(op);
+				self.add_instruction(op)?;
 			} else {
-				self.drop_unreachable(op);
+				self.drop_unreachable(op)?;
 			}
 		}
 
@@ -663,6 +2073,6 @@ impl<'a> Factory<'a> {
 			self.target.leak_all();
 		}
 
-		std::mem::take(&mut self.target)
+		Ok(std::mem::take(&mut self.target))
 	}
 }
@@ -1,6 +1,15 @@
+pub mod cfg;
+pub mod dwarf;
+pub mod encode;
+pub mod error;
 pub mod factory;
 pub mod module;
 pub mod node;
+pub mod optimize;
+pub mod print;
+pub mod source_map;
 pub mod visit;
 
 mod stack;
+
+pub use error::Error;
@@ -1,11 +1,14 @@
 use std::collections::HashMap;
 
 use wasmparser::{
-	BlockType, Data, Element, Export, ExternalKind, FunctionBody, Global, Import, LocalsReader,
-	MemoryType, Name, NameSectionReader, Parser, Payload, RecGroup as Type, Result, Table, TypeRef,
-	ValType,
+	BlockType, Data, Element, Export, ExternalKind, FuncValidatorAllocations, FunctionBody, Global,
+	Import, IndirectNameMap, LocalsReader, MemoryType, Name, NameMap, NameSectionReader, Operator,
+	Parser, Payload, RecGroup as Type, Result, Table, TagType, TypeRef, ValType, ValidPayload,
+	Validator, WasmFeatures,
 };
 
+use crate::node::IndexType;
+
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub enum External {
 	Func,
@@ -54,6 +57,120 @@ pub(crate) fn read_checked_locals(reader: LocalsReader) -> Result<Vec<ValType>>
 	})
 }
 
+// Names are only debug information, so malformed entries
+// end their map instead of failing the whole module
+fn read_name_map(map: NameMap<'_>) -> HashMap<u32, &str> {
+	map.into_iter()
+		.map_while(std::result::Result::ok)
+		.map(|v| (v.index, v.name))
+		.collect()
+}
+
+fn read_indirect_name_map(map: IndirectNameMap<'_>) -> HashMap<u32, HashMap<u32, &str>> {
+	map.into_iter()
+		.map_while(std::result::Result::ok)
+		.map(|v| (v.index, read_name_map(v.names)))
+		.collect()
+}
+
+// Every subsection of the extended name proposal, with the
+// indirect maps keyed by function before the inner index
+#[derive(Default)]
+pub struct NameSection<'a> {
+	module: Option<&'a str>,
+	function_map: HashMap<u32, &'a str>,
+	local_map: HashMap<u32, HashMap<u32, &'a str>>,
+	label_map: HashMap<u32, HashMap<u32, &'a str>>,
+	type_map: HashMap<u32, &'a str>,
+	table_map: HashMap<u32, &'a str>,
+	memory_map: HashMap<u32, &'a str>,
+	global_map: HashMap<u32, &'a str>,
+	element_map: HashMap<u32, &'a str>,
+	data_map: HashMap<u32, &'a str>,
+	tag_map: HashMap<u32, &'a str>,
+	field_map: HashMap<u32, HashMap<u32, &'a str>>,
+}
+
+impl<'a> NameSection<'a> {
+	fn load_name(&mut self, name: Name<'a>) {
+		match name {
+			Name::Module { name, .. } => self.module = Some(name),
+			Name::Function(map) => self.function_map = read_name_map(map),
+			Name::Local(map) => self.local_map = read_indirect_name_map(map),
+			Name::Label(map) => self.label_map = read_indirect_name_map(map),
+			Name::Type(map) => self.type_map = read_name_map(map),
+			Name::Table(map) => self.table_map = read_name_map(map),
+			Name::Memory(map) => self.memory_map = read_name_map(map),
+			Name::Global(map) => self.global_map = read_name_map(map),
+			Name::Element(map) => self.element_map = read_name_map(map),
+			Name::Data(map) => self.data_map = read_name_map(map),
+			Name::Tag(map) => self.tag_map = read_name_map(map),
+			Name::Field(map) => self.field_map = read_indirect_name_map(map),
+			Name::Unknown { .. } => {}
+		}
+	}
+
+	#[must_use]
+	pub const fn module(&self) -> Option<&'a str> {
+		self.module
+	}
+
+	#[must_use]
+	pub const fn function_map(&self) -> &HashMap<u32, &'a str> {
+		&self.function_map
+	}
+
+	#[must_use]
+	pub const fn local_map(&self) -> &HashMap<u32, HashMap<u32, &'a str>> {
+		&self.local_map
+	}
+
+	#[must_use]
+	pub const fn label_map(&self) -> &HashMap<u32, HashMap<u32, &'a str>> {
+		&self.label_map
+	}
+
+	#[must_use]
+	pub const fn type_map(&self) -> &HashMap<u32, &'a str> {
+		&self.type_map
+	}
+
+	#[must_use]
+	pub const fn table_map(&self) -> &HashMap<u32, &'a str> {
+		&self.table_map
+	}
+
+	#[must_use]
+	pub const fn memory_map(&self) -> &HashMap<u32, &'a str> {
+		&self.memory_map
+	}
+
+	#[must_use]
+	pub const fn global_map(&self) -> &HashMap<u32, &'a str> {
+		&self.global_map
+	}
+
+	#[must_use]
+	pub const fn element_map(&self) -> &HashMap<u32, &'a str> {
+		&self.element_map
+	}
+
+	#[must_use]
+	pub const fn data_map(&self) -> &HashMap<u32, &'a str> {
+		&self.data_map
+	}
+
+	#[must_use]
+	pub const fn tag_map(&self) -> &HashMap<u32, &'a str> {
+		&self.tag_map
+	}
+
+	#[must_use]
+	pub const fn field_map(&self) -> &HashMap<u32, HashMap<u32, &'a str>> {
+		&self.field_map
+	}
+}
+
 pub struct Module<'a> {
 	type_section: Vec<Type>,
 	import_section: Vec<Import<'a>>,
@@ -61,21 +178,108 @@ pub struct Module<'a> {
 	table_section: Vec<Table<'a>>,
 	memory_section: Vec<MemoryType>,
 	global_section: Vec<Global<'a>>,
+	tag_section: Vec<TagType>,
 	export_section: Vec<Export<'a>>,
 	element_section: Vec<Element<'a>>,
 	data_section: Vec<Data<'a>>,
 	code_section: Vec<FunctionBody<'a>>,
 
-	name_section: HashMap<u32, &'a str>,
+	name_section: NameSection<'a>,
 
 	start_section: Option<u32>,
+
+	// Custom sections other than `name` are kept as raw bytes, which is
+	// where debug information such as DWARF lives
+	custom_section_map: HashMap<&'a str, &'a [u8]>,
+
+	// Start of the code section contents, which DWARF addresses are relative to
+	code_section_offset: usize,
+
+	// Every section as it appeared in the binary, so a module
+	// can be written back out with only its code replaced
+	section_list: Vec<(u8, &'a [u8])>,
+}
+
+// `wasmparser` refuses to validate the legacy exception opcodes, so
+// function bodies using them are only checked for being well formed.
+fn has_legacy_exception(body: &FunctionBody) -> Result<bool> {
+	for operator in body.get_operators_reader()? {
+		if let Operator::Try { .. }
+		| Operator::Catch { .. }
+		| Operator::CatchAll
+		| Operator::Rethrow { .. }
+		| Operator::Delegate { .. } = operator?
+		{
+			return Ok(true);
+		}
+	}
+
+	Ok(false)
+}
+
+fn validate_data(data: &[u8], features: WasmFeatures) -> Result<()> {
+	let mut validator = Validator::new_with_features(features);
+	let mut allocations = FuncValidatorAllocations::default();
+
+	for payload in Parser::new(0).parse_all(data) {
+		let ValidPayload::Func(func, body) = validator.payload(&payload?)? else {
+			continue;
+		};
+
+		if has_legacy_exception(&body)? {
+			continue;
+		}
+
+		let mut func = func.into_validator(allocations);
+
+		func.validate(&body)?;
+		allocations = func.into_allocations();
+	}
+
+	Ok(())
 }
 
 impl<'a> Module<'a> {
+	// The proposals the code generators know how to lower; anything else
+	// is rejected by validation before a translator ever sees it.
+	#[must_use]
+	pub fn supported_features() -> WasmFeatures {
+		let mut features = WasmFeatures::MUTABLE_GLOBAL
+			| WasmFeatures::SATURATING_FLOAT_TO_INT
+			| WasmFeatures::SIGN_EXTENSION
+			| WasmFeatures::REFERENCE_TYPES
+			| WasmFeatures::MULTI_VALUE
+			| WasmFeatures::BULK_MEMORY
+			| WasmFeatures::SIMD
+			| WasmFeatures::TAIL_CALL
+			| WasmFeatures::FLOATS
+			| WasmFeatures::MULTI_MEMORY
+			| WasmFeatures::EXCEPTIONS
+			| WasmFeatures::MEMORY64
+			| WasmFeatures::EXTENDED_CONST;
+
+		if cfg!(feature = "atomics") {
+			features |= WasmFeatures::THREADS;
+		}
+
+		features
+	}
+
 	/// # Errors
 	///
-	/// Returns a `BinaryReaderError` if any module section is malformed.
+	/// Returns a `BinaryReaderError` if the module is malformed or fails
+	/// validation with the supported features.
 	pub fn try_from_data(data: &'a [u8]) -> Result<Self> {
+		Self::try_from_data_with_features(data, Self::supported_features())
+	}
+
+	/// # Errors
+	///
+	/// Returns a `BinaryReaderError` if the module is malformed or fails
+	/// validation with the given features.
+	pub fn try_from_data_with_features(data: &'a [u8], features: WasmFeatures) -> Result<Self> {
+		validate_data(data, features)?;
+
 		let mut temp = Module {
 			type_section: Vec::new(),
 			import_section: Vec::new(),
@@ -83,12 +287,16 @@ impl<'a> Module<'a> {
 			table_section: Vec::new(),
 			memory_section: Vec::new(),
 			global_section: Vec::new(),
+			tag_section: Vec::new(),
 			export_section: Vec::new(),
 			element_section: Vec::new(),
 			data_section: Vec::new(),
 			code_section: Vec::new(),
-			name_section: HashMap::new(),
+			name_section: NameSection::default(),
 			start_section: None,
+			custom_section_map: HashMap::new(),
+			code_section_offset: 0,
+			section_list: Vec::new(),
 		};
 
 		temp.load_data(data)?;
@@ -97,16 +305,26 @@ impl<'a> Module<'a> {
 
 	fn load_data(&mut self, data: &'a [u8]) -> Result<()> {
 		for payload in Parser::new(0).parse_all(data) {
-			match payload? {
+			let payload = payload?;
+
+			if let Some((id, range)) = payload.as_section() {
+				self.section_list.push((id, &data[range]));
+			}
+
+			match payload {
 				Payload::TypeSection(v) => self.type_section = read_checked(v)?,
 				Payload::ImportSection(v) => self.import_section = read_checked(v)?,
 				Payload::FunctionSection(v) => self.func_section = read_checked(v)?,
 				Payload::TableSection(v) => self.table_section = read_checked(v)?,
 				Payload::MemorySection(v) => self.memory_section = read_checked(v)?,
 				Payload::GlobalSection(v) => self.global_section = read_checked(v)?,
+				Payload::TagSection(v) => self.tag_section = read_checked(v)?,
 				Payload::ExportSection(v) => self.export_section = read_checked(v)?,
 				Payload::ElementSection(v) => self.element_section = read_checked(v)?,
 				Payload::DataSection(v) => self.data_section = read_checked(v)?,
+				Payload::CodeSectionStart { range, .. } => {
+					self.code_section_offset = range.start;
+				}
 				Payload::CodeSectionEntry(v) => {
 					self.code_section.push(v);
 				}
@@ -115,14 +333,12 @@ impl<'a> Module<'a> {
 				}
 				Payload::CustomSection(v) if v.name() == "name" => {
 					for name in NameSectionReader::new(v.data(), v.data_offset()) {
-						if let Name::Function(map) = name? {
-							let mut iter = map.into_iter();
-							while let Some(Ok(elem)) = iter.next() {
-								self.name_section.insert(elem.index, elem.name);
-							}
-						}
+						self.name_section.load_name(name?);
 					}
 				}
+				Payload::CustomSection(v) => {
+					self.custom_section_map.insert(v.name(), v.data());
+				}
 				_ => {}
 			}
 		}
@@ -157,6 +373,11 @@ impl<'a> Module<'a> {
 		self.import_count(External::Global) + self.global_section.len()
 	}
 
+	#[must_use]
+	pub fn tag_space(&self) -> usize {
+		self.import_count(External::Tag) + self.tag_section.len()
+	}
+
 	#[must_use]
 	pub fn type_section(&self) -> &[Type] {
 		&self.type_section
@@ -187,6 +408,11 @@ impl<'a> Module<'a> {
 		&self.global_section
 	}
 
+	#[must_use]
+	pub fn tag_section(&self) -> &[TagType] {
+		&self.tag_section
+	}
+
 	#[must_use]
 	pub fn export_section(&self) -> &[Export] {
 		&self.export_section
@@ -208,7 +434,7 @@ impl<'a> Module<'a> {
 	}
 
 	#[must_use]
-	pub const fn name_section(&self) -> &HashMap<u32, &'a str> {
+	pub const fn name_section(&self) -> &NameSection<'a> {
 		&self.name_section
 	}
 
@@ -216,11 +442,28 @@ impl<'a> Module<'a> {
 	pub const fn start_section(&self) -> Option<u32> {
 		self.start_section
 	}
+
+	#[must_use]
+	pub fn custom_section(&self, name: &str) -> Option<&'a [u8]> {
+		self.custom_section_map.get(name).copied()
+	}
+
+	#[must_use]
+	pub const fn code_section_offset(&self) -> usize {
+		self.code_section_offset
+	}
+
+	#[must_use]
+	pub fn section_list(&self) -> &[(u8, &'a [u8])] {
+		&self.section_list
+	}
 }
 
 pub struct TypeInfo<'a> {
 	type_list: &'a [Type],
 	func_list: Vec<usize>,
+	tag_list: Vec<usize>,
+	memory_list: Vec<IndexType>,
 }
 
 impl<'a> TypeInfo<'a> {
@@ -229,10 +472,14 @@ impl<'a> TypeInfo<'a> {
 		let mut temp = Self {
 			type_list: &wasm.type_section,
 			func_list: Vec::new(),
+			tag_list: Vec::new(),
+			memory_list: Vec::new(),
 		};
 
 		temp.load_import_list(&wasm.import_section);
 		temp.load_func_list(&wasm.func_section);
+		temp.load_tag_list(&wasm.tag_section);
+		temp.load_memory_list(&wasm.memory_section);
 		temp
 	}
 
@@ -247,6 +494,24 @@ impl<'a> TypeInfo<'a> {
 			.map(|v| usize::try_from(v).unwrap());
 
 		self.func_list.extend(iter);
+
+		let iter = list
+			.iter()
+			.copied()
+			.filter_map(|v| match v.ty {
+				TypeRef::Tag(v) => Some(v.func_type_idx),
+				_ => None,
+			})
+			.map(|v| usize::try_from(v).unwrap());
+
+		self.tag_list.extend(iter);
+
+		let iter = list.iter().filter_map(|v| match v.ty {
+			TypeRef::Memory(v) => Some(IndexType::from(v)),
+			_ => None,
+		});
+
+		self.memory_list.extend(iter);
 	}
 
 	fn load_func_list(&mut self, list: &[u32]) {
@@ -255,6 +520,25 @@ impl<'a> TypeInfo<'a> {
 		self.func_list.extend(iter);
 	}
 
+	fn load_tag_list(&mut self, list: &[TagType]) {
+		let iter = list
+			.iter()
+			.map(|v| usize::try_from(v.func_type_idx).unwrap());
+
+		self.tag_list.extend(iter);
+	}
+
+	fn load_memory_list(&mut self, list: &[MemoryType]) {
+		let iter = list.iter().copied().map(IndexType::from);
+
+		self.memory_list.extend(iter);
+	}
+
+	#[must_use]
+	pub fn by_memory_index(&self, index: usize) -> IndexType {
+		self.memory_list[index]
+	}
+
 	pub(crate) fn by_type_index(&self, index: usize) -> (usize, usize) {
 		// let Type::Func(ty) = &self.type_list[index] else {
 		// 	unreachable!("type at func index must be a func type");
@@ -270,6 +554,23 @@ impl<'a> TypeInfo<'a> {
 		self.by_type_index(adjusted)
 	}
 
+	pub(crate) fn by_func_index_param_list(&self, index: usize) -> &'a [ValType] {
+		let adjusted = self.func_list[index];
+		let ty = self.type_list[adjusted]
+			.types()
+			.next()
+			.unwrap()
+			.unwrap_func();
+
+		ty.params()
+	}
+
+	pub(crate) fn by_tag_index(&self, index: usize) -> usize {
+		let adjusted = self.tag_list[index];
+
+		self.by_type_index(adjusted).0
+	}
+
 	pub(crate) fn by_block_type(&self, ty: BlockType) -> (usize, usize) {
 		match ty {
 			BlockType::Empty => (0, 0),
@@ -282,3 +583,73 @@ impl<'a> TypeInfo<'a> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use wasm_encoder::{
+		BlockType, CodeSection, Function, FunctionSection, Instruction, TagKind, TagSection,
+		TagType, TypeSection, ValType,
+	};
+
+	use super::Module;
+
+	fn encode_module(code: &[Instruction]) -> Vec<u8> {
+		let mut types = TypeSection::new();
+		let mut funcs = FunctionSection::new();
+		let mut tags = TagSection::new();
+		let mut bodies = CodeSection::new();
+		let mut body = Function::new([]);
+
+		types.function([ValType::I32], []);
+		types.function([], [ValType::I32]);
+		funcs.function(1);
+		tags.tag(TagType {
+			kind: TagKind::Exception,
+			func_type_idx: 0,
+		});
+
+		code.iter().for_each(|v| {
+			body.instruction(v);
+		});
+
+		bodies.function(&body);
+
+		let mut module = wasm_encoder::Module::new();
+
+		module
+			.section(&types)
+			.section(&funcs)
+			.section(&tags)
+			.section(&bodies);
+
+		module.finish()
+	}
+
+	#[test]
+	fn loads_legacy_try_catch() {
+		let data = encode_module(&[
+			Instruction::Try(BlockType::Result(ValType::I32)),
+			Instruction::I32Const(7),
+			Instruction::Throw(0),
+			Instruction::Catch(0),
+			Instruction::CatchAll,
+			Instruction::Try(BlockType::Empty),
+			Instruction::Rethrow(1),
+			Instruction::Delegate(0),
+			Instruction::I32Const(0),
+			Instruction::End,
+			Instruction::End,
+		]);
+
+		let module = Module::try_from_data(&data).unwrap();
+
+		assert_eq!(module.code_section().len(), 1);
+	}
+
+	#[test]
+	fn rejects_invalid_body() {
+		let data = encode_module(&[Instruction::I32Add, Instruction::End]);
+
+		assert!(Module::try_from_data(&data).is_err());
+	}
+}
@@ -1,7 +1,7 @@
-use wasmparser::{Operator, ValType};
+use wasmparser::{HeapType, MemoryType, Operator, ValType};
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum LoadType {
 	I32,
 	I64,
@@ -17,6 +17,13 @@ pub enum LoadType {
 	I64_U16,
 	I64_I32,
 	I64_U32,
+	V128,
+	V128_I8X8,
+	V128_U8X8,
+	V128_I16X4,
+	V128_U16X4,
+	V128_I32X2,
+	V128_U32X2,
 }
 
 impl TryFrom<&Operator<'_>> for LoadType {
@@ -38,6 +45,13 @@ impl TryFrom<&Operator<'_>> for LoadType {
 			Operator::I64Load16U { .. } => Self::I64_U16,
 			Operator::I64Load32S { .. } => Self::I64_I32,
 			Operator::I64Load32U { .. } => Self::I64_U32,
+			Operator::V128Load { .. } => Self::V128,
+			Operator::V128Load8x8S { .. } => Self::V128_I8X8,
+			Operator::V128Load8x8U { .. } => Self::V128_U8X8,
+			Operator::V128Load16x4S { .. } => Self::V128_I16X4,
+			Operator::V128Load16x4U { .. } => Self::V128_U16X4,
+			Operator::V128Load32x2S { .. } => Self::V128_I32X2,
+			Operator::V128Load32x2U { .. } => Self::V128_U32X2,
 			_ => return Err(()),
 		};
 
@@ -45,8 +59,30 @@ impl TryFrom<&Operator<'_>> for LoadType {
 	}
 }
 
+#[derive(Clone, Copy, Debug)]
+pub enum ExtractLaneType {
+	I8X16,
+	U8X16,
+	I16X8,
+	U16X8,
+	I32X4,
+	I64X2,
+	F32X4,
+	F64X2,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ReplaceLaneType {
+	I8X16,
+	I16X8,
+	I32X4,
+	I64X2,
+	F32X4,
+	F64X2,
+}
+
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum StoreType {
 	I32,
 	I64,
@@ -57,6 +93,7 @@ pub enum StoreType {
 	I64_N8,
 	I64_N16,
 	I64_N32,
+	V128,
 }
 
 impl TryFrom<&Operator<'_>> for StoreType {
@@ -73,6 +110,7 @@ impl TryFrom<&Operator<'_>> for StoreType {
 			Operator::I64Store8 { .. } => Self::I64_N8,
 			Operator::I64Store16 { .. } => Self::I64_N16,
 			Operator::I64Store32 { .. } => Self::I64_N32,
+			Operator::V128Store { .. } => Self::V128,
 			_ => return Err(()),
 		};
 
@@ -80,10 +118,27 @@ impl TryFrom<&Operator<'_>> for StoreType {
 	}
 }
 
+// Memories are indexed by either 32 or 64 bit addresses
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IndexType {
+	I32,
+	I64,
+}
+
+impl From<MemoryType> for IndexType {
+	fn from(value: MemoryType) -> Self {
+		if value.memory64 {
+			Self::I64
+		} else {
+			Self::I32
+		}
+	}
+}
+
 // Order of mnemonics is:
 // operation_result_parameter
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum UnOpType {
 	Clz_I32,
 	Ctz_I32,
@@ -143,11 +198,77 @@ pub enum UnOpType {
 	Reinterpret_I64_F64,
 	Reinterpret_F32_I32,
 	Reinterpret_F64_I64,
+	Not_V128,
+	AnyTrue_V128,
+	Splat_I8X16,
+	Splat_I16X8,
+	Splat_I32X4,
+	Splat_I64X2,
+	Splat_F32X4,
+	Splat_F64X2,
+	Abs_I8X16,
+	Neg_I8X16,
+	Abs_I16X8,
+	Neg_I16X8,
+	Abs_I32X4,
+	Neg_I32X4,
+	Abs_I64X2,
+	Neg_I64X2,
+	Abs_F32X4,
+	Neg_F32X4,
+	Abs_F64X2,
+	Neg_F64X2,
+	Popcnt_I8X16,
+	AllTrue_I8X16,
+	Bitmask_I8X16,
+	AllTrue_I16X8,
+	Bitmask_I16X8,
+	AllTrue_I32X4,
+	Bitmask_I32X4,
+	AllTrue_I64X2,
+	Bitmask_I64X2,
+	Ceil_F32X4,
+	Floor_F32X4,
+	Truncate_F32X4,
+	Nearest_F32X4,
+	Sqrt_F32X4,
+	Ceil_F64X2,
+	Floor_F64X2,
+	Truncate_F64X2,
+	Nearest_F64X2,
+	Sqrt_F64X2,
+	ExtAddPairwise_I16X8_I8X16,
+	ExtAddPairwise_I16X8_U8X16,
+	ExtAddPairwise_I32X4_I16X8,
+	ExtAddPairwise_I32X4_U16X8,
+	ExtendLow_I16X8_I8X16,
+	ExtendLow_I16X8_U8X16,
+	ExtendHigh_I16X8_I8X16,
+	ExtendHigh_I16X8_U8X16,
+	ExtendLow_I32X4_I16X8,
+	ExtendLow_I32X4_U16X8,
+	ExtendHigh_I32X4_I16X8,
+	ExtendHigh_I32X4_U16X8,
+	ExtendLow_I64X2_I32X4,
+	ExtendLow_I64X2_U32X4,
+	ExtendHigh_I64X2_I32X4,
+	ExtendHigh_I64X2_U32X4,
+	Saturate_I32X4_F32X4,
+	Saturate_U32X4_F32X4,
+	SaturateZero_I32X4_F64X2,
+	SaturateZero_U32X4_F64X2,
+	Convert_F32X4_I32X4,
+	Convert_F32X4_U32X4,
+	ConvertLow_F64X2_I32X4,
+	ConvertLow_F64X2_U32X4,
+	DemoteZero_F32X4_F64X2,
+	PromoteLow_F64X2_F32X4,
 }
 
 impl TryFrom<&Operator<'_>> for UnOpType {
 	type Error = ();
 
+	#[allow(clippy::too_many_lines)]
 	fn try_from(inst: &Operator) -> Result<Self, Self::Error> {
 		let result = match inst {
 			Operator::I32Clz => Self::Clz_I32,
@@ -208,6 +329,71 @@ impl TryFrom<&Operator<'_>> for UnOpType {
 			Operator::I64ReinterpretF64 => Self::Reinterpret_I64_F64,
 			Operator::F32ReinterpretI32 => Self::Reinterpret_F32_I32,
 			Operator::F64ReinterpretI64 => Self::Reinterpret_F64_I64,
+			Operator::V128Not => Self::Not_V128,
+			Operator::V128AnyTrue => Self::AnyTrue_V128,
+			Operator::I8x16Splat => Self::Splat_I8X16,
+			Operator::I16x8Splat => Self::Splat_I16X8,
+			Operator::I32x4Splat => Self::Splat_I32X4,
+			Operator::I64x2Splat => Self::Splat_I64X2,
+			Operator::F32x4Splat => Self::Splat_F32X4,
+			Operator::F64x2Splat => Self::Splat_F64X2,
+			Operator::I8x16Abs => Self::Abs_I8X16,
+			Operator::I8x16Neg => Self::Neg_I8X16,
+			Operator::I16x8Abs => Self::Abs_I16X8,
+			Operator::I16x8Neg => Self::Neg_I16X8,
+			Operator::I32x4Abs => Self::Abs_I32X4,
+			Operator::I32x4Neg => Self::Neg_I32X4,
+			Operator::I64x2Abs => Self::Abs_I64X2,
+			Operator::I64x2Neg => Self::Neg_I64X2,
+			Operator::F32x4Abs => Self::Abs_F32X4,
+			Operator::F32x4Neg => Self::Neg_F32X4,
+			Operator::F64x2Abs => Self::Abs_F64X2,
+			Operator::F64x2Neg => Self::Neg_F64X2,
+			Operator::I8x16Popcnt => Self::Popcnt_I8X16,
+			Operator::I8x16AllTrue => Self::AllTrue_I8X16,
+			Operator::I8x16Bitmask => Self::Bitmask_I8X16,
+			Operator::I16x8AllTrue => Self::AllTrue_I16X8,
+			Operator::I16x8Bitmask => Self::Bitmask_I16X8,
+			Operator::I32x4AllTrue => Self::AllTrue_I32X4,
+			Operator::I32x4Bitmask => Self::Bitmask_I32X4,
+			Operator::I64x2AllTrue => Self::AllTrue_I64X2,
+			Operator::I64x2Bitmask => Self::Bitmask_I64X2,
+			Operator::F32x4Ceil => Self::Ceil_F32X4,
+			Operator::F32x4Floor => Self::Floor_F32X4,
+			Operator::F32x4Trunc => Self::Truncate_F32X4,
+			Operator::F32x4Nearest => Self::Nearest_F32X4,
+			Operator::F32x4Sqrt => Self::Sqrt_F32X4,
+			Operator::F64x2Ceil => Self::Ceil_F64X2,
+			Operator::F64x2Floor => Self::Floor_F64X2,
+			Operator::F64x2Trunc => Self::Truncate_F64X2,
+			Operator::F64x2Nearest => Self::Nearest_F64X2,
+			Operator::F64x2Sqrt => Self::Sqrt_F64X2,
+			Operator::I16x8ExtAddPairwiseI8x16S => Self::ExtAddPairwise_I16X8_I8X16,
+			Operator::I16x8ExtAddPairwiseI8x16U => Self::ExtAddPairwise_I16X8_U8X16,
+			Operator::I32x4ExtAddPairwiseI16x8S => Self::ExtAddPairwise_I32X4_I16X8,
+			Operator::I32x4ExtAddPairwiseI16x8U => Self::ExtAddPairwise_I32X4_U16X8,
+			Operator::I16x8ExtendLowI8x16S => Self::ExtendLow_I16X8_I8X16,
+			Operator::I16x8ExtendLowI8x16U => Self::ExtendLow_I16X8_U8X16,
+			Operator::I16x8ExtendHighI8x16S => Self::ExtendHigh_I16X8_I8X16,
+			Operator::I16x8ExtendHighI8x16U => Self::ExtendHigh_I16X8_U8X16,
+			Operator::I32x4ExtendLowI16x8S => Self::ExtendLow_I32X4_I16X8,
+			Operator::I32x4ExtendLowI16x8U => Self::ExtendLow_I32X4_U16X8,
+			Operator::I32x4ExtendHighI16x8S => Self::ExtendHigh_I32X4_I16X8,
+			Operator::I32x4ExtendHighI16x8U => Self::ExtendHigh_I32X4_U16X8,
+			Operator::I64x2ExtendLowI32x4S => Self::ExtendLow_I64X2_I32X4,
+			Operator::I64x2ExtendLowI32x4U => Self::ExtendLow_I64X2_U32X4,
+			Operator::I64x2ExtendHighI32x4S => Self::ExtendHigh_I64X2_I32X4,
+			Operator::I64x2ExtendHighI32x4U => Self::ExtendHigh_I64X2_U32X4,
+			Operator::I32x4TruncSatF32x4S => Self::Saturate_I32X4_F32X4,
+			Operator::I32x4TruncSatF32x4U => Self::Saturate_U32X4_F32X4,
+			Operator::I32x4TruncSatF64x2SZero => Self::SaturateZero_I32X4_F64X2,
+			Operator::I32x4TruncSatF64x2UZero => Self::SaturateZero_U32X4_F64X2,
+			Operator::F32x4ConvertI32x4S => Self::Convert_F32X4_I32X4,
+			Operator::F32x4ConvertI32x4U => Self::Convert_F32X4_U32X4,
+			Operator::F64x2ConvertLowI32x4S => Self::ConvertLow_F64X2_I32X4,
+			Operator::F64x2ConvertLowI32x4U => Self::ConvertLow_F64X2_U32X4,
+			Operator::F32x4DemoteF64x2Zero => Self::DemoteZero_F32X4_F64X2,
+			Operator::F64x2PromoteLowF32x4 => Self::PromoteLow_F64X2_F32X4,
 			_ => return Err(()),
 		};
 
@@ -216,7 +402,7 @@ impl TryFrom<&Operator<'_>> for UnOpType {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum BinOpType {
 	Add_I32,
 	Sub_I32,
@@ -262,11 +448,144 @@ pub enum BinOpType {
 	Min_F64,
 	Max_F64,
 	Copysign_F64,
+	And_V128,
+	AndNot_V128,
+	Or_V128,
+	Xor_V128,
+	Swizzle_I8X16,
+	Eq_I8X16,
+	Ne_I8X16,
+	LtS_I8X16,
+	LtU_I8X16,
+	GtS_I8X16,
+	GtU_I8X16,
+	LeS_I8X16,
+	LeU_I8X16,
+	GeS_I8X16,
+	GeU_I8X16,
+	Eq_I16X8,
+	Ne_I16X8,
+	LtS_I16X8,
+	LtU_I16X8,
+	GtS_I16X8,
+	GtU_I16X8,
+	LeS_I16X8,
+	LeU_I16X8,
+	GeS_I16X8,
+	GeU_I16X8,
+	Eq_I32X4,
+	Ne_I32X4,
+	LtS_I32X4,
+	LtU_I32X4,
+	GtS_I32X4,
+	GtU_I32X4,
+	LeS_I32X4,
+	LeU_I32X4,
+	GeS_I32X4,
+	GeU_I32X4,
+	Eq_I64X2,
+	Ne_I64X2,
+	LtS_I64X2,
+	GtS_I64X2,
+	LeS_I64X2,
+	GeS_I64X2,
+	Eq_F32X4,
+	Ne_F32X4,
+	Lt_F32X4,
+	Gt_F32X4,
+	Le_F32X4,
+	Ge_F32X4,
+	Eq_F64X2,
+	Ne_F64X2,
+	Lt_F64X2,
+	Gt_F64X2,
+	Le_F64X2,
+	Ge_F64X2,
+	Narrow_I8X16_I16X8,
+	Narrow_U8X16_I16X8,
+	Narrow_I16X8_I32X4,
+	Narrow_U16X8_I32X4,
+	Shl_I8X16,
+	ShrS_I8X16,
+	ShrU_I8X16,
+	Add_I8X16,
+	Sub_I8X16,
+	Shl_I16X8,
+	ShrS_I16X8,
+	ShrU_I16X8,
+	Add_I16X8,
+	Sub_I16X8,
+	Mul_I16X8,
+	Shl_I32X4,
+	ShrS_I32X4,
+	ShrU_I32X4,
+	Add_I32X4,
+	Sub_I32X4,
+	Mul_I32X4,
+	Shl_I64X2,
+	ShrS_I64X2,
+	ShrU_I64X2,
+	Add_I64X2,
+	Sub_I64X2,
+	Mul_I64X2,
+	AddSatS_I8X16,
+	AddSatU_I8X16,
+	SubSatS_I8X16,
+	SubSatU_I8X16,
+	AddSatS_I16X8,
+	AddSatU_I16X8,
+	SubSatS_I16X8,
+	SubSatU_I16X8,
+	MinS_I8X16,
+	MinU_I8X16,
+	MaxS_I8X16,
+	MaxU_I8X16,
+	MinS_I16X8,
+	MinU_I16X8,
+	MaxS_I16X8,
+	MaxU_I16X8,
+	MinS_I32X4,
+	MinU_I32X4,
+	MaxS_I32X4,
+	MaxU_I32X4,
+	AvgrU_I8X16,
+	AvgrU_I16X8,
+	Q15MulrSatS_I16X8,
+	ExtMulLow_I16X8_I8X16,
+	ExtMulLow_I16X8_U8X16,
+	ExtMulHigh_I16X8_I8X16,
+	ExtMulHigh_I16X8_U8X16,
+	ExtMulLow_I32X4_I16X8,
+	ExtMulLow_I32X4_U16X8,
+	ExtMulHigh_I32X4_I16X8,
+	ExtMulHigh_I32X4_U16X8,
+	ExtMulLow_I64X2_I32X4,
+	ExtMulLow_I64X2_U32X4,
+	ExtMulHigh_I64X2_I32X4,
+	ExtMulHigh_I64X2_U32X4,
+	Dot_I32X4_I16X8,
+	Add_F32X4,
+	Sub_F32X4,
+	Mul_F32X4,
+	Div_F32X4,
+	Min_F32X4,
+	Max_F32X4,
+	PMin_F32X4,
+	PMax_F32X4,
+	Add_F64X2,
+	Sub_F64X2,
+	Mul_F64X2,
+	Div_F64X2,
+	Min_F64X2,
+	Max_F64X2,
+	PMin_F64X2,
+	PMax_F64X2,
 }
 
 impl TryFrom<&Operator<'_>> for BinOpType {
 	type Error = ();
 
+	#[allow(clippy::too_many_lines)]
 	fn try_from(inst: &Operator) -> Result<Self, Self::Error> {
 		let result = match inst {
 			Operator::I32Add => Self::Add_I32,
@@ -313,6 +632,138 @@ impl TryFrom<&Operator<'_>> for BinOpType {
 			Operator::F64Min => Self::Min_F64,
 			Operator::F64Max => Self::Max_F64,
 			Operator::F64Copysign => Self::Copysign_F64,
+			Operator::V128And => Self::And_V128,
+			Operator::V128AndNot => Self::AndNot_V128,
+			Operator::V128Or => Self::Or_V128,
+			Operator::V128Xor => Self::Xor_V128,
+			Operator::I8x16Swizzle => Self::Swizzle_I8X16,
+			Operator::I8x16Eq => Self::Eq_I8X16,
+			Operator::I8x16Ne => Self::Ne_I8X16,
+			Operator::I8x16LtS => Self::LtS_I8X16,
+			Operator::I8x16LtU => Self::LtU_I8X16,
+			Operator::I8x16GtS => Self::GtS_I8X16,
+			Operator::I8x16GtU => Self::GtU_I8X16,
+			Operator::I8x16LeS => Self::LeS_I8X16,
+			Operator::I8x16LeU => Self::LeU_I8X16,
+			Operator::I8x16GeS => Self::GeS_I8X16,
+			Operator::I8x16GeU => Self::GeU_I8X16,
+			Operator::I16x8Eq => Self::Eq_I16X8,
+			Operator::I16x8Ne => Self::Ne_I16X8,
+			Operator::I16x8LtS => Self::LtS_I16X8,
+			Operator::I16x8LtU => Self::LtU_I16X8,
+			Operator::I16x8GtS => Self::GtS_I16X8,
+			Operator::I16x8GtU => Self::GtU_I16X8,
+			Operator::I16x8LeS => Self::LeS_I16X8,
+			Operator::I16x8LeU => Self::LeU_I16X8,
+			Operator::I16x8GeS => Self::GeS_I16X8,
+			Operator::I16x8GeU => Self::GeU_I16X8,
+			Operator::I32x4Eq => Self::Eq_I32X4,
+			Operator::I32x4Ne => Self::Ne_I32X4,
+			Operator::I32x4LtS => Self::LtS_I32X4,
+			Operator::I32x4LtU => Self::LtU_I32X4,
+			Operator::I32x4GtS => Self::GtS_I32X4,
+			Operator::I32x4GtU => Self::GtU_I32X4,
+			Operator::I32x4LeS => Self::LeS_I32X4,
+			Operator::I32x4LeU => Self::LeU_I32X4,
+			Operator::I32x4GeS => Self::GeS_I32X4,
+			Operator::I32x4GeU => Self::GeU_I32X4,
+			Operator::I64x2Eq => Self::Eq_I64X2,
+			Operator::I64x2Ne => Self::Ne_I64X2,
+			Operator::I64x2LtS => Self::LtS_I64X2,
+			Operator::I64x2GtS => Self::GtS_I64X2,
+			Operator::I64x2LeS => Self::LeS_I64X2,
+			Operator::I64x2GeS => Self::GeS_I64X2,
+			Operator::F32x4Eq => Self::Eq_F32X4,
+			Operator::F32x4Ne => Self::Ne_F32X4,
+			Operator::F32x4Lt => Self::Lt_F32X4,
+			Operator::F32x4Gt => Self::Gt_F32X4,
+			Operator::F32x4Le => Self::Le_F32X4,
+			Operator::F32x4Ge => Self::Ge_F32X4,
+			Operator::F64x2Eq => Self::Eq_F64X2,
+			Operator::F64x2Ne => Self::Ne_F64X2,
+			Operator::F64x2Lt => Self::Lt_F64X2,
+			Operator::F64x2Gt => Self::Gt_F64X2,
+			Operator::F64x2Le => Self::Le_F64X2,
+			Operator::F64x2Ge => Self::Ge_F64X2,
+			Operator::I8x16NarrowI16x8S => Self::Narrow_I8X16_I16X8,
+			Operator::I8x16NarrowI16x8U => Self::Narrow_U8X16_I16X8,
+			Operator::I16x8NarrowI32x4S => Self::Narrow_I16X8_I32X4,
+			Operator::I16x8NarrowI32x4U => Self::Narrow_U16X8_I32X4,
+			Operator::I8x16Shl => Self::Shl_I8X16,
+			Operator::I8x16ShrS => Self::ShrS_I8X16,
+			Operator::I8x16ShrU => Self::ShrU_I8X16,
+			Operator::I8x16Add => Self::Add_I8X16,
+			Operator::I8x16Sub => Self::Sub_I8X16,
+			Operator::I16x8Shl => Self::Shl_I16X8,
+			Operator::I16x8ShrS => Self::ShrS_I16X8,
+			Operator::I16x8ShrU => Self::ShrU_I16X8,
+			Operator::I16x8Add => Self::Add_I16X8,
+			Operator::I16x8Sub => Self::Sub_I16X8,
+			Operator::I16x8Mul => Self::Mul_I16X8,
+			Operator::I32x4Shl => Self::Shl_I32X4,
+			Operator::I32x4ShrS => Self::ShrS_I32X4,
+			Operator::I32x4ShrU => Self::ShrU_I32X4,
+			Operator::I32x4Add => Self::Add_I32X4,
+			Operator::I32x4Sub => Self::Sub_I32X4,
+			Operator::I32x4Mul => Self::Mul_I32X4,
+			Operator::I64x2Shl => Self::Shl_I64X2,
+			Operator::I64x2ShrS => Self::ShrS_I64X2,
+			Operator::I64x2ShrU => Self::ShrU_I64X2,
+			Operator::I64x2Add => Self::Add_I64X2,
+			Operator::I64x2Sub => Self::Sub_I64X2,
+			Operator::I64x2Mul => Self::Mul_I64X2,
+			Operator::I8x16AddSatS => Self::AddSatS_I8X16,
+			Operator::I8x16AddSatU => Self::AddSatU_I8X16,
+			Operator::I8x16SubSatS => Self::SubSatS_I8X16,
+			Operator::I8x16SubSatU => Self::SubSatU_I8X16,
+			Operator::I16x8AddSatS => Self::AddSatS_I16X8,
+			Operator::I16x8AddSatU => Self::AddSatU_I16X8,
+			Operator::I16x8SubSatS => Self::SubSatS_I16X8,
+			Operator::I16x8SubSatU => Self::SubSatU_I16X8,
+			Operator::I8x16MinS => Self::MinS_I8X16,
+			Operator::I8x16MinU => Self::MinU_I8X16,
+			Operator::I8x16MaxS => Self::MaxS_I8X16,
+			Operator::I8x16MaxU => Self::MaxU_I8X16,
+			Operator::I16x8MinS => Self::MinS_I16X8,
+			Operator::I16x8MinU => Self::MinU_I16X8,
+			Operator::I16x8MaxS => Self::MaxS_I16X8,
+			Operator::I16x8MaxU => Self::MaxU_I16X8,
+			Operator::I32x4MinS => Self::MinS_I32X4,
+			Operator::I32x4MinU => Self::MinU_I32X4,
+			Operator::I32x4MaxS => Self::MaxS_I32X4,
+			Operator::I32x4MaxU => Self::MaxU_I32X4,
+			Operator::I8x16AvgrU => Self::AvgrU_I8X16,
+			Operator::I16x8AvgrU => Self::AvgrU_I16X8,
+			Operator::I16x8Q15MulrSatS => Self::Q15MulrSatS_I16X8,
+			Operator::I16x8ExtMulLowI8x16S => Self::ExtMulLow_I16X8_I8X16,
+			Operator::I16x8ExtMulLowI8x16U => Self::ExtMulLow_I16X8_U8X16,
+			Operator::I16x8ExtMulHighI8x16S => Self::ExtMulHigh_I16X8_I8X16,
+			Operator::I16x8ExtMulHighI8x16U => Self::ExtMulHigh_I16X8_U8X16,
+			Operator::I32x4ExtMulLowI16x8S => Self::ExtMulLow_I32X4_I16X8,
+			Operator::I32x4ExtMulLowI16x8U => Self::ExtMulLow_I32X4_U16X8,
+			Operator::I32x4ExtMulHighI16x8S => Self::ExtMulHigh_I32X4_I16X8,
+			Operator::I32x4ExtMulHighI16x8U => Self::ExtMulHigh_I32X4_U16X8,
+			Operator::I64x2ExtMulLowI32x4S => Self::ExtMulLow_I64X2_I32X4,
+			Operator::I64x2ExtMulLowI32x4U => Self::ExtMulLow_I64X2_U32X4,
+			Operator::I64x2ExtMulHighI32x4S => Self::ExtMulHigh_I64X2_I32X4,
+			Operator::I64x2ExtMulHighI32x4U => Self::ExtMulHigh_I64X2_U32X4,
+			Operator::I32x4DotI16x8S => Self::Dot_I32X4_I16X8,
+			Operator::F32x4Add => Self::Add_F32X4,
+			Operator::F32x4Sub => Self::Sub_F32X4,
+			Operator::F32x4Mul => Self::Mul_F32X4,
+			Operator::F32x4Div => Self::Div_F32X4,
+			Operator::F32x4Min => Self::Min_F32X4,
+			Operator::F32x4Max => Self::Max_F32X4,
+			Operator::F32x4PMin => Self::PMin_F32X4,
+			Operator::F32x4PMax => Self::PMax_F32X4,
+			Operator::F64x2Add => Self::Add_F64X2,
+			Operator::F64x2Sub => Self::Sub_F64X2,
+			Operator::F64x2Mul => Self::Mul_F64X2,
+			Operator::F64x2Div => Self::Div_F64X2,
+			Operator::F64x2Min => Self::Min_F64X2,
+			Operator::F64x2Max => Self::Max_F64X2,
+			Operator::F64x2PMin => Self::PMin_F64X2,
+			Operator::F64x2PMax => Self::PMax_F64X2,
 			_ => {
 				return Err(());
 			}
@@ -323,7 +774,7 @@ impl TryFrom<&Operator<'_>> for BinOpType {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum CmpOpType {
 	Eq_I32,
 	Ne_I32,
@@ -405,6 +856,7 @@ impl TryFrom<&Operator<'_>> for CmpOpType {
 	}
 }
 
+#[derive(Clone)]
 pub struct Select {
 	pub(crate) condition: Box<Expression>,
 	pub(crate) on_true: Box<Expression>,
@@ -412,6 +864,19 @@ pub struct Select {
 }
 
 impl Select {
+	#[must_use]
+	pub const fn new(
+		condition: Box<Expression>,
+		on_true: Box<Expression>,
+		on_false: Box<Expression>,
+	) -> Self {
+		Self {
+			condition,
+			on_true,
+			on_false,
+		}
+	}
+
 	#[must_use]
 	pub const fn condition(&self) -> &Expression {
 		&self.condition
@@ -426,6 +891,18 @@ impl Select {
 	pub const fn on_false(&self) -> &Expression {
 		&self.on_false
 	}
+
+	pub fn condition_mut(&mut self) -> &mut Expression {
+		&mut self.condition
+	}
+
+	pub fn on_true_mut(&mut self) -> &mut Expression {
+		&mut self.on_true
+	}
+
+	pub fn on_false_mut(&mut self) -> &mut Expression {
+		&mut self.on_false
+	}
 }
 
 #[derive(Clone, Copy)]
@@ -434,10 +911,19 @@ pub struct Temporary {
 }
 
 impl Temporary {
+	#[must_use]
+	pub const fn new(var: usize) -> Self {
+		Self { var }
+	}
+
 	#[must_use]
 	pub const fn var(self) -> usize {
 		self.var
 	}
+
+	pub fn var_mut(&mut self) -> &mut usize {
+		&mut self.var
+	}
 }
 
 #[derive(Clone, Copy)]
@@ -446,10 +932,19 @@ pub struct Local {
 }
 
 impl Local {
+	#[must_use]
+	pub const fn new(var: usize) -> Self {
+		Self { var }
+	}
+
 	#[must_use]
 	pub const fn var(self) -> usize {
 		self.var
 	}
+
+	pub fn var_mut(&mut self) -> &mut usize {
+		&mut self.var
+	}
 }
 
 #[derive(Clone, Copy)]
@@ -458,20 +953,50 @@ pub struct GetGlobal {
 }
 
 impl GetGlobal {
+	#[must_use]
+	pub const fn new(var: usize) -> Self {
+		Self { var }
+	}
+
 	#[must_use]
 	pub const fn var(self) -> usize {
 		self.var
 	}
+
+	pub fn var_mut(&mut self) -> &mut usize {
+		&mut self.var
+	}
 }
 
+#[derive(Clone)]
 pub struct LoadAt {
 	pub(crate) load_type: LoadType,
 	pub(crate) memory: usize,
-	pub(crate) offset: u32,
+	pub(crate) index_type: IndexType,
+	pub(crate) offset: u64,
 	pub(crate) pointer: Box<Expression>,
+	pub(crate) is_atomic: bool,
 }
 
 impl LoadAt {
+	#[must_use]
+	pub const fn new(
+		load_type: LoadType,
+		memory: usize,
+		index_type: IndexType,
+		offset: u64,
+		pointer: Box<Expression>,
+	) -> Self {
+		Self {
+			load_type,
+			memory,
+			index_type,
+			offset,
+			pointer,
+			is_atomic: false,
+		}
+	}
+
 	#[must_use]
 	pub const fn load_type(&self) -> LoadType {
 		self.load_type
@@ -483,7 +1008,12 @@ impl LoadAt {
 	}
 
 	#[must_use]
-	pub const fn offset(&self) -> u32 {
+	pub const fn index_type(&self) -> IndexType {
+		self.index_type
+	}
+
+	#[must_use]
+	pub const fn offset(&self) -> u64 {
 		self.offset
 	}
 
@@ -491,18 +1021,64 @@ impl LoadAt {
 	pub const fn pointer(&self) -> &Expression {
 		&self.pointer
 	}
+
+	/// Whether the load came from an atomic access, which traps when
+	/// the address is not aligned to its size.
+	#[must_use]
+	pub const fn is_atomic(&self) -> bool {
+		self.is_atomic
+	}
+
+	pub fn load_type_mut(&mut self) -> &mut LoadType {
+		&mut self.load_type
+	}
+
+	pub fn memory_mut(&mut self) -> &mut usize {
+		&mut self.memory
+	}
+
+	pub fn index_type_mut(&mut self) -> &mut IndexType {
+		&mut self.index_type
+	}
+
+	pub fn offset_mut(&mut self) -> &mut u64 {
+		&mut self.offset
+	}
+
+	pub fn pointer_mut(&mut self) -> &mut Expression {
+		&mut self.pointer
+	}
 }
 
 #[derive(Clone, Copy)]
 pub struct MemorySize {
 	pub(crate) memory: usize,
+	pub(crate) index_type: IndexType,
 }
 
 impl MemorySize {
+	#[must_use]
+	pub const fn new(memory: usize, index_type: IndexType) -> Self {
+		Self { memory, index_type }
+	}
+
 	#[must_use]
 	pub const fn memory(&self) -> usize {
 		self.memory
 	}
+
+	#[must_use]
+	pub const fn index_type(&self) -> IndexType {
+		self.index_type
+	}
+
+	pub fn memory_mut(&mut self) -> &mut usize {
+		&mut self.memory
+	}
+
+	pub fn index_type_mut(&mut self) -> &mut IndexType {
+		&mut self.index_type
+	}
 }
 
 #[derive(Clone, Copy)]
@@ -511,6 +1087,9 @@ pub enum Value {
 	I64(i64),
 	F32(f32),
 	F64(f64),
+	RefNull(HeapType),
+	RefFunc(usize),
+	V128(u128),
 }
 
 impl From<i32> for Value {
@@ -537,12 +1116,18 @@ impl From<u64> for Value {
 	}
 }
 
+#[derive(Clone)]
 pub struct UnOp {
 	pub(crate) op_type: UnOpType,
 	pub(crate) rhs: Box<Expression>,
 }
 
 impl UnOp {
+	#[must_use]
+	pub const fn new(op_type: UnOpType, rhs: Box<Expression>) -> Self {
+		Self { op_type, rhs }
+	}
+
 	#[must_use]
 	pub const fn op_type(&self) -> UnOpType {
 		self.op_type
@@ -552,8 +1137,17 @@ impl UnOp {
 	pub const fn rhs(&self) -> &Expression {
 		&self.rhs
 	}
+
+	pub fn op_type_mut(&mut self) -> &mut UnOpType {
+		&mut self.op_type
+	}
+
+	pub fn rhs_mut(&mut self) -> &mut Expression {
+		&mut self.rhs
+	}
 }
 
+#[derive(Clone)]
 pub struct BinOp {
 	pub(crate) op_type: BinOpType,
 	pub(crate) lhs: Box<Expression>,
@@ -561,6 +1155,11 @@ pub struct BinOp {
 }
 
 impl BinOp {
+	#[must_use]
+	pub const fn new(op_type: BinOpType, lhs: Box<Expression>, rhs: Box<Expression>) -> Self {
+		Self { op_type, lhs, rhs }
+	}
+
 	#[must_use]
 	pub const fn op_type(&self) -> BinOpType {
 		self.op_type
@@ -575,8 +1174,21 @@ impl BinOp {
 	pub const fn rhs(&self) -> &Expression {
 		&self.rhs
 	}
+
+	pub fn op_type_mut(&mut self) -> &mut BinOpType {
+		&mut self.op_type
+	}
+
+	pub fn lhs_mut(&mut self) -> &mut Expression {
+		&mut self.lhs
+	}
+
+	pub fn rhs_mut(&mut self) -> &mut Expression {
+		&mut self.rhs
+	}
 }
 
+#[derive(Clone)]
 pub struct CmpOp {
 	pub(crate) op_type: CmpOpType,
 	pub(crate) lhs: Box<Expression>,
@@ -584,6 +1196,11 @@ pub struct CmpOp {
 }
 
 impl CmpOp {
+	#[must_use]
+	pub const fn new(op_type: CmpOpType, lhs: Box<Expression>, rhs: Box<Expression>) -> Self {
+		Self { op_type, lhs, rhs }
+	}
+
 	#[must_use]
 	pub const fn op_type(&self) -> CmpOpType {
 		self.op_type
@@ -598,8 +1215,244 @@ impl CmpOp {
 	pub const fn rhs(&self) -> &Expression {
 		&self.rhs
 	}
+
+	pub fn op_type_mut(&mut self) -> &mut CmpOpType {
+		&mut self.op_type
+	}
+
+	pub fn lhs_mut(&mut self) -> &mut Expression {
+		&mut self.lhs
+	}
+
+	pub fn rhs_mut(&mut self) -> &mut Expression {
+		&mut self.rhs
+	}
+}
+
+#[derive(Clone)]
+pub struct ExtractLane {
+	pub(crate) lane_type: ExtractLaneType,
+	pub(crate) lane: u8,
+	pub(crate) vector: Box<Expression>,
+}
+
+impl ExtractLane {
+	#[must_use]
+	pub const fn new(lane_type: ExtractLaneType, lane: u8, vector: Box<Expression>) -> Self {
+		Self {
+			lane_type,
+			lane,
+			vector,
+		}
+	}
+
+	#[must_use]
+	pub const fn lane_type(&self) -> ExtractLaneType {
+		self.lane_type
+	}
+
+	#[must_use]
+	pub const fn lane(&self) -> u8 {
+		self.lane
+	}
+
+	#[must_use]
+	pub const fn vector(&self) -> &Expression {
+		&self.vector
+	}
+
+	pub fn lane_type_mut(&mut self) -> &mut ExtractLaneType {
+		&mut self.lane_type
+	}
+
+	pub fn lane_mut(&mut self) -> &mut u8 {
+		&mut self.lane
+	}
+
+	pub fn vector_mut(&mut self) -> &mut Expression {
+		&mut self.vector
+	}
+}
+
+#[derive(Clone)]
+pub struct ReplaceLane {
+	pub(crate) lane_type: ReplaceLaneType,
+	pub(crate) lane: u8,
+	pub(crate) vector: Box<Expression>,
+	pub(crate) value: Box<Expression>,
+}
+
+impl ReplaceLane {
+	#[must_use]
+	pub const fn new(
+		lane_type: ReplaceLaneType,
+		lane: u8,
+		vector: Box<Expression>,
+		value: Box<Expression>,
+	) -> Self {
+		Self {
+			lane_type,
+			lane,
+			vector,
+			value,
+		}
+	}
+
+	#[must_use]
+	pub const fn lane_type(&self) -> ReplaceLaneType {
+		self.lane_type
+	}
+
+	#[must_use]
+	pub const fn lane(&self) -> u8 {
+		self.lane
+	}
+
+	#[must_use]
+	pub const fn vector(&self) -> &Expression {
+		&self.vector
+	}
+
+	#[must_use]
+	pub const fn value(&self) -> &Expression {
+		&self.value
+	}
+
+	pub fn lane_type_mut(&mut self) -> &mut ReplaceLaneType {
+		&mut self.lane_type
+	}
+
+	pub fn lane_mut(&mut self) -> &mut u8 {
+		&mut self.lane
+	}
+
+	pub fn vector_mut(&mut self) -> &mut Expression {
+		&mut self.vector
+	}
+
+	pub fn value_mut(&mut self) -> &mut Expression {
+		&mut self.value
+	}
+}
+
+#[derive(Clone)]
+pub struct Shuffle {
+	pub(crate) lane_list: [u8; 16],
+	pub(crate) lhs: Box<Expression>,
+	pub(crate) rhs: Box<Expression>,
+}
+
+impl Shuffle {
+	#[must_use]
+	pub const fn new(lane_list: [u8; 16], lhs: Box<Expression>, rhs: Box<Expression>) -> Self {
+		Self {
+			lane_list,
+			lhs,
+			rhs,
+		}
+	}
+
+	#[must_use]
+	pub const fn lane_list(&self) -> [u8; 16] {
+		self.lane_list
+	}
+
+	#[must_use]
+	pub const fn lhs(&self) -> &Expression {
+		&self.lhs
+	}
+
+	#[must_use]
+	pub const fn rhs(&self) -> &Expression {
+		&self.rhs
+	}
+
+	pub fn lane_list_mut(&mut self) -> &mut [u8; 16] {
+		&mut self.lane_list
+	}
+
+	pub fn lhs_mut(&mut self) -> &mut Expression {
+		&mut self.lhs
+	}
+
+	pub fn rhs_mut(&mut self) -> &mut Expression {
+		&mut self.rhs
+	}
+}
+
+// Bits are taken from `on_true` where the `condition` bit is set
+// and from `on_false` where it is not
+#[derive(Clone)]
+pub struct BitSelect {
+	pub(crate) condition: Box<Expression>,
+	pub(crate) on_true: Box<Expression>,
+	pub(crate) on_false: Box<Expression>,
+}
+
+impl BitSelect {
+	#[must_use]
+	pub const fn new(
+		condition: Box<Expression>,
+		on_true: Box<Expression>,
+		on_false: Box<Expression>,
+	) -> Self {
+		Self {
+			condition,
+			on_true,
+			on_false,
+		}
+	}
+
+	#[must_use]
+	pub const fn condition(&self) -> &Expression {
+		&self.condition
+	}
+
+	#[must_use]
+	pub const fn on_true(&self) -> &Expression {
+		&self.on_true
+	}
+
+	#[must_use]
+	pub const fn on_false(&self) -> &Expression {
+		&self.on_false
+	}
+
+	pub fn condition_mut(&mut self) -> &mut Expression {
+		&mut self.condition
+	}
+
+	pub fn on_true_mut(&mut self) -> &mut Expression {
+		&mut self.on_true
+	}
+
+	pub fn on_false_mut(&mut self) -> &mut Expression {
+		&mut self.on_false
+	}
+}
+
+#[derive(Clone)]
+pub struct RefIsNull {
+	pub(crate) value: Box<Expression>,
+}
+
+impl RefIsNull {
+	#[must_use]
+	pub const fn new(value: Box<Expression>) -> Self {
+		Self { value }
+	}
+
+	#[must_use]
+	pub const fn value(&self) -> &Expression {
+		&self.value
+	}
+
+	pub fn value_mut(&mut self) -> &mut Expression {
+		&mut self.value
+	}
 }
 
+#[derive(Clone)]
 pub enum Expression {
 	Select(Select),
 	GetTemporary(Temporary),
@@ -611,12 +1464,17 @@ pub enum Expression {
 	UnOp(UnOp),
 	BinOp(BinOp),
 	CmpOp(CmpOp),
+	RefIsNull(RefIsNull),
+	ExtractLane(ExtractLane),
+	ReplaceLane(ReplaceLane),
+	Shuffle(Shuffle),
+	BitSelect(BitSelect),
 }
 
 #[derive(Clone, Copy)]
 pub struct ResultList {
-	start: usize,
-	end: usize,
+	pub(crate) start: usize,
+	pub(crate) end: usize,
 }
 
 impl ResultList {
@@ -643,6 +1501,11 @@ pub struct Align {
 }
 
 impl Align {
+	#[must_use]
+	pub const fn new(new: usize, old: usize, length: usize) -> Self {
+		Self { new, old, length }
+	}
+
 	#[must_use]
 	pub const fn is_aligned(self) -> bool {
 		self.length == 0 || self.new == self.old
@@ -659,13 +1522,44 @@ impl Align {
 	}
 }
 
+#[derive(Clone, Copy, Default)]
+pub struct Unreachable {
+	pub(crate) code_offset: usize,
+}
+
+impl Unreachable {
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { code_offset: 0 }
+	}
+
+	#[must_use]
+	pub const fn code_offset(self) -> usize {
+		self.code_offset
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
 #[derive(Clone, Copy)]
 pub struct Br {
 	pub(crate) target: usize,
 	pub(crate) align: Align,
+	pub(crate) code_offset: usize,
 }
 
 impl Br {
+	#[must_use]
+	pub const fn new(target: usize, align: Align) -> Self {
+		Self {
+			target,
+			align,
+			code_offset: 0,
+		}
+	}
+
 	#[must_use]
 	pub const fn target(self) -> usize {
 		self.target
@@ -675,15 +1569,44 @@ impl Br {
 	pub const fn align(self) -> Align {
 		self.align
 	}
+
+	#[must_use]
+	pub const fn code_offset(self) -> usize {
+		self.code_offset
+	}
+
+	pub fn target_mut(&mut self) -> &mut usize {
+		&mut self.target
+	}
+
+	pub fn align_mut(&mut self) -> &mut Align {
+		&mut self.align
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
 }
 
+#[derive(Clone)]
 pub struct BrTable {
 	pub(crate) condition: Box<Expression>,
 	pub(crate) data: Vec<Br>,
 	pub(crate) default: Br,
+	pub(crate) code_offset: usize,
 }
 
 impl BrTable {
+	#[must_use]
+	pub const fn new(condition: Box<Expression>, data: Vec<Br>, default: Br) -> Self {
+		Self {
+			condition,
+			data,
+			default,
+			code_offset: 0,
+		}
+	}
+
 	#[must_use]
 	pub const fn condition(&self) -> &Expression {
 		&self.condition
@@ -698,28 +1621,282 @@ impl BrTable {
 	pub const fn default(&self) -> Br {
 		self.default
 	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn condition_mut(&mut self) -> &mut Expression {
+		&mut self.condition
+	}
+
+	pub fn data_mut(&mut self) -> &mut Vec<Br> {
+		&mut self.data
+	}
+
+	pub fn default_mut(&mut self) -> &mut Br {
+		&mut self.default
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
 }
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum LabelType {
 	Forward,
 	Backward,
 }
 
-pub enum Terminator {
-	Unreachable,
+#[derive(Clone)]
+pub struct ReturnCall {
+	pub(crate) function: usize,
+	pub(crate) param_list: Vec<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl ReturnCall {
+	#[must_use]
+	pub const fn new(function: usize, param_list: Vec<Expression>) -> Self {
+		Self {
+			function,
+			param_list,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn function(&self) -> usize {
+		self.function
+	}
+
+	#[must_use]
+	pub fn param_list(&self) -> &[Expression] {
+		&self.param_list
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn function_mut(&mut self) -> &mut usize {
+		&mut self.function
+	}
+
+	pub fn param_list_mut(&mut self) -> &mut Vec<Expression> {
+		&mut self.param_list
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct ReturnCallIndirect {
+	pub(crate) ty: usize,
+	pub(crate) table: usize,
+	pub(crate) index: Box<Expression>,
+	pub(crate) param_list: Vec<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl ReturnCallIndirect {
+	#[must_use]
+	pub const fn new(
+		ty: usize,
+		table: usize,
+		index: Box<Expression>,
+		param_list: Vec<Expression>,
+	) -> Self {
+		Self {
+			ty,
+			table,
+			index,
+			param_list,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn ty(&self) -> usize {
+		self.ty
+	}
+
+	#[must_use]
+	pub const fn table(&self) -> usize {
+		self.table
+	}
+
+	#[must_use]
+	pub const fn index(&self) -> &Expression {
+		&self.index
+	}
+
+	#[must_use]
+	pub fn param_list(&self) -> &[Expression] {
+		&self.param_list
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn ty_mut(&mut self) -> &mut usize {
+		&mut self.ty
+	}
+
+	pub fn table_mut(&mut self) -> &mut usize {
+		&mut self.table
+	}
+
+	pub fn index_mut(&mut self) -> &mut Expression {
+		&mut self.index
+	}
+
+	pub fn param_list_mut(&mut self) -> &mut Vec<Expression> {
+		&mut self.param_list
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct Throw {
+	pub(crate) tag: usize,
+	pub(crate) param_list: Vec<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl Throw {
+	#[must_use]
+	pub const fn new(tag: usize, param_list: Vec<Expression>) -> Self {
+		Self {
+			tag,
+			param_list,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn tag(&self) -> usize {
+		self.tag
+	}
+
+	#[must_use]
+	pub fn param_list(&self) -> &[Expression] {
+		&self.param_list
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn tag_mut(&mut self) -> &mut usize {
+		&mut self.tag
+	}
+
+	pub fn param_list_mut(&mut self) -> &mut Vec<Expression> {
+		&mut self.param_list
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone, Copy)]
+pub struct Rethrow {
+	pub(crate) target: usize,
+	pub(crate) code_offset: usize,
+}
+
+impl Rethrow {
+	#[must_use]
+	pub const fn new(target: usize) -> Self {
+		Self {
+			target,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn target(self) -> usize {
+		self.target
+	}
+
+	#[must_use]
+	pub const fn code_offset(self) -> usize {
+		self.code_offset
+	}
+
+	pub fn target_mut(&mut self) -> &mut usize {
+		&mut self.target
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub enum Terminator {
+	Unreachable(Unreachable),
 	Br(Br),
 	BrTable(BrTable),
+	ReturnCall(ReturnCall),
+	ReturnCallIndirect(ReturnCallIndirect),
+	Throw(Throw),
+	Rethrow(Rethrow),
 }
 
-#[derive(Default)]
+impl Terminator {
+	/// Offset of the operator ending the block, with the same meaning as
+	/// [`Statement::code_offset`].
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		match self {
+			Self::Unreachable(v) => v.code_offset(),
+			Self::Br(v) => v.code_offset(),
+			Self::BrTable(v) => v.code_offset(),
+			Self::ReturnCall(v) => v.code_offset(),
+			Self::ReturnCallIndirect(v) => v.code_offset(),
+			Self::Throw(v) => v.code_offset(),
+			Self::Rethrow(v) => v.code_offset(),
+		}
+	}
+}
+
+#[derive(Clone, Default)]
 pub struct Block {
 	pub(crate) label_type: Option<LabelType>,
 	pub(crate) code: Vec<Statement>,
 	pub(crate) last: Option<Box<Terminator>>,
+	pub(crate) code_offset: usize,
 }
 
 impl Block {
+	#[must_use]
+	pub const fn new(
+		label_type: Option<LabelType>,
+		code: Vec<Statement>,
+		last: Option<Box<Terminator>>,
+	) -> Self {
+		Self {
+			label_type,
+			code,
+			last,
+			code_offset: 0,
+		}
+	}
+
 	#[must_use]
 	pub const fn label_type(&self) -> Option<LabelType> {
 		self.label_type
@@ -730,83 +1907,1163 @@ impl Block {
 		&self.code
 	}
 
-	#[must_use]
-	pub fn last(&self) -> Option<&Terminator> {
-		self.last.as_deref()
+	#[must_use]
+	pub fn last(&self) -> Option<&Terminator> {
+		self.last.as_deref()
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn label_type_mut(&mut self) -> &mut Option<LabelType> {
+		&mut self.label_type
+	}
+
+	pub fn code_mut(&mut self) -> &mut Vec<Statement> {
+		&mut self.code
+	}
+
+	pub fn last_mut(&mut self) -> &mut Option<Box<Terminator>> {
+		&mut self.last
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct BrIf {
+	pub(crate) condition: Box<Expression>,
+	pub(crate) target: Br,
+	pub(crate) code_offset: usize,
+}
+
+impl BrIf {
+	#[must_use]
+	pub const fn new(condition: Box<Expression>, target: Br) -> Self {
+		Self {
+			condition,
+			target,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn condition(&self) -> &Expression {
+		&self.condition
+	}
+
+	#[must_use]
+	pub const fn target(&self) -> Br {
+		self.target
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn condition_mut(&mut self) -> &mut Expression {
+		&mut self.condition
+	}
+
+	pub fn target_mut(&mut self) -> &mut Br {
+		&mut self.target
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct If {
+	pub(crate) condition: Box<Expression>,
+	pub(crate) on_true: Box<Block>,
+	pub(crate) on_false: Option<Box<Block>>,
+	pub(crate) code_offset: usize,
+}
+
+impl If {
+	#[must_use]
+	pub const fn new(
+		condition: Box<Expression>,
+		on_true: Box<Block>,
+		on_false: Option<Box<Block>>,
+	) -> Self {
+		Self {
+			condition,
+			on_true,
+			on_false,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn condition(&self) -> &Expression {
+		&self.condition
+	}
+
+	#[must_use]
+	pub const fn on_true(&self) -> &Block {
+		&self.on_true
+	}
+
+	#[must_use]
+	pub fn on_false(&self) -> Option<&Block> {
+		self.on_false.as_deref()
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn condition_mut(&mut self) -> &mut Expression {
+		&mut self.condition
+	}
+
+	pub fn on_true_mut(&mut self) -> &mut Block {
+		&mut self.on_true
+	}
+
+	pub fn on_false_mut(&mut self) -> &mut Option<Box<Block>> {
+		&mut self.on_false
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct Catch {
+	pub(crate) tag: usize,
+	pub(crate) payload: ResultList,
+	pub(crate) block: Block,
+}
+
+impl Catch {
+	#[must_use]
+	pub const fn new(tag: usize, payload: ResultList, block: Block) -> Self {
+		Self {
+			tag,
+			payload,
+			block,
+		}
+	}
+
+	#[must_use]
+	pub const fn tag(&self) -> usize {
+		self.tag
+	}
+
+	#[must_use]
+	pub const fn payload(&self) -> ResultList {
+		self.payload
+	}
+
+	#[must_use]
+	pub const fn block(&self) -> &Block {
+		&self.block
+	}
+
+	pub fn tag_mut(&mut self) -> &mut usize {
+		&mut self.tag
+	}
+
+	pub fn payload_mut(&mut self) -> &mut ResultList {
+		&mut self.payload
+	}
+
+	pub fn block_mut(&mut self) -> &mut Block {
+		&mut self.block
+	}
+}
+
+#[derive(Clone)]
+pub struct Try {
+	pub(crate) body: Box<Block>,
+	pub(crate) catch_list: Vec<Catch>,
+	pub(crate) catch_all: Option<Box<Block>>,
+	pub(crate) delegate: Option<usize>,
+	pub(crate) code_offset: usize,
+}
+
+impl Try {
+	#[must_use]
+	pub const fn new(
+		body: Box<Block>,
+		catch_list: Vec<Catch>,
+		catch_all: Option<Box<Block>>,
+	) -> Self {
+		Self {
+			body,
+			catch_list,
+			catch_all,
+			delegate: None,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn body(&self) -> &Block {
+		&self.body
+	}
+
+	#[must_use]
+	pub fn catch_list(&self) -> &[Catch] {
+		&self.catch_list
+	}
+
+	#[must_use]
+	pub fn catch_all(&self) -> Option<&Block> {
+		self.catch_all.as_deref()
+	}
+
+	/// The label, relative to the outside of the `try`, whose handlers
+	/// receive any exception thrown in the body.
+	#[must_use]
+	pub const fn delegate(&self) -> Option<usize> {
+		self.delegate
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn body_mut(&mut self) -> &mut Block {
+		&mut self.body
+	}
+
+	pub fn catch_list_mut(&mut self) -> &mut Vec<Catch> {
+		&mut self.catch_list
+	}
+
+	pub fn catch_all_mut(&mut self) -> &mut Option<Box<Block>> {
+		&mut self.catch_all
+	}
+
+	pub fn delegate_mut(&mut self) -> &mut Option<usize> {
+		&mut self.delegate
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct Call {
+	pub(crate) function: usize,
+	pub(crate) param_list: Vec<Expression>,
+	pub(crate) result_list: ResultList,
+	pub(crate) code_offset: usize,
+}
+
+impl Call {
+	#[must_use]
+	pub const fn new(
+		function: usize,
+		param_list: Vec<Expression>,
+		result_list: ResultList,
+	) -> Self {
+		Self {
+			function,
+			param_list,
+			result_list,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn function(&self) -> usize {
+		self.function
+	}
+
+	#[must_use]
+	pub fn param_list(&self) -> &[Expression] {
+		&self.param_list
+	}
+
+	#[must_use]
+	pub const fn result_list(&self) -> ResultList {
+		self.result_list
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn function_mut(&mut self) -> &mut usize {
+		&mut self.function
+	}
+
+	pub fn param_list_mut(&mut self) -> &mut Vec<Expression> {
+		&mut self.param_list
+	}
+
+	pub fn result_list_mut(&mut self) -> &mut ResultList {
+		&mut self.result_list
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct CallIndirect {
+	pub(crate) ty: usize,
+	pub(crate) table: usize,
+	pub(crate) index: Box<Expression>,
+	pub(crate) param_list: Vec<Expression>,
+	pub(crate) result_list: ResultList,
+	pub(crate) code_offset: usize,
+}
+
+impl CallIndirect {
+	#[must_use]
+	pub const fn new(
+		ty: usize,
+		table: usize,
+		index: Box<Expression>,
+		param_list: Vec<Expression>,
+		result_list: ResultList,
+	) -> Self {
+		Self {
+			ty,
+			table,
+			index,
+			param_list,
+			result_list,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn ty(&self) -> usize {
+		self.ty
+	}
+
+	#[must_use]
+	pub const fn table(&self) -> usize {
+		self.table
+	}
+
+	#[must_use]
+	pub const fn index(&self) -> &Expression {
+		&self.index
+	}
+
+	#[must_use]
+	pub fn param_list(&self) -> &[Expression] {
+		&self.param_list
+	}
+
+	#[must_use]
+	pub const fn result_list(&self) -> ResultList {
+		self.result_list
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn ty_mut(&mut self) -> &mut usize {
+		&mut self.ty
+	}
+
+	pub fn table_mut(&mut self) -> &mut usize {
+		&mut self.table
+	}
+
+	pub fn index_mut(&mut self) -> &mut Expression {
+		&mut self.index
+	}
+
+	pub fn param_list_mut(&mut self) -> &mut Vec<Expression> {
+		&mut self.param_list
+	}
+
+	pub fn result_list_mut(&mut self) -> &mut ResultList {
+		&mut self.result_list
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct SetTemporary {
+	pub(crate) var: Temporary,
+	pub(crate) value: Box<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl SetTemporary {
+	#[must_use]
+	pub const fn new(var: Temporary, value: Box<Expression>) -> Self {
+		Self {
+			var,
+			value,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn var(&self) -> Temporary {
+		self.var
+	}
+
+	#[must_use]
+	pub const fn value(&self) -> &Expression {
+		&self.value
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn var_mut(&mut self) -> &mut Temporary {
+		&mut self.var
+	}
+
+	pub fn value_mut(&mut self) -> &mut Expression {
+		&mut self.value
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct SetLocal {
+	pub(crate) var: Local,
+	pub(crate) value: Box<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl SetLocal {
+	#[must_use]
+	pub const fn new(var: Local, value: Box<Expression>) -> Self {
+		Self {
+			var,
+			value,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn var(&self) -> Local {
+		self.var
+	}
+
+	#[must_use]
+	pub const fn value(&self) -> &Expression {
+		&self.value
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn var_mut(&mut self) -> &mut Local {
+		&mut self.var
+	}
+
+	pub fn value_mut(&mut self) -> &mut Expression {
+		&mut self.value
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct SetGlobal {
+	pub(crate) var: usize,
+	pub(crate) value: Box<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl SetGlobal {
+	#[must_use]
+	pub const fn new(var: usize, value: Box<Expression>) -> Self {
+		Self {
+			var,
+			value,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn var(&self) -> usize {
+		self.var
+	}
+
+	#[must_use]
+	pub const fn value(&self) -> &Expression {
+		&self.value
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn var_mut(&mut self) -> &mut usize {
+		&mut self.var
+	}
+
+	pub fn value_mut(&mut self) -> &mut Expression {
+		&mut self.value
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct StoreAt {
+	pub(crate) store_type: StoreType,
+	pub(crate) memory: usize,
+	pub(crate) index_type: IndexType,
+	pub(crate) offset: u64,
+	pub(crate) pointer: Box<Expression>,
+	pub(crate) value: Box<Expression>,
+	pub(crate) is_atomic: bool,
+	pub(crate) code_offset: usize,
+}
+
+impl StoreAt {
+	#[must_use]
+	pub const fn new(
+		store_type: StoreType,
+		memory: usize,
+		index_type: IndexType,
+		offset: u64,
+		pointer: Box<Expression>,
+		value: Box<Expression>,
+	) -> Self {
+		Self {
+			store_type,
+			memory,
+			index_type,
+			offset,
+			pointer,
+			value,
+			is_atomic: false,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn store_type(&self) -> StoreType {
+		self.store_type
+	}
+
+	#[must_use]
+	pub const fn memory(&self) -> usize {
+		self.memory
+	}
+
+	#[must_use]
+	pub const fn index_type(&self) -> IndexType {
+		self.index_type
+	}
+
+	#[must_use]
+	pub const fn offset(&self) -> u64 {
+		self.offset
+	}
+
+	#[must_use]
+	pub const fn pointer(&self) -> &Expression {
+		&self.pointer
+	}
+
+	#[must_use]
+	pub const fn value(&self) -> &Expression {
+		&self.value
+	}
+
+	/// Whether the store came from an atomic access, which traps when
+	/// the address is not aligned to its size.
+	#[must_use]
+	pub const fn is_atomic(&self) -> bool {
+		self.is_atomic
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn store_type_mut(&mut self) -> &mut StoreType {
+		&mut self.store_type
+	}
+
+	pub fn memory_mut(&mut self) -> &mut usize {
+		&mut self.memory
+	}
+
+	pub fn index_type_mut(&mut self) -> &mut IndexType {
+		&mut self.index_type
+	}
+
+	pub fn offset_mut(&mut self) -> &mut u64 {
+		&mut self.offset
+	}
+
+	pub fn pointer_mut(&mut self) -> &mut Expression {
+		&mut self.pointer
+	}
+
+	pub fn value_mut(&mut self) -> &mut Expression {
+		&mut self.value
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct MemoryGrow {
+	pub(crate) memory: usize,
+	pub(crate) index_type: IndexType,
+	pub(crate) result: Temporary,
+	pub(crate) size: Box<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl MemoryGrow {
+	#[must_use]
+	pub const fn new(
+		memory: usize,
+		index_type: IndexType,
+		result: Temporary,
+		size: Box<Expression>,
+	) -> Self {
+		Self {
+			memory,
+			index_type,
+			result,
+			size,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn memory(&self) -> usize {
+		self.memory
+	}
+
+	#[must_use]
+	pub const fn index_type(&self) -> IndexType {
+		self.index_type
+	}
+
+	#[must_use]
+	pub const fn result(&self) -> Temporary {
+		self.result
+	}
+
+	#[must_use]
+	pub const fn size(&self) -> &Expression {
+		&self.size
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn memory_mut(&mut self) -> &mut usize {
+		&mut self.memory
+	}
+
+	pub fn index_type_mut(&mut self) -> &mut IndexType {
+		&mut self.index_type
+	}
+
+	pub fn result_mut(&mut self) -> &mut Temporary {
+		&mut self.result
+	}
+
+	pub fn size_mut(&mut self) -> &mut Expression {
+		&mut self.size
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+// Waiting compares the current value against the expected one, so the
+// loaded value is kept alongside the other operands
+#[derive(Clone)]
+pub struct AtomicWait {
+	pub(crate) result: Temporary,
+	pub(crate) value: LoadAt,
+	pub(crate) expected: Box<Expression>,
+	pub(crate) timeout: Box<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl AtomicWait {
+	#[must_use]
+	pub const fn new(
+		result: Temporary,
+		value: LoadAt,
+		expected: Box<Expression>,
+		timeout: Box<Expression>,
+	) -> Self {
+		Self {
+			result,
+			value,
+			expected,
+			timeout,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn result(&self) -> Temporary {
+		self.result
+	}
+
+	#[must_use]
+	pub const fn value(&self) -> &LoadAt {
+		&self.value
+	}
+
+	#[must_use]
+	pub const fn expected(&self) -> &Expression {
+		&self.expected
+	}
+
+	#[must_use]
+	pub const fn timeout(&self) -> &Expression {
+		&self.timeout
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn result_mut(&mut self) -> &mut Temporary {
+		&mut self.result
+	}
+
+	pub fn value_mut(&mut self) -> &mut LoadAt {
+		&mut self.value
+	}
+
+	pub fn expected_mut(&mut self) -> &mut Expression {
+		&mut self.expected
+	}
+
+	pub fn timeout_mut(&mut self) -> &mut Expression {
+		&mut self.timeout
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct MemoryArgument {
+	pub(crate) memory: usize,
+	pub(crate) index_type: IndexType,
+	pub(crate) pointer: Box<Expression>,
+}
+
+impl MemoryArgument {
+	#[must_use]
+	pub const fn new(memory: usize, index_type: IndexType, pointer: Box<Expression>) -> Self {
+		Self {
+			memory,
+			index_type,
+			pointer,
+		}
+	}
+
+	#[must_use]
+	pub const fn memory(&self) -> usize {
+		self.memory
+	}
+
+	#[must_use]
+	pub const fn index_type(&self) -> IndexType {
+		self.index_type
+	}
+
+	#[must_use]
+	pub const fn pointer(&self) -> &Expression {
+		&self.pointer
+	}
+
+	pub fn memory_mut(&mut self) -> &mut usize {
+		&mut self.memory
+	}
+
+	pub fn index_type_mut(&mut self) -> &mut IndexType {
+		&mut self.index_type
+	}
+
+	pub fn pointer_mut(&mut self) -> &mut Expression {
+		&mut self.pointer
+	}
+}
+
+#[derive(Clone)]
+pub struct MemoryCopy {
+	pub(crate) destination: MemoryArgument,
+	pub(crate) source: MemoryArgument,
+	pub(crate) size: Box<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl MemoryCopy {
+	#[must_use]
+	pub const fn new(
+		destination: MemoryArgument,
+		source: MemoryArgument,
+		size: Box<Expression>,
+	) -> Self {
+		Self {
+			destination,
+			source,
+			size,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn destination(&self) -> &MemoryArgument {
+		&self.destination
+	}
+
+	#[must_use]
+	pub const fn source(&self) -> &MemoryArgument {
+		&self.source
+	}
+
+	#[must_use]
+	pub const fn size(&self) -> &Expression {
+		&self.size
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn destination_mut(&mut self) -> &mut MemoryArgument {
+		&mut self.destination
+	}
+
+	pub fn source_mut(&mut self) -> &mut MemoryArgument {
+		&mut self.source
+	}
+
+	pub fn size_mut(&mut self) -> &mut Expression {
+		&mut self.size
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct MemoryFill {
+	pub(crate) destination: MemoryArgument,
+	pub(crate) size: Box<Expression>,
+	pub(crate) value: Box<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl MemoryFill {
+	#[must_use]
+	pub const fn new(
+		destination: MemoryArgument,
+		size: Box<Expression>,
+		value: Box<Expression>,
+	) -> Self {
+		Self {
+			destination,
+			size,
+			value,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn destination(&self) -> &MemoryArgument {
+		&self.destination
+	}
+
+	#[must_use]
+	pub const fn size(&self) -> &Expression {
+		&self.size
+	}
+
+	#[must_use]
+	pub const fn value(&self) -> &Expression {
+		&self.value
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn destination_mut(&mut self) -> &mut MemoryArgument {
+		&mut self.destination
+	}
+
+	pub fn size_mut(&mut self) -> &mut Expression {
+		&mut self.size
+	}
+
+	pub fn value_mut(&mut self) -> &mut Expression {
+		&mut self.value
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct MemoryInit {
+	pub(crate) destination: MemoryArgument,
+	pub(crate) data: usize,
+	pub(crate) offset: Box<Expression>,
+	pub(crate) size: Box<Expression>,
+	pub(crate) code_offset: usize,
+}
+
+impl MemoryInit {
+	#[must_use]
+	pub const fn new(
+		destination: MemoryArgument,
+		data: usize,
+		offset: Box<Expression>,
+		size: Box<Expression>,
+	) -> Self {
+		Self {
+			destination,
+			data,
+			offset,
+			size,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn destination(&self) -> &MemoryArgument {
+		&self.destination
+	}
+
+	#[must_use]
+	pub const fn data(&self) -> usize {
+		self.data
+	}
+
+	#[must_use]
+	pub const fn offset(&self) -> &Expression {
+		&self.offset
+	}
+
+	#[must_use]
+	pub const fn size(&self) -> &Expression {
+		&self.size
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn destination_mut(&mut self) -> &mut MemoryArgument {
+		&mut self.destination
+	}
+
+	pub fn data_mut(&mut self) -> &mut usize {
+		&mut self.data
+	}
+
+	pub fn offset_mut(&mut self) -> &mut Expression {
+		&mut self.offset
+	}
+
+	pub fn size_mut(&mut self) -> &mut Expression {
+		&mut self.size
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone, Copy)]
+pub struct DataDrop {
+	pub(crate) data: usize,
+	pub(crate) code_offset: usize,
+}
+
+impl DataDrop {
+	#[must_use]
+	pub const fn new(data: usize) -> Self {
+		Self {
+			data,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn data(self) -> usize {
+		self.data
+	}
+
+	#[must_use]
+	pub const fn code_offset(self) -> usize {
+		self.code_offset
+	}
+
+	pub fn data_mut(&mut self) -> &mut usize {
+		&mut self.data
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
+}
+
+#[derive(Clone)]
+pub struct TableArgument {
+	pub(crate) table: usize,
+	pub(crate) index: Box<Expression>,
+}
+
+impl TableArgument {
+	#[must_use]
+	pub const fn new(table: usize, index: Box<Expression>) -> Self {
+		Self { table, index }
+	}
+
+	#[must_use]
+	pub const fn table(&self) -> usize {
+		self.table
+	}
+
+	#[must_use]
+	pub const fn index(&self) -> &Expression {
+		&self.index
+	}
+
+	pub fn table_mut(&mut self) -> &mut usize {
+		&mut self.table
+	}
+
+	pub fn index_mut(&mut self) -> &mut Expression {
+		&mut self.index
 	}
 }
 
-pub struct BrIf {
-	pub(crate) condition: Box<Expression>,
-	pub(crate) target: Br,
+#[derive(Clone)]
+pub struct TableGet {
+	pub(crate) table: usize,
+	pub(crate) index: Box<Expression>,
+	pub(crate) result: Temporary,
+	pub(crate) code_offset: usize,
 }
 
-impl BrIf {
+impl TableGet {
 	#[must_use]
-	pub const fn condition(&self) -> &Expression {
-		&self.condition
+	pub const fn new(table: usize, index: Box<Expression>, result: Temporary) -> Self {
+		Self {
+			table,
+			index,
+			result,
+			code_offset: 0,
+		}
 	}
 
 	#[must_use]
-	pub const fn target(&self) -> Br {
-		self.target
+	pub const fn table(&self) -> usize {
+		self.table
 	}
-}
-
-pub struct If {
-	pub(crate) condition: Box<Expression>,
-	pub(crate) on_true: Box<Block>,
-	pub(crate) on_false: Option<Box<Block>>,
-}
 
-impl If {
 	#[must_use]
-	pub const fn condition(&self) -> &Expression {
-		&self.condition
+	pub const fn index(&self) -> &Expression {
+		&self.index
 	}
 
 	#[must_use]
-	pub const fn on_true(&self) -> &Block {
-		&self.on_true
+	pub const fn result(&self) -> Temporary {
+		self.result
 	}
 
 	#[must_use]
-	pub fn on_false(&self) -> Option<&Block> {
-		self.on_false.as_deref()
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
 	}
-}
 
-pub struct Call {
-	pub(crate) function: usize,
-	pub(crate) param_list: Vec<Expression>,
-	pub(crate) result_list: ResultList,
-}
+	pub fn table_mut(&mut self) -> &mut usize {
+		&mut self.table
+	}
 
-impl Call {
-	#[must_use]
-	pub const fn function(&self) -> usize {
-		self.function
+	pub fn index_mut(&mut self) -> &mut Expression {
+		&mut self.index
 	}
 
-	#[must_use]
-	pub fn param_list(&self) -> &[Expression] {
-		&self.param_list
+	pub fn result_mut(&mut self) -> &mut Temporary {
+		&mut self.result
 	}
 
-	#[must_use]
-	pub const fn result_list(&self) -> ResultList {
-		self.result_list
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
 	}
 }
 
-pub struct CallIndirect {
+#[derive(Clone)]
+pub struct TableSet {
 	pub(crate) table: usize,
 	pub(crate) index: Box<Expression>,
-	pub(crate) param_list: Vec<Expression>,
-	pub(crate) result_list: ResultList,
+	pub(crate) value: Box<Expression>,
+	pub(crate) code_offset: usize,
 }
 
-impl CallIndirect {
+impl TableSet {
+	#[must_use]
+	pub const fn new(table: usize, index: Box<Expression>, value: Box<Expression>) -> Self {
+		Self {
+			table,
+			index,
+			value,
+			code_offset: 0,
+		}
+	}
+
 	#[must_use]
 	pub const fn table(&self) -> usize {
 		self.table
@@ -818,188 +3075,376 @@ impl CallIndirect {
 	}
 
 	#[must_use]
-	pub fn param_list(&self) -> &[Expression] {
-		&self.param_list
+	pub const fn value(&self) -> &Expression {
+		&self.value
 	}
 
 	#[must_use]
-	pub const fn result_list(&self) -> ResultList {
-		self.result_list
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn table_mut(&mut self) -> &mut usize {
+		&mut self.table
+	}
+
+	pub fn index_mut(&mut self) -> &mut Expression {
+		&mut self.index
+	}
+
+	pub fn value_mut(&mut self) -> &mut Expression {
+		&mut self.value
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
 	}
 }
 
-pub struct SetTemporary {
-	pub(crate) var: Temporary,
-	pub(crate) value: Box<Expression>,
+#[derive(Clone, Copy)]
+pub struct TableSize {
+	pub(crate) table: usize,
+	pub(crate) result: Temporary,
+	pub(crate) code_offset: usize,
 }
 
-impl SetTemporary {
+impl TableSize {
 	#[must_use]
-	pub const fn var(&self) -> Temporary {
-		self.var
+	pub const fn new(table: usize, result: Temporary) -> Self {
+		Self {
+			table,
+			result,
+			code_offset: 0,
+		}
 	}
 
 	#[must_use]
-	pub const fn value(&self) -> &Expression {
-		&self.value
+	pub const fn table(self) -> usize {
+		self.table
 	}
-}
-
-pub struct SetLocal {
-	pub(crate) var: Local,
-	pub(crate) value: Box<Expression>,
-}
 
-impl SetLocal {
 	#[must_use]
-	pub const fn var(&self) -> Local {
-		self.var
+	pub const fn result(self) -> Temporary {
+		self.result
 	}
 
 	#[must_use]
-	pub const fn value(&self) -> &Expression {
-		&self.value
+	pub const fn code_offset(self) -> usize {
+		self.code_offset
+	}
+
+	pub fn table_mut(&mut self) -> &mut usize {
+		&mut self.table
+	}
+
+	pub fn result_mut(&mut self) -> &mut Temporary {
+		&mut self.result
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
 	}
 }
 
-pub struct SetGlobal {
-	pub(crate) var: usize,
+#[derive(Clone)]
+pub struct TableGrow {
+	pub(crate) table: usize,
+	pub(crate) result: Temporary,
 	pub(crate) value: Box<Expression>,
+	pub(crate) size: Box<Expression>,
+	pub(crate) code_offset: usize,
 }
 
-impl SetGlobal {
+impl TableGrow {
 	#[must_use]
-	pub const fn var(&self) -> usize {
-		self.var
+	pub const fn new(
+		table: usize,
+		result: Temporary,
+		value: Box<Expression>,
+		size: Box<Expression>,
+	) -> Self {
+		Self {
+			table,
+			result,
+			value,
+			size,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn table(&self) -> usize {
+		self.table
+	}
+
+	#[must_use]
+	pub const fn result(&self) -> Temporary {
+		self.result
 	}
 
 	#[must_use]
 	pub const fn value(&self) -> &Expression {
 		&self.value
 	}
+
+	#[must_use]
+	pub const fn size(&self) -> &Expression {
+		&self.size
+	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn table_mut(&mut self) -> &mut usize {
+		&mut self.table
+	}
+
+	pub fn result_mut(&mut self) -> &mut Temporary {
+		&mut self.result
+	}
+
+	pub fn value_mut(&mut self) -> &mut Expression {
+		&mut self.value
+	}
+
+	pub fn size_mut(&mut self) -> &mut Expression {
+		&mut self.size
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
 }
 
-pub struct StoreAt {
-	pub(crate) store_type: StoreType,
-	pub(crate) memory: usize,
-	pub(crate) offset: u32,
-	pub(crate) pointer: Box<Expression>,
+#[derive(Clone)]
+pub struct TableFill {
+	pub(crate) destination: TableArgument,
 	pub(crate) value: Box<Expression>,
+	pub(crate) size: Box<Expression>,
+	pub(crate) code_offset: usize,
 }
 
-impl StoreAt {
+impl TableFill {
 	#[must_use]
-	pub const fn store_type(&self) -> StoreType {
-		self.store_type
+	pub const fn new(
+		destination: TableArgument,
+		value: Box<Expression>,
+		size: Box<Expression>,
+	) -> Self {
+		Self {
+			destination,
+			value,
+			size,
+			code_offset: 0,
+		}
 	}
 
 	#[must_use]
-	pub const fn memory(&self) -> usize {
-		self.memory
+	pub const fn destination(&self) -> &TableArgument {
+		&self.destination
 	}
 
 	#[must_use]
-	pub const fn offset(&self) -> u32 {
-		self.offset
+	pub const fn value(&self) -> &Expression {
+		&self.value
 	}
 
 	#[must_use]
-	pub const fn pointer(&self) -> &Expression {
-		&self.pointer
+	pub const fn size(&self) -> &Expression {
+		&self.size
 	}
 
 	#[must_use]
-	pub const fn value(&self) -> &Expression {
-		&self.value
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn destination_mut(&mut self) -> &mut TableArgument {
+		&mut self.destination
+	}
+
+	pub fn value_mut(&mut self) -> &mut Expression {
+		&mut self.value
+	}
+
+	pub fn size_mut(&mut self) -> &mut Expression {
+		&mut self.size
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
 	}
 }
 
-pub struct MemoryGrow {
-	pub(crate) memory: usize,
-	pub(crate) result: Temporary,
+#[derive(Clone)]
+pub struct TableCopy {
+	pub(crate) destination: TableArgument,
+	pub(crate) source: TableArgument,
 	pub(crate) size: Box<Expression>,
+	pub(crate) code_offset: usize,
 }
 
-impl MemoryGrow {
+impl TableCopy {
 	#[must_use]
-	pub const fn memory(&self) -> usize {
-		self.memory
+	pub const fn new(
+		destination: TableArgument,
+		source: TableArgument,
+		size: Box<Expression>,
+	) -> Self {
+		Self {
+			destination,
+			source,
+			size,
+			code_offset: 0,
+		}
 	}
 
 	#[must_use]
-	pub const fn result(&self) -> Temporary {
-		self.result
+	pub const fn destination(&self) -> &TableArgument {
+		&self.destination
+	}
+
+	#[must_use]
+	pub const fn source(&self) -> &TableArgument {
+		&self.source
 	}
 
 	#[must_use]
 	pub const fn size(&self) -> &Expression {
 		&self.size
 	}
-}
-
-pub struct MemoryArgument {
-	pub(crate) memory: usize,
-	pub(crate) pointer: Box<Expression>,
-}
 
-impl MemoryArgument {
 	#[must_use]
-	pub const fn memory(&self) -> usize {
-		self.memory
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
 	}
 
-	#[must_use]
-	pub const fn pointer(&self) -> &Expression {
-		&self.pointer
+	pub fn destination_mut(&mut self) -> &mut TableArgument {
+		&mut self.destination
+	}
+
+	pub fn source_mut(&mut self) -> &mut TableArgument {
+		&mut self.source
+	}
+
+	pub fn size_mut(&mut self) -> &mut Expression {
+		&mut self.size
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
 	}
 }
 
-pub struct MemoryCopy {
-	pub(crate) destination: MemoryArgument,
-	pub(crate) source: MemoryArgument,
+#[derive(Clone)]
+pub struct TableInit {
+	pub(crate) destination: TableArgument,
+	pub(crate) element: usize,
+	pub(crate) offset: Box<Expression>,
 	pub(crate) size: Box<Expression>,
+	pub(crate) code_offset: usize,
 }
 
-impl MemoryCopy {
+impl TableInit {
 	#[must_use]
-	pub const fn destination(&self) -> &MemoryArgument {
+	pub const fn new(
+		destination: TableArgument,
+		element: usize,
+		offset: Box<Expression>,
+		size: Box<Expression>,
+	) -> Self {
+		Self {
+			destination,
+			element,
+			offset,
+			size,
+			code_offset: 0,
+		}
+	}
+
+	#[must_use]
+	pub const fn destination(&self) -> &TableArgument {
 		&self.destination
 	}
 
 	#[must_use]
-	pub const fn source(&self) -> &MemoryArgument {
-		&self.source
+	pub const fn element(&self) -> usize {
+		self.element
+	}
+
+	#[must_use]
+	pub const fn offset(&self) -> &Expression {
+		&self.offset
 	}
 
 	#[must_use]
 	pub const fn size(&self) -> &Expression {
 		&self.size
 	}
+
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		self.code_offset
+	}
+
+	pub fn destination_mut(&mut self) -> &mut TableArgument {
+		&mut self.destination
+	}
+
+	pub fn element_mut(&mut self) -> &mut usize {
+		&mut self.element
+	}
+
+	pub fn offset_mut(&mut self) -> &mut Expression {
+		&mut self.offset
+	}
+
+	pub fn size_mut(&mut self) -> &mut Expression {
+		&mut self.size
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
+	}
 }
 
-pub struct MemoryFill {
-	pub(crate) destination: MemoryArgument,
-	pub(crate) size: Box<Expression>,
-	pub(crate) value: Box<Expression>,
+#[derive(Clone, Copy)]
+pub struct ElemDrop {
+	pub(crate) element: usize,
+	pub(crate) code_offset: usize,
 }
 
-impl MemoryFill {
+impl ElemDrop {
 	#[must_use]
-	pub const fn destination(&self) -> &MemoryArgument {
-		&self.destination
+	pub const fn new(element: usize) -> Self {
+		Self {
+			element,
+			code_offset: 0,
+		}
 	}
 
 	#[must_use]
-	pub const fn size(&self) -> &Expression {
-		&self.size
+	pub const fn element(self) -> usize {
+		self.element
 	}
 
 	#[must_use]
-	pub const fn value(&self) -> &Expression {
-		&self.value
+	pub const fn code_offset(self) -> usize {
+		self.code_offset
+	}
+
+	pub fn element_mut(&mut self) -> &mut usize {
+		&mut self.element
+	}
+
+	pub fn code_offset_mut(&mut self) -> &mut usize {
+		&mut self.code_offset
 	}
 }
 
+#[derive(Clone)]
 pub enum Statement {
 	Block(Block),
 	BrIf(BrIf),
@@ -1011,10 +3456,57 @@ pub enum Statement {
 	SetGlobal(SetGlobal),
 	StoreAt(StoreAt),
 	MemoryGrow(MemoryGrow),
+	AtomicWait(AtomicWait),
 	MemoryCopy(MemoryCopy),
 	MemoryFill(MemoryFill),
-}
-
+	MemoryInit(MemoryInit),
+	DataDrop(DataDrop),
+	TableGet(TableGet),
+	TableSet(TableSet),
+	TableSize(TableSize),
+	TableGrow(TableGrow),
+	TableFill(TableFill),
+	TableCopy(TableCopy),
+	TableInit(TableInit),
+	ElemDrop(ElemDrop),
+	Try(Try),
+}
+
+impl Statement {
+	/// Offset of the operator in the module binary, or 0 if the node was not
+	/// read from one.
+	#[must_use]
+	pub const fn code_offset(&self) -> usize {
+		match self {
+			Self::Block(v) => v.code_offset(),
+			Self::BrIf(v) => v.code_offset(),
+			Self::If(v) => v.code_offset(),
+			Self::Call(v) => v.code_offset(),
+			Self::CallIndirect(v) => v.code_offset(),
+			Self::SetTemporary(v) => v.code_offset(),
+			Self::SetLocal(v) => v.code_offset(),
+			Self::SetGlobal(v) => v.code_offset(),
+			Self::StoreAt(v) => v.code_offset(),
+			Self::MemoryGrow(v) => v.code_offset(),
+			Self::AtomicWait(v) => v.code_offset(),
+			Self::MemoryCopy(v) => v.code_offset(),
+			Self::MemoryFill(v) => v.code_offset(),
+			Self::MemoryInit(v) => v.code_offset(),
+			Self::DataDrop(v) => v.code_offset(),
+			Self::TableGet(v) => v.code_offset(),
+			Self::TableSet(v) => v.code_offset(),
+			Self::TableSize(v) => v.code_offset(),
+			Self::TableGrow(v) => v.code_offset(),
+			Self::TableFill(v) => v.code_offset(),
+			Self::TableCopy(v) => v.code_offset(),
+			Self::TableInit(v) => v.code_offset(),
+			Self::ElemDrop(v) => v.code_offset(),
+			Self::Try(v) => v.code_offset(),
+		}
+	}
+}
+
+#[derive(Clone)]
 pub struct FuncData {
 	pub(crate) local_data: Vec<ValType>,
 	pub(crate) num_result: usize,
@@ -1024,6 +3516,23 @@ pub struct FuncData {
 }
 
 impl FuncData {
+	#[must_use]
+	pub const fn new(
+		local_data: Vec<ValType>,
+		num_result: usize,
+		num_param: usize,
+		num_stack: usize,
+		code: Block,
+	) -> Self {
+		Self {
+			local_data,
+			num_result,
+			num_param,
+			num_stack,
+			code,
+		}
+	}
+
 	#[must_use]
 	pub fn local_data(&self) -> &[ValType] {
 		&self.local_data
@@ -1048,4 +3557,24 @@ impl FuncData {
 	pub const fn code(&self) -> &Block {
 		&self.code
 	}
+
+	pub fn local_data_mut(&mut self) -> &mut Vec<ValType> {
+		&mut self.local_data
+	}
+
+	pub fn num_result_mut(&mut self) -> &mut usize {
+		&mut self.num_result
+	}
+
+	pub fn num_param_mut(&mut self) -> &mut usize {
+		&mut self.num_param
+	}
+
+	pub fn num_stack_mut(&mut self) -> &mut usize {
+		&mut self.num_stack
+	}
+
+	pub fn code_mut(&mut self) -> &mut Block {
+		&mut self.code
+	}
 }
@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use crate::{
+	node::{
+		Align, AtomicWait, Br, BrIf, BrTable, Call, CallIndirect, MemoryGrow, ResultList, SetLocal,
+		SetTemporary, TableGet, TableGrow, TableSize, Temporary, Try,
+	},
+	visit::{Driver, Visitor},
+};
+
+// Branches carrying values read their old range implicitly
+#[derive(Default)]
+pub struct ReadList {
+	pub temporary_set: HashSet<usize>,
+}
+
+impl ReadList {
+	pub fn run<D: Driver<Self>>(node: &D) -> HashSet<usize> {
+		let mut visitor = Self::default();
+
+		node.accept(&mut visitor);
+
+		visitor.temporary_set
+	}
+
+	fn insert_align(&mut self, align: Align) {
+		self.temporary_set
+			.extend(align.old_range().iter().map(Temporary::var));
+	}
+}
+
+impl Visitor for ReadList {
+	fn visit_get_temporary(&mut self, temporary: Temporary) {
+		self.temporary_set.insert(temporary.var());
+	}
+
+	fn visit_br(&mut self, br: Br) {
+		self.insert_align(br.align());
+	}
+
+	fn visit_br_if(&mut self, br_if: &BrIf) {
+		self.insert_align(br_if.target().align());
+	}
+
+	fn visit_br_table(&mut self, br_table: &BrTable) {
+		for br in br_table.data() {
+			self.insert_align(br.align());
+		}
+
+		self.insert_align(br_table.default().align());
+	}
+}
+
+// Branches write their values into the new range before leaving
+#[derive(Default)]
+pub struct WriteList {
+	pub temporary_set: HashSet<usize>,
+	pub local_set: HashSet<usize>,
+}
+
+impl WriteList {
+	pub fn run<D: Driver<Self>>(node: &D) -> Self {
+		let mut visitor = Self::default();
+
+		node.accept(&mut visitor);
+
+		visitor
+	}
+
+	fn insert_range(&mut self, range: ResultList) {
+		self.temporary_set.extend(range.iter().map(Temporary::var));
+	}
+
+	fn insert_align(&mut self, align: Align) {
+		self.insert_range(align.new_range());
+	}
+}
+
+impl Visitor for WriteList {
+	fn visit_br(&mut self, br: Br) {
+		self.insert_align(br.align());
+	}
+
+	fn visit_br_table(&mut self, br_table: &BrTable) {
+		for br in br_table.data() {
+			self.insert_align(br.align());
+		}
+
+		self.insert_align(br_table.default().align());
+	}
+
+	fn visit_br_if(&mut self, br_if: &BrIf) {
+		self.insert_align(br_if.target().align());
+	}
+
+	fn visit_try(&mut self, try_catch: &Try) {
+		for catch in try_catch.catch_list() {
+			self.insert_range(catch.payload());
+		}
+	}
+
+	fn visit_call(&mut self, call: &Call) {
+		self.insert_range(call.result_list());
+	}
+
+	fn visit_call_indirect(&mut self, call_indirect: &CallIndirect) {
+		self.insert_range(call_indirect.result_list());
+	}
+
+	fn visit_set_temporary(&mut self, set_temporary: &SetTemporary) {
+		self.temporary_set.insert(set_temporary.var().var());
+	}
+
+	fn visit_set_local(&mut self, set_local: &SetLocal) {
+		self.local_set.insert(set_local.var().var());
+	}
+
+	fn visit_memory_grow(&mut self, memory_grow: &MemoryGrow) {
+		self.temporary_set.insert(memory_grow.result().var());
+	}
+
+	fn visit_atomic_wait(&mut self, atomic_wait: &AtomicWait) {
+		self.temporary_set.insert(atomic_wait.result().var());
+	}
+
+	fn visit_table_get(&mut self, table_get: &TableGet) {
+		self.temporary_set.insert(table_get.result().var());
+	}
+
+	fn visit_table_size(&mut self, table_size: TableSize) {
+		self.temporary_set.insert(table_size.result().var());
+	}
+
+	fn visit_table_grow(&mut self, table_grow: &TableGrow) {
+		self.temporary_set.insert(table_grow.result().var());
+	}
+}
@@ -0,0 +1,222 @@
+use crate::{
+	node::{BinOpType, CmpOpType, Expression, FuncData, UnOpType, Value},
+	visit::{DriverMut, VisitorMut},
+};
+
+use super::Pass;
+
+// Only integer operations are folded, as float results depend on
+// how the runtime rounds and represents them
+pub struct ConstantFold;
+
+const fn as_value(expression: &Expression) -> Option<Value> {
+	if let Expression::Value(value) = expression {
+		Some(*value)
+	} else {
+		None
+	}
+}
+
+#[allow(
+	clippy::cast_possible_wrap,
+	clippy::cast_possible_truncation,
+	clippy::cast_sign_loss
+)]
+fn fold_un_op(op_type: UnOpType, rhs: Value) -> Option<Value> {
+	let result = match (op_type, rhs) {
+		(UnOpType::Clz_I32, Value::I32(rhs)) => Value::I32(rhs.leading_zeros() as i32),
+		(UnOpType::Ctz_I32, Value::I32(rhs)) => Value::I32(rhs.trailing_zeros() as i32),
+		(UnOpType::Popcnt_I32, Value::I32(rhs)) => Value::I32(rhs.count_ones() as i32),
+		(UnOpType::Clz_I64, Value::I64(rhs)) => Value::I64(rhs.leading_zeros().into()),
+		(UnOpType::Ctz_I64, Value::I64(rhs)) => Value::I64(rhs.trailing_zeros().into()),
+		(UnOpType::Popcnt_I64, Value::I64(rhs)) => Value::I64(rhs.count_ones().into()),
+		(UnOpType::Wrap_I32_I64, Value::I64(rhs)) => Value::I32(rhs as i32),
+		(UnOpType::Extend_I32_N8, Value::I32(rhs)) => Value::I32((rhs as i8).into()),
+		(UnOpType::Extend_I32_N16, Value::I32(rhs)) => Value::I32((rhs as i16).into()),
+		(UnOpType::Extend_I64_N8, Value::I64(rhs)) => Value::I64((rhs as i8).into()),
+		(UnOpType::Extend_I64_N16, Value::I64(rhs)) => Value::I64((rhs as i16).into()),
+		(UnOpType::Extend_I64_N32, Value::I64(rhs)) => Value::I64((rhs as i32).into()),
+		(UnOpType::Extend_I64_I32, Value::I32(rhs)) => Value::I64(rhs.into()),
+		(UnOpType::Extend_I64_U32, Value::I32(rhs)) => Value::I64((rhs as u32).into()),
+		_ => return None,
+	};
+
+	Some(result)
+}
+
+// Division that would trap is left in place so it still traps at runtime
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn fold_bin_op_i32(op_type: BinOpType, lhs: i32, rhs: i32) -> Option<i32> {
+	let result = match op_type {
+		BinOpType::Add_I32 => lhs.wrapping_add(rhs),
+		BinOpType::Sub_I32 => lhs.wrapping_sub(rhs),
+		BinOpType::Mul_I32 => lhs.wrapping_mul(rhs),
+		BinOpType::DivS_I32 => lhs.checked_div(rhs)?,
+		BinOpType::DivU_I32 => (lhs as u32).checked_div(rhs as u32)? as i32,
+		BinOpType::RemS_I32 if rhs != 0 => lhs.wrapping_rem(rhs),
+		BinOpType::RemU_I32 => (lhs as u32).checked_rem(rhs as u32)? as i32,
+		BinOpType::And_I32 => lhs & rhs,
+		BinOpType::Or_I32 => lhs | rhs,
+		BinOpType::Xor_I32 => lhs ^ rhs,
+		BinOpType::Shl_I32 => lhs.wrapping_shl(rhs as u32),
+		BinOpType::ShrS_I32 => lhs.wrapping_shr(rhs as u32),
+		BinOpType::ShrU_I32 => (lhs as u32).wrapping_shr(rhs as u32) as i32,
+		BinOpType::Rotl_I32 => lhs.rotate_left(rhs as u32),
+		BinOpType::Rotr_I32 => lhs.rotate_right(rhs as u32),
+		_ => return None,
+	};
+
+	Some(result)
+}
+
+#[allow(
+	clippy::cast_possible_wrap,
+	clippy::cast_possible_truncation,
+	clippy::cast_sign_loss
+)]
+fn fold_bin_op_i64(op_type: BinOpType, lhs: i64, rhs: i64) -> Option<i64> {
+	let result = match op_type {
+		BinOpType::Add_I64 => lhs.wrapping_add(rhs),
+		BinOpType::Sub_I64 => lhs.wrapping_sub(rhs),
+		BinOpType::Mul_I64 => lhs.wrapping_mul(rhs),
+		BinOpType::DivS_I64 => lhs.checked_div(rhs)?,
+		BinOpType::DivU_I64 => (lhs as u64).checked_div(rhs as u64)? as i64,
+		BinOpType::RemS_I64 if rhs != 0 => lhs.wrapping_rem(rhs),
+		BinOpType::RemU_I64 => (lhs as u64).checked_rem(rhs as u64)? as i64,
+		BinOpType::And_I64 => lhs & rhs,
+		BinOpType::Or_I64 => lhs | rhs,
+		BinOpType::Xor_I64 => lhs ^ rhs,
+		BinOpType::Shl_I64 => lhs.wrapping_shl(rhs as u32),
+		BinOpType::ShrS_I64 => lhs.wrapping_shr(rhs as u32),
+		BinOpType::ShrU_I64 => (lhs as u64).wrapping_shr(rhs as u32) as i64,
+		BinOpType::Rotl_I64 => lhs.rotate_left(rhs as u32),
+		BinOpType::Rotr_I64 => lhs.rotate_right(rhs as u32),
+		_ => return None,
+	};
+
+	Some(result)
+}
+
+fn fold_bin_op(op_type: BinOpType, lhs: Value, rhs: Value) -> Option<Value> {
+	match (lhs, rhs) {
+		(Value::I32(lhs), Value::I32(rhs)) => fold_bin_op_i32(op_type, lhs, rhs).map(Value::I32),
+		(Value::I64(lhs), Value::I64(rhs)) => fold_bin_op_i64(op_type, lhs, rhs).map(Value::I64),
+		_ => None,
+	}
+}
+
+#[allow(clippy::cast_sign_loss)]
+fn fold_cmp_op(op_type: CmpOpType, lhs: Value, rhs: Value) -> Option<Value> {
+	let result = match (op_type, lhs, rhs) {
+		(CmpOpType::Eq_I32, Value::I32(lhs), Value::I32(rhs)) => lhs == rhs,
+		(CmpOpType::Ne_I32, Value::I32(lhs), Value::I32(rhs)) => lhs != rhs,
+		(CmpOpType::LtS_I32, Value::I32(lhs), Value::I32(rhs)) => lhs < rhs,
+		(CmpOpType::LtU_I32, Value::I32(lhs), Value::I32(rhs)) => (lhs as u32) < (rhs as u32),
+		(CmpOpType::GtS_I32, Value::I32(lhs), Value::I32(rhs)) => lhs > rhs,
+		(CmpOpType::GtU_I32, Value::I32(lhs), Value::I32(rhs)) => (lhs as u32) > (rhs as u32),
+		(CmpOpType::LeS_I32, Value::I32(lhs), Value::I32(rhs)) => lhs <= rhs,
+		(CmpOpType::LeU_I32, Value::I32(lhs), Value::I32(rhs)) => (lhs as u32) <= (rhs as u32),
+		(CmpOpType::GeS_I32, Value::I32(lhs), Value::I32(rhs)) => lhs >= rhs,
+		(CmpOpType::GeU_I32, Value::I32(lhs), Value::I32(rhs)) => (lhs as u32) >= (rhs as u32),
+		(CmpOpType::Eq_I64, Value::I64(lhs), Value::I64(rhs)) => lhs == rhs,
+		(CmpOpType::Ne_I64, Value::I64(lhs), Value::I64(rhs)) => lhs != rhs,
+		(CmpOpType::LtS_I64, Value::I64(lhs), Value::I64(rhs)) => lhs < rhs,
+		(CmpOpType::LtU_I64, Value::I64(lhs), Value::I64(rhs)) => (lhs as u64) < (rhs as u64),
+		(CmpOpType::GtS_I64, Value::I64(lhs), Value::I64(rhs)) => lhs > rhs,
+		(CmpOpType::GtU_I64, Value::I64(lhs), Value::I64(rhs)) => (lhs as u64) > (rhs as u64),
+		(CmpOpType::LeS_I64, Value::I64(lhs), Value::I64(rhs)) => lhs <= rhs,
+		(CmpOpType::LeU_I64, Value::I64(lhs), Value::I64(rhs)) => (lhs as u64) <= (rhs as u64),
+		(CmpOpType::GeS_I64, Value::I64(lhs), Value::I64(rhs)) => lhs >= rhs,
+		(CmpOpType::GeU_I64, Value::I64(lhs), Value::I64(rhs)) => (lhs as u64) >= (rhs as u64),
+		_ => return None,
+	};
+
+	Some(Value::I32(result.into()))
+}
+
+impl VisitorMut for ConstantFold {
+	fn visit_expression_mut(&mut self, expression: &mut Expression) {
+		let result = match expression {
+			Expression::UnOp(v) => as_value(v.rhs()).and_then(|rhs| fold_un_op(v.op_type(), rhs)),
+			Expression::BinOp(v) => as_value(v.lhs())
+				.zip(as_value(v.rhs()))
+				.and_then(|(lhs, rhs)| fold_bin_op(v.op_type(), lhs, rhs)),
+			Expression::CmpOp(v) => as_value(v.lhs())
+				.zip(as_value(v.rhs()))
+				.and_then(|(lhs, rhs)| fold_cmp_op(v.op_type(), lhs, rhs)),
+			_ => None,
+		};
+
+		if let Some(value) = result {
+			*expression = Expression::Value(value);
+		}
+	}
+}
+
+impl Pass for ConstantFold {
+	fn run(&mut self, func: &mut FuncData) {
+		func.accept_mut(self);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::node::{
+		BinOp, BinOpType, Block, Expression, FuncData, SetTemporary, Statement, Temporary, Value,
+	};
+
+	use super::{ConstantFold, Pass};
+
+	fn fold_bin_op(op_type: BinOpType, lhs: Value, rhs: Value) -> Expression {
+		let bin_op = BinOp::new(
+			op_type,
+			Expression::Value(lhs).into(),
+			Expression::Value(rhs).into(),
+		);
+		let set = SetTemporary::new(Temporary::new(0), Expression::BinOp(bin_op).into());
+		let code = Block::new(None, vec![Statement::SetTemporary(set)], None);
+		let mut func = FuncData::new(Vec::new(), 0, 0, 1, code);
+
+		ConstantFold.run(&mut func);
+
+		let Statement::SetTemporary(set) = &func.code().code()[0] else {
+			unreachable!("statement should be kept");
+		};
+
+		set.value().clone()
+	}
+
+	#[test]
+	fn folds_wrapping_arithmetic() {
+		let add = fold_bin_op(BinOpType::Add_I32, Value::I32(i32::MAX), Value::I32(1));
+		let rem = fold_bin_op(BinOpType::RemS_I64, Value::I64(i64::MIN), Value::I64(-1));
+
+		assert!(matches!(add, Expression::Value(Value::I32(i32::MIN))));
+		assert!(matches!(rem, Expression::Value(Value::I64(0))));
+	}
+
+	#[test]
+	fn keeps_trapping_division() {
+		let trapping = [
+			(BinOpType::DivS_I32, Value::I32(1), Value::I32(0)),
+			(BinOpType::DivU_I32, Value::I32(1), Value::I32(0)),
+			(BinOpType::RemS_I32, Value::I32(1), Value::I32(0)),
+			(BinOpType::RemU_I32, Value::I32(1), Value::I32(0)),
+			(BinOpType::DivS_I32, Value::I32(i32::MIN), Value::I32(-1)),
+			(BinOpType::DivS_I64, Value::I64(1), Value::I64(0)),
+			(BinOpType::DivU_I64, Value::I64(1), Value::I64(0)),
+			(BinOpType::RemS_I64, Value::I64(1), Value::I64(0)),
+			(BinOpType::RemU_I64, Value::I64(1), Value::I64(0)),
+			(BinOpType::DivS_I64, Value::I64(i64::MIN), Value::I64(-1)),
+		];
+
+		for (op_type, lhs, rhs) in trapping {
+			let result = fold_bin_op(op_type, lhs, rhs);
+
+			assert!(
+				matches!(result, Expression::BinOp(_)),
+				"`{op_type:?}` should not be folded"
+			);
+		}
+	}
+}
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use crate::{
+	node::{Block, Expression, FuncData, Local, Statement, Temporary, Value},
+	visit::{DriverMut, VisitorMut},
+};
+
+use super::{access::WriteList, Pass};
+
+// Copies are only tracked through straight-line code; a nested block
+// starts from scratch and afterwards invalidates whatever it writes
+pub struct CopyPropagate;
+
+#[derive(Clone, Copy)]
+enum Source {
+	Value(Value),
+	Temporary(Temporary),
+	Local(Local),
+}
+
+impl Source {
+	const fn from_expression(expression: &Expression) -> Option<Self> {
+		match *expression {
+			Expression::Value(value) => Some(Self::Value(value)),
+			Expression::GetTemporary(temporary) => Some(Self::Temporary(temporary)),
+			Expression::GetLocal(local) => Some(Self::Local(local)),
+			_ => None,
+		}
+	}
+
+	const fn reads_temporary(self, var: usize) -> bool {
+		matches!(self, Self::Temporary(temporary) if temporary.var() == var)
+	}
+
+	const fn reads_local(self, var: usize) -> bool {
+		matches!(self, Self::Local(local) if local.var() == var)
+	}
+
+	const fn into_expression(self) -> Expression {
+		match self {
+			Self::Value(value) => Expression::Value(value),
+			Self::Temporary(temporary) => Expression::GetTemporary(temporary),
+			Self::Local(local) => Expression::GetLocal(local),
+		}
+	}
+}
+
+#[derive(Default)]
+struct Substitute {
+	source_map: HashMap<usize, Source>,
+}
+
+impl Substitute {
+	fn forget_temporary(&mut self, var: usize) {
+		self.source_map
+			.retain(|&key, source| key != var && !source.reads_temporary(var));
+	}
+
+	fn forget_local(&mut self, var: usize) {
+		self.source_map.retain(|_, source| !source.reads_local(var));
+	}
+
+	fn forget_written(&mut self, statement: &Statement) {
+		let write_list = WriteList::run(statement);
+
+		for var in write_list.temporary_set {
+			self.forget_temporary(var);
+		}
+
+		for var in write_list.local_set {
+			self.forget_local(var);
+		}
+	}
+
+	fn remember(&mut self, statement: &Statement) {
+		let Statement::SetTemporary(set) = statement else {
+			return;
+		};
+
+		let var = set.var().var();
+
+		if let Some(source) = Source::from_expression(set.value()) {
+			if !source.reads_temporary(var) {
+				self.source_map.insert(var, source);
+			}
+		}
+	}
+}
+
+impl VisitorMut for Substitute {
+	fn visit_expression_mut(&mut self, expression: &mut Expression) {
+		if let Expression::GetTemporary(temporary) = expression {
+			if let Some(source) = self.source_map.get(&temporary.var()) {
+				*expression = source.into_expression();
+			}
+		}
+	}
+}
+
+fn propagate_block(block: &mut Block) {
+	let mut substitute = Substitute::default();
+
+	for statement in block.code_mut() {
+		match statement {
+			Statement::Block(v) => propagate_block(v),
+			Statement::If(v) => {
+				v.condition_mut().accept_mut(&mut substitute);
+
+				propagate_block(v.on_true_mut());
+
+				if let Some(v) = v.on_false_mut() {
+					propagate_block(v);
+				}
+			}
+			Statement::Try(v) => {
+				propagate_block(v.body_mut());
+
+				for v in v.catch_list_mut() {
+					propagate_block(v.block_mut());
+				}
+
+				if let Some(v) = v.catch_all_mut() {
+					propagate_block(v);
+				}
+			}
+			_ => statement.accept_mut(&mut substitute),
+		}
+
+		substitute.forget_written(statement);
+		substitute.remember(statement);
+	}
+
+	if let Some(v) = block.last_mut() {
+		v.accept_mut(&mut substitute);
+	}
+}
+
+impl Pass for CopyPropagate {
+	fn run(&mut self, func: &mut FuncData) {
+		propagate_block(func.code_mut());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::node::{
+		Block, Expression, FuncData, Local, SetLocal, SetTemporary, Statement, Temporary, Value,
+	};
+
+	use super::{CopyPropagate, Pass};
+
+	fn set_temporary(var: usize, value: Expression) -> Statement {
+		Statement::SetTemporary(SetTemporary::new(Temporary::new(var), value.into()))
+	}
+
+	fn set_local(var: usize, value: Expression) -> Statement {
+		Statement::SetLocal(SetLocal::new(Local::new(var), value.into()))
+	}
+
+	fn get_temporary(var: usize) -> Expression {
+		Expression::GetTemporary(Temporary::new(var))
+	}
+
+	// Runs the pass and returns the value stored by the last statement
+	fn propagate(code: Vec<Statement>) -> Expression {
+		let code = Block::new(None, code, None);
+		let mut func = FuncData::new(vec![], 0, 0, 2, code);
+
+		CopyPropagate.run(&mut func);
+
+		let Some(Statement::SetTemporary(set)) = func.code().code().last() else {
+			unreachable!("last statement should be kept");
+		};
+
+		set.value().clone()
+	}
+
+	#[test]
+	fn forwards_copy_of_local() {
+		let result = propagate(vec![
+			set_temporary(0, Expression::GetLocal(Local::new(0))),
+			set_temporary(1, get_temporary(0)),
+		]);
+
+		assert!(matches!(result, Expression::GetLocal(local) if local.var() == 0));
+	}
+
+	#[test]
+	fn set_local_invalidates_copy() {
+		let result = propagate(vec![
+			set_temporary(0, Expression::GetLocal(Local::new(0))),
+			set_local(0, Expression::Value(Value::I32(5))),
+			set_temporary(1, get_temporary(0)),
+		]);
+
+		assert!(matches!(result, Expression::GetTemporary(temporary) if temporary.var() == 0));
+	}
+
+	#[test]
+	fn nested_block_invalidates_copy() {
+		let nested = Block::new(
+			None,
+			vec![set_temporary(0, Expression::Value(Value::I32(2)))],
+			None,
+		);
+		let result = propagate(vec![
+			set_temporary(0, Expression::Value(Value::I32(1))),
+			Statement::Block(nested),
+			set_temporary(1, get_temporary(0)),
+		]);
+
+		assert!(matches!(result, Expression::GetTemporary(temporary) if temporary.var() == 0));
+	}
+
+	#[test]
+	fn nested_block_starts_without_copies() {
+		let nested = Block::new(None, vec![set_temporary(1, get_temporary(0))], None);
+		let code = Block::new(
+			None,
+			vec![
+				set_temporary(0, Expression::Value(Value::I32(1))),
+				Statement::Block(nested),
+			],
+			None,
+		);
+		let mut func = FuncData::new(vec![], 0, 0, 2, code);
+
+		CopyPropagate.run(&mut func);
+
+		let Statement::Block(nested) = &func.code().code()[1] else {
+			unreachable!("nested block should be kept");
+		};
+		let Statement::SetTemporary(set) = &nested.code()[0] else {
+			unreachable!("nested statement should be kept");
+		};
+
+		assert!(matches!(set.value(), Expression::GetTemporary(temporary) if temporary.var() == 0));
+	}
+}
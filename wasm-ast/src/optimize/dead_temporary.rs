@@ -0,0 +1,246 @@
+use std::collections::HashSet;
+
+use crate::{
+	node::{Block, FuncData, Statement, Terminator},
+	stack::Trap,
+};
+
+use super::{
+	access::{ReadList, WriteList},
+	Pass,
+};
+
+// Removes stores to temporaries that are never read, as long as
+// computing the stored value cannot trap
+pub struct DeadTemporary;
+
+fn kill_set(statement: &Statement) -> HashSet<usize> {
+	match statement {
+		Statement::Block(_) | Statement::If(_) | Statement::Try(_) | Statement::BrIf(_) => {
+			HashSet::new()
+		}
+		_ => WriteList::run(statement).temporary_set,
+	}
+}
+
+// Walks the statements that run in order after a store, returning whether
+// `var` is read before being overwritten. Branches leaving the list only
+// carry what their alignment reads, but inside a nested block a branch to
+// its own end could skip an overwrite, so those stop being trusted
+fn find_first_use(
+	code: &[Statement],
+	last: Option<&Terminator>,
+	var: usize,
+	mut trust_kill: bool,
+	is_nested: bool,
+) -> Option<bool> {
+	for statement in code {
+		let result = if let Statement::Block(v) = statement {
+			find_first_use(v.code(), v.last(), var, trust_kill, true)
+		} else if ReadList::run(statement).contains(&var) {
+			Some(true)
+		} else if trust_kill && kill_set(statement).contains(&var) {
+			Some(false)
+		} else {
+			None
+		};
+
+		if result.is_some() {
+			return result;
+		}
+
+		trust_kill &= !is_nested
+			|| !matches!(
+				statement,
+				Statement::Block(_) | Statement::If(_) | Statement::Try(_) | Statement::BrIf(_)
+			);
+	}
+
+	last.filter(|v| ReadList::run(*v).contains(&var))
+		.map(|_| true)
+}
+
+fn is_dead(
+	statement: &Statement,
+	rest: &[Statement],
+	last: Option<&Terminator>,
+	read_set: &HashSet<usize>,
+	is_nested: bool,
+) -> bool {
+	let Statement::SetTemporary(set) = statement else {
+		return false;
+	};
+
+	let var = set.var().var();
+	let is_read =
+		find_first_use(rest, last, var, true, is_nested).unwrap_or_else(|| read_set.contains(&var));
+
+	if is_read {
+		return false;
+	}
+
+	!Trap::run(set.value())
+}
+
+fn remove_dead(block: &mut Block, read_set: &HashSet<usize>, is_nested: bool) -> bool {
+	let mut changed = false;
+	let mut index = 0;
+
+	while index < block.code().len() {
+		let (statement, rest) = block.code()[index..].split_first().unwrap();
+
+		if is_dead(statement, rest, block.last(), read_set, is_nested) {
+			block.code_mut().remove(index);
+			changed = true;
+		} else {
+			index += 1;
+		}
+	}
+
+	for statement in block.code_mut() {
+		match statement {
+			Statement::Block(v) => changed |= remove_dead(v, read_set, true),
+			Statement::If(v) => {
+				changed |= remove_dead(v.on_true_mut(), read_set, true);
+
+				if let Some(v) = v.on_false_mut() {
+					changed |= remove_dead(v, read_set, true);
+				}
+			}
+			Statement::Try(v) => {
+				changed |= remove_dead(v.body_mut(), read_set, true);
+
+				for v in v.catch_list_mut() {
+					changed |= remove_dead(v.block_mut(), read_set, true);
+				}
+
+				if let Some(v) = v.catch_all_mut() {
+					changed |= remove_dead(v, read_set, true);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	changed
+}
+
+impl Pass for DeadTemporary {
+	fn run(&mut self, func: &mut FuncData) {
+		loop {
+			let mut read_set = ReadList::run(func);
+
+			read_set.extend(0..func.num_result());
+
+			if !remove_dead(func.code_mut(), &read_set, false) {
+				break;
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::node::{
+		Align, BinOp, BinOpType, Block, Br, BrIf, Expression, FuncData, LabelType, Local, SetLocal,
+		SetTemporary, Statement, Temporary, Value,
+	};
+
+	use super::{DeadTemporary, Pass};
+
+	fn set_temporary(var: usize, value: i32) -> Statement {
+		let value = Expression::Value(Value::I32(value));
+
+		Statement::SetTemporary(SetTemporary::new(Temporary::new(var), value.into()))
+	}
+
+	fn read_temporary(var: usize) -> Statement {
+		let value = Expression::GetTemporary(Temporary::new(var));
+
+		Statement::SetLocal(SetLocal::new(Local::new(0), value.into()))
+	}
+
+	fn br_if_local() -> Statement {
+		let condition = Expression::GetLocal(Local::new(0));
+
+		Statement::BrIf(BrIf::new(condition.into(), Br::new(0, Align::new(0, 0, 0))))
+	}
+
+	fn eliminate(code: Vec<Statement>) -> FuncData {
+		let code = Block::new(None, code, None);
+		let mut func = FuncData::new(vec![], 0, 0, 2, code);
+
+		DeadTemporary.run(&mut func);
+
+		func
+	}
+
+	// Collects the constants still stored to `var`, in order, with stores
+	// that are not constants shown as `i32::MIN`
+	fn stored_value(code: &[Statement], var: usize) -> Vec<i32> {
+		code.iter()
+			.flat_map(|statement| match statement {
+				Statement::Block(v) => stored_value(v.code(), var),
+				Statement::SetTemporary(v) if v.var().var() == var => match *v.value() {
+					Expression::Value(Value::I32(value)) => vec![value],
+					_ => vec![i32::MIN],
+				},
+				_ => Vec::new(),
+			})
+			.collect()
+	}
+
+	#[test]
+	fn removes_overwritten_store() {
+		let func = eliminate(vec![
+			set_temporary(0, 1),
+			set_temporary(0, 2),
+			read_temporary(0),
+		]);
+
+		assert_eq!(stored_value(func.code().code(), 0), [2]);
+	}
+
+	#[test]
+	fn keeps_store_read_after_br_if() {
+		let nested = Block::new(
+			Some(LabelType::Forward),
+			vec![set_temporary(0, 1), br_if_local(), set_temporary(0, 2)],
+			None,
+		);
+		let func = eliminate(vec![Statement::Block(nested), read_temporary(0)]);
+
+		assert_eq!(stored_value(func.code().code(), 0), [1, 2]);
+	}
+
+	#[test]
+	fn keeps_store_read_by_next_iteration() {
+		let nested = Block::new(
+			Some(LabelType::Backward),
+			vec![
+				read_temporary(0),
+				set_temporary(0, 1),
+				br_if_local(),
+				set_temporary(0, 2),
+			],
+			None,
+		);
+		let func = eliminate(vec![set_temporary(0, 0), Statement::Block(nested)]);
+
+		assert_eq!(stored_value(func.code().code(), 0), [0, 1, 2]);
+	}
+
+	#[test]
+	fn keeps_trapping_store() {
+		let div = BinOp::new(
+			BinOpType::DivS_I32,
+			Expression::Value(Value::I32(1)).into(),
+			Expression::Value(Value::I32(0)).into(),
+		);
+		let set = SetTemporary::new(Temporary::new(1), Expression::BinOp(div).into());
+		let func = eliminate(vec![Statement::SetTemporary(set), set_temporary(0, 1)]);
+
+		assert_eq!(stored_value(func.code().code(), 1), [i32::MIN]);
+		assert!(stored_value(func.code().code(), 0).is_empty());
+	}
+}
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+
+use wasmparser::ValType;
+
+use crate::{
+	module::TypeInfo,
+	node::{
+		AtomicWait, Block, Br, BrIf, BrTable, Call, Expression, FuncData, Local, MemoryGrow,
+		SetLocal, SetTemporary, Statement, TableGet, TableGrow, TableSize, Temporary, Terminator,
+		Try, Value,
+	},
+	visit::{Driver, DriverMut, Visitor, VisitorMut},
+};
+
+use super::Pass;
+
+// Functions are small enough to inline when their body has at most
+// this many expressions, statements, and terminators
+pub const DEFAULT_MAX_SIZE: usize = 24;
+
+#[derive(Default)]
+struct Size {
+	result: usize,
+	has_call: bool,
+}
+
+impl Visitor for Size {
+	fn visit_expression(&mut self, _: &Expression) {
+		self.result += 1;
+	}
+
+	fn visit_terminator(&mut self, terminator: &Terminator) {
+		self.result += 1;
+		self.has_call |= matches!(
+			terminator,
+			Terminator::ReturnCall(_) | Terminator::ReturnCallIndirect(_)
+		);
+	}
+
+	fn visit_statement(&mut self, statement: &Statement) {
+		self.result += 1;
+		self.has_call |= matches!(statement, Statement::Call(_) | Statement::CallIndirect(_));
+	}
+}
+
+// Moves the locals and temporaries of an inlined body past those of
+// the function it is placed in
+struct Remap {
+	local: usize,
+	temporary: usize,
+}
+
+impl Remap {
+	fn remap_align(&self, br: &mut Br) {
+		br.align.new += self.temporary;
+		br.align.old += self.temporary;
+	}
+
+	fn remap_result(&self, result: &mut Temporary) {
+		result.var += self.temporary;
+	}
+}
+
+impl VisitorMut for Remap {
+	fn visit_get_temporary_mut(&mut self, temporary: &mut Temporary) {
+		self.remap_result(temporary);
+	}
+
+	fn visit_get_local_mut(&mut self, local: &mut Local) {
+		local.var += self.local;
+	}
+
+	fn visit_br_mut(&mut self, br: &mut Br) {
+		self.remap_align(br);
+	}
+
+	fn visit_br_table_mut(&mut self, br_table: &mut BrTable) {
+		for br in &mut br_table.data {
+			self.remap_align(br);
+		}
+
+		self.remap_align(&mut br_table.default);
+	}
+
+	fn visit_br_if_mut(&mut self, br_if: &mut BrIf) {
+		self.remap_align(&mut br_if.target);
+	}
+
+	fn visit_try_mut(&mut self, try_: &mut Try) {
+		for catch in &mut try_.catch_list {
+			catch.payload.start += self.temporary;
+			catch.payload.end += self.temporary;
+		}
+	}
+
+	fn visit_set_temporary_mut(&mut self, set: &mut SetTemporary) {
+		self.remap_result(&mut set.var);
+	}
+
+	fn visit_set_local_mut(&mut self, set: &mut SetLocal) {
+		set.var.var += self.local;
+	}
+
+	fn visit_memory_grow_mut(&mut self, memory_grow: &mut MemoryGrow) {
+		self.remap_result(&mut memory_grow.result);
+	}
+
+	fn visit_atomic_wait_mut(&mut self, atomic_wait: &mut AtomicWait) {
+		self.remap_result(&mut atomic_wait.result);
+	}
+
+	fn visit_table_get_mut(&mut self, table_get: &mut TableGet) {
+		self.remap_result(&mut table_get.result);
+	}
+
+	fn visit_table_size_mut(&mut self, table_size: &mut TableSize) {
+		self.remap_result(&mut table_size.result);
+	}
+
+	fn visit_table_grow_mut(&mut self, table_grow: &mut TableGrow) {
+		self.remap_result(&mut table_grow.result);
+	}
+}
+
+fn zero_of(ty: ValType) -> Value {
+	match ty {
+		ValType::I32 => Value::I32(0),
+		ValType::I64 => Value::I64(0),
+		ValType::F32 => Value::F32(0.0),
+		ValType::F64 => Value::F64(0.0),
+		ValType::V128 => Value::V128(0),
+		ValType::Ref(ty) => Value::RefNull(ty.heap_type()),
+	}
+}
+
+struct Callee {
+	param_list: Vec<ValType>,
+	func: FuncData,
+}
+
+// Locals given to each callee in the function being rewritten. Every
+// inlined call sets all of them first, so calls to the same function
+// can share them
+#[derive(Default)]
+struct Frame {
+	local_map: HashMap<usize, usize>,
+	num_local: usize,
+	num_stack: usize,
+	local_data: Vec<ValType>,
+	max_stack: usize,
+}
+
+// Replaces calls to small functions that make no calls of their own
+// with a copy of their body, so no callee can ever reach back into the
+// function being rewritten
+pub struct Inline {
+	callee_map: HashMap<usize, Callee>,
+}
+
+impl Inline {
+	/// Collects the functions of `func_list` that can be inlined, where
+	/// the first one has the index `offset` and no body may be larger
+	/// than `max_size`.
+	#[must_use]
+	pub fn from_func_list(
+		type_info: &TypeInfo,
+		offset: usize,
+		func_list: &[FuncData],
+		max_size: usize,
+	) -> Self {
+		let callee_map = func_list
+			.iter()
+			.enumerate()
+			.filter(|(_, func)| {
+				let mut size = Size::default();
+
+				func.code().accept(&mut size);
+
+				!size.has_call && size.result <= max_size
+			})
+			.map(|(index, func)| {
+				let index = index + offset;
+				let callee = Callee {
+					param_list: type_info.by_func_index_param_list(index).to_vec(),
+					func: func.clone(),
+				};
+
+				(index, callee)
+			})
+			.collect();
+
+		Self { callee_map }
+	}
+
+	fn inline_call(call: Call, callee: &Callee, frame: &mut Frame) -> Statement {
+		let local = *frame.local_map.entry(call.function).or_insert_with(|| {
+			let local = frame.num_local + frame.local_data.len();
+
+			frame.local_data.extend_from_slice(&callee.param_list);
+			frame.local_data.extend_from_slice(callee.func.local_data());
+
+			local
+		});
+
+		let mut remap = Remap {
+			local,
+			temporary: frame.num_stack,
+		};
+
+		let mut code = callee.func.code().clone();
+
+		code.accept_mut(&mut remap);
+
+		frame.max_stack = frame.max_stack.max(callee.func.num_stack());
+
+		let code_offset = call.code_offset;
+		let zero_list = callee
+			.func
+			.local_data()
+			.iter()
+			.map(|ty| Expression::Value(zero_of(*ty)));
+		let value_list = call.param_list.into_iter().chain(zero_list);
+
+		let mut list: Vec<_> = value_list
+			.enumerate()
+			.map(|(i, value)| {
+				Statement::SetLocal(SetLocal {
+					var: Local::new(local + i),
+					value: value.into(),
+					code_offset,
+				})
+			})
+			.collect();
+
+		list.push(Statement::Block(code));
+
+		let result_list = call.result_list.iter().enumerate().map(|(i, var)| {
+			Statement::SetTemporary(SetTemporary {
+				var,
+				value: Expression::GetTemporary(Temporary::new(remap.temporary + i)).into(),
+				code_offset,
+			})
+		});
+
+		list.extend(result_list);
+
+		Statement::Block(Block::new(None, list, None))
+	}
+
+	fn inline_block(&self, block: &mut Block, frame: &mut Frame) {
+		for statement in block.code_mut() {
+			match statement {
+				Statement::Block(v) => self.inline_block(v, frame),
+				Statement::If(v) => {
+					self.inline_block(v.on_true_mut(), frame);
+
+					if let Some(v) = v.on_false_mut() {
+						self.inline_block(v, frame);
+					}
+				}
+				Statement::Try(v) => {
+					self.inline_block(v.body_mut(), frame);
+
+					for v in v.catch_list_mut() {
+						self.inline_block(v.block_mut(), frame);
+					}
+
+					if let Some(v) = v.catch_all_mut() {
+						self.inline_block(v, frame);
+					}
+				}
+				Statement::Call(v) => {
+					let Some(callee) = self.callee_map.get(&v.function) else {
+						continue;
+					};
+
+					let Statement::Call(call) =
+						std::mem::replace(statement, Statement::Block(Block::default()))
+					else {
+						unreachable!()
+					};
+
+					*statement = Self::inline_call(call, callee, frame);
+				}
+				_ => {}
+			}
+		}
+	}
+}
+
+impl Pass for Inline {
+	fn run(&mut self, func: &mut FuncData) {
+		let mut frame = Frame {
+			num_local: func.num_param() + func.local_data().len(),
+			num_stack: func.num_stack(),
+			..Frame::default()
+		};
+
+		self.inline_block(func.code_mut(), &mut frame);
+
+		func.local_data_mut().append(&mut frame.local_data);
+		*func.num_stack_mut() += frame.max_stack;
+	}
+}
@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+
+use crate::cfg::{BasicBlock, Branch, Cfg, EXIT};
+
+use super::access::{ReadList, WriteList};
+
+// A statement, or the branch ending a node. Branches only move their
+// values when taken, so what they write does not end any live range
+pub struct Step {
+	pub read_set: HashSet<usize>,
+	pub write_set: HashSet<usize>,
+	pub is_kill: bool,
+}
+
+impl Step {
+	fn list_of(node: &BasicBlock) -> Vec<Self> {
+		let mut list: Vec<_> = node
+			.code()
+			.iter()
+			.map(|&v| Self {
+				read_set: ReadList::run(v),
+				write_set: WriteList::run(v).temporary_set,
+				is_kill: true,
+			})
+			.collect();
+
+		let last = match node.last() {
+			Some(Branch::BrIf(v)) => (ReadList::run(v), WriteList::run(v).temporary_set),
+			Some(Branch::If(v)) => (ReadList::run(v.condition()), HashSet::new()),
+			Some(Branch::Terminator(v)) => (ReadList::run(v), WriteList::run(v).temporary_set),
+			None => return list,
+		};
+
+		list.push(Self {
+			read_set: last.0,
+			write_set: last.1,
+			is_kill: false,
+		});
+
+		list
+	}
+}
+
+// Temporaries live on entry to every node, found by iterating backwards
+// until nothing changes. Unreachable nodes are kept in, so their code
+// is treated as if it could run.
+pub struct Liveness {
+	step_list: Vec<Vec<Step>>,
+	live_in_list: Vec<HashSet<usize>>,
+}
+
+impl Liveness {
+	pub fn from_cfg(cfg: &Cfg, num_result: usize) -> Self {
+		let mut liveness = Self {
+			step_list: cfg.node_list().iter().map(Step::list_of).collect(),
+			live_in_list: vec![HashSet::new(); cfg.len()],
+		};
+
+		liveness.live_in_list[EXIT].extend(0..num_result);
+
+		let mut has_changed = true;
+
+		while has_changed {
+			has_changed = false;
+
+			for id in (0..cfg.len()).rev().filter(|&v| v != EXIT) {
+				let live_in = liveness.scan(cfg, id, |_, _| {});
+
+				// Sets only ever grow, so comparing sizes is enough
+				if live_in.len() != liveness.live_in_list[id].len() {
+					liveness.live_in_list[id] = live_in;
+					has_changed = true;
+				}
+			}
+		}
+
+		liveness
+	}
+
+	/// Walks the steps of the node backwards, passing each one along with
+	/// the temporaries live right after it, and returns those live on entry.
+	pub fn scan<F>(&self, cfg: &Cfg, id: usize, mut func: F) -> HashSet<usize>
+	where
+		F: FnMut(&Step, &HashSet<usize>),
+	{
+		let node = &cfg.node_list()[id];
+		let get_live_in = |list: &[usize]| -> HashSet<usize> {
+			list.iter()
+				.flat_map(|&v| &self.live_in_list[v])
+				.copied()
+				.collect()
+		};
+
+		// Any step may throw into a handler, which can read what it likes
+		let handler_set = get_live_in(node.handler_list());
+		let mut live = get_live_in(node.successor_list());
+
+		live.extend(&handler_set);
+
+		for step in self.step_list[id].iter().rev() {
+			func(step, &live);
+
+			if step.is_kill {
+				live.retain(|v| !step.write_set.contains(v));
+			}
+
+			live.extend(&step.read_set);
+			live.extend(&handler_set);
+		}
+
+		live
+	}
+}
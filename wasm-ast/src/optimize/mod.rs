@@ -0,0 +1,99 @@
+pub mod constant_fold;
+pub mod copy_propagate;
+pub mod dead_temporary;
+pub mod inline;
+pub mod reuse_temporary;
+pub mod use_count;
+
+mod access;
+mod liveness;
+
+use crate::{module::TypeInfo, node::FuncData};
+
+use self::{
+	constant_fold::ConstantFold,
+	copy_propagate::CopyPropagate,
+	dead_temporary::DeadTemporary,
+	inline::{Inline, DEFAULT_MAX_SIZE},
+	reuse_temporary::ReuseTemporary,
+};
+
+pub trait Pass {
+	fn run(&mut self, func: &mut FuncData);
+}
+
+#[derive(Default)]
+pub struct PassManager {
+	pass_list: Vec<Box<dyn Pass>>,
+}
+
+impl PassManager {
+	// Folding runs again after propagation, since copied constants
+	// often end up as the operands of another operation. Slots are only
+	// reused at the end, once the dead temporaries are gone
+	#[must_use]
+	pub fn standard() -> Self {
+		let mut manager = Self::default();
+
+		manager.add(ConstantFold);
+		manager.add(CopyPropagate);
+		manager.add(ConstantFold);
+		manager.add(DeadTemporary);
+		manager.add(ReuseTemporary);
+
+		manager
+	}
+
+	pub fn add<P: Pass + 'static>(&mut self, pass: P) {
+		self.pass_list.push(Box::new(pass));
+	}
+
+	pub fn run(&mut self, func: &mut FuncData) {
+		for pass in &mut self.pass_list {
+			pass.run(func);
+		}
+	}
+}
+
+/// Chooses what is done to the functions of a module before they are
+/// written. The default does nothing.
+#[derive(Clone, Copy, Default)]
+pub struct Settings {
+	/// Functions whose body is at most this large are inlined into their
+	/// callers, or none are with `None`.
+	pub inline_max_size: Option<usize>,
+
+	/// Whether the passes of [`PassManager::standard`] are run.
+	pub has_passes: bool,
+}
+
+impl Settings {
+	/// Inlines with [`DEFAULT_MAX_SIZE`] and runs the standard passes.
+	#[must_use]
+	pub const fn standard() -> Self {
+		Self {
+			inline_max_size: Some(DEFAULT_MAX_SIZE),
+			has_passes: true,
+		}
+	}
+
+	/// Applies the settings to `func_list`, where the first function has
+	/// the index `offset`.
+	pub fn run(&self, type_info: &TypeInfo, offset: usize, func_list: &mut [FuncData]) {
+		if let Some(max_size) = self.inline_max_size {
+			let mut inline = Inline::from_func_list(type_info, offset, func_list, max_size);
+
+			for func in &mut *func_list {
+				inline.run(func);
+			}
+		}
+
+		if self.has_passes {
+			let mut pass_manager = PassManager::standard();
+
+			for func in func_list {
+				pass_manager.run(func);
+			}
+		}
+	}
+}
@@ -0,0 +1,252 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::{
+	cfg::Cfg,
+	node::{
+		Align, AtomicWait, Br, BrIf, BrTable, Call, CallIndirect, FuncData, MemoryGrow, ResultList,
+		SetTemporary, TableGet, TableGrow, TableSize, Temporary, Try,
+	},
+	visit::{Driver, DriverMut, Visitor, VisitorMut},
+};
+
+use super::{
+	access::{ReadList, WriteList},
+	liveness::Liveness,
+	Pass,
+};
+
+// Gives temporaries that are never live at the same time the same slot,
+// which lowers `num_stack` and so the number of spilled registers.
+// Ranges of more than one temporary keep their slots, since they have to
+// stay contiguous, and so do exception payloads and the function results.
+pub struct ReuseTemporary;
+
+#[derive(Default)]
+struct FixedList {
+	temporary_set: HashSet<usize>,
+}
+
+impl FixedList {
+	fn insert_range(&mut self, range: ResultList) {
+		self.temporary_set.extend(range.iter().map(Temporary::var));
+	}
+
+	fn insert_wide(&mut self, range: ResultList) {
+		if range.end > range.start + 1 {
+			self.insert_range(range);
+		}
+	}
+
+	fn insert_align(&mut self, align: Align) {
+		self.insert_wide(align.new_range());
+		self.insert_wide(align.old_range());
+	}
+}
+
+impl Visitor for FixedList {
+	fn visit_br(&mut self, br: Br) {
+		self.insert_align(br.align());
+	}
+
+	fn visit_br_if(&mut self, br_if: &BrIf) {
+		self.insert_align(br_if.target().align());
+	}
+
+	fn visit_br_table(&mut self, br_table: &BrTable) {
+		for br in br_table.data() {
+			self.insert_align(br.align());
+		}
+
+		self.insert_align(br_table.default().align());
+	}
+
+	// Payloads are written on entry to a handler, which is not a step the
+	// liveness sees, so they can't be moved safely
+	fn visit_try(&mut self, try_catch: &Try) {
+		for catch in try_catch.catch_list() {
+			self.insert_range(catch.payload());
+		}
+	}
+
+	fn visit_call(&mut self, call: &Call) {
+		self.insert_wide(call.result_list());
+	}
+
+	fn visit_call_indirect(&mut self, call_indirect: &CallIndirect) {
+		self.insert_wide(call_indirect.result_list());
+	}
+}
+
+struct Rename {
+	slot_map: HashMap<usize, usize>,
+}
+
+impl Rename {
+	fn rename(&self, temporary: &mut Temporary) {
+		if let Some(&var) = self.slot_map.get(&temporary.var) {
+			temporary.var = var;
+		}
+	}
+
+	// Only single temporaries are ever moved, so wider ranges are left as is
+	fn rename_range(&self, range: &mut ResultList) {
+		if range.end != range.start + 1 {
+			return;
+		}
+
+		if let Some(&var) = self.slot_map.get(&range.start) {
+			*range = ResultList::new(var, var + 1);
+		}
+	}
+
+	fn rename_align(&self, align: &mut Align) {
+		if align.length != 1 {
+			return;
+		}
+
+		if let Some(&var) = self.slot_map.get(&align.new) {
+			align.new = var;
+		}
+
+		if let Some(&var) = self.slot_map.get(&align.old) {
+			align.old = var;
+		}
+	}
+}
+
+impl VisitorMut for Rename {
+	fn visit_br_mut(&mut self, br: &mut Br) {
+		self.rename_align(&mut br.align);
+	}
+
+	fn visit_br_if_mut(&mut self, br_if: &mut BrIf) {
+		self.rename_align(&mut br_if.target.align);
+	}
+
+	fn visit_br_table_mut(&mut self, br_table: &mut BrTable) {
+		for br in &mut br_table.data {
+			self.rename_align(&mut br.align);
+		}
+
+		self.rename_align(&mut br_table.default.align);
+	}
+
+	fn visit_call_mut(&mut self, call: &mut Call) {
+		self.rename_range(&mut call.result_list);
+	}
+
+	fn visit_call_indirect_mut(&mut self, call_indirect: &mut CallIndirect) {
+		self.rename_range(&mut call_indirect.result_list);
+	}
+
+	fn visit_get_temporary_mut(&mut self, temporary: &mut Temporary) {
+		self.rename(temporary);
+	}
+
+	fn visit_set_temporary_mut(&mut self, set_temporary: &mut SetTemporary) {
+		self.rename(&mut set_temporary.var);
+	}
+
+	fn visit_memory_grow_mut(&mut self, memory_grow: &mut MemoryGrow) {
+		self.rename(&mut memory_grow.result);
+	}
+
+	fn visit_atomic_wait_mut(&mut self, atomic_wait: &mut AtomicWait) {
+		self.rename(&mut atomic_wait.result);
+	}
+
+	fn visit_table_get_mut(&mut self, table_get: &mut TableGet) {
+		self.rename(&mut table_get.result);
+	}
+
+	fn visit_table_size_mut(&mut self, table_size: &mut TableSize) {
+		self.rename(&mut table_size.result);
+	}
+
+	fn visit_table_grow_mut(&mut self, table_grow: &mut TableGrow) {
+		self.rename(&mut table_grow.result);
+	}
+}
+
+// Every temporary written while another is live interferes with it
+fn find_interference(func: &FuncData) -> HashMap<usize, HashSet<usize>> {
+	let cfg = Cfg::from_func(func);
+	let liveness = Liveness::from_cfg(&cfg, func.num_result());
+	let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+
+	for id in 0..cfg.len() {
+		liveness.scan(&cfg, id, |step, live| {
+			for &var in &step.write_set {
+				let other_list = live.iter().chain(&step.write_set);
+
+				for &other in other_list.filter(|&&v| v != var) {
+					graph.entry(var).or_default().insert(other);
+					graph.entry(other).or_default().insert(var);
+				}
+			}
+		});
+	}
+
+	graph
+}
+
+// Slots are handed out greedily in order, each taking the lowest one not
+// held by a fixed or already placed temporary it interferes with
+fn find_slot_map(func: &FuncData) -> HashMap<usize, usize> {
+	let graph = find_interference(func);
+	let mut fixed_list = FixedList::default();
+
+	func.accept(&mut fixed_list);
+	fixed_list.temporary_set.extend(0..func.num_result());
+
+	let fixed_set = fixed_list.temporary_set;
+	let var_set: BTreeSet<_> = ReadList::run(func)
+		.into_iter()
+		.chain(WriteList::run(func).temporary_set)
+		.filter(|v| !fixed_set.contains(v))
+		.collect();
+
+	let mut slot_map = HashMap::new();
+
+	for var in var_set {
+		let taken: HashSet<_> = graph
+			.get(&var)
+			.into_iter()
+			.flatten()
+			.filter_map(|v| {
+				if fixed_set.contains(v) {
+					Some(*v)
+				} else {
+					slot_map.get(v).copied()
+				}
+			})
+			.collect();
+
+		let slot = (0..).find(|v| !taken.contains(v)).unwrap();
+
+		slot_map.insert(var, slot);
+	}
+
+	slot_map
+}
+
+impl Pass for ReuseTemporary {
+	fn run(&mut self, func: &mut FuncData) {
+		let slot_map = find_slot_map(func);
+		let mut fixed_list = FixedList::default();
+
+		func.accept_mut(&mut Rename { slot_map });
+		func.accept(&mut fixed_list);
+
+		let num_stack = ReadList::run(func)
+			.into_iter()
+			.chain(WriteList::run(func).temporary_set)
+			.chain(fixed_list.temporary_set)
+			.map(|v| v + 1)
+			.max()
+			.unwrap_or_default()
+			.max(func.num_result());
+
+		*func.num_stack_mut() = num_stack.min(func.num_stack());
+	}
+}
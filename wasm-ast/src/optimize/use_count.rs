@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use crate::{
+	cfg::{
+		dominator::{Dominators, LoopNest},
+		Branch, Cfg,
+	},
+	node::{
+		Align, AtomicWait, Br, BrIf, BrTable, Call, CallIndirect, FuncData, Local, MemoryGrow,
+		ResultList, SetLocal, SetTemporary, TableGet, TableGrow, TableSize, Temporary,
+	},
+	visit::{Driver, Visitor},
+};
+
+// Every loop a use is nested in makes it count this many times more
+const LOOP_WEIGHT: u64 = 8;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum Variable {
+	Temporary(usize),
+	Local(usize),
+}
+
+#[derive(Default)]
+struct Visit {
+	weight: u64,
+	count_map: HashMap<Variable, u64>,
+}
+
+impl Visit {
+	fn insert(&mut self, var: Variable) {
+		let count = self.count_map.entry(var).or_default();
+
+		*count = count.saturating_add(self.weight);
+	}
+
+	fn insert_range(&mut self, range: ResultList) {
+		for var in range.iter() {
+			self.insert(Variable::Temporary(var.var()));
+		}
+	}
+
+	fn insert_align(&mut self, align: Align) {
+		if !align.is_aligned() {
+			self.insert_range(align.new_range());
+			self.insert_range(align.old_range());
+		}
+	}
+}
+
+impl Visitor for Visit {
+	fn visit_get_temporary(&mut self, temporary: Temporary) {
+		self.insert(Variable::Temporary(temporary.var()));
+	}
+
+	fn visit_get_local(&mut self, local: Local) {
+		self.insert(Variable::Local(local.var()));
+	}
+
+	fn visit_br(&mut self, br: Br) {
+		self.insert_align(br.align());
+	}
+
+	fn visit_br_table(&mut self, br_table: &BrTable) {
+		for br in br_table.data() {
+			self.insert_align(br.align());
+		}
+
+		self.insert_align(br_table.default().align());
+	}
+
+	fn visit_br_if(&mut self, br_if: &BrIf) {
+		self.insert_align(br_if.target().align());
+	}
+
+	fn visit_call(&mut self, call: &Call) {
+		self.insert_range(call.result_list());
+	}
+
+	fn visit_call_indirect(&mut self, call_indirect: &CallIndirect) {
+		self.insert_range(call_indirect.result_list());
+	}
+
+	fn visit_set_temporary(&mut self, set_temporary: &SetTemporary) {
+		self.insert(Variable::Temporary(set_temporary.var().var()));
+	}
+
+	fn visit_set_local(&mut self, set_local: &SetLocal) {
+		self.insert(Variable::Local(set_local.var().var()));
+	}
+
+	fn visit_memory_grow(&mut self, memory_grow: &MemoryGrow) {
+		self.insert(Variable::Temporary(memory_grow.result().var()));
+	}
+
+	fn visit_atomic_wait(&mut self, atomic_wait: &AtomicWait) {
+		self.insert(Variable::Temporary(atomic_wait.result().var()));
+	}
+
+	fn visit_table_get(&mut self, table_get: &TableGet) {
+		self.insert(Variable::Temporary(table_get.result().var()));
+	}
+
+	fn visit_table_size(&mut self, table_size: TableSize) {
+		self.insert(Variable::Temporary(table_size.result().var()));
+	}
+
+	fn visit_table_grow(&mut self, table_grow: &TableGrow) {
+		self.insert(Variable::Temporary(table_grow.result().var()));
+	}
+}
+
+fn find_count_map(func: &FuncData) -> HashMap<Variable, u64> {
+	let cfg = Cfg::from_func(func);
+	let dominators = Dominators::from_cfg(&cfg);
+	let loop_nest = LoopNest::from_cfg(&cfg, &dominators);
+	let mut visit = Visit::default();
+
+	for (id, node) in cfg.node_list().iter().enumerate() {
+		let depth = loop_nest.loop_depth(id).try_into().unwrap_or(u32::MAX);
+
+		visit.weight = LOOP_WEIGHT.saturating_pow(depth);
+
+		for stat in node.code() {
+			stat.accept(&mut visit);
+		}
+
+		match node.last() {
+			Some(Branch::BrIf(v)) => v.accept(&mut visit),
+			Some(Branch::If(v)) => v.condition().accept(&mut visit),
+			Some(Branch::Terminator(v)) => v.accept(&mut visit),
+			None => {}
+		}
+	}
+
+	visit.count_map
+}
+
+/// Lists the declared locals and the temporaries of `func`, most used
+/// first, where a use nested in loops counts for more. Ties go to
+/// temporaries first and then to lower indices.
+#[must_use]
+pub fn by_use_count(func: &FuncData) -> Vec<Variable> {
+	let count_map = find_count_map(func);
+	let mut var_list: Vec<_> = (func.num_param()..func.num_param() + func.local_data().len())
+		.map(Variable::Local)
+		.chain((0..func.num_stack()).map(Variable::Temporary))
+		.collect();
+
+	var_list.sort_by_key(|v| {
+		let count = count_map.get(v).copied().unwrap_or_default();
+
+		(std::cmp::Reverse(count), *v)
+	});
+
+	var_list
+}
+
+#[cfg(test)]
+mod tests {
+	use wasmparser::ValType;
+
+	use crate::node::{
+		Align, Block, Br, BrIf, Expression, FuncData, LabelType, Local, SetLocal, Statement,
+		Temporary, Value,
+	};
+
+	use super::{by_use_count, Variable};
+
+	fn set_local(var: usize, value: Expression) -> Statement {
+		Statement::SetLocal(SetLocal::new(Local::new(var), value.into()))
+	}
+
+	#[test]
+	fn prefers_temporaries_then_lower_indices() {
+		let code = Block::new(None, Vec::new(), None);
+		let func = FuncData::new(vec![ValType::I32; 2], 0, 1, 2, code);
+
+		assert_eq!(
+			by_use_count(&func),
+			[
+				Variable::Temporary(0),
+				Variable::Temporary(1),
+				Variable::Local(1),
+				Variable::Local(2),
+			]
+		);
+	}
+
+	#[test]
+	fn prefers_variable_used_in_loop() {
+		let condition = Expression::GetLocal(Local::new(1));
+		let body = Block::new(
+			Some(LabelType::Backward),
+			vec![
+				set_local(1, Expression::GetTemporary(Temporary::new(0))),
+				Statement::BrIf(BrIf::new(condition.into(), Br::new(0, Align::new(0, 0, 0)))),
+			],
+			None,
+		);
+		let code = Block::new(
+			None,
+			vec![
+				set_local(0, Expression::Value(Value::I32(1))),
+				set_local(0, Expression::Value(Value::I32(2))),
+				set_local(0, Expression::GetLocal(Local::new(0))),
+				Statement::Block(body),
+			],
+			None,
+		);
+		let func = FuncData::new(vec![ValType::I32; 2], 0, 0, 1, code);
+		let var_list = by_use_count(&func);
+
+		assert_eq!(var_list[0], Variable::Local(1));
+		assert_eq!(var_list[2], Variable::Local(0));
+	}
+}
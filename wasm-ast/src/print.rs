@@ -0,0 +1,729 @@
+use std::io::{Result, Write};
+
+use crate::node::{
+	Align, AtomicWait, BinOp, BitSelect, Block, Br, BrIf, BrTable, Call, CallIndirect, CmpOp,
+	DataDrop, ElemDrop, Expression, ExtractLane, FuncData, GetGlobal, If, IndexType, LabelType,
+	LoadAt, Local, MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemorySize, RefIsNull,
+	ReplaceLane, ResultList, Rethrow, ReturnCall, ReturnCallIndirect, Select, SetGlobal, SetLocal,
+	SetTemporary, Shuffle, Statement, StoreAt, TableCopy, TableFill, TableGet, TableGrow,
+	TableInit, TableSet, TableSize, Temporary, Terminator, Throw, Try, UnOp, Value,
+};
+
+// Labels are numbered by depth, so `@0` is always the function body and
+// a branch prints the label it lands on rather than its relative target
+#[derive(Default)]
+pub struct Printer {
+	indentation: usize,
+	label_depth: usize,
+}
+
+impl Printer {
+	fn indent(&mut self) {
+		self.indentation += 1;
+	}
+
+	fn dedent(&mut self) {
+		self.indentation -= 1;
+	}
+
+	fn write_indentation(&self, w: &mut dyn Write) -> Result<()> {
+		(0..self.indentation).try_for_each(|_| write!(w, "\t"))
+	}
+
+	// Nodes printed on their own may branch past the labels we know of
+	fn write_label(&self, target: usize, w: &mut dyn Write) -> Result<()> {
+		match self.label_depth.checked_sub(target + 1) {
+			Some(label) => write!(w, "@{label}"),
+			None => write!(w, "@outer_{}", target + 1 - self.label_depth),
+		}
+	}
+}
+
+pub trait Print {
+	/// # Errors
+	/// Returns `Err` if writing to `Write` failed.
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()>;
+}
+
+fn print_separated<T: Print>(list: &[T], printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+	list.iter().enumerate().try_for_each(|(i, v)| {
+		if i != 0 {
+			write!(w, ", ")?;
+		}
+
+		v.print(printer, w)
+	})
+}
+
+fn print_call<T: Print>(list: &[T], printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+	write!(w, "(")?;
+	print_separated(list, printer, w)?;
+	write!(w, ")")
+}
+
+fn write_memory(memory: usize, index_type: IndexType, w: &mut dyn Write) -> Result<()> {
+	match index_type {
+		IndexType::I32 => write!(w, "memory {memory}"),
+		IndexType::I64 => write!(w, "memory {memory} i64"),
+	}
+}
+
+impl Print for Select {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "select")?;
+		print_call(
+			&[self.condition(), self.on_true(), self.on_false()],
+			printer,
+			w,
+		)
+	}
+}
+
+impl Print for Temporary {
+	fn print(&self, _: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "t{}", self.var())
+	}
+}
+
+impl Print for Local {
+	fn print(&self, _: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "l{}", self.var())
+	}
+}
+
+impl Print for GetGlobal {
+	fn print(&self, _: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "g{}", self.var())
+	}
+}
+
+impl Print for LoadAt {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		let prefix = if self.is_atomic() { "atomic_" } else { "" };
+
+		write!(w, "{prefix}load_{:?}[", self.load_type())?;
+		write_memory(self.memory(), self.index_type(), w)?;
+		write!(w, ", offset {}]", self.offset())?;
+		print_call(&[self.pointer()], printer, w)
+	}
+}
+
+impl Print for MemorySize {
+	fn print(&self, _: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "memory_size[")?;
+		write_memory(self.memory(), self.index_type(), w)?;
+		write!(w, "]")
+	}
+}
+
+impl Print for Value {
+	fn print(&self, _: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		match self {
+			Self::I32(v) => write!(w, "i32 {v}"),
+			Self::I64(v) => write!(w, "i64 {v}"),
+			Self::F32(v) => write!(w, "f32 {v:?}"),
+			Self::F64(v) => write!(w, "f64 {v:?}"),
+			Self::RefNull(_) => write!(w, "ref_null"),
+			Self::RefFunc(v) => write!(w, "ref_func {v}"),
+			Self::V128(v) => write!(w, "v128 0x{v:032x}"),
+		}
+	}
+}
+
+impl Print for UnOp {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "{:?}", self.op_type())?;
+		print_call(&[self.rhs()], printer, w)
+	}
+}
+
+impl Print for BinOp {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "{:?}", self.op_type())?;
+		print_call(&[self.lhs(), self.rhs()], printer, w)
+	}
+}
+
+impl Print for CmpOp {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "{:?}", self.op_type())?;
+		print_call(&[self.lhs(), self.rhs()], printer, w)
+	}
+}
+
+impl Print for RefIsNull {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "ref_is_null")?;
+		print_call(&[self.value()], printer, w)
+	}
+}
+
+impl Print for ExtractLane {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(
+			w,
+			"extract_lane_{:?}[lane {}]",
+			self.lane_type(),
+			self.lane()
+		)?;
+		print_call(&[self.vector()], printer, w)
+	}
+}
+
+impl Print for ReplaceLane {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(
+			w,
+			"replace_lane_{:?}[lane {}]",
+			self.lane_type(),
+			self.lane()
+		)?;
+		print_call(&[self.vector(), self.value()], printer, w)
+	}
+}
+
+impl Print for Shuffle {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "shuffle{:?}", self.lane_list())?;
+		print_call(&[self.lhs(), self.rhs()], printer, w)
+	}
+}
+
+impl Print for BitSelect {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "bit_select")?;
+		print_call(
+			&[self.on_true(), self.on_false(), self.condition()],
+			printer,
+			w,
+		)
+	}
+}
+
+impl Print for Expression {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		match self {
+			Self::Select(v) => v.print(printer, w),
+			Self::GetTemporary(v) => v.print(printer, w),
+			Self::GetLocal(v) => v.print(printer, w),
+			Self::GetGlobal(v) => v.print(printer, w),
+			Self::LoadAt(v) => v.print(printer, w),
+			Self::MemorySize(v) => v.print(printer, w),
+			Self::Value(v) => v.print(printer, w),
+			Self::UnOp(v) => v.print(printer, w),
+			Self::BinOp(v) => v.print(printer, w),
+			Self::CmpOp(v) => v.print(printer, w),
+			Self::RefIsNull(v) => v.print(printer, w),
+			Self::ExtractLane(v) => v.print(printer, w),
+			Self::ReplaceLane(v) => v.print(printer, w),
+			Self::Shuffle(v) => v.print(printer, w),
+			Self::BitSelect(v) => v.print(printer, w),
+		}
+	}
+}
+
+impl<T: Print> Print for &T {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		(**self).print(printer, w)
+	}
+}
+
+impl Print for ResultList {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		let list: Vec<_> = self.iter().collect();
+
+		print_separated(&list, printer, w)
+	}
+}
+
+// Only moves that actually copy anything are shown
+impl Print for Align {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		if self.is_aligned() {
+			return Ok(());
+		}
+
+		write!(w, " [")?;
+		self.new_range().print(printer, w)?;
+		write!(w, " = ")?;
+		self.old_range().print(printer, w)?;
+		write!(w, "]")
+	}
+}
+
+impl Print for Br {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		printer.write_label(self.target(), w)?;
+		self.align().print(printer, w)
+	}
+}
+
+impl Print for BrTable {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "br_table ")?;
+		self.condition().print(printer, w)?;
+		write!(w, ", [")?;
+		print_separated(self.data(), printer, w)?;
+		write!(w, "], default ")?;
+		self.default().print(printer, w)
+	}
+}
+
+impl Print for ReturnCall {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "return_call f{}", self.function())?;
+		print_call(self.param_list(), printer, w)
+	}
+}
+
+impl Print for ReturnCallIndirect {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(
+			w,
+			"return_call_indirect[type {}, table {}, ",
+			self.ty(),
+			self.table()
+		)?;
+		self.index().print(printer, w)?;
+		write!(w, "]")?;
+		print_call(self.param_list(), printer, w)
+	}
+}
+
+impl Print for Throw {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "throw[tag {}]", self.tag())?;
+		print_call(self.param_list(), printer, w)
+	}
+}
+
+impl Print for Rethrow {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "rethrow ")?;
+		printer.write_label(self.target(), w)
+	}
+}
+
+impl Print for Terminator {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		printer.write_indentation(w)?;
+
+		match self {
+			Self::Unreachable(_) => write!(w, "unreachable")?,
+			Self::Br(v) => {
+				write!(w, "br ")?;
+				v.print(printer, w)?;
+			}
+			Self::BrTable(v) => v.print(printer, w)?,
+			Self::ReturnCall(v) => v.print(printer, w)?,
+			Self::ReturnCallIndirect(v) => v.print(printer, w)?,
+			Self::Throw(v) => v.print(printer, w)?,
+			Self::Rethrow(v) => v.print(printer, w)?,
+		}
+
+		writeln!(w)
+	}
+}
+
+impl Print for Block {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		let label = printer.label_depth;
+
+		match self.label_type() {
+			Some(LabelType::Forward) => writeln!(w, "block @{label} forward")?,
+			Some(LabelType::Backward) => writeln!(w, "block @{label} backward")?,
+			None => writeln!(w, "block @{label}")?,
+		}
+
+		printer.label_depth += 1;
+		printer.indent();
+
+		self.code().iter().try_for_each(|v| v.print(printer, w))?;
+
+		if let Some(v) = self.last() {
+			v.print(printer, w)?;
+		}
+
+		printer.dedent();
+		printer.label_depth -= 1;
+		printer.write_indentation(w)?;
+
+		write!(w, "end @{label}")
+	}
+}
+
+impl Print for BrIf {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "br_if ")?;
+		self.condition().print(printer, w)?;
+		write!(w, ", ")?;
+		self.target().print(printer, w)
+	}
+}
+
+impl Print for If {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "if ")?;
+		self.condition().print(printer, w)?;
+		writeln!(w)?;
+
+		printer.indent();
+		printer.write_indentation(w)?;
+		self.on_true().print(printer, w)?;
+		writeln!(w)?;
+		printer.dedent();
+
+		if let Some(v) = self.on_false() {
+			printer.write_indentation(w)?;
+			writeln!(w, "else")?;
+
+			printer.indent();
+			printer.write_indentation(w)?;
+			v.print(printer, w)?;
+			writeln!(w)?;
+			printer.dedent();
+		}
+
+		printer.write_indentation(w)?;
+		write!(w, "end")
+	}
+}
+
+impl Print for Try {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		writeln!(w, "try")?;
+
+		printer.indent();
+		printer.write_indentation(w)?;
+		self.body().print(printer, w)?;
+		writeln!(w)?;
+		printer.dedent();
+
+		for v in self.catch_list() {
+			printer.write_indentation(w)?;
+			write!(w, "catch[tag {}]", v.tag())?;
+
+			if !v.payload().is_empty() {
+				write!(w, " ")?;
+				v.payload().print(printer, w)?;
+			}
+
+			writeln!(w)?;
+
+			printer.indent();
+			printer.write_indentation(w)?;
+			v.block().print(printer, w)?;
+			writeln!(w)?;
+			printer.dedent();
+		}
+
+		if let Some(v) = self.catch_all() {
+			printer.write_indentation(w)?;
+			writeln!(w, "catch_all")?;
+
+			printer.indent();
+			printer.write_indentation(w)?;
+			v.print(printer, w)?;
+			writeln!(w)?;
+			printer.dedent();
+		}
+
+		printer.write_indentation(w)?;
+
+		match self.delegate() {
+			Some(target) => write!(w, "delegate {target}"),
+			None => write!(w, "end"),
+		}
+	}
+}
+
+fn print_result_list(list: ResultList, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+	if list.is_empty() {
+		return Ok(());
+	}
+
+	list.print(printer, w)?;
+	write!(w, " = ")
+}
+
+impl Print for Call {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		print_result_list(self.result_list(), printer, w)?;
+		write!(w, "call f{}", self.function())?;
+		print_call(self.param_list(), printer, w)
+	}
+}
+
+impl Print for CallIndirect {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		print_result_list(self.result_list(), printer, w)?;
+		write!(
+			w,
+			"call_indirect[type {}, table {}, ",
+			self.ty(),
+			self.table()
+		)?;
+		self.index().print(printer, w)?;
+		write!(w, "]")?;
+		print_call(self.param_list(), printer, w)
+	}
+}
+
+impl Print for SetTemporary {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		self.var().print(printer, w)?;
+		write!(w, " = ")?;
+		self.value().print(printer, w)
+	}
+}
+
+impl Print for SetLocal {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		self.var().print(printer, w)?;
+		write!(w, " = ")?;
+		self.value().print(printer, w)
+	}
+}
+
+impl Print for SetGlobal {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "g{} = ", self.var())?;
+		self.value().print(printer, w)
+	}
+}
+
+impl Print for StoreAt {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		let prefix = if self.is_atomic() { "atomic_" } else { "" };
+
+		write!(w, "{prefix}store_{:?}[", self.store_type())?;
+		write_memory(self.memory(), self.index_type(), w)?;
+		write!(w, ", offset {}]", self.offset())?;
+		print_call(&[self.pointer(), self.value()], printer, w)
+	}
+}
+
+impl Print for MemoryGrow {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		self.result().print(printer, w)?;
+		write!(w, " = memory_grow[")?;
+		write_memory(self.memory(), self.index_type(), w)?;
+		write!(w, "]")?;
+		print_call(&[self.size()], printer, w)
+	}
+}
+
+impl Print for AtomicWait {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		self.result().print(printer, w)?;
+		write!(w, " = atomic_wait(")?;
+		self.value().print(printer, w)?;
+		write!(w, ", ")?;
+		print_separated(&[self.expected(), self.timeout()], printer, w)?;
+		write!(w, ")")
+	}
+}
+
+impl Print for MemoryCopy {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		let destination = self.destination();
+		let source = self.source();
+
+		write!(w, "memory_copy[")?;
+		write_memory(destination.memory(), destination.index_type(), w)?;
+		write!(w, ", ")?;
+		write_memory(source.memory(), source.index_type(), w)?;
+		write!(w, "]")?;
+		print_call(
+			&[destination.pointer(), source.pointer(), self.size()],
+			printer,
+			w,
+		)
+	}
+}
+
+impl Print for MemoryFill {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		let destination = self.destination();
+
+		write!(w, "memory_fill[")?;
+		write_memory(destination.memory(), destination.index_type(), w)?;
+		write!(w, "]")?;
+		print_call(
+			&[destination.pointer(), self.value(), self.size()],
+			printer,
+			w,
+		)
+	}
+}
+
+impl Print for MemoryInit {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		let destination = self.destination();
+
+		write!(w, "memory_init[")?;
+		write_memory(destination.memory(), destination.index_type(), w)?;
+		write!(w, ", data {}]", self.data())?;
+		print_call(
+			&[destination.pointer(), self.offset(), self.size()],
+			printer,
+			w,
+		)
+	}
+}
+
+impl Print for DataDrop {
+	fn print(&self, _: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "data_drop[data {}]", self.data())
+	}
+}
+
+impl Print for TableGet {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		self.result().print(printer, w)?;
+		write!(w, " = table_get[table {}]", self.table())?;
+		print_call(&[self.index()], printer, w)
+	}
+}
+
+impl Print for TableSet {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "table_set[table {}]", self.table())?;
+		print_call(&[self.index(), self.value()], printer, w)
+	}
+}
+
+impl Print for TableSize {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		self.result().print(printer, w)?;
+		write!(w, " = table_size[table {}]", self.table())
+	}
+}
+
+impl Print for TableGrow {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		self.result().print(printer, w)?;
+		write!(w, " = table_grow[table {}]", self.table())?;
+		print_call(&[self.value(), self.size()], printer, w)
+	}
+}
+
+impl Print for TableFill {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		let destination = self.destination();
+
+		write!(w, "table_fill[table {}]", destination.table())?;
+		print_call(
+			&[destination.index(), self.value(), self.size()],
+			printer,
+			w,
+		)
+	}
+}
+
+impl Print for TableCopy {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		let destination = self.destination();
+		let source = self.source();
+
+		write!(
+			w,
+			"table_copy[table {}, table {}]",
+			destination.table(),
+			source.table()
+		)?;
+		print_call(
+			&[destination.index(), source.index(), self.size()],
+			printer,
+			w,
+		)
+	}
+}
+
+impl Print for TableInit {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		let destination = self.destination();
+
+		write!(
+			w,
+			"table_init[table {}, element {}]",
+			destination.table(),
+			self.element()
+		)?;
+		print_call(
+			&[destination.index(), self.offset(), self.size()],
+			printer,
+			w,
+		)
+	}
+}
+
+impl Print for ElemDrop {
+	fn print(&self, _: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		write!(w, "elem_drop[element {}]", self.element())
+	}
+}
+
+impl Print for Statement {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		printer.write_indentation(w)?;
+
+		match self {
+			Self::Block(v) => v.print(printer, w)?,
+			Self::BrIf(v) => v.print(printer, w)?,
+			Self::If(v) => v.print(printer, w)?,
+			Self::Call(v) => v.print(printer, w)?,
+			Self::CallIndirect(v) => v.print(printer, w)?,
+			Self::SetTemporary(v) => v.print(printer, w)?,
+			Self::SetLocal(v) => v.print(printer, w)?,
+			Self::SetGlobal(v) => v.print(printer, w)?,
+			Self::StoreAt(v) => v.print(printer, w)?,
+			Self::MemoryGrow(v) => v.print(printer, w)?,
+			Self::AtomicWait(v) => v.print(printer, w)?,
+			Self::MemoryCopy(v) => v.print(printer, w)?,
+			Self::MemoryFill(v) => v.print(printer, w)?,
+			Self::MemoryInit(v) => v.print(printer, w)?,
+			Self::DataDrop(v) => v.print(printer, w)?,
+			Self::TableGet(v) => v.print(printer, w)?,
+			Self::TableSet(v) => v.print(printer, w)?,
+			Self::TableSize(v) => v.print(printer, w)?,
+			Self::TableGrow(v) => v.print(printer, w)?,
+			Self::TableFill(v) => v.print(printer, w)?,
+			Self::TableCopy(v) => v.print(printer, w)?,
+			Self::TableInit(v) => v.print(printer, w)?,
+			Self::ElemDrop(v) => v.print(printer, w)?,
+			Self::Try(v) => v.print(printer, w)?,
+		}
+
+		writeln!(w)
+	}
+}
+
+impl Print for FuncData {
+	fn print(&self, printer: &mut Printer, w: &mut dyn Write) -> Result<()> {
+		printer.write_indentation(w)?;
+		writeln!(
+			w,
+			"function (param {}) (result {}) (stack {})",
+			self.num_param(),
+			self.num_result(),
+			self.num_stack()
+		)?;
+
+		printer.indent();
+
+		for (i, typ) in self.local_data().iter().enumerate() {
+			printer.write_indentation(w)?;
+			writeln!(w, "local l{} {typ}", self.num_param() + i)?;
+		}
+
+		printer.write_indentation(w)?;
+		self.code().print(printer, w)?;
+		writeln!(w)?;
+
+		printer.dedent();
+		printer.write_indentation(w)?;
+
+		writeln!(w, "end")
+	}
+}
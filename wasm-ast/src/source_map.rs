@@ -0,0 +1,187 @@
+use std::{
+	cell::RefCell,
+	io::{Result, Write},
+	rc::Rc,
+};
+
+use crate::dwarf::LineTable;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn write_vlq(magnitude: usize, is_negative: bool, w: &mut dyn Write) -> Result<()> {
+	// The sign is kept in the lowest bit, then 5 bits go in each digit
+	let mut rest = (magnitude << 1) | usize::from(is_negative);
+
+	loop {
+		let mut digit = rest & 0x1F;
+
+		rest >>= 5;
+
+		if rest != 0 {
+			digit |= 0x20;
+		}
+
+		w.write_all(&[BASE64[digit]])?;
+
+		if rest == 0 {
+			break Ok(());
+		}
+	}
+}
+
+fn write_json_string(data: &str, w: &mut dyn Write) -> Result<()> {
+	write!(w, "\"")?;
+
+	for c in data.chars() {
+		match c {
+			'"' => write!(w, "\\\""),
+			'\\' => write!(w, "\\\\"),
+			'\n' => write!(w, "\\n"),
+			c if c.is_control() => write!(w, "\\u{:04x}", u32::from(c)),
+			c => write!(w, "{c}"),
+		}?;
+	}
+
+	write!(w, "\"")
+}
+
+// Lines of generated code paired with the code offset of the statement
+// that was written on them, so crash locations can be traced back to
+// the original binary
+#[derive(Default)]
+pub struct SourceMap {
+	line: usize,
+	line_list: Vec<(usize, usize)>,
+}
+
+impl SourceMap {
+	#[must_use]
+	pub const fn line(&self) -> usize {
+		self.line
+	}
+
+	#[must_use]
+	pub fn line_list(&self) -> &[(usize, usize)] {
+		&self.line_list
+	}
+
+	/// Maps the current line to `offset`. Only the first statement on a line
+	/// is kept, and an offset of 0 means the node has no position to map.
+	pub fn add_offset(&mut self, offset: usize) {
+		let is_mapped = self.line_list.last().is_some_and(|v| v.0 == self.line);
+
+		if offset != 0 && !is_mapped {
+			self.line_list.push((self.line, offset));
+		}
+	}
+
+	/// Moves past the lines in `data`, as if it had been written.
+	pub fn add_text(&mut self, data: &[u8]) {
+		for &v in data {
+			if v == b'\n' {
+				self.line += 1;
+			}
+		}
+	}
+
+	// Each mapped line gets one segment at its first column, with the
+	// source index, line, and column written relative to the last segment
+	fn write_json_with<F>(&self, source_list: &[&str], resolve: F, w: &mut dyn Write) -> Result<()>
+	where
+		F: Fn(usize) -> Option<[usize; 3]>,
+	{
+		write!(w, r#"{{"version":3,"sources":["#)?;
+
+		for (i, source) in source_list.iter().enumerate() {
+			if i != 0 {
+				write!(w, ",")?;
+			}
+
+			write_json_string(source, w)?;
+		}
+
+		write!(w, r#"],"names":[],"mappings":""#)?;
+
+		let mut line = 0;
+		let mut last = [0; 3];
+
+		for &(next, offset) in &self.line_list {
+			let Some(position) = resolve(offset) else {
+				continue;
+			};
+
+			for _ in line..next {
+				write!(w, ";")?;
+			}
+
+			write!(w, "A")?;
+
+			for (position, last) in position.iter().zip(&last) {
+				write_vlq(position.abs_diff(*last), position < last, w)?;
+			}
+
+			line = next;
+			last = position;
+		}
+
+		writeln!(w, r#""}}"#)
+	}
+
+	/// Writes the map in the version 3 source map format. The binary is the
+	/// only source and has a single line, so each offset is written as a column.
+	///
+	/// # Errors
+	/// Returns `Err` if writing to `Write` failed.
+	pub fn write_json(&self, source: &str, w: &mut dyn Write) -> Result<()> {
+		self.write_json_with(&[source], |offset| Some([0, 0, offset]), w)
+	}
+
+	/// Writes the map in the version 3 source map format, but with every offset
+	/// resolved through the DWARF line table to the file and line it was compiled
+	/// from. Lines the table has no location for are left unmapped.
+	///
+	/// # Errors
+	/// Returns `Err` if writing to `Write` failed.
+	pub fn write_json_dwarf(&self, line_table: &LineTable, w: &mut dyn Write) -> Result<()> {
+		let source_list: Vec<_> = line_table.file_list().iter().map(String::as_str).collect();
+
+		self.write_json_with(
+			&source_list,
+			|offset| {
+				let location = line_table.find(offset)?;
+				let line = usize::try_from(location.line()).ok()?;
+				let column = usize::try_from(location.column()).ok()?;
+
+				// Source maps count both from 0, DWARF counts both from 1
+				Some([location.file(), line - 1, column.saturating_sub(1)])
+			},
+			w,
+		)
+	}
+}
+
+// Forwards everything written while counting the lines it adds to the map
+pub struct LineCounter<'a> {
+	inner: &'a mut dyn Write,
+	source_map: Rc<RefCell<SourceMap>>,
+}
+
+impl<'a> LineCounter<'a> {
+	pub fn new(inner: &'a mut dyn Write, source_map: Rc<RefCell<SourceMap>>) -> Self {
+		Self { inner, source_map }
+	}
+}
+
+impl Write for LineCounter<'_> {
+	fn write(&mut self, buf: &[u8]) -> Result<usize> {
+		let written = self.inner.write(buf)?;
+
+		self.source_map.borrow_mut().add_text(&buf[..written]);
+
+		Ok(written)
+	}
+
+	fn flush(&mut self) -> Result<()> {
+		self.inner.flush()
+	}
+}
@@ -1,6 +1,7 @@
 use crate::{
 	node::{
-		Align, Expression, GetGlobal, LoadAt, Local, ResultList, SetTemporary, Statement, Temporary,
+		Align, BinOp, BinOpType, Expression, GetGlobal, LoadAt, Local, ResultList, SetTemporary,
+		Statement, Temporary, UnOp, UnOpType,
 	},
 	visit::{Driver, Visitor},
 };
@@ -51,6 +52,56 @@ where
 	}
 }
 
+// Finds whether computing an expression can trap, in which case it
+// must not be removed even when its value is unused
+pub struct Trap {
+	result: bool,
+}
+
+impl Trap {
+	pub fn run<D: Driver<Self>>(node: &D) -> bool {
+		let mut visitor = Self { result: false };
+
+		node.accept(&mut visitor);
+
+		visitor.result
+	}
+}
+
+impl Visitor for Trap {
+	fn visit_load_at(&mut self, _: &LoadAt) {
+		self.result = true;
+	}
+
+	fn visit_un_op(&mut self, un_op: &UnOp) {
+		self.result |= matches!(
+			un_op.op_type(),
+			UnOpType::Truncate_I32_F32
+				| UnOpType::Truncate_I32_F64
+				| UnOpType::Truncate_U32_F32
+				| UnOpType::Truncate_U32_F64
+				| UnOpType::Truncate_I64_F32
+				| UnOpType::Truncate_I64_F64
+				| UnOpType::Truncate_U64_F32
+				| UnOpType::Truncate_U64_F64
+		);
+	}
+
+	fn visit_bin_op(&mut self, bin_op: &BinOp) {
+		self.result |= matches!(
+			bin_op.op_type(),
+			BinOpType::DivS_I32
+				| BinOpType::DivU_I32
+				| BinOpType::RemS_I32
+				| BinOpType::RemU_I32
+				| BinOpType::DivS_I64
+				| BinOpType::DivU_I64
+				| BinOpType::RemS_I64
+				| BinOpType::RemU_I64
+		);
+	}
+}
+
 #[derive(Default)]
 pub struct Stack {
 	var_list: Vec<Expression>,
@@ -121,7 +172,7 @@ impl Stack {
 
 	// Try to leak a slot's value to a `SetTemporary` instruction,
 	// adjusting the capacity and old index accordingly
-	pub fn leak_into<P>(&mut self, code: &mut Vec<Statement>, predicate: P)
+	pub fn leak_into<P>(&mut self, code: &mut Vec<Statement>, code_offset: usize, predicate: P)
 	where
 		P: Fn(&Expression) -> bool,
 	{
@@ -138,6 +189,7 @@ impl Stack {
 			let set = Statement::SetTemporary(SetTemporary {
 				var: Temporary { var },
 				value: std::mem::replace(old, get).into(),
+				code_offset,
 			});
 
 			self.capacity = self.capacity.max(var + 1);
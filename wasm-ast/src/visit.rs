@@ -1,7 +1,10 @@
 use crate::node::{
-	BinOp, Block, Br, BrIf, BrTable, Call, CallIndirect, CmpOp, Expression, FuncData, GetGlobal,
-	If, LoadAt, Local, MemoryCopy, MemoryFill, MemoryGrow, MemorySize, Select, SetGlobal, SetLocal,
-	SetTemporary, Statement, StoreAt, Temporary, Terminator, UnOp, Value,
+	AtomicWait, BinOp, BitSelect, Block, Br, BrIf, BrTable, Call, CallIndirect, CmpOp, DataDrop,
+	ElemDrop, Expression, ExtractLane, FuncData, GetGlobal, If, LoadAt, Local, MemoryCopy,
+	MemoryFill, MemoryGrow, MemoryInit, MemorySize, RefIsNull, ReplaceLane, Rethrow, ReturnCall,
+	ReturnCallIndirect, Select, SetGlobal, SetLocal, SetTemporary, Shuffle, Statement, StoreAt,
+	TableCopy, TableFill, TableGet, TableGrow, TableInit, TableSet, TableSize, Temporary,
+	Terminator, Throw, Try, UnOp, Unreachable, Value,
 };
 
 pub trait Visitor {
@@ -25,14 +28,32 @@ pub trait Visitor {
 
 	fn visit_cmp_op(&mut self, _: &CmpOp) {}
 
+	fn visit_ref_is_null(&mut self, _: &RefIsNull) {}
+
+	fn visit_extract_lane(&mut self, _: &ExtractLane) {}
+
+	fn visit_replace_lane(&mut self, _: &ReplaceLane) {}
+
+	fn visit_shuffle(&mut self, _: &Shuffle) {}
+
+	fn visit_bit_select(&mut self, _: &BitSelect) {}
+
 	fn visit_expression(&mut self, _: &Expression) {}
 
-	fn visit_unreachable(&mut self) {}
+	fn visit_unreachable(&mut self, _: Unreachable) {}
 
 	fn visit_br(&mut self, _: Br) {}
 
 	fn visit_br_table(&mut self, _: &BrTable) {}
 
+	fn visit_return_call(&mut self, _: &ReturnCall) {}
+
+	fn visit_return_call_indirect(&mut self, _: &ReturnCallIndirect) {}
+
+	fn visit_throw(&mut self, _: &Throw) {}
+
+	fn visit_rethrow(&mut self, _: Rethrow) {}
+
 	fn visit_terminator(&mut self, _: &Terminator) {}
 
 	fn visit_block(&mut self, _: &Block) {}
@@ -41,6 +62,8 @@ pub trait Visitor {
 
 	fn visit_if(&mut self, _: &If) {}
 
+	fn visit_try(&mut self, _: &Try) {}
+
 	fn visit_call(&mut self, _: &Call) {}
 
 	fn visit_call_indirect(&mut self, _: &CallIndirect) {}
@@ -55,10 +78,32 @@ pub trait Visitor {
 
 	fn visit_memory_grow(&mut self, _: &MemoryGrow) {}
 
+	fn visit_atomic_wait(&mut self, _: &AtomicWait) {}
+
 	fn visit_memory_copy(&mut self, _: &MemoryCopy) {}
 
 	fn visit_memory_fill(&mut self, _: &MemoryFill) {}
 
+	fn visit_memory_init(&mut self, _: &MemoryInit) {}
+
+	fn visit_data_drop(&mut self, _: DataDrop) {}
+
+	fn visit_table_get(&mut self, _: &TableGet) {}
+
+	fn visit_table_set(&mut self, _: &TableSet) {}
+
+	fn visit_table_size(&mut self, _: TableSize) {}
+
+	fn visit_table_grow(&mut self, _: &TableGrow) {}
+
+	fn visit_table_fill(&mut self, _: &TableFill) {}
+
+	fn visit_table_copy(&mut self, _: &TableCopy) {}
+
+	fn visit_table_init(&mut self, _: &TableInit) {}
+
+	fn visit_elem_drop(&mut self, _: ElemDrop) {}
+
 	fn visit_statement(&mut self, _: &Statement) {}
 }
 
@@ -128,6 +173,90 @@ impl<T: Visitor> Driver<T> for MemoryFill {
 	}
 }
 
+impl<T: Visitor> Driver<T> for MemoryInit {
+	fn accept(&self, visitor: &mut T) {
+		self.destination().pointer().accept(visitor);
+		self.offset().accept(visitor);
+		self.size().accept(visitor);
+
+		visitor.visit_memory_init(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for DataDrop {
+	fn accept(&self, visitor: &mut T) {
+		visitor.visit_data_drop(*self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for TableGet {
+	fn accept(&self, visitor: &mut T) {
+		self.index().accept(visitor);
+
+		visitor.visit_table_get(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for TableSet {
+	fn accept(&self, visitor: &mut T) {
+		self.index().accept(visitor);
+		self.value().accept(visitor);
+
+		visitor.visit_table_set(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for TableSize {
+	fn accept(&self, visitor: &mut T) {
+		visitor.visit_table_size(*self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for TableGrow {
+	fn accept(&self, visitor: &mut T) {
+		self.value().accept(visitor);
+		self.size().accept(visitor);
+
+		visitor.visit_table_grow(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for TableFill {
+	fn accept(&self, visitor: &mut T) {
+		self.destination().index().accept(visitor);
+		self.value().accept(visitor);
+		self.size().accept(visitor);
+
+		visitor.visit_table_fill(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for TableCopy {
+	fn accept(&self, visitor: &mut T) {
+		self.destination().index().accept(visitor);
+		self.source().index().accept(visitor);
+		self.size().accept(visitor);
+
+		visitor.visit_table_copy(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for TableInit {
+	fn accept(&self, visitor: &mut T) {
+		self.destination().index().accept(visitor);
+		self.offset().accept(visitor);
+		self.size().accept(visitor);
+
+		visitor.visit_table_init(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for ElemDrop {
+	fn accept(&self, visitor: &mut T) {
+		visitor.visit_elem_drop(*self);
+	}
+}
+
 impl<T: Visitor> Driver<T> for Value {
 	fn accept(&self, visitor: &mut T) {
 		visitor.visit_value(*self);
@@ -160,6 +289,50 @@ impl<T: Visitor> Driver<T> for CmpOp {
 	}
 }
 
+impl<T: Visitor> Driver<T> for RefIsNull {
+	fn accept(&self, visitor: &mut T) {
+		self.value().accept(visitor);
+
+		visitor.visit_ref_is_null(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for ExtractLane {
+	fn accept(&self, visitor: &mut T) {
+		self.vector().accept(visitor);
+
+		visitor.visit_extract_lane(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for ReplaceLane {
+	fn accept(&self, visitor: &mut T) {
+		self.vector().accept(visitor);
+		self.value().accept(visitor);
+
+		visitor.visit_replace_lane(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for Shuffle {
+	fn accept(&self, visitor: &mut T) {
+		self.lhs().accept(visitor);
+		self.rhs().accept(visitor);
+
+		visitor.visit_shuffle(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for BitSelect {
+	fn accept(&self, visitor: &mut T) {
+		self.on_true().accept(visitor);
+		self.on_false().accept(visitor);
+		self.condition().accept(visitor);
+
+		visitor.visit_bit_select(self);
+	}
+}
+
 impl<T: Visitor> Driver<T> for Expression {
 	fn accept(&self, visitor: &mut T) {
 		match self {
@@ -173,6 +346,11 @@ impl<T: Visitor> Driver<T> for Expression {
 			Self::UnOp(v) => v.accept(visitor),
 			Self::BinOp(v) => v.accept(visitor),
 			Self::CmpOp(v) => v.accept(visitor),
+			Self::RefIsNull(v) => v.accept(visitor),
+			Self::ExtractLane(v) => v.accept(visitor),
+			Self::ReplaceLane(v) => v.accept(visitor),
+			Self::Shuffle(v) => v.accept(visitor),
+			Self::BitSelect(v) => v.accept(visitor),
 		}
 
 		visitor.visit_expression(self);
@@ -193,12 +371,60 @@ impl<T: Visitor> Driver<T> for BrTable {
 	}
 }
 
+impl<T: Visitor> Driver<T> for ReturnCall {
+	fn accept(&self, visitor: &mut T) {
+		for v in self.param_list() {
+			v.accept(visitor);
+		}
+
+		visitor.visit_return_call(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for ReturnCallIndirect {
+	fn accept(&self, visitor: &mut T) {
+		self.index().accept(visitor);
+
+		for v in self.param_list() {
+			v.accept(visitor);
+		}
+
+		visitor.visit_return_call_indirect(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for Throw {
+	fn accept(&self, visitor: &mut T) {
+		for v in self.param_list() {
+			v.accept(visitor);
+		}
+
+		visitor.visit_throw(self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for Unreachable {
+	fn accept(&self, visitor: &mut T) {
+		visitor.visit_unreachable(*self);
+	}
+}
+
+impl<T: Visitor> Driver<T> for Rethrow {
+	fn accept(&self, visitor: &mut T) {
+		visitor.visit_rethrow(*self);
+	}
+}
+
 impl<T: Visitor> Driver<T> for Terminator {
 	fn accept(&self, visitor: &mut T) {
 		match self {
-			Self::Unreachable => visitor.visit_unreachable(),
+			Self::Unreachable(v) => v.accept(visitor),
 			Self::Br(v) => v.accept(visitor),
 			Self::BrTable(v) => v.accept(visitor),
+			Self::ReturnCall(v) => v.accept(visitor),
+			Self::ReturnCallIndirect(v) => v.accept(visitor),
+			Self::Throw(v) => v.accept(visitor),
+			Self::Rethrow(v) => v.accept(visitor),
 		}
 
 		visitor.visit_terminator(self);
@@ -240,6 +466,22 @@ impl<T: Visitor> Driver<T> for If {
 	}
 }
 
+impl<T: Visitor> Driver<T> for Try {
+	fn accept(&self, visitor: &mut T) {
+		self.body().accept(visitor);
+
+		for v in self.catch_list() {
+			v.block().accept(visitor);
+		}
+
+		if let Some(v) = self.catch_all() {
+			v.accept(visitor);
+		}
+
+		visitor.visit_try(self);
+	}
+}
+
 impl<T: Visitor> Driver<T> for Call {
 	fn accept(&self, visitor: &mut T) {
 		for v in self.param_list() {
@@ -303,6 +545,16 @@ impl<T: Visitor> Driver<T> for MemoryGrow {
 	}
 }
 
+impl<T: Visitor> Driver<T> for AtomicWait {
+	fn accept(&self, visitor: &mut T) {
+		self.value().accept(visitor);
+		self.expected().accept(visitor);
+		self.timeout().accept(visitor);
+
+		visitor.visit_atomic_wait(self);
+	}
+}
+
 impl<T: Visitor> Driver<T> for Statement {
 	fn accept(&self, visitor: &mut T) {
 		match self {
@@ -316,8 +568,20 @@ impl<T: Visitor> Driver<T> for Statement {
 			Self::SetGlobal(v) => v.accept(visitor),
 			Self::StoreAt(v) => v.accept(visitor),
 			Self::MemoryGrow(v) => v.accept(visitor),
+			Self::AtomicWait(v) => v.accept(visitor),
 			Self::MemoryCopy(v) => v.accept(visitor),
 			Self::MemoryFill(v) => v.accept(visitor),
+			Self::MemoryInit(v) => v.accept(visitor),
+			Self::DataDrop(v) => v.accept(visitor),
+			Self::TableGet(v) => v.accept(visitor),
+			Self::TableSet(v) => v.accept(visitor),
+			Self::TableSize(v) => v.accept(visitor),
+			Self::TableGrow(v) => v.accept(visitor),
+			Self::TableFill(v) => v.accept(visitor),
+			Self::TableCopy(v) => v.accept(visitor),
+			Self::TableInit(v) => v.accept(visitor),
+			Self::ElemDrop(v) => v.accept(visitor),
+			Self::Try(v) => v.accept(visitor),
 		}
 
 		visitor.visit_statement(self);
@@ -329,3 +593,593 @@ impl<T: Visitor> Driver<T> for FuncData {
 		self.code().accept(visitor);
 	}
 }
+
+// Mirrors `Visitor` over a mutable tree, so passes can rewrite nodes in
+// place. Hooks run after the children, and `visit_expression_mut` or
+// `visit_statement_mut` may replace the node they are given outright.
+pub trait VisitorMut {
+	fn visit_select_mut(&mut self, _: &mut Select) {}
+
+	fn visit_get_temporary_mut(&mut self, _: &mut Temporary) {}
+
+	fn visit_get_local_mut(&mut self, _: &mut Local) {}
+
+	fn visit_get_global_mut(&mut self, _: &mut GetGlobal) {}
+
+	fn visit_load_at_mut(&mut self, _: &mut LoadAt) {}
+
+	fn visit_memory_size_mut(&mut self, _: &mut MemorySize) {}
+
+	fn visit_value_mut(&mut self, _: &mut Value) {}
+
+	fn visit_un_op_mut(&mut self, _: &mut UnOp) {}
+
+	fn visit_bin_op_mut(&mut self, _: &mut BinOp) {}
+
+	fn visit_cmp_op_mut(&mut self, _: &mut CmpOp) {}
+
+	fn visit_ref_is_null_mut(&mut self, _: &mut RefIsNull) {}
+
+	fn visit_extract_lane_mut(&mut self, _: &mut ExtractLane) {}
+
+	fn visit_replace_lane_mut(&mut self, _: &mut ReplaceLane) {}
+
+	fn visit_shuffle_mut(&mut self, _: &mut Shuffle) {}
+
+	fn visit_bit_select_mut(&mut self, _: &mut BitSelect) {}
+
+	fn visit_expression_mut(&mut self, _: &mut Expression) {}
+
+	fn visit_unreachable_mut(&mut self, _: &mut Unreachable) {}
+
+	fn visit_br_mut(&mut self, _: &mut Br) {}
+
+	fn visit_br_table_mut(&mut self, _: &mut BrTable) {}
+
+	fn visit_return_call_mut(&mut self, _: &mut ReturnCall) {}
+
+	fn visit_return_call_indirect_mut(&mut self, _: &mut ReturnCallIndirect) {}
+
+	fn visit_throw_mut(&mut self, _: &mut Throw) {}
+
+	fn visit_rethrow_mut(&mut self, _: &mut Rethrow) {}
+
+	fn visit_terminator_mut(&mut self, _: &mut Terminator) {}
+
+	fn visit_block_mut(&mut self, _: &mut Block) {}
+
+	fn visit_br_if_mut(&mut self, _: &mut BrIf) {}
+
+	fn visit_if_mut(&mut self, _: &mut If) {}
+
+	fn visit_try_mut(&mut self, _: &mut Try) {}
+
+	fn visit_call_mut(&mut self, _: &mut Call) {}
+
+	fn visit_call_indirect_mut(&mut self, _: &mut CallIndirect) {}
+
+	fn visit_set_temporary_mut(&mut self, _: &mut SetTemporary) {}
+
+	fn visit_set_local_mut(&mut self, _: &mut SetLocal) {}
+
+	fn visit_set_global_mut(&mut self, _: &mut SetGlobal) {}
+
+	fn visit_store_at_mut(&mut self, _: &mut StoreAt) {}
+
+	fn visit_memory_grow_mut(&mut self, _: &mut MemoryGrow) {}
+
+	fn visit_atomic_wait_mut(&mut self, _: &mut AtomicWait) {}
+
+	fn visit_memory_copy_mut(&mut self, _: &mut MemoryCopy) {}
+
+	fn visit_memory_fill_mut(&mut self, _: &mut MemoryFill) {}
+
+	fn visit_memory_init_mut(&mut self, _: &mut MemoryInit) {}
+
+	fn visit_data_drop_mut(&mut self, _: &mut DataDrop) {}
+
+	fn visit_table_get_mut(&mut self, _: &mut TableGet) {}
+
+	fn visit_table_set_mut(&mut self, _: &mut TableSet) {}
+
+	fn visit_table_size_mut(&mut self, _: &mut TableSize) {}
+
+	fn visit_table_grow_mut(&mut self, _: &mut TableGrow) {}
+
+	fn visit_table_fill_mut(&mut self, _: &mut TableFill) {}
+
+	fn visit_table_copy_mut(&mut self, _: &mut TableCopy) {}
+
+	fn visit_table_init_mut(&mut self, _: &mut TableInit) {}
+
+	fn visit_elem_drop_mut(&mut self, _: &mut ElemDrop) {}
+
+	fn visit_statement_mut(&mut self, _: &mut Statement) {}
+}
+
+pub trait DriverMut<T: VisitorMut> {
+	fn accept_mut(&mut self, visitor: &mut T);
+}
+
+impl<T: VisitorMut> DriverMut<T> for Select {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.condition_mut().accept_mut(visitor);
+		self.on_true_mut().accept_mut(visitor);
+		self.on_false_mut().accept_mut(visitor);
+
+		visitor.visit_select_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Temporary {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_get_temporary_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Local {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_get_local_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for GetGlobal {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_get_global_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for LoadAt {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.pointer_mut().accept_mut(visitor);
+
+		visitor.visit_load_at_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for MemorySize {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_memory_size_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for MemoryCopy {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.destination_mut().pointer_mut().accept_mut(visitor);
+		self.source_mut().pointer_mut().accept_mut(visitor);
+		self.size_mut().accept_mut(visitor);
+
+		visitor.visit_memory_copy_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for MemoryFill {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.destination_mut().pointer_mut().accept_mut(visitor);
+		self.size_mut().accept_mut(visitor);
+		self.value_mut().accept_mut(visitor);
+
+		visitor.visit_memory_fill_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for MemoryInit {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.destination_mut().pointer_mut().accept_mut(visitor);
+		self.offset_mut().accept_mut(visitor);
+		self.size_mut().accept_mut(visitor);
+
+		visitor.visit_memory_init_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for DataDrop {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_data_drop_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for TableGet {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.index_mut().accept_mut(visitor);
+
+		visitor.visit_table_get_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for TableSet {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.index_mut().accept_mut(visitor);
+		self.value_mut().accept_mut(visitor);
+
+		visitor.visit_table_set_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for TableSize {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_table_size_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for TableGrow {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.value_mut().accept_mut(visitor);
+		self.size_mut().accept_mut(visitor);
+
+		visitor.visit_table_grow_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for TableFill {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.destination_mut().index_mut().accept_mut(visitor);
+		self.value_mut().accept_mut(visitor);
+		self.size_mut().accept_mut(visitor);
+
+		visitor.visit_table_fill_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for TableCopy {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.destination_mut().index_mut().accept_mut(visitor);
+		self.source_mut().index_mut().accept_mut(visitor);
+		self.size_mut().accept_mut(visitor);
+
+		visitor.visit_table_copy_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for TableInit {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.destination_mut().index_mut().accept_mut(visitor);
+		self.offset_mut().accept_mut(visitor);
+		self.size_mut().accept_mut(visitor);
+
+		visitor.visit_table_init_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for ElemDrop {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_elem_drop_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Value {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_value_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for UnOp {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.rhs_mut().accept_mut(visitor);
+
+		visitor.visit_un_op_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for BinOp {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.lhs_mut().accept_mut(visitor);
+		self.rhs_mut().accept_mut(visitor);
+
+		visitor.visit_bin_op_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for CmpOp {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.lhs_mut().accept_mut(visitor);
+		self.rhs_mut().accept_mut(visitor);
+
+		visitor.visit_cmp_op_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for RefIsNull {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.value_mut().accept_mut(visitor);
+
+		visitor.visit_ref_is_null_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for ExtractLane {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.vector_mut().accept_mut(visitor);
+
+		visitor.visit_extract_lane_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for ReplaceLane {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.vector_mut().accept_mut(visitor);
+		self.value_mut().accept_mut(visitor);
+
+		visitor.visit_replace_lane_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Shuffle {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.lhs_mut().accept_mut(visitor);
+		self.rhs_mut().accept_mut(visitor);
+
+		visitor.visit_shuffle_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for BitSelect {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.on_true_mut().accept_mut(visitor);
+		self.on_false_mut().accept_mut(visitor);
+		self.condition_mut().accept_mut(visitor);
+
+		visitor.visit_bit_select_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Expression {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		match self {
+			Self::Select(v) => v.accept_mut(visitor),
+			Self::GetTemporary(v) => v.accept_mut(visitor),
+			Self::GetLocal(v) => v.accept_mut(visitor),
+			Self::GetGlobal(v) => v.accept_mut(visitor),
+			Self::LoadAt(v) => v.accept_mut(visitor),
+			Self::MemorySize(v) => v.accept_mut(visitor),
+			Self::Value(v) => v.accept_mut(visitor),
+			Self::UnOp(v) => v.accept_mut(visitor),
+			Self::BinOp(v) => v.accept_mut(visitor),
+			Self::CmpOp(v) => v.accept_mut(visitor),
+			Self::RefIsNull(v) => v.accept_mut(visitor),
+			Self::ExtractLane(v) => v.accept_mut(visitor),
+			Self::ReplaceLane(v) => v.accept_mut(visitor),
+			Self::Shuffle(v) => v.accept_mut(visitor),
+			Self::BitSelect(v) => v.accept_mut(visitor),
+		}
+
+		visitor.visit_expression_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Br {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_br_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for BrTable {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.condition_mut().accept_mut(visitor);
+
+		visitor.visit_br_table_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for ReturnCall {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		for v in self.param_list_mut() {
+			v.accept_mut(visitor);
+		}
+
+		visitor.visit_return_call_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for ReturnCallIndirect {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.index_mut().accept_mut(visitor);
+
+		for v in self.param_list_mut() {
+			v.accept_mut(visitor);
+		}
+
+		visitor.visit_return_call_indirect_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Throw {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		for v in self.param_list_mut() {
+			v.accept_mut(visitor);
+		}
+
+		visitor.visit_throw_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Unreachable {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_unreachable_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Rethrow {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		visitor.visit_rethrow_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Terminator {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		match self {
+			Self::Unreachable(v) => v.accept_mut(visitor),
+			Self::Br(v) => v.accept_mut(visitor),
+			Self::BrTable(v) => v.accept_mut(visitor),
+			Self::ReturnCall(v) => v.accept_mut(visitor),
+			Self::ReturnCallIndirect(v) => v.accept_mut(visitor),
+			Self::Throw(v) => v.accept_mut(visitor),
+			Self::Rethrow(v) => v.accept_mut(visitor),
+		}
+
+		visitor.visit_terminator_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Block {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		for v in self.code_mut() {
+			v.accept_mut(visitor);
+		}
+
+		if let Some(v) = self.last_mut() {
+			v.accept_mut(visitor);
+		}
+
+		visitor.visit_block_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for BrIf {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.condition_mut().accept_mut(visitor);
+
+		visitor.visit_br_if_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for If {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.condition_mut().accept_mut(visitor);
+		self.on_true_mut().accept_mut(visitor);
+
+		if let Some(v) = self.on_false_mut() {
+			v.accept_mut(visitor);
+		}
+
+		visitor.visit_if_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Try {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.body_mut().accept_mut(visitor);
+
+		for v in self.catch_list_mut() {
+			v.block_mut().accept_mut(visitor);
+		}
+
+		if let Some(v) = self.catch_all_mut() {
+			v.accept_mut(visitor);
+		}
+
+		visitor.visit_try_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Call {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		for v in self.param_list_mut() {
+			v.accept_mut(visitor);
+		}
+
+		visitor.visit_call_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for CallIndirect {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.index_mut().accept_mut(visitor);
+
+		for v in self.param_list_mut() {
+			v.accept_mut(visitor);
+		}
+
+		visitor.visit_call_indirect_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for SetTemporary {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.value_mut().accept_mut(visitor);
+
+		visitor.visit_set_temporary_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for SetLocal {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.value_mut().accept_mut(visitor);
+
+		visitor.visit_set_local_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for SetGlobal {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.value_mut().accept_mut(visitor);
+
+		visitor.visit_set_global_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for StoreAt {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.pointer_mut().accept_mut(visitor);
+		self.value_mut().accept_mut(visitor);
+
+		visitor.visit_store_at_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for MemoryGrow {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.size_mut().accept_mut(visitor);
+
+		visitor.visit_memory_grow_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for AtomicWait {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.value_mut().accept_mut(visitor);
+		self.expected_mut().accept_mut(visitor);
+		self.timeout_mut().accept_mut(visitor);
+
+		visitor.visit_atomic_wait_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for Statement {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		match self {
+			Self::Block(v) => v.accept_mut(visitor),
+			Self::BrIf(v) => v.accept_mut(visitor),
+			Self::If(v) => v.accept_mut(visitor),
+			Self::Call(v) => v.accept_mut(visitor),
+			Self::CallIndirect(v) => v.accept_mut(visitor),
+			Self::SetTemporary(v) => v.accept_mut(visitor),
+			Self::SetLocal(v) => v.accept_mut(visitor),
+			Self::SetGlobal(v) => v.accept_mut(visitor),
+			Self::StoreAt(v) => v.accept_mut(visitor),
+			Self::MemoryGrow(v) => v.accept_mut(visitor),
+			Self::AtomicWait(v) => v.accept_mut(visitor),
+			Self::MemoryCopy(v) => v.accept_mut(visitor),
+			Self::MemoryFill(v) => v.accept_mut(visitor),
+			Self::MemoryInit(v) => v.accept_mut(visitor),
+			Self::DataDrop(v) => v.accept_mut(visitor),
+			Self::TableGet(v) => v.accept_mut(visitor),
+			Self::TableSet(v) => v.accept_mut(visitor),
+			Self::TableSize(v) => v.accept_mut(visitor),
+			Self::TableGrow(v) => v.accept_mut(visitor),
+			Self::TableFill(v) => v.accept_mut(visitor),
+			Self::TableCopy(v) => v.accept_mut(visitor),
+			Self::TableInit(v) => v.accept_mut(visitor),
+			Self::ElemDrop(v) => v.accept_mut(visitor),
+			Self::Try(v) => v.accept_mut(visitor),
+		}
+
+		visitor.visit_statement_mut(self);
+	}
+}
+
+impl<T: VisitorMut> DriverMut<T> for FuncData {
+	fn accept_mut(&mut self, visitor: &mut T) {
+		self.code_mut().accept_mut(visitor);
+	}
+}